@@ -0,0 +1,56 @@
+/*
+ * C API for cursor_changer_ffi.dll
+ *
+ * All strings are NUL-terminated UTF-8. Every call that takes a session returns a
+ * CcStatus; on failure, cc_session_last_error() describes what went wrong.
+ */
+#ifndef CURSOR_CHANGER_H
+#define CURSOR_CHANGER_H
+
+#include <stdbool.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+typedef enum CcStatus {
+    CC_STATUS_OK = 0,
+    CC_STATUS_NULL_ARGUMENT = 1,
+    CC_STATUS_INVALID_UTF8 = 2,
+    CC_STATUS_UNKNOWN_ROLE = 3,
+    CC_STATUS_APPLY_FAILED = 4,
+    CC_STATUS_RESTORE_FAILED = 5,
+    CC_STATUS_CONVERT_FAILED = 6,
+    CC_STATUS_PANIC = 7
+} CcStatus;
+
+typedef struct CcSession CcSession;
+
+CcSession *cc_session_new(void);
+void cc_session_free(CcSession *session);
+
+/* Valid until the next call on the same session; NULL if the last call succeeded. */
+const char *cc_session_last_error(const CcSession *session);
+
+const char *cc_version(void);
+
+/* Apply one cursor file to every system role. */
+CcStatus cc_apply_cursor_file(CcSession *session, const char *path, int32_t size);
+
+/* Apply a cursor file to one role, e.g. "Normal", "Hand" or the registry key "Arrow". */
+CcStatus cc_apply_cursor_role(CcSession *session, const char *role, const char *path,
+                              int32_t size);
+
+/* Reload the cursors from the user's registry scheme. */
+CcStatus cc_restore_system_cursors(CcSession *session);
+
+/* Convert an SVG/PNG/ICO/BMP/JPG image to a .cur file. */
+CcStatus cc_convert_to_cur(CcSession *session, const char *input, const char *output,
+                           uint32_t size, uint16_t hotspot_x, uint16_t hotspot_y);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* CURSOR_CHANGER_H */
@@ -0,0 +1,274 @@
+//! C ABI for the core cursor operations.
+//!
+//! Lets AutoHotkey, Python (ctypes), C# (P/Invoke) and similar tools apply, restore and
+//! convert cursors through `cursor_changer_ffi.dll` instead of shelling out to the exe.
+//! The matching header is `include/cursor_changer.h`.
+//!
+//! Conventions:
+//! - Every call takes a `CcSession*` from `cc_session_new` and returns a `CcStatus`.
+//! - On failure the session keeps a message, readable via `cc_session_last_error`.
+//! - Strings are NUL-terminated UTF-8. Returned strings are owned by the library.
+//! - Panics are caught at the boundary and reported as `CC_STATUS_PANIC`.
+
+use std::ffi::{c_char, CStr, CString};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+/// Result code returned by every `cc_*` function.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CcStatus {
+    Ok = 0,
+    NullArgument = 1,
+    InvalidUtf8 = 2,
+    UnknownRole = 3,
+    ApplyFailed = 4,
+    RestoreFailed = 5,
+    ConvertFailed = 6,
+    Panic = 7,
+}
+
+/// Opaque handle owning the last error message.
+pub struct CcSession {
+    last_error: Option<CString>,
+}
+
+impl CcSession {
+    const fn new() -> Self {
+        Self { last_error: None }
+    }
+
+    fn fail(&mut self, status: CcStatus, message: impl Into<String>) -> CcStatus {
+        // Interior NULs can't cross the boundary; replace them rather than dropping the message
+        let message = message.into().replace('\0', " ");
+        self.last_error = CString::new(message).ok();
+        status
+    }
+}
+
+/// Run `f` against the session, mapping errors and panics to status codes.
+fn with_session(
+    session: *mut CcSession,
+    f: impl FnOnce(&mut CcSession) -> Result<(), (CcStatus, String)>,
+) -> CcStatus {
+    // SAFETY: callers pass a pointer from `cc_session_new` that hasn't been freed
+    let Some(session) = (unsafe { session.as_mut() }) else {
+        return CcStatus::NullArgument;
+    };
+    session.last_error = None;
+
+    match catch_unwind(AssertUnwindSafe(|| f(session))) {
+        Ok(Ok(())) => CcStatus::Ok,
+        Ok(Err((status, message))) => session.fail(status, message),
+        Err(_) => session.fail(CcStatus::Panic, "Internal error (panic) in cursor_changer_ffi"),
+    }
+}
+
+fn read_str<'a>(ptr: *const c_char, arg: &str) -> Result<&'a str, (CcStatus, String)> {
+    if ptr.is_null() {
+        return Err((CcStatus::NullArgument, format!("{arg} must not be null")));
+    }
+    // SAFETY: non-null and, per the API contract, NUL-terminated
+    unsafe { CStr::from_ptr(ptr) }
+        .to_str()
+        .map_err(|_| (CcStatus::InvalidUtf8, format!("{arg} is not valid UTF-8")))
+}
+
+fn role_id(role: &str) -> Option<u32> {
+    cursor_changer::CURSOR_TYPES
+        .iter()
+        .find(|ct| ct.name.eq_ignore_ascii_case(role) || ct.registry_key.eq_ignore_ascii_case(role))
+        .map(|ct| ct.id)
+}
+
+/// Create a session. Free it with `cc_session_free`.
+#[no_mangle]
+pub extern "C" fn cc_session_new() -> *mut CcSession {
+    Box::into_raw(Box::new(CcSession::new()))
+}
+
+/// Free a session. Passing null is a no-op.
+///
+/// # Safety
+/// `session` must come from `cc_session_new` and must not be used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn cc_session_free(session: *mut CcSession) {
+    if !session.is_null() {
+        drop(Box::from_raw(session));
+    }
+}
+
+/// Message for the last failed call on this session, or null if it succeeded.
+/// The pointer stays valid until the next call on the same session.
+///
+/// # Safety
+/// `session` must be null or a live pointer from `cc_session_new`.
+#[no_mangle]
+pub unsafe extern "C" fn cc_session_last_error(session: *const CcSession) -> *const c_char {
+    session
+        .as_ref()
+        .and_then(|s| s.last_error.as_ref())
+        .map_or(std::ptr::null(), |e| e.as_ptr())
+}
+
+/// Library version as a static NUL-terminated string.
+#[no_mangle]
+pub extern "C" fn cc_version() -> *const c_char {
+    concat!(env!("CARGO_PKG_VERSION"), "\0").as_ptr().cast()
+}
+
+/// Apply one cursor file to every system role at `size` pixels.
+/// Only the live cursors change; the registry scheme is left alone.
+///
+/// # Safety
+/// `session` must be live; `path` must be a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn cc_apply_cursor_file(
+    session: *mut CcSession,
+    path: *const c_char,
+    size: i32,
+) -> CcStatus {
+    with_session(session, |_| {
+        let path = read_str(path, "path")?;
+        if unsafe { cursor_changer::apply_cursor_file_with_size(path, size) } {
+            Ok(())
+        } else {
+            Err((CcStatus::ApplyFailed, format!("Failed to apply {path}")))
+        }
+    })
+}
+
+/// Apply a cursor file to a single role, e.g. `"Normal"`, `"Hand"` or the registry key `"Arrow"`.
+///
+/// # Safety
+/// `session` must be live; `role` and `path` must be NUL-terminated strings.
+#[no_mangle]
+pub unsafe extern "C" fn cc_apply_cursor_role(
+    session: *mut CcSession,
+    role: *const c_char,
+    path: *const c_char,
+    size: i32,
+) -> CcStatus {
+    with_session(session, |_| {
+        let role = read_str(role, "role")?;
+        let path = read_str(path, "path")?;
+        let id = role_id(role)
+            .ok_or_else(|| (CcStatus::UnknownRole, format!("Unknown cursor role: {role}")))?;
+        if unsafe { cursor_changer::apply_cursor_from_file_with_size(path, id, size) } {
+            Ok(())
+        } else {
+            Err((CcStatus::ApplyFailed, format!("Failed to apply {path} to {role}")))
+        }
+    })
+}
+
+/// Reload the cursors from the user's registry scheme, undoing any applies.
+///
+/// # Safety
+/// `session` must be live.
+#[no_mangle]
+pub unsafe extern "C" fn cc_restore_system_cursors(session: *mut CcSession) -> CcStatus {
+    with_session(session, |_| {
+        if unsafe { cursor_changer::restore_system_cursors() } {
+            Ok(())
+        } else {
+            Err((CcStatus::RestoreFailed, "Failed to reload system cursors".to_string()))
+        }
+    })
+}
+
+/// Convert an SVG/PNG/ICO/BMP/JPG image to a `.cur` file.
+///
+/// # Safety
+/// `session` must be live; `input` and `output` must be NUL-terminated strings.
+#[no_mangle]
+pub unsafe extern "C" fn cc_convert_to_cur(
+    session: *mut CcSession,
+    input: *const c_char,
+    output: *const c_char,
+    size: u32,
+    hotspot_x: u16,
+    hotspot_y: u16,
+) -> CcStatus {
+    with_session(session, |_| {
+        let input = read_str(input, "input")?;
+        let output = read_str(output, "output")?;
+        cursor_convert::convert_to_cur(input, output, size, hotspot_x, hotspot_y, 1.0, 0, 0)
+            .map_err(|e| (CcStatus::ConvertFailed, e))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn last_error(session: *const CcSession) -> String {
+        let ptr = unsafe { cc_session_last_error(session) };
+        assert!(!ptr.is_null());
+        unsafe { CStr::from_ptr(ptr) }.to_string_lossy().into_owned()
+    }
+
+    #[test]
+    fn null_arguments_are_reported() {
+        let session = cc_session_new();
+        let status = unsafe { cc_apply_cursor_file(session, std::ptr::null(), 32) };
+        assert_eq!(status, CcStatus::NullArgument);
+        assert_eq!(last_error(session), "path must not be null");
+
+        assert_eq!(
+            unsafe { cc_restore_system_cursors(std::ptr::null_mut()) },
+            CcStatus::NullArgument
+        );
+        unsafe { cc_session_free(session) };
+    }
+
+    #[test]
+    fn unknown_role_is_rejected_before_touching_the_system() {
+        let session = cc_session_new();
+        let role = CString::new("NotARole").unwrap();
+        let path = CString::new("C:\\missing.cur").unwrap();
+        let status = unsafe { cc_apply_cursor_role(session, role.as_ptr(), path.as_ptr(), 32) };
+        assert_eq!(status, CcStatus::UnknownRole);
+        assert_eq!(last_error(session), "Unknown cursor role: NotARole");
+        unsafe { cc_session_free(session) };
+    }
+
+    #[test]
+    fn role_lookup_accepts_names_and_registry_keys() {
+        assert_eq!(role_id("normal"), role_id("Arrow"));
+        assert!(role_id("Hand").is_some());
+        assert!(role_id("nope").is_none());
+    }
+
+    #[test]
+    fn convert_writes_cur_and_clears_error_on_success() {
+        let temp = tempfile::tempdir().unwrap();
+        let svg_path = temp.path().join("in.svg");
+        let cur_path = temp.path().join("out.cur");
+        std::fs::write(
+            &svg_path,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="32" height="32"><rect width="32" height="32" fill="red"/></svg>"#,
+        )
+        .unwrap();
+
+        let session = cc_session_new();
+        let missing = CString::new(temp.path().join("nope.svg").to_str().unwrap()).unwrap();
+        let input = CString::new(svg_path.to_str().unwrap()).unwrap();
+        let output = CString::new(cur_path.to_str().unwrap()).unwrap();
+
+        let status =
+            unsafe { cc_convert_to_cur(session, missing.as_ptr(), output.as_ptr(), 32, 0, 0) };
+        assert_eq!(status, CcStatus::ConvertFailed);
+
+        let status = unsafe { cc_convert_to_cur(session, input.as_ptr(), output.as_ptr(), 32, 0, 0) };
+        assert_eq!(status, CcStatus::Ok);
+        assert!(unsafe { cc_session_last_error(session) }.is_null());
+        assert!(cur_path.exists());
+        unsafe { cc_session_free(session) };
+    }
+
+    #[test]
+    fn version_matches_crate() {
+        let version = unsafe { CStr::from_ptr(cc_version()) };
+        assert_eq!(version.to_str().unwrap(), env!("CARGO_PKG_VERSION"));
+    }
+}
@@ -0,0 +1,168 @@
+//! .ANI file generation module for animated cursors
+//!
+//! This module provides functionality to:
+//! - Wrap a sequence of RGBA frames into a RIFF `ACON` animated cursor
+//! - Encode every frame as a PNG-embedded .CUR (see [`crate::cur_generator`])
+//! - Write per-frame display rates in jiffies (1/60 s)
+//!
+//! Layout written:
+//! - `RIFF` / `ACON` header
+//! - `anih` header (36 bytes, `AF_ICON` set since frames are .CUR data)
+//! - `rate` chunk, only when frames have different rates
+//! - `LIST` / `fram` with one `icon` chunk per frame
+
+use super::binary_writer::{u32_len, write_u32};
+use super::cur_generator::generate_cur_data;
+use image::{ImageBuffer, Rgba};
+
+/// `anih` flag: frames are icon/cursor data rather than raw bitmaps
+const AF_ICON: u32 = 0x1;
+
+/// Convert a frame delay in milliseconds to jiffies (1/60 s), at least one
+#[must_use]
+pub fn ms_to_jiffies(ms: u32) -> u32 {
+    u32::try_from(((u64::from(ms) * 60 + 500) / 1000).max(1)).unwrap_or(u32::MAX)
+}
+
+/// Generate .ANI file data from frames that share one hotspot
+///
+/// `rates` holds the display time of each frame in jiffies; a single value applies to every
+/// frame. All frames must have the dimensions of the first.
+///
+/// # Errors
+/// When there are no frames, frame sizes differ, the rates do not match the frames, or a frame
+/// cannot be encoded
+pub fn generate_ani_data(
+    frames: &[ImageBuffer<Rgba<u8>, Vec<u8>>],
+    click_point_x: u16,
+    click_point_y: u16,
+    rates: &[u32],
+) -> Result<Vec<u8>, String> {
+    let first = frames
+        .first()
+        .ok_or_else(|| "Animated cursor needs at least one frame".to_string())?;
+    if frames.iter().any(|f| f.dimensions() != first.dimensions()) {
+        return Err("All animated cursor frames must have the same size".to_string());
+    }
+    if rates.is_empty() || (rates.len() != 1 && rates.len() != frames.len()) {
+        return Err("Animated cursor needs one rate or one rate per frame".to_string());
+    }
+
+    let icons = frames
+        .iter()
+        .map(|frame| generate_cur_data(frame, click_point_x, click_point_y))
+        .collect::<Result<Vec<_>, _>>()?;
+    let frame_count = u32_len(frames.len())?;
+    let uniform_rate = rates.iter().all(|&r| r == rates[0]);
+
+    let mut body = Vec::new();
+    body.extend_from_slice(b"ACON");
+
+    // anih: cbSize, nFrames, nSteps, cx, cy, cBitCount, cPlanes, JifRate, flags
+    body.extend_from_slice(b"anih");
+    write_u32(&mut body, 36)?;
+    write_u32(&mut body, 36)?;
+    write_u32(&mut body, frame_count)?;
+    write_u32(&mut body, frame_count)?;
+    write_u32(&mut body, 0)?; // cx/cy/bit count/planes are unused with AF_ICON
+    write_u32(&mut body, 0)?;
+    write_u32(&mut body, 0)?;
+    write_u32(&mut body, 0)?;
+    write_u32(&mut body, rates[0])?;
+    write_u32(&mut body, AF_ICON)?;
+
+    if !uniform_rate {
+        body.extend_from_slice(b"rate");
+        write_u32(&mut body, frame_count * 4)?;
+        for &rate in rates {
+            write_u32(&mut body, rate)?;
+        }
+    }
+
+    let list_size: usize = 4 + icons
+        .iter()
+        .map(|i| 8 + i.len() + i.len() % 2)
+        .sum::<usize>();
+    body.extend_from_slice(b"LIST");
+    write_u32(&mut body, u32_len(list_size)?)?;
+    body.extend_from_slice(b"fram");
+    for icon in &icons {
+        body.extend_from_slice(b"icon");
+        write_u32(&mut body, u32_len(icon.len())?)?;
+        body.extend_from_slice(icon);
+        if !icon.len().is_multiple_of(2) {
+            body.push(0); // RIFF chunks are word aligned
+        }
+    }
+
+    let mut data = Vec::with_capacity(body.len() + 8);
+    data.extend_from_slice(b"RIFF");
+    write_u32(&mut data, u32_len(body.len())?)?;
+    data.extend_from_slice(&body);
+    Ok(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame(shade: u8) -> ImageBuffer<Rgba<u8>, Vec<u8>> {
+        ImageBuffer::from_pixel(8, 8, Rgba([shade, shade, shade, 255]))
+    }
+
+    fn chunk<'a>(data: &'a [u8], id: &[u8]) -> Option<&'a [u8]> {
+        let pos = data.windows(4).position(|w| w == id)?;
+        let size = u32::from_le_bytes(data[pos + 4..pos + 8].try_into().ok()?) as usize;
+        data.get(pos + 8..pos + 8 + size)
+    }
+
+    #[test]
+    fn test_ani_layout() {
+        let data = generate_ani_data(&[frame(0), frame(128), frame(255)], 3, 4, &[6]).unwrap();
+
+        assert_eq!(&data[0..4], b"RIFF");
+        assert_eq!(&data[8..12], b"ACON");
+        assert_eq!(
+            u32::from_le_bytes(data[4..8].try_into().unwrap()) as usize,
+            data.len() - 8
+        );
+
+        let anih = chunk(&data, b"anih").unwrap();
+        assert_eq!(u32::from_le_bytes(anih[4..8].try_into().unwrap()), 3);
+        assert_eq!(u32::from_le_bytes(anih[28..32].try_into().unwrap()), 6);
+        assert_eq!(
+            u32::from_le_bytes(anih[32..36].try_into().unwrap()),
+            AF_ICON
+        );
+        assert!(chunk(&data, b"rate").is_none());
+
+        let icon = chunk(&data, b"icon").unwrap();
+        assert_eq!(&icon[0..4], &[0, 0, 2, 0]);
+        // Hotspot of the first frame
+        assert_eq!(u16::from_le_bytes([icon[10], icon[11]]), 3);
+        assert_eq!(u16::from_le_bytes([icon[12], icon[13]]), 4);
+        assert_eq!(data.windows(4).filter(|w| w == b"icon").count(), 3);
+    }
+
+    #[test]
+    fn test_ani_per_frame_rates() {
+        let data = generate_ani_data(&[frame(0), frame(255)], 0, 0, &[2, 9]).unwrap();
+        let rate = chunk(&data, b"rate").unwrap();
+        assert_eq!(rate, &[2, 0, 0, 0, 9, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_ani_rejects_bad_input() {
+        assert!(generate_ani_data(&[], 0, 0, &[6]).is_err());
+        assert!(generate_ani_data(&[frame(0), frame(1)], 0, 0, &[1, 2, 3]).is_err());
+        let small = ImageBuffer::from_pixel(4, 4, Rgba([0, 0, 0, 255]));
+        assert!(generate_ani_data(&[frame(0), small], 0, 0, &[6]).is_err());
+    }
+
+    #[test]
+    fn test_ms_to_jiffies() {
+        assert_eq!(ms_to_jiffies(100), 6);
+        assert_eq!(ms_to_jiffies(1000), 60);
+        assert_eq!(ms_to_jiffies(0), 1);
+    }
+}
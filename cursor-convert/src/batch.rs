@@ -0,0 +1,266 @@
+//! Batch conversion of every image in a folder
+//!
+//! [`convert_directory_to_cur`] converts each supported file on its own, so one unreadable
+//! or corrupt image shows up as a failed entry in the [`BatchReport`] instead of aborting the
+//! rest of the folder.
+
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::cur_generator::MAX_CURSOR_SIZE;
+use crate::format_handler::handler_for_extension;
+
+/// Settings applied to every file of a batch
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BatchDefaults {
+    /// Target size in pixels (width and height, max 256)
+    pub size: u32,
+    /// Hotspot for inputs without one of their own. .CUR inputs keep their hotspot, scaled
+    /// with the image.
+    pub hotspot_x: u16,
+    pub hotspot_y: u16,
+}
+
+impl Default for BatchDefaults {
+    fn default() -> Self {
+        Self {
+            size: MAX_CURSOR_SIZE,
+            hotspot_x: 0,
+            hotspot_y: 0,
+        }
+    }
+}
+
+/// A file the batch converted
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConvertedFile {
+    pub output_path: String,
+    /// Hotspot written to the output file
+    pub hotspot: (u16, u16),
+}
+
+/// Outcome for one input file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BatchFileResult {
+    pub input_path: String,
+    pub outcome: Result<ConvertedFile, String>,
+}
+
+/// Per-file outcomes of [`convert_directory_to_cur`], in file name order
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BatchReport {
+    pub files: Vec<BatchFileResult>,
+}
+
+impl BatchReport {
+    #[must_use]
+    pub fn succeeded(&self) -> usize {
+        self.files
+            .iter()
+            .filter(|file| file.outcome.is_ok())
+            .count()
+    }
+
+    #[must_use]
+    pub fn failed(&self) -> usize {
+        self.files.len() - self.succeeded()
+    }
+}
+
+/// Files directly inside `dir` whose extension a registered handler claims, sorted by name.
+/// Subfolders and other files are ignored.
+fn supported_files(dir: &Path) -> Result<Vec<PathBuf>, String> {
+    let entries =
+        fs::read_dir(dir).map_err(|e| format!("Failed to read {}: {}", dir.display(), e))?;
+    let mut files: Vec<PathBuf> = entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file())
+        .filter(|path| {
+            path.extension()
+                .and_then(|ext| ext.to_str())
+                .is_some_and(|ext| handler_for_extension(ext).is_some())
+        })
+        .collect();
+    files.sort();
+    Ok(files)
+}
+
+fn convert_file(
+    input: &Path,
+    output: &Path,
+    defaults: BatchDefaults,
+) -> Result<(u16, u16), String> {
+    let input = input.to_string_lossy();
+    let output = output.to_string_lossy();
+    let is_cur = input
+        .rsplit_once('.')
+        .is_some_and(|(_, ext)| ext.eq_ignore_ascii_case("cur"));
+    if is_cur {
+        return crate::convert_to_cur_preserving_hotspot(&input, &output, defaults.size, 1.0, 0, 0);
+    }
+
+    // Keep the default hotspot on the canvas for small sizes
+    let max = u16::try_from(defaults.size.clamp(1, MAX_CURSOR_SIZE) - 1).unwrap_or(u16::MAX);
+    let hotspot = (defaults.hotspot_x.min(max), defaults.hotspot_y.min(max));
+    crate::convert_to_cur(
+        &input,
+        &output,
+        defaults.size,
+        hotspot.0,
+        hotspot.1,
+        1.0,
+        0,
+        0,
+    )?;
+    Ok(hotspot)
+}
+
+/// Convert every supported image directly inside `input_dir` to a .CUR file of the same name
+/// in `output_dir`, which is created if missing.
+///
+/// Each file is converted on its own and its success or error is recorded in the returned
+/// report; when two inputs would produce the same output name (e.g. `arrow.png` and
+/// `arrow.svg`) only the first is written.
+///
+/// # Errors
+/// When `input_dir` cannot be read, `output_dir` cannot be created, or both are the same folder
+pub fn convert_directory_to_cur(
+    input_dir: &str,
+    output_dir: &str,
+    defaults: BatchDefaults,
+) -> Result<BatchReport, String> {
+    let input_dir = Path::new(input_dir);
+    let output_dir = Path::new(output_dir);
+    let files = supported_files(input_dir)?;
+    fs::create_dir_all(output_dir)
+        .map_err(|e| format!("Failed to create {}: {}", output_dir.display(), e))?;
+
+    let same_dir = match (input_dir.canonicalize(), output_dir.canonicalize()) {
+        (Ok(input), Ok(output)) => input == output,
+        _ => false,
+    };
+    if same_dir {
+        return Err("Output folder must differ from the input folder".to_string());
+    }
+
+    let mut written = HashSet::new();
+    let mut report = BatchReport::default();
+    for input in files {
+        let stem = input
+            .file_stem()
+            .map_or_else(|| "cursor".to_string(), |s| s.to_string_lossy().to_string());
+        let output = output_dir.join(format!("{stem}.cur"));
+        let outcome = if written.insert(stem.to_lowercase()) {
+            convert_file(&input, &output, defaults).map(|hotspot| ConvertedFile {
+                output_path: output.to_string_lossy().to_string(),
+                hotspot,
+            })
+        } else {
+            Err(format!(
+                "Another file in the folder already converts to {stem}.cur"
+            ))
+        };
+        report.files.push(BatchFileResult {
+            input_path: input.to_string_lossy().to_string(),
+            outcome,
+        });
+    }
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{ImageBuffer, Rgba};
+
+    fn write_png(path: &Path) {
+        ImageBuffer::from_pixel(16, 16, Rgba([0u8, 0, 255, 255]))
+            .save(path)
+            .expect("save png");
+    }
+
+    #[test]
+    fn bad_files_are_reported_without_stopping_the_batch() {
+        let input = tempfile::tempdir().expect("input dir");
+        let output = tempfile::tempdir().expect("output dir");
+        write_png(&input.path().join("b_good.png"));
+        fs::write(input.path().join("a_broken.png"), b"not a png").expect("write");
+        fs::write(input.path().join("notes.txt"), b"ignored").expect("write");
+
+        let report = convert_directory_to_cur(
+            &input.path().to_string_lossy(),
+            &output.path().join("out").to_string_lossy(),
+            BatchDefaults {
+                size: 32,
+                hotspot_x: 5,
+                hotspot_y: 6,
+            },
+        )
+        .expect("batch");
+
+        assert_eq!(report.files.len(), 2);
+        assert_eq!((report.succeeded(), report.failed()), (1, 1));
+        assert!(report.files[0].input_path.ends_with("a_broken.png"));
+        assert!(report.files[0].outcome.is_err());
+        let converted = report.files[1].outcome.as_ref().expect("converted");
+        assert_eq!(converted.hotspot, (5, 6));
+        let data = fs::read(&converted.output_path).expect("output written");
+        assert_eq!(u16::from_le_bytes([data[10], data[11]]), 5);
+        assert_eq!(u16::from_le_bytes([data[12], data[13]]), 6);
+    }
+
+    #[test]
+    fn colliding_output_names_are_reported() {
+        let input = tempfile::tempdir().expect("input dir");
+        let output = tempfile::tempdir().expect("output dir");
+        write_png(&input.path().join("arrow.png"));
+        write_png(&input.path().join("Arrow.bmp"));
+
+        let report = convert_directory_to_cur(
+            &input.path().to_string_lossy(),
+            &output.path().to_string_lossy(),
+            BatchDefaults::default(),
+        )
+        .expect("batch");
+
+        assert_eq!((report.succeeded(), report.failed()), (1, 1));
+    }
+
+    #[test]
+    fn default_hotspot_is_clamped_to_the_canvas() {
+        let input = tempfile::tempdir().expect("input dir");
+        let output = tempfile::tempdir().expect("output dir");
+        write_png(&input.path().join("dot.png"));
+
+        let report = convert_directory_to_cur(
+            &input.path().to_string_lossy(),
+            &output.path().to_string_lossy(),
+            BatchDefaults {
+                size: 16,
+                hotspot_x: 40,
+                hotspot_y: 3,
+            },
+        )
+        .expect("batch");
+
+        let converted = report.files[0].outcome.as_ref().expect("converted");
+        assert_eq!(converted.hotspot, (15, 3));
+    }
+
+    #[test]
+    fn missing_input_folder_and_same_folder_are_errors() {
+        let dir = tempfile::tempdir().expect("dir");
+        let missing = dir.path().join("missing");
+        assert!(convert_directory_to_cur(
+            &missing.to_string_lossy(),
+            &dir.path().to_string_lossy(),
+            BatchDefaults::default(),
+        )
+        .is_err());
+
+        let path = dir.path().to_string_lossy();
+        assert!(convert_directory_to_cur(&path, &path, BatchDefaults::default()).is_err());
+    }
+}
@@ -5,17 +5,31 @@
 //! - Handle byte-level data manipulation for .CUR file generation
 
 /// Write a u16 value to data in little-endian format
+///
+/// # Errors
+/// Never; the `Result` lets callers chain writes with `?`
 pub fn write_u16(data: &mut Vec<u8>, value: u16) -> Result<(), String> {
     data.extend_from_slice(&value.to_le_bytes());
     Ok(())
 }
 
 /// Write a u32 value to data in little-endian format
+///
+/// # Errors
+/// Never; the `Result` lets callers chain writes with `?`
 pub fn write_u32(data: &mut Vec<u8>, value: u32) -> Result<(), String> {
     data.extend_from_slice(&value.to_le_bytes());
     Ok(())
 }
 
+/// Convert a length or count to the u32 stored in a file header
+///
+/// # Errors
+/// When `value` does not fit in 32 bits
+pub fn u32_len(value: usize) -> Result<u32, String> {
+    u32::try_from(value).map_err(|_| format!("Length {value} does not fit in a 32-bit field"))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -30,7 +44,7 @@ mod tests {
     #[test]
     fn test_write_u32() {
         let mut data = Vec::new();
-        write_u32(&mut data, 0x12345678).unwrap();
+        write_u32(&mut data, 0x1234_5678).unwrap();
         assert_eq!(data, vec![0x78, 0x56, 0x34, 0x12]);
     }
 }
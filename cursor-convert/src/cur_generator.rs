@@ -0,0 +1,441 @@
+//! .CUR file generation module for cursor conversion
+//!
+//! This module provides functionality to:
+//! - Generate proper .CUR file format with hotspot coordinates
+//! - Use PNG embedded format for maximum quality (32-bit RGBA, lossless)
+//! - Generate cursor headers and directory entries correctly
+//! - Embed several sizes of one cursor in a single file, for Windows to pick per DPI
+//!
+//! Quality notes:
+//! - Maximum practical cursor size is 256x256 (Windows .CUR format limit)
+//! - All cursors use embedded PNG format for lossless RGBA8 quality
+//! - 32-bit color depth with full 8-bit alpha channel
+//! - [`PngEncoding::Deterministic`] trades size for output that depends only on the pixels,
+//!   for golden-file tests
+
+use super::binary_writer::{u32_len, write_u16, write_u32};
+use image::{codecs::png::PngEncoder, ImageBuffer, ImageEncoder, Rgba};
+
+/// Maximum cursor dimension (Windows .CUR format limit)
+pub const MAX_CURSOR_SIZE: u32 = 256;
+
+/// Sizes embedded by [`generate_multi_size_cur_data`] callers that have no preference
+pub const MULTI_SIZE_CURSOR_SIZES: [u32; 5] = [32, 48, 64, 128, 256];
+
+/// One image of a multi-size cursor with its click point
+pub type CursorImage<'a> = (&'a ImageBuffer<Rgba<u8>, Vec<u8>>, (u16, u16));
+
+const ICONDIR_LEN: usize = 6;
+const ICONDIRENTRY_LEN: usize = 16;
+
+/// Largest payload of a stored (uncompressed) deflate block
+const STORED_BLOCK_MAX: usize = 65_535;
+
+/// How the PNG embedded in a .CUR is encoded
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PngEncoding {
+    /// Best compression with adaptive filtering. The bytes can change between releases of the
+    /// `image`/`png` crates even when the pixels don't.
+    #[default]
+    Compact,
+    /// Unfiltered rows in stored deflate blocks and only the IHDR, IDAT and IEND chunks, in
+    /// that order. The bytes depend only on the pixels, so golden files stay valid across
+    /// encoder upgrades.
+    Deterministic,
+}
+
+/// Generate .CUR file data from an RGBA image
+///
+/// Always uses PNG embedding for maximum quality (lossless RGBA8).
+///
+/// .CUR file format:
+/// - ICONDIR header (6 bytes)
+/// - ICONDIRENTRY (16 bytes)
+/// - PNG image data (lossless compression)
+///
+/// # Errors
+/// When the image is larger than [`MAX_CURSOR_SIZE`] or cannot be encoded as PNG
+pub fn generate_cur_data(
+    image: &ImageBuffer<Rgba<u8>, Vec<u8>>,
+    click_point_x: u16,
+    click_point_y: u16,
+) -> Result<Vec<u8>, String> {
+    generate_cur_data_with(image, click_point_x, click_point_y, PngEncoding::Compact)
+}
+
+/// Generate .CUR file data like [`generate_cur_data`], choosing how the PNG is encoded
+///
+/// # Errors
+/// See [`generate_cur_data`]
+pub fn generate_cur_data_with(
+    image: &ImageBuffer<Rgba<u8>, Vec<u8>>,
+    click_point_x: u16,
+    click_point_y: u16,
+    encoding: PngEncoding,
+) -> Result<Vec<u8>, String> {
+    generate_multi_size_cur_data_with(&[(image, (click_point_x, click_point_y))], encoding)
+}
+
+/// Generate one .CUR file holding several images of the same cursor, each with its own click
+/// point, so Windows can pick the size closest to the display's DPI instead of rescaling one.
+///
+/// .CUR file format:
+/// - ICONDIR header (6 bytes)
+/// - One ICONDIRENTRY (16 bytes) per image, in the order given
+/// - PNG image data for each entry, in the same order
+///
+/// # Errors
+/// When `images` is empty, an image is larger than [`MAX_CURSOR_SIZE`], two images share a
+/// size, or a PNG cannot be encoded
+pub fn generate_multi_size_cur_data(images: &[CursorImage<'_>]) -> Result<Vec<u8>, String> {
+    generate_multi_size_cur_data_with(images, PngEncoding::Compact)
+}
+
+/// Generate .CUR file data like [`generate_multi_size_cur_data`], choosing how the PNGs are
+/// encoded
+///
+/// # Errors
+/// See [`generate_multi_size_cur_data`]
+pub fn generate_multi_size_cur_data_with(
+    images: &[CursorImage<'_>],
+    encoding: PngEncoding,
+) -> Result<Vec<u8>, String> {
+    if images.is_empty() {
+        return Err("Cursor needs at least one image".to_string());
+    }
+    let count = u16::try_from(images.len()).map_err(|_| "Too many cursor images".to_string())?;
+    for (index, (image, _)) in images.iter().enumerate() {
+        validate_cursor_dimensions(image.width(), image.height())?;
+        let duplicate = images[..index]
+            .iter()
+            .any(|(other, _)| other.dimensions() == image.dimensions());
+        if duplicate {
+            return Err(format!(
+                "Cursor has more than one {}x{} image",
+                image.width(),
+                image.height()
+            ));
+        }
+    }
+
+    // Encode the images to PNG bytes (RGBA8, maximum quality)
+    let payloads = images
+        .iter()
+        .map(|(image, _)| match encoding {
+            PngEncoding::Compact => encode_image_to_png_bytes(image),
+            PngEncoding::Deterministic => Ok(encode_image_to_stored_png(image)),
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    let mut data = Vec::new();
+
+    // Write ICONDIR header
+    write_u16(&mut data, 0)?; // Reserved (must be 0)
+    write_u16(&mut data, 2)?; // Type (2 = cursor)
+    write_u16(&mut data, count)?; // Number of images
+
+    // Always use PNG embedding for maximum quality:
+    // - Lossless compression preserves all pixel data
+    // - Full 32-bit RGBA with 8-bit alpha channel
+    // - Better file size than uncompressed DIB for most images
+    // Image data follows the header and the whole directory
+    let mut image_data_offset = ICONDIR_LEN + ICONDIRENTRY_LEN * images.len();
+
+    for ((image, (click_point_x, click_point_y)), png_bytes) in images.iter().zip(&payloads) {
+        let width = image.width();
+        let height = image.height();
+        let last_pixel = |extent: u32| u16::try_from(extent.saturating_sub(1)).unwrap_or(u16::MAX);
+        let click_point_x = (*click_point_x).min(last_pixel(width));
+        let click_point_y = (*click_point_y).min(last_pixel(height));
+        let png_len = u32_len(png_bytes.len())?;
+
+        // Write ICONDIRENTRY
+        // Note: For dimension 256, we use 0 in the byte field (which means 256 in the spec);
+        // sizes were validated against MAX_CURSOR_SIZE above
+        data.push(u8::try_from(width).unwrap_or(0)); // Width
+        data.push(u8::try_from(height).unwrap_or(0)); // Height
+        data.push(0); // Color count (0 for truecolor)
+        data.push(0); // Reserved
+        write_u16(&mut data, click_point_x)?; // Click point X (hotspot)
+        write_u16(&mut data, click_point_y)?; // Click point Y (hotspot)
+        write_u32(&mut data, png_len)?; // Size of PNG data
+        write_u32(&mut data, u32_len(image_data_offset)?)?; // Offset to PNG data
+        image_data_offset += png_bytes.len();
+    }
+
+    // Append PNG bytes
+    for png_bytes in &payloads {
+        data.extend_from_slice(png_bytes);
+    }
+
+    Ok(data)
+}
+
+/// Encode an RGBA `ImageBuffer` to PNG bytes with maximum quality settings
+///
+/// Uses:
+/// - RGBA8 color type (32-bit with full alpha)
+/// - Best compression level for smallest file size without quality loss
+/// - PNG is lossless, so no quality degradation occurs
+fn encode_image_to_png_bytes(image: &ImageBuffer<Rgba<u8>, Vec<u8>>) -> Result<Vec<u8>, String> {
+    use image::codecs::png::CompressionType;
+    use image::codecs::png::FilterType;
+
+    let mut png_data = Vec::new();
+    let width = image.width();
+    let height = image.height();
+
+    // Use best compression (smaller file, same quality since PNG is lossless)
+    let encoder =
+        PngEncoder::new_with_quality(&mut png_data, CompressionType::Best, FilterType::Adaptive);
+    encoder
+        .write_image(
+            image.as_raw(),
+            width,
+            height,
+            image::ColorType::Rgba8.into(),
+        )
+        .map_err(|e| format!("Failed to encode PNG: {e}"))?;
+
+    Ok(png_data)
+}
+
+/// CRC-32 (ISO-HDLC) as used by PNG chunks
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = u32::MAX;
+    for &byte in bytes {
+        crc ^= u32::from(byte);
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+fn adler32(bytes: &[u8]) -> u32 {
+    const MOD_ADLER: u32 = 65_521;
+    let (mut a, mut b) = (1u32, 0u32);
+    for &byte in bytes {
+        a = (a + u32::from(byte)) % MOD_ADLER;
+        b = (b + a) % MOD_ADLER;
+    }
+    (b << 16) | a
+}
+
+fn push_png_chunk(png: &mut Vec<u8>, kind: [u8; 4], payload: &[u8]) {
+    // Chunks of a cursor image (at most 256x256) are far below 4 GiB
+    #[allow(clippy::cast_possible_truncation)]
+    png.extend_from_slice(&(payload.len() as u32).to_be_bytes());
+    let start = png.len();
+    png.extend_from_slice(&kind);
+    png.extend_from_slice(payload);
+    let crc = crc32(&png[start..]);
+    png.extend_from_slice(&crc.to_be_bytes());
+}
+
+/// Encode an RGBA `ImageBuffer` as a PNG whose bytes depend only on the pixels
+/// (see [`PngEncoding::Deterministic`])
+fn encode_image_to_stored_png(image: &ImageBuffer<Rgba<u8>, Vec<u8>>) -> Vec<u8> {
+    let mut ihdr = Vec::with_capacity(13);
+    ihdr.extend_from_slice(&image.width().to_be_bytes());
+    ihdr.extend_from_slice(&image.height().to_be_bytes());
+    // Bit depth 8, colour type 6 (RGBA), deflate, adaptive filter method, no interlace
+    ihdr.extend_from_slice(&[8, 6, 0, 0, 0]);
+
+    // Filter type 0 (None) before every row
+    let row_len = image.width() as usize * 4;
+    let mut raw = Vec::with_capacity((row_len + 1) * image.height() as usize);
+    for row in image.as_raw().chunks(row_len.max(1)) {
+        raw.push(0);
+        raw.extend_from_slice(row);
+    }
+
+    // zlib stream (32K window, no preset dictionary) made of stored deflate blocks
+    let mut zlib = vec![0x78, 0x01];
+    let mut blocks = raw.chunks(STORED_BLOCK_MAX).peekable();
+    if blocks.peek().is_none() {
+        zlib.extend_from_slice(&[1, 0, 0, 0xFF, 0xFF]);
+    }
+    while let Some(block) = blocks.next() {
+        // Blocks are at most STORED_BLOCK_MAX bytes
+        #[allow(clippy::cast_possible_truncation)]
+        let len = block.len() as u16;
+        zlib.push(u8::from(blocks.peek().is_none()));
+        zlib.extend_from_slice(&len.to_le_bytes());
+        zlib.extend_from_slice(&(!len).to_le_bytes());
+        zlib.extend_from_slice(block);
+    }
+    zlib.extend_from_slice(&adler32(&raw).to_be_bytes());
+
+    let mut png = vec![0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+    push_png_chunk(&mut png, *b"IHDR", &ihdr);
+    push_png_chunk(&mut png, *b"IDAT", &zlib);
+    push_png_chunk(&mut png, *b"IEND", &[]);
+    png
+}
+
+/// Validate cursor dimensions (max 256x256 for Windows .CUR format)
+///
+/// # Errors
+/// When either dimension is larger than [`MAX_CURSOR_SIZE`]
+pub fn validate_cursor_dimensions(width: u32, height: u32) -> Result<(), String> {
+    if width > MAX_CURSOR_SIZE || height > MAX_CURSOR_SIZE {
+        Err(format!(
+            "Image dimensions must be {MAX_CURSOR_SIZE}x{MAX_CURSOR_SIZE} or smaller"
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_cur_data_contains_click_point_coordinates() {
+        let image = ImageBuffer::from_pixel(32, 32, Rgba([255, 0, 0, 255]));
+        let data = generate_cur_data(&image, 7, 9).expect("generate");
+
+        // ICONDIR header: reserved(0), type(2), count(1)
+        assert_eq!(&data[0..4], &[0, 0, 2, 0]);
+        // Click point coordinates in ICONDIRENTRY
+        assert_eq!(u16::from_le_bytes([data[10], data[11]]), 7);
+        assert_eq!(u16::from_le_bytes([data[12], data[13]]), 9);
+    }
+
+    #[test]
+    fn test_generate_cur_data_uses_png_format() {
+        // All sizes now use PNG for maximum quality
+        let image = ImageBuffer::from_pixel(64, 64, Rgba([10, 20, 30, 255]));
+        let data = generate_cur_data(&image, 5, 7).expect("generate");
+
+        // ICONDIR header should be present
+        assert_eq!(&data[0..4], &[0, 0, 2, 0]);
+
+        // Image data should start at offset 6 + 16 = 22
+        let offset = 6 + 16;
+        assert!(data.len() >= offset + 8);
+
+        // PNG signature at the start of the image blob
+        assert_eq!(
+            &data[offset..offset + 8],
+            &[0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A]
+        );
+
+        // Click point coordinates preserved in ICONDIRENTRY
+        assert_eq!(u16::from_le_bytes([data[10], data[11]]), 5);
+        assert_eq!(u16::from_le_bytes([data[12], data[13]]), 7);
+    }
+
+    #[test]
+    fn test_generate_cur_data_256_is_png() {
+        // 256x256 is the maximum size
+        let image =
+            ImageBuffer::from_pixel(MAX_CURSOR_SIZE, MAX_CURSOR_SIZE, Rgba([10, 20, 30, 255]));
+        let data = generate_cur_data(&image, 5, 7).expect("generate");
+
+        // ICONDIR header should be present
+        assert_eq!(&data[0..4], &[0, 0, 2, 0]);
+
+        // Width/height bytes should be 0 (meaning 256)
+        assert_eq!(data[6], 0); // Width = 256
+        assert_eq!(data[7], 0); // Height = 256
+
+        // Image data should start at offset 6 + 16 = 22
+        let offset = 6 + 16;
+        assert!(data.len() >= offset + 8);
+
+        // PNG signature at the start of the image blob
+        assert_eq!(
+            &data[offset..offset + 8],
+            &[0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A]
+        );
+    }
+
+    #[test]
+    fn test_validate_cursor_dimensions() {
+        assert!(validate_cursor_dimensions(32, 32).is_ok());
+        assert!(validate_cursor_dimensions(128, 128).is_ok());
+        assert!(validate_cursor_dimensions(MAX_CURSOR_SIZE, MAX_CURSOR_SIZE).is_ok());
+
+        // 256 is the max
+        assert!(validate_cursor_dimensions(MAX_CURSOR_SIZE + 1, MAX_CURSOR_SIZE).is_err());
+        assert!(validate_cursor_dimensions(MAX_CURSOR_SIZE, MAX_CURSOR_SIZE + 1).is_err());
+        assert!(validate_cursor_dimensions(MAX_CURSOR_SIZE + 256, MAX_CURSOR_SIZE + 256).is_err());
+    }
+
+    #[test]
+    fn test_max_cursor_size_constant() {
+        assert_eq!(MAX_CURSOR_SIZE, 256);
+    }
+
+    #[test]
+    fn test_png_checksums() {
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+        assert_eq!(adler32(b"Wikipedia"), 0x11E6_0398);
+    }
+
+    #[test]
+    #[allow(clippy::cast_possible_truncation)] // pixel values wrap on purpose
+    fn test_deterministic_png_round_trips() {
+        // 256x256 RGBA needs several stored blocks
+        let image = ImageBuffer::from_fn(MAX_CURSOR_SIZE, MAX_CURSOR_SIZE, |x, y| {
+            Rgba([x as u8, y as u8, (x ^ y) as u8, (x + y) as u8])
+        });
+        let data =
+            generate_cur_data_with(&image, 3, 4, PngEncoding::Deterministic).expect("generate");
+        assert_eq!(
+            data,
+            generate_cur_data_with(&image, 3, 4, PngEncoding::Deterministic).expect("generate")
+        );
+
+        let decoded = image::load_from_memory_with_format(&data[22..], image::ImageFormat::Png)
+            .expect("decode")
+            .to_rgba8();
+        assert_eq!(decoded, image);
+    }
+
+    #[test]
+    fn test_multi_size_cur_directory_points_at_each_png() {
+        let large = ImageBuffer::from_pixel(64, 64, Rgba([1, 2, 3, 255]));
+        let small = ImageBuffer::from_pixel(32, 32, Rgba([4, 5, 6, 255]));
+        let data = generate_multi_size_cur_data_with(
+            &[(&large, (10, 12)), (&small, (5, 40))],
+            PngEncoding::Deterministic,
+        )
+        .expect("generate");
+
+        assert_eq!(&data[0..6], &[0, 0, 2, 0, 2, 0]);
+        let mut expected_offset = 6 + 2 * 16;
+        for (index, (image, hotspot)) in [(&large, (10, 12)), (&small, (5, 31))]
+            .into_iter()
+            .enumerate()
+        {
+            let at = 6 + index * 16;
+            assert_eq!(u32::from(data[at]), image.width());
+            assert_eq!(u16::from_le_bytes([data[at + 4], data[at + 5]]), hotspot.0);
+            // Click points are clamped to each image
+            assert_eq!(u16::from_le_bytes([data[at + 6], data[at + 7]]), hotspot.1);
+            let len = u32::from_le_bytes(data[at + 8..at + 12].try_into().unwrap()) as usize;
+            let offset = u32::from_le_bytes(data[at + 12..at + 16].try_into().unwrap()) as usize;
+            assert_eq!(offset, expected_offset);
+            let decoded = image::load_from_memory_with_format(
+                &data[offset..offset + len],
+                image::ImageFormat::Png,
+            )
+            .expect("decode")
+            .to_rgba8();
+            assert_eq!(&decoded, image);
+            expected_offset += len;
+        }
+        assert_eq!(expected_offset, data.len());
+
+        assert!(generate_multi_size_cur_data(&[]).is_err());
+        assert!(generate_multi_size_cur_data(&[(&small, (0, 0)), (&small, (1, 1))]).is_err());
+    }
+}
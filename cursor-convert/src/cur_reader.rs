@@ -0,0 +1,271 @@
+//! Reading existing .CUR files as conversion input
+//!
+//! Re-converting a cursor to another size should keep its click point on the same pixel of
+//! the artwork. This module parses the cursor directory, picks the frame that resizes best to
+//! the requested size, and maps that frame's hotspot onto the output canvas using the same
+//! placement as [`raster_handler::compose_raster_image`].
+
+use crate::binary_writer::u32_len;
+use crate::format_handler::{RenderParams, RgbaFrame};
+use crate::raster_handler;
+
+const ICONDIR_LEN: usize = 6;
+const ICONDIRENTRY_LEN: usize = 16;
+const CURSOR_TYPE: u16 = 2;
+const ICON_TYPE: u16 = 1;
+
+/// One decoded frame of a .CUR file with its hotspot.
+#[derive(Debug, Clone)]
+pub struct CurImage {
+    pub image: RgbaFrame,
+    pub hotspot_x: u16,
+    pub hotspot_y: u16,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct CurEntry {
+    width: u32,
+    height: u32,
+    hotspot_x: u16,
+    hotspot_y: u16,
+    size: usize,
+    offset: usize,
+}
+
+fn read_u16(data: &[u8], at: usize) -> u16 {
+    u16::from_le_bytes([data[at], data[at + 1]])
+}
+
+fn read_u32(data: &[u8], at: usize) -> u32 {
+    u32::from_le_bytes([data[at], data[at + 1], data[at + 2], data[at + 3]])
+}
+
+/// Whether `header` starts with a cursor (not icon) directory.
+#[must_use]
+pub fn is_cur_header(header: &[u8]) -> bool {
+    header.len() >= ICONDIR_LEN && read_u16(header, 0) == 0 && read_u16(header, 2) == CURSOR_TYPE
+}
+
+fn parse_entries(data: &[u8]) -> Result<Vec<CurEntry>, String> {
+    if !is_cur_header(data) {
+        return Err("Not a .CUR file".to_string());
+    }
+    let count = read_u16(data, 4) as usize;
+    if count == 0 {
+        return Err(".CUR file contains no images".to_string());
+    }
+    if data.len() < ICONDIR_LEN + count * ICONDIRENTRY_LEN {
+        return Err(".CUR directory is truncated".to_string());
+    }
+
+    (0..count)
+        .map(|index| {
+            let at = ICONDIR_LEN + index * ICONDIRENTRY_LEN;
+            let entry = CurEntry {
+                // A stored 0 means 256
+                width: match data[at] {
+                    0 => 256,
+                    w => u32::from(w),
+                },
+                height: match data[at + 1] {
+                    0 => 256,
+                    h => u32::from(h),
+                },
+                hotspot_x: read_u16(data, at + 4),
+                hotspot_y: read_u16(data, at + 6),
+                size: read_u32(data, at + 8) as usize,
+                offset: read_u32(data, at + 12) as usize,
+            };
+            if entry.size == 0 || entry.offset.saturating_add(entry.size) > data.len() {
+                return Err(format!(".CUR image {index} points outside the file"));
+            }
+            Ok(entry)
+        })
+        .collect()
+}
+
+/// The smallest frame at least `size` wide (downscaling keeps detail), else the largest.
+fn best_entry(entries: &[CurEntry], size: u32) -> CurEntry {
+    entries
+        .iter()
+        .filter(|entry| entry.width.max(entry.height) >= size)
+        .min_by_key(|entry| entry.width.max(entry.height))
+        .or_else(|| {
+            entries
+                .iter()
+                .max_by_key(|entry| entry.width.max(entry.height))
+        })
+        .copied()
+        .expect("parse_entries never returns an empty list")
+}
+
+/// Decode one entry by presenting it to the `image` crate as a single-image .ICO,
+/// which handles both PNG and DIB payloads.
+fn decode_entry(data: &[u8], entry: &CurEntry) -> Result<RgbaFrame, String> {
+    let payload = &data[entry.offset..entry.offset + entry.size];
+    let mut ico = Vec::with_capacity(ICONDIR_LEN + ICONDIRENTRY_LEN + payload.len());
+    ico.extend_from_slice(&0u16.to_le_bytes());
+    ico.extend_from_slice(&ICON_TYPE.to_le_bytes());
+    ico.extend_from_slice(&1u16.to_le_bytes());
+    // 256 is stored as 0; entry sizes come from single bytes, so nothing else is lost
+    #[allow(clippy::cast_possible_truncation)]
+    ico.extend_from_slice(&[(entry.width % 256) as u8, (entry.height % 256) as u8, 0, 0]);
+    ico.extend_from_slice(&1u16.to_le_bytes()); // planes
+    ico.extend_from_slice(&32u16.to_le_bytes()); // bit count
+    ico.extend_from_slice(&u32_len(entry.size)?.to_le_bytes());
+    ico.extend_from_slice(&u32_len(ICONDIR_LEN + ICONDIRENTRY_LEN)?.to_le_bytes());
+    ico.extend_from_slice(payload);
+
+    image::load_from_memory_with_format(&ico, image::ImageFormat::Ico)
+        .map(|img| img.to_rgba8())
+        .map_err(|e| format!("Failed to decode .CUR image: {e}"))
+}
+
+/// Decode the frame of `data` best suited to a `size` x `size` output.
+///
+/// # Errors
+/// When `data` is not a valid .CUR file or the chosen frame cannot be decoded
+pub fn read_cur_bytes(data: &[u8], size: u32) -> Result<CurImage, String> {
+    let entries = parse_entries(data)?;
+    let entry = best_entry(&entries, size);
+    let image = decode_entry(data, &entry)?;
+    Ok(CurImage {
+        image,
+        hotspot_x: entry.hotspot_x,
+        hotspot_y: entry.hotspot_y,
+    })
+}
+
+/// [`read_cur_bytes`] for a file on disk.
+///
+/// # Errors
+/// When the file cannot be read, or as [`read_cur_bytes`]
+pub fn read_cur_file(path: &str, size: u32) -> Result<CurImage, String> {
+    let data = std::fs::read(path).map_err(|e| format!("Failed to read .CUR file: {e}"))?;
+    read_cur_bytes(&data, size)
+}
+
+/// Dimensions of the largest frame and the number of frames.
+///
+/// # Errors
+/// When `data` is not a valid .CUR file
+pub fn cur_dimensions(data: &[u8]) -> Result<(u32, u32, usize), String> {
+    let entries = parse_entries(data)?;
+    let largest = best_entry(&entries, u32::MAX);
+    Ok((largest.width, largest.height, entries.len()))
+}
+
+/// Hotspot of the largest frame, the one [`cur_dimensions`] reports.
+///
+/// # Errors
+/// When `data` is not a valid .CUR file
+pub fn cur_hotspot(data: &[u8]) -> Result<(u16, u16), String> {
+    let entries = parse_entries(data)?;
+    let largest = best_entry(&entries, u32::MAX);
+    Ok((largest.hotspot_x, largest.hotspot_y))
+}
+
+/// Where a hotspot on a `source_width` x `source_height` image lands after placing the image
+/// with `params`, clamped to the canvas.
+#[must_use]
+pub fn map_hotspot(
+    hotspot: (u16, u16),
+    source_width: u32,
+    source_height: u32,
+    params: RenderParams,
+) -> (u16, u16) {
+    let (scaled_width, scaled_height, x, y) = raster_handler::placement(
+        source_width,
+        source_height,
+        params.size,
+        params.scale,
+        params.offset_x,
+        params.offset_y,
+    );
+    let map = |point: u16, source: u32, scaled: u32, origin: i32| -> u16 {
+        if source == 0 {
+            return 0;
+        }
+        let scaled_point = (f64::from(point) * f64::from(scaled) / f64::from(source)).round();
+        let max = i64::from(params.size.saturating_sub(1));
+        // Rounded, and clamped to the canvas, which is at most 256 pixels wide
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let mapped = (i64::from(origin) + scaled_point as i64).clamp(0, max) as u16;
+        mapped
+    };
+    (
+        map(hotspot.0, source_width, scaled_width, x),
+        map(hotspot.1, source_height, scaled_height, y),
+    )
+}
+
+/// Render the best frame of a .CUR for `params` and return it with its mapped hotspot.
+#[must_use]
+pub fn render_cur(cur: &CurImage, params: RenderParams) -> (RgbaFrame, (u16, u16)) {
+    let (width, height) = cur.image.dimensions();
+    let frame = raster_handler::compose_raster_image(
+        &cur.image,
+        params.size,
+        params.scale,
+        params.offset_x,
+        params.offset_y,
+    );
+    let hotspot = map_hotspot((cur.hotspot_x, cur.hotspot_y), width, height, params);
+    (frame, hotspot)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+
+    fn params(size: u32, scale: f32) -> RenderParams {
+        RenderParams {
+            size,
+            scale,
+            offset_x: 0,
+            offset_y: 0,
+        }
+    }
+
+    /// Two-frame cursor: 32px with hotspot (4, 6) and 64px with hotspot (8, 12).
+    fn two_frame_cur() -> Vec<u8> {
+        fixtures::multi_cur(&[(32, (4, 6)), (64, (8, 12))])
+    }
+
+    #[test]
+    fn picks_smallest_frame_that_covers_target() {
+        let data = two_frame_cur();
+        let cur = read_cur_bytes(&data, 48).unwrap();
+        assert_eq!(cur.image.dimensions(), (64, 64));
+        assert_eq!((cur.hotspot_x, cur.hotspot_y), (8, 12));
+
+        let cur = read_cur_bytes(&data, 32).unwrap();
+        assert_eq!(cur.image.dimensions(), (32, 32));
+        assert_eq!((cur.hotspot_x, cur.hotspot_y), (4, 6));
+
+        // Nothing big enough: fall back to the largest
+        let cur = read_cur_bytes(&data, 128).unwrap();
+        assert_eq!(cur.image.dimensions(), (64, 64));
+        assert_eq!(cur_dimensions(&data).unwrap(), (64, 64, 2));
+        assert_eq!(cur_hotspot(&data).unwrap(), (8, 12));
+    }
+
+    #[test]
+    fn hotspot_scales_with_the_image() {
+        assert_eq!(map_hotspot((8, 12), 64, 64, params(32, 0.5)), (4, 6));
+        assert_eq!(map_hotspot((8, 12), 64, 64, params(128, 2.0)), (16, 24));
+        // Centered on a larger canvas at 1:1
+        assert_eq!(map_hotspot((0, 0), 32, 32, params(64, 1.0)), (16, 16));
+        // Never outside the canvas
+        assert_eq!(map_hotspot((60, 60), 64, 64, params(32, 1.0)), (31, 31));
+    }
+
+    #[test]
+    fn rejects_icons_and_truncated_files() {
+        let mut data = two_frame_cur();
+        data[2] = 1;
+        assert!(read_cur_bytes(&data, 32).is_err());
+        assert!(read_cur_bytes(&[0, 0, 2, 0, 1, 0], 32).is_err());
+    }
+}
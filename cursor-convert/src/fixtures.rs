@@ -0,0 +1,476 @@
+//! Synthetic .CUR and .ANI files for tests
+//!
+//! Built with the `test-fixtures` feature (and always for this crate's own tests) so the app's
+//! property and fuzz tests share one source of cursor bytes instead of assembling headers by
+//! hand. Besides valid cursors of any size it builds the awkward cases parsers must survive:
+//! the 256px limit, hotspots outside the image, animations without frames, and files that are
+//! cut short or point past their end.
+//!
+//! Frames use [`PngEncoding::Deterministic`], so a fixture's bytes only change when its
+//! parameters do.
+
+use crate::cur_generator::{generate_cur_data_with, PngEncoding, MAX_CURSOR_SIZE};
+use crate::format_handler::RgbaFrame;
+use image::Rgba;
+
+const ICONDIR_LEN: usize = 6;
+const ICONDIRENTRY_LEN: usize = 16;
+/// `anih` flag: frames are icon/cursor data
+const AF_ICON: u32 = 0x1;
+/// `anih` flag: the file has a `seq ` chunk
+const AF_SEQUENCE: u32 = 0x2;
+
+/// Opaque square of one grey shade; different shades tell frames apart.
+#[must_use]
+pub fn frame(size: u32, shade: u8) -> RgbaFrame {
+    RgbaFrame::from_pixel(size, size, Rgba([shade, shade, shade, 255]))
+}
+
+/// Single-image .CUR of a `size` x `size` square (1 to [`MAX_CURSOR_SIZE`]).
+///
+/// Like [`crate::generate_cur_data`], a hotspot outside the image is clamped to its last
+/// pixel; see [`cur_with_raw_hotspot`] to keep it.
+///
+/// # Panics
+/// If `size` is 0 or above [`MAX_CURSOR_SIZE`].
+#[must_use]
+pub fn cur(size: u32, hotspot: (u16, u16)) -> Vec<u8> {
+    assert!(
+        (1..=MAX_CURSOR_SIZE).contains(&size),
+        "fixture size {size} out of range"
+    );
+    generate_cur_data_with(
+        &frame(size, 0x80),
+        hotspot.0,
+        hotspot.1,
+        PngEncoding::Deterministic,
+    )
+    .expect("fixture cursor encodes")
+}
+
+/// Single-image .CUR whose directory stores `hotspot` unchanged, even outside the image, as
+/// files from other tools sometimes do.
+#[must_use]
+pub fn cur_with_raw_hotspot(size: u32, hotspot: (u16, u16)) -> Vec<u8> {
+    let mut data = cur(size, (0, 0));
+    let at = ICONDIR_LEN;
+    data[at + 4..at + 6].copy_from_slice(&hotspot.0.to_le_bytes());
+    data[at + 6..at + 8].copy_from_slice(&hotspot.1.to_le_bytes());
+    data
+}
+
+/// .CUR holding one image per `(size, hotspot)`, in the given order.
+///
+/// # Panics
+/// If `images` is empty or a size is out of range.
+#[must_use]
+pub fn multi_cur(images: &[(u32, (u16, u16))]) -> Vec<u8> {
+    assert!(
+        !images.is_empty(),
+        "a .CUR fixture needs at least one image"
+    );
+    let singles: Vec<Vec<u8>> = images
+        .iter()
+        .map(|&(size, hotspot)| cur(size, hotspot))
+        .collect();
+
+    let mut data = Vec::new();
+    data.extend_from_slice(&0u16.to_le_bytes());
+    data.extend_from_slice(&2u16.to_le_bytes());
+    let count = u16::try_from(images.len()).expect("too many images");
+    data.extend_from_slice(&count.to_le_bytes());
+
+    let mut offset = ICONDIR_LEN + images.len() * ICONDIRENTRY_LEN;
+    for single in &singles {
+        let mut entry = single[ICONDIR_LEN..ICONDIR_LEN + ICONDIRENTRY_LEN].to_vec();
+        entry[12..16].copy_from_slice(&len_u32(offset).to_le_bytes());
+        data.extend_from_slice(&entry);
+        offset += single.len() - ICONDIR_LEN - ICONDIRENTRY_LEN;
+    }
+    for single in &singles {
+        data.extend_from_slice(&single[ICONDIR_LEN + ICONDIRENTRY_LEN..]);
+    }
+    data
+}
+
+/// Valid but unusual .CUR files, labelled for assertion messages.
+#[must_use]
+pub fn edge_case_curs() -> Vec<(&'static str, Vec<u8>)> {
+    vec![
+        ("1px", cur(1, (0, 0))),
+        ("max size", cur(MAX_CURSOR_SIZE, (255, 255))),
+        ("hotspot on the far corner", cur(32, (31, 31))),
+        (
+            "hotspot outside the image",
+            cur_with_raw_hotspot(32, (500, 40)),
+        ),
+        (
+            "several sizes, largest first",
+            multi_cur(&[(MAX_CURSOR_SIZE, (128, 128)), (48, (24, 24)), (16, (8, 8))]),
+        ),
+    ]
+}
+
+/// Ways a .CUR fixture can be broken.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CurDefect {
+    /// Cut off halfway through the image data
+    Truncated,
+    /// Directory claims no images
+    NoImages,
+    /// Icon (type 1) directory instead of a cursor
+    IconType,
+    /// Image offset points past the end of the file
+    OffsetPastEnd,
+    /// Image entry with a size of 0
+    EmptyImage,
+}
+
+impl CurDefect {
+    pub const ALL: [Self; 5] = [
+        Self::Truncated,
+        Self::NoImages,
+        Self::IconType,
+        Self::OffsetPastEnd,
+        Self::EmptyImage,
+    ];
+}
+
+/// A 32px .CUR broken in the given way.
+#[must_use]
+pub fn broken_cur(defect: CurDefect) -> Vec<u8> {
+    let mut data = cur(32, (4, 4));
+    let entry = ICONDIR_LEN;
+    match defect {
+        CurDefect::Truncated => {
+            let image_len = data.len() - ICONDIR_LEN - ICONDIRENTRY_LEN;
+            data.truncate(ICONDIR_LEN + ICONDIRENTRY_LEN + image_len / 2);
+        }
+        CurDefect::NoImages => data[4..6].copy_from_slice(&0u16.to_le_bytes()),
+        CurDefect::IconType => data[2..4].copy_from_slice(&1u16.to_le_bytes()),
+        CurDefect::OffsetPastEnd => {
+            let past_end = len_u32(data.len());
+            data[entry + 12..entry + 16].copy_from_slice(&past_end.to_le_bytes());
+        }
+        CurDefect::EmptyImage => data[entry + 8..entry + 12].copy_from_slice(&0u32.to_le_bytes()),
+    }
+    data
+}
+
+/// Fixtures are small; a length that does not fit a header field is a bug in the test.
+fn len_u32(len: usize) -> u32 {
+    u32::try_from(len).expect("fixture length fits in 32 bits")
+}
+
+fn push_chunk(out: &mut Vec<u8>, id: &[u8], body: &[u8]) {
+    out.extend_from_slice(id);
+    out.extend_from_slice(&len_u32(body.len()).to_le_bytes());
+    out.extend_from_slice(body);
+    if !body.len().is_multiple_of(2) {
+        out.push(0); // RIFF chunks are word aligned
+    }
+}
+
+fn u32_list(values: &[u32]) -> Vec<u8> {
+    values.iter().flat_map(|v| v.to_le_bytes()).collect()
+}
+
+/// Builds RIFF `ACON` files chunk by chunk.
+///
+/// Frames are stored as given, so they can be real cursors ([`AniBuilder::cur_frames`]) or
+/// arbitrary bytes for parsers that only split chunks.
+#[derive(Debug, Clone)]
+pub struct AniBuilder {
+    frames: Vec<Vec<u8>>,
+    header: bool,
+    default_rate: u32,
+    rates: Vec<u32>,
+    sequence: Vec<u32>,
+    info: Vec<(&'static [u8; 4], String)>,
+}
+
+impl Default for AniBuilder {
+    fn default() -> Self {
+        Self {
+            frames: Vec::new(),
+            header: true,
+            default_rate: 6,
+            rates: Vec::new(),
+            sequence: Vec::new(),
+            info: Vec::new(),
+        }
+    }
+}
+
+impl AniBuilder {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append one `icon` chunk holding `bytes`.
+    #[must_use]
+    pub fn icon(mut self, bytes: impl Into<Vec<u8>>) -> Self {
+        self.frames.push(bytes.into());
+        self
+    }
+
+    /// Append `count` .CUR frames of `size` px, each a different shade.
+    ///
+    /// # Panics
+    /// When `size` is 0 or larger than [`MAX_CURSOR_SIZE`]
+    #[must_use]
+    pub fn cur_frames(mut self, count: usize, size: u32) -> Self {
+        for index in 0..count {
+            let shade = u8::try_from(index * 40 % 256).unwrap_or_default();
+            let icon =
+                generate_cur_data_with(&frame(size, shade), 0, 0, PngEncoding::Deterministic)
+                    .expect("fixture frame encodes");
+            self.frames.push(icon);
+        }
+        self
+    }
+
+    /// `anih` display rate in jiffies (1/60 s).
+    #[must_use]
+    pub const fn default_rate(mut self, jiffies: u32) -> Self {
+        self.default_rate = jiffies;
+        self
+    }
+
+    /// Per-step rates, written as a `rate` chunk.
+    #[must_use]
+    pub fn rates(mut self, jiffies: &[u32]) -> Self {
+        self.rates = jiffies.to_vec();
+        self
+    }
+
+    /// Playback order, written as a `seq ` chunk.
+    #[must_use]
+    pub fn sequence(mut self, frame_indices: &[u32]) -> Self {
+        self.sequence = frame_indices.to_vec();
+        self
+    }
+
+    /// Title, written as `INAM` in a `LIST INFO` chunk.
+    #[must_use]
+    pub fn title(mut self, title: &str) -> Self {
+        self.info.push((b"INAM", title.to_string()));
+        self
+    }
+
+    /// Author, written as `IART` in a `LIST INFO` chunk.
+    #[must_use]
+    pub fn author(mut self, author: &str) -> Self {
+        self.info.push((b"IART", author.to_string()));
+        self
+    }
+
+    /// Leave out the `anih` header, as some hand-made files do.
+    #[must_use]
+    pub const fn without_header(mut self) -> Self {
+        self.header = false;
+        self
+    }
+
+    #[must_use]
+    pub fn build(&self) -> Vec<u8> {
+        let mut body = b"ACON".to_vec();
+        if !self.info.is_empty() {
+            let mut list = b"INFO".to_vec();
+            for (id, text) in &self.info {
+                let mut bytes = text.as_bytes().to_vec();
+                bytes.push(0);
+                push_chunk(&mut list, *id, &bytes);
+            }
+            push_chunk(&mut body, b"LIST", &list);
+        }
+        if self.header {
+            let steps = if self.sequence.is_empty() {
+                self.frames.len()
+            } else {
+                self.sequence.len()
+            };
+            let flags = if self.sequence.is_empty() {
+                AF_ICON
+            } else {
+                AF_ICON | AF_SEQUENCE
+            };
+            // cbSize, nFrames, nSteps, cx, cy, cBitCount, cPlanes, JifRate, flags
+            let anih = u32_list(&[
+                36,
+                len_u32(self.frames.len()),
+                len_u32(steps),
+                0,
+                0,
+                0,
+                0,
+                self.default_rate,
+                flags,
+            ]);
+            push_chunk(&mut body, b"anih", &anih);
+        }
+        if !self.rates.is_empty() {
+            push_chunk(&mut body, b"rate", &u32_list(&self.rates));
+        }
+        if !self.sequence.is_empty() {
+            push_chunk(&mut body, b"seq ", &u32_list(&self.sequence));
+        }
+
+        let mut list = b"fram".to_vec();
+        for icon in &self.frames {
+            push_chunk(&mut list, b"icon", icon);
+        }
+        push_chunk(&mut body, b"LIST", &list);
+
+        let mut data = Vec::with_capacity(body.len() + 8);
+        push_chunk(&mut data, b"RIFF", &body);
+        data
+    }
+}
+
+/// Valid .ANI with `frame_count` .CUR frames of `size` px.
+#[must_use]
+pub fn ani(frame_count: usize, size: u32) -> Vec<u8> {
+    AniBuilder::new().cur_frames(frame_count, size).build()
+}
+
+/// Ways an .ANI fixture can be broken.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AniDefect {
+    /// Cut off halfway through the frames
+    Truncated,
+    /// `RIFF` form other than `ACON`
+    WrongForm,
+    /// Header and an empty frame list
+    NoFrames,
+    /// Frame list claims more bytes than the file holds
+    ListPastEnd,
+}
+
+impl AniDefect {
+    pub const ALL: [Self; 4] = [
+        Self::Truncated,
+        Self::WrongForm,
+        Self::NoFrames,
+        Self::ListPastEnd,
+    ];
+}
+
+/// A three-frame 32px .ANI broken in the given way.
+///
+/// # Panics
+/// Never; the frame list this looks for is always written
+#[must_use]
+pub fn broken_ani(defect: AniDefect) -> Vec<u8> {
+    if defect == AniDefect::NoFrames {
+        return AniBuilder::new().build();
+    }
+    let mut data = ani(3, 32);
+    match defect {
+        AniDefect::Truncated => data.truncate(data.len() / 2),
+        AniDefect::WrongForm => data[8..12].copy_from_slice(b"WAVE"),
+        AniDefect::ListPastEnd => {
+            let list = data
+                .windows(4)
+                .position(|w| w == b"LIST")
+                .expect("fixture has a frame list");
+            data[list + 4..list + 8].copy_from_slice(&u32::MAX.to_le_bytes());
+        }
+        AniDefect::NoFrames => unreachable!(),
+    }
+    data
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cur_reader::{cur_dimensions, read_cur_bytes};
+
+    fn chunk<'a>(data: &'a [u8], id: &[u8]) -> Option<&'a [u8]> {
+        let pos = data.windows(4).position(|w| w == id)?;
+        let size = u32::from_le_bytes(data[pos + 4..pos + 8].try_into().ok()?) as usize;
+        data.get(pos + 8..pos + 8 + size)
+    }
+
+    #[test]
+    fn edge_case_curs_read_back() {
+        for (label, data) in edge_case_curs() {
+            assert!(read_cur_bytes(&data, 32).is_ok(), "{label}");
+        }
+
+        let max = read_cur_bytes(&cur(MAX_CURSOR_SIZE, (255, 255)), 256).unwrap();
+        assert_eq!(max.image.dimensions(), (256, 256));
+        assert_eq!((max.hotspot_x, max.hotspot_y), (255, 255));
+
+        let outside = read_cur_bytes(&cur_with_raw_hotspot(32, (500, 40)), 32).unwrap();
+        assert_eq!((outside.hotspot_x, outside.hotspot_y), (500, 40));
+    }
+
+    #[test]
+    fn multi_cur_keeps_every_image() {
+        let data = multi_cur(&[(16, (1, 2)), (64, (8, 12))]);
+        assert_eq!(cur_dimensions(&data).unwrap(), (64, 64, 2));
+        let small = read_cur_bytes(&data, 16).unwrap();
+        assert_eq!((small.hotspot_x, small.hotspot_y), (1, 2));
+    }
+
+    #[test]
+    fn broken_curs_are_rejected() {
+        for defect in CurDefect::ALL {
+            assert!(
+                read_cur_bytes(&broken_cur(defect), 32).is_err(),
+                "{defect:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn ani_builder_writes_requested_chunks() {
+        let data = AniBuilder::new()
+            .cur_frames(2, 16)
+            .default_rate(5)
+            .rates(&[3, 6, 9])
+            .sequence(&[0, 1, 0])
+            .build();
+        assert_eq!(&data[8..12], b"ACON");
+        assert_eq!(
+            u32::from_le_bytes(data[4..8].try_into().unwrap()) as usize,
+            data.len() - 8
+        );
+
+        let anih = chunk(&data, b"anih").unwrap();
+        let field = |i: usize| u32::from_le_bytes(anih[i * 4..i * 4 + 4].try_into().unwrap());
+        assert_eq!((field(1), field(2), field(7)), (2, 3, 5));
+        assert_eq!(field(8), AF_ICON | AF_SEQUENCE);
+        assert_eq!(chunk(&data, b"rate").unwrap().len(), 12);
+        assert_eq!(chunk(&data, b"seq ").unwrap().len(), 12);
+
+        let icon = chunk(&data, b"icon").unwrap();
+        assert!(read_cur_bytes(icon, 16).is_ok());
+        assert_eq!(data.windows(4).filter(|w| w == b"icon").count(), 2);
+
+        let bare = AniBuilder::new()
+            .icon(vec![7u8; 3])
+            .without_header()
+            .build();
+        assert!(chunk(&bare, b"anih").is_none());
+        // Odd-sized chunks are padded
+        assert_eq!(bare.len() % 2, 0);
+    }
+
+    #[test]
+    fn broken_anis_differ_from_the_valid_one() {
+        let valid = ani(3, 32);
+        for defect in AniDefect::ALL {
+            assert_ne!(broken_ani(defect), valid, "{defect:?}");
+        }
+        assert_eq!(
+            broken_ani(AniDefect::NoFrames)
+                .windows(4)
+                .filter(|w| w == b"icon")
+                .count(),
+            0
+        );
+    }
+}
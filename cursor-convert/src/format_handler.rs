@@ -0,0 +1,513 @@
+//! Pluggable input formats for cursor conversion
+//!
+//! Each supported input format implements [`FormatHandler`]. `convert_to_cur` asks the
+//! registry for a handler instead of matching on extensions itself, so adding a format is a
+//! matter of writing a handler and listing it in [`handlers`]. Optional formats are compiled
+//! in behind `format-*` cargo features.
+
+use image::{ImageBuffer, Rgba};
+use std::io::Read;
+use std::path::Path;
+
+use super::preview_source::PreviewSource;
+use super::{cur_reader, raster_handler, svg_handler};
+
+pub type RgbaFrame = ImageBuffer<Rgba<u8>, Vec<u8>>;
+
+/// How the source should be placed on the square cursor canvas.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RenderParams {
+    /// Target canvas size in pixels (already clamped to `MAX_CURSOR_SIZE`)
+    pub size: u32,
+    /// Scale factor to apply (1.0 = 100%)
+    pub scale: f32,
+    /// Horizontal offset in pixels (positive = right)
+    pub offset_x: i32,
+    /// Vertical offset in pixels (positive = down)
+    pub offset_y: i32,
+}
+
+/// Basic facts about a source file, read without rendering it onto a canvas.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormatMetadata {
+    pub format: &'static str,
+    pub width: u32,
+    pub height: u32,
+    pub frame_count: usize,
+}
+
+pub trait FormatHandler: Send + Sync {
+    /// Short identifier used in logs and metadata (e.g. "svg", "raster")
+    fn name(&self) -> &'static str;
+
+    /// Lowercase file extensions this handler claims
+    fn extensions(&self) -> &'static [&'static str];
+
+    /// Content sniffing for files with a missing or misleading extension.
+    ///
+    /// `header` holds the first [`SNIFF_LEN`] bytes of the file (fewer if the file is shorter).
+    fn detect(&self, header: &[u8]) -> bool;
+
+    /// Render the file to one or more RGBA frames of `params.size` x `params.size`.
+    ///
+    /// # Errors
+    /// When the file cannot be read or decoded
+    fn decode(&self, path: &str, params: RenderParams) -> Result<Vec<RgbaFrame>, String>;
+
+    /// Read the source dimensions and frame count.
+    ///
+    /// # Errors
+    /// When the file cannot be read or its header is not valid
+    fn metadata(&self, path: &str) -> Result<FormatMetadata, String>;
+
+    /// Decode the file once for repeated re-rendering (see [`PreviewSource`]).
+    ///
+    /// The default renders the first frame at `MAX_CURSOR_SIZE` and rescales that bitmap.
+    ///
+    /// # Errors
+    /// When the file cannot be read or decoded
+    fn open_preview(&self, path: &str) -> Result<PreviewSource, String> {
+        let params = RenderParams {
+            size: crate::MAX_CURSOR_SIZE,
+            scale: 1.0,
+            offset_x: 0,
+            offset_y: 0,
+        };
+        self.decode(path, params)?
+            .into_iter()
+            .next()
+            .map(PreviewSource::Raster)
+            .ok_or_else(|| format!("{} handler produced no frames", self.name()))
+    }
+
+    fn handles_extension(&self, extension: &str) -> bool {
+        self.extensions()
+            .iter()
+            .any(|candidate| candidate.eq_ignore_ascii_case(extension))
+    }
+}
+
+/// Number of leading bytes passed to [`FormatHandler::detect`].
+pub const SNIFF_LEN: usize = 512;
+
+pub struct SvgFormatHandler;
+
+impl FormatHandler for SvgFormatHandler {
+    fn name(&self) -> &'static str {
+        "svg"
+    }
+
+    fn extensions(&self) -> &'static [&'static str] {
+        &["svg"]
+    }
+
+    fn detect(&self, header: &[u8]) -> bool {
+        let text = String::from_utf8_lossy(header);
+        text.contains("<svg")
+    }
+
+    fn decode(&self, path: &str, params: RenderParams) -> Result<Vec<RgbaFrame>, String> {
+        svg_handler::load_svg(
+            path,
+            params.size,
+            params.scale,
+            params.offset_x,
+            params.offset_y,
+        )
+        .map(|frame| vec![frame])
+    }
+
+    fn metadata(&self, path: &str) -> Result<FormatMetadata, String> {
+        let data = std::fs::read(path).map_err(|e| format!("Failed to read SVG file: {e}"))?;
+        // Same options as rendering so text metrics match; stripped resources are reported
+        // when the file is actually converted
+        let parsed = svg_handler::parse_svg_with_policy(&data, &svg_handler::resource_policy())?;
+        let (width, height) = svg_handler::tree_dimensions(&parsed.tree);
+        Ok(FormatMetadata {
+            format: self.name(),
+            width,
+            height,
+            frame_count: 1,
+        })
+    }
+
+    fn open_preview(&self, path: &str) -> Result<PreviewSource, String> {
+        let data = std::fs::read(path).map_err(|e| format!("Failed to read SVG file: {e}"))?;
+        if data.is_empty() {
+            return Err("SVG file is empty".to_string());
+        }
+        svg_handler::parse_svg(&data).map(|tree| PreviewSource::Svg(Box::new(tree)))
+    }
+}
+
+pub struct RasterFormatHandler;
+
+impl FormatHandler for RasterFormatHandler {
+    fn name(&self) -> &'static str {
+        "raster"
+    }
+
+    fn extensions(&self) -> &'static [&'static str] {
+        &["png", "ico", "bmp", "jpg", "jpeg", "gif"]
+    }
+
+    fn detect(&self, header: &[u8]) -> bool {
+        matches!(
+            image::guess_format(header),
+            Ok(image::ImageFormat::Png
+                | image::ImageFormat::Ico
+                | image::ImageFormat::Bmp
+                | image::ImageFormat::Jpeg
+                | image::ImageFormat::Gif)
+        )
+    }
+
+    /// Static cursors take the first frame of an animated GIF or APNG; see
+    /// [`raster_handler::load_raster_frames`] for all of them.
+    fn decode(&self, path: &str, params: RenderParams) -> Result<Vec<RgbaFrame>, String> {
+        raster_handler::load_raster_image(
+            path,
+            params.size,
+            params.scale,
+            params.offset_x,
+            params.offset_y,
+        )
+        .map(|frame| vec![frame])
+    }
+
+    fn metadata(&self, path: &str) -> Result<FormatMetadata, String> {
+        let mut metadata = raster_metadata(self.name(), path)?;
+        let data = std::fs::read(path).map_err(|e| format!("Failed to read image: {e}"))?;
+        if let Some(frame_count) = raster_handler::animation_frame_count(&data) {
+            metadata.frame_count = frame_count;
+        }
+        Ok(metadata)
+    }
+
+    fn open_preview(&self, path: &str) -> Result<PreviewSource, String> {
+        open_raster_preview(path)
+    }
+}
+
+/// Existing .CUR files, so a cursor can be re-converted at another size. Renders the frame
+/// best suited to the target size; see [`cur_reader`] for keeping its hotspot.
+pub struct CurFormatHandler;
+
+impl FormatHandler for CurFormatHandler {
+    fn name(&self) -> &'static str {
+        "cur"
+    }
+
+    fn extensions(&self) -> &'static [&'static str] {
+        &["cur"]
+    }
+
+    fn detect(&self, header: &[u8]) -> bool {
+        cur_reader::is_cur_header(header)
+    }
+
+    fn decode(&self, path: &str, params: RenderParams) -> Result<Vec<RgbaFrame>, String> {
+        let cur = cur_reader::read_cur_file(path, params.size)?;
+        Ok(vec![cur_reader::render_cur(&cur, params).0])
+    }
+
+    fn metadata(&self, path: &str) -> Result<FormatMetadata, String> {
+        let data = std::fs::read(path).map_err(|e| format!("Failed to read .CUR file: {e}"))?;
+        let (width, height, frame_count) = cur_reader::cur_dimensions(&data)?;
+        Ok(FormatMetadata {
+            format: self.name(),
+            width,
+            height,
+            frame_count,
+        })
+    }
+
+    fn open_preview(&self, path: &str) -> Result<PreviewSource, String> {
+        let cur = cur_reader::read_cur_file(path, crate::MAX_CURSOR_SIZE)?;
+        Ok(PreviewSource::Raster(cur.image))
+    }
+}
+
+/// TIFF input, decoded through the `image` crate. Enabled with the `format-tiff` feature.
+#[cfg(feature = "format-tiff")]
+pub struct TiffFormatHandler;
+
+#[cfg(feature = "format-tiff")]
+impl FormatHandler for TiffFormatHandler {
+    fn name(&self) -> &'static str {
+        "tiff"
+    }
+
+    fn extensions(&self) -> &'static [&'static str] {
+        &["tif", "tiff"]
+    }
+
+    fn detect(&self, header: &[u8]) -> bool {
+        matches!(image::guess_format(header), Ok(image::ImageFormat::Tiff))
+    }
+
+    fn decode(&self, path: &str, params: RenderParams) -> Result<Vec<RgbaFrame>, String> {
+        raster_handler::load_raster_image(
+            path,
+            params.size,
+            params.scale,
+            params.offset_x,
+            params.offset_y,
+        )
+        .map(|frame| vec![frame])
+    }
+
+    fn metadata(&self, path: &str) -> Result<FormatMetadata, String> {
+        raster_metadata(self.name(), path)
+    }
+
+    fn open_preview(&self, path: &str) -> Result<PreviewSource, String> {
+        open_raster_preview(path)
+    }
+}
+
+fn open_raster_preview(path: &str) -> Result<PreviewSource, String> {
+    raster_handler::decode_raster_image(path).map(PreviewSource::Raster)
+}
+
+/// Reads dimensions from the image header, detecting the format from content like
+/// [`FormatHandler::detect`] so misnamed files still work.
+fn raster_metadata(format: &'static str, path: &str) -> Result<FormatMetadata, String> {
+    let (width, height) = image::ImageReader::open(path)
+        .map_err(|e| format!("Failed to read image: {e}"))?
+        .with_guessed_format()
+        .map_err(|e| format!("Failed to read image: {e}"))?
+        .into_dimensions()
+        .map_err(|e| format!("Failed to read image: {e}"))?;
+    Ok(FormatMetadata {
+        format,
+        width,
+        height,
+        frame_count: 1,
+    })
+}
+
+static SVG_HANDLER: SvgFormatHandler = SvgFormatHandler;
+static RASTER_HANDLER: RasterFormatHandler = RasterFormatHandler;
+static CUR_HANDLER: CurFormatHandler = CurFormatHandler;
+#[cfg(feature = "format-tiff")]
+static TIFF_HANDLER: TiffFormatHandler = TiffFormatHandler;
+
+/// All handlers compiled into this build, in lookup order.
+#[must_use]
+pub fn handlers() -> Vec<&'static dyn FormatHandler> {
+    #[allow(unused_mut)]
+    let mut list: Vec<&'static dyn FormatHandler> = vec![&SVG_HANDLER, &RASTER_HANDLER, &CUR_HANDLER];
+    #[cfg(feature = "format-tiff")]
+    list.push(&TIFF_HANDLER);
+    list
+}
+
+/// Every input extension accepted by the registered handlers.
+#[must_use]
+pub fn supported_extensions() -> Vec<&'static str> {
+    handlers()
+        .into_iter()
+        .flat_map(|handler| handler.extensions().iter().copied())
+        .collect()
+}
+
+#[must_use]
+pub fn handler_for_extension(extension: &str) -> Option<&'static dyn FormatHandler> {
+    handlers()
+        .into_iter()
+        .find(|handler| handler.handles_extension(extension))
+}
+
+/// Find the handler for `path`, preferring the extension and falling back to content sniffing.
+///
+/// # Errors
+/// When neither the extension nor the content matches a registered handler
+pub fn handler_for_path(path: &str) -> Result<&'static dyn FormatHandler, String> {
+    let extension = Path::new(path)
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(str::to_lowercase);
+
+    if let Some(handler) = extension.as_deref().and_then(handler_for_extension) {
+        return Ok(handler);
+    }
+
+    if let Some(handler) = detect_handler(path) {
+        return Ok(handler);
+    }
+
+    Err(extension.map_or_else(
+        || "File has no extension and its format was not recognized".to_string(),
+        |ext| format!("Unsupported file type: {ext}"),
+    ))
+}
+
+/// Run `op` with the handler for `path`. When the extension's handler fails, the handler the
+/// content is detected as gets a second try, so a PNG saved as `.svg` still loads.
+///
+/// # Errors
+/// When no handler accepts the file, or the error from `op` with the extension's handler
+pub fn with_handler_for_path<T>(
+    path: &str,
+    mut op: impl FnMut(&'static dyn FormatHandler) -> Result<T, String>,
+) -> Result<T, String> {
+    let handler = handler_for_path(path)?;
+    let err = match op(handler) {
+        Ok(value) => return Ok(value),
+        Err(err) => err,
+    };
+    match detect_handler(path) {
+        Some(detected) if detected.name() != handler.name() => op(detected).map_err(|_| err),
+        _ => Err(err),
+    }
+}
+
+fn detect_handler(path: &str) -> Option<&'static dyn FormatHandler> {
+    let header = read_header(path).ok()?;
+    handlers()
+        .into_iter()
+        .find(|handler| handler.detect(&header))
+}
+
+fn read_header(path: &str) -> Result<Vec<u8>, String> {
+    let file = std::fs::File::open(path).map_err(|e| format!("Failed to open file: {e}"))?;
+    let mut header = Vec::with_capacity(SNIFF_LEN);
+    file.take(SNIFF_LEN as u64)
+        .read_to_end(&mut header)
+        .map_err(|e| format!("Failed to read file: {e}"))?;
+    Ok(header)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::ImageFormat;
+
+    #[test]
+    fn extension_lookup_uses_builtin_handlers() {
+        let name = |extension| handler_for_extension(extension).map(FormatHandler::name);
+        assert_eq!(name("SVG"), Some("svg"));
+        assert_eq!(name("jpeg"), Some("raster"));
+        assert!(handler_for_extension("txt").is_none());
+        assert!(supported_extensions().contains(&"png"));
+    }
+
+    #[test]
+    fn content_sniffing_handles_misnamed_files() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let path = temp.path().join("cursor.data");
+        ImageBuffer::from_pixel(4, 4, Rgba([1u8, 2, 3, 255]))
+            .save_with_format(&path, ImageFormat::Png)
+            .expect("save png");
+
+        let handler = handler_for_path(path.to_str().unwrap()).expect("sniffed");
+        assert_eq!(handler.name(), "raster");
+
+        let metadata = handler.metadata(path.to_str().unwrap()).expect("metadata");
+        assert_eq!((metadata.width, metadata.height), (4, 4));
+        assert_eq!(metadata.frame_count, 1);
+    }
+
+    #[test]
+    fn content_detection_rescues_a_misleading_extension() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let path = temp.path().join("arrow.svg");
+        ImageBuffer::from_pixel(6, 3, Rgba([1u8, 2, 3, 255]))
+            .save_with_format(&path, ImageFormat::Png)
+            .expect("save png");
+        let path = path.to_str().unwrap();
+
+        assert_eq!(handler_for_path(path).expect("by extension").name(), "svg");
+        let metadata = with_handler_for_path(path, |handler| handler.metadata(path))
+            .expect("falls back to the detected format");
+        assert_eq!(metadata.format, "raster");
+        assert_eq!((metadata.width, metadata.height), (6, 3));
+    }
+
+    #[test]
+    fn failures_keep_the_extension_handler_error() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let path = temp.path().join("broken.png");
+        std::fs::write(&path, b"<svg but not really").expect("write");
+        let path = path.to_str().unwrap();
+
+        let err = with_handler_for_path(path, |handler| handler.metadata(path))
+            .expect_err("neither handler can read it");
+        assert!(err.contains("Failed to read image"), "{err}");
+    }
+
+    #[cfg(feature = "format-tiff")]
+    #[test]
+    fn tiff_files_are_found_by_extension_and_content() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let path = temp.path().join("pointer.tif");
+        ImageBuffer::from_pixel(5, 7, Rgba([9u8, 8, 7, 255]))
+            .save_with_format(&path, ImageFormat::Tiff)
+            .expect("save tiff");
+        let renamed = temp.path().join("pointer.svg");
+        std::fs::copy(&path, &renamed).expect("copy");
+
+        for path in [path.to_str().unwrap(), renamed.to_str().unwrap()] {
+            let metadata = with_handler_for_path(path, |handler| handler.metadata(path))
+                .expect("tiff metadata");
+            assert_eq!(metadata.format, "tiff");
+            assert_eq!((metadata.width, metadata.height), (5, 7));
+        }
+        assert_eq!(
+            handler_for_extension("TIFF").map(FormatHandler::name),
+            Some("tiff")
+        );
+    }
+
+    #[test]
+    fn animated_gifs_use_the_first_frame() {
+        use image::codecs::gif::GifEncoder;
+        use image::{Delay, Frame};
+
+        let temp = tempfile::tempdir().expect("tempdir");
+        let path = temp.path().join("spin.gif");
+        {
+            let file = std::fs::File::create(&path).expect("create gif");
+            let mut encoder = GifEncoder::new(file);
+            for shade in [255u8, 0] {
+                let buffer = ImageBuffer::from_pixel(8, 8, Rgba([shade, 0, 0, 255]));
+                encoder
+                    .encode_frame(Frame::from_parts(
+                        buffer,
+                        0,
+                        0,
+                        Delay::from_numer_denom_ms(80, 1),
+                    ))
+                    .expect("encode frame");
+            }
+        }
+        let path = path.to_str().unwrap();
+
+        let handler = handler_for_path(path).expect("gif handler");
+        assert_eq!(handler.name(), "raster");
+        assert_eq!(handler.metadata(path).expect("metadata").frame_count, 2);
+
+        let params = RenderParams {
+            size: 8,
+            scale: 1.0,
+            offset_x: 0,
+            offset_y: 0,
+        };
+        let frames = handler.decode(path, params).expect("decode");
+        assert_eq!(frames.len(), 1);
+        assert_eq!(frames[0].get_pixel(4, 4), &Rgba([255, 0, 0, 255]));
+    }
+
+    #[test]
+    fn unknown_content_is_rejected() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let path = temp.path().join("notes.txt");
+        std::fs::write(&path, b"just text").expect("write");
+
+        let err = handler_for_path(path.to_str().unwrap())
+            .err()
+            .expect("should be rejected");
+        assert!(err.contains("Unsupported file type"));
+    }
+}
@@ -0,0 +1,589 @@
+//! cursor-convert - converts images to Windows .CUR cursor format
+//!
+//! Standalone library shared by the Cursor Changer app, its CLI and third-party tools.
+//! It has no Tauri or Windows dependencies. It provides functionality to:
+//! - Convert various image formats (SVG, PNG, ICO, BMP, JPG, GIF) to Windows .CUR cursor format
+//! - Handle SVG parsing and rendering with robust error handling, using bundled `DejaVu` fonts
+//!   for text and stripping external resources (see [`svg_handler::SvgResourcePolicy`])
+//! - Support raster image loading and high-quality resizing (Lanczos3)
+//! - Generate proper .CUR file format with hotspot coordinates
+//! - Wrap RGBA frames into animated .ANI cursors, from GIF/APNG animations or image lists
+//! - Re-size existing .CUR files while keeping their hotspot on the same pixel
+//! - Convert a whole folder at once, reporting each file's success or error
+//!
+//! # Quality Settings
+//!
+//! - Maximum resolution: 256x256 (Windows .CUR format limit)
+//! - Color depth: 32-bit RGBA (8-bit per channel with full alpha)
+//! - Format: PNG embedded in .CUR (lossless compression)
+//! - Resize filter: Lanczos3 (highest quality resampling)
+
+pub mod ani_generator;
+pub mod batch;
+pub mod binary_writer;
+pub mod cur_reader;
+pub mod cur_generator;
+#[cfg(any(test, feature = "test-fixtures"))]
+pub mod fixtures;
+pub mod format_handler;
+pub mod preview_source;
+pub mod raster_handler;
+pub mod svg_handler;
+
+#[cfg(test)]
+mod property_tests;
+
+// Re-export public API for backward compatibility
+pub use ani_generator::{generate_ani_data, ms_to_jiffies};
+pub use batch::{
+    convert_directory_to_cur, BatchDefaults, BatchFileResult, BatchReport, ConvertedFile,
+};
+pub use cur_generator::{
+    generate_cur_data, generate_cur_data_with, generate_multi_size_cur_data,
+    generate_multi_size_cur_data_with, validate_cursor_dimensions, CursorImage, PngEncoding,
+    MAX_CURSOR_SIZE, MULTI_SIZE_CURSOR_SIZES,
+};
+pub use format_handler::{
+    handler_for_path, with_handler_for_path, FormatHandler, FormatMetadata, RenderParams,
+};
+pub use preview_source::PreviewSource;
+pub use raster_handler::{load_raster_image, DEFAULT_FRAME_DELAY_MS};
+pub use svg_handler::{load_svg, render_svg_to_png_bytes, SvgResourcePolicy};
+// Internal helpers from binary_writer are intentionally kept private to avoid unused export warnings
+
+/// Convert an image file (SVG, PNG, ICO, BMP, JPG, GIF, or any registered format) to a .CUR file
+///
+/// # Arguments
+/// * `input_path` - Path to the input image file
+/// * `output_path` - Path where the .CUR file will be saved
+/// * `size` - Target size in pixels (width and height, max 256)
+/// * `click_point_x` - Click point X coordinate (hotspot, default 0)
+/// * `click_point_y` - Click point Y coordinate (hotspot, default 0)
+/// * `scale` - Scale factor to apply (1.0 = 100%, 0.5 = 50%, etc.)
+/// * `offset_x` - Horizontal offset in pixels (positive = right, negative = left)
+/// * `offset_y` - Vertical offset in pixels (positive = down, negative = up)
+///
+/// # Quality
+/// - Uses Lanczos3 resampling for highest quality resizing
+/// - Outputs PNG-embedded .CUR for lossless 32-bit RGBA
+///
+/// # Returns
+/// `Ok(())` on success, or an error message
+///
+/// # Errors
+/// When no handler accepts the input, it cannot be decoded, or the file cannot be written
+// Mirrors the flat argument list of the app's conversion command and the C API
+#[allow(clippy::too_many_arguments)]
+pub fn convert_to_cur(
+    input_path: &str,
+    output_path: &str,
+    size: u32,
+    hotspot_x: u16,
+    hotspot_y: u16,
+    scale: f32,
+    offset_x: i32,
+    offset_y: i32,
+) -> Result<(), String> {
+    let params = RenderParams {
+        size,
+        scale,
+        offset_x,
+        offset_y,
+    };
+    let cur_data = render_cur_bytes(
+        input_path,
+        params,
+        (hotspot_x, hotspot_y),
+        PngEncoding::Compact,
+    )?;
+
+    std::fs::write(output_path, cur_data).map_err(|e| format!("Failed to write .CUR file: {e}"))
+}
+
+/// Render an image file to .CUR bytes in memory, as [`convert_to_cur`] does before writing.
+///
+/// With [`PngEncoding::Deterministic`] the output depends only on the input and the
+/// parameters, which is what the golden-file tests under `tests/golden` compare.
+///
+/// # Errors
+/// When no handler accepts the input or it cannot be decoded or encoded
+pub fn render_cur_bytes(
+    input_path: &str,
+    params: RenderParams,
+    hotspot: (u16, u16),
+    encoding: PngEncoding,
+) -> Result<Vec<u8>, String> {
+    // Clamp size to maximum allowed (256x256 is Windows .CUR limit)
+    let params = RenderParams {
+        size: params.size.min(cur_generator::MAX_CURSOR_SIZE),
+        ..params
+    };
+
+    // Pick the input handler by extension, falling back to content sniffing
+    let image = with_handler_for_path(input_path, |handler| {
+        first_frame(handler, input_path, params)
+    })?;
+
+    generate_cur_data_with(&image, hotspot.0, hotspot.1, encoding)
+}
+
+/// Static cursors only use the first frame.
+fn first_frame(
+    handler: &dyn FormatHandler,
+    input_path: &str,
+    params: RenderParams,
+) -> Result<format_handler::RgbaFrame, String> {
+    handler
+        .decode(input_path, params)?
+        .into_iter()
+        .next()
+        .ok_or_else(|| format!("{} handler produced no frames", handler.name()))
+}
+
+/// Convert an image file to a .CUR holding one image per entry of `sizes` (for example
+/// [`MULTI_SIZE_CURSOR_SIZES`]), so Windows can pick the best size for each display's DPI.
+///
+/// The cursor is rendered once at the largest size, as [`convert_to_cur`] would render it, and
+/// scaled down for the others. The hotspot is given on the largest image and scaled with it.
+///
+/// # Errors
+/// When `sizes` is empty, the input cannot be decoded, or the file cannot be written
+#[allow(clippy::too_many_arguments)] // same arguments as `convert_to_cur`, plus the sizes
+pub fn convert_to_multi_size_cur(
+    input_path: &str,
+    output_path: &str,
+    sizes: &[u32],
+    hotspot_x: u16,
+    hotspot_y: u16,
+    scale: f32,
+    offset_x: i32,
+    offset_y: i32,
+) -> Result<(), String> {
+    let mut sizes: Vec<u32> = sizes
+        .iter()
+        .map(|size| (*size).clamp(1, cur_generator::MAX_CURSOR_SIZE))
+        .collect();
+    sizes.sort_unstable_by(|a, b| b.cmp(a));
+    sizes.dedup();
+    let largest = *sizes
+        .first()
+        .ok_or_else(|| "Choose at least one cursor size".to_string())?;
+
+    let params = RenderParams {
+        size: largest,
+        scale,
+        offset_x,
+        offset_y,
+    };
+    let image = with_handler_for_path(input_path, |handler| {
+        first_frame(handler, input_path, params)
+    })?;
+
+    let scale_hotspot = |value: u16, size: u32| {
+        u16::try_from((u32::from(value) * size + largest / 2) / largest).unwrap_or(u16::MAX)
+    };
+    let images: Vec<(format_handler::RgbaFrame, (u16, u16))> = sizes
+        .iter()
+        .map(|&size| {
+            let resized = if size == largest {
+                image.clone()
+            } else {
+                raster_handler::resize_premultiplied(&image, size, size)
+            };
+            let hotspot = (
+                scale_hotspot(hotspot_x, size),
+                scale_hotspot(hotspot_y, size),
+            );
+            (resized, hotspot)
+        })
+        .collect();
+    let entries: Vec<_> = images
+        .iter()
+        .map(|(image, hotspot)| (image, *hotspot))
+        .collect();
+
+    let cur_data = generate_multi_size_cur_data(&entries)?;
+    std::fs::write(output_path, cur_data).map_err(|e| format!("Failed to write .CUR file: {e}"))
+}
+
+/// Convert like [`convert_to_cur`], taking the hotspot from the input when it has one.
+///
+/// For .CUR input the source hotspot is scaled and offset along with the image, so resizing a
+/// cursor keeps its click point on the same pixel. Other formats have no hotspot and get (0, 0).
+///
+/// # Returns
+/// The hotspot written to the output file
+///
+/// # Errors
+/// As [`convert_to_cur`]
+pub fn convert_to_cur_preserving_hotspot(
+    input_path: &str,
+    output_path: &str,
+    size: u32,
+    scale: f32,
+    offset_x: i32,
+    offset_y: i32,
+) -> Result<(u16, u16), String> {
+    let params = RenderParams {
+        size: size.min(cur_generator::MAX_CURSOR_SIZE),
+        scale,
+        offset_x,
+        offset_y,
+    };
+    let (image, (hotspot_x, hotspot_y)) = with_handler_for_path(input_path, |handler| {
+        if handler.name() != "cur" {
+            return first_frame(handler, input_path, params).map(|image| (image, (0, 0)));
+        }
+        let cur = cur_reader::read_cur_file(input_path, params.size)?;
+        Ok(cur_reader::render_cur(&cur, params))
+    })?;
+    write_cur_file(&image, hotspot_x, hotspot_y, output_path)?;
+    Ok((hotspot_x, hotspot_y))
+}
+
+/// Assemble an animated .ANI cursor from `input_paths` and write it to `output_path`
+///
+/// A single GIF or APNG input contributes all of its frames with their own timing. Otherwise
+/// each input, in any format [`convert_to_cur`] accepts, becomes one frame in order. Frames
+/// are scaled to fit the `size` canvas and share one hotspot.
+///
+/// # Arguments
+/// * `delays_ms` - Frame delays in milliseconds: one value for every frame or one per frame.
+///   Empty keeps the source timing, or [`DEFAULT_FRAME_DELAY_MS`] for still images.
+///
+/// # Returns
+/// The number of frames written
+///
+/// # Errors
+/// When an input cannot be decoded, the delays do not match the frames, or the file cannot be
+/// written
+pub fn convert_to_ani(
+    input_paths: &[&str],
+    output_path: &str,
+    size: u32,
+    hotspot_x: u16,
+    hotspot_y: u16,
+    delays_ms: &[u32],
+) -> Result<usize, String> {
+    let size = size.clamp(1, cur_generator::MAX_CURSOR_SIZE);
+    let sources: Vec<(PreviewSource, u32)> = match input_paths {
+        [] => return Err("Animated cursor needs at least one input image".to_string()),
+        [path] => match raster_handler::load_animation_frames(path)? {
+            Some(frames) => frames
+                .into_iter()
+                .map(|(frame, delay)| (PreviewSource::Raster(frame), delay))
+                .collect(),
+            None => vec![(PreviewSource::open(path)?, DEFAULT_FRAME_DELAY_MS)],
+        },
+        paths => paths
+            .iter()
+            .map(|path| Ok((PreviewSource::open(path)?, DEFAULT_FRAME_DELAY_MS)))
+            .collect::<Result<_, String>>()?,
+    };
+
+    let delays: Vec<u32> = match delays_ms {
+        [] => sources.iter().map(|(_, delay)| *delay).collect(),
+        [delay] => vec![*delay],
+        per_frame if per_frame.len() == sources.len() => per_frame.to_vec(),
+        _ => {
+            return Err(format!(
+                "Give one frame delay or one per frame ({} frames)",
+                sources.len()
+            ))
+        }
+    };
+    let rates: Vec<u32> = delays.into_iter().map(ms_to_jiffies).collect();
+
+    let frames = sources
+        .iter()
+        .map(|(source, _)| {
+            // SVG rendering already fits the canvas; raster frames are scaled to fit here
+            let scale = match source {
+                PreviewSource::Svg(_) => 1.0,
+                PreviewSource::Raster(img) => {
+                    raster_handler::contain_scale(img.width(), img.height(), size)
+                }
+            };
+            source.render(RenderParams {
+                size,
+                scale,
+                offset_x: 0,
+                offset_y: 0,
+            })
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    let ani_data = generate_ani_data(&frames, hotspot_x, hotspot_y, &rates)?;
+    std::fs::write(output_path, ani_data).map_err(|e| format!("Failed to write .ANI file: {e}"))?;
+    Ok(frames.len())
+}
+
+fn write_cur_file(
+    image: &format_handler::RgbaFrame,
+    hotspot_x: u16,
+    hotspot_y: u16,
+    output_path: &str,
+) -> Result<(), String> {
+    // Generate .CUR file data (PNG-embedded for maximum quality)
+    let cur_data = generate_cur_data(image, hotspot_x, hotspot_y)?;
+
+    // Write to file
+    std::fs::write(output_path, cur_data).map_err(|e| format!("Failed to write .CUR file: {e}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{ImageBuffer, ImageFormat, Rgba};
+
+    #[test]
+    fn test_write_helpers() {
+        let mut data = Vec::new();
+        binary_writer::write_u16(&mut data, 0x1234).unwrap();
+        assert_eq!(data, vec![0x34, 0x12]);
+
+        let mut data = Vec::new();
+        binary_writer::write_u32(&mut data, 0x1234_5678).unwrap();
+        assert_eq!(data, vec![0x78, 0x56, 0x34, 0x12]);
+    }
+
+    #[test]
+    fn generate_cur_data_contains_click_point_coordinates() {
+        let image = ImageBuffer::from_pixel(32, 32, Rgba([255, 0, 0, 255]));
+        let data = generate_cur_data(&image, 7, 9).expect("generate");
+
+        assert_eq!(&data[0..4], &[0, 0, 2, 0]);
+        assert_eq!(u16::from_le_bytes([data[10], data[11]]), 7);
+        assert_eq!(u16::from_le_bytes([data[12], data[13]]), 9);
+    }
+
+    #[test]
+    fn generate_cur_data_always_uses_png() {
+        // All sizes now use PNG embedding for maximum quality
+        let image = ImageBuffer::from_pixel(32, 32, Rgba([255, 0, 0, 255]));
+        let data = generate_cur_data(&image, 0, 0).expect("generate");
+
+        // PNG signature at offset 22 (after ICONDIR + ICONDIRENTRY)
+        let offset = 6 + 16;
+        assert_eq!(
+            &data[offset..offset + 8],
+            &[0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A]
+        );
+    }
+
+    #[test]
+    fn convert_to_cur_from_png_creates_file() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let input = temp.path().join("input.png");
+        let output = temp.path().join("out.cur");
+
+        let image = ImageBuffer::from_fn(8, 8, |x, y| {
+            let value = ((x + y) % 2) as u8 * 255;
+            Rgba([value, 0, 255 - value, 255])
+        });
+        image
+            .save_with_format(&input, ImageFormat::Png)
+            .expect("save png");
+
+        convert_to_cur(
+            input.to_string_lossy().as_ref(),
+            output.to_string_lossy().as_ref(),
+            256,
+            0,
+            0,
+            1.0,
+            0,
+            0,
+        )
+        .expect("convert png");
+
+        let metadata = std::fs::metadata(&output).expect("metadata");
+        assert!(metadata.len() > 0);
+    }
+
+    #[test]
+    fn convert_to_cur_from_svg_creates_file() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let input = temp.path().join("input.svg");
+        let output = temp.path().join("out.cur");
+
+        let svg = r"<svg xmlns='http://www.w3.org/2000/svg' width='16' height='16'><rect width='16' height='16' fill='blue'/></svg>";
+        std::fs::write(&input, svg).expect("write svg");
+
+        convert_to_cur(
+            input.to_string_lossy().as_ref(),
+            output.to_string_lossy().as_ref(),
+            MAX_CURSOR_SIZE,
+            2,
+            3,
+            1.0,
+            0,
+            0,
+        )
+        .expect("convert svg");
+
+        let metadata = std::fs::metadata(&output).expect("metadata");
+        assert!(metadata.len() > 0);
+    }
+
+    #[test]
+    fn convert_to_cur_clamps_size_to_max() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let input = temp.path().join("input.png");
+        let output = temp.path().join("out.cur");
+
+        let image: ImageBuffer<Rgba<u8>, Vec<u8>> =
+            ImageBuffer::from_pixel(64, 64, Rgba([255u8, 0, 0, 255]));
+        image
+            .save_with_format(&input, ImageFormat::Png)
+            .expect("save png");
+
+        // Request oversize, should be clamped to MAX_CURSOR_SIZE
+        let oversize = MAX_CURSOR_SIZE + 1;
+        convert_to_cur(
+            input.to_string_lossy().as_ref(),
+            output.to_string_lossy().as_ref(),
+            oversize,
+            0,
+            0,
+            1.0,
+            0,
+            0,
+        )
+        .expect("convert with oversized request");
+
+        let metadata = std::fs::metadata(&output).expect("metadata");
+        assert!(metadata.len() > 0);
+    }
+
+    #[test]
+    fn resizing_a_cur_scales_its_hotspot() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let input = temp.path().join("arrow.cur");
+        let output = temp.path().join("arrow_64.cur");
+
+        let image = ImageBuffer::from_pixel(32, 32, Rgba([0u8, 0, 0, 255]));
+        std::fs::write(&input, generate_cur_data(&image, 10, 5).expect("generate"))
+            .expect("write cur");
+
+        let hotspot = convert_to_cur_preserving_hotspot(
+            input.to_string_lossy().as_ref(),
+            output.to_string_lossy().as_ref(),
+            64,
+            2.0,
+            0,
+            0,
+        )
+        .expect("convert cur");
+        assert_eq!(hotspot, (20, 10));
+
+        let data = std::fs::read(&output).expect("read output");
+        assert_eq!(u16::from_le_bytes([data[10], data[11]]), 20);
+        assert_eq!(u16::from_le_bytes([data[12], data[13]]), 10);
+    }
+
+    #[test]
+    fn generate_cur_data_256_is_png() {
+        // MAX_CURSOR_SIZE x MAX_CURSOR_SIZE is the maximum size
+        let image =
+            ImageBuffer::from_pixel(MAX_CURSOR_SIZE, MAX_CURSOR_SIZE, Rgba([10, 20, 30, 255]));
+        let data = generate_cur_data(&image, 5, 7).expect("generate");
+
+        // ICONDIR header should be present
+        assert_eq!(&data[0..4], &[0, 0, 2, 0]);
+
+        // Image data should start at offset 6 + 16 = 22
+        let offset = 6 + 16;
+        assert!(data.len() >= offset + 8);
+
+        // PNG signature at the start of the image blob
+        assert_eq!(
+            &data[offset..offset + 8],
+            &[0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A]
+        );
+
+        // Click point coordinates preserved in ICONDIRENTRY
+        assert_eq!(u16::from_le_bytes([data[10], data[11]]), 5);
+        assert_eq!(u16::from_le_bytes([data[12], data[13]]), 7);
+    }
+
+    #[test]
+    fn convert_to_ani_builds_one_frame_per_image() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let mut inputs = Vec::new();
+        for (name, shade) in [("a.png", 0u8), ("b.png", 255)] {
+            let path = temp.path().join(name);
+            ImageBuffer::from_pixel(64, 32, Rgba([shade, 0, 0, 255]))
+                .save(&path)
+                .expect("save png");
+            inputs.push(path.to_string_lossy().to_string());
+        }
+        let inputs: Vec<&str> = inputs.iter().map(String::as_str).collect();
+        let output = temp.path().join("out.ani");
+        let output = output.to_string_lossy();
+
+        let frames = convert_to_ani(&inputs, &output, 32, 3, 4, &[50, 200]).expect("convert ani");
+        assert_eq!(frames, 2);
+        let data = std::fs::read(output.as_ref()).expect("read output");
+        assert_eq!(&data[0..4], b"RIFF");
+        assert_eq!(&data[8..12], b"ACON");
+        assert_eq!(data.windows(4).filter(|w| w == b"icon").count(), 2);
+        let rate = data
+            .windows(4)
+            .position(|w| w == b"rate")
+            .expect("rate chunk");
+        assert_eq!(&data[rate + 8..rate + 16], &[3, 0, 0, 0, 12, 0, 0, 0]);
+
+        assert!(convert_to_ani(&inputs, &output, 32, 0, 0, &[1, 2, 3]).is_err());
+        assert!(convert_to_ani(&[], &output, 32, 0, 0, &[]).is_err());
+    }
+
+    #[test]
+    fn multi_size_cur_holds_every_size_with_scaled_hotspots() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let input = temp.path().join("arrow.png");
+        ImageBuffer::from_pixel(256, 256, Rgba([0u8, 128, 255, 255]))
+            .save(&input)
+            .expect("save png");
+        let output = temp.path().join("arrow.cur");
+        let output = output.to_string_lossy();
+
+        convert_to_multi_size_cur(
+            &input.to_string_lossy(),
+            &output,
+            &[32, 256, 64, 32],
+            128,
+            64,
+            1.0,
+            0,
+            0,
+        )
+        .expect("convert");
+
+        let data = std::fs::read(output.as_ref()).expect("read output");
+        assert_eq!(u16::from_le_bytes([data[4], data[5]]), 3);
+        let entries: Vec<(u8, u16, u16)> = (0..3)
+            .map(|index| {
+                let at = 6 + index * 16;
+                (
+                    data[at],
+                    u16::from_le_bytes([data[at + 4], data[at + 5]]),
+                    u16::from_le_bytes([data[at + 6], data[at + 7]]),
+                )
+            })
+            .collect();
+        assert_eq!(entries, vec![(0, 128, 64), (64, 32, 16), (32, 16, 8)]);
+
+        // Windows and the reader pick the closest size
+        let small = cur_reader::read_cur_bytes(&data, 32).expect("read 32");
+        assert_eq!(small.image.dimensions(), (32, 32));
+        assert_eq!((small.hotspot_x, small.hotspot_y), (16, 8));
+        let large = cur_reader::read_cur_bytes(&data, 200).expect("read 256");
+        assert_eq!(large.image.dimensions(), (256, 256));
+
+        assert!(
+            convert_to_multi_size_cur(&input.to_string_lossy(), &output, &[], 0, 0, 1.0, 0, 0)
+                .is_err()
+        );
+    }
+}
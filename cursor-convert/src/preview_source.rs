@@ -0,0 +1,125 @@
+//! Decoded sources for live conversion previews
+//!
+//! Editors that let the user drag scale and offset sliders should not re-read and re-decode the
+//! input for every tweak. A [`PreviewSource`] holds the decoded input and renders it with new
+//! [`RenderParams`] using the same placement rules as `convert_to_cur`, so the preview matches
+//! the converted file.
+
+use crate::format_handler::{with_handler_for_path, RenderParams, RgbaFrame};
+use crate::{raster_handler, svg_handler, MAX_CURSOR_SIZE};
+
+pub enum PreviewSource {
+    /// Vector input keeps its parsed tree so every render stays sharp
+    Svg(Box<usvg::Tree>),
+    /// Raster input, decoded to RGBA at its native size
+    Raster(RgbaFrame),
+}
+
+impl PreviewSource {
+    /// Decode `path` with the handler `convert_to_cur` would use.
+    ///
+    /// # Errors
+    /// When no handler accepts the file or it cannot be decoded
+    pub fn open(path: &str) -> Result<Self, String> {
+        with_handler_for_path(path, |handler| handler.open_preview(path))
+    }
+
+    /// Source dimensions in pixels (SVG sizes are rounded up).
+    #[must_use]
+    pub fn dimensions(&self) -> (u32, u32) {
+        match self {
+            Self::Svg(tree) => svg_handler::tree_dimensions(tree),
+            Self::Raster(img) => img.dimensions(),
+        }
+    }
+
+    /// Render onto a square canvas; `params.size` is clamped to `MAX_CURSOR_SIZE`.
+    ///
+    /// # Errors
+    /// When the scale is not a finite positive number or the SVG cannot be rendered
+    pub fn render(&self, params: RenderParams) -> Result<RgbaFrame, String> {
+        if !params.scale.is_finite() || params.scale <= 0.0 {
+            return Err("Scale must be a finite positive number".to_string());
+        }
+        let size = params.size.clamp(1, MAX_CURSOR_SIZE);
+        match self {
+            Self::Svg(tree) => svg_handler::render_svg_tree(
+                tree,
+                size,
+                params.scale,
+                params.offset_x,
+                params.offset_y,
+            ),
+            Self::Raster(img) => Ok(raster_handler::compose_raster_image(
+                img,
+                size,
+                params.scale,
+                params.offset_x,
+                params.offset_y,
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{ImageBuffer, Rgba};
+
+    fn params(size: u32, scale: f32) -> RenderParams {
+        RenderParams {
+            size,
+            scale,
+            offset_x: 0,
+            offset_y: 0,
+        }
+    }
+
+    #[test]
+    fn raster_preview_matches_file_conversion() {
+        let temp = tempfile::tempdir().unwrap();
+        let path = temp.path().join("dot.png");
+        let img: RgbaFrame = ImageBuffer::from_fn(20, 10, |x, _| {
+            if x < 10 {
+                Rgba([255, 0, 0, 255])
+            } else {
+                Rgba([0, 0, 255, 255])
+            }
+        });
+        img.save(&path).unwrap();
+        let path = path.to_string_lossy().to_string();
+
+        let source = PreviewSource::open(&path).unwrap();
+        assert_eq!(source.dimensions(), (20, 10));
+
+        for scale in [0.5, 1.0, 2.0] {
+            let preview = source.render(params(32, scale)).unwrap();
+            let converted = raster_handler::load_raster_image(&path, 32, scale, 0, 0).unwrap();
+            assert_eq!(preview, converted);
+        }
+    }
+
+    #[test]
+    fn svg_preview_renders_from_parsed_tree() {
+        let temp = tempfile::tempdir().unwrap();
+        let path = temp.path().join("square.svg");
+        std::fs::write(
+            &path,
+            r##"<svg xmlns="http://www.w3.org/2000/svg" width="16" height="16"><rect width="16" height="16" fill="#00ff00"/></svg>"##,
+        )
+        .unwrap();
+        let path = path.to_string_lossy().to_string();
+
+        let source = PreviewSource::open(&path).unwrap();
+        assert!(matches!(source, PreviewSource::Svg(_)));
+        let preview = source.render(params(64, 1.0)).unwrap();
+        assert_eq!(preview, svg_handler::load_svg(&path, 64, 1.0, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn invalid_scale_is_rejected() {
+        let source = PreviewSource::Raster(ImageBuffer::new(4, 4));
+        assert!(source.render(params(32, 0.0)).is_err());
+        assert!(source.render(params(32, f32::NAN)).is_err());
+    }
+}
@@ -5,10 +5,10 @@
 
 #[cfg(test)]
 mod tests {
-    use crate::cursor_converter::{convert_to_cur, generate_cur_data};
+    use crate::cur_reader::{cur_dimensions, read_cur_bytes};
+    use crate::{convert_to_cur, fixtures, generate_cur_data};
     use image::{ImageBuffer, ImageFormat, Rgba};
     use proptest::prelude::*;
-    use std::io::Cursor;
 
     // Helper function to validate .CUR file format
     fn validate_cur_format(data: &[u8]) -> Result<(), String> {
@@ -22,18 +22,16 @@ mod tests {
         let num_images = u16::from_le_bytes([data[4], data[5]]);
 
         if reserved != 0 {
-            return Err(format!("Invalid reserved field: {}", reserved));
+            return Err(format!("Invalid reserved field: {reserved}"));
         }
         if image_type != 2 {
             return Err(format!(
-                "Invalid image type (expected 2 for cursor): {}",
-                image_type
+                "Invalid image type (expected 2 for cursor): {image_type}"
             ));
         }
         if num_images != 1 {
             return Err(format!(
-                "Invalid number of images (expected 1): {}",
-                num_images
+                "Invalid number of images (expected 1): {num_images}"
             ));
         }
 
@@ -44,27 +42,23 @@ mod tests {
 
         let width = data[6];
         let height = data[7];
-        let _color_count = data[8];
-        let _reserved = data[9];
-        let _hotspot_x = u16::from_le_bytes([data[10], data[11]]);
-        let _hotspot_y = u16::from_le_bytes([data[12], data[13]]);
+        // Bytes 8..14 (colour count, reserved, hotspot) accept any value
         let image_size = u32::from_le_bytes([data[14], data[15], data[16], data[17]]);
         let image_offset = u32::from_le_bytes([data[18], data[19], data[20], data[21]]);
 
         // Validate dimensions (0 means 256, which is the maximum)
-        let actual_width = if width == 0 { 256 } else { width as u32 };
-        let actual_height = if height == 0 { 256 } else { height as u32 };
+        let actual_width = if width == 0 { 256 } else { u32::from(width) };
+        let actual_height = if height == 0 { 256 } else { u32::from(height) };
 
         if actual_width > 256 || actual_height > 256 {
             return Err(format!(
-                "Invalid dimensions: {}x{} (max 256x256)",
-                actual_width, actual_height
+                "Invalid dimensions: {actual_width}x{actual_height} (max 256x256)"
             ));
         }
 
         // Validate image offset and size
         if image_offset < 22 {
-            return Err(format!("Invalid image offset: {}", image_offset));
+            return Err(format!("Invalid image offset: {image_offset}"));
         }
         if image_size == 0 {
             return Err("Invalid image size: 0".to_string());
@@ -81,35 +75,24 @@ mod tests {
         Ok(())
     }
 
-    // Helper function to extract hotspot coordinates from .CUR data
-    fn extract_hotspot(data: &[u8]) -> Result<(u16, u16), String> {
-        if data.len() < 14 {
-            return Err("CUR file too small to extract hotspot".to_string());
-        }
-
-        let hotspot_x = u16::from_le_bytes([data[10], data[11]]);
-        let hotspot_y = u16::from_le_bytes([data[12], data[13]]);
-
-        Ok((hotspot_x, hotspot_y))
-    }
-
     // Helper function to create a valid test image
+    #[allow(clippy::cast_possible_truncation)] // channels are taken modulo 256
     fn create_test_image(width: u32, height: u32, pattern: u8) -> ImageBuffer<Rgba<u8>, Vec<u8>> {
         ImageBuffer::from_fn(width, height, |x, y| {
-            let r = ((x + pattern as u32) % 256) as u8;
-            let g = ((y + pattern as u32) % 256) as u8;
-            let b = ((x + y + pattern as u32) % 256) as u8;
+            let r = ((x + u32::from(pattern)) % 256) as u8;
+            let g = ((y + u32::from(pattern)) % 256) as u8;
+            let b = ((x + y + u32::from(pattern)) % 256) as u8;
             let a = 255;
             Rgba([r, g, b, a])
         })
     }
 
-    /// **Feature: app-quality-improvement, Property 1: Valid image input produces valid cursor output**
-    ///
-    /// For any valid image input (PNG, SVG, ICO, etc.), cursor conversion should produce
-    /// a valid .cur file that conforms to the Windows cursor format specification.
-    ///
-    /// **Validates: Requirements 2.1**
+    // **Feature: app-quality-improvement, Property 1: Valid image input produces valid cursor output**
+    //
+    // For any valid image input (PNG, SVG, ICO, etc.), cursor conversion should produce
+    // a valid .cur file that conforms to the Windows cursor format specification.
+    //
+    // **Validates: Requirements 2.1**
     proptest! {
         #![proptest_config(ProptestConfig::with_cases(100))]
 
@@ -136,7 +119,7 @@ mod tests {
         }
     }
 
-    /// Test property 1 with PNG file conversion
+    // Test property 1 with PNG file conversion
     proptest! {
         #![proptest_config(ProptestConfig::with_cases(100))]
 
@@ -175,7 +158,7 @@ mod tests {
         }
     }
 
-    /// Test property 1 with various image formats
+    // Test property 1 with various image formats
     proptest! {
         #![proptest_config(ProptestConfig::with_cases(50))]
 
@@ -193,7 +176,7 @@ mod tests {
             let (format, ext) = formats[format_idx];
 
             let temp_dir = tempfile::tempdir().expect("Failed to create temp dir");
-            let input_path = temp_dir.path().join(format!("test.{}", ext));
+            let input_path = temp_dir.path().join(format!("test.{ext}"));
             let output_path = temp_dir.path().join("test.cur");
 
             // Create and save an image
@@ -221,4 +204,53 @@ mod tests {
             prop_assert!(validation.is_ok(), "Invalid CUR format for {}: {:?}", ext, validation.err());
         }
     }
+
+    // Any fixture size and in-bounds hotspot reads back unchanged
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(50))]
+
+        #[test]
+        fn prop_fixture_cursors_round_trip(
+            size in 1u32..=256u32,
+            hotspot_x in 0u16..=255u16,
+            hotspot_y in 0u16..=255u16,
+        ) {
+            let size_u16 = u16::try_from(size).unwrap();
+            let hotspot = (hotspot_x % size_u16, hotspot_y % size_u16);
+            let data = fixtures::cur(size, hotspot);
+            prop_assert!(validate_cur_format(&data).is_ok());
+
+            let cur = read_cur_bytes(&data, size).unwrap();
+            prop_assert_eq!(cur.image.dimensions(), (size, size));
+            prop_assert_eq!((cur.hotspot_x, cur.hotspot_y), hotspot);
+        }
+    }
+
+    // Cut-short or corrupted fixtures are rejected or read, never panic
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(200))]
+
+        #[test]
+        #[allow(
+            clippy::cast_possible_truncation,
+            clippy::cast_precision_loss,
+            clippy::cast_sign_loss
+        )]
+        fn prop_damaged_cursors_never_panic(
+            fixture in 0usize..5,
+            cut in 0.0f64..=1.0,
+            flips in proptest::collection::vec((0.0f64..1.0, any::<u8>()), 0..8),
+        ) {
+            let mut data = fixtures::edge_case_curs().swap_remove(fixture).1;
+            data.truncate((data.len() as f64 * cut) as usize);
+            for (at, byte) in flips {
+                if !data.is_empty() {
+                    let index = ((data.len() as f64 * at) as usize).min(data.len() - 1);
+                    data[index] = byte;
+                }
+            }
+            let _ = read_cur_bytes(&data, 32);
+            let _ = cur_dimensions(&data);
+        }
+    }
 }
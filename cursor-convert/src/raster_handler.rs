@@ -0,0 +1,558 @@
+//! Raster image handling module for cursor conversion
+//!
+//! This module provides functionality to:
+//! - Load and resize raster images (PNG, ICO, BMP, JPG, JPEG, GIF)
+//! - Handle different image formats and bit depths
+//! - Support transparency and various color modes
+//! - Read the frames and timing of animated GIF and APNG files
+
+use image::codecs::gif::GifDecoder;
+use image::codecs::png::PngDecoder;
+use image::{
+    imageops::FilterType, AnimationDecoder, DynamicImage, Frames, ImageBuffer, ImageFormat, Rgba,
+};
+use std::io::Cursor;
+use std::path::Path;
+
+use crate::format_handler::RgbaFrame;
+
+/// Delay for animation frames that specify none, as browsers show them
+pub const DEFAULT_FRAME_DELAY_MS: u32 = 100;
+
+/// Most frames read from one animation, to bound memory on huge files
+pub const MAX_ANIMATION_FRAMES: usize = 256;
+
+/// Load a raster image (PNG, ICO, etc.) and resize if needed
+///
+/// Animated GIF and APNG files contribute their first frame.
+///
+/// # Arguments
+/// * `path` - Path to the image file
+/// * `size` - Target size in pixels (width and height)
+/// * `scale` - Scale factor to apply (1.0 = 100%, 0.5 = 50%, etc.)
+/// * `offset_x` - Horizontal offset in pixels (positive = right, negative = left)
+/// * `offset_y` - Vertical offset in pixels (positive = down, negative = up)
+///
+/// # Errors
+/// When the file cannot be read or decoded
+pub fn load_raster_image(
+    path: &str,
+    size: u32,
+    scale: f32,
+    offset_x: i32,
+    offset_y: i32,
+) -> Result<ImageBuffer<Rgba<u8>, Vec<u8>>, String> {
+    let img = decode_raster_image(path)?;
+    Ok(compose_raster_image(&img, size, scale, offset_x, offset_y))
+}
+
+/// Decode a raster image at its own size, as RGBA.
+///
+/// For animated GIF and APNG files this is the first animation frame. An APNG's default
+/// image may be a fallback that is not part of the animation, so it is not used.
+///
+/// # Errors
+/// When the file cannot be read or decoded
+pub fn decode_raster_image(path: &str) -> Result<RgbaFrame, String> {
+    let data = std::fs::read(path).map_err(|e| format!("Failed to load image: {e}"))?;
+    if let Some(mut frames) = animation_decoder(&data)? {
+        let frame = frames
+            .next()
+            .ok_or_else(|| "Animation has no frames".to_string())?;
+        return frame
+            .map(image::Frame::into_buffer)
+            .map_err(|e| format!("Failed to decode animation: {e}"));
+    }
+    image::load_from_memory(&data)
+        .map(|img| img.to_rgba8())
+        .map_err(|e| format!("Failed to load image: {e}"))
+}
+
+/// Frame decoder for `data` when it is a GIF (animated or not) or an APNG; `None` for
+/// anything else, including still PNGs.
+fn animation_decoder(data: &[u8]) -> Result<Option<Frames<'_>>, String> {
+    let decode_error = |e: image::ImageError| format!("Failed to decode animation: {e}");
+    match image::guess_format(data) {
+        Ok(ImageFormat::Gif) => GifDecoder::new(Cursor::new(data))
+            .map(|decoder| Some(decoder.into_frames()))
+            .map_err(decode_error),
+        Ok(ImageFormat::Png) => {
+            let decoder = PngDecoder::new(Cursor::new(data)).map_err(decode_error)?;
+            if decoder.is_apng().map_err(decode_error)? {
+                Ok(Some(decoder.apng().map_err(decode_error)?.into_frames()))
+            } else {
+                Ok(None)
+            }
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Number of frames in a GIF or APNG, read from the container without decoding any pixels.
+/// A still PNG counts as one frame; `None` means `data` is neither format.
+pub(crate) fn animation_frame_count(data: &[u8]) -> Option<usize> {
+    match image::guess_format(data) {
+        Ok(ImageFormat::Gif) => Some(gif_frame_count(data).max(1)),
+        Ok(ImageFormat::Png) => Some(apng_frame_count(data).unwrap_or(1)),
+        _ => None,
+    }
+}
+
+/// Count GIF image descriptors by walking the block structure. A truncated file counts the
+/// frames read so far.
+fn gif_frame_count(data: &[u8]) -> usize {
+    // Skip the color table that follows a descriptor whose `flags` have it
+    let color_table_len = |flags: u8| {
+        if flags & 0x80 == 0 {
+            0
+        } else {
+            3 << ((flags & 0x07) + 1)
+        }
+    };
+    // Skip a run of data sub-blocks, ending at the zero-length terminator
+    let skip_sub_blocks = |mut pos: usize| {
+        while let Some(&len) = data.get(pos) {
+            pos += 1;
+            if len == 0 {
+                return Some(pos);
+            }
+            pos += usize::from(len);
+        }
+        None
+    };
+
+    let Some(&screen_flags) = data.get(10) else {
+        return 0;
+    };
+    let mut pos = 13 + color_table_len(screen_flags);
+    let mut frames = 0;
+    while let Some(&block) = data.get(pos) {
+        let next = match block {
+            // Extension: label byte, then sub-blocks
+            0x21 => skip_sub_blocks(pos + 2),
+            // Image descriptor: 9 bytes, optional color table, LZW code size, sub-blocks
+            0x2C => {
+                frames += 1;
+                let Some(&flags) = data.get(pos + 9) else {
+                    break;
+                };
+                skip_sub_blocks(pos + 10 + color_table_len(flags) + 1)
+            }
+            _ => None,
+        };
+        match next {
+            Some(next) => pos = next,
+            None => break,
+        }
+    }
+    frames
+}
+
+/// Frame count from an APNG's `acTL` chunk, or `None` for a still PNG.
+fn apng_frame_count(data: &[u8]) -> Option<usize> {
+    let mut pos = 8;
+    while pos + 8 <= data.len() {
+        let len = u32::from_be_bytes(data[pos..pos + 4].try_into().ok()?) as usize;
+        match &data[pos + 4..pos + 8] {
+            b"acTL" => {
+                let frames = data.get(pos + 8..pos + 12)?;
+                return Some(u32::from_be_bytes(frames.try_into().ok()?) as usize);
+            }
+            // `acTL` must come before the image data
+            b"IDAT" => return None,
+            _ => {}
+        }
+        pos = pos.checked_add(12 + len)?;
+    }
+    None
+}
+
+/// Read every frame of an animated GIF or APNG with its delay in milliseconds.
+///
+/// Frames come back composited onto the full canvas at the source size, with transparency
+/// kept. A still PNG or GIF gives a single frame; `None` means the file is neither format.
+///
+/// # Errors
+/// When the file cannot be read or decoded, or has more than [`MAX_ANIMATION_FRAMES`] frames
+pub fn load_animation_frames(path: &str) -> Result<Option<Vec<(RgbaFrame, u32)>>, String> {
+    let data = std::fs::read(path).map_err(|e| format!("Failed to read image: {e}"))?;
+    let decode_error = |e: image::ImageError| format!("Failed to decode animation: {e}");
+    let Some(frames) = animation_decoder(&data)? else {
+        if image::guess_format(&data).ok() != Some(ImageFormat::Png) {
+            return Ok(None);
+        }
+        let img =
+            image::load_from_memory_with_format(&data, ImageFormat::Png).map_err(decode_error)?;
+        return Ok(Some(vec![(img.to_rgba8(), DEFAULT_FRAME_DELAY_MS)]));
+    };
+
+    let mut decoded = Vec::new();
+    for frame in frames {
+        if decoded.len() == MAX_ANIMATION_FRAMES {
+            return Err(format!(
+                "Animation has more than {MAX_ANIMATION_FRAMES} frames"
+            ));
+        }
+        let frame = frame.map_err(decode_error)?;
+        let (numer, denom) = frame.delay().numer_denom_ms();
+        let delay = match numer / denom.max(1) {
+            0 => DEFAULT_FRAME_DELAY_MS,
+            ms => ms,
+        };
+        decoded.push((frame.into_buffer(), delay));
+    }
+    Ok(Some(decoded))
+}
+
+/// [`load_animation_frames`] with every frame placed on a `size` x `size` canvas like
+/// [`load_raster_image`]. Other raster formats give a single frame.
+///
+/// # Errors
+/// When the file cannot be read or decoded, or has more than [`MAX_ANIMATION_FRAMES`] frames
+pub fn load_raster_frames(
+    path: &str,
+    size: u32,
+    scale: f32,
+    offset_x: i32,
+    offset_y: i32,
+) -> Result<Vec<(RgbaFrame, u32)>, String> {
+    let frames = match load_animation_frames(path)? {
+        Some(frames) => frames,
+        None => vec![(decode_raster_image(path)?, DEFAULT_FRAME_DELAY_MS)],
+    };
+    Ok(frames
+        .into_iter()
+        .map(|(frame, delay)| {
+            let composed = compose_raster_image(&frame, size, scale, offset_x, offset_y);
+            (composed, delay)
+        })
+        .collect())
+}
+
+/// Scale that fits a `width` x `height` image inside a `size` canvas, like CSS
+/// `object-fit: contain`.
+#[must_use]
+#[allow(clippy::cast_precision_loss)] // image sizes are far below f32's exact integer range
+pub fn contain_scale(width: u32, height: u32, size: u32) -> f32 {
+    size as f32 / width.max(height).max(1) as f32
+}
+
+/// Place an already decoded image on a `size` x `size` canvas with the given scale and offset.
+///
+/// Split out of [`load_raster_image`] so preview sessions can re-render without decoding again.
+#[must_use]
+pub fn compose_raster_image(
+    img: &ImageBuffer<Rgba<u8>, Vec<u8>>,
+    size: u32,
+    scale: f32,
+    offset_x: i32,
+    offset_y: i32,
+) -> ImageBuffer<Rgba<u8>, Vec<u8>> {
+    // Create a transparent canvas of target size
+    let mut canvas = ImageBuffer::from_pixel(size, size, Rgba([0, 0, 0, 0]));
+
+    let (scaled_width, scaled_height, final_x, final_y) =
+        placement(img.width(), img.height(), size, scale, offset_x, offset_y);
+
+    // Resize the image to the scaled dimensions
+    let scaled_img = if scaled_width > 0 && scaled_height > 0 {
+        resize_premultiplied(img, scaled_width, scaled_height)
+    } else {
+        // If scale results in zero size, return empty canvas
+        return canvas;
+    };
+
+    // Copy the scaled image onto the canvas; pixels outside it are clipped
+    image::imageops::replace(
+        &mut canvas,
+        &scaled_img,
+        i64::from(final_x),
+        i64::from(final_y),
+    );
+
+    canvas
+}
+
+/// Resize with premultiplied alpha, so the colour of fully transparent pixels (black in most
+/// GIF and APNG frames) does not bleed into the edges of the visible ones.
+pub(crate) fn resize_premultiplied(img: &RgbaFrame, width: u32, height: u32) -> RgbaFrame {
+    let mut premultiplied = DynamicImage::ImageRgba8(img.clone()).into_rgba32f();
+    for pixel in premultiplied.pixels_mut() {
+        let alpha = pixel[3];
+        for channel in &mut pixel.0[..3] {
+            *channel *= alpha;
+        }
+    }
+
+    let mut resized = image::imageops::resize(&premultiplied, width, height, FilterType::Lanczos3);
+    for pixel in resized.pixels_mut() {
+        // Lanczos overshoots at hard edges
+        let alpha = pixel[3].clamp(0.0, 1.0);
+        pixel[3] = alpha;
+        for channel in &mut pixel.0[..3] {
+            *channel = if alpha > 0.0 {
+                (*channel / alpha).clamp(0.0, 1.0)
+            } else {
+                0.0
+            };
+        }
+    }
+    DynamicImage::ImageRgba32F(resized).into_rgba8()
+}
+
+/// Scaled size and top-left canvas position of a `width` x `height` source.
+///
+/// Returns `(scaled_width, scaled_height, x, y)`. Shared with hotspot mapping so a point in the
+/// source lands exactly where [`compose_raster_image`] draws it.
+// Canvases are at most 256 pixels and decoded sources are bounded by the decoder limits, so
+// these casts neither wrap nor lose precision
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_possible_wrap,
+    clippy::cast_precision_loss,
+    clippy::cast_sign_loss
+)]
+pub(crate) fn placement(
+    width: u32,
+    height: u32,
+    size: u32,
+    scale: f32,
+    offset_x: i32,
+    offset_y: i32,
+) -> (u32, u32, i32, i32) {
+    // Calculate the scaled dimensions
+    let scaled_width = (width as f32 * scale) as u32;
+    let scaled_height = (height as f32 * scale) as u32;
+
+    // Calculate position to place the scaled image on the canvas
+    // Center the image first, then apply offset
+    // Note: CSS transform applies translate in scaled space, so we need to scale the offset
+    let center_x = (size as i32 - scaled_width as i32) / 2;
+    let center_y = (size as i32 - scaled_height as i32) / 2;
+    let final_x = center_x + (offset_x as f32 * scale) as i32;
+    let final_y = center_y + (offset_y as f32 * scale) as i32;
+
+    (scaled_width, scaled_height, final_x, final_y)
+}
+
+/// Check if a file path points to a raster image file
+#[must_use]
+pub fn is_raster_image(path: &str) -> bool {
+    Path::new(path)
+        .extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|s| {
+            let ext = s.to_lowercase();
+            matches!(ext.as_str(), "png" | "ico" | "bmp" | "jpg" | "jpeg" | "gif")
+        })
+}
+
+/// Get the file extension for a raster image
+#[must_use]
+pub fn get_raster_extension(path: &str) -> Option<String> {
+    Path::new(path)
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(str::to_lowercase)
+}
+
+/// Check if a file extension is a supported raster format
+#[must_use]
+pub fn is_supported_raster_format(extension: &str) -> bool {
+    let ext = extension.to_lowercase();
+    matches!(ext.as_str(), "png" | "ico" | "bmp" | "jpg" | "jpeg" | "gif")
+}
+
+/// Get the MIME type for a raster image format
+#[must_use]
+pub fn get_raster_mime_type(extension: &str) -> &'static str {
+    match extension.to_lowercase().as_str() {
+        "png" => "image/png",
+        "ico" => "image/x-icon",
+        "bmp" => "image/bmp",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        _ => "application/octet-stream",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{ImageBuffer, Rgba};
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_is_raster_image() {
+        assert!(is_raster_image("test.png"));
+        assert!(is_raster_image("test.ico"));
+        assert!(is_raster_image("test.bmp"));
+        assert!(is_raster_image("test.jpg"));
+        assert!(is_raster_image("test.jpeg"));
+        assert!(is_raster_image("test.gif"));
+        assert!(is_raster_image("test.PNG"));
+
+        assert!(!is_raster_image("test.svg"));
+        assert!(!is_raster_image("test.cur"));
+        assert!(!is_raster_image("test"));
+    }
+
+    #[test]
+    fn test_offset_scaling_matches_css_behavior() {
+        // Create a test image
+        let temp = tempdir().expect("tempdir");
+        let input = temp.path().join("input.png");
+        let image = ImageBuffer::from_fn(64, 64, |x, y| {
+            let value = ((x + y) % 2) as u8 * 255;
+            Rgba([value, 0, 255 - value, 255])
+        });
+        image.save(&input).expect("save png");
+
+        // Test with scale = 2.0 and offset = 10
+        // CSS behavior: translate happens in scaled space, so 10px offset becomes 20px in original space
+        let result = load_raster_image(input.to_string_lossy().as_ref(), 128, 2.0, 10, 10)
+            .expect("load image");
+
+        // The image should be positioned at center + (10 * 2.0) = center + 20
+        // For a 128px canvas with a 128px scaled image (64px * 2.0), center is 0
+        // So final position should be 20, 20
+
+        // Verify the image is not empty
+        assert_eq!(result.width(), 128);
+        assert_eq!(result.height(), 128);
+    }
+
+    #[test]
+    fn test_get_raster_extension() {
+        assert_eq!(get_raster_extension("test.png"), Some("png".to_string()));
+        assert_eq!(
+            get_raster_extension("path/to/image.ico"),
+            Some("ico".to_string())
+        );
+        assert_eq!(get_raster_extension("noextension"), None);
+    }
+
+    #[test]
+    fn test_is_supported_raster_format() {
+        assert!(is_supported_raster_format("png"));
+        assert!(is_supported_raster_format("ico"));
+        assert!(is_supported_raster_format("bmp"));
+        assert!(is_supported_raster_format("jpg"));
+        assert!(is_supported_raster_format("jpeg"));
+        assert!(is_supported_raster_format("gif"));
+        assert!(!is_supported_raster_format("svg"));
+        assert!(!is_supported_raster_format("cur"));
+    }
+
+    #[test]
+    fn test_get_raster_mime_type() {
+        assert_eq!(get_raster_mime_type("png"), "image/png");
+        assert_eq!(get_raster_mime_type("ico"), "image/x-icon");
+        assert_eq!(get_raster_mime_type("bmp"), "image/bmp");
+        assert_eq!(get_raster_mime_type("jpg"), "image/jpeg");
+        assert_eq!(get_raster_mime_type("jpeg"), "image/jpeg");
+        assert_eq!(get_raster_mime_type("gif"), "image/gif");
+        assert_eq!(get_raster_mime_type("svg"), "application/octet-stream");
+    }
+
+    #[test]
+    fn test_raster_file_detection() {
+        // Test various raster formats
+        assert!(is_raster_image("cursor.png"));
+        assert!(is_raster_image("icon.ICO"));
+        assert!(is_raster_image("photo.bmp"));
+        assert!(is_raster_image("image.jpg"));
+        assert!(is_raster_image("picture.jpeg"));
+
+        // Test case insensitive
+        assert!(is_raster_image("test.PNG"));
+        assert!(is_raster_image("test.JPG"));
+
+        // Test non-raster files
+        assert!(!is_raster_image("vector.svg"));
+        assert!(!is_raster_image("cursor.cur"));
+        assert!(!is_raster_image("document.txt"));
+    }
+
+    #[test]
+    fn animation_frames_keep_their_delays() {
+        use image::codecs::gif::{GifEncoder, Repeat};
+        use image::{Delay, Frame};
+
+        let temp = tempdir().expect("tempdir");
+        let gif = temp.path().join("spin.gif");
+        {
+            let file = std::fs::File::create(&gif).expect("create gif");
+            let mut encoder = GifEncoder::new(file);
+            encoder.set_repeat(Repeat::Infinite).expect("repeat");
+            for (shade, ms) in [(0u8, 50u32), (255, 0)] {
+                let buffer = ImageBuffer::from_pixel(8, 8, Rgba([shade, 0, 0, 255]));
+                encoder
+                    .encode_frame(Frame::from_parts(
+                        buffer,
+                        0,
+                        0,
+                        Delay::from_numer_denom_ms(ms, 1),
+                    ))
+                    .expect("encode frame");
+            }
+        }
+
+        let frames = load_animation_frames(gif.to_string_lossy().as_ref())
+            .expect("decode")
+            .expect("gif is an animation");
+        assert_eq!(frames.len(), 2);
+        let gif_data = std::fs::read(&gif).expect("read gif");
+        assert_eq!(animation_frame_count(&gif_data), Some(2));
+        assert_eq!(frames[0].1, 50);
+        // Frames without a delay get the default
+        assert_eq!(frames[1].1, DEFAULT_FRAME_DELAY_MS);
+        assert_eq!(frames[1].0.get_pixel(0, 0), &Rgba([255, 0, 0, 255]));
+
+        let png = temp.path().join("still.png");
+        ImageBuffer::from_pixel(4, 4, Rgba([0u8, 0, 255, 128]))
+            .save(&png)
+            .expect("save png");
+        let still = load_animation_frames(png.to_string_lossy().as_ref())
+            .expect("decode")
+            .expect("png is read");
+        assert_eq!(still.len(), 1);
+        let png_data = std::fs::read(&png).expect("read png");
+        assert_eq!(animation_frame_count(&png_data), Some(1));
+        assert_eq!(still[0].0.get_pixel(0, 0), &Rgba([0, 0, 255, 128]));
+
+        // An APNG declares its frame count up front
+        let mut apng = png_data[..33].to_vec();
+        apng.extend_from_slice(&8u32.to_be_bytes());
+        apng.extend_from_slice(b"acTL");
+        apng.extend_from_slice(&3u32.to_be_bytes());
+        apng.extend_from_slice(&0u32.to_be_bytes());
+        apng.extend_from_slice(&[0; 4]);
+        apng.extend_from_slice(&png_data[33..]);
+        assert_eq!(animation_frame_count(&apng), Some(3));
+
+        let bmp = temp.path().join("still.bmp");
+        ImageBuffer::from_pixel(4, 4, Rgba([0u8, 0, 255, 255]))
+            .save(&bmp)
+            .expect("save bmp");
+        assert!(load_animation_frames(bmp.to_string_lossy().as_ref())
+            .expect("read")
+            .is_none());
+    }
+
+    #[test]
+    fn transparent_pixels_do_not_darken_edges() {
+        // Opaque white next to transparent black, as GIF frames come out of the decoder
+        let image = ImageBuffer::from_fn(8, 8, |x, _| {
+            if x < 4 {
+                Rgba([255u8, 255, 255, 255])
+            } else {
+                Rgba([0, 0, 0, 0])
+            }
+        });
+        let scaled = compose_raster_image(&image, 16, 2.0, 0, 0);
+        for pixel in scaled.pixels().filter(|pixel| pixel[3] > 0) {
+            assert!(pixel[0] > 250, "edge pixel darkened: {pixel:?}");
+        }
+    }
+}
@@ -0,0 +1,406 @@
+//! SVG handling module for cursor conversion
+//!
+//! This module provides functionality to:
+//! - Load and parse SVG files with robust error handling
+//! - Render SVG to bitmap with proper scaling and positioning
+//! - Handle various SVG edge cases and malformed content
+//! - Apply a resource policy: text renders with a bundled font set (plus system fonts when
+//!   allowed) and external `href`s are stripped, so output doesn't depend on the machine
+
+use image::{ImageBuffer, ImageEncoder, Rgba};
+use std::path::Path;
+use std::sync::{Arc, Mutex, OnceLock, RwLock};
+
+/// Fallback fonts embedded in the binary; see `fonts/LICENSE-DejaVu.txt`.
+const BUNDLED_FONTS: [&[u8]; 3] = [
+    include_bytes!("../fonts/DejaVuSans.ttf"),
+    include_bytes!("../fonts/DejaVuSans-Bold.ttf"),
+    include_bytes!("../fonts/DejaVuSansMono.ttf"),
+];
+const BUNDLED_SANS_FAMILY: &str = "DejaVu Sans";
+const BUNDLED_MONO_FAMILY: &str = "DejaVu Sans Mono";
+
+/// Called with the `href`s stripped from an SVG, for logging or user-facing warnings.
+pub type StrippedResourcesHook = fn(&[String]);
+
+/// How SVG rendering treats fonts and resources outside the file.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SvgResourcePolicy {
+    /// Also resolve fonts installed on this machine. Off by default so the same SVG renders the
+    /// same everywhere; the bundled fonts are always available.
+    pub load_system_fonts: bool,
+    /// Receives the list of stripped external resources after each parse that stripped any.
+    pub on_stripped: Option<StrippedResourcesHook>,
+}
+
+static RESOURCE_POLICY: RwLock<SvgResourcePolicy> = RwLock::new(SvgResourcePolicy {
+    load_system_fonts: false,
+    on_stripped: None,
+});
+static BUNDLED_FONT_DB: OnceLock<Arc<usvg::fontdb::Database>> = OnceLock::new();
+static SYSTEM_FONT_DB: OnceLock<Arc<usvg::fontdb::Database>> = OnceLock::new();
+
+/// Replace the process-wide policy used by [`load_svg`] and [`parse_svg`].
+pub fn set_resource_policy(policy: SvgResourcePolicy) {
+    if let Ok(mut current) = RESOURCE_POLICY.write() {
+        *current = policy;
+    }
+}
+
+#[must_use]
+pub fn resource_policy() -> SvgResourcePolicy {
+    RESOURCE_POLICY.read().map(|p| *p).unwrap_or_default()
+}
+
+fn font_database(load_system_fonts: bool) -> Arc<usvg::fontdb::Database> {
+    let cell = if load_system_fonts {
+        &SYSTEM_FONT_DB
+    } else {
+        &BUNDLED_FONT_DB
+    };
+    cell.get_or_init(|| {
+        let mut db = usvg::fontdb::Database::new();
+        for font in BUNDLED_FONTS {
+            db.load_font_data(font.to_vec());
+        }
+        if load_system_fonts {
+            db.load_system_fonts();
+        } else {
+            // Generic families must resolve to something that is actually loaded
+            db.set_serif_family(BUNDLED_SANS_FAMILY);
+            db.set_sans_serif_family(BUNDLED_SANS_FAMILY);
+            db.set_cursive_family(BUNDLED_SANS_FAMILY);
+            db.set_fantasy_family(BUNDLED_SANS_FAMILY);
+            db.set_monospace_family(BUNDLED_MONO_FAMILY);
+        }
+        Arc::new(db)
+    })
+    .clone()
+}
+
+/// A parsed SVG plus the external resources that were stripped while parsing it.
+pub struct ParsedSvg {
+    pub tree: usvg::Tree,
+    /// `href` values that pointed outside the file (paths or URLs), in document order
+    pub stripped_resources: Vec<String>,
+}
+
+/// Load and render an SVG file to a bitmap
+///
+/// # Arguments
+/// * `path` - Path to the SVG file
+/// * `size` - Target size in pixels (width and height)
+/// * `scale` - Additional scale factor to apply (1.0 = 100%, 0.5 = 50%, etc.)
+/// * `offset_x` - Horizontal offset in pixels (positive = right, negative = left)
+/// * `offset_y` - Vertical offset in pixels (positive = down, negative = up)
+///
+/// # Errors
+/// When the file cannot be read, is empty, or is not a valid SVG
+pub fn load_svg(
+    path: &str,
+    size: u32,
+    scale: f32,
+    offset_x: i32,
+    offset_y: i32,
+) -> Result<ImageBuffer<Rgba<u8>, Vec<u8>>, String> {
+    // Read SVG file
+    let svg_data = std::fs::read(path).map_err(|e| format!("Failed to read SVG file: {e}"))?;
+
+    if svg_data.is_empty() {
+        return Err("SVG file is empty".to_string());
+    }
+
+    let tree = parse_svg(&svg_data)?;
+    render_svg_tree(&tree, size, scale, offset_x, offset_y)
+}
+
+/// Parse SVG bytes under the current [`SvgResourcePolicy`], reporting stripped resources to
+/// the policy's hook.
+///
+/// # Errors
+/// When the data is not a valid SVG
+pub fn parse_svg(svg_data: &[u8]) -> Result<usvg::Tree, String> {
+    let policy = resource_policy();
+    let parsed = parse_svg_with_policy(svg_data, &policy)?;
+    if !parsed.stripped_resources.is_empty() {
+        if let Some(hook) = policy.on_stripped {
+            hook(&parsed.stripped_resources);
+        }
+    }
+    Ok(parsed.tree)
+}
+
+/// Parse SVG bytes, retrying without a BOM or leading junk before `<svg` if needed.
+///
+/// Embedded `data:` images are kept; every other image `href` is stripped and listed in
+/// [`ParsedSvg::stripped_resources`].
+///
+/// # Errors
+/// When the data is not a valid SVG, even after the retry
+pub fn parse_svg_with_policy(
+    svg_data: &[u8],
+    policy: &SvgResourcePolicy,
+) -> Result<ParsedSvg, String> {
+    let stripped = Mutex::new(Vec::<String>::new());
+    let stripped_ref = &stripped;
+
+    let mut opts = usvg::Options {
+        font_family: BUNDLED_SANS_FAMILY.to_string(),
+        fontdb: font_database(policy.load_system_fonts),
+        ..usvg::Options::default()
+    };
+    opts.image_href_resolver.resolve_string = Box::new(move |href: &str, _: &usvg::Options| {
+        if let Ok(mut list) = stripped_ref.lock() {
+            if !list.iter().any(|seen| seen == href) {
+                list.push(href.to_string());
+            }
+        }
+        None
+    });
+
+    // Try to parse the SVG; if parsing fails, attempt a few safe fallbacks
+
+    // Primary attempt: parse the raw bytes as provided
+    let tree = match usvg::Tree::from_data(svg_data, &opts) {
+        Ok(t) => t,
+        Err(e1) => {
+            // 1) Try stripping a UTF-8 BOM if present
+            if svg_data.starts_with(&[0xEF, 0xBB, 0xBF]) {
+                if let Ok(t) = usvg::Tree::from_data(&svg_data[3..], &opts) {
+                    t
+                } else {
+                    // 2) Try to salvage by locating the first "<svg" and parsing from there
+                    let text = String::from_utf8_lossy(svg_data);
+                    if let Some(pos) = text.find("<svg") {
+                        let suffix = &text[pos..];
+                        if let Ok(t) = usvg::Tree::from_data(suffix.as_bytes(), &opts) {
+                            t
+                        } else {
+                            let preview = if text.len() > 200 {
+                                &text[..200]
+                            } else {
+                                &text
+                            };
+                            return Err(format!(
+                                "Failed to parse SVG: {}. File size: {} bytes. Preview: {}",
+                                e1,
+                                svg_data.len(),
+                                preview
+                            ));
+                        }
+                    } else {
+                        let first_bytes = &svg_data[..std::cmp::min(64, svg_data.len())];
+                        return Err(format!(
+                            "Failed to parse SVG: {}. File size: {} bytes. First bytes: {:?}",
+                            e1,
+                            svg_data.len(),
+                            first_bytes
+                        ));
+                    }
+                }
+            } else {
+                // No BOM: try to find an <svg start and parse from there
+                let text = String::from_utf8_lossy(svg_data);
+                if let Some(pos) = text.find("<svg") {
+                    let suffix = &text[pos..];
+                    if let Ok(t) = usvg::Tree::from_data(suffix.as_bytes(), &opts) {
+                        t
+                    } else {
+                        let preview = if text.len() > 200 {
+                            &text[..200]
+                        } else {
+                            &text
+                        };
+                        return Err(format!(
+                            "Failed to parse SVG: {}. File size: {} bytes. Preview: {}",
+                            e1,
+                            svg_data.len(),
+                            preview
+                        ));
+                    }
+                } else {
+                    let first_bytes = &svg_data[..std::cmp::min(64, svg_data.len())];
+                    return Err(format!(
+                        "Failed to parse SVG: {}. File size: {} bytes. First bytes: {:?}",
+                        e1,
+                        svg_data.len(),
+                        first_bytes
+                    ));
+                }
+            }
+        }
+    };
+
+    drop(opts);
+    Ok(ParsedSvg {
+        tree,
+        stripped_resources: stripped.into_inner().unwrap_or_default(),
+    })
+}
+
+/// Size of a parsed SVG in whole pixels, rounded up.
+#[must_use]
+pub fn tree_dimensions(tree: &usvg::Tree) -> (u32, u32) {
+    let size = tree.size();
+    // usvg sizes are finite and positive
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    (size.width().ceil() as u32, size.height().ceil() as u32)
+}
+
+/// Render a parsed SVG onto a `size` x `size` canvas with the given scale and offset.
+///
+/// # Errors
+/// When the canvas cannot be allocated
+// Canvas sizes and offsets are far below f32's exact integer range
+#[allow(clippy::cast_precision_loss)]
+pub fn render_svg_tree(
+    tree: &usvg::Tree,
+    size: u32,
+    scale: f32,
+    offset_x: i32,
+    offset_y: i32,
+) -> Result<ImageBuffer<Rgba<u8>, Vec<u8>>, String> {
+    // Create pixmap for rendering
+    let mut pixmap =
+        tiny_skia::Pixmap::new(size, size).ok_or_else(|| "Failed to create pixmap".to_string())?;
+
+    // Calculate scale to fit SVG in target size while maintaining aspect ratio
+    // This replicates CSS "object-fit: contain" behavior
+    let svg_size = tree.size();
+    // Guard against zero dimensions which would cause division by zero
+    let svg_w = if svg_size.width() == 0.0 {
+        1.0
+    } else {
+        svg_size.width()
+    };
+    let svg_h = if svg_size.height() == 0.0 {
+        1.0
+    } else {
+        svg_size.height()
+    };
+
+    let scale_x = size as f32 / svg_w;
+    let scale_y = size as f32 / svg_h;
+    let fit_scale = scale_x.min(scale_y);
+
+    // Apply user-specified scale on top of fit scale
+    let final_scale = fit_scale * scale;
+
+    // Calculate the final rendered size after all scaling
+    let final_width = svg_w * final_scale;
+    let final_height = svg_h * final_scale;
+
+    // Center the scaled SVG in the canvas (transform-origin: center behavior)
+    let base_offset_x = (size as f32 - final_width) / 2.0;
+    let base_offset_y = (size as f32 - final_height) / 2.0;
+
+    // Apply user-specified offset (offset is in pre-scale space, so multiply by scale)
+    // CSS: transform: scale(s) translate(ox, oy) means translate happens in scaled space
+    let final_offset_x = (offset_x as f32).mul_add(scale, base_offset_x);
+    let final_offset_y = (offset_y as f32).mul_add(scale, base_offset_y);
+
+    let transform = tiny_skia::Transform::from_translate(final_offset_x, final_offset_y)
+        .post_scale(final_scale, final_scale);
+
+    // Render SVG
+    resvg::render(tree, transform, &mut pixmap.as_mut());
+
+    // Convert pixmap to ImageBuffer
+    let raw_data = pixmap.take();
+    ImageBuffer::from_raw(size, size, raw_data)
+        .ok_or_else(|| "Failed to create image buffer from pixmap".to_string())
+}
+
+/// Render an SVG file to PNG bytes using the same rendering pipeline as cursor conversion
+///
+/// # Errors
+/// As [`load_svg`], or when the PNG cannot be encoded
+pub fn render_svg_to_png_bytes(path: &str, size: u32) -> Result<Vec<u8>, String> {
+    // Use default transformations (no scale/offset)
+    let image = load_svg(path, size, 1.0, 0, 0)?;
+    let mut png_data = Vec::new();
+    let width = image.width();
+    let height = image.height();
+
+    let encoder = image::codecs::png::PngEncoder::new(&mut png_data);
+    encoder
+        .write_image(
+            image.as_raw(),
+            width,
+            height,
+            image::ColorType::Rgba8.into(),
+        )
+        .map_err(|e| format!("Failed to encode PNG: {e}"))?;
+
+    Ok(png_data)
+}
+
+/// Check if a file path points to an SVG file
+#[must_use]
+pub fn is_svg_file(path: &str) -> bool {
+    Path::new(path)
+        .extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|s| s.to_lowercase() == "svg")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_svg_file() {
+        assert!(is_svg_file("test.svg"));
+        assert!(is_svg_file("test.SVG"));
+        assert!(is_svg_file("C:\\path\\to\\file.SVG"));
+        assert!(!is_svg_file("test.png"));
+        assert!(!is_svg_file("test"));
+    }
+
+    #[test]
+    fn test_svg_file_detection_with_various_extensions() {
+        // Test various SVG extensions
+        assert!(is_svg_file("cursor.svg"));
+        assert!(is_svg_file("icon.SVG"));
+        assert!(is_svg_file("image.SvG"));
+
+        // Test non-SVG files
+        assert!(!is_svg_file("image.png"));
+        assert!(!is_svg_file("photo.jpg"));
+        assert!(!is_svg_file("icon.ico"));
+        assert!(!is_svg_file("noextension"));
+    }
+
+    #[test]
+    fn test_external_hrefs_are_stripped_and_reported() {
+        let svg = br#"<svg xmlns="http://www.w3.org/2000/svg" xmlns:xlink="http://www.w3.org/1999/xlink" width="16" height="16">
+            <image href="https://example.com/arrow.png" width="16" height="16"/>
+            <image xlink:href="file:///C:/cursors/arrow.png" width="16" height="16"/>
+            <image href="https://example.com/arrow.png" width="16" height="16"/>
+            <rect width="4" height="4" fill="red"/>
+        </svg>"#;
+
+        let parsed = parse_svg_with_policy(svg, &SvgResourcePolicy::default()).unwrap();
+
+        assert_eq!(
+            parsed.stripped_resources,
+            vec![
+                "https://example.com/arrow.png".to_string(),
+                "file:///C:/cursors/arrow.png".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_text_renders_with_bundled_font() {
+        let svg = br#"<svg xmlns="http://www.w3.org/2000/svg" width="32" height="32">
+            <text x="2" y="24" font-family="NoSuchFont, sans-serif" font-size="24" fill="black">A</text>
+        </svg>"#;
+
+        let parsed = parse_svg_with_policy(svg, &SvgResourcePolicy::default()).unwrap();
+        assert!(parsed.stripped_resources.is_empty());
+
+        let img = render_svg_tree(&parsed.tree, 32, 1.0, 0, 0).unwrap();
+        assert!(img.pixels().any(|p| p.0[3] > 0), "text should produce visible pixels");
+    }
+}
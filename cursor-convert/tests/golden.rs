@@ -0,0 +1,124 @@
+//! Golden-file tests for conversion output.
+//!
+//! Each case renders a fixture from `tests/golden` with [`PngEncoding::Deterministic`] and
+//! compares the bytes with the committed `.cur`, so refactors of the generator or the input
+//! handlers can't change the pixels unnoticed. After an intentional output change, re-run
+//! with `CURSOR_CONVERT_BLESS=1` to rewrite the golden files and review the diff.
+
+use std::path::PathBuf;
+
+use cursor_convert::{render_cur_bytes, PngEncoding, RenderParams};
+
+/// ICONDIR + one ICONDIRENTRY
+const CUR_HEADER_LEN: usize = 6 + 16;
+
+struct Case {
+    input: &'static str,
+    golden: &'static str,
+    params: RenderParams,
+    hotspot: (u16, u16),
+}
+
+fn golden_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("golden")
+}
+
+/// Where two cursors first differ, in pixels when both decode.
+fn describe_difference(actual: &[u8], expected: &[u8]) -> String {
+    if actual.get(..CUR_HEADER_LEN) != expected.get(..CUR_HEADER_LEN) {
+        return "header differs (size or hotspot)".to_string();
+    }
+    let decode = |bytes: &[u8]| {
+        image::load_from_memory_with_format(&bytes[CUR_HEADER_LEN..], image::ImageFormat::Png)
+            .map(|image| image.to_rgba8())
+    };
+    match (decode(actual), decode(expected)) {
+        (Ok(actual), Ok(expected)) => actual
+            .enumerate_pixels()
+            .zip(expected.pixels())
+            .find(|((_, _, a), e)| a != e)
+            .map_or_else(
+                || "pixels match but the encoding differs".to_string(),
+                |((x, y, a), e)| format!("pixel ({x}, {y}) is {:?}, expected {:?}", a.0, e.0),
+            ),
+        _ => "embedded PNG does not decode".to_string(),
+    }
+}
+
+fn check(case: &Case) {
+    let dir = golden_dir();
+    let input = dir.join(case.input);
+    let actual = render_cur_bytes(
+        &input.to_string_lossy(),
+        case.params,
+        case.hotspot,
+        PngEncoding::Deterministic,
+    )
+    .unwrap_or_else(|e| panic!("failed to convert {}: {e}", case.input));
+
+    let golden = dir.join(case.golden);
+    if std::env::var_os("CURSOR_CONVERT_BLESS").is_some() {
+        std::fs::write(&golden, &actual).expect("write golden file");
+        return;
+    }
+    let expected = std::fs::read(&golden).unwrap_or_else(|e| {
+        panic!(
+            "missing golden file {} ({e}); run with CURSOR_CONVERT_BLESS=1",
+            case.golden
+        )
+    });
+    assert!(
+        actual == expected,
+        "{} no longer matches {}: {}",
+        case.input,
+        case.golden,
+        describe_difference(&actual, &expected)
+    );
+}
+
+#[test]
+fn png_upscaled_to_32() {
+    check(&Case {
+        input: "arrow.png",
+        golden: "arrow_png_32.cur",
+        params: RenderParams {
+            size: 32,
+            scale: 1.0,
+            offset_x: 0,
+            offset_y: 0,
+        },
+        hotspot: (0, 0),
+    });
+}
+
+#[test]
+fn png_scaled_and_offset_at_48() {
+    check(&Case {
+        input: "arrow.png",
+        golden: "arrow_png_48_offset.cur",
+        params: RenderParams {
+            size: 48,
+            scale: 0.75,
+            offset_x: 3,
+            offset_y: -2,
+        },
+        hotspot: (3, 1),
+    });
+}
+
+#[test]
+fn svg_rendered_at_32() {
+    check(&Case {
+        input: "arrow.svg",
+        golden: "arrow_svg_32.cur",
+        params: RenderParams {
+            size: 32,
+            scale: 1.0,
+            offset_x: 0,
+            offset_y: 0,
+        },
+        hotspot: (2, 2),
+    });
+}
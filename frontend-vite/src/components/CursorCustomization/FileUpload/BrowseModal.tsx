@@ -6,6 +6,7 @@ import { Card, CardContent } from '@/components/ui/card';
 import { Button } from '@/components/ui/button';
 import { logger } from '../../../utils/logger';
 import { Commands } from '../../../tauri/commands';
+import type { BrowsedCursorFile } from '../../../types/generated/BrowsedCursorFile';
 
 // Tauri drag-drop event payload type
 interface DragDropPayload {
@@ -35,6 +36,29 @@ const SUPPORTED_IMAGE_EXTENSIONS = ['svg', 'png', 'ico', 'bmp', 'jpg', 'jpeg'];
 const SUPPORTED_CURSOR_EXTENSIONS = ['cur', 'ani', 'zip'];
 const SUPPORTED_EXTENSIONS = [...SUPPORTED_IMAGE_EXTENSIONS, ...SUPPORTED_CURSOR_EXTENSIONS];
 
+const formatFileSize = (bytes: number) => {
+  if (bytes < 1024) return `${bytes} B`;
+  if (bytes < 1024 * 1024) return `${(bytes / 1024).toFixed(1)} KB`;
+  return `${(bytes / (1024 * 1024)).toFixed(1)} MB`;
+};
+
+// Label/value rows describing a browsed file, skipping details the backend could not read
+const describeBrowsedFile = (file: BrowsedCursorFile): Array<[string, string]> => {
+  const rows: Array<[string, string]> = [];
+  if (file.format) rows.push(['Format', file.format.toUpperCase()]);
+  if (file.width !== null && file.height !== null) {
+    rows.push(['Size', `${file.width} × ${file.height} px`]);
+  }
+  if (file.hotspot_x !== null && file.hotspot_y !== null) {
+    rows.push(['Hotspot', `${file.hotspot_x}, ${file.hotspot_y}`]);
+  }
+  if (file.format === 'ani' && file.frame_count !== null) {
+    rows.push(['Frames', String(file.frame_count)]);
+  }
+  rows.push(['File size', formatFileSize(file.file_size)]);
+  return rows;
+};
+
 export function BrowseModal({
   isOpen,
   onClose,
@@ -43,12 +67,18 @@ export function BrowseModal({
   clickPointItemId
 }: BrowseModalProps) {
   const [isDragging, setIsDragging] = useState(false);
+  // File picked through the native dialog, shown for confirmation before it is imported
+  const [browsedFile, setBrowsedFile] = useState<BrowsedCursorFile | null>(null);
   const inputRef = useRef<HTMLInputElement | null>(null);
 
   const handleClose = useCallback(() => {
     onClose?.();
   }, [onClose]);
 
+  useEffect(() => {
+    if (!isOpen) setBrowsedFile(null);
+  }, [isOpen]);
+
   // Helper helper to convert base64 to Blob
   const base64ToBlob = (base64: string, type: string) => {
     const binStr = atob(base64);
@@ -176,14 +206,21 @@ export function BrowseModal({
 
   const handleBrowseClick = useCallback(async () => {
     try {
-      const selectedPath = await invoke<string | null>(Commands.browseCursorFile);
-      if (selectedPath) {
-        await processFilePath(selectedPath);
+      const selected = await invoke<BrowsedCursorFile | null>(Commands.browseCursorFile);
+      if (selected) {
+        setBrowsedFile(selected);
       }
     } catch (error) {
       logger.error('Failed to browse files:', error);
     }
-  }, [processFilePath]);
+  }, []);
+
+  const handleImportBrowsed = useCallback(async () => {
+    if (!browsedFile) return;
+    const { path } = browsedFile;
+    setBrowsedFile(null);
+    await processFilePath(path);
+  }, [browsedFile, processFilePath]);
 
   // Only render if modal should be open. Placed after hooks to keep hook order stable.
   if (!isOpen) return null;
@@ -314,60 +351,89 @@ export function BrowseModal({
 
         {/* Body */}
         <CardContent className="pt-2 pb-8 flex-1 flex flex-col">
-          {/* Drop area with compact, focused design and accessibility */}
-          <label
-            className={`group flex flex-col items-center justify-center gap-2 rounded-lg border-2 border-dashed px-6 py-6 text-center transition-all min-h-[140px] w-full max-w-lg cursor-pointer mx-auto ${isDragging
-              ? 'border-primary bg-primary/10 scale-[1.02]'
-              : 'border-border bg-muted/30 hover:border-primary hover:bg-accent/50'
-              }`}
-            role="button"
-            tabIndex={0}
-            aria-label="File upload area. Click to browse for files or drag and drop cursor files here. Supports .cur, .ani, .svg, .png, .ico, .bmp, .jpg, .jpeg files."
-            aria-describedby="upload-description"
-            onDragOver={handleDragOver}
-            onDragEnter={handleDragEnter}
-            onDragLeave={handleDragLeave}
-            onDrop={handleDrop}
-            onKeyDown={handleKeyboardInteract}
-            onClick={(e) => {
-              e.preventDefault();
-              handleBrowseClick();
-            }}
-          >
-            <input
-              type="file"
-              className="hidden"
-              accept=".cur,.ani,.zip,.svg,.png,.ico,.bmp,.jpg,.jpeg"
-              onChange={handleFileChange}
-              aria-label="Hidden file input for cursor upload"
-              ref={inputRef}
-            />
-            <div className="flex h-10 w-10 items-center justify-center rounded-full bg-primary/10 text-primary group-hover:bg-primary/20 transition-colors">
-              <svg
-                xmlns="http://www.w3.org/2000/svg"
-                viewBox="0 0 24 24"
-                className="h-5 w-5"
-                aria-hidden="true"
-              >
-                <path
-                  d="M12 5v9m0-9-3 3m3-3 3 3M6 13v4.5A1.5 1.5 0 0 0 7.5 19h9A1.5 1.5 0 0 0 18 17.5V13"
-                  fill="none"
-                  stroke="currentColor"
-                  strokeWidth="1.6"
-                  strokeLinecap="round"
-                  strokeLinejoin="round"
-                />
-              </svg>
-            </div>
-            <div className="space-y-0.5" id="upload-description">
-              <p className="text-base font-medium text-foreground">
-                Click to browse files
-              </p>
-              <p className="text-sm text-muted-foreground">
-                or drag and drop here
+          {browsedFile ? (
+            <div className="w-full max-w-lg mx-auto rounded-lg border border-border bg-muted/30 px-6 py-5">
+              <p className="font-medium text-foreground text-sm break-all" title={browsedFile.path}>
+                {browsedFile.path.split(/[\\/]/).pop()}
               </p>
+              <dl className="mt-3 grid grid-cols-[auto_1fr] gap-x-4 gap-y-1 text-sm" aria-label="Selected file details">
+                {describeBrowsedFile(browsedFile).map(([label, value]) => (
+                  <React.Fragment key={label}>
+                    <dt className="text-muted-foreground">{label}</dt>
+                    <dd className="text-foreground">{value}</dd>
+                  </React.Fragment>
+                ))}
+              </dl>
+              {browsedFile.error && (
+                <p className="mt-3 text-sm text-muted-foreground">
+                  Details unavailable: {browsedFile.error}
+                </p>
+              )}
+              <div className="mt-4 flex justify-end gap-2">
+                <Button variant="outline" onClick={() => setBrowsedFile(null)}>
+                  Choose another
+                </Button>
+                <Button onClick={handleImportBrowsed}>
+                  Import
+                </Button>
+              </div>
             </div>
-          </label>
+          ) : (
+            /* Drop area with compact, focused design and accessibility */
+            <label
+              className={`group flex flex-col items-center justify-center gap-2 rounded-lg border-2 border-dashed px-6 py-6 text-center transition-all min-h-[140px] w-full max-w-lg cursor-pointer mx-auto ${isDragging
+                ? 'border-primary bg-primary/10 scale-[1.02]'
+                : 'border-border bg-muted/30 hover:border-primary hover:bg-accent/50'
+                }`}
+              role="button"
+              tabIndex={0}
+              aria-label="File upload area. Click to browse for files or drag and drop cursor files here. Supports .cur, .ani, .svg, .png, .ico, .bmp, .jpg, .jpeg files."
+              aria-describedby="upload-description"
+              onDragOver={handleDragOver}
+              onDragEnter={handleDragEnter}
+              onDragLeave={handleDragLeave}
+              onDrop={handleDrop}
+              onKeyDown={handleKeyboardInteract}
+              onClick={(e) => {
+                e.preventDefault();
+                handleBrowseClick();
+              }}
+            >
+              <input
+                type="file"
+                className="hidden"
+                accept=".cur,.ani,.zip,.svg,.png,.ico,.bmp,.jpg,.jpeg"
+                onChange={handleFileChange}
+                aria-label="Hidden file input for cursor upload"
+                ref={inputRef}
+              />
+              <div className="flex h-10 w-10 items-center justify-center rounded-full bg-primary/10 text-primary group-hover:bg-primary/20 transition-colors">
+                <svg
+                  xmlns="http://www.w3.org/2000/svg"
+                  viewBox="0 0 24 24"
+                  className="h-5 w-5"
+                  aria-hidden="true"
+                >
+                  <path
+                    d="M12 5v9m0-9-3 3m3-3 3 3M6 13v4.5A1.5 1.5 0 0 0 7.5 19h9A1.5 1.5 0 0 0 18 17.5V13"
+                    fill="none"
+                    stroke="currentColor"
+                    strokeWidth="1.6"
+                    strokeLinecap="round"
+                    strokeLinejoin="round"
+                  />
+                </svg>
+              </div>
+              <div className="space-y-0.5" id="upload-description">
+                <p className="text-base font-medium text-foreground">
+                  Click to browse files
+                </p>
+                <p className="text-sm text-muted-foreground">
+                  or drag and drop here
+                </p>
+              </div>
+            </label>
+          )}
           <div className="px-6 py-4 text-left">
             <p className="font-medium text-foreground mb-2 text-sm text-left">Supported file types:</p>
             <ul className="space-y-1 text-[14px] text-left">
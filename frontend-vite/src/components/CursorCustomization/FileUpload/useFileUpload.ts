@@ -71,8 +71,15 @@ export function useFileUpload() {
             data
           });
 
-          const displayName = (result && result.name) ? result.name : file.name;
-          showMessage(`Imported cursor pack ${displayName}`, 'success');
+          const displayName = (result && result.pack?.name) ? result.pack.name : file.name;
+          if (result && result.warnings.length > 0) {
+            showMessage(
+              `Imported cursor pack ${displayName}, skipping ${result.warnings.length} file(s)`,
+              'warning'
+            );
+          } else {
+            showMessage(`Imported cursor pack ${displayName}`, 'success');
+          }
 
           try {
             await loadLibraryCursors();
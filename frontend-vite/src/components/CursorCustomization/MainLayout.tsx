@@ -71,6 +71,7 @@ export function MainLayout({
     setShowActiveCursorsModal,
     setShowPackDetailsModal,
     closePackDetailsModal,
+    loadPackItemPreview,
     applyCursorPack
   } = actions.modal;
   const { setDraggingLib, handleDragEnd } = actions.dragDrop;
@@ -181,6 +182,7 @@ export function MainLayout({
         packFilePreviews={packFilePreviews}
         isApplyingPack={isApplyingPack}
         closePackDetailsModal={closePackDetailsModal}
+        loadPackItemPreview={loadPackItemPreview}
         applyCursorPack={applyCursorPack}
       />
     </ErrorBoundary>
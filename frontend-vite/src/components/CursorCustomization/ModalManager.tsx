@@ -37,6 +37,7 @@ interface ModalManagerProps {
   packFilePreviews?: Record<string, string>;
   isApplyingPack?: boolean;
   closePackDetailsModal: () => void;
+  loadPackItemPreview?: (fileName: string) => void | Promise<void>;
   applyCursorPack: (pack: LibraryCursor) => void | Promise<void>;
 }
 
@@ -63,6 +64,7 @@ export function ModalManager({
   packFilePreviews,
   isApplyingPack,
   closePackDetailsModal,
+  loadPackItemPreview,
   applyCursorPack
 }: ModalManagerProps) {
   // Create safe timer instance
@@ -147,6 +149,7 @@ export function ModalManager({
         previews={packFilePreviews}
         isApplying={isApplyingPack}
         onClose={closePackDetailsModal}
+        onRequestPreview={loadPackItemPreview}
         onApply={(pack) => applyCursorPack(pack)}
       />
     </>
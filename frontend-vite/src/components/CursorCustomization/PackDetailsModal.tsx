@@ -1,4 +1,4 @@
-import React, { useMemo } from 'react';
+import React, { useEffect, useMemo, useRef } from 'react';
 import { Button } from '@/components/ui/button';
 import { cn } from '@/lib/utils';
 import type { LibraryCursor } from '@/types/generated/LibraryCursor';
@@ -12,6 +12,54 @@ interface PackDetailsModalProps {
   isApplying?: boolean;
   onApply: (pack: LibraryCursor) => void;
   onClose: () => void;
+  /** Asks for the preview of a file once its card scrolls into view */
+  onRequestPreview?: (fileName: string) => void;
+}
+
+interface PackFileThumbnailProps {
+  fileName: string;
+  previewUrl?: string;
+  onRequestPreview?: (fileName: string) => void;
+}
+
+function PackFileThumbnail({ fileName, previewUrl, onRequestPreview }: PackFileThumbnailProps) {
+  const ref = useRef<HTMLDivElement>(null);
+
+  useEffect(() => {
+    const element = ref.current;
+    if (previewUrl || !onRequestPreview || !element) return;
+    if (typeof IntersectionObserver === 'undefined') {
+      onRequestPreview(fileName);
+      return;
+    }
+
+    const observer = new IntersectionObserver((entries) => {
+      if (entries.some((entry) => entry.isIntersecting)) {
+        observer.disconnect();
+        onRequestPreview(fileName);
+      }
+    });
+    observer.observe(element);
+    return () => observer.disconnect();
+  }, [fileName, previewUrl, onRequestPreview]);
+
+  return (
+    <div
+      ref={ref}
+      className="flex h-12 w-12 items-center justify-center overflow-hidden rounded-md border border-border/60 bg-muted/40"
+    >
+      {previewUrl ? (
+        <img
+          src={previewUrl}
+          alt={`Preview of ${fileName}`}
+          className="h-full w-full object-contain"
+          loading="lazy"
+        />
+      ) : (
+        <MousePointer2 className="h-5 w-5 text-muted-foreground" aria-hidden="true" />
+      )}
+    </div>
+  );
 }
 
 export function PackDetailsModal({
@@ -21,7 +69,8 @@ export function PackDetailsModal({
   loading = false,
   isApplying = false,
   onApply,
-  onClose
+  onClose,
+  onRequestPreview
 }: PackDetailsModalProps) {
   const packItems = pack?.pack_metadata?.items ?? [];
   const modeLabel = pack?.pack_metadata?.mode === 'simple' ? 'Simple Mode' : 'Advanced Mode';
@@ -114,18 +163,11 @@ export function PackDetailsModal({
               {cursorFiles.map((file) => (
                 <div key={file.id} className="rounded-lg bg-background/70 p-3 shadow-sm">
                   <div className="flex items-start gap-3">
-                    <div className="flex h-12 w-12 items-center justify-center overflow-hidden rounded-md border border-border/60 bg-muted/40">
-                      {file.previewUrl ? (
-                        <img
-                          src={file.previewUrl}
-                          alt={`Preview of ${file.fileName}`}
-                          className="h-full w-full object-contain"
-                          loading="lazy"
-                        />
-                      ) : (
-                        <MousePointer2 className="h-5 w-5 text-muted-foreground" aria-hidden="true" />
-                      )}
-                    </div>
+                    <PackFileThumbnail
+                      fileName={file.fileName}
+                      previewUrl={file.previewUrl}
+                      onRequestPreview={onRequestPreview}
+                    />
                     <div className="flex-1">
                       <div className="flex items-center justify-between gap-2">
                         <p className="text-sm font-medium text-card-foreground truncate">{file.fileName}</p>
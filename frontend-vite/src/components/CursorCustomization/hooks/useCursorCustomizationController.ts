@@ -1,11 +1,11 @@
-import { useCallback, useEffect, useMemo, useState } from 'react';
+import { useCallback, useEffect, useMemo, useRef, useState } from 'react';
 import type React from 'react';
 
 import { useApp } from '../../../context/AppContext';
 import { useMessage } from '../../../hooks/useMessage';
 import { useSafeAsync } from '../../../hooks/useSafeAsync';
 import { useAppStore } from '../../../store/useAppStore';
-import { Commands } from '../../../tauri/commands';
+import { Commands, invokeCommand } from '../../../tauri/commands';
 import { logger } from '../../../utils/logger';
 import type { CursorInfo } from '../../../types/generated/CursorInfo';
 import type { LibraryCursor } from '../../../types/generated/LibraryCursor';
@@ -73,6 +73,9 @@ export function useCursorCustomizationController() {
   const [packDetails, setPackDetails] = useState<LibraryCursor | null>(null);
   const [packDetailsLoading, setPackDetailsLoading] = useState(false);
   const [packFilePreviews, setPackFilePreviews] = useState<Record<string, string>>({});
+  // Archive of the open pack and the item previews already asked for, for lazy loading
+  const packArchivePathRef = useRef('');
+  const requestedPackPreviewsRef = useRef(new Set<string>());
   const [isApplyingPack, setIsApplyingPack] = useState(false);
 
 
@@ -247,6 +250,8 @@ export function useCursorCustomizationController() {
     setShowPackDetailsModal(true);
 
     const archivePath = resolvePackArchivePath(cursor);
+    packArchivePathRef.current = archivePath;
+    requestedPackPreviewsRef.current = new Set();
     if (!archivePath) {
       showMessage('Cursor pack file is missing or invalid.', 'error');
       setPackDetailsLoading(false);
@@ -297,28 +302,12 @@ export function useCursorCustomizationController() {
         errorType: 'error'
       });
 
+      // Without a cache, previews are loaded one item at a time as they scroll into view
       if (cachedResult.status === 'success') {
         const previewMap = cachedResult.value as Record<string, string>;
         setPackFilePreviews(Object.fromEntries(
           Object.entries(previewMap).map(([key, value]) => [key.toLowerCase(), value])
         ));
-        return;
-      }
-
-      const previewResult = await invokeWithFeedback(invoke, Commands.getCursorPackFilePreviews, {
-        args: { archive_path: archivePath },
-        logLabel: '[CursorCustomization] Failed to load pack previews:',
-        errorMessage: 'Failed to load pack previews',
-        errorType: 'error'
-      });
-
-      if (previewResult.status === 'success') {
-        const values = previewResult.value as { file_name: string; data_url: string }[];
-        const previewMap = values.reduce<Record<string, string>>((acc, preview) => {
-          acc[preview.file_name.toLowerCase()] = preview.data_url;
-          return acc;
-        }, {});
-        setPackFilePreviews(previewMap);
       }
     })();
 
@@ -326,12 +315,31 @@ export function useCursorCustomizationController() {
     setPackDetailsLoading(false);
   }, [invoke, resolvePackArchivePath, showMessage]);
 
+  const loadPackItemPreview = useCallback(async (fileName: string) => {
+    const key = fileName.toLowerCase();
+    const archivePath = packArchivePathRef.current;
+    if (!archivePath || requestedPackPreviewsRef.current.has(key)) return;
+    requestedPackPreviewsRef.current.add(key);
+
+    try {
+      const preview = await invokeCommand(invoke, Commands.getPackItemPreview, {
+        archive_path: archivePath,
+        file_name: fileName
+      });
+      if (packArchivePathRef.current !== archivePath) return;
+      setPackFilePreviews((prev) => (prev[key] ? prev : { ...prev, [key]: preview.data_url }));
+    } catch (error) {
+      logger.warn('[CursorCustomization] Failed to load pack item preview:', fileName, error);
+    }
+  }, [invoke]);
+
   const closePackDetailsModal = useCallback(() => {
     setShowPackDetailsModal(false);
     setPackDetails(null);
     setPackDetailsLoading(false);
     setIsApplyingPack(false);
     setPackFilePreviews({});
+    packArchivePathRef.current = '';
   }, []);
 
   const determinePackMode = useCallback((pack: LibraryCursor): 'simple' | 'advanced' | null => {
@@ -498,6 +506,7 @@ export function useCursorCustomizationController() {
         },
         setShowActiveCursorsModal,
         closePackDetailsModal,
+        loadPackItemPreview,
         applyCursorPack: handleApplyCursorPack
       },
       dragDrop: {
@@ -138,6 +138,7 @@ export interface ModalActions {
   setShowActiveCursorsModal: (show: boolean) => void;
   setShowPackDetailsModal: (show: boolean) => void;
   closePackDetailsModal: () => void;
+  loadPackItemPreview?: (fileName: string) => void | Promise<void>;
   applyCursorPack: (pack: LibraryCursor) => void | Promise<void>;
 }
 
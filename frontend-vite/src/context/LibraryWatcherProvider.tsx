@@ -21,21 +21,21 @@ export function LibraryWatcherProvider({ children }: { children: ReactNode }) {
         await invokeCommand(invoke, Commands.syncLibraryWithFolder);
 
         const unlistenAdd = await listenEvent<unknown>(listen, Events.libraryFileAdded, async () => {
+          // The backend has already synced the batch that triggered this
           try {
-            await invokeCommand(invoke, Commands.syncLibraryWithFolder);
             await loadLibraryCursors();
           } catch (error) {
-            logger.error('[LibraryWatcherProvider] Failed to sync after file add:', error);
+            logger.error('[LibraryWatcherProvider] Failed to reload after file add:', error);
           }
         });
         unlisteners.push(unlistenAdd);
 
         const unlistenRemove = await listenEvent<unknown>(listen, Events.libraryFileRemoved, async () => {
+          // The backend has already synced the batch that triggered this
           try {
-            await invokeCommand(invoke, Commands.syncLibraryWithFolder);
             await loadLibraryCursors();
           } catch (error) {
-            logger.error('[LibraryWatcherProvider] Failed to sync after file remove:', error);
+            logger.error('[LibraryWatcherProvider] Failed to reload after file remove:', error);
           }
         });
         unlisteners.push(unlistenRemove);
@@ -8,6 +8,15 @@ import type { DefaultCursorStyle } from '../../types/generated/DefaultCursorStyl
 import { mapCursorStatePayloadWithDefaults } from '../../tauri/mappers';
 import { invokeWithFeedback } from './invokeWithFeedback';
 
+const DEFAULT_CURSOR_STYLE_LABELS: Record<DefaultCursorStyle, string> = {
+    windows: 'Windows',
+    mac: 'Mac',
+    'windows-black': 'Windows Black',
+    'windows-inverted': 'Windows Inverted',
+    'windows-magnified': 'Magnified',
+    touch: 'Touch & Pen'
+};
+
 export interface SettingsOperations {
     setHotkey: (shortcut: string) => Promise<void>;
     setShortcutEnabled: (enabled: boolean) => Promise<void>;
@@ -119,10 +128,7 @@ export const createSettingsOperations = (
             rethrow: true
         });
         updateCursorState({ defaultCursorStyle: style });
-        showMessage(
-            style === 'mac' ? 'Default cursors set to Mac style' : 'Default cursors set to Windows style',
-            'success'
-        );
+        showMessage(`Default cursors set to ${DEFAULT_CURSOR_STYLE_LABELS[style]} style`, 'success');
     },
 
     resetAllSettings: async () => {
@@ -2,9 +2,13 @@
 import { StateCreator } from 'zustand';
 import type { ThemeMode } from '../../types/generated/ThemeMode';
 import type { DefaultCursorStyle } from '../../types/generated/DefaultCursorStyle';
+import type { PolicyLocks } from '../../types/generated/PolicyLocks';
+import type { EffectsConfig } from '../../types/generated/EffectsConfig';
+import type { CursorVisibility } from '../../types/generated/CursorVisibility';
 
 export interface CursorState {
   hidden: boolean;
+  visibility: CursorVisibility;
   shortcut: string | null;
   shortcutEnabled: boolean;
   cursorSize: number;
@@ -15,6 +19,8 @@ export interface CursorState {
   accentColor: string;
   themeMode: ThemeMode;
   defaultCursorStyle: DefaultCursorStyle;
+  policyLocks: PolicyLocks;
+  effects: EffectsConfig;
 }
 
 export interface CursorStateSlice {
@@ -25,6 +31,7 @@ export interface CursorStateSlice {
 
 export const defaultCursorState: CursorState = {
   hidden: false,
+  visibility: { state: 'visible' },
   shortcut: 'Ctrl+Shift+X',
   shortcutEnabled: false,
   cursorSize: 32,
@@ -34,7 +41,13 @@ export const defaultCursorState: CursorState = {
   cursorPaths: {},
   accentColor: '#7c3aed',
   themeMode: 'dark',
-  defaultCursorStyle: 'windows'
+  defaultCursorStyle: 'windows',
+  policyLocks: {
+    hide_disabled: false,
+    default_cursor_style_locked: false,
+    marketplace_disabled: false
+  },
+  effects: { enabled: [] }
 };
 
 export const createCursorStateSlice: StateCreator<
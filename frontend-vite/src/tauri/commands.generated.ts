@@ -2,16 +2,48 @@ export const Commands = {
   getStatus: 'get_status',
   toggleCursor: 'toggle_cursor',
   restoreCursor: 'restore_cursor',
+  restoreCursorRole: 'restore_cursor_role',
+  forceCursorRefresh: 'force_cursor_refresh',
+  getCursorCacheFlushEnabled: 'get_cursor_cache_flush_enabled',
+  setCursorCacheFlushEnabled: 'set_cursor_cache_flush_enabled',
   setHotkey: 'set_hotkey',
+  getHotkeyChordSettings: 'get_hotkey_chord_settings',
+  setHotkeyChordSettings: 'set_hotkey_chord_settings',
+  getHotkeyPressSettings: 'get_hotkey_press_settings',
+  setHotkeyPressSettings: 'set_hotkey_press_settings',
+  getSizeGestureSettings: 'get_size_gesture_settings',
+  setSizeGestureSettings: 'set_size_gesture_settings',
+  getPointerControlSettings: 'get_pointer_control_settings',
+  setPointerControlSettings: 'set_pointer_control_settings',
+  getHotCornerSettings: 'get_hot_corner_settings',
+  setHotCornerSettings: 'set_hot_corner_settings',
+  getMonitorSchemeSettings: 'get_monitor_scheme_settings',
+  setMonitorSchemeSettings: 'set_monitor_scheme_settings',
   setHotkeyTemporarilyEnabled: 'set_hotkey_temporarily_enabled',
   setShortcutEnabled: 'set_shortcut_enabled',
   setMinimizeToTray: 'set_minimize_to_tray',
   setRunOnStartup: 'set_run_on_startup',
   setAccentColor: 'set_accent_color',
+  getSvgResourceSettings: 'get_svg_resource_settings',
+  setSvgLoadSystemFonts: 'set_svg_load_system_fonts',
+  getCursorVariantSettings: 'get_cursor_variant_settings',
+  setCursorVariantPregeneration: 'set_cursor_variant_pregeneration',
+  getAdaptiveBrightnessSettings: 'get_adaptive_brightness_settings',
+  setAdaptiveBrightness: 'set_adaptive_brightness',
+  takeConfigValidationReport: 'take_config_validation_report',
   resetAllSettings: 'reset_all_settings',
   resetWindowSizeToDefault: 'reset_window_size_to_default',
+  getLastSettingsPage: 'get_last_settings_page',
+  setLastSettingsPage: 'set_last_settings_page',
   setDefaultCursorStyle: 'set_default_cursor_style',
+  getLogonServiceStatus: 'get_logon_service_status',
+  installLogonService: 'install_logon_service',
+  uninstallLogonService: 'uninstall_logon_service',
   quitApp: 'quit_app',
+  cancelOperation: 'cancel_operation',
+  getPendingJobs: 'get_pending_jobs',
+  resumePendingJob: 'resume_pending_job',
+  discardPendingJobs: 'discard_pending_jobs',
   setThemeMode: 'set_theme_mode',
   getThemeMode: 'get_theme_mode',
   switchCustomizationMode: 'switch_customization_mode',
@@ -22,8 +54,46 @@ export const Commands = {
   getLibraryCursorsFolder: 'get_library_cursors_folder',
   showLibraryCursorsFolder: 'show_library_cursors_folder',
   readFileContent: 'read_file_content',
+  runCacheGc: 'run_cache_gc',
+  getCacheQuotaMb: 'get_cache_quota_mb',
+  setCacheQuotaMb: 'set_cache_quota_mb',
+  getStatusFile: 'get_status_file',
+  setStatusFile: 'set_status_file',
+  getHealthEndpoint: 'get_health_endpoint',
+  setHealthEndpoint: 'set_health_endpoint',
+  getDecodeSandbox: 'get_decode_sandbox',
+  setDecodeSandbox: 'set_decode_sandbox',
+  getHideRoles: 'get_hide_roles',
+  setHideRoles: 'set_hide_roles',
+  getMemoryPolicy: 'get_memory_policy',
+  setMemoryPolicy: 'set_memory_policy',
+  getConversionOutputSettings: 'get_conversion_output_settings',
+  setConversionOutputSettings: 'set_conversion_output_settings',
+  getCursorsStorageDir: 'get_cursors_storage_dir',
+  setCursorsStorageDir: 'set_cursors_storage_dir',
+  pickScreenColor: 'pick_screen_color',
+  getCrashReportSettings: 'get_crash_report_settings',
+  setCrashReportingEnabled: 'set_crash_reporting_enabled',
+  getPendingCrashReports: 'get_pending_crash_reports',
+  showCrashReportsFolder: 'show_crash_reports_folder',
+  dismissCrashReports: 'dismiss_crash_reports',
+  exportLogs: 'export_logs',
+  clearLogs: 'clear_logs',
+  localizeError: 'localize_error',
+  getUsageStats: 'get_usage_stats',
+  setUsageStatsEnabled: 'set_usage_stats_enabled',
+  purgeUsageStats: 'purge_usage_stats',
+  previewUninstallCleanup: 'preview_uninstall_cleanup',
+  runUninstallCleanup: 'run_uninstall_cleanup',
+  exportDiagnosticsBundle: 'export_diagnostics_bundle',
+  runBenchmarks: 'run_benchmarks',
   saveEffectsConfig: 'save_effects_config',
   loadEffectsConfig: 'load_effects_config',
+  getReducedMotionStatus: 'get_reduced_motion_status',
+  setReducedMotionFollowSystem: 'set_reduced_motion_follow_system',
+  getEffectQuality: 'get_effect_quality',
+  setEffectQuality: 'set_effect_quality',
+  reportEffectFrameTimes: 'report_effect_frame_times',
   startLibraryFolderWatcher: 'start_library_folder_watcher',
   stopLibraryFolderWatcher: 'stop_library_folder_watcher',
   syncLibraryWithFolder: 'sync_library_with_folder',
@@ -31,21 +101,35 @@ export const Commands = {
   getCustomCursors: 'get_custom_cursors',
   getCursorImage: 'get_cursor_image',
   getSystemCursorPreview: 'get_system_cursor_preview',
+  getDefaultCursorPreviews: 'get_default_cursor_previews',
+  getAppliedCursorPreviews: 'get_applied_cursor_previews',
   browseCursorFile: 'browse_cursor_file',
   getCursorWithClickPoint: 'get_cursor_with_click_point',
   renderCursorImagePreview: 'render_cursor_image_preview',
+  beginPreviewSession: 'begin_preview_session',
+  updatePreview: 'update_preview',
+  endPreviewSession: 'end_preview_session',
   readCursorFileAsDataUrl: 'read_cursor_file_as_data_url',
   readCursorFileAsBytes: 'read_cursor_file_as_bytes',
   convertBytesToDataUrl: 'convert_bytes_to_data_url',
   convertImageToCurWithClickPoint: 'convert_image_to_cur_with_click_point',
+  convertDirectoryToCur: 'convert_directory_to_cur',
+  convertImagesToAni: 'convert_images_to_ani',
   addUploadedCursorToLibrary: 'add_uploaded_cursor_to_library',
+  addUploadedCursorsToLibrary: 'add_uploaded_cursors_to_library',
   addUploadedImageWithClickPointToLibrary: 'add_uploaded_image_with_click_point_to_library',
   updateLibraryCursorClickPoint: 'update_library_cursor_click_point',
+  startHotspotCalibration: 'start_hotspot_calibration',
+  recordCalibrationClick: 'record_calibration_click',
+  finishHotspotCalibration: 'finish_hotspot_calibration',
+  cancelHotspotCalibration: 'cancel_hotspot_calibration',
   setCursorImage: 'set_cursor_image',
   setAllCursors: 'set_all_cursors',
   setAllCursorsWithSize: 'set_all_cursors_with_size',
   setSingleCursorWithSize: 'set_single_cursor_with_size',
   setMultipleCursorsWithSize: 'set_multiple_cursors_with_size',
+  getRoleGroups: 'get_role_groups',
+  applyCursorToGroup: 'apply_cursor_to_group',
   setCursorSize: 'set_cursor_size',
   setCursorsToWindowsDefaults: 'set_cursors_to_windows_defaults',
   loadAppDefaultCursors: 'load_app_default_cursors',
@@ -55,19 +139,52 @@ export const Commands = {
   exportActiveCursorPack: 'export_active_cursor_pack',
   importCursorPack: 'import_cursor_pack',
   applyCursorPack: 'apply_cursor_pack',
+  startPackTrial: 'start_pack_trial',
+  commitPackTrial: 'commit_pack_trial',
+  cancelPackTrial: 'cancel_pack_trial',
+  schedulePackApply: 'schedule_pack_apply',
+  getScheduledPackApplies: 'get_scheduled_pack_applies',
+  cancelScheduledPackApply: 'cancel_scheduled_pack_apply',
   getCursorPackManifest: 'get_cursor_pack_manifest',
+  getPackDetails: 'get_pack_details',
+  ratePack: 'rate_pack',
+  setPackNotes: 'set_pack_notes',
+  setPackSoundsEnabled: 'set_pack_sounds_enabled',
+  previewPackSound: 'preview_pack_sound',
+  verifyPackIntegrity: 'verify_pack_integrity',
+  importCursorPackFromUrl: 'import_cursor_pack_from_url',
+  checkPackUpdates: 'check_pack_updates',
+  updateAllPacks: 'update_all_packs',
+  importCursorfxTheme: 'import_cursorfx_theme',
+  importCape: 'import_cape',
+  detectMigrationSources: 'detect_migration_sources',
+  runMigration: 'run_migration',
   getCachedPackPreviews: 'get_cached_pack_previews',
   getCursorPackFilePreviews: 'get_cursor_pack_file_previews',
+  getPackItemPreview: 'get_pack_item_preview',
   getLibraryCursors: 'get_library_cursors',
+  getLibraryPage: 'get_library_page',
   reorderLibraryCursors: 'reorder_library_cursors',
+  getLibraryCollections: 'get_library_collections',
+  createCollection: 'create_collection',
+  moveCursorToCollection: 'move_cursor_to_collection',
+  reorderCollectionCursors: 'reorder_collection_cursors',
   exportLibraryCursors: 'export_library_cursors',
   addCursorToLibrary: 'add_cursor_to_library',
   updateCursorInLibrary: 'update_cursor_in_library',
   removeCursorFromLibrary: 'remove_cursor_from_library',
   renameCursorInLibrary: 'rename_cursor_in_library',
+  updateCursorMetadata: 'update_cursor_metadata',
+  setCursorSourceLink: 'set_cursor_source_link',
+  setCursorHotReload: 'set_cursor_hot_reload',
+  renderDesktopContextPreview: 'render_desktop_context_preview',
   getLibraryCursorPreview: 'get_library_cursor_preview',
   getAniPreviewData: 'get_ani_preview_data',
+  exportAniAsGif: 'export_ani_as_gif',
+  exportAniAsApng: 'export_ani_as_apng',
+  downscaleLibraryAni: 'downscale_library_ani',
   resetLibrary: 'reset_library',
+  repairDefaultLibrary: 'repair_default_library',
 } as const;
 
 export type CommandName = (typeof Commands)[keyof typeof Commands];
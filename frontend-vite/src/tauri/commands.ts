@@ -1,13 +1,80 @@
 import type { AniPreviewData } from '../types/generated/AniPreviewData';
+import type { CacheGcReport } from '../types/generated/CacheGcReport';
 import type { CursorClickPointInfo } from '../types/generated/CursorClickPointInfo';
 import type { CursorInfo } from '../types/generated/CursorInfo';
+import type { CursorMetadataUpdate } from '../types/generated/CursorMetadataUpdate';
+import type { CursorRoleRestoredPayload } from '../types/generated/CursorRoleRestoredPayload';
 import type { CursorStatePayload } from '../types/generated/CursorStatePayload';
 import type { EffectsConfig } from '../types/generated/EffectsConfig';
+import type { HotkeyChordSettings } from '../types/generated/HotkeyChordSettings';
+import type { HotspotCalibrationResult } from '../types/generated/HotspotCalibrationResult';
+import type { HotspotCalibrationSession } from '../types/generated/HotspotCalibrationSession';
 import type { DefaultCursorStyle } from '../types/generated/DefaultCursorStyle';
+import type { DefaultsInitReport } from '../types/generated/DefaultsInitReport';
 import type { ThemeMode } from '../types/generated/ThemeMode';
 import type { CustomizationMode } from '../types/generated/CustomizationMode';
 import type { LibraryCursor } from '../types/generated/LibraryCursor';
+import type { LibraryFilter } from '../types/generated/LibraryFilter';
+import type { LibraryPage } from '../types/generated/LibraryPage';
+import type { LibrarySort } from '../types/generated/LibrarySort';
+import type { LogonServiceStatus } from '../types/generated/LogonServiceStatus';
 import type { PackFilePreview } from '../types/generated/PackFilePreview';
+import type { PackTrialStatus } from '../types/generated/PackTrialStatus';
+import type { PreviewParams } from '../types/generated/PreviewParams';
+import type { PreviewSessionInfo } from '../types/generated/PreviewSessionInfo';
+import type { ReducedMotionStatus } from '../types/generated/ReducedMotionStatus';
+import type { SvgResourceSettings } from '../types/generated/SvgResourceSettings';
+import type { CursorVariantSettings } from '../types/generated/CursorVariantSettings';
+import type { StatusFileSettings } from '../types/generated/StatusFileSettings';
+import type { CrashReport } from '../types/generated/CrashReport';
+import type { CrashReportSettings } from '../types/generated/CrashReportSettings';
+import type { RoleGroup } from '../types/generated/RoleGroup';
+import type { RoleGroupInfo } from '../types/generated/RoleGroupInfo';
+import type { AdaptiveBrightnessSource } from '../types/generated/AdaptiveBrightnessSource';
+import type { AdaptiveBrightnessSettings } from '../types/generated/AdaptiveBrightnessSettings';
+import type { DefaultCursorPreview } from '../types/generated/DefaultCursorPreview';
+import type { HotkeyPressSettings } from '../types/generated/HotkeyPressSettings';
+import type { PackDetails } from '../types/generated/PackDetails';
+import type { PackChangelogEntry } from '../types/generated/PackChangelogEntry';
+import type { UploadedCursorFile } from '../types/generated/UploadedCursorFile';
+import type { BatchUploadReport } from '../types/generated/BatchUploadReport';
+import type { BrowsedCursorFile } from '../types/generated/BrowsedCursorFile';
+import type { DirectoryConversionReport } from '../types/generated/DirectoryConversionReport';
+import type { SizeGestureSettings } from '../types/generated/SizeGestureSettings';
+import type { PickedScreenColor } from '../types/generated/PickedScreenColor';
+import type { ConfigValidationReport } from '../types/generated/ConfigValidationReport';
+import type { PackIntegrityReport } from '../types/generated/PackIntegrityReport';
+import type { CursorSourceLink } from '../types/generated/CursorSourceLink';
+import type { LibraryCollection } from '../types/generated/LibraryCollection';
+import type { EffectQuality } from '../types/generated/EffectQuality';
+import type { EffectQualityStatus } from '../types/generated/EffectQualityStatus';
+import type { CursorThemeImport } from '../types/generated/CursorThemeImport';
+import type { PackImport } from '../types/generated/PackImport';
+import type { AppliedCursorPreview } from '../types/generated/AppliedCursorPreview';
+import type { UsageStats } from '../types/generated/UsageStats';
+import type { UsageStatsSettings } from '../types/generated/UsageStatsSettings';
+import type { PointerControlSettings } from '../types/generated/PointerControlSettings';
+import type { PendingJob } from '../types/generated/PendingJob';
+import type { LocalizedError } from '../types/generated/LocalizedError';
+import type { HotCornerSettings } from '../types/generated/HotCornerSettings';
+import type { BenchmarkReport } from '../types/generated/BenchmarkReport';
+import type { MonitorSchemeSettings } from '../types/generated/MonitorSchemeSettings';
+import type { ScheduledApply } from '../types/generated/ScheduledApply';
+import type { HealthEndpointSettings } from '../types/generated/HealthEndpointSettings';
+import type { MigrationSource } from '../types/generated/MigrationSource';
+import type { MigrationReport } from '../types/generated/MigrationReport';
+import type { DecodeSandboxSettings } from '../types/generated/DecodeSandboxSettings';
+import type { HideRolesSettings } from '../types/generated/HideRolesSettings';
+import type { MemoryPolicySettings } from '../types/generated/MemoryPolicySettings';
+import type { MemoryPolicy } from '../types/generated/MemoryPolicy';
+import type { CleanupItem } from '../types/generated/CleanupItem';
+import type { UninstallCleanupReport } from '../types/generated/UninstallCleanupReport';
+import type { PackSoundEvent } from '../types/generated/PackSoundEvent';
+import type { StorageLocationSettings } from '../types/generated/StorageLocationSettings';
+import type { StorageRelocationReport } from '../types/generated/StorageRelocationReport';
+import type { PackUpdateStatus } from '../types/generated/PackUpdateStatus';
+import type { PackUpdateReport } from '../types/generated/PackUpdateReport';
+import type { ConversionOutputSettings } from '../types/generated/ConversionOutputSettings';
 
 import { Commands as GeneratedCommands } from './commands.generated';
 import type { CommandName } from './commands.generated';
@@ -19,10 +86,26 @@ export type CommandArgsMap = {
   [Commands.getStatus]: undefined;
   [Commands.toggleCursor]: undefined;
   [Commands.restoreCursor]: undefined;
+  [Commands.restoreCursorRole]: { cursor_type: string };
+  [Commands.forceCursorRefresh]: undefined;
+  [Commands.getCursorCacheFlushEnabled]: undefined;
+  [Commands.setCursorCacheFlushEnabled]: { enable: boolean };
 
   [Commands.getThemeMode]: undefined;
 
   [Commands.setHotkey]: { shortcut: string };
+  [Commands.getHotkeyChordSettings]: undefined;
+  [Commands.setHotkeyChordSettings]: { settings: HotkeyChordSettings };
+  [Commands.getHotkeyPressSettings]: undefined;
+  [Commands.setHotkeyPressSettings]: { settings: HotkeyPressSettings };
+  [Commands.getSizeGestureSettings]: undefined;
+  [Commands.setSizeGestureSettings]: { settings: SizeGestureSettings };
+  [Commands.getPointerControlSettings]: undefined;
+  [Commands.setPointerControlSettings]: { settings: PointerControlSettings };
+  [Commands.getHotCornerSettings]: undefined;
+  [Commands.setHotCornerSettings]: { settings: HotCornerSettings };
+  [Commands.getMonitorSchemeSettings]: undefined;
+  [Commands.setMonitorSchemeSettings]: { settings: MonitorSchemeSettings };
   [Commands.setHotkeyTemporarilyEnabled]: { enabled: boolean };
   [Commands.setShortcutEnabled]: { enabled: boolean };
 
@@ -30,10 +113,26 @@ export type CommandArgsMap = {
   [Commands.setRunOnStartup]: { enable: boolean };
   [Commands.setCursorSize]: { size: number };
   [Commands.setDefaultCursorStyle]: { style: DefaultCursorStyle };
+  [Commands.getSvgResourceSettings]: undefined;
+  [Commands.setSvgLoadSystemFonts]: { enabled: boolean };
+  [Commands.getCursorVariantSettings]: undefined;
+  [Commands.setCursorVariantPregeneration]: { enabled: boolean };
+  [Commands.getAdaptiveBrightnessSettings]: undefined;
+  [Commands.setAdaptiveBrightness]: { source: AdaptiveBrightnessSource; strength_percent: number };
+  [Commands.takeConfigValidationReport]: undefined;
   [Commands.resetAllSettings]: undefined;
+  [Commands.getLogonServiceStatus]: undefined;
+  [Commands.installLogonService]: undefined;
+  [Commands.uninstallLogonService]: undefined;
   [Commands.resetWindowSizeToDefault]: undefined;
+  [Commands.getLastSettingsPage]: undefined;
+  [Commands.setLastSettingsPage]: { page: string | null };
 
   [Commands.quitApp]: undefined;
+  [Commands.cancelOperation]: { op_id: string };
+  [Commands.getPendingJobs]: undefined;
+  [Commands.resumePendingJob]: { id: string };
+  [Commands.discardPendingJobs]: undefined;
 
   [Commands.setAccentColor]: { color: string };
   [Commands.setThemeMode]: { theme_mode: ThemeMode };
@@ -52,11 +151,18 @@ export type CommandArgsMap = {
   [Commands.setAllCursors]: { image_path: string };
   [Commands.setAllCursorsWithSize]: { image_path: string; size: number };
   [Commands.setMultipleCursorsWithSize]: { cursor_names: string[]; image_path: string; size: number };
+  [Commands.getRoleGroups]: undefined;
+  [Commands.applyCursorToGroup]: { group: RoleGroup; image_path: string };
 
   [Commands.setCursorsToWindowsDefaults]: undefined;
   [Commands.loadAppDefaultCursors]: undefined;
   [Commands.deleteCustomCursor]: { cursor_name: string };
-  [Commands.exportActiveCursorPack]: { pack_name?: string };
+  [Commands.exportActiveCursorPack]: {
+    pack_name?: string;
+    description?: string | null;
+    changelog?: PackChangelogEntry[] | null;
+    op_id?: string | null;
+  };
 
   [Commands.readCursorFileAsDataUrl]: { file_path: string };
 
@@ -69,6 +175,9 @@ export type CommandArgsMap = {
   [Commands.getCursorWithClickPoint]: { file_path: string };
 
   [Commands.renderCursorImagePreview]: { file_path: string };
+  [Commands.beginPreviewSession]: { path: string };
+  [Commands.updatePreview]: { session_id: number; params: PreviewParams };
+  [Commands.endPreviewSession]: { session_id: number };
 
   [Commands.convertImageToCurWithClickPoint]: {
     input_path: string;
@@ -79,6 +188,20 @@ export type CommandArgsMap = {
     offset_x: number;
     offset_y: number;
   };
+  [Commands.convertDirectoryToCur]: {
+    input_dir: string;
+    output_dir: string;
+    size?: number | null;
+    click_point_x?: number | null;
+    click_point_y?: number | null;
+  };
+  [Commands.convertImagesToAni]: {
+    input_paths: string[];
+    size: number;
+    click_point_x: number;
+    click_point_y: number;
+    delays_ms: number[];
+  };
 
   [Commands.saveCursorFile]: { filename: string; data: number[] };
   [Commands.saveTempCursorFile]: { filename: string; data: number[] };
@@ -87,12 +210,64 @@ export type CommandArgsMap = {
 
   [Commands.saveEffectsConfig]: { config: EffectsConfig };
   [Commands.loadEffectsConfig]: undefined;
+  [Commands.getReducedMotionStatus]: undefined;
+  [Commands.setReducedMotionFollowSystem]: { follow_system: boolean };
+  [Commands.getEffectQuality]: undefined;
+  [Commands.setEffectQuality]: { quality: EffectQuality };
+  [Commands.reportEffectFrameTimes]: { frame_ms: number[] };
 
   [Commands.getLibraryCursors]: undefined;
+  [Commands.getLibraryPage]: {
+    offset: number;
+    limit: number;
+    sort?: LibrarySort;
+    filter?: LibraryFilter;
+  };
   [Commands.showLibraryCursorsFolder]: undefined;
+  [Commands.runCacheGc]: undefined;
+  [Commands.getCacheQuotaMb]: undefined;
+  [Commands.setCacheQuotaMb]: { quota_mb: number };
+  [Commands.getStatusFile]: undefined;
+  [Commands.setStatusFile]: { path: string | null; enabled: boolean };
+  [Commands.getHealthEndpoint]: undefined;
+  [Commands.setHealthEndpoint]: { enabled: boolean; port?: number };
+  [Commands.getDecodeSandbox]: undefined;
+  [Commands.setDecodeSandbox]: { enabled: boolean };
+  [Commands.getHideRoles]: undefined;
+  [Commands.setHideRoles]: { strict: boolean; visible_roles: string[] };
+  [Commands.getMemoryPolicy]: undefined;
+  [Commands.setMemoryPolicy]: { policy: MemoryPolicy };
+  [Commands.getConversionOutputSettings]: undefined;
+  [Commands.setConversionOutputSettings]: { output_dir?: string | null; name_template: string };
+  [Commands.getCursorsStorageDir]: undefined;
+  [Commands.setCursorsStorageDir]: { path: string; op_id?: string | null };
+  [Commands.pickScreenColor]: undefined;
+  [Commands.getCrashReportSettings]: undefined;
+  [Commands.setCrashReportingEnabled]: { enabled: boolean };
+  [Commands.getPendingCrashReports]: undefined;
+  [Commands.showCrashReportsFolder]: undefined;
+  [Commands.dismissCrashReports]: undefined;
+  [Commands.exportLogs]: { path: string; since?: string | null };
+  [Commands.clearLogs]: undefined;
+  [Commands.localizeError]: { message: string; locale?: string | null };
+  [Commands.getUsageStats]: undefined;
+  [Commands.setUsageStatsEnabled]: { enabled: boolean };
+  [Commands.purgeUsageStats]: undefined;
+  [Commands.previewUninstallCleanup]: undefined;
+  [Commands.runUninstallCleanup]: { locations?: string[] | null };
+  [Commands.exportDiagnosticsBundle]: { path: string; includeCursors?: boolean };
+  [Commands.runBenchmarks]: undefined;
   [Commands.reorderLibraryCursors]: { order: string[] };
+  [Commands.getLibraryCollections]: undefined;
+  [Commands.createCollection]: { name: string; parent_id?: string | null };
+  [Commands.moveCursorToCollection]: { cursor_id: string; collection_id?: string | null; position?: number | null };
+  [Commands.reorderCollectionCursors]: { collection_id: string; order: string[] };
   [Commands.removeCursorFromLibrary]: { id: string };
   [Commands.renameCursorInLibrary]: { id: string; new_name: string };
+  [Commands.updateCursorMetadata]: { id: string; fields: CursorMetadataUpdate };
+  [Commands.setCursorSourceLink]: { id: string; link: CursorSourceLink | null };
+  [Commands.setCursorHotReload]: { id: string; enabled: boolean };
+  [Commands.renderDesktopContextPreview]: { path: string; background?: string | null };
   [Commands.setSingleCursorWithSize]: { cursor_name: string; image_path: string; size: number };
 
   [Commands.addCursorToLibrary]: {
@@ -125,27 +300,80 @@ export type CommandArgsMap = {
     offset_y: number;
   };
   [Commands.updateLibraryCursorClickPoint]: { id: string; click_point_x: number; click_point_y: number };
+  [Commands.startHotspotCalibration]: { cursor_name: string };
+  [Commands.recordCalibrationClick]: {
+    target_x: number;
+    target_y: number;
+    click_x: number;
+    click_y: number;
+  };
+  [Commands.finishHotspotCalibration]: undefined;
+  [Commands.cancelHotspotCalibration]: undefined;
 
   [Commands.addUploadedCursorToLibrary]: { filename: string; data: number[] };
+  [Commands.addUploadedCursorsToLibrary]: { files: UploadedCursorFile[]; op_id?: string | null };
 
   [Commands.getLibraryCursorPreview]: { file_path: string; filePath?: string };
   [Commands.getSystemCursorPreview]: { cursor_name: string; cursorName?: string };
+  [Commands.getDefaultCursorPreviews]: { style: DefaultCursorStyle };
+  [Commands.getAppliedCursorPreviews]: undefined;
   [Commands.getAniPreviewData]: { file_path: string; filePath?: string };
+  [Commands.exportAniAsGif]: { id: string; path: string; scale?: number };
+  [Commands.exportAniAsApng]: { id: string; path: string; scale?: number };
+  [Commands.downscaleLibraryAni]: { id: string; size?: number };
   [Commands.resetLibrary]: undefined;
+  [Commands.repairDefaultLibrary]: undefined;
 
   [Commands.getCursorPackManifest]: { archive_path: string };
-  [Commands.getCursorPackFilePreviews]: { archive_path: string };
+  [Commands.getPackDetails]: { id: string };
+  [Commands.ratePack]: { id: string; stars?: number | null };
+  [Commands.setPackNotes]: { id: string; notes?: string | null };
+  [Commands.setPackSoundsEnabled]: { id: string; enabled: boolean };
+  [Commands.previewPackSound]: { id: string; event: PackSoundEvent };
+  [Commands.verifyPackIntegrity]: { id: string };
+  [Commands.importCursorPack]: { filename: string; data: number[]; op_id?: string | null; lenient?: boolean | null };
+  [Commands.importCursorPackFromUrl]: { url: string; op_id?: string | null };
+  [Commands.checkPackUpdates]: { op_id?: string | null };
+  [Commands.updateAllPacks]: { op_id?: string | null };
+  [Commands.importCursorfxTheme]: { filename: string; data: number[]; op_id?: string | null };
+  [Commands.importCape]: { filename: string; data: number[]; op_id?: string | null };
+  [Commands.detectMigrationSources]: undefined;
+  [Commands.runMigration]: { source: string; op_id?: string | null };
+  [Commands.getCursorPackFilePreviews]: { archive_path: string; op_id?: string | null };
+  [Commands.getPackItemPreview]: { archive_path: string; file_name: string };
   [Commands.getCachedPackPreviews]: { pack_id: string };
+  [Commands.startPackTrial]: { id: string; seconds: number };
+  [Commands.commitPackTrial]: undefined;
+  [Commands.cancelPackTrial]: undefined;
+  [Commands.schedulePackApply]: { id: string; run_at_ms: number };
+  [Commands.getScheduledPackApplies]: undefined;
+  [Commands.cancelScheduledPackApply]: { schedule_id: string };
 };
 
 export type CommandResultMap = {
   [Commands.getStatus]: CursorStatePayload;
   [Commands.toggleCursor]: CursorStatePayload;
   [Commands.restoreCursor]: CursorStatePayload;
+  [Commands.restoreCursorRole]: CursorRoleRestoredPayload;
+  [Commands.forceCursorRefresh]: void;
+  [Commands.getCursorCacheFlushEnabled]: boolean;
+  [Commands.setCursorCacheFlushEnabled]: boolean;
 
   [Commands.getThemeMode]: ThemeMode;
 
   [Commands.setHotkey]: CursorStatePayload;
+  [Commands.getHotkeyChordSettings]: HotkeyChordSettings;
+  [Commands.setHotkeyChordSettings]: HotkeyChordSettings;
+  [Commands.getHotkeyPressSettings]: HotkeyPressSettings;
+  [Commands.setHotkeyPressSettings]: HotkeyPressSettings;
+  [Commands.getSizeGestureSettings]: SizeGestureSettings;
+  [Commands.setSizeGestureSettings]: SizeGestureSettings;
+  [Commands.getPointerControlSettings]: PointerControlSettings;
+  [Commands.setPointerControlSettings]: PointerControlSettings;
+  [Commands.getHotCornerSettings]: HotCornerSettings;
+  [Commands.setHotCornerSettings]: HotCornerSettings;
+  [Commands.getMonitorSchemeSettings]: MonitorSchemeSettings;
+  [Commands.setMonitorSchemeSettings]: MonitorSchemeSettings;
   [Commands.setHotkeyTemporarilyEnabled]: void;
   [Commands.setShortcutEnabled]: CursorStatePayload;
 
@@ -153,10 +381,26 @@ export type CommandResultMap = {
   [Commands.setRunOnStartup]: CursorStatePayload;
   [Commands.setCursorSize]: CursorStatePayload;
   [Commands.setDefaultCursorStyle]: CursorStatePayload;
+  [Commands.getSvgResourceSettings]: SvgResourceSettings;
+  [Commands.setSvgLoadSystemFonts]: SvgResourceSettings;
+  [Commands.getCursorVariantSettings]: CursorVariantSettings;
+  [Commands.setCursorVariantPregeneration]: CursorVariantSettings;
+  [Commands.getAdaptiveBrightnessSettings]: AdaptiveBrightnessSettings;
+  [Commands.setAdaptiveBrightness]: AdaptiveBrightnessSettings;
+  [Commands.takeConfigValidationReport]: ConfigValidationReport | null;
   [Commands.resetAllSettings]: CursorStatePayload;
+  [Commands.getLogonServiceStatus]: LogonServiceStatus;
+  [Commands.installLogonService]: LogonServiceStatus;
+  [Commands.uninstallLogonService]: LogonServiceStatus;
   [Commands.resetWindowSizeToDefault]: void;
+  [Commands.getLastSettingsPage]: string | null;
+  [Commands.setLastSettingsPage]: void;
 
   [Commands.quitApp]: void;
+  [Commands.cancelOperation]: boolean;
+  [Commands.getPendingJobs]: PendingJob[];
+  [Commands.resumePendingJob]: void;
+  [Commands.discardPendingJobs]: number;
 
   [Commands.setAccentColor]: CursorStatePayload;
   [Commands.setThemeMode]: CursorStatePayload;
@@ -175,6 +419,8 @@ export type CommandResultMap = {
   [Commands.setAllCursors]: CursorInfo[];
   [Commands.setAllCursorsWithSize]: CursorInfo[];
   [Commands.setMultipleCursorsWithSize]: CursorInfo[];
+  [Commands.getRoleGroups]: RoleGroupInfo[];
+  [Commands.applyCursorToGroup]: CursorInfo[];
 
   [Commands.setCursorsToWindowsDefaults]: CursorInfo[];
   [Commands.loadAppDefaultCursors]: CursorInfo[];
@@ -187,13 +433,18 @@ export type CommandResultMap = {
 
   [Commands.convertBytesToDataUrl]: string;
 
-  [Commands.browseCursorFile]: string | null;
+  [Commands.browseCursorFile]: BrowsedCursorFile | null;
 
   [Commands.getCursorWithClickPoint]: CursorClickPointInfo;
 
   [Commands.renderCursorImagePreview]: string;
+  [Commands.beginPreviewSession]: PreviewSessionInfo;
+  [Commands.updatePreview]: string;
+  [Commands.endPreviewSession]: void;
 
   [Commands.convertImageToCurWithClickPoint]: string;
+  [Commands.convertDirectoryToCur]: DirectoryConversionReport;
+  [Commands.convertImagesToAni]: string;
 
   [Commands.saveCursorFile]: string | null;
   [Commands.saveTempCursorFile]: string;
@@ -202,12 +453,59 @@ export type CommandResultMap = {
 
   [Commands.saveEffectsConfig]: void;
   [Commands.loadEffectsConfig]: EffectsConfig;
+  [Commands.getReducedMotionStatus]: ReducedMotionStatus;
+  [Commands.setReducedMotionFollowSystem]: ReducedMotionStatus;
+  [Commands.getEffectQuality]: EffectQualityStatus;
+  [Commands.setEffectQuality]: EffectQualityStatus;
+  [Commands.reportEffectFrameTimes]: EffectQualityStatus;
 
   [Commands.getLibraryCursors]: LibraryCursor[];
+  [Commands.getLibraryPage]: LibraryPage;
   [Commands.showLibraryCursorsFolder]: void;
+  [Commands.runCacheGc]: CacheGcReport;
+  [Commands.getCacheQuotaMb]: number;
+  [Commands.setCacheQuotaMb]: CacheGcReport;
+  [Commands.getStatusFile]: StatusFileSettings;
+  [Commands.setStatusFile]: StatusFileSettings;
+  [Commands.getHealthEndpoint]: HealthEndpointSettings;
+  [Commands.setHealthEndpoint]: HealthEndpointSettings;
+  [Commands.getDecodeSandbox]: DecodeSandboxSettings;
+  [Commands.setDecodeSandbox]: DecodeSandboxSettings;
+  [Commands.getHideRoles]: HideRolesSettings;
+  [Commands.setHideRoles]: HideRolesSettings;
+  [Commands.getMemoryPolicy]: MemoryPolicySettings;
+  [Commands.setMemoryPolicy]: MemoryPolicySettings;
+  [Commands.getConversionOutputSettings]: ConversionOutputSettings;
+  [Commands.setConversionOutputSettings]: ConversionOutputSettings;
+  [Commands.getCursorsStorageDir]: StorageLocationSettings;
+  [Commands.setCursorsStorageDir]: StorageRelocationReport;
+  [Commands.pickScreenColor]: PickedScreenColor;
+  [Commands.getCrashReportSettings]: CrashReportSettings;
+  [Commands.setCrashReportingEnabled]: CrashReportSettings;
+  [Commands.getPendingCrashReports]: CrashReport[];
+  [Commands.showCrashReportsFolder]: void;
+  [Commands.dismissCrashReports]: number;
+  [Commands.exportLogs]: number;
+  [Commands.clearLogs]: void;
+  [Commands.localizeError]: LocalizedError;
+  [Commands.getUsageStats]: UsageStats;
+  [Commands.setUsageStatsEnabled]: UsageStatsSettings;
+  [Commands.purgeUsageStats]: void;
+  [Commands.previewUninstallCleanup]: CleanupItem[];
+  [Commands.runUninstallCleanup]: UninstallCleanupReport;
+  [Commands.exportDiagnosticsBundle]: string[];
+  [Commands.runBenchmarks]: BenchmarkReport;
   [Commands.reorderLibraryCursors]: void;
+  [Commands.getLibraryCollections]: LibraryCollection[];
+  [Commands.createCollection]: LibraryCollection;
+  [Commands.moveCursorToCollection]: void;
+  [Commands.reorderCollectionCursors]: string[];
   [Commands.removeCursorFromLibrary]: void;
   [Commands.renameCursorInLibrary]: void;
+  [Commands.updateCursorMetadata]: LibraryCursor;
+  [Commands.setCursorSourceLink]: LibraryCursor;
+  [Commands.setCursorHotReload]: LibraryCursor;
+  [Commands.renderDesktopContextPreview]: string;
   [Commands.setSingleCursorWithSize]: CursorInfo;
 
   [Commands.addCursorToLibrary]: LibraryCursor;
@@ -220,16 +518,48 @@ export type CommandResultMap = {
 
   [Commands.addUploadedImageWithClickPointToLibrary]: LibraryCursor;
   [Commands.updateLibraryCursorClickPoint]: LibraryCursor;
+  [Commands.startHotspotCalibration]: HotspotCalibrationSession;
+  [Commands.recordCalibrationClick]: number;
+  [Commands.finishHotspotCalibration]: HotspotCalibrationResult;
+  [Commands.cancelHotspotCalibration]: void;
 
   [Commands.addUploadedCursorToLibrary]: LibraryCursor;
+  [Commands.addUploadedCursorsToLibrary]: BatchUploadReport;
 
   [Commands.getLibraryCursorPreview]: string;
   [Commands.getSystemCursorPreview]: string;
+  [Commands.getDefaultCursorPreviews]: DefaultCursorPreview[];
+  [Commands.getAppliedCursorPreviews]: AppliedCursorPreview[];
   [Commands.getAniPreviewData]: AniPreviewData;
+  [Commands.exportAniAsGif]: string;
+  [Commands.exportAniAsApng]: string;
+  [Commands.downscaleLibraryAni]: LibraryCursor;
   [Commands.resetLibrary]: void;
+  [Commands.repairDefaultLibrary]: DefaultsInitReport;
   [Commands.getCursorPackManifest]: CursorPackManifest;
+  [Commands.getPackDetails]: PackDetails;
+  [Commands.ratePack]: LibraryCursor;
+  [Commands.setPackNotes]: LibraryCursor;
+  [Commands.setPackSoundsEnabled]: LibraryCursor;
+  [Commands.previewPackSound]: void;
+  [Commands.verifyPackIntegrity]: PackIntegrityReport;
+  [Commands.importCursorPack]: PackImport;
+  [Commands.importCursorPackFromUrl]: LibraryCursor;
+  [Commands.checkPackUpdates]: PackUpdateStatus[];
+  [Commands.updateAllPacks]: PackUpdateReport;
+  [Commands.importCursorfxTheme]: CursorThemeImport;
+  [Commands.importCape]: CursorThemeImport;
+  [Commands.detectMigrationSources]: MigrationSource[];
+  [Commands.runMigration]: MigrationReport;
   [Commands.getCursorPackFilePreviews]: PackFilePreview[];
+  [Commands.getPackItemPreview]: PackFilePreview;
   [Commands.getCachedPackPreviews]: Record<string, string>;
+  [Commands.startPackTrial]: PackTrialStatus;
+  [Commands.commitPackTrial]: void;
+  [Commands.cancelPackTrial]: void;
+  [Commands.schedulePackApply]: ScheduledApply;
+  [Commands.getScheduledPackApplies]: ScheduledApply[];
+  [Commands.cancelScheduledPackApply]: ScheduledApply[];
 };
 
 type AssertAllCommandArgsMapped = CommandName extends keyof CommandArgsMap
@@ -6,6 +6,23 @@ export const Events = {
   showCloseConfirmation: 'show-close-confirmation',
   libraryFileAdded: 'library:file-added',
   libraryFileRemoved: 'library:file-removed',
+  libraryImportQueue: 'library:import-queue',
+  cursorRoleRestored: 'cursor-role-restored',
+  packTrialReverted: 'pack-trial-reverted',
+  defaultsInitReport: 'library:defaults-init-report',
+  cacheGcReport: 'cache-gc-report',
+  hotkeyChordHint: 'hotkey-chord-hint',
+  applyVerificationFailed: 'apply-verification-failed',
+  operationProgress: 'operation-progress',
+  operationCancelled: 'operation-cancelled',
+  cursorSizeGesture: 'cursor-size-gesture',
+  configValidationFailed: 'config-validation-failed',
+  cursorSourceReloaded: 'cursor-source-reloaded',
+  effectQualityChanged: 'effect-quality-changed',
+  libraryOversizedAni: 'library:oversized-ani',
+  applyScheduled: 'apply-scheduled',
+  applyCountdown: 'apply-countdown',
+  packUpdatesAvailable: 'pack-updates-available',
 } as const;
 
 export type EventName = (typeof Events)[keyof typeof Events];
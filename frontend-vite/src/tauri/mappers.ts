@@ -36,6 +36,9 @@ export const mapCursorStatePayload = (
   if (payload.hidden !== undefined) updates.hidden = payload.hidden;
   else if (effectiveDefaults) updates.hidden = effectiveDefaults.hidden;
 
+  if (payload.visibility !== undefined) updates.visibility = payload.visibility;
+  else if (effectiveDefaults) updates.visibility = effectiveDefaults.visibility;
+
   if (payload.shortcut !== undefined) updates.shortcut = payload.shortcut;
   else if (effectiveDefaults) updates.shortcut = effectiveDefaults.shortcut;
 
@@ -69,6 +72,12 @@ export const mapCursorStatePayload = (
   if (payload.default_cursor_style !== undefined) updates.defaultCursorStyle = payload.default_cursor_style;
   else if (effectiveDefaults) updates.defaultCursorStyle = effectiveDefaults.defaultCursorStyle;
 
+  if (payload.policy_locks !== undefined) updates.policyLocks = { ...payload.policy_locks };
+  else if (effectiveDefaults) updates.policyLocks = { ...effectiveDefaults.policyLocks };
+
+  if (payload.effects !== undefined) updates.effects = { enabled: [...payload.effects.enabled] };
+  else if (effectiveDefaults) updates.effects = { enabled: [...effectiveDefaults.effects.enabled] };
+
   return updates;
 };
 
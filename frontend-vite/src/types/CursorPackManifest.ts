@@ -1,5 +1,6 @@
 import type { CustomizationMode } from './generated/CustomizationMode';
 import type { LibraryPackItem } from './generated/LibraryPackItem';
+import type { PackChangelogEntry } from './generated/PackChangelogEntry';
 
 export interface CursorPackManifest {
   version: number;
@@ -7,4 +8,6 @@ export interface CursorPackManifest {
   mode: CustomizationMode;
   created_at: string;
   items: LibraryPackItem[];
+  description?: string;
+  changelog?: PackChangelogEntry[];
 }
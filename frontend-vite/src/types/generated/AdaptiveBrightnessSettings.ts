@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AdaptiveBrightnessSource } from "./AdaptiveBrightnessSource";
+
+export type AdaptiveBrightnessSettings = { source: AdaptiveBrightnessSource, 
+/**
+ * How far pixels move towards white or black, in percent
+ */
+strength_percent: number, };
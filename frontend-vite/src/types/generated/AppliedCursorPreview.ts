@@ -0,0 +1,20 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AniPreviewData } from "./AniPreviewData";
+
+/**
+ * What Windows is currently using for one cursor role, read from the registry.
+ */
+export type AppliedCursorPreview = { cursor_name: string, display_name: string, 
+/**
+ * Registry value with `%VAR%` expanded; `None` when Windows uses its built-in cursor
+ */
+file_path: string | null, 
+/**
+ * Still preview (first frame of animated cursors); `None` if the file is missing or
+ * unreadable
+ */
+data_url: string | null, 
+/**
+ * Frames and timing when the applied file is an .ani
+ */
+animation: AniPreviewData | null, };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ApplyVerificationFailure } from "./ApplyVerificationFailure";
+
+export type ApplyVerificationWarning = { kind: ApplyVerificationFailure, 
+/**
+ * Cursor role name (e.g. "Normal") or registry key (e.g. "Arrow")
+ */
+role: string, detail: string, };
@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BatchUploadFailure } from "./BatchUploadFailure";
+import type { LibraryCursor } from "./LibraryCursor";
+
+/**
+ * Outcome of a batch upload. The batch is all-or-nothing: when `failures` is non-empty,
+ * `added` is empty and no file was written to the cursors folder.
+ */
+export type BatchUploadReport = { added: Array<LibraryCursor>, failures: Array<BatchUploadFailure>, };
@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BenchmarkResult } from "./BenchmarkResult";
+
+export type BenchmarkReport = { app_version: string, os: string, arch: string, 
+/**
+ * Threads the OS lets the app run in parallel
+ */
+cpu_threads: number, ran_at: string, results: Array<BenchmarkResult>, 
+/**
+ * When the run the results are compared with was made
+ */
+previous_ran_at: string | null, };
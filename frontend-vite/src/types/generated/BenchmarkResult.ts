@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type BenchmarkResult = { name: string, iterations: number, median_ms: number, min_ms: number, max_ms: number, 
+/**
+ * Work done per second at the median time, in `throughput_unit`
+ */
+throughput: number, throughput_unit: string, 
+/**
+ * Median of the same benchmark in the previous saved run
+ */
+previous_median_ms: number | null, 
+/**
+ * Slower (positive) or faster (negative) than the previous run, in percent
+ */
+change_percent: number | null, };
@@ -0,0 +1,26 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A file picked in the browse dialog, with what the import dialog shows before importing.
+ */
+export type BrowsedCursorFile = { path: string, 
+/**
+ * `ani`, `cur`, `svg`, `raster` or `tiff`; `None` when the file could not be read
+ */
+format: string | null, 
+/**
+ * Dimensions of the largest frame
+ */
+width: number | null, height: number | null, 
+/**
+ * Hotspot of the largest frame; only cursor files carry one
+ */
+hotspot_x: number | null, hotspot_y: number | null, 
+/**
+ * Frames in an .ani; images and other files hold one
+ */
+frame_count: number | null, file_size: number, 
+/**
+ * Why the details are missing, when they are
+ */
+error: string | null, };
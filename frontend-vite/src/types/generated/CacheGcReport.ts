@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type CacheGcReport = { reclaimed_bytes: number, removed_entries: number, 
+/**
+ * Cache size left after collection
+ */
+remaining_bytes: number, quota_bytes: number, };
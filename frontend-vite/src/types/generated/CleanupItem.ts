@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CleanupItemKind } from "./CleanupItemKind";
+
+export type CleanupItem = { kind: CleanupItemKind, 
+/**
+ * Registry value, service or path; selects the item in `run_uninstall_cleanup`
+ */
+location: string, 
+/**
+ * Whether the app can remove the item itself
+ */
+removable: boolean, 
+/**
+ * How to remove the item by hand when the app cannot
+ */
+note: string | null, };
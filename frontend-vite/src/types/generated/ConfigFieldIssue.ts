@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type ConfigFieldIssue = { field: string, 
+/**
+ * What the field should hold, e.g. "boolean" or "integer between 32 and 256"
+ */
+expected: string, 
+/**
+ * The offending value as found in the file
+ */
+actual: string, };
@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ConfigFieldIssue } from "./ConfigFieldIssue";
+
+export type ConfigValidationReport = { issues: Array<ConfigFieldIssue>, 
+/**
+ * The file could not be read at all, so every setting is back to its default
+ */
+reset_all: boolean, 
+/**
+ * Copy of the invalid file, if it could be made
+ */
+backup_path: string | null, };
@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type ConversionOutputSettings = { 
+/**
+ * Folder converted cursors are written to; the cursors folder when unset
+ */
+output_dir: string | null, 
+/**
+ * File name without extension. `{name}` is the source file name, `{size}` the cursor
+ * size in pixels and `{hash}` a short hash of the source file.
+ */
+name_template: string, };
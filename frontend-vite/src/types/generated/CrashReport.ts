@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A crash that has not been dismissed yet. Either file may be missing if writing it failed.
+ */
+export type CrashReport = { 
+/**
+ * File stem shared by the dump and the log, e.g. `crash-1734000000000`
+ */
+id: string, 
+/**
+ * Milliseconds since the Unix epoch, taken from the file name
+ */
+created_ms: number, dump_path: string | null, log_path: string | null, };
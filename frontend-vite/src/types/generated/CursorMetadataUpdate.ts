@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Attribution fields for `update_cursor_metadata`. Omitted fields are left unchanged;
+ * an empty string clears the field.
+ */
+export type CursorMetadataUpdate = { notes: string | null, author: string | null, source_url: string | null, };
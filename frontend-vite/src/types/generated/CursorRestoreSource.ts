@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Where a single restored role got its cursor from.
+ */
+export type CursorRestoreSource = "snapshot" | "app_default" | "system";
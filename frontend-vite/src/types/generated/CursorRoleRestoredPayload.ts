@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CursorRestoreSource } from "./CursorRestoreSource";
+
+/**
+ * Delta payload emitted when a single role is restored.
+ */
+export type CursorRoleRestoredPayload = { cursor_name: string, image_path: string | null, source: CursorRestoreSource, };
@@ -0,0 +1,3 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type CursorSourceLink = { source_path: string, size: number, click_point_x: number, click_point_y: number, scale: number, offset_x: number, offset_y: number, hot_reload: boolean, };
@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { LibraryCursor } from "./LibraryCursor";
+
+/**
+ * Result of importing a theme: the new pack and what did not survive conversion.
+ */
+export type CursorThemeImport = { pack: LibraryCursor, 
+/**
+ * Pack base names of the converted cursors, e.g. `normal-select`
+ */
+imported: Array<string>, 
+/**
+ * Parts of the theme that were skipped or simplified
+ */
+warnings: Array<string>, };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type CursorVariantSettings = { 
+/**
+ * Write pre-scaled copies of imported cursors and apply them at the matching sizes.
+ * When off, Windows scales the original file.
+ */
+pregenerate: boolean, };
@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { HideSnapshot } from "./HideSnapshot";
+
+export type CursorVisibility = { "state": "visible" } | { "state": "hiding" } | { "state": "hidden", snapshot: HideSnapshot, } | { "state": "restoring" } | { "state": "error", message: string, cursor_hidden: boolean, };
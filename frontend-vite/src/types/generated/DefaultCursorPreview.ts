@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DefaultPreviewSource } from "./DefaultPreviewSource";
+
+export type DefaultCursorPreview = { cursor_name: string, display_name: string, data_url: string, source: DefaultPreviewSource, };
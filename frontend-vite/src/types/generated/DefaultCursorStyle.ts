@@ -1,3 +1,3 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
-export type DefaultCursorStyle = "windows" | "mac";
+export type DefaultCursorStyle = "windows" | "mac" | "windows-black" | "windows-inverted" | "windows-magnified" | "touch";
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DefaultItemKind } from "./DefaultItemKind";
+
+export type DefaultItemResult = { kind: DefaultItemKind, 
+/**
+ * Bundled file name (cursors) or folder name (packs)
+ */
+name: string, ok: boolean, error: string | null, };
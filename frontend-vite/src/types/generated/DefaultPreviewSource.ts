@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Where a default-cursor preview came from.
+ */
+export type DefaultPreviewSource = "bundled" | "system_scheme" | "system_aero" | "placeholder";
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * One input file of a folder conversion. Either `output_path` and the click point are set,
+ * or `error` is.
+ */
+export type DirectoryConversionFile = { input_path: string, output_path: string | null, click_point_x: number | null, click_point_y: number | null, error: string | null, };
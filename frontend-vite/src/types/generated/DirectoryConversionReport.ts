@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DirectoryConversionFile } from "./DirectoryConversionFile";
+
+/**
+ * Outcome of a folder conversion, one entry per supported input file in name order.
+ */
+export type DirectoryConversionReport = { files: Array<DirectoryConversionFile>, };
@@ -0,0 +1,17 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { EffectQuality } from "./EffectQuality";
+import type { EffectRenderProfile } from "./EffectRenderProfile";
+
+export type EffectQualityStatus = { 
+/**
+ * The user's setting
+ */
+quality: EffectQuality, 
+/**
+ * What the overlay should draw with now
+ */
+profile: EffectRenderProfile, 
+/**
+ * Average effect render time over the last full window, in milliseconds
+ */
+average_frame_ms: number | null, };
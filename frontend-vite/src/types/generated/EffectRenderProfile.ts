@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { EffectQuality } from "./EffectQuality";
+
+/**
+ * How the overlay should draw effects at one quality level.
+ */
+export type EffectRenderProfile = { 
+/**
+ * Low, Medium or High; never Auto
+ */
+level: EffectQuality, 
+/**
+ * Segments kept in the cursor trail
+ */
+trail_segments: number, 
+/**
+ * Overlay redraws per second
+ */
+refresh_hz: number, };
@@ -1,3 +1,6 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
+/**
+ * Cursor effects (trail, ripple, spotlight, ...) enabled for one customization profile.
+ */
 export type EffectsConfig = { enabled: Array<string>, };
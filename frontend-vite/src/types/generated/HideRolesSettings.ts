@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type HideRolesSettings = { 
+/**
+ * Hide every cursor role, ignoring `visible_roles`
+ */
+strict: boolean, 
+/**
+ * Cursor roles (by name) that stay visible while the cursor is hidden
+ */
+visible_roles: Array<string>, };
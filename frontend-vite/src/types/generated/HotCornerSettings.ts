@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { HotCornerAction } from "./HotCornerAction";
+
+export type HotCornerSettings = { enabled: boolean, top_left: HotCornerAction, top_right: HotCornerAction, bottom_left: HotCornerAction, bottom_right: HotCornerAction, 
+/**
+ * How long the pointer must rest in a corner before its action runs
+ */
+dwell_ms: number, 
+/**
+ * Side of the square that counts as the corner; larger is easier to hit
+ */
+corner_size_px: number, };
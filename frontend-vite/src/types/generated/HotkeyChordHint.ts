@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { HotkeyChordOption } from "./HotkeyChordOption";
+
+/**
+ * Payload of `hotkey-chord-hint`: shown while `active`, hidden otherwise.
+ */
+export type HotkeyChordHint = { active: boolean, prefix: string, options: Array<HotkeyChordOption>, timeout_ms: number, };
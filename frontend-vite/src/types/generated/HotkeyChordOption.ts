@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type HotkeyChordOption = { 
+/**
+ * Key to press, "1".."9"
+ */
+key: string, pack_id: string, pack_name: string, };
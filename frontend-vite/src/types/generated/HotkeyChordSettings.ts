@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type HotkeyChordSettings = { enabled: boolean, 
+/**
+ * Accelerator that opens the chord, e.g. "Ctrl+Alt+C"
+ */
+prefix: string, 
+/**
+ * How long the chord waits for the second key
+ */
+timeout_ms: number, };
@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { HotkeyPressAction } from "./HotkeyPressAction";
+
+export type HotkeyPressSettings = { single_press: HotkeyPressAction, 
+/**
+ * `None` keeps single presses instant
+ */
+double_press: HotkeyPressAction, 
+/**
+ * How long to wait for the second press
+ */
+double_press_window_ms: number, };
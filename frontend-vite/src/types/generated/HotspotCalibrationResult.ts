@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type HotspotCalibrationResult = { cursor_name: string, cursor_path: string, sample_count: number, 
+/**
+ * Median click offset from the target, in screen pixels
+ */
+offset_x: number, offset_y: number, current_click_point_x: number, current_click_point_y: number, suggested_click_point_x: number, suggested_click_point_y: number, 
+/**
+ * Library entry to pass to `update_library_cursor_click_point` when the user accepts.
+ * `None` when the applied cursor is not in the library and cannot be rewritten.
+ */
+library_cursor_id: string | null, };
@@ -0,0 +1,3 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type HotspotCalibrationSession = { cursor_name: string, cursor_path: string, click_point_x: number, click_point_y: number, timeout_ms: number, min_samples: number, };
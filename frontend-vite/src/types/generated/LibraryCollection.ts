@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type LibraryCollection = { id: string, name: string, 
+/**
+ * Enclosing collection; `None` for top-level collections
+ */
+parent_id: string | null, 
+/**
+ * Member cursor IDs in the collection's own order
+ */
+cursor_ids: Array<string>, };
@@ -1,4 +1,26 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CursorSourceLink } from "./CursorSourceLink";
+import type { LibraryCursorVariant } from "./LibraryCursorVariant";
 import type { LibraryPackMetadata } from "./LibraryPackMetadata";
 
-export type LibraryCursor = { id: string, name: string, file_path: string, click_point_x: number, click_point_y: number, created_at: string, is_pack: boolean, pack_metadata: LibraryPackMetadata | null, };
+export type LibraryCursor = { id: string, name: string, file_path: string, click_point_x: number, click_point_y: number, created_at: string, is_pack: boolean, pack_metadata: LibraryPackMetadata | null, 
+/**
+ * Pixel size of the image stored in the cursor file, if it could be read.
+ */
+native_size: number | null, scaled_variants: Array<LibraryCursorVariant>, 
+/**
+ * Free-form notes from the user or pack author.
+ */
+notes: string | null, 
+/**
+ * Who made the cursor, preserved when packs are shared.
+ */
+author: string | null, 
+/**
+ * Where the cursor was downloaded from.
+ */
+source_url: string | null, 
+/**
+ * Image the cursor was converted from, so it can be reconverted when the image changes.
+ */
+source_link: CursorSourceLink | null, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A pre-scaled copy of a library cursor generated for one of the large apply sizes.
+ */
+export type LibraryCursorVariant = { size: number, file_path: string, };
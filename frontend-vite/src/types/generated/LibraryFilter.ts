@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type LibraryFilter = { 
+/**
+ * Case-insensitive match against the name, author and notes
+ */
+query: string | null, 
+/**
+ * `true` for packs only, `false` for single cursors only
+ */
+is_pack: boolean | null, 
+/**
+ * Only the members of this collection; the `custom` sort then follows its order
+ */
+collection_id: string | null, };
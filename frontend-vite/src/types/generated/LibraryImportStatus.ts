@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type LibraryImportStatus = { 
+/**
+ * Files waiting for a free import slot
+ */
+queued: number, in_progress: number, imported: number, total: number, };
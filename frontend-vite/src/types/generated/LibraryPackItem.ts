@@ -1,3 +1,3 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
-export type LibraryPackItem = { cursor_name: string, display_name: string, file_name: string, file_path: string | null, };
+export type LibraryPackItem = { cursor_name: string, display_name: string, file_name: string, file_path: string | null, notes: string | null, author: string | null, source_url: string | null, };
@@ -1,5 +1,38 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 import type { CustomizationMode } from "./CustomizationMode";
 import type { LibraryPackItem } from "./LibraryPackItem";
+import type { PackSoundEvent } from "./PackSoundEvent";
 
-export type LibraryPackMetadata = { mode: CustomizationMode, archive_path: string, items: Array<LibraryPackItem>, previews: { [key in string]?: string } | null, };
+export type LibraryPackMetadata = { mode: CustomizationMode, archive_path: string, items: Array<LibraryPackItem>, previews: { [key in string]?: string } | null, 
+/**
+ * 3×3 grid of the key role previews as a PNG data URL, for the library grid.
+ */
+sprite: string | null, 
+/**
+ * The user's own 1-5 star rating, kept only on this machine.
+ */
+rating: number | null, 
+/**
+ * Version label of the newest changelog entry when the pack was installed.
+ */
+installed_version: string | null, 
+/**
+ * Events the pack bundles a sound for.
+ */
+sounds: Array<PackSoundEvent>, 
+/**
+ * Whether the user turned this pack's sounds on. Off until they do.
+ */
+sounds_enabled: boolean, 
+/**
+ * Download link the pack was imported from, checked for newer archives.
+ */
+update_url: string | null, 
+/**
+ * Hash of the archive last downloaded from `update_url`.
+ */
+archive_hash: string | null, 
+/**
+ * Whether the last check found a different archive at `update_url`.
+ */
+update_available: boolean, };
@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { LibraryCursor } from "./LibraryCursor";
+
+export type LibraryPage = { items: Array<LibraryCursor>, 
+/**
+ * Offset of the first item, after clamping to the filtered total
+ */
+offset: number, 
+/**
+ * Number of entries matching the filter
+ */
+total: number, };
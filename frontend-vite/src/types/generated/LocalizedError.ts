@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type LocalizedError = { 
+/**
+ * e.g. `"E-REG-001"`; `None` for errors without a code
+ */
+code: string | null, 
+/**
+ * Translated message, or the English detail for errors without a code
+ */
+message: string, 
+/**
+ * What the user can try next
+ */
+hint: string | null, 
+/**
+ * English detail, for logs and bug reports
+ */
+detail: string, };
@@ -0,0 +1,20 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { LibraryCursor } from "./LibraryCursor";
+import type { MigrationSource } from "./MigrationSource";
+
+/**
+ * Outcome of `run_migration`.
+ */
+export type MigrationReport = { source: MigrationSource, 
+/**
+ * Packs created from themes and schemes
+ */
+packs: Array<LibraryCursor>, 
+/**
+ * Single cursors added to the library
+ */
+cursors: Array<LibraryCursor>, 
+/**
+ * Items that were skipped, and parts of imported items that were dropped
+ */
+warnings: Array<string>, };
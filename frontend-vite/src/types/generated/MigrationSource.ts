@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { MigrationSourceKind } from "./MigrationSourceKind";
+
+/**
+ * Something another tool left behind that can be imported.
+ */
+export type MigrationSource = { 
+/**
+ * Passed back to `run_migration`, e.g. `cursorfx` or `scheme:Neon`
+ */
+id: string, kind: MigrationSourceKind, name: string, 
+/**
+ * Folders or registry key the items were found in
+ */
+locations: Array<string>, 
+/**
+ * Themes, cursor files or scheme cursors found
+ */
+item_count: number, };
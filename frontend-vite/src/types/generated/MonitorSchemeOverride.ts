@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type MonitorSchemeOverride = { 
+/**
+ * Device name of the monitor, such as `\\.\DISPLAY2`
+ */
+monitor: string, 
+/**
+ * Cursor file for each role (`Normal`, `IBeam`, ...); `Normal` is required
+ */
+cursor_paths: { [key in string]?: string }, 
+/**
+ * Size the cursor is drawn at on this monitor
+ */
+cursor_size: number, };
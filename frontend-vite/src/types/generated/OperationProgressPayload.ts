@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { OperationPhase } from "./OperationPhase";
+
+export type OperationProgressPayload = { op_id: string, phase: OperationPhase, done: number, total: number, 
+/**
+ * Short description of the current step, e.g. the file being processed
+ */
+message: string | null, };
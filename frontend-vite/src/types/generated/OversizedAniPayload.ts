@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Sent after importing an animated cursor drawn much larger than the user's cursor size, so
+ * the frontend can offer [`downscale_library_ani`].
+ */
+export type OversizedAniPayload = { cursor_id: string, name: string, native_size: number, 
+/**
+ * The user's cursor size, the size the downscaled copy would get
+ */
+target_size: number, };
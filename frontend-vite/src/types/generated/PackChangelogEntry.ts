@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * One release note in a pack's changelog, newest first in the manifest.
+ */
+export type PackChangelogEntry = { 
+/**
+ * Pack author's version label, e.g. "1.2"
+ */
+version: string | null, 
+/**
+ * ISO 8601 date of the change
+ */
+date: string, notes: string, };
@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CustomizationMode } from "./CustomizationMode";
+import type { PackChangelogEntry } from "./PackChangelogEntry";
+
+/**
+ * Description and changelog of a library pack, ready to render.
+ */
+export type PackDetails = { id: string, name: string, mode: CustomizationMode, created_at: string, item_count: number, description: string | null, 
+/**
+ * Newest first
+ */
+changelog: Array<PackChangelogEntry>, 
+/**
+ * Distinct authors credited by the pack's items
+ */
+authors: Array<string>, };
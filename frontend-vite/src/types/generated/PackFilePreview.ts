@@ -1,3 +1,7 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
-export type PackFilePreview = { file_name: string, data_url: string, };
+export type PackFilePreview = { file_name: string, 
+/**
+ * Preview image: a `cc-preview` URL, or a data URL when the image could not be cached
+ */
+data_url: string, };
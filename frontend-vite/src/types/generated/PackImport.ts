@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { LibraryCursor } from "./LibraryCursor";
+
+/**
+ * Result of importing a pack: the new library entry and what a lenient import skipped.
+ */
+export type PackImport = { pack: LibraryCursor, 
+/**
+ * Files a lenient import skipped and why; always empty for a strict import
+ */
+warnings: Array<string>, };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PackIntegrityIssue } from "./PackIntegrityIssue";
+
+export type PackIntegrityReport = { pack_id: string, 
+/**
+ * Manifest items checked
+ */
+checked: number, issues: Array<PackIntegrityIssue>, };
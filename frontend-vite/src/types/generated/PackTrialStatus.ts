@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type PackTrialStatus = { pack_id: string, 
+/**
+ * Seconds until the previous cursors are restored
+ */
+seconds: number, };
@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PackUpdateStatus } from "./PackUpdateStatus";
+
+export type PackUpdateReport = { 
+/**
+ * Packs now on the downloaded archive; `installed_version` is the version they had before
+ */
+updated: Array<PackUpdateStatus>, 
+/**
+ * Packs whose download matched the installed archive
+ */
+up_to_date: number, 
+/**
+ * Packs that could not be downloaded or whose new archive was rejected
+ */
+failed: Array<PackUpdateStatus>, };
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type PackUpdateStatus = { pack_id: string, name: string, installed_version: string | null, 
+/**
+ * Version label of the downloaded archive, when its manifest has one
+ */
+remote_version: string | null, update_available: boolean, 
+/**
+ * Why the pack could not be checked or updated
+ */
+error: string | null, };
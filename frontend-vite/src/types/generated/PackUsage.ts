@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type PackUsage = { pack_id: string, 
+/**
+ * Library name, or `None` if the pack has since been removed
+ */
+name: string | null, applies: number, };
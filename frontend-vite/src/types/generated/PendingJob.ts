@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PendingJobKind } from "./PendingJobKind";
+
+export type PendingJob = { 
+/**
+ * Also used as the `op_id` when the job is resumed
+ */
+id: string, kind: PendingJobKind, target: string, 
+/**
+ * What the job was doing, for the resume prompt
+ */
+description: string, queued_at: string, };
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type PickedScreenColor = { 
+/**
+ * "#rrggbb", the format used for `accent_color`
+ */
+hex: string, red: number, green: number, blue: number, 
+/**
+ * Screen position the colour was sampled at
+ */
+x: number, y: number, };
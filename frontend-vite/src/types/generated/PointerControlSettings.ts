@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PointerKeyBindings } from "./PointerKeyBindings";
+
+export type PointerControlSettings = { enabled: boolean, 
+/**
+ * Pixels per nudge when a move key is first pressed
+ */
+step_px: number, 
+/**
+ * Pixels per nudge once a move key has been held for `acceleration_ms`
+ */
+max_step_px: number, 
+/**
+ * Hold time to reach full speed; 0 keeps the step constant
+ */
+acceleration_ms: number, keys: PointerKeyBindings, };
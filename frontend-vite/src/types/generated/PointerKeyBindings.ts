@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Virtual-key codes bound to each pointer action.
+ */
+export type PointerKeyBindings = { up: number, down: number, left: number, right: number, left_click: number, right_click: number, };
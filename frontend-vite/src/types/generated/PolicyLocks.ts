@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Locked settings as seen by the UI, so it can grey out the matching controls.
+ */
+export type PolicyLocks = { hide_disabled: boolean, default_cursor_style_locked: boolean, marketplace_disabled: boolean, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type ReducedMotionSettings = { 
+/**
+ * Follow the Windows animation setting; when false, effects and previews always animate
+ */
+follow_system: boolean, };
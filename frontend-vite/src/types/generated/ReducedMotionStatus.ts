@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type ReducedMotionStatus = { 
+/**
+ * Windows "Animation effects" is turned off
+ */
+system_reduce_motion: boolean, follow_system: boolean, 
+/**
+ * Motion effects and animated previews are currently suppressed
+ */
+active: boolean, };
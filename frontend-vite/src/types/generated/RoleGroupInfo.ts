@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { RoleGroup } from "./RoleGroup";
+
+export type RoleGroupInfo = { group: RoleGroup, display_name: string, 
+/**
+ * Cursor role names (`CURSOR_TYPES` names) the group applies to
+ */
+roles: Array<string>, };
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type ScheduledApply = { id: string, pack_id: string, 
+/**
+ * Name of the pack when it was scheduled
+ */
+pack_name: string, 
+/**
+ * When to apply, in milliseconds since the Unix epoch
+ */
+run_at_ms: number, };
@@ -0,0 +1,3 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type ScheduledApplyCountdown = { id: string, pack_id: string, pack_name: string, seconds_left: number, };
@@ -0,0 +1,3 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type SizeGestureModifier = "win_ctrl" | "win_alt" | "win_shift" | "ctrl_alt" | "ctrl_shift";
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Payload of `cursor-size-gesture`, emitted for every wheel step.
+ */
+export type SizeGesturePayload = { 
+/**
+ * Size that will be applied once scrolling pauses
+ */
+size: number, min: number, max: number, };
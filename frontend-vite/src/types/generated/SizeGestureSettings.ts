@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SizeGestureModifier } from "./SizeGestureModifier";
+
+export type SizeGestureSettings = { enabled: boolean, modifier: SizeGestureModifier, 
+/**
+ * Pixels added or removed per wheel step
+ */
+step_px: number, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type StatusFileSettings = { enabled: boolean, 
+/**
+ * Where the status is written; `.ini` selects INI output, anything else JSON
+ */
+path: string | null, };
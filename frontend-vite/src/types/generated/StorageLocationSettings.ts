@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type StorageLocationSettings = { 
+/**
+ * Folder holding the library files; the default folder inside the app folder when unset
+ */
+library_dir: string | null, };
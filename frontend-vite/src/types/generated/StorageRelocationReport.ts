@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type StorageRelocationReport = { 
+/**
+ * Folder the library files live in now
+ */
+library_dir: string, moved_files: number, 
+/**
+ * Paths in `library.json` that were pointed at the new folder
+ */
+library_paths_updated: number, 
+/**
+ * Windows cursor registry values that were pointed at the new folder
+ */
+registry_values_updated: number, 
+/**
+ * Old folder that could not be deleted completely and can be removed by hand
+ */
+leftover_dir: string | null, };
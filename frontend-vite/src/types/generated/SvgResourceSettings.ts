@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type SvgResourceSettings = { 
+/**
+ * Let SVG text use fonts installed on this PC; output may then differ between machines
+ */
+load_system_fonts: boolean, };
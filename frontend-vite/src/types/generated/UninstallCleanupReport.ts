@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CleanupFailure } from "./CleanupFailure";
+import type { CleanupItem } from "./CleanupItem";
+
+export type UninstallCleanupReport = { removed: Array<CleanupItem>, failed: Array<CleanupFailure>, 
+/**
+ * Items left in place: not selected, or not removable by the app
+ */
+skipped: Array<CleanupItem>, };
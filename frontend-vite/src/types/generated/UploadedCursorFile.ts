@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * One file of a batch upload.
+ */
+export type UploadedCursorFile = { filename: string, data: Array<number>, };
@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PackUsage } from "./PackUsage";
+
+export type UsageStats = { enabled: boolean, since: string | null, toggles: number, 
+/**
+ * Cursor and pack applies
+ */
+applies: number, 
+/**
+ * Most applied packs, most used first
+ */
+top_packs: Array<PackUsage>, };
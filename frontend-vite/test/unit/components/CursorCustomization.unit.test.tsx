@@ -125,7 +125,17 @@ function handleResetAllCursors() {
 }
 
 function handleBrowseCursorFile() {
-  return Promise.resolve('C:\\test\\cursor.cur');
+  return Promise.resolve({
+    path: 'C:\\test\\cursor.cur',
+    format: 'cur',
+    width: 32,
+    height: 32,
+    hotspot_x: 0,
+    hotspot_y: 0,
+    frame_count: 1,
+    file_size: 4286,
+    error: null
+  });
 }
 
 function handleReadCursorFile(args: any) {
@@ -1,14 +1,22 @@
 import React from 'react';
 import { describe, it, expect, vi, beforeEach, afterEach } from 'vitest';
-import { render, screen, fireEvent } from '@testing-library/react';
+import { render, screen, fireEvent, waitFor } from '@testing-library/react';
+
+const invokeMock = vi.fn((..._args: any[]) => Promise.resolve(null as any));
 
 vi.mock('@tauri-apps/api/event', () => ({
   listen: vi.fn(() => Promise.resolve(() => { })),
 }));
 
+vi.mock('@tauri-apps/api/core', () => ({
+  invoke: (...args: any[]) => invokeMock(...args),
+}));
+
 describe('CursorCustomization/FileUpload/BrowseModal', () => {
   beforeEach(() => {
     vi.restoreAllMocks();
+    invokeMock.mockReset();
+    invokeMock.mockImplementation(() => Promise.resolve(null));
 
     if (!(globalThis as any).DataTransfer) {
       (globalThis as any).DataTransfer = class {
@@ -183,4 +191,49 @@ describe('CursorCustomization/FileUpload/BrowseModal', () => {
 
     clickSpy.mockRestore();
   });
+
+  it('shows details of a browsed file before importing it', async () => {
+    const { BrowseModal } = await import('@/components/CursorCustomization/FileUpload/BrowseModal');
+
+    invokeMock.mockImplementation((command: string) => {
+      if (command === 'browse_cursor_file') {
+        return Promise.resolve({
+          path: 'C:\\cursors\\busy.ani',
+          format: 'ani',
+          width: 64,
+          height: 64,
+          hotspot_x: 10,
+          hotspot_y: 12,
+          frame_count: 8,
+          file_size: 2048,
+          error: null
+        });
+      }
+      if (command === 'read_file_content') return Promise.resolve(btoa('RIFF'));
+      return Promise.resolve(null);
+    });
+
+    const onClose = vi.fn();
+    const handleFileSelect = vi.fn();
+
+    render(
+      <BrowseModal isOpen={true} onClose={onClose} handleFileSelect={handleFileSelect} />
+    );
+
+    fireEvent.click(screen.getByLabelText(/File upload area/i));
+
+    const details = await screen.findByLabelText('Selected file details');
+    expect(screen.getByText('busy.ani')).toBeInTheDocument();
+    expect(details).toHaveTextContent('ANI');
+    expect(details).toHaveTextContent('64 × 64 px');
+    expect(details).toHaveTextContent('10, 12');
+    expect(details).toHaveTextContent('8');
+    expect(details).toHaveTextContent('2.0 KB');
+    expect(handleFileSelect).not.toHaveBeenCalled();
+
+    fireEvent.click(screen.getByRole('button', { name: 'Import' }));
+
+    await waitFor(() => expect(handleFileSelect).toHaveBeenCalled());
+    expect(invokeMock).toHaveBeenCalledWith('read_file_content', { path: 'C:\\cursors\\busy.ani' });
+  });
 });
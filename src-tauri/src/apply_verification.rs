@@ -0,0 +1,121 @@
+//! Checks that cursor changes actually took effect.
+//!
+//! Group policy and some kiosk shells let `SetSystemCursor` and registry writes succeed
+//! without changing anything. After an apply we fingerprint the live Normal cursor and
+//! compare it with the file that was applied; after registry writes we read the values back.
+//! Failures are logged and surfaced to the UI as `apply-verification-failed`.
+
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use tauri::{AppHandle, Emitter, Runtime};
+
+use crate::system;
+
+/// `OCR_NORMAL`
+const NORMAL_CURSOR_ID: u32 = 32512;
+
+#[derive(ts_rs::TS, Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub enum ApplyVerificationFailure {
+    /// A registry value read back differs from what was written
+    RegistryMismatch,
+    /// The live cursor still shows the previous image after an apply
+    LiveCursorUnchanged,
+}
+
+#[derive(ts_rs::TS, Serialize, Clone, Debug, PartialEq, Eq)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct ApplyVerificationWarning {
+    pub kind: ApplyVerificationFailure,
+    /// Cursor role name (e.g. "Normal") or registry key (e.g. "Arrow")
+    pub role: String,
+    pub detail: String,
+}
+
+/// Fingerprint of the live Normal cursor as of the last check.
+static LAST_LIVE_NORMAL: Mutex<Option<u64>> = Mutex::new(None);
+
+/// Remember the live Normal cursor before any customization. Called once at startup.
+pub fn record_baseline() {
+    if let Ok(mut last) = LAST_LIVE_NORMAL.lock() {
+        *last = system::live_cursor_hash(NORMAL_CURSOR_ID);
+    }
+}
+
+/// An apply is a silent no-op when the live cursor neither matches the file nor moved
+/// away from what it showed before. Comparing against the previous image as well avoids
+/// false alarms when Windows rescales the file and the bitmaps differ slightly.
+fn is_silent_no_op(expected: u64, live: u64, previous: Option<u64>) -> bool {
+    live != expected && previous == Some(live)
+}
+
+/// Check that the live Normal cursor now shows `path` at `size`.
+pub fn verify_normal_role(path: &str, size: i32) -> Option<ApplyVerificationWarning> {
+    // Files Windows can't load (or non-Windows test runs) can't be verified
+    let expected = system::cursor_file_hash(path, size)?;
+    let live = system::live_cursor_hash(NORMAL_CURSOR_ID)?;
+    let previous = LAST_LIVE_NORMAL.lock().ok()?.replace(live);
+
+    is_silent_no_op(expected, live, previous).then(|| ApplyVerificationWarning {
+        kind: ApplyVerificationFailure::LiveCursorUnchanged,
+        role: "Normal".to_string(),
+        detail: format!("Windows is still showing the previous cursor after applying {path}"),
+    })
+}
+
+/// Read the cursor registry back and report keys that don't hold `expected`.
+pub fn verify_registry(expected: &HashMap<String, Option<String>>) -> Vec<ApplyVerificationWarning> {
+    cursor_changer::verify_cursor_registry_entries(expected)
+        .into_iter()
+        .map(|key| ApplyVerificationWarning {
+            kind: ApplyVerificationFailure::RegistryMismatch,
+            detail: format!("Registry value for {key} was not updated"),
+            role: key,
+        })
+        .collect()
+}
+
+/// Expected registry contents after the cursor entries have been cleared.
+pub fn cleared_registry_entries() -> HashMap<String, Option<String>> {
+    cursor_changer::CURSOR_TYPES
+        .iter()
+        .map(|ct| (ct.registry_key.to_string(), None))
+        .collect()
+}
+
+/// Log each warning and forward it to the UI.
+pub fn report<R: Runtime>(app: &AppHandle<R>, warnings: Vec<ApplyVerificationWarning>) {
+    for warning in warnings {
+        cc_warn!(
+            "[CursorChanger] Apply verification failed for {}: {}",
+            warning.role,
+            warning.detail
+        );
+        let _ = app.emit(crate::events::APPLY_VERIFICATION_FAILED, warning);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unchanged_live_cursor_that_does_not_match_is_a_no_op() {
+        assert!(is_silent_no_op(1, 2, Some(2)));
+        // Matches the applied file
+        assert!(!is_silent_no_op(1, 1, Some(1)));
+        // Changed, even if Windows rescaled it
+        assert!(!is_silent_no_op(1, 3, Some(2)));
+        // Nothing to compare against yet
+        assert!(!is_silent_no_op(1, 2, None));
+    }
+
+    #[test]
+    fn cleared_entries_cover_every_role() {
+        let cleared = cleared_registry_entries();
+        assert_eq!(cleared.len(), cursor_changer::CURSOR_TYPES.len());
+        assert!(cleared.values().all(Option::is_none));
+    }
+}
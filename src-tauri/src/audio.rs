@@ -0,0 +1,169 @@
+//! Sound cues bundled with cursor packs.
+//!
+//! A pack may declare a few short WAV files in its manifest, one per [`PackSoundEvent`]. Once
+//! the user turns sounds on for that pack, applying it and hiding or showing the cursor play the
+//! matching file. Playback runs on its own short-lived thread, so neither hotkeys nor commands
+//! wait for a sound to finish.
+
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+
+use tauri::{AppHandle, Manager, Runtime};
+use zip::ZipArchive;
+
+use crate::commands::customization::library::load_library;
+use crate::commands::customization::pack_manifest::{
+    read_manifest, PackSoundEvent, MAX_PACK_SOUND_BYTES,
+};
+use crate::state::AppState;
+
+/// Read the WAV file the pack at `archive_path` bundles for `event`, if it has one.
+pub fn read_pack_sound(
+    archive_path: &Path,
+    event: PackSoundEvent,
+) -> Result<Option<Vec<u8>>, String> {
+    let file = File::open(archive_path).map_err(|e| format!("Failed to open pack: {}", e))?;
+    let mut archive =
+        ZipArchive::new(file).map_err(|e| format!("Pack is not a valid ZIP archive: {}", e))?;
+    let Some(manifest) = read_manifest(&mut archive) else {
+        return Ok(None);
+    };
+    let Some(name) = manifest.sounds.get(event) else {
+        return Ok(None);
+    };
+    let mut entry = archive
+        .by_name(name)
+        .map_err(|e| format!("Pack sound {} is missing: {}", name, e))?;
+    if entry.size() > MAX_PACK_SOUND_BYTES {
+        return Err(format!(
+            "Pack sound {} is larger than {} KB",
+            name,
+            MAX_PACK_SOUND_BYTES / 1024
+        ));
+    }
+    let mut wav = Vec::with_capacity(entry.size() as usize);
+    entry
+        .read_to_end(&mut wav)
+        .map_err(|e| format!("Failed to read pack sound {}: {}", name, e))?;
+    Ok(Some(wav))
+}
+
+fn play(wav: &[u8]) {
+    if !cursor_changer::play_wav_bytes(wav) {
+        cc_debug!("[CursorChanger] Windows could not play a pack sound");
+    }
+}
+
+fn spawn_playback(task: impl FnOnce() + Send + 'static) {
+    let spawned = std::thread::Builder::new()
+        .name("pack-sound".to_string())
+        .spawn(task);
+    if let Err(e) = spawned {
+        cc_warn!("[CursorChanger] Failed to start pack sound playback: {}", e);
+    }
+}
+
+/// Play `wav` without blocking the caller.
+pub fn play_in_background(wav: Vec<u8>) {
+    spawn_playback(move || play(&wav));
+}
+
+/// Pack whose cursors are on screen, going by the mounted files the cursor paths point at.
+fn active_pack_id<R: Runtime>(app: &AppHandle<R>) -> Option<String> {
+    let state = app.try_state::<AppState>()?;
+    let cursor = state.cursor.read().ok()?;
+    let mounts = crate::paths::pack_mounts_dir().ok()?;
+    cursor
+        .cursor_paths
+        .values()
+        .find_map(|path| crate::status_file::mounted_pack_id(&mounts, Path::new(path)))
+}
+
+fn enabled_pack_sound<R: Runtime>(
+    app: &AppHandle<R>,
+    pack_id: &str,
+    event: PackSoundEvent,
+) -> Result<Option<Vec<u8>>, String> {
+    let library = load_library(app)?;
+    let Some(pack) = library
+        .cursors
+        .iter()
+        .find(|c| c.is_pack && c.id == pack_id)
+    else {
+        return Ok(None);
+    };
+    let wanted = pack
+        .pack_metadata
+        .as_ref()
+        .is_some_and(|m| m.sounds_enabled && m.sounds.contains(&event));
+    if !wanted {
+        return Ok(None);
+    }
+    read_pack_sound(Path::new(&pack.file_path), event)
+}
+
+/// Play the sound `pack_id` (or else the active pack) has for `event`, if the user enabled
+/// that pack's sounds. Failures are only logged; a missing cue never fails the action.
+pub fn play_pack_sound<R: Runtime>(
+    app: &AppHandle<R>,
+    pack_id: Option<&str>,
+    event: PackSoundEvent,
+) {
+    let Some(pack_id) = pack_id.map(str::to_string).or_else(|| active_pack_id(app)) else {
+        return;
+    };
+    let app = app.clone();
+    spawn_playback(move || match enabled_pack_sound(&app, &pack_id, event) {
+        Ok(Some(wav)) => play(&wav),
+        Ok(None) => {}
+        Err(e) => cc_warn!("[CursorChanger] Pack sound for {}: {}", pack_id, e),
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    fn write_pack(dir: &Path, manifest: &str, files: &[(&str, &[u8])]) -> std::path::PathBuf {
+        let path = dir.join("neon.zip");
+        let mut writer = zip::ZipWriter::new(File::create(&path).unwrap());
+        let options = zip::write::SimpleFileOptions::default();
+        writer.start_file("cursor-pack.json", options).unwrap();
+        writer.write_all(manifest.as_bytes()).unwrap();
+        for (name, data) in files {
+            writer.start_file(*name, options).unwrap();
+            writer.write_all(data).unwrap();
+        }
+        writer.finish().unwrap();
+        path
+    }
+
+    #[test]
+    fn reads_only_the_sound_declared_for_the_event() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = write_pack(
+            dir.path(),
+            r#"{"version":1,"pack_name":"Neon","mode":"advanced","created_at":"","items":[],
+                "sounds":{"toggle":"sounds/blip.wav"}}"#,
+            &[("sounds/blip.wav", b"RIFF....WAVE")],
+        );
+
+        let wav = read_pack_sound(&path, PackSoundEvent::Toggle).unwrap();
+        assert_eq!(wav.as_deref(), Some(&b"RIFF....WAVE"[..]));
+        assert_eq!(read_pack_sound(&path, PackSoundEvent::Apply).unwrap(), None);
+    }
+
+    #[test]
+    fn a_declared_but_missing_sound_is_an_error() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = write_pack(
+            dir.path(),
+            r#"{"version":1,"pack_name":"Neon","mode":"advanced","created_at":"","items":[],
+                "sounds":{"apply":"tada.wav"}}"#,
+            &[],
+        );
+        assert!(read_pack_sound(&path, PackSoundEvent::Apply).is_err());
+    }
+}
@@ -0,0 +1,277 @@
+/// Optional Windows service that applies the saved cursor scheme at user logon.
+/// Build with: cargo build --bin cursor_changer_service --features logon-service
+///
+/// The tray app can take a while to start on slow machines, so users briefly see the default
+/// Windows cursors after signing in. This service watches for session logons and starts
+/// `cursor_changer_service.exe apply` inside the new session, which applies the scheme the
+/// tray app mirrored to `logon-scheme.json` and exits.
+///
+/// Usage:
+///   cursor_changer_service install    register and start the service (requires admin)
+///   cursor_changer_service uninstall  stop and remove the service (requires admin)
+///   cursor_changer_service apply      apply the saved scheme to the current session
+///   (no arguments)                    run under the service control manager
+///
+/// Add `--json` to `apply` for machine-readable output (see `cursor_changer::cli_output`).
+
+#[cfg(windows)]
+fn main() {
+    use cursor_changer::cli_output::OutputFormat;
+
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+    let format = OutputFormat::take_from_args(&mut args);
+    let result = match args.first().map(String::as_str) {
+        Some("install") => service::install(),
+        Some("uninstall") => service::uninstall(),
+        Some("apply") => std::process::exit(apply_saved_scheme(format)),
+        _ => service::run(),
+    };
+
+    if let Err(e) = result {
+        eprintln!("[cursor_changer_service] {}", e);
+        std::process::exit(1);
+    }
+}
+
+#[cfg(not(windows))]
+fn main() {
+    eprintln!("cursor_changer_service is only supported on Windows");
+    std::process::exit(1);
+}
+
+#[cfg(windows)]
+fn apply_saved_scheme(format: cursor_changer::cli_output::OutputFormat) -> i32 {
+    use cursor_changer::cli_output::{print_report, CliReport};
+    use cursor_changer_tauri::logon_scheme::{apply_logon_scheme, load_logon_scheme};
+    use cursor_changer_tauri::state::policy::load_admin_policy;
+
+    let mut report = CliReport::start("apply");
+    match load_logon_scheme() {
+        Ok(Some(scheme)) => {
+            // The logon screen keeps the Windows cursors while an administrator pins the style
+            if let Err(e) = load_admin_policy().check_cursor_style() {
+                report.error(e);
+                return print_report(&report.finish(), format);
+            }
+            let applied = apply_logon_scheme(&scheme);
+            for role in &applied {
+                report.applied(role);
+            }
+            let mut missing: Vec<&String> = scheme
+                .cursor_paths
+                .keys()
+                .filter(|role| !applied.contains(role))
+                .collect();
+            missing.sort();
+            for role in missing {
+                report.error(format!("Cursor file for {role} no longer exists"));
+            }
+        }
+        // Nothing saved yet is not a failure; the report just has no applied roles
+        Ok(None) => {}
+        Err(e) => report.error(e),
+    }
+    print_report(&report.finish(), format)
+}
+
+#[cfg(windows)]
+mod service {
+    use std::ffi::{c_void, OsString};
+    use std::sync::mpsc;
+    use std::time::Duration;
+
+    use windows::core::PWSTR;
+    use windows::Win32::Foundation::{CloseHandle, HANDLE};
+    use windows::Win32::System::Environment::{CreateEnvironmentBlock, DestroyEnvironmentBlock};
+    use windows::Win32::System::RemoteDesktop::WTSQueryUserToken;
+    use windows::Win32::System::Threading::{
+        CreateProcessAsUserW, CREATE_NO_WINDOW, CREATE_UNICODE_ENVIRONMENT, PROCESS_INFORMATION,
+        STARTUPINFOW,
+    };
+    use windows_service::service::{
+        ServiceAccess, ServiceControl, ServiceControlAccept, ServiceErrorControl, ServiceExitCode,
+        ServiceInfo, ServiceStartType, ServiceState, ServiceStatus, ServiceType,
+    };
+    use windows_service::service_control_handler::{self, ServiceControlHandlerResult};
+    use windows_service::service_manager::{ServiceManager, ServiceManagerAccess};
+    use windows_service::{define_windows_service, service_dispatcher};
+
+    // Must match LOGON_SERVICE_NAME in commands/logon_service_commands.rs
+    const SERVICE_NAME: &str = "CursorChangerLogon";
+    const SERVICE_DISPLAY_NAME: &str = "Cursor Changer Logon";
+    const SERVICE_TYPE: ServiceType = ServiceType::OWN_PROCESS;
+
+    define_windows_service!(ffi_service_main, service_main);
+
+    pub fn run() -> Result<(), String> {
+        service_dispatcher::start(SERVICE_NAME, ffi_service_main)
+            .map_err(|e| format!("Failed to start service dispatcher: {}", e))
+    }
+
+    fn service_main(_arguments: Vec<OsString>) {
+        if let Err(e) = run_service() {
+            eprintln!("[cursor_changer_service] {}", e);
+        }
+    }
+
+    fn run_service() -> Result<(), String> {
+        let (shutdown_tx, shutdown_rx) = mpsc::channel();
+
+        let event_handler = move |control| -> ServiceControlHandlerResult {
+            match control {
+                ServiceControl::Stop => {
+                    let _ = shutdown_tx.send(());
+                    ServiceControlHandlerResult::NoError
+                }
+                ServiceControl::SessionChange(param) => {
+                    if param.reason == windows_service::service::SessionChangeReason::SessionLogon
+                    {
+                        let session_id = param.notification.session_id;
+                        if let Err(e) = launch_apply_in_session(session_id) {
+                            eprintln!(
+                                "[cursor_changer_service] Failed to apply scheme in session {}: {}",
+                                session_id, e
+                            );
+                        }
+                    }
+                    ServiceControlHandlerResult::NoError
+                }
+                ServiceControl::Interrogate => ServiceControlHandlerResult::NoError,
+                _ => ServiceControlHandlerResult::NotImplemented,
+            }
+        };
+
+        let status_handle = service_control_handler::register(SERVICE_NAME, event_handler)
+            .map_err(|e| format!("Failed to register control handler: {}", e))?;
+
+        let running = ServiceStatus {
+            service_type: SERVICE_TYPE,
+            current_state: ServiceState::Running,
+            controls_accepted: ServiceControlAccept::STOP | ServiceControlAccept::SESSION_CHANGE,
+            exit_code: ServiceExitCode::Win32(0),
+            checkpoint: 0,
+            wait_hint: Duration::default(),
+            process_id: None,
+        };
+        status_handle
+            .set_service_status(running.clone())
+            .map_err(|e| format!("Failed to report running status: {}", e))?;
+
+        let _ = shutdown_rx.recv();
+
+        status_handle
+            .set_service_status(ServiceStatus {
+                current_state: ServiceState::Stopped,
+                controls_accepted: ServiceControlAccept::empty(),
+                ..running
+            })
+            .map_err(|e| format!("Failed to report stopped status: {}", e))
+    }
+
+    fn to_wide(value: &str) -> Vec<u16> {
+        value.encode_utf16().chain(std::iter::once(0)).collect()
+    }
+
+    /// Start `<this exe> apply` as the user who just logged on, on their interactive desktop.
+    fn launch_apply_in_session(session_id: u32) -> Result<(), String> {
+        let exe = std::env::current_exe().map_err(|e| e.to_string())?;
+        let mut command_line = to_wide(&format!("\"{}\" apply", exe.display()));
+        let mut desktop = to_wide("winsta0\\default");
+
+        unsafe {
+            let mut token = HANDLE::default();
+            WTSQueryUserToken(session_id, &mut token)
+                .map_err(|e| format!("WTSQueryUserToken failed: {}", e))?;
+
+            // Give the child the user's environment so %APPDATA% points at their profile.
+            let mut environment: *mut c_void = std::ptr::null_mut();
+            let has_environment =
+                CreateEnvironmentBlock(&mut environment, Some(token), false).is_ok();
+
+            let startup_info = STARTUPINFOW {
+                cb: std::mem::size_of::<STARTUPINFOW>() as u32,
+                lpDesktop: PWSTR(desktop.as_mut_ptr()),
+                ..Default::default()
+            };
+            let mut process_info = PROCESS_INFORMATION::default();
+
+            let result = CreateProcessAsUserW(
+                Some(token),
+                None,
+                Some(PWSTR(command_line.as_mut_ptr())),
+                None,
+                None,
+                false,
+                CREATE_NO_WINDOW | CREATE_UNICODE_ENVIRONMENT,
+                has_environment.then_some(environment as *const c_void),
+                None,
+                &startup_info,
+                &mut process_info,
+            );
+
+            if has_environment {
+                let _ = DestroyEnvironmentBlock(environment);
+            }
+            let _ = CloseHandle(token);
+
+            result.map_err(|e| format!("CreateProcessAsUserW failed: {}", e))?;
+            let _ = CloseHandle(process_info.hThread);
+            let _ = CloseHandle(process_info.hProcess);
+        }
+
+        Ok(())
+    }
+
+    pub fn install() -> Result<(), String> {
+        let manager = ServiceManager::local_computer(
+            None::<&str>,
+            ServiceManagerAccess::CONNECT | ServiceManagerAccess::CREATE_SERVICE,
+        )
+        .map_err(|e| format!("Failed to connect to service manager: {}", e))?;
+
+        let info = ServiceInfo {
+            name: OsString::from(SERVICE_NAME),
+            display_name: OsString::from(SERVICE_DISPLAY_NAME),
+            service_type: SERVICE_TYPE,
+            start_type: ServiceStartType::AutoStart,
+            error_control: ServiceErrorControl::Normal,
+            executable_path: std::env::current_exe().map_err(|e| e.to_string())?,
+            launch_arguments: vec![],
+            dependencies: vec![],
+            account_name: None, // LocalSystem, required for WTSQueryUserToken
+            account_password: None,
+        };
+
+        let service = manager
+            .create_service(&info, ServiceAccess::CHANGE_CONFIG | ServiceAccess::START)
+            .map_err(|e| format!("Failed to create service: {}", e))?;
+        let _ = service.set_description(
+            "Applies your Cursor Changer cursors at sign-in, before the tray app starts.",
+        );
+        service
+            .start::<&str>(&[])
+            .map_err(|e| format!("Failed to start service: {}", e))
+    }
+
+    pub fn uninstall() -> Result<(), String> {
+        let manager = ServiceManager::local_computer(None::<&str>, ServiceManagerAccess::CONNECT)
+            .map_err(|e| format!("Failed to connect to service manager: {}", e))?;
+        let service = manager
+            .open_service(
+                SERVICE_NAME,
+                ServiceAccess::QUERY_STATUS | ServiceAccess::STOP | ServiceAccess::DELETE,
+            )
+            .map_err(|e| format!("Failed to open service: {}", e))?;
+
+        if let Ok(status) = service.query_status() {
+            if status.current_state != ServiceState::Stopped {
+                let _ = service.stop();
+                std::thread::sleep(Duration::from_millis(500));
+            }
+        }
+
+        service
+            .delete()
+            .map_err(|e| format!("Failed to delete service: {}", e))
+    }
+}
@@ -9,12 +9,67 @@ use ts_rs::TS;
 
 // Import types from the library crate
 use cursor_changer_tauri::commands::customization::{
-    library::{AniPreviewData, LibraryCursor, LibraryPackItem, LibraryPackMetadata},
-    pack_commands::PackFilePreview,
+    file_ops::{
+        BatchUploadFailure, BatchUploadReport, BrowsedCursorFile, DirectoryConversionFile,
+        DirectoryConversionReport, HotspotCalibrationResult, HotspotCalibrationSession,
+        PreviewParams, PreviewSessionInfo, UploadedCursorFile,
+    },
+    library::{
+        AniPreviewData, CursorMetadataUpdate, CursorSourceLink, CursorVariantSettings,
+        DefaultItemKind, DefaultItemResult, DefaultsInitReport, LibraryCollection, LibraryCursor,
+        LibraryCursorVariant, LibraryFilter, LibraryPackItem, LibraryPackMetadata, LibraryPage,
+        LibrarySort, OversizedAniPayload,
+    },
+    migration::{MigrationReport, MigrationSource, MigrationSourceKind},
+    pack_commands::{PackDetails, PackFilePreview, PackImport},
+    pack_cursorfx::CursorThemeImport,
+    pack_integrity::{PackIntegrityIssue, PackIntegrityIssueKind, PackIntegrityReport},
+    pack_manifest::{PackChangelogEntry, PackSoundEvent},
+    pack_schedule::{ScheduledApply, ScheduledApplyCountdown},
+    pack_trial::PackTrialStatus,
+    pack_updates::{PackUpdateReport, PackUpdateStatus},
+    query::{AppliedCursorPreview, DefaultCursorPreview, DefaultPreviewSource},
+    role_groups::{RoleGroup, RoleGroupInfo},
 };
+use cursor_changer_tauri::apply_verification::{ApplyVerificationFailure, ApplyVerificationWarning};
+use cursor_changer_tauri::cache_gc::CacheGcReport;
+use cursor_changer_tauri::cursor_brightness::{AdaptiveBrightnessSettings, AdaptiveBrightnessSource};
+use cursor_changer_tauri::effects_quality::{EffectQuality, EffectQualitySettings, EffectQualityStatus, EffectRenderProfile};
+use cursor_changer_tauri::reduced_motion::{ReducedMotionSettings, ReducedMotionStatus};
+use cursor_changer_tauri::svg_resources::SvgResourceSettings;
+use cursor_changer_tauri::status_file::StatusFileSettings;
+use cursor_changer_tauri::health_endpoint::HealthEndpointSettings;
+use cursor_changer_tauri::decode_worker::DecodeSandboxSettings;
+use cursor_changer_tauri::hide_roles::HideRolesSettings;
+use cursor_changer_tauri::memory_policy::{MemoryPolicy, MemoryPolicySettings};
+use cursor_changer_tauri::conversion_output::ConversionOutputSettings;
+use cursor_changer_tauri::storage_location::{StorageLocationSettings, StorageRelocationReport};
+use cursor_changer_tauri::crash_reports::{CrashReport, CrashReportSettings};
+use cursor_changer_tauri::usage_stats::{PackUsage, UsageStats, UsageStatsSettings};
+use cursor_changer_tauri::uninstall_cleanup::{
+    CleanupFailure, CleanupItem, CleanupItemKind, UninstallCleanupReport,
+};
+use cursor_changer_tauri::operations::{
+    OperationCancelledPayload, OperationPhase, OperationProgressPayload,
+};
+use cursor_changer_tauri::pending_jobs::{PendingJob, PendingJobKind};
+use cursor_changer_tauri::error_codes::LocalizedError;
+use cursor_changer_tauri::hotkey_chords::{HotkeyChordHint, HotkeyChordOption, HotkeyChordSettings};
+use cursor_changer_tauri::hotkey_press::{HotkeyPressAction, HotkeyPressSettings};
+use cursor_changer_tauri::size_gesture::{SizeGestureModifier, SizeGesturePayload, SizeGestureSettings};
+use cursor_changer_tauri::pointer_control::{PointerControlSettings, PointerKeyBindings};
+use cursor_changer_tauri::hot_corners::{HotCornerAction, HotCornerSettings};
+use cursor_changer_tauri::monitor_schemes::{MonitorSchemeOverride, MonitorSchemeSettings};
+use cursor_changer_tauri::commands::benchmark_commands::{BenchmarkReport, BenchmarkResult};
+use cursor_changer_tauri::commands::folder_watcher::LibraryImportStatus;
+use cursor_changer_tauri::commands::logon_service_commands::LogonServiceStatus;
+use cursor_changer_tauri::commands::screen_color_commands::PickedScreenColor;
 use cursor_changer_tauri::state::app_state::CursorInfo;
 use cursor_changer_tauri::state::types::{CursorClickPointInfo, CursorStatePayload};
-use cursor_changer_tauri::state::{CustomizationMode, DefaultCursorStyle, ThemeMode};
+use cursor_changer_tauri::state::config::{ConfigFieldIssue, ConfigValidationReport};
+use cursor_changer_tauri::state::{
+    CursorVisibility, CustomizationMode, DefaultCursorStyle, HideSnapshot, PolicyLocks, ThemeMode,
+};
 
 fn main() {
     println!("Generating TypeScript types...");
@@ -23,6 +78,12 @@ fn main() {
     CursorStatePayload::export().expect("Failed to export CursorStatePayload");
     println!("✓ Generated CursorStatePayload.ts");
 
+    CursorVisibility::export().expect("Failed to export CursorVisibility");
+    println!("✓ Generated CursorVisibility.ts");
+
+    HideSnapshot::export().expect("Failed to export HideSnapshot");
+    println!("✓ Generated HideSnapshot.ts");
+
     CursorClickPointInfo::export().expect("Failed to export CursorClickPointInfo");
     println!("✓ Generated CursorClickPointInfo.ts");
 
@@ -38,17 +99,230 @@ fn main() {
     CustomizationMode::export().expect("Failed to export CustomizationMode");
     println!("✓ Generated CustomizationMode.ts");
 
+    PolicyLocks::export().expect("Failed to export PolicyLocks");
+    println!("✓ Generated PolicyLocks.ts");
+
     LibraryCursor::export().expect("Failed to export LibraryCursor");
     println!("✓ Generated LibraryCursor.ts");
+    LibraryCursorVariant::export().expect("Failed to export LibraryCursorVariant");
+    println!("✓ Generated LibraryCursorVariant.ts");
+    OversizedAniPayload::export().expect("Failed to export OversizedAniPayload");
+    println!("✓ Generated OversizedAniPayload.ts");
+    UploadedCursorFile::export().expect("Failed to export UploadedCursorFile");
+    println!("✓ Generated UploadedCursorFile.ts");
+    BrowsedCursorFile::export().expect("Failed to export BrowsedCursorFile");
+    println!("✓ Generated BrowsedCursorFile.ts");
+    BatchUploadFailure::export().expect("Failed to export BatchUploadFailure");
+    println!("✓ Generated BatchUploadFailure.ts");
+    BatchUploadReport::export().expect("Failed to export BatchUploadReport");
+    println!("✓ Generated BatchUploadReport.ts");
+    DirectoryConversionFile::export().expect("Failed to export DirectoryConversionFile");
+    println!("✓ Generated DirectoryConversionFile.ts");
+    DirectoryConversionReport::export().expect("Failed to export DirectoryConversionReport");
+    println!("✓ Generated DirectoryConversionReport.ts");
+    LibrarySort::export().expect("Failed to export LibrarySort");
+    println!("✓ Generated LibrarySort.ts");
+    LibraryCollection::export().expect("Failed to export LibraryCollection");
+    println!("✓ Generated LibraryCollection.ts");
+    LibraryFilter::export().expect("Failed to export LibraryFilter");
+    println!("✓ Generated LibraryFilter.ts");
+    LibraryPage::export().expect("Failed to export LibraryPage");
+    println!("✓ Generated LibraryPage.ts");
+    CursorMetadataUpdate::export().expect("Failed to export CursorMetadataUpdate");
+    println!("✓ Generated CursorMetadataUpdate.ts");
+    CursorSourceLink::export().expect("Failed to export CursorSourceLink");
+    println!("✓ Generated CursorSourceLink.ts");
     LibraryPackMetadata::export().expect("Failed to export LibraryPackMetadata");
     println!("✓ Generated LibraryPackMetadata.ts");
     LibraryPackItem::export().expect("Failed to export LibraryPackItem");
     println!("✓ Generated LibraryPackItem.ts");
     AniPreviewData::export().expect("Failed to export AniPreviewData");
     println!("✓ Generated AniPreviewData.ts");
+    DefaultItemKind::export().expect("Failed to export DefaultItemKind");
+    println!("✓ Generated DefaultItemKind.ts");
+    DefaultItemResult::export().expect("Failed to export DefaultItemResult");
+    println!("✓ Generated DefaultItemResult.ts");
+    DefaultsInitReport::export().expect("Failed to export DefaultsInitReport");
+    println!("✓ Generated DefaultsInitReport.ts");
 
     PackFilePreview::export().expect("Failed to export PackFilePreview");
     println!("✓ Generated PackFilePreview.ts");
+    PackChangelogEntry::export().expect("Failed to export PackChangelogEntry");
+    println!("✓ Generated PackChangelogEntry.ts");
+
+    PackSoundEvent::export().expect("Failed to export PackSoundEvent");
+    println!("✓ Generated PackSoundEvent.ts");
+    PackDetails::export().expect("Failed to export PackDetails");
+    println!("✓ Generated PackDetails.ts");
+    PackImport::export().expect("Failed to export PackImport");
+    println!("✓ Generated PackImport.ts");
+    ScheduledApply::export().expect("Failed to export ScheduledApply");
+    println!("✓ Generated ScheduledApply.ts");
+    ScheduledApplyCountdown::export().expect("Failed to export ScheduledApplyCountdown");
+    println!("✓ Generated ScheduledApplyCountdown.ts");
+    PackUpdateStatus::export().expect("Failed to export PackUpdateStatus");
+    println!("✓ Generated PackUpdateStatus.ts");
+    PackUpdateReport::export().expect("Failed to export PackUpdateReport");
+    println!("✓ Generated PackUpdateReport.ts");
+    CursorThemeImport::export().expect("Failed to export CursorThemeImport");
+    println!("✓ Generated CursorThemeImport.ts");
+    MigrationSourceKind::export().expect("Failed to export MigrationSourceKind");
+    println!("✓ Generated MigrationSourceKind.ts");
+    MigrationSource::export().expect("Failed to export MigrationSource");
+    println!("✓ Generated MigrationSource.ts");
+    MigrationReport::export().expect("Failed to export MigrationReport");
+    println!("✓ Generated MigrationReport.ts");
+    PackIntegrityIssueKind::export().expect("Failed to export PackIntegrityIssueKind");
+    println!("✓ Generated PackIntegrityIssueKind.ts");
+    PackIntegrityIssue::export().expect("Failed to export PackIntegrityIssue");
+    println!("✓ Generated PackIntegrityIssue.ts");
+    PackIntegrityReport::export().expect("Failed to export PackIntegrityReport");
+    println!("✓ Generated PackIntegrityReport.ts");
+    PackTrialStatus::export().expect("Failed to export PackTrialStatus");
+    println!("✓ Generated PackTrialStatus.ts");
+
+    HotspotCalibrationSession::export().expect("Failed to export HotspotCalibrationSession");
+    println!("✓ Generated HotspotCalibrationSession.ts");
+    HotspotCalibrationResult::export().expect("Failed to export HotspotCalibrationResult");
+    println!("✓ Generated HotspotCalibrationResult.ts");
+    PreviewSessionInfo::export().expect("Failed to export PreviewSessionInfo");
+    println!("✓ Generated PreviewSessionInfo.ts");
+    PreviewParams::export().expect("Failed to export PreviewParams");
+    println!("✓ Generated PreviewParams.ts");
+
+    LogonServiceStatus::export().expect("Failed to export LogonServiceStatus");
+    println!("✓ Generated LogonServiceStatus.ts");
+
+    LibraryImportStatus::export().expect("Failed to export LibraryImportStatus");
+    println!("✓ Generated LibraryImportStatus.ts");
+
+    CacheGcReport::export().expect("Failed to export CacheGcReport");
+    println!("✓ Generated CacheGcReport.ts");
+
+    HotkeyChordSettings::export().expect("Failed to export HotkeyChordSettings");
+    println!("✓ Generated HotkeyChordSettings.ts");
+    HotkeyChordOption::export().expect("Failed to export HotkeyChordOption");
+    println!("✓ Generated HotkeyChordOption.ts");
+    HotkeyChordHint::export().expect("Failed to export HotkeyChordHint");
+    println!("✓ Generated HotkeyChordHint.ts");
+    HotkeyPressAction::export().expect("Failed to export HotkeyPressAction");
+    println!("✓ Generated HotkeyPressAction.ts");
+    HotkeyPressSettings::export().expect("Failed to export HotkeyPressSettings");
+    println!("✓ Generated HotkeyPressSettings.ts");
+    SizeGestureModifier::export().expect("Failed to export SizeGestureModifier");
+    println!("✓ Generated SizeGestureModifier.ts");
+    SizeGestureSettings::export().expect("Failed to export SizeGestureSettings");
+    println!("✓ Generated SizeGestureSettings.ts");
+    SizeGesturePayload::export().expect("Failed to export SizeGesturePayload");
+    println!("✓ Generated SizeGesturePayload.ts");
+    PointerKeyBindings::export().expect("Failed to export PointerKeyBindings");
+    println!("✓ Generated PointerKeyBindings.ts");
+    PointerControlSettings::export().expect("Failed to export PointerControlSettings");
+    println!("✓ Generated PointerControlSettings.ts");
+    HotCornerAction::export().expect("Failed to export HotCornerAction");
+    println!("✓ Generated HotCornerAction.ts");
+    HotCornerSettings::export().expect("Failed to export HotCornerSettings");
+    println!("✓ Generated HotCornerSettings.ts");
+    MonitorSchemeOverride::export().expect("Failed to export MonitorSchemeOverride");
+    println!("✓ Generated MonitorSchemeOverride.ts");
+    MonitorSchemeSettings::export().expect("Failed to export MonitorSchemeSettings");
+    println!("✓ Generated MonitorSchemeSettings.ts");
+    BenchmarkResult::export().expect("Failed to export BenchmarkResult");
+    println!("✓ Generated BenchmarkResult.ts");
+    BenchmarkReport::export().expect("Failed to export BenchmarkReport");
+    println!("✓ Generated BenchmarkReport.ts");
+    PickedScreenColor::export().expect("Failed to export PickedScreenColor");
+    println!("✓ Generated PickedScreenColor.ts");
+    ConfigFieldIssue::export().expect("Failed to export ConfigFieldIssue");
+    println!("✓ Generated ConfigFieldIssue.ts");
+    ConfigValidationReport::export().expect("Failed to export ConfigValidationReport");
+    println!("✓ Generated ConfigValidationReport.ts");
+
+    StatusFileSettings::export().expect("Failed to export StatusFileSettings");
+    println!("✓ Generated StatusFileSettings.ts");
+
+    HealthEndpointSettings::export().expect("Failed to export HealthEndpointSettings");
+    println!("✓ Generated HealthEndpointSettings.ts");
+    DecodeSandboxSettings::export().expect("Failed to export DecodeSandboxSettings");
+    println!("✓ Generated DecodeSandboxSettings.ts");
+    HideRolesSettings::export().expect("Failed to export HideRolesSettings");
+    println!("✓ Generated HideRolesSettings.ts");
+    MemoryPolicy::export().expect("Failed to export MemoryPolicy");
+    println!("✓ Generated MemoryPolicy.ts");
+    MemoryPolicySettings::export().expect("Failed to export MemoryPolicySettings");
+    println!("✓ Generated MemoryPolicySettings.ts");
+    ConversionOutputSettings::export().expect("Failed to export ConversionOutputSettings");
+    println!("✓ Generated ConversionOutputSettings.ts");
+    StorageLocationSettings::export().expect("Failed to export StorageLocationSettings");
+    println!("✓ Generated StorageLocationSettings.ts");
+    StorageRelocationReport::export().expect("Failed to export StorageRelocationReport");
+    println!("✓ Generated StorageRelocationReport.ts");
+    RoleGroup::export().expect("Failed to export RoleGroup");
+    println!("✓ Generated RoleGroup.ts");
+    RoleGroupInfo::export().expect("Failed to export RoleGroupInfo");
+    println!("✓ Generated RoleGroupInfo.ts");
+    CrashReportSettings::export().expect("Failed to export CrashReportSettings");
+    println!("✓ Generated CrashReportSettings.ts");
+    CrashReport::export().expect("Failed to export CrashReport");
+    println!("✓ Generated CrashReport.ts");
+    UsageStatsSettings::export().expect("Failed to export UsageStatsSettings");
+    println!("✓ Generated UsageStatsSettings.ts");
+    PackUsage::export().expect("Failed to export PackUsage");
+    println!("✓ Generated PackUsage.ts");
+    UsageStats::export().expect("Failed to export UsageStats");
+    println!("✓ Generated UsageStats.ts");
+    CleanupItemKind::export().expect("Failed to export CleanupItemKind");
+    println!("✓ Generated CleanupItemKind.ts");
+    CleanupItem::export().expect("Failed to export CleanupItem");
+    println!("✓ Generated CleanupItem.ts");
+    CleanupFailure::export().expect("Failed to export CleanupFailure");
+    println!("✓ Generated CleanupFailure.ts");
+    UninstallCleanupReport::export().expect("Failed to export UninstallCleanupReport");
+    println!("✓ Generated UninstallCleanupReport.ts");
+    ReducedMotionSettings::export().expect("Failed to export ReducedMotionSettings");
+    println!("✓ Generated ReducedMotionSettings.ts");
+    ReducedMotionStatus::export().expect("Failed to export ReducedMotionStatus");
+    println!("✓ Generated ReducedMotionStatus.ts");
+    EffectQuality::export().expect("Failed to export EffectQuality");
+    println!("✓ Generated EffectQuality.ts");
+    EffectRenderProfile::export().expect("Failed to export EffectRenderProfile");
+    println!("✓ Generated EffectRenderProfile.ts");
+    EffectQualitySettings::export().expect("Failed to export EffectQualitySettings");
+    println!("✓ Generated EffectQualitySettings.ts");
+    EffectQualityStatus::export().expect("Failed to export EffectQualityStatus");
+    println!("✓ Generated EffectQualityStatus.ts");
+    SvgResourceSettings::export().expect("Failed to export SvgResourceSettings");
+    println!("✓ Generated SvgResourceSettings.ts");
+    CursorVariantSettings::export().expect("Failed to export CursorVariantSettings");
+    println!("✓ Generated CursorVariantSettings.ts");
+    DefaultPreviewSource::export().expect("Failed to export DefaultPreviewSource");
+    println!("✓ Generated DefaultPreviewSource.ts");
+    DefaultCursorPreview::export().expect("Failed to export DefaultCursorPreview");
+    println!("✓ Generated DefaultCursorPreview.ts");
+    AppliedCursorPreview::export().expect("Failed to export AppliedCursorPreview");
+    println!("✓ Generated AppliedCursorPreview.ts");
+    AdaptiveBrightnessSource::export().expect("Failed to export AdaptiveBrightnessSource");
+    println!("✓ Generated AdaptiveBrightnessSource.ts");
+    AdaptiveBrightnessSettings::export().expect("Failed to export AdaptiveBrightnessSettings");
+    println!("✓ Generated AdaptiveBrightnessSettings.ts");
+
+    ApplyVerificationFailure::export().expect("Failed to export ApplyVerificationFailure");
+    println!("✓ Generated ApplyVerificationFailure.ts");
+    ApplyVerificationWarning::export().expect("Failed to export ApplyVerificationWarning");
+    println!("✓ Generated ApplyVerificationWarning.ts");
+
+    OperationPhase::export().expect("Failed to export OperationPhase");
+    println!("✓ Generated OperationPhase.ts");
+    OperationProgressPayload::export().expect("Failed to export OperationProgressPayload");
+    println!("✓ Generated OperationProgressPayload.ts");
+    OperationCancelledPayload::export().expect("Failed to export OperationCancelledPayload");
+    println!("✓ Generated OperationCancelledPayload.ts");
+    PendingJobKind::export().expect("Failed to export PendingJobKind");
+    println!("✓ Generated PendingJobKind.ts");
+    PendingJob::export().expect("Failed to export PendingJob");
+    println!("✓ Generated PendingJob.ts");
+    LocalizedError::export().expect("Failed to export LocalizedError");
+    println!("✓ Generated LocalizedError.ts");
 
     println!("\n✅ All TypeScript types generated successfully!");
     println!("Location: frontend-vite/src/types/generated/");
@@ -0,0 +1,400 @@
+//! Garbage collection for on-disk caches that outlive a session.
+//!
+//! Runs at startup and then every few hours:
+//! - removes leftover temp files from image conversion,
+//! - removes pack folders and pack mounts whose pack is no longer in the library,
+//! - removes pack-mount files that no cursor path references,
+//! - evicts least recently used ANI previews and served preview images until the preview
+//!   caches fit the quota.
+//!
+//! The quota only covers what can be evicted. Pack mounts hold applied cursors, and previews
+//! whose URL the webview received this session must keep resolving, so neither counts.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+use tauri::{AppHandle, Emitter, Manager, Runtime};
+
+use crate::commands::customization::library::load_library;
+use crate::commands::customization::pack_mount;
+use crate::state::settings_file::{self, SettingsFile};
+use crate::state::AppState;
+use crate::timers::{self, TimerPriority};
+
+pub const CACHE_GC_FILE_NAME: &str = "cache-gc.json";
+pub const DEFAULT_CACHE_QUOTA_MB: u64 = 256;
+const GC_INTERVAL: Duration = Duration::from_secs(6 * 60 * 60);
+/// Temp files younger than this may still belong to a running conversion.
+const TEMP_FILE_MIN_AGE: Duration = Duration::from_secs(60 * 60);
+const TEMP_FILE_PREFIX: &str = "cursor_temp_";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CacheGcSettings {
+    #[serde(default = "default_quota_mb")]
+    pub quota_mb: u64,
+}
+
+fn default_quota_mb() -> u64 {
+    DEFAULT_CACHE_QUOTA_MB
+}
+
+impl Default for CacheGcSettings {
+    fn default() -> Self {
+        Self {
+            quota_mb: DEFAULT_CACHE_QUOTA_MB,
+        }
+    }
+}
+
+#[derive(ts_rs::TS, Serialize, Clone, Debug, Default, PartialEq, Eq)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct CacheGcReport {
+    #[ts(type = "number")]
+    pub reclaimed_bytes: u64,
+    pub removed_entries: u32,
+    /// Size of the evictable preview caches left after collection
+    #[ts(type = "number")]
+    pub remaining_bytes: u64,
+    #[ts(type = "number")]
+    pub quota_bytes: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct CacheEntry {
+    path: PathBuf,
+    size: u64,
+    last_used: SystemTime,
+}
+
+impl SettingsFile for CacheGcSettings {
+    const FILE_NAME: &'static str = CACHE_GC_FILE_NAME;
+    const DESCRIPTION: &'static str = "cache settings";
+}
+
+pub fn load_settings<R: Runtime>(app: &AppHandle<R>) -> CacheGcSettings {
+    settings_file::load(app)
+}
+
+pub fn save_settings<R: Runtime>(app: &AppHandle<R>, settings: &CacheGcSettings) -> Result<(), String> {
+    settings_file::save(app, settings)
+}
+
+/// Run collection at startup and then every `GC_INTERVAL`. Collection is deferrable, so it
+/// waits while battery saver is on.
+pub fn schedule<R: Runtime>(app: AppHandle<R>) {
+    timers::every(
+        Duration::ZERO,
+        GC_INTERVAL,
+        TimerPriority::Deferrable,
+        move || {
+            let report = run(&app);
+            if report.removed_entries > 0 {
+                let _ = app.emit(crate::events::CACHE_GC_REPORT, report);
+            }
+        },
+    );
+}
+
+pub fn run<R: Runtime>(app: &AppHandle<R>) -> CacheGcReport {
+    let quota_bytes = load_settings(app).quota_mb.saturating_mul(1024 * 1024);
+    let mut report = CacheGcReport {
+        quota_bytes,
+        ..Default::default()
+    };
+
+    remove_stale_temp_files(&std::env::temp_dir(), &mut report);
+    remove_orphaned_pack_data(app, &mut report);
+
+    let preview_dirs: Vec<PathBuf> = [
+        crate::paths::ani_preview_cache_dir(),
+        crate::paths::preview_cache_dir(),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+    let evictable: Vec<CacheEntry> = preview_dirs
+        .iter()
+        .flat_map(|previews| list_entries(previews))
+        .filter(|e| !crate::preview_protocol::is_published_this_session(&e.path))
+        .collect();
+    let total: u64 = evictable.iter().map(|e| e.size).sum();
+
+    let mut remaining = total;
+    for entry in select_evictions(evictable, total, quota_bytes) {
+        if remove_path(&entry.path) {
+            report.reclaimed_bytes += entry.size;
+            report.removed_entries += 1;
+            remaining -= entry.size;
+        }
+    }
+    report.remaining_bytes = remaining;
+
+    cc_debug!(
+        "[CursorChanger] Cache GC reclaimed {} bytes ({} entries), {} bytes remain",
+        report.reclaimed_bytes,
+        report.removed_entries,
+        report.remaining_bytes
+    );
+    report
+}
+
+/// Oldest-first entries to delete so that `total` drops to `quota` or below.
+fn select_evictions(mut entries: Vec<CacheEntry>, total: u64, quota: u64) -> Vec<CacheEntry> {
+    if total <= quota {
+        return Vec::new();
+    }
+    entries.sort_by_key(|e| e.last_used);
+
+    let mut over = total - quota;
+    let mut evicted = Vec::new();
+    for entry in entries {
+        if over == 0 {
+            break;
+        }
+        over = over.saturating_sub(entry.size);
+        evicted.push(entry);
+    }
+    evicted
+}
+
+fn remove_stale_temp_files(temp_dir: &Path, report: &mut CacheGcReport) {
+    let Ok(read_dir) = fs::read_dir(temp_dir) else {
+        return;
+    };
+    let now = SystemTime::now();
+
+    for entry in read_dir.flatten() {
+        let name = entry.file_name();
+        if !name.to_string_lossy().starts_with(TEMP_FILE_PREFIX) {
+            continue;
+        }
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+        let old_enough = metadata
+            .modified()
+            .ok()
+            .and_then(|modified| now.duration_since(modified).ok())
+            .is_some_and(|age| age >= TEMP_FILE_MIN_AGE);
+        if metadata.is_file() && old_enough && fs::remove_file(entry.path()).is_ok() {
+            report.reclaimed_bytes += metadata.len();
+            report.removed_entries += 1;
+        }
+    }
+}
+
+fn remove_orphaned_pack_data<R: Runtime>(app: &AppHandle<R>, report: &mut CacheGcReport) {
+    let Ok(library) = load_library(app) else {
+        return;
+    };
+    let pack_ids: HashSet<&str> = library
+        .cursors
+        .iter()
+        .filter(|c| c.is_pack)
+        .map(|c| c.id.as_str())
+        .collect();
+
+    if let Ok(packs_dir) = crate::paths::pack_cache_dir() {
+        let before = dir_size(&packs_dir);
+        if let Err(e) = crate::commands::customization::library::cleanup_orphaned_pack_folders(app)
+        {
+            cc_warn!("[CursorChanger] Failed to cleanup orphaned pack folders: {}", e);
+        }
+        report.reclaimed_bytes += before.saturating_sub(dir_size(&packs_dir));
+    }
+
+    let Ok(mounts_dir) = crate::paths::pack_mounts_dir() else {
+        return;
+    };
+    // Held from the snapshot to the last deletion, so a pack applied meanwhile waits instead
+    // of having its fresh files pruned
+    let _mounts = pack_mount::lock_mounts();
+    let referenced: HashSet<PathBuf> = match app.state::<AppState>().read_all() {
+        Ok(guard) => guard
+            .cursor
+            .cursor_paths
+            .values()
+            .chain(guard.modes.simple_mode_cursor_paths.values())
+            .chain(guard.modes.advanced_mode_cursor_paths.values())
+            .map(PathBuf::from)
+            .collect(),
+        // Without state we can't tell what's applied; leave mounts alone.
+        Err(_) => return,
+    };
+    remove_unused_mounts(&mounts_dir, &pack_ids, &referenced, report);
+}
+
+/// Prune unreferenced files from pack mounts, removing whole mounts of packs no longer in the
+/// library unless a cursor still points into them (a removed pack that is still applied).
+fn remove_unused_mounts(
+    mounts_dir: &Path,
+    pack_ids: &HashSet<&str>,
+    referenced: &HashSet<PathBuf>,
+    report: &mut CacheGcReport,
+) {
+    for entry in list_entries(mounts_dir) {
+        let name = entry.path.file_name().map(|n| n.to_string_lossy().to_string());
+        let in_library = name.is_some_and(|n| pack_ids.contains(n.as_str()));
+        if in_library || pack_mount::holds_referenced(&entry.path, referenced) {
+            let before = dir_size(&entry.path);
+            let removed = pack_mount::prune_unreferenced(&entry.path, referenced);
+            report.reclaimed_bytes += before.saturating_sub(dir_size(&entry.path));
+            report.removed_entries += removed as u32;
+        } else if remove_path(&entry.path) {
+            report.reclaimed_bytes += entry.size;
+            report.removed_entries += 1;
+        }
+    }
+}
+
+/// Direct children of `dir`, each with its total size and most recent modification time.
+fn list_entries(dir: &Path) -> Vec<CacheEntry> {
+    let Ok(read_dir) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    read_dir
+        .flatten()
+        .map(|entry| {
+            let path = entry.path();
+            CacheEntry {
+                size: dir_size(&path),
+                last_used: last_used(&path),
+                path,
+            }
+        })
+        .collect()
+}
+
+fn dir_size(path: &Path) -> u64 {
+    let Ok(metadata) = fs::metadata(path) else {
+        return 0;
+    };
+    if metadata.is_file() {
+        return metadata.len();
+    }
+    fs::read_dir(path)
+        .map(|entries| entries.flatten().map(|e| dir_size(&e.path())).sum())
+        .unwrap_or(0)
+}
+
+fn last_used(path: &Path) -> SystemTime {
+    let own = fs::metadata(path)
+        .and_then(|m| m.modified())
+        .unwrap_or(SystemTime::UNIX_EPOCH);
+    if !path.is_dir() {
+        return own;
+    }
+    fs::read_dir(path)
+        .map(|entries| entries.flatten().map(|e| last_used(&e.path())).fold(own, SystemTime::max))
+        .unwrap_or(own)
+}
+
+fn remove_path(path: &Path) -> bool {
+    let result = if path.is_dir() {
+        fs::remove_dir_all(path)
+    } else {
+        fs::remove_file(path)
+    };
+    match result {
+        Ok(()) => true,
+        Err(e) => {
+            cc_warn!("[CursorChanger] Cache GC failed to remove {}: {}", path.display(), e);
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(name: &str, size: u64, age_secs: u64) -> CacheEntry {
+        CacheEntry {
+            path: PathBuf::from(name),
+            size,
+            last_used: SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000 - age_secs),
+        }
+    }
+
+    #[test]
+    fn evicts_least_recently_used_until_under_quota() {
+        let entries = vec![entry("new", 40, 10), entry("old", 30, 500), entry("mid", 30, 100)];
+
+        let evicted = select_evictions(entries, 100, 50);
+        let names: Vec<_> = evicted.iter().map(|e| e.path.to_string_lossy().to_string()).collect();
+        assert_eq!(names, vec!["old", "mid"]);
+    }
+
+    #[test]
+    fn nothing_evicted_when_within_quota() {
+        assert!(select_evictions(vec![entry("a", 10, 1)], 10, 10).is_empty());
+    }
+
+    #[test]
+    fn stale_temp_files_are_removed_but_fresh_ones_kept() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let stale = temp.path().join("cursor_temp_1_1.svg");
+        let fresh = temp.path().join("cursor_temp_2_2.svg");
+        let unrelated = temp.path().join("other.svg");
+        for path in [&stale, &fresh, &unrelated] {
+            fs::write(path, b"<svg/>").unwrap();
+        }
+        let two_hours_ago = SystemTime::now() - Duration::from_secs(2 * 60 * 60);
+        fs::File::options()
+            .write(true)
+            .open(&stale)
+            .and_then(|f| f.set_modified(two_hours_ago))
+            .unwrap();
+        fs::File::options()
+            .write(true)
+            .open(&unrelated)
+            .and_then(|f| f.set_modified(two_hours_ago))
+            .unwrap();
+
+        let mut report = CacheGcReport::default();
+        remove_stale_temp_files(temp.path(), &mut report);
+
+        assert!(!stale.exists());
+        assert!(fresh.exists());
+        assert!(unrelated.exists());
+        assert_eq!(report.removed_entries, 1);
+        assert_eq!(report.reclaimed_bytes, 6);
+    }
+
+    #[test]
+    fn mounts_of_removed_packs_are_kept_while_still_applied() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let applied = temp.path().join("removed-but-applied");
+        let gone = temp.path().join("removed");
+        let kept = temp.path().join("in-library");
+        for dir in [&applied, &gone, &kept] {
+            fs::create_dir_all(dir).unwrap();
+            fs::write(dir.join("a.cur"), b"cur").unwrap();
+            fs::write(dir.join("b.cur"), b"cur").unwrap();
+        }
+        let referenced = HashSet::from([applied.join("a.cur"), kept.join("a.cur")]);
+
+        let mut report = CacheGcReport::default();
+        remove_unused_mounts(
+            temp.path(),
+            &HashSet::from(["in-library"]),
+            &referenced,
+            &mut report,
+        );
+
+        assert!(applied.join("a.cur").exists());
+        assert!(!applied.join("b.cur").exists());
+        assert!(!gone.exists());
+        assert!(kept.join("a.cur").exists());
+        assert!(!kept.join("b.cur").exists());
+        assert_eq!(report.removed_entries, 3);
+    }
+
+    #[test]
+    fn settings_default_quota_when_missing() {
+        let parsed: CacheGcSettings = serde_json::from_str("{}").unwrap();
+        assert_eq!(parsed.quota_mb, DEFAULT_CACHE_QUOTA_MB);
+    }
+}
@@ -0,0 +1,362 @@
+//! In-process benchmarks for "it's slow on my PC" reports.
+//!
+//! `run_benchmarks` times the slow paths on the user's own machine: converting an image at
+//! several cursor sizes, rendering library previews, extracting a pack and writing the cursor
+//! scheme to the registry. Inputs are generated in a temp folder, so the numbers don't depend
+//! on the user's library. Each report is saved in the config folder (and so ends up in the
+//! diagnostics bundle), and the next run lists it as the previous result, for comparing
+//! before and after a change on the same machine.
+//!
+//! Only available in debug builds and builds with the `diagnostics` feature.
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+use tauri::{AppHandle, Runtime};
+
+use crate::commands::customization::library::get_library_cursor_preview;
+use crate::commands::customization::pack_commands::{
+    extract_pack_assets, read_manifest_or_infer, PackValidationMode,
+};
+use crate::cursor_converter::{
+    generate_ani_data, generate_cur_data, render_cur_bytes, PngEncoding, RenderParams,
+};
+
+const BENCHMARKS_FILE_NAME: &str = "benchmarks.json";
+const ITERATIONS: u32 = 9;
+/// Registry writes go through the retry policy and touch the live scheme, so fewer runs
+const REGISTRY_ITERATIONS: u32 = 3;
+const CONVERSION_SIZES: [u32; 4] = [32, 64, 128, 256];
+/// Side of the generated source image, larger than every target so each size is a downscale
+const SOURCE_SIZE: u32 = 512;
+const ANI_FRAMES: usize = 8;
+
+#[derive(ts_rs::TS, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct BenchmarkResult {
+    pub name: String,
+    pub iterations: u32,
+    pub median_ms: f64,
+    pub min_ms: f64,
+    pub max_ms: f64,
+    /// Work done per second at the median time, in `throughput_unit`
+    pub throughput: f64,
+    pub throughput_unit: String,
+    /// Median of the same benchmark in the previous saved run
+    pub previous_median_ms: Option<f64>,
+    /// Slower (positive) or faster (negative) than the previous run, in percent
+    pub change_percent: Option<f64>,
+}
+
+#[derive(ts_rs::TS, Serialize, Deserialize, Clone, Debug)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct BenchmarkReport {
+    pub app_version: String,
+    pub os: String,
+    pub arch: String,
+    /// Threads the OS lets the app run in parallel
+    pub cpu_threads: u32,
+    pub ran_at: String,
+    pub results: Vec<BenchmarkResult>,
+    /// When the run the results are compared with was made
+    pub previous_ran_at: Option<String>,
+}
+
+/// Run `work` `iterations` times after one untimed warm-up run. `work` returns how much it
+/// did per run, in `unit`.
+fn measure(
+    name: impl Into<String>,
+    iterations: u32,
+    unit: &str,
+    mut work: impl FnMut() -> Result<f64, String>,
+) -> Result<BenchmarkResult, String> {
+    let mut amount = work()?;
+    let mut times = Vec::with_capacity(iterations as usize);
+    for _ in 0..iterations.max(1) {
+        let start = Instant::now();
+        amount = work()?;
+        times.push(start.elapsed().as_secs_f64() * 1000.0);
+    }
+    Ok(summarize(name.into(), unit, times, amount))
+}
+
+fn summarize(name: String, unit: &str, mut times_ms: Vec<f64>, amount: f64) -> BenchmarkResult {
+    times_ms.sort_by(f64::total_cmp);
+    let mid = times_ms.len() / 2;
+    let median_ms = if times_ms.len() % 2 == 0 {
+        (times_ms[mid - 1] + times_ms[mid]) / 2.0
+    } else {
+        times_ms[mid]
+    };
+    BenchmarkResult {
+        name,
+        iterations: times_ms.len() as u32,
+        median_ms,
+        min_ms: times_ms[0],
+        max_ms: times_ms[times_ms.len() - 1],
+        throughput: if median_ms > 0.0 {
+            amount * 1000.0 / median_ms
+        } else {
+            0.0
+        },
+        throughput_unit: unit.to_string(),
+        previous_median_ms: None,
+        change_percent: None,
+    }
+}
+
+/// Fill in the previous median of every benchmark `previous` also ran.
+fn compare_with_previous(results: &mut [BenchmarkResult], previous: &BenchmarkReport) {
+    for result in results {
+        let Some(before) = previous.results.iter().find(|r| r.name == result.name) else {
+            continue;
+        };
+        result.previous_median_ms = Some(before.median_ms);
+        if before.median_ms > 0.0 {
+            result.change_percent =
+                Some((result.median_ms - before.median_ms) / before.median_ms * 100.0);
+        }
+    }
+}
+
+/// Opaque, detailed source image so PNG encoding can't take shortcuts on flat areas.
+fn source_image() -> image::RgbaImage {
+    image::RgbaImage::from_fn(SOURCE_SIZE, SOURCE_SIZE, |x, y| {
+        image::Rgba([x as u8, y as u8, (x ^ y) as u8, 255])
+    })
+}
+
+fn write_file(path: &Path, data: &[u8]) -> Result<(), String> {
+    fs::write(path, data).map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+}
+
+fn conversion_benchmarks(dir: &Path, iterations: u32) -> Result<Vec<BenchmarkResult>, String> {
+    let source = dir.join("source.png");
+    source_image()
+        .save(&source)
+        .map_err(|e| format!("Failed to write benchmark image: {}", e))?;
+    let source = source.to_string_lossy().to_string();
+
+    CONVERSION_SIZES
+        .iter()
+        .map(|&size| {
+            let params = RenderParams {
+                size,
+                scale: 1.0,
+                offset_x: 0,
+                offset_y: 0,
+            };
+            measure(
+                format!("convert_png_{}px", size),
+                iterations,
+                "conversions/s",
+                || render_cur_bytes(&source, params, (0, 0), PngEncoding::Compact).map(|_| 1.0),
+            )
+        })
+        .collect()
+}
+
+fn preview_benchmarks(dir: &Path, iterations: u32) -> Result<Vec<BenchmarkResult>, String> {
+    let source = source_image();
+    let mut files: Vec<(String, PathBuf)> = Vec::new();
+    for size in [32, 256] {
+        let frame = image::imageops::thumbnail(&source, size, size);
+        let path = dir.join(format!("preview-{}.cur", size));
+        write_file(&path, &generate_cur_data(&frame, 0, 0)?)?;
+        files.push((format!("preview_cur_{}px", size), path));
+    }
+    let frames: Vec<_> = (0..ANI_FRAMES)
+        .map(|i| {
+            let frame = image::imageops::thumbnail(&source, 64, 64);
+            image::imageops::colorops::huerotate(&frame, (i * 360 / ANI_FRAMES) as i32)
+        })
+        .collect();
+    let path = dir.join("preview-64.ani");
+    write_file(&path, &generate_ani_data(&frames, 0, 0, &[6])?)?;
+    files.push(("preview_ani_64px".to_string(), path));
+
+    files
+        .into_iter()
+        .map(|(name, path)| {
+            let path = path.to_string_lossy().to_string();
+            measure(name, iterations, "previews/s", || {
+                get_library_cursor_preview(path.clone()).map(|_| 1.0)
+            })
+        })
+        .collect()
+}
+
+/// Write a pack with every core role as a 128px cursor. Returns the archive path and the
+/// total size of its cursor files.
+fn write_benchmark_pack(dir: &Path) -> Result<(PathBuf, usize), String> {
+    let pack_dir = dir.join("pack");
+    fs::create_dir_all(&pack_dir).map_err(|e| format!("Failed to create folder: {}", e))?;
+    let archive_path = pack_dir.join("benchmark.zip");
+    let file = fs::File::create(&archive_path)
+        .map_err(|e| format!("Failed to create {}: {}", archive_path.display(), e))?;
+
+    let cursor = generate_cur_data(&image::imageops::thumbnail(&source_image(), 128, 128), 0, 0)?;
+    let mut zip_writer = zip::ZipWriter::new(file);
+    let options = zip::write::SimpleFileOptions::default();
+    let mut total = 0;
+    for (_, base_name) in cursor_changer::DEFAULT_CURSOR_BASE_NAMES.iter() {
+        let name = format!("{}.cur", base_name);
+        zip_writer
+            .start_file(name.as_str(), options)
+            .map_err(|e| format!("Failed to start zip entry {}: {}", name, e))?;
+        zip_writer
+            .write_all(&cursor)
+            .map_err(|e| format!("Failed to write {} to zip: {}", name, e))?;
+        total += cursor.len();
+    }
+    zip_writer
+        .finish()
+        .map_err(|e| format!("Failed to finish benchmark pack: {}", e))?;
+    Ok((archive_path, total))
+}
+
+fn pack_benchmark(dir: &Path, iterations: u32) -> Result<BenchmarkResult, String> {
+    let (archive_path, total_bytes) = write_benchmark_pack(dir)?;
+    let manifest = read_manifest_or_infer(&archive_path, PackValidationMode::Strict)?;
+    let megabytes = total_bytes as f64 / (1024.0 * 1024.0);
+    measure("extract_pack", iterations, "MB/s", || {
+        extract_pack_assets("benchmark", &archive_path, &manifest).map(|_| megabytes)
+    })
+}
+
+/// Time writing the cursor scheme back to the registry with the values it already holds.
+/// Windows is not asked to reload cursors, which would drop a hidden or live-applied cursor.
+fn registry_benchmark(iterations: u32) -> Result<BenchmarkResult, String> {
+    let snapshot = cursor_changer::snapshot_cursor_registry_entries();
+    if snapshot.is_empty() {
+        return Err("Failed to read the cursor registry key".to_string());
+    }
+    let roles = snapshot.len() as f64;
+    measure("registry_write_scheme", iterations, "roles/s", || {
+        if cursor_changer::restore_cursor_registry_entries(&snapshot) {
+            Ok(roles)
+        } else {
+            Err("Failed to write the cursor registry key".to_string())
+        }
+    })
+}
+
+/// Everything but the registry benchmark, using `dir` for generated files.
+fn run_file_benchmarks(dir: &Path, iterations: u32) -> Result<Vec<BenchmarkResult>, String> {
+    let mut results = conversion_benchmarks(dir, iterations)?;
+    results.extend(preview_benchmarks(dir, iterations)?);
+    results.push(pack_benchmark(dir, iterations)?);
+    Ok(results)
+}
+
+fn run_benchmarks_sync(config_dir: Option<PathBuf>) -> Result<BenchmarkReport, String> {
+    let temp = tempfile::tempdir().map_err(|e| format!("Failed to create temp folder: {}", e))?;
+    let mut results = run_file_benchmarks(temp.path(), ITERATIONS)?;
+    match registry_benchmark(REGISTRY_ITERATIONS) {
+        Ok(result) => results.push(result),
+        Err(e) => cc_warn!("[CursorChanger] Skipped registry benchmark: {}", e),
+    }
+
+    let saved_path = config_dir.map(|dir| dir.join(BENCHMARKS_FILE_NAME));
+    let previous = saved_path
+        .as_ref()
+        .and_then(|path| fs::read_to_string(path).ok())
+        .and_then(|contents| serde_json::from_str::<BenchmarkReport>(&contents).ok());
+    if let Some(previous) = &previous {
+        compare_with_previous(&mut results, previous);
+    }
+
+    let report = BenchmarkReport {
+        app_version: env!("CARGO_PKG_VERSION").to_string(),
+        os: std::env::consts::OS.to_string(),
+        arch: std::env::consts::ARCH.to_string(),
+        cpu_threads: std::thread::available_parallelism().map_or(1, |n| n.get() as u32),
+        ran_at: crate::utils::library_meta::now_iso8601_utc(),
+        results,
+        previous_ran_at: previous.map(|p| p.ran_at),
+    };
+
+    if let Some(path) = saved_path {
+        let saved = serde_json::to_string_pretty(&report)
+            .map_err(|e| format!("Failed to serialize benchmarks: {}", e))
+            .and_then(|json| write_file(&path, json.as_bytes()));
+        if let Err(e) = saved {
+            cc_warn!("[CursorChanger] Failed to save benchmark results: {}", e);
+        }
+    }
+    Ok(report)
+}
+
+/// Time conversion, preview, pack extraction and registry writes on this machine and compare
+/// them with the previous run.
+#[tauri::command]
+pub async fn run_benchmarks<R: Runtime>(app: AppHandle<R>) -> Result<BenchmarkReport, String> {
+    if !cfg!(any(debug_assertions, feature = "diagnostics")) {
+        return Err("Benchmarks are only available in debug and diagnostics builds".to_string());
+    }
+    let config_dir = crate::state::config::config_path(&app).ok();
+    let report = tauri::async_runtime::spawn_blocking(move || run_benchmarks_sync(config_dir))
+        .await
+        .map_err(|e| format!("Task join error: {}", e))??;
+    cc_info!("[CursorChanger] Ran {} benchmarks", report.results.len());
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn median_and_throughput_use_the_middle_runs() {
+        let result = summarize("x".to_string(), "runs/s", vec![40.0, 10.0, 20.0, 30.0], 2.0);
+        assert_eq!(result.iterations, 4);
+        assert_eq!(result.median_ms, 25.0);
+        assert_eq!((result.min_ms, result.max_ms), (10.0, 40.0));
+        assert_eq!(result.throughput, 80.0);
+    }
+
+    #[test]
+    fn results_are_compared_by_name_with_the_previous_run() {
+        let mut results = vec![
+            summarize("convert".to_string(), "runs/s", vec![15.0], 1.0),
+            summarize("new".to_string(), "runs/s", vec![5.0], 1.0),
+        ];
+        let previous = BenchmarkReport {
+            app_version: "1.0.0".to_string(),
+            os: "windows".to_string(),
+            arch: "x86_64".to_string(),
+            cpu_threads: 8,
+            ran_at: "2026-01-01T00:00:00Z".to_string(),
+            results: vec![summarize("convert".to_string(), "runs/s", vec![10.0], 1.0)],
+            previous_ran_at: None,
+        };
+
+        compare_with_previous(&mut results, &previous);
+
+        assert_eq!(results[0].previous_median_ms, Some(10.0));
+        assert_eq!(results[0].change_percent, Some(50.0));
+        assert_eq!(results[1].previous_median_ms, None);
+    }
+
+    #[test]
+    fn file_benchmarks_run_on_generated_inputs() {
+        let temp = tempfile::tempdir().unwrap();
+        let results = run_file_benchmarks(temp.path(), 1).unwrap();
+        let names: Vec<&str> = results.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(
+            names,
+            [
+                "convert_png_32px",
+                "convert_png_64px",
+                "convert_png_128px",
+                "convert_png_256px",
+                "preview_cur_32px",
+                "preview_cur_256px",
+                "preview_ani_64px",
+                "extract_pack",
+            ]
+        );
+        assert!(temp.path().join("pack").join("normal-select.cur").exists());
+    }
+}
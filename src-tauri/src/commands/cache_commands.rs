@@ -0,0 +1,31 @@
+/// Cache housekeeping: manual garbage collection and the disk quota for cached files.
+use crate::cache_gc::{self, CacheGcReport, CacheGcSettings};
+use tauri::{AppHandle, Emitter};
+
+/// Smallest quota we accept; below this the preview cache would thrash.
+const MIN_CACHE_QUOTA_MB: u64 = 16;
+
+#[tauri::command]
+pub fn run_cache_gc(app: AppHandle) -> Result<CacheGcReport, String> {
+    let report = cache_gc::run(&app);
+    let _ = app.emit(crate::events::CACHE_GC_REPORT, report.clone());
+    Ok(report)
+}
+
+#[tauri::command]
+pub fn get_cache_quota_mb(app: AppHandle) -> u64 {
+    cache_gc::load_settings(&app).quota_mb
+}
+
+/// Change the quota and collect right away so the new limit takes effect.
+#[tauri::command]
+pub fn set_cache_quota_mb(app: AppHandle, quota_mb: u64) -> Result<CacheGcReport, String> {
+    if quota_mb < MIN_CACHE_QUOTA_MB {
+        return Err(format!(
+            "Cache quota must be at least {} MB",
+            MIN_CACHE_QUOTA_MB
+        ));
+    }
+    cache_gc::save_settings(&app, &CacheGcSettings { quota_mb })?;
+    run_cache_gc(app)
+}
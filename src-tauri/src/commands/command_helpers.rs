@@ -22,13 +22,16 @@ fn build_payload_and_config(
             theme_mode: Some(guard.prefs.theme_mode),
             default_cursor_style: Some(guard.prefs.default_cursor_style),
             customization_mode: Some(guard.modes.customization_mode),
+            window_geometry: guard.prefs.window_geometry,
+            last_page: guard.prefs.last_page.clone(),
         })
     } else {
         None
     };
 
     let payload = CursorStatePayload {
-        hidden: guard.cursor.hidden,
+        hidden: guard.cursor.visibility.is_hidden(),
+        visibility: guard.cursor.visibility.clone(),
         shortcut: guard.prefs.shortcut.clone(),
         shortcut_enabled: guard.prefs.shortcut_enabled,
         app_shortcut: guard.prefs.app_shortcut.clone(),
@@ -42,11 +45,41 @@ fn build_payload_and_config(
         accent_color: guard.prefs.accent_color.clone(),
         theme_mode: guard.prefs.theme_mode,
         default_cursor_style: guard.prefs.default_cursor_style,
+        policy_locks: guard.policy.locks(),
+        effects: guard.modes.effects.clone(),
     };
 
     (payload, config)
 }
 
+/// The Normal cursor file and size, used to spot applies that need verifying.
+fn normal_apply_key(guard: &crate::state::app_state::AppStateWriteGuard<'_>) -> (Option<String>, i32) {
+    (
+        guard.cursor.cursor_paths.get("Normal").cloned(),
+        guard.prefs.cursor_size,
+    )
+}
+
+fn verify_normal_if_changed<R: Runtime>(
+    app: &AppHandle<R>,
+    before: (Option<String>, i32),
+    payload: &CursorStatePayload,
+) {
+    if payload.hidden {
+        return;
+    }
+    let Some(normal) = payload.cursor_paths.get("Normal") else {
+        return;
+    };
+    if before == (Some(normal.clone()), payload.cursor_size) {
+        return;
+    }
+    crate::health_endpoint::record_apply();
+    if let Some(warning) = crate::apply_verification::verify_normal_role(normal, payload.cursor_size) {
+        crate::apply_verification::report(app, vec![warning]);
+    }
+}
+
 pub fn update_state<F, R: Runtime>(
     app: &AppHandle<R>,
     state: &State<AppState>,
@@ -56,11 +89,13 @@ pub fn update_state<F, R: Runtime>(
 where
     F: FnOnce(&mut crate::state::app_state::AppStateWriteGuard<'_>) -> Result<(), String>,
 {
-    let (payload, config) = {
+    let (payload, config, before) = {
         let mut guard = state.write_all()?;
+        let before = normal_apply_key(&guard);
         f(&mut guard)?;
 
-        build_payload_and_config(&guard, persist)
+        let (payload, config) = build_payload_and_config(&guard, persist);
+        (payload, config, before)
     };
 
     if let Some(config) = config {
@@ -69,6 +104,9 @@ where
         }
     }
 
+    crate::logon_scheme::mirror_logon_scheme(payload.cursor_size, &payload.cursor_paths);
+    verify_normal_if_changed(app, before, &payload);
+
     Ok(payload)
 }
 
@@ -95,13 +133,14 @@ pub fn update_state_with_result<F, Res, R: Runtime>(
 where
     F: FnOnce(&mut crate::state::app_state::AppStateWriteGuard<'_>) -> Result<Res, String>,
 {
-    let ((payload, result), config) = {
+    let ((payload, result), config, before) = {
         let mut guard = state.write_all()?;
+        let before = normal_apply_key(&guard);
         let result = f(&mut guard)?;
 
         let (payload, config) = build_payload_and_config(&guard, persist);
 
-        ((payload, result), config)
+        ((payload, result), config, before)
     };
 
     if let Some(config) = config {
@@ -110,6 +149,9 @@ where
         }
     }
 
+    crate::logon_scheme::mirror_logon_scheme(payload.cursor_size, &payload.cursor_paths);
+    verify_normal_if_changed(app, before, &payload);
+
     Ok((payload, result))
 }
 
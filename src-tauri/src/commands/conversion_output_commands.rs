@@ -0,0 +1,35 @@
+/// Output folder and file names for converted cursors (see `crate::conversion_output`).
+use crate::conversion_output::{self, ConversionOutputSettings};
+use std::path::Path;
+use tauri::AppHandle;
+
+#[tauri::command]
+pub fn get_conversion_output_settings(app: AppHandle) -> ConversionOutputSettings {
+    conversion_output::load_settings(&app)
+}
+
+/// Choose where conversions are written and how the files are named. An empty `output_dir`
+/// goes back to the cursors folder; `name_template` may use `{name}`, `{size}` and `{hash}`.
+#[tauri::command]
+pub fn set_conversion_output_settings(
+    app: AppHandle,
+    output_dir: Option<String>,
+    name_template: String,
+) -> Result<ConversionOutputSettings, String> {
+    let output_dir = output_dir
+        .map(|dir| dir.trim().to_string())
+        .filter(|dir| !dir.is_empty());
+    if let Some(dir) = &output_dir {
+        if !Path::new(dir).is_absolute() {
+            return Err("Choose a full folder path for converted cursors".to_string());
+        }
+        std::fs::create_dir_all(dir)
+            .map_err(|e| format!("Failed to create output folder: {}", e))?;
+    }
+    let settings = ConversionOutputSettings {
+        output_dir,
+        name_template: name_template.trim().to_string(),
+    };
+    conversion_output::save_settings(&app, &settings)?;
+    Ok(settings)
+}
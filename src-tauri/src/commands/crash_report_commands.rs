@@ -0,0 +1,44 @@
+/// Opt-in crash reporting (see `crate::crash_reports`).
+use crate::crash_reports::{self, CrashReport, CrashReportSettings};
+use tauri::AppHandle;
+
+#[tauri::command]
+pub fn get_crash_report_settings(app: AppHandle) -> CrashReportSettings {
+    crash_reports::load_settings(&app)
+}
+
+/// Enable or disable crash capture. Takes effect immediately.
+#[tauri::command]
+pub fn set_crash_reporting_enabled(
+    app: AppHandle,
+    enabled: bool,
+) -> Result<CrashReportSettings, String> {
+    let settings = CrashReportSettings { enabled };
+    crash_reports::save_settings(&app, &settings)?;
+    crash_reports::set_enabled(enabled);
+    Ok(settings)
+}
+
+/// Crash reports left by earlier runs, newest first.
+#[tauri::command]
+pub fn get_pending_crash_reports() -> Result<Vec<CrashReport>, String> {
+    let dir = crate::paths::crash_reports_dir()?;
+    Ok(crash_reports::list_reports(&dir))
+}
+
+#[tauri::command]
+pub fn show_crash_reports_folder() -> Result<(), String> {
+    let dir = crate::paths::crash_reports_dir()?;
+    std::process::Command::new("explorer")
+        .arg(&dir)
+        .spawn()
+        .map_err(|e| format!("Failed to open folder: {}", e))?;
+    Ok(())
+}
+
+/// Delete all pending crash reports. Returns how many were removed.
+#[tauri::command]
+pub fn dismiss_crash_reports() -> Result<usize, String> {
+    let dir = crate::paths::crash_reports_dir()?;
+    crash_reports::dismiss_reports(&dir)
+}
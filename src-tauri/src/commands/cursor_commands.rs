@@ -1,14 +1,21 @@
 use crate::commands::command_helpers;
-use crate::state::{AppState, CursorStatePayload};
+use crate::commands::customization::pack_manifest::PackSoundEvent;
+use crate::error_codes::{with_code, ErrorCode};
+use crate::state::{
+    AppState, CursorStatePayload, CustomizationMode, HideSnapshot, VisibilityEvent,
+};
 use crate::system::{self, apply_blank_system_cursors};
+use cursor_changer::BlankRoleMask;
+use serde::Serialize;
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use tauri::{AppHandle, Emitter, State};
 
-fn hide_cursor_system() -> bool {
-    apply_blank_system_cursors()
+fn hide_cursor_system(roles: BlankRoleMask) -> bool {
+    apply_blank_system_cursors(roles)
 }
 
-fn show_cursor_system(cursor_paths: &HashMap<String, String>, cursor_size: i32) -> bool {
+pub(crate) fn show_cursor_system(cursor_paths: &HashMap<String, String>, cursor_size: i32) -> bool {
     let has_custom_cursors = !cursor_paths.is_empty();
 
     if has_custom_cursors {
@@ -76,54 +83,102 @@ fn decide_cursor_action(intent: CursorVisibilityIntent, currently_hidden: bool)
 
 fn apply_cursor_action_system(
     action: CursorAction,
-    currently_hidden: bool,
+    hide_roles: BlankRoleMask,
     cursor_paths: &HashMap<String, String>,
     cursor_size: i32,
-) -> Result<bool, String> {
+) -> Result<VisibilityEvent, String> {
     match action {
         CursorAction::Hide => {
-            if hide_cursor_system() {
-                Ok(true)
+            if hide_cursor_system(hide_roles) {
+                Ok(VisibilityEvent::Hid(HideSnapshot {
+                    blanked_roles: hide_roles.names().map(str::to_string).collect(),
+                }))
             } else {
-                Err("Failed to hide system cursors".into())
+                Err(with_code(
+                    ErrorCode::CursorHide,
+                    "Failed to hide system cursors",
+                ))
             }
         }
         CursorAction::Show => {
             if show_cursor_system(cursor_paths, cursor_size) {
-                Ok(false)
+                Ok(VisibilityEvent::Restored)
             } else {
-                Err("Failed to restore cursors".into())
+                Err(with_code(
+                    ErrorCode::CursorRestore,
+                    "Failed to restore cursors",
+                ))
             }
         }
-        CursorAction::Noop => Ok(currently_hidden),
+        // Handled before any system call
+        CursorAction::Noop => Err("No cursor change to apply".to_string()),
     }
 }
 
+pub(crate) fn hide_disabled_by_policy(shared: &AppState) -> Result<bool, String> {
+    let policy = shared
+        .policy
+        .read()
+        .map_err(|_| "Application state poisoned".to_string())?;
+    Ok(policy.disable_hide)
+}
+
+/// [`AdminPolicy::check_cursor_style`](crate::state::policy::AdminPolicy::check_cursor_style)
+/// for every path that applies cursor files: packs, with the trials, schedules, chords and
+/// hotkeys that go through them, and every pick that passes through `validate_cursor_file`.
+pub(crate) fn check_custom_cursors_allowed(shared: &AppState) -> Result<(), String> {
+    let policy = shared
+        .policy
+        .read()
+        .map_err(|_| "Application state poisoned".to_string())?;
+    policy.check_cursor_style()
+}
+
 fn apply_cursor_visibility_intent_with_shared_state(
     shared: &AppState,
     intent: CursorVisibilityIntent,
 ) -> Result<CursorStatePayload, String> {
-    let (currently_hidden, cursor_paths, cursor_size) = {
-        let cursor_guard = shared
+    let (action, cursor_paths, cursor_size) = {
+        let mut cursor_guard = shared
             .cursor
-            .read()
+            .write()
             .map_err(|_| "Application state poisoned".to_string())?;
 
-        let action = decide_cursor_action(intent, cursor_guard.hidden);
-
-        if matches!(action, CursorAction::Noop) {
+        // Another caller is already restoring, which is all ShowIfHidden asks for
+        if matches!(intent, CursorVisibilityIntent::ShowIfHidden)
+            && cursor_guard.visibility.is_restoring()
+        {
+            drop(cursor_guard);
             return CursorStatePayload::try_from(shared);
         }
 
-        let needs_show_snapshot = matches!(action, CursorAction::Show);
+        let action = decide_cursor_action(intent, cursor_guard.visibility.is_hidden());
+
+        match action {
+            CursorAction::Noop => {
+                drop(cursor_guard);
+                return CursorStatePayload::try_from(shared);
+            }
+            CursorAction::Hide => {
+                if hide_disabled_by_policy(shared)? {
+                    return Err("Hiding the cursor has been disabled by your administrator".into());
+                }
+                // Claim the hide so a concurrent hotkey cannot start another, or a restore
+                cursor_guard.visibility.apply(VisibilityEvent::BeginHide)?;
+            }
+            CursorAction::Show => {
+                // Claim the restore so a concurrent hotkey or shutdown hook cannot start another
+                cursor_guard
+                    .visibility
+                    .apply(VisibilityEvent::BeginRestore)?;
+            }
+        }
 
-        let cursor_paths = if needs_show_snapshot {
+        let cursor_paths = if matches!(action, CursorAction::Show) {
             cursor_guard.cursor_paths.clone()
         } else {
             HashMap::new()
         };
-
-        let currently_hidden = cursor_guard.hidden;
         drop(cursor_guard);
 
         let prefs_guard = shared
@@ -131,19 +186,29 @@ fn apply_cursor_visibility_intent_with_shared_state(
             .read()
             .map_err(|_| "Application state poisoned".to_string())?;
 
-        (currently_hidden, cursor_paths, prefs_guard.cursor_size)
+        (action, cursor_paths, prefs_guard.cursor_size)
     };
 
-    let action = decide_cursor_action(intent, currently_hidden);
-    let new_hidden = apply_cursor_action_system(action, currently_hidden, &cursor_paths, cursor_size)?;
+    let result = apply_cursor_action_system(
+        action,
+        crate::hide_roles::current_mask(),
+        &cursor_paths,
+        cursor_size,
+    );
 
     {
         let mut cursor_guard = shared
             .cursor
             .write()
             .map_err(|_| "Application state poisoned".to_string())?;
-        cursor_guard.hidden = new_hidden;
+        match &result {
+            Ok(event) => cursor_guard.visibility.apply(event.clone())?,
+            Err(err) => cursor_guard
+                .visibility
+                .apply(VisibilityEvent::Failed(err.clone()))?,
+        }
     }
+    result?;
 
     CursorStatePayload::try_from(shared)
 }
@@ -155,7 +220,10 @@ pub fn hide_cursor(state: &AppState) -> Result<(), String> {
     if payload.hidden {
         Ok(())
     } else {
-        Err("Failed to hide system cursors".into())
+        Err(with_code(
+            ErrorCode::CursorHide,
+            "Failed to hide system cursors",
+        ))
     }
 }
 
@@ -166,7 +234,10 @@ pub fn show_cursor(state: &AppState) -> Result<(), String> {
     if !payload.hidden {
         Ok(())
     } else {
-        Err("Failed to restore cursors".into())
+        Err(with_code(
+            ErrorCode::CursorRestore,
+            "Failed to restore cursors",
+        ))
     }
 }
 
@@ -185,6 +256,7 @@ pub fn get_status(state: State<AppState>) -> Result<CursorStatePayload, String>
 #[tauri::command]
 pub fn toggle_cursor(app: AppHandle, state: State<AppState>) -> Result<CursorStatePayload, String> {
     let payload = toggle_cursor_with_shared_state(&*state)?;
+    crate::audio::play_pack_sound(&app, None, PackSoundEvent::Toggle);
     let _ = app.emit(crate::events::CURSOR_STATE, payload.clone());
     Ok(payload)
 }
@@ -199,8 +271,218 @@ pub fn restore_cursor(
     Ok(payload)
 }
 
+/// Where a single restored role got its cursor from.
+#[derive(ts_rs::TS, Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub enum CursorRestoreSource {
+    /// The registry value captured at startup, before any customization was applied.
+    Snapshot,
+    /// The bundled default for the current default cursor style.
+    AppDefault,
+    /// Windows' built-in cursor (no file path available).
+    System,
+}
+
+/// Delta payload emitted when a single role is restored.
+#[derive(ts_rs::TS, Serialize, Clone, Debug)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct CursorRoleRestoredPayload {
+    pub cursor_name: String,
+    pub image_path: Option<String>,
+    pub source: CursorRestoreSource,
+}
+
+/// Expand `%VAR%` tokens the way Windows does for `REG_EXPAND_SZ` cursor values
+/// (e.g. `%SystemRoot%\cursors\aero_arrow.cur`). Unknown variables are left as-is.
+pub(crate) fn expand_env_vars(value: &str) -> String {
+    expand_vars_with(value, |name| std::env::var(name).ok())
+}
+
+fn expand_vars_with(value: &str, lookup: impl Fn(&str) -> Option<String>) -> String {
+    let mut out = String::with_capacity(value.len());
+    let mut rest = value;
+    while let Some(start) = rest.find('%') {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        match after.find('%') {
+            Some(end) => {
+                let name = &after[..end];
+                match lookup(name) {
+                    Some(expanded) if !name.is_empty() => out.push_str(&expanded),
+                    _ => {
+                        out.push('%');
+                        out.push_str(name);
+                        out.push('%');
+                    }
+                }
+                rest = &after[end + 1..];
+            }
+            None => {
+                out.push_str(&rest[start..]);
+                rest = "";
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+fn choose_role_restore_target(
+    snapshot_value: Option<&Option<String>>,
+    default_path: Option<PathBuf>,
+) -> (CursorRestoreSource, Option<String>) {
+    if let Some(Some(path)) = snapshot_value {
+        let expanded = expand_env_vars(path.trim());
+        if !expanded.is_empty() && Path::new(&expanded).exists() {
+            return (CursorRestoreSource::Snapshot, Some(expanded));
+        }
+    }
+
+    match default_path {
+        Some(path) => (
+            CursorRestoreSource::AppDefault,
+            Some(path.to_string_lossy().to_string()),
+        ),
+        None => (CursorRestoreSource::System, None),
+    }
+}
+
+/// Revert a single cursor role to its startup snapshot (or the app default) while leaving
+/// every other customized role untouched.
+#[tauri::command]
+pub fn restore_cursor_role(
+    app: AppHandle,
+    state: State<AppState>,
+    cursor_type: String,
+) -> Result<CursorRoleRestoredPayload, String> {
+    let role = cursor_changer::find_cursor_type(&cursor_type)
+        .ok_or_else(|| format!("Cursor type '{}' not found", cursor_type))?;
+
+    let (snapshot_value, style, cursor_size, other_paths, hidden) = {
+        let guard = state.read_all()?;
+        let snapshot_value = guard
+            .restoration
+            .cursor_registry_snapshot
+            .as_ref()
+            .and_then(|s| s.get(role.registry_key).cloned());
+        let mut other_paths = guard.cursor.cursor_paths.clone();
+        other_paths.remove(role.name);
+        (
+            snapshot_value,
+            guard.prefs.default_cursor_style,
+            guard.prefs.cursor_size,
+            other_paths,
+            guard.cursor.visibility.is_hidden(),
+        )
+    };
+
+    let default_path =
+        crate::cursor_defaults::resolve_default_cursor_path(&app, style.as_str(), role.name)?;
+    let (source, image_path) = choose_role_restore_target(snapshot_value.as_ref(), default_path);
+
+    // While the cursor is hidden only the stored path changes; un-hiding applies it.
+    if !hidden {
+        match &image_path {
+            Some(path) => {
+                if !system::apply_cursor_from_file_with_size(path, role.id, cursor_size) {
+                    return Err(with_code(
+                        ErrorCode::CursorRestore,
+                        format!("Failed to restore cursor {}", role.name),
+                    ));
+                }
+            }
+            None => {
+                // Windows can only reload its built-in cursors as a set, so put the other
+                // customized roles back on top afterwards.
+                if let Err(e) = system::reload_system_cursors() {
+                    return Err(if e.is_timeout() {
+                        with_code(ErrorCode::CursorReloadTimeout, e)
+                    } else {
+                        with_code(
+                            ErrorCode::RegistryReload,
+                            "Failed to restore system cursors",
+                        )
+                    });
+                }
+                if !show_cursor_system(&other_paths, cursor_size) && !other_paths.is_empty() {
+                    cc_warn!("[CursorChanger] Failed to reapply some cursors after role restore");
+                }
+            }
+        }
+    }
+
+    let name_for_state = role.name.to_string();
+    let path_for_state = image_path.clone();
+    command_helpers::update_state_and_emit(&app, &state, false, move |guard| {
+        let mode_paths = match guard.modes.customization_mode {
+            CustomizationMode::Simple => &mut guard.modes.simple_mode_cursor_paths,
+            CustomizationMode::Advanced => &mut guard.modes.advanced_mode_cursor_paths,
+        };
+        match path_for_state {
+            Some(path) => {
+                guard.cursor.cursor_paths.insert(name_for_state.clone(), path.clone());
+                mode_paths.insert(name_for_state, path);
+            }
+            None => {
+                guard.cursor.cursor_paths.remove(&name_for_state);
+                mode_paths.remove(&name_for_state);
+            }
+        }
+        Ok(())
+    })?;
+
+    let payload = CursorRoleRestoredPayload {
+        cursor_name: role.name.to_string(),
+        image_path,
+        source,
+    };
+    let _ = app.emit(crate::events::CURSOR_ROLE_RESTORED, payload.clone());
+    Ok(payload)
+}
+
+/// Troubleshooting: reload every cursor role and make running apps drop cached cursors.
+/// Use when Windows keeps showing an old cursor after an apply.
+#[tauri::command]
+pub fn force_cursor_refresh(state: State<AppState>) -> Result<(), String> {
+    let (hidden, mode, cursor_paths, cursor_size) = {
+        let guard = state.read_all()?;
+        (
+            guard.cursor.visibility.is_hidden(),
+            guard.modes.customization_mode,
+            guard.cursor.cursor_paths.clone(),
+            guard.prefs.cursor_size,
+        )
+    };
+    if hidden {
+        return Err("Show the cursor before refreshing it".to_string());
+    }
+
+    crate::cursor_refresh::force_refresh(mode, &cursor_paths, cursor_size)?;
+    cc_info!(
+        "[CursorChanger] Forced cursor refresh ({} custom roles)",
+        cursor_paths.len()
+    );
+    Ok(())
+}
+
+#[tauri::command]
+pub fn get_cursor_cache_flush_enabled() -> bool {
+    crate::cursor_refresh::flush_after_apply_enabled()
+}
+
+/// Opt in/out of broadcasting the cursor setting change after every apply.
+#[tauri::command]
+pub fn set_cursor_cache_flush_enabled(app: AppHandle, enable: bool) -> Result<bool, String> {
+    crate::cursor_refresh::set_flush_after_apply(&app, enable)?;
+    Ok(enable)
+}
+
 pub fn toggle_cursor_with_shared_state(shared: &AppState) -> Result<CursorStatePayload, String> {
-    apply_cursor_visibility_intent_with_shared_state(shared, CursorVisibilityIntent::Toggle)
+    let payload =
+        apply_cursor_visibility_intent_with_shared_state(shared, CursorVisibilityIntent::Toggle)?;
+    crate::usage_stats::record_toggle();
+    Ok(payload)
 }
 
 pub fn show_cursor_if_hidden_with_shared_state(
@@ -223,11 +505,47 @@ pub fn toggle_app_enabled_internal(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::state::CursorVisibility;
     use crate::system::{
         set_apply_blank_mock_guard, set_apply_cursor_from_file_with_size_mock_guard,
         set_restore_mock_guard,
     };
 
+    #[test]
+    fn choose_role_restore_target_prefers_existing_snapshot() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let snapshot_file = temp.path().join("arrow.cur");
+        std::fs::write(&snapshot_file, [0u8; 4]).expect("write");
+        let snapshot = Some(snapshot_file.to_string_lossy().to_string());
+        let default_path = Some(PathBuf::from("C:\\defaults\\normal-select.cur"));
+
+        let (source, path) = choose_role_restore_target(Some(&snapshot), default_path.clone());
+        assert_eq!(source, CursorRestoreSource::Snapshot);
+        assert_eq!(path, snapshot);
+
+        let missing = Some(temp.path().join("gone.cur").to_string_lossy().to_string());
+        let (source, _) = choose_role_restore_target(Some(&missing), default_path.clone());
+        assert_eq!(source, CursorRestoreSource::AppDefault);
+
+        let (source, path) = choose_role_restore_target(Some(&None), None);
+        assert_eq!(source, CursorRestoreSource::System);
+        assert!(path.is_none());
+    }
+
+    #[test]
+    fn expand_env_vars_handles_known_and_unknown_tokens() {
+        let lookup = |name: &str| (name == "CC_TEST_ROOT").then(|| "C:\\Windows".to_string());
+        assert_eq!(
+            expand_vars_with("%CC_TEST_ROOT%\\cursors\\arrow.cur", lookup),
+            "C:\\Windows\\cursors\\arrow.cur"
+        );
+        assert_eq!(
+            expand_vars_with("%CC_TEST_MISSING%\\a.cur", lookup),
+            "%CC_TEST_MISSING%\\a.cur"
+        );
+        assert_eq!(expand_vars_with("100%", lookup), "100%");
+    }
+
     #[test]
     fn decide_cursor_action_covers_all_intents() {
         assert_eq!(
@@ -267,6 +585,77 @@ mod tests {
         );
     }
 
+    #[test]
+    fn hide_is_rejected_when_policy_disables_it() {
+        let _apply_guard = set_apply_blank_mock_guard(|| true);
+        let state = AppState::default();
+        state.policy.write().unwrap().disable_hide = true;
+
+        assert!(hide_cursor(&state).is_err());
+        assert!(toggle_cursor_internal(&state).is_err());
+        assert!(!state.cursor.read().unwrap().visibility.is_hidden());
+    }
+
+    #[test]
+    fn restoring_twice_is_rejected_and_failures_are_recorded() {
+        let state = AppState::default();
+        {
+            let mut cursor = state.cursor.write().unwrap();
+            cursor
+                .visibility
+                .apply(VisibilityEvent::Hid(HideSnapshot::default()))
+                .unwrap();
+            cursor
+                .visibility
+                .apply(VisibilityEvent::BeginRestore)
+                .unwrap();
+        }
+
+        // A restore is already running: an explicit show fails, show-if-hidden leaves it be
+        assert!(show_cursor(&state).is_err());
+        let payload = show_cursor_if_hidden_with_shared_state(&state).expect("no-op");
+        assert_eq!(payload.visibility, CursorVisibility::Restoring);
+
+        let _restore_guard = set_restore_mock_guard(|| false);
+        state.cursor.write().unwrap().visibility.reset();
+        state
+            .cursor
+            .write()
+            .unwrap()
+            .visibility
+            .apply(VisibilityEvent::Hid(HideSnapshot::default()))
+            .unwrap();
+        assert!(show_cursor(&state).is_err());
+        let visibility = state.cursor.read().unwrap().visibility.clone();
+        assert!(matches!(
+            visibility,
+            CursorVisibility::Error {
+                cursor_hidden: true,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn hiding_twice_is_rejected_while_the_first_hide_runs() {
+        let _apply_guard = set_apply_blank_mock_guard(|| true);
+        let state = AppState::default();
+        state
+            .cursor
+            .write()
+            .unwrap()
+            .visibility
+            .apply(VisibilityEvent::BeginHide)
+            .unwrap();
+
+        assert!(hide_cursor(&state).is_err());
+        assert!(toggle_cursor_internal(&state).is_err());
+        assert_eq!(
+            state.cursor.read().unwrap().visibility,
+            CursorVisibility::Hiding
+        );
+    }
+
     #[test]
     fn test_cursor_commands_scenarios() {
         // Scenario 1: hide_cursor_sets_hidden_on_success
@@ -275,11 +664,11 @@ mod tests {
             let state = AppState::default();
             {
                 let mut cursor = state.cursor.write().unwrap();
-                cursor.hidden = false;
+                cursor.visibility.reset();
             }
 
             assert!(hide_cursor(&state).is_ok());
-            assert!(state.cursor.read().unwrap().hidden);
+            assert!(state.cursor.read().unwrap().visibility.is_hidden());
         }
 
         // Scenario 2: hide_cursor_returns_error_on_failure
@@ -289,7 +678,7 @@ mod tests {
 
             let result = hide_cursor(&state);
             assert!(result.is_err());
-            assert!(!state.cursor.read().unwrap().hidden);
+            assert!(!state.cursor.read().unwrap().visibility.is_hidden());
         }
 
         // Scenario 3: show_cursor_clears_hidden_on_success
@@ -298,11 +687,14 @@ mod tests {
             let state = AppState::default();
             {
                 let mut cursor = state.cursor.write().unwrap();
-                cursor.hidden = true;
+                cursor
+                    .visibility
+                    .apply(VisibilityEvent::Hid(HideSnapshot::default()))
+                    .unwrap();
             }
 
             assert!(show_cursor(&state).is_ok());
-            assert!(!state.cursor.read().unwrap().hidden);
+            assert!(!state.cursor.read().unwrap().visibility.is_hidden());
         }
 
         // Scenario 4: toggle_cursor_internal_switches_both_directions
@@ -314,12 +706,12 @@ mod tests {
             // First toggle hides
             let hidden = toggle_cursor_internal(&state).expect("first toggle");
             assert!(hidden);
-            assert!(state.cursor.read().unwrap().hidden);
+            assert!(state.cursor.read().unwrap().visibility.is_hidden());
 
             // Second toggle shows
             let hidden = toggle_cursor_internal(&state).expect("second toggle");
             assert!(!hidden);
-            assert!(!state.cursor.read().unwrap().hidden);
+            assert!(!state.cursor.read().unwrap().visibility.is_hidden());
         }
 
         // Scenario 5: show_cursor_reapplies_custom_cursors
@@ -343,7 +735,10 @@ mod tests {
             let state = AppState::default();
             {
                 let mut cursor = state.cursor.write().unwrap();
-                cursor.hidden = true;
+                cursor
+                    .visibility
+                    .apply(VisibilityEvent::Hid(HideSnapshot::default()))
+                    .unwrap();
                 cursor.cursor_paths = cursor_paths;
             }
             {
@@ -353,7 +748,7 @@ mod tests {
 
             let result = show_cursor(&state);
             assert!(result.is_ok());
-            assert!(!state.cursor.read().unwrap().hidden);
+            assert!(!state.cursor.read().unwrap().visibility.is_hidden());
 
             // Verify the cursor was applied
             assert!(*call_count.lock().unwrap() > 0);
@@ -390,7 +785,7 @@ mod tests {
 
             let result = hide_cursor(&state);
             assert!(result.is_ok());
-            assert!(state.cursor.read().unwrap().hidden);
+            assert!(state.cursor.read().unwrap().visibility.is_hidden());
         }
 
         // Scenario 8: toggle_preserves_cursor_size
@@ -1,10 +1,15 @@
 use crate::commands::command_helpers;
+use crate::error_codes::{with_code, ErrorCode};
 use crate::state::{AppState, CursorInfo, CursorStatePayload};
 use crate::system;
-use cursor_changer::CURSOR_TYPES;
+use cursor_changer::{find_cursor_type, CURSOR_TYPES};
 use std::collections::HashMap;
+use std::path::Path;
 use tauri::{AppHandle, Runtime, State};
 
+use super::library::{
+    find_cursor_by_path, load_library_if_present, prescaled_variant_path, LibraryData,
+};
 use super::set_cursor_focus::refocus_main_window_later;
 use super::set_cursor_validation::{validate_cursor_file, validate_cursor_size};
 
@@ -20,6 +25,26 @@ pub(crate) fn apply_cursor_paths_for_mode(
     }
 }
 
+/// Resolve the file that should actually be handed to Windows for `size`.
+///
+/// Library cursors may carry pre-scaled variants generated at import time; when one exists
+/// for the requested size it is used instead of letting Windows rescale the original.
+/// State keeps tracking the original path so the UI and persistence are unaffected.
+/// Adaptive brightness (see `crate::cursor_brightness`) is applied on top of the variant.
+/// `library` is loaded once per apply with `library::load_library_if_present`.
+pub(crate) fn resolve_apply_path<R: Runtime>(
+    app: &AppHandle<R>,
+    library: Option<&LibraryData>,
+    path: &str,
+    size: i32,
+) -> String {
+    let sized = library
+        .and_then(|library| find_cursor_by_path(library, path))
+        .and_then(|c| prescaled_variant_path(c, size))
+        .unwrap_or_else(|| path.to_string());
+    crate::cursor_brightness::adapt_apply_path(app, &sized)
+}
+
 pub(crate) fn sync_active_cursor_update<R: Runtime>(app: &AppHandle<R>, old_path: &str, new_path: &str) {
     use crate::system;
     use tauri::Manager;
@@ -51,9 +76,9 @@ pub(crate) fn sync_active_cursor_update<R: Runtime>(app: &AppHandle<R>, old_path
     // Check if our updated file is being used by any active cursor
     for (cursor_name, active_path) in &cursor_paths {
         // Compare paths
-        if active_path == old_path {
+        if crate::paths::canonical_eq(Path::new(active_path), Path::new(old_path)) {
             // Find the ID for this cursor type
-            if let Some(cursor_type) = CURSOR_TYPES.iter().find(|ct| ct.name == *cursor_name) {
+            if let Some(cursor_type) = find_cursor_type(cursor_name) {
                 println!(
                     "Re-applying updated cursor {} (ID {}) due to library update",
                     cursor_name, cursor_type.id
@@ -138,8 +163,13 @@ pub(super) fn set_all_cursors_with_size<R: Runtime>(
 
     validate_cursor_size(size)?;
 
-    if !system::apply_cursor_file_with_size(&final_path, size) {
-        return Err("Failed to apply cursor file with specified size".into());
+    let library = load_library_if_present(&app);
+    let apply_path = resolve_apply_path(&app, library.as_ref(), &final_path, size);
+    if !system::apply_cursor_file_with_size(&apply_path, size) {
+        return Err(with_code(
+            ErrorCode::CursorApply,
+            "Failed to apply cursor file with specified size",
+        ));
     }
 
     let mut new_cursor_paths = std::collections::HashMap::new();
@@ -176,9 +206,7 @@ pub(super) fn set_single_cursor_with_size<R: Runtime>(
     state: State<AppState>,
     app: AppHandle<R>,
 ) -> Result<CursorInfo, String> {
-    let cursor_type = CURSOR_TYPES
-        .iter()
-        .find(|ct| ct.name == cursor_name)
+    let cursor_type = find_cursor_type(&cursor_name)
         .ok_or_else(|| format!("Cursor type '{}' not found", cursor_name))?;
 
     if image_path.is_empty() {
@@ -189,8 +217,13 @@ pub(super) fn set_single_cursor_with_size<R: Runtime>(
 
     validate_cursor_size(size)?;
 
-    if !system::apply_cursor_from_file_with_size(&final_path, cursor_type.id, size) {
-        return Err("Failed to apply cursor file with specified size".into());
+    let library = load_library_if_present(&app);
+    let apply_path = resolve_apply_path(&app, library.as_ref(), &final_path, size);
+    if !system::apply_cursor_from_file_with_size(&apply_path, cursor_type.id, size) {
+        return Err(with_code(
+            ErrorCode::CursorApply,
+            "Failed to apply cursor file with specified size",
+        ));
     }
 
     let id = cursor_type.id;
@@ -236,17 +269,20 @@ pub(super) fn set_multiple_cursors_with_size<R: Runtime>(
     validate_cursor_size(size)?;
 
     let mut new_cursor_paths = std::collections::HashMap::new();
+    let library = load_library_if_present(&app);
+    let apply_path = resolve_apply_path(&app, library.as_ref(), &final_path, size);
 
     for cursor_name in &cursor_names {
-        let cursor_type = CURSOR_TYPES
-            .iter()
-            .find(|ct| &ct.name == cursor_name)
+        let cursor_type = find_cursor_type(cursor_name)
             .ok_or_else(|| format!("Cursor type '{}' not found", cursor_name))?;
 
-        if !system::apply_cursor_from_file_with_size(&final_path, cursor_type.id, size) {
-            return Err(format!(
-                "Failed to apply cursor file to {} with specified size",
-                cursor_name
+        if !system::apply_cursor_from_file_with_size(&apply_path, cursor_type.id, size) {
+            return Err(with_code(
+                ErrorCode::CursorApply,
+                format!(
+                    "Failed to apply cursor file to {} with specified size",
+                    cursor_name
+                ),
             ));
         }
 
@@ -277,6 +313,17 @@ pub(super) fn set_cursor_size<R: Runtime>(
     size: i32,
     state: State<AppState>,
     app: AppHandle<R>,
+) -> Result<CursorStatePayload, String> {
+    resize_cursor(size, state, app, true)
+}
+
+/// Reload the current cursors at `size`. `refocus` brings the main window back to the front
+/// afterwards, which callers acting while another app has focus should skip.
+pub(super) fn resize_cursor<R: Runtime>(
+    size: i32,
+    state: State<AppState>,
+    app: AppHandle<R>,
+    refocus: bool,
 ) -> Result<CursorStatePayload, String> {
     validate_cursor_size(size)?;
 
@@ -293,10 +340,12 @@ pub(super) fn set_cursor_size<R: Runtime>(
 
     if !cursor_paths.is_empty() {
         let cursor_types = &CURSOR_TYPES;
+        let library = load_library_if_present(&app);
 
         for cursor_type in cursor_types {
             if let Some(cur_path) = cursor_paths.get(cursor_type.name) {
-                if !system::apply_cursor_from_file_with_size(cur_path, cursor_type.id, size) {
+                let apply_path = resolve_apply_path(&app, library.as_ref(), cur_path, size);
+                if !system::apply_cursor_from_file_with_size(&apply_path, cursor_type.id, size) {
                     cc_warn!(
                         "Warning: Failed to resize cursor {} to {}px",
                         cursor_type.name,
@@ -315,12 +364,19 @@ pub(super) fn set_cursor_size<R: Runtime>(
             Ok(())
         })?;
 
-        refocus_main_window_later(app);
+        if refocus {
+            refocus_main_window_later(app);
+        }
 
         Ok(payload)
     } else if let Some(path) = cursor_path {
-        if !system::apply_cursor_file_with_size(&path, size) {
-            return Err("Failed to apply cursor at new size".into());
+        let library = load_library_if_present(&app);
+        let apply_path = resolve_apply_path(&app, library.as_ref(), &path, size);
+        if !system::apply_cursor_file_with_size(&apply_path, size) {
+            return Err(with_code(
+                ErrorCode::CursorApply,
+                "Failed to apply cursor at new size",
+            ));
         }
 
         let new_cursor_paths = std::collections::HashMap::new();
@@ -332,7 +388,9 @@ pub(super) fn set_cursor_size<R: Runtime>(
             Ok(())
         })?;
 
-        refocus_main_window_later(app);
+        if refocus {
+            refocus_main_window_later(app);
+        }
 
         Ok(payload)
     } else {
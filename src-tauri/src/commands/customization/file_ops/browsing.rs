@@ -1,10 +1,121 @@
 /// File browsing dialog operations
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
 use tauri::AppHandle;
 use tauri_plugin_dialog::DialogExt;
 
-/// Browse for cursor file
+use crate::commands::customization::library::read_ani_frame_summary;
+use crate::cursor_converter::{self, cur_reader};
+
+/// A file picked in the browse dialog, with what the import dialog shows before importing.
+#[derive(ts_rs::TS, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct BrowsedCursorFile {
+    pub path: String,
+    /// `ani`, `cur`, `svg`, `raster` or `tiff`; `None` when the file could not be read
+    pub format: Option<String>,
+    /// Dimensions of the largest frame
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+    /// Hotspot of the largest frame; only cursor files carry one
+    pub hotspot_x: Option<u16>,
+    pub hotspot_y: Option<u16>,
+    /// Frames in an .ani; images and other files hold one
+    pub frame_count: Option<u32>,
+    #[ts(type = "number")]
+    pub file_size: u64,
+    /// Why the details are missing, when they are
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct CursorFileDetails {
+    format: String,
+    width: u32,
+    height: u32,
+    hotspot: Option<(u16, u16)>,
+    frame_count: usize,
+}
+
+/// Read the details of the file at `path`, in a worker process when decode sandboxing is on.
+fn cursor_file_details(path: &str) -> Result<CursorFileDetails, String> {
+    if crate::decode_worker::is_enabled() {
+        return crate::decode_worker::describe(path);
+    }
+    read_cursor_file_details(path)
+}
+
+/// [`cursor_file_details`] without sandboxing; what the decode worker runs.
+pub(crate) fn read_cursor_file_details(path: &str) -> Result<CursorFileDetails, String> {
+    let is_ani = Path::new(path)
+        .extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|e| e.eq_ignore_ascii_case("ani"));
+    if is_ani {
+        let data = fs::read(path).map_err(|e| format!("Failed to read file: {}", e))?;
+        let (first_frame, frame_count) =
+            read_ani_frame_summary(&data).ok_or_else(|| "Not a valid .ANI file".to_string())?;
+        let (width, height, _) = cur_reader::cur_dimensions(&first_frame)?;
+        return Ok(CursorFileDetails {
+            format: "ani".to_string(),
+            width,
+            height,
+            hotspot: cur_reader::cur_hotspot(&first_frame).ok(),
+            frame_count,
+        });
+    }
+
+    let metadata = cursor_converter::with_handler_for_path(path, |handler| handler.metadata(path))?;
+    let hotspot = if metadata.format == "cur" {
+        let data = fs::read(path).map_err(|e| format!("Failed to read file: {}", e))?;
+        cur_reader::cur_hotspot(&data).ok()
+    } else {
+        None
+    };
+    Ok(CursorFileDetails {
+        format: metadata.format.to_string(),
+        width: metadata.width,
+        height: metadata.height,
+        hotspot,
+        frame_count: metadata.frame_count,
+    })
+}
+
+/// Describe the file at `path`. Files that cannot be parsed still come back with their path
+/// and size, so the import itself reports the problem.
+pub(crate) fn describe_cursor_file(path: &str) -> Result<BrowsedCursorFile, String> {
+    let file_size = fs::metadata(path)
+        .map_err(|e| format!("Failed to read file: {}", e))?
+        .len();
+    let mut described = BrowsedCursorFile {
+        path: path.to_string(),
+        format: None,
+        width: None,
+        height: None,
+        hotspot_x: None,
+        hotspot_y: None,
+        frame_count: None,
+        file_size,
+        error: None,
+    };
+    match cursor_file_details(path) {
+        Ok(details) => {
+            described.format = Some(details.format);
+            described.width = Some(details.width);
+            described.height = Some(details.height);
+            described.hotspot_x = details.hotspot.map(|(x, _)| x);
+            described.hotspot_y = details.hotspot.map(|(_, y)| y);
+            described.frame_count = Some(details.frame_count as u32);
+        }
+        Err(error) => described.error = Some(error),
+    }
+    Ok(described)
+}
+
+/// Browse for a cursor or image file and describe it
 #[tauri::command]
-pub async fn browse_cursor_file(app: AppHandle) -> Result<Option<String>, String> {
+pub async fn browse_cursor_file(app: AppHandle) -> Result<Option<BrowsedCursorFile>, String> {
     // Default to Desktop folder for intuitive user experience
     let default_path = dirs::desktop_dir()
         .and_then(|p| p.to_str().map(|s| s.to_string()))
@@ -34,7 +145,7 @@ pub async fn browse_cursor_file(app: AppHandle) -> Result<Option<String>, String
     match file_path {
         Some(path) => {
             if let Some(path_ref) = path.as_path() {
-                Ok(Some(path_ref.to_string_lossy().to_string()))
+                describe_cursor_file(&path_ref.to_string_lossy()).map(Some)
             } else {
                 Ok(None)
             }
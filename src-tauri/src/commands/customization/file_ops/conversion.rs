@@ -1,12 +1,16 @@
 /// Image to cursor conversion operations
 use std::path::{Path, PathBuf};
 
+use super::staging::ConversionStaging;
+use crate::conversion_output;
 use crate::cursor_converter;
+use crate::decode_worker::UploadConversion;
 use crate::paths;
 use image::{imageops::FilterType, ImageBuffer, Rgba};
+use serde::{Deserialize, Serialize};
 use tauri::{AppHandle, Runtime};
 
-enum ConversionInput<'a> {
+pub(crate) enum ConversionInput<'a> {
     Path(&'a str),
     Bytes { data: &'a [u8], filename: &'a str },
 }
@@ -57,92 +61,194 @@ fn extension_lower(path_or_filename: &str) -> String {
         .unwrap_or_default()
 }
 
-fn make_output_path(file_stem: &str) -> Result<String, String> {
-    let cursors_dir = paths::cursors_dir()?;
-    let base_name = format!("{}.cur", file_stem);
-    let mut candidate = cursors_dir.join(&base_name);
-
-    if !candidate.exists() {
-        return Ok(candidate.to_string_lossy().to_string());
-    }
-
-    // Fallback to sequential suffixes: name_2.cur, name_3.cur, ...
-    for idx in 2u32.. {
-        let filename = format!("{}_{}.cur", file_stem, idx);
-        candidate = cursors_dir.join(&filename);
-        if !candidate.exists() {
-            return Ok(candidate.to_string_lossy().to_string());
-        }
-    }
-
-    Err("Unable to generate unique cursor filename".to_string())
-}
-
+/// Convert into `output_dir` via a staging area, so a failed conversion leaves no partial
+/// file behind. Returns the final path.
 fn convert_to_cur_impl(
+    output_dir: &Path,
     input: ConversionInput<'_>,
     size: u32,
-    click_point_x: u16,
-    click_point_y: u16,
+    click_point: Option<(u16, u16)>,
     scale: f32,
     offset_x: i32,
     offset_y: i32,
 ) -> Result<String, String> {
+    let mut staging = ConversionStaging::new(output_dir)?;
+    let staged = stage_conversion(
+        &mut staging,
+        input,
+        size,
+        click_point,
+        scale,
+        offset_x,
+        offset_y,
+    )?;
+    let committed = staging.commit()?;
+    committed
+        .into_iter()
+        .next()
+        .map(|path| path.to_string_lossy().to_string())
+        .ok_or_else(|| format!("Converted file {} was not committed", staged.display()))
+}
+
+/// Convert `input` into a new staged .cur file, named by the conversion output template, and
+/// return its staged path. On failure the staged file is discarded.
+///
+/// `click_point` of `None` keeps the source's own hotspot (scaled with the image) when the
+/// input is a .CUR, and uses (0, 0) for other formats.
+pub(crate) fn stage_conversion(
+    staging: &mut ConversionStaging,
+    input: ConversionInput<'_>,
+    size: u32,
+    click_point: Option<(u16, u16)>,
+    scale: f32,
+    offset_x: i32,
+    offset_y: i32,
+) -> Result<PathBuf, String> {
     if !scale.is_finite() || scale <= 0.0 {
         return Err("Scale must be a finite positive number".to_string());
     }
 
-    let file_stem = match input {
-        ConversionInput::Path(input_path) => file_stem_or_default(input_path),
-        ConversionInput::Bytes { filename, .. } => file_stem_or_default(filename),
+    let file_name = match input {
+        ConversionInput::Path(input_path) => conversion_output::file_name_for_path(
+            input_path,
+            file_stem_or_default(input_path),
+            size,
+            "cur",
+        ),
+        ConversionInput::Bytes { data, filename } => conversion_output::file_name_for_bytes(
+            data,
+            file_stem_or_default(filename),
+            size,
+            "cur",
+        ),
     };
 
-    let output_path_str = make_output_path(file_stem)?;
+    let staged = staging.stage(&file_name);
+    let output_path_str = staged.to_string_lossy().to_string();
+    let result = write_converted_cur(
+        input,
+        &output_path_str,
+        size,
+        click_point,
+        scale,
+        offset_x,
+        offset_y,
+    );
+    match result {
+        Ok(()) => Ok(staged),
+        Err(e) => {
+            staging.discard(&staged);
+            Err(e)
+        }
+    }
+}
 
+pub(crate) fn write_converted_cur(
+    input: ConversionInput<'_>,
+    output_path_str: &str,
+    size: u32,
+    click_point: Option<(u16, u16)>,
+    scale: f32,
+    offset_x: i32,
+    offset_y: i32,
+) -> Result<(), String> {
     match input {
         ConversionInput::Path(input_path) => {
-            cursor_converter::convert_to_cur(
-                input_path,
-                &output_path_str,
+            match click_point {
+                Some((click_point_x, click_point_y)) => cursor_converter::convert_to_cur(
+                    input_path,
+                    output_path_str,
+                    size,
+                    click_point_x,
+                    click_point_y,
+                    scale,
+                    offset_x,
+                    offset_y,
+                )?,
+                None => {
+                    cursor_converter::convert_to_cur_preserving_hotspot(
+                        input_path,
+                        output_path_str,
+                        size,
+                        scale,
+                        offset_x,
+                        offset_y,
+                    )?;
+                }
+            }
+            Ok(())
+        }
+        ConversionInput::Bytes { data, filename } => {
+            let conversion = UploadConversion {
                 size,
-                click_point_x,
-                click_point_y,
+                click_point,
                 scale,
                 offset_x,
                 offset_y,
-            )?;
-            Ok(output_path_str)
-        }
-        ConversionInput::Bytes { data, filename } => {
-            let ext = extension_lower(filename);
-            let size = size.min(cursor_converter::MAX_CURSOR_SIZE);
-
-            let image = if ext == "svg" {
-                let temp_svg = TempSvgFile::new(data)?;
-                let temp_path_str = temp_svg.as_path_str();
-                cursor_converter::load_svg(&temp_path_str, size, scale, offset_x, offset_y)?
+            };
+            // Uploads come from anywhere, so they are decoded like other untrusted files
+            let cur_data = if crate::decode_worker::is_enabled() {
+                crate::decode_worker::convert_upload(data, filename, conversion)?
             } else {
-                load_raster_image_from_bytes(data, size, scale, offset_x, offset_y)?
+                convert_uploaded_image(data, filename, &conversion)?
             };
 
-            let cur_data =
-                cursor_converter::generate_cur_data(&image, click_point_x, click_point_y)?;
-
-            std::fs::write(&output_path_str, cur_data)
-                .map_err(|e| format!("Failed to write .CUR file: {}", e))?;
-
-            Ok(output_path_str)
+            std::fs::write(output_path_str, cur_data)
+                .map_err(|e| format!("Failed to write .CUR file: {}", e))
         }
     }
 }
 
+/// .cur bytes for the uploaded image `data` named `filename`, converted in this process; what
+/// the decode worker runs.
+pub(crate) fn convert_uploaded_image(
+    data: &[u8],
+    filename: &str,
+    conversion: &UploadConversion,
+) -> Result<Vec<u8>, String> {
+    let UploadConversion {
+        size,
+        click_point,
+        scale,
+        offset_x,
+        offset_y,
+    } = *conversion;
+    let ext = extension_lower(filename);
+    let size = size.min(cursor_converter::MAX_CURSOR_SIZE);
+
+    let (image, source_click_point) = if ext == "svg" {
+        let temp_svg = TempSvgFile::new(data)?;
+        let temp_path_str = temp_svg.as_path_str();
+        let image = cursor_converter::load_svg(&temp_path_str, size, scale, offset_x, offset_y)?;
+        (image, (0, 0))
+    } else if ext == "cur" {
+        let cur = cursor_converter::cur_reader::read_cur_bytes(data, size)?;
+        cursor_converter::cur_reader::render_cur(
+            &cur,
+            cursor_converter::RenderParams {
+                size,
+                scale,
+                offset_x,
+                offset_y,
+            },
+        )
+    } else {
+        let image = load_raster_image_from_bytes(data, size, scale, offset_x, offset_y)?;
+        (image, (0, 0))
+    };
+
+    let (click_point_x, click_point_y) = click_point.unwrap_or(source_click_point);
+    cursor_converter::generate_cur_data(&image, click_point_x, click_point_y)
+}
+
 /// Convert an image file to .CUR format
 /// Returns the path to the converted .CUR file
 pub fn convert_image_to_cur<R: Runtime>(input_path: &str, _app: &AppHandle<R>) -> Result<String, String> {
     convert_to_cur_impl(
+        &paths::cursors_dir()?,
         ConversionInput::Path(input_path),
         cursor_converter::MAX_CURSOR_SIZE,
-        0,
-        0,
+        None,
         1.0,
         0,
         0,
@@ -158,10 +264,10 @@ pub fn convert_image_bytes_to_cur<R: Runtime>(
     _app: &AppHandle<R>,
 ) -> Result<String, String> {
     convert_to_cur_impl(
+        &paths::cursors_dir()?,
         ConversionInput::Bytes { data, filename },
         cursor_converter::MAX_CURSOR_SIZE,
-        0,
-        0,
+        None,
         1.0,
         0,
         0,
@@ -182,10 +288,10 @@ pub fn convert_image_bytes_to_cur_with_click_point(
     offset_y: i32,
 ) -> Result<String, String> {
     convert_to_cur_impl(
+        &paths::cursors_dir()?,
         ConversionInput::Bytes { data, filename },
         size,
-        click_point_x,
-        click_point_y,
+        Some((click_point_x, click_point_y)),
         scale,
         offset_x,
         offset_y,
@@ -267,8 +373,8 @@ fn load_raster_image_from_bytes(
     Ok(canvas)
 }
 
-/// Convert an image file to .CUR format with an explicit click point and size.
-/// Returns the path to the converted .CUR file.
+/// Convert an image file to .CUR format with an explicit click point and size, into the
+/// conversion output folder. Returns the path to the converted .CUR file.
 #[tauri::command]
 pub fn convert_image_to_cur_with_click_point<R: Runtime>(
     _app: AppHandle<R>,
@@ -281,12 +387,120 @@ pub fn convert_image_to_cur_with_click_point<R: Runtime>(
     offset_y: i32,
 ) -> Result<String, String> {
     convert_to_cur_impl(
+        &conversion_output::output_dir()?,
         ConversionInput::Path(&input_path),
         size,
-        click_point_x,
-        click_point_y,
+        Some((click_point_x, click_point_y)),
         scale,
         offset_x,
         offset_y,
     )
 }
+
+/// Assemble an animated .ani cursor in the cursors folder from one GIF/APNG or a list of
+/// images, one frame each (see `cursor_converter::convert_to_ani`). Empty `delays_ms` keeps
+/// the source timing. Returns the path to the new .ani file.
+#[tauri::command]
+pub fn convert_images_to_ani(
+    input_paths: Vec<String>,
+    size: u32,
+    click_point_x: u16,
+    click_point_y: u16,
+    delays_ms: Vec<u32>,
+) -> Result<String, String> {
+    let first = input_paths
+        .first()
+        .ok_or_else(|| "Choose at least one image for the animation".to_string())?;
+    let mut staging = ConversionStaging::new(&conversion_output::output_dir()?)?;
+    let staged = staging.stage(&conversion_output::file_name_for_path(
+        first,
+        file_stem_or_default(first),
+        size,
+        "ani",
+    ));
+    let inputs: Vec<&str> = input_paths.iter().map(String::as_str).collect();
+    cursor_converter::convert_to_ani(
+        &inputs,
+        &staged.to_string_lossy(),
+        size,
+        click_point_x,
+        click_point_y,
+        &delays_ms,
+    )?;
+    staging
+        .commit()?
+        .into_iter()
+        .next()
+        .map(|path| path.to_string_lossy().to_string())
+        .ok_or_else(|| format!("Converted file {} was not committed", staged.display()))
+}
+
+/// One input file of a folder conversion. Either `output_path` and the click point are set,
+/// or `error` is.
+#[derive(ts_rs::TS, Debug, Clone, Serialize, Deserialize)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct DirectoryConversionFile {
+    pub input_path: String,
+    pub output_path: Option<String>,
+    pub click_point_x: Option<u16>,
+    pub click_point_y: Option<u16>,
+    pub error: Option<String>,
+}
+
+/// Outcome of a folder conversion, one entry per supported input file in name order.
+#[derive(ts_rs::TS, Debug, Clone, Default, Serialize, Deserialize)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct DirectoryConversionReport {
+    pub files: Vec<DirectoryConversionFile>,
+}
+
+impl From<cursor_converter::BatchReport> for DirectoryConversionReport {
+    fn from(report: cursor_converter::BatchReport) -> Self {
+        let files = report
+            .files
+            .into_iter()
+            .map(|file| match file.outcome {
+                Ok(converted) => DirectoryConversionFile {
+                    input_path: file.input_path,
+                    output_path: Some(converted.output_path),
+                    click_point_x: Some(converted.hotspot.0),
+                    click_point_y: Some(converted.hotspot.1),
+                    error: None,
+                },
+                Err(error) => DirectoryConversionFile {
+                    input_path: file.input_path,
+                    output_path: None,
+                    click_point_x: None,
+                    click_point_y: None,
+                    error: Some(error),
+                },
+            })
+            .collect();
+        Self { files }
+    }
+}
+
+/// Convert every supported image in `input_dir` to a .cur file in `output_dir`.
+///
+/// `size` and the click point default to the full cursor size and (0, 0); .cur inputs keep
+/// their own click point. A file that fails to convert is reported and the rest still run.
+#[tauri::command]
+pub async fn convert_directory_to_cur(
+    input_dir: String,
+    output_dir: String,
+    size: Option<u32>,
+    click_point_x: Option<u16>,
+    click_point_y: Option<u16>,
+) -> Result<DirectoryConversionReport, String> {
+    let defaults = cursor_converter::BatchDefaults {
+        size: size.unwrap_or(cursor_converter::MAX_CURSOR_SIZE),
+        hotspot_x: click_point_x.unwrap_or(0),
+        hotspot_y: click_point_y.unwrap_or(0),
+    };
+    tauri::async_runtime::spawn_blocking(move || {
+        cursor_converter::convert_directory_to_cur(&input_dir, &output_dir, defaults)
+    })
+    .await
+    .map_err(|e| format!("Task join error: {}", e))?
+    .map(DirectoryConversionReport::from)
+}
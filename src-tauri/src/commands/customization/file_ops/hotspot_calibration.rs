@@ -0,0 +1,322 @@
+/// Click calibration for cursor hotspots.
+///
+/// The UI draws a target and asks the user to click it with the tip of the cursor they see.
+/// Windows reports each click at the cursor's hotspot, so the distance between click and
+/// target is how far the stored hotspot is from the tip the user perceives.
+use crate::commands::customization::library::find_library_cursor_by_path;
+use crate::state::AppState;
+use serde::Serialize;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, State};
+
+/// Sessions that run longer than this are treated as abandoned.
+const DEFAULT_SESSION_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Fewer samples than this gives an unreliable estimate.
+pub(crate) const MIN_CALIBRATION_SAMPLES: usize = 3;
+
+#[derive(Debug, Clone)]
+struct CalibrationSession {
+    cursor_name: String,
+    cursor_path: String,
+    native_size: u32,
+    display_size: u32,
+    hotspot: (u16, u16),
+    started_at: Instant,
+    timeout: Duration,
+    // (click - target) in screen pixels
+    samples: Vec<(f64, f64)>,
+}
+
+/// Managed state holding the active calibration session, if any.
+#[derive(Default)]
+pub struct HotspotCalibrationState {
+    session: Option<CalibrationSession>,
+}
+
+#[derive(ts_rs::TS, Serialize, Clone, Debug)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct HotspotCalibrationSession {
+    pub cursor_name: String,
+    pub cursor_path: String,
+    pub click_point_x: u16,
+    pub click_point_y: u16,
+    pub timeout_ms: u32,
+    pub min_samples: u32,
+}
+
+#[derive(ts_rs::TS, Serialize, Clone, Debug, PartialEq)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct HotspotCalibrationResult {
+    pub cursor_name: String,
+    pub cursor_path: String,
+    pub sample_count: u32,
+    /// Median click offset from the target, in screen pixels
+    pub offset_x: f64,
+    pub offset_y: f64,
+    pub current_click_point_x: u16,
+    pub current_click_point_y: u16,
+    pub suggested_click_point_x: u16,
+    pub suggested_click_point_y: u16,
+    /// Library entry to pass to `update_library_cursor_click_point` when the user accepts.
+    /// `None` when the applied cursor is not in the library and cannot be rewritten.
+    pub library_cursor_id: Option<String>,
+}
+
+/// Start capturing calibration clicks for the cursor currently applied to `cursor_name`.
+#[tauri::command]
+pub fn start_hotspot_calibration(
+    state: State<AppState>,
+    calibration: State<'_, Mutex<HotspotCalibrationState>>,
+    cursor_name: String,
+) -> Result<HotspotCalibrationSession, String> {
+    let (cursor_path, display_size) = {
+        let guard = state.read_all()?;
+        let path = guard
+            .cursor
+            .cursor_paths
+            .get(&cursor_name)
+            .cloned()
+            .ok_or_else(|| format!("No custom cursor is applied to {}", cursor_name))?;
+        (path, guard.prefs.cursor_size.max(1) as u32)
+    };
+
+    let bytes = std::fs::read(&cursor_path)
+        .map_err(|e| format!("Failed to read cursor file: {}", e))?;
+    let (native_size, hotspot) = read_cur_header(&bytes)
+        .ok_or_else(|| "Only .cur files can be calibrated".to_string())?;
+
+    let session = CalibrationSession {
+        cursor_name: cursor_name.clone(),
+        cursor_path: cursor_path.clone(),
+        native_size,
+        display_size,
+        hotspot,
+        started_at: Instant::now(),
+        timeout: DEFAULT_SESSION_TIMEOUT,
+        samples: Vec::new(),
+    };
+
+    let mut guard = calibration
+        .lock()
+        .map_err(|_| "Calibration state poisoned".to_string())?;
+    guard.session = Some(session);
+
+    cc_debug!(
+        "[CursorChanger] Started hotspot calibration for {} ({})",
+        cursor_name,
+        cursor_path
+    );
+
+    Ok(HotspotCalibrationSession {
+        cursor_name,
+        cursor_path,
+        click_point_x: hotspot.0,
+        click_point_y: hotspot.1,
+        timeout_ms: DEFAULT_SESSION_TIMEOUT.as_millis() as u32,
+        min_samples: MIN_CALIBRATION_SAMPLES as u32,
+    })
+}
+
+/// Record one click. Coordinates are physical screen pixels. Returns the sample count so far.
+#[tauri::command]
+pub fn record_calibration_click(
+    calibration: State<'_, Mutex<HotspotCalibrationState>>,
+    target_x: f64,
+    target_y: f64,
+    click_x: f64,
+    click_y: f64,
+) -> Result<u32, String> {
+    let mut guard = calibration
+        .lock()
+        .map_err(|_| "Calibration state poisoned".to_string())?;
+    let session = active_session(&mut guard)?;
+
+    if ![target_x, target_y, click_x, click_y]
+        .iter()
+        .all(|v| v.is_finite())
+    {
+        return Err("Calibration coordinates must be finite numbers".to_string());
+    }
+
+    session.samples.push((click_x - target_x, click_y - target_y));
+    Ok(session.samples.len() as u32)
+}
+
+/// End the session and compute the suggested hotspot. Nothing is written to disk here;
+/// the UI offers the result and calls `update_library_cursor_click_point` if accepted.
+#[tauri::command]
+pub fn finish_hotspot_calibration(
+    app: AppHandle,
+    calibration: State<'_, Mutex<HotspotCalibrationState>>,
+) -> Result<HotspotCalibrationResult, String> {
+    let session = {
+        let mut guard = calibration
+            .lock()
+            .map_err(|_| "Calibration state poisoned".to_string())?;
+        active_session(&mut guard)?;
+        guard.session.take().ok_or("No calibration session in progress")?
+    };
+
+    let mut result = compute_calibration(&session)?;
+    result.library_cursor_id =
+        find_library_cursor_by_path(&app, &session.cursor_path).map(|cursor| cursor.id);
+    Ok(result)
+}
+
+#[tauri::command]
+pub fn cancel_hotspot_calibration(
+    calibration: State<'_, Mutex<HotspotCalibrationState>>,
+) -> Result<(), String> {
+    let mut guard = calibration
+        .lock()
+        .map_err(|_| "Calibration state poisoned".to_string())?;
+    guard.session = None;
+    Ok(())
+}
+
+fn active_session(
+    state: &mut HotspotCalibrationState,
+) -> Result<&mut CalibrationSession, String> {
+    let expired = match &state.session {
+        Some(session) => session.started_at.elapsed() > session.timeout,
+        None => return Err("No calibration session in progress".to_string()),
+    };
+
+    if expired {
+        state.session = None;
+        return Err("Calibration session timed out".to_string());
+    }
+
+    state
+        .session
+        .as_mut()
+        .ok_or_else(|| "No calibration session in progress".to_string())
+}
+
+fn compute_calibration(session: &CalibrationSession) -> Result<HotspotCalibrationResult, String> {
+    if session.samples.len() < MIN_CALIBRATION_SAMPLES {
+        return Err(format!(
+            "At least {} clicks are needed to calibrate (got {})",
+            MIN_CALIBRATION_SAMPLES,
+            session.samples.len()
+        ));
+    }
+
+    let offset_x = median(session.samples.iter().map(|s| s.0).collect());
+    let offset_y = median(session.samples.iter().map(|s| s.1).collect());
+
+    // Offsets are measured on screen; convert to pixels of the cursor image, which Windows
+    // scales from its native size to the configured cursor size.
+    let ratio = f64::from(session.native_size) / f64::from(session.display_size);
+    let max = f64::from(session.native_size.saturating_sub(1));
+    let adjust = |current: u16, offset: f64| -> u16 {
+        (f64::from(current) - offset * ratio).round().clamp(0.0, max) as u16
+    };
+
+    Ok(HotspotCalibrationResult {
+        cursor_name: session.cursor_name.clone(),
+        cursor_path: session.cursor_path.clone(),
+        sample_count: session.samples.len() as u32,
+        offset_x,
+        offset_y,
+        current_click_point_x: session.hotspot.0,
+        current_click_point_y: session.hotspot.1,
+        suggested_click_point_x: adjust(session.hotspot.0, offset_x),
+        suggested_click_point_y: adjust(session.hotspot.1, offset_y),
+        library_cursor_id: None,
+    })
+}
+
+fn median(mut values: Vec<f64>) -> f64 {
+    values.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    let mid = values.len() / 2;
+    if values.len() % 2 == 0 {
+        (values[mid - 1] + values[mid]) / 2.0
+    } else {
+        values[mid]
+    }
+}
+
+/// Read the image size and hotspot from the first ICONDIRENTRY of a .cur file.
+fn read_cur_header(bytes: &[u8]) -> Option<(u32, (u16, u16))> {
+    // ICONDIR type 2 marks a cursor (1 would be an icon without a hotspot)
+    if bytes.len() < 22 || bytes[0..4] != [0, 0, 2, 0] {
+        return None;
+    }
+
+    let width = if bytes[6] == 0 { 256 } else { u32::from(bytes[6]) };
+    let height = if bytes[7] == 0 { 256 } else { u32::from(bytes[7]) };
+    let hotspot_x = u16::from_le_bytes([bytes[10], bytes[11]]);
+    let hotspot_y = u16::from_le_bytes([bytes[12], bytes[13]]);
+    Some((width.max(height), (hotspot_x, hotspot_y)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn session_with(samples: Vec<(f64, f64)>, native: u32, display: u32) -> CalibrationSession {
+        CalibrationSession {
+            cursor_name: "Normal".to_string(),
+            cursor_path: "C:\\cursors\\arrow.cur".to_string(),
+            native_size: native,
+            display_size: display,
+            hotspot: (10, 10),
+            started_at: Instant::now(),
+            timeout: DEFAULT_SESSION_TIMEOUT,
+            samples,
+        }
+    }
+
+    #[test]
+    fn calibration_moves_hotspot_against_click_offset() {
+        // Clicks land 4px right and 2px below the target on a 2x scaled cursor.
+        let session = session_with(vec![(4.0, 2.0), (4.0, 2.0), (5.0, 1.0)], 32, 64);
+        let result = compute_calibration(&session).expect("calibration");
+
+        assert_eq!(result.offset_x, 4.0);
+        assert_eq!(result.offset_y, 2.0);
+        assert_eq!(result.suggested_click_point_x, 8);
+        assert_eq!(result.suggested_click_point_y, 9);
+    }
+
+    #[test]
+    fn calibration_ignores_single_outlier_and_clamps() {
+        let session = session_with(vec![(-30.0, 0.0), (-30.0, 0.0), (200.0, 0.0)], 32, 32);
+        let result = compute_calibration(&session).expect("calibration");
+        assert_eq!(result.offset_x, -30.0);
+        assert_eq!(result.suggested_click_point_x, 31);
+    }
+
+    #[test]
+    fn calibration_requires_minimum_samples() {
+        let session = session_with(vec![(1.0, 1.0)], 32, 32);
+        assert!(compute_calibration(&session).is_err());
+    }
+
+    #[test]
+    fn expired_session_is_dropped() {
+        let mut session = session_with(Vec::new(), 32, 32);
+        session.timeout = Duration::ZERO;
+        std::thread::sleep(Duration::from_millis(2));
+        let mut state = HotspotCalibrationState {
+            session: Some(session),
+        };
+        assert!(active_session(&mut state).is_err());
+        assert!(state.session.is_none());
+    }
+
+    #[test]
+    fn read_cur_header_rejects_icons() {
+        use cursor_convert::fixtures::{self, CurDefect};
+
+        let bytes = fixtures::cur(48, (3, 5));
+        assert_eq!(read_cur_header(&bytes), Some((48, (3, 5))));
+        assert_eq!(
+            read_cur_header(&fixtures::broken_cur(CurDefect::IconType)),
+            None
+        );
+    }
+}
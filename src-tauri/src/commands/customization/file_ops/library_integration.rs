@@ -1,10 +1,42 @@
-use super::conversion::{convert_image_bytes_to_cur, convert_image_bytes_to_cur_with_click_point};
+use super::conversion::{
+    convert_image_bytes_to_cur, convert_image_bytes_to_cur_with_click_point, stage_conversion,
+    ConversionInput,
+};
 use super::preview::get_cursor_with_click_point;
-use crate::commands::customization::library::{add_cursor_to_library, LibraryCursor};
+use super::staging::ConversionStaging;
+use crate::commands::customization::library::{
+    add_cursor_to_library, read_ani_info, LibraryCursor,
+};
+use crate::operations::{Operation, OperationPhase};
 /// Library integration operations for cursor uploads
-use std::path::Path;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
 use tauri::AppHandle;
 
+/// One file of a batch upload.
+#[derive(ts_rs::TS, Debug, Clone, Serialize, Deserialize)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct UploadedCursorFile {
+    pub filename: String,
+    pub data: Vec<u8>,
+}
+
+#[derive(ts_rs::TS, Debug, Clone, Serialize, Deserialize)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct BatchUploadFailure {
+    pub filename: String,
+    pub error: String,
+}
+
+/// Outcome of a batch upload. The batch is all-or-nothing: when `failures` is non-empty,
+/// `added` is empty and no file was written to the cursors folder.
+#[derive(ts_rs::TS, Debug, Clone, Default, Serialize, Deserialize)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct BatchUploadReport {
+    pub added: Vec<LibraryCursor>,
+    pub failures: Vec<BatchUploadFailure>,
+}
+
 /// Accept an uploaded file as raw bytes, convert if necessary (without saving source image),
 /// extract hotspot info and add the resulting .cur/.ani file to the library.
 ///
@@ -53,12 +85,8 @@ pub fn add_uploaded_cursor_to_library(
     // Extract click point information from the final .cur/.ani file
     let click_point_info = get_cursor_with_click_point(final_path.clone())?;
 
-    // Derive a friendly name from the uploaded filename (file stem)
-    let name = Path::new(&filename)
-        .file_stem()
-        .and_then(|s| s.to_str())
-        .unwrap_or("Custom Cursor")
-        .to_string();
+    // Keep an animated cursor's own title, else derive a friendly name from the filename
+    let name = uploaded_cursor_name(&filename, &ext, &data);
 
     // Add to library using existing library logic
     let cursor = add_cursor_to_library(
@@ -168,3 +196,113 @@ pub fn add_uploaded_image_with_click_point_to_library(
 
     Ok(cursor)
 }
+
+/// Library name for an uploaded file: the title stored in an .ani, else the file stem.
+fn uploaded_cursor_name(filename: &str, ext: &str, data: &[u8]) -> String {
+    let title = if ext.eq_ignore_ascii_case("ani") {
+        read_ani_info(data).title
+    } else {
+        None
+    };
+    title.unwrap_or_else(|| {
+        Path::new(filename)
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or("Custom Cursor")
+            .to_string()
+    })
+}
+
+/// Convert or copy one uploaded file into `staging`.
+fn stage_uploaded_cursor(
+    staging: &mut ConversionStaging,
+    filename: &str,
+    data: &[u8],
+) -> Result<PathBuf, String> {
+    let ext = Path::new(filename)
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(|s| s.to_lowercase())
+        .unwrap_or_default();
+
+    match ext.as_str() {
+        "svg" | "png" | "ico" | "bmp" | "jpg" | "jpeg" => stage_conversion(
+            staging,
+            ConversionInput::Bytes { data, filename },
+            crate::cursor_converter::MAX_CURSOR_SIZE,
+            None,
+            1.0,
+            0,
+            0,
+        ),
+        "cur" | "ani" => {
+            let file_name = Path::new(filename)
+                .file_name()
+                .and_then(|n| n.to_str())
+                .ok_or_else(|| format!("Invalid file name: {}", filename))?;
+            let staged = staging.stage(file_name);
+            if let Err(e) = std::fs::write(&staged, data) {
+                staging.discard(&staged);
+                return Err(format!("Failed to save cursor file: {}", e));
+            }
+            Ok(staged)
+        }
+        _ => Err(format!("Unsupported file type: .{}", ext)),
+    }
+}
+
+/// Add several uploaded files to the library at once.
+///
+/// Every file is converted into a staging area first; only if all of them succeed are the
+/// results moved into the cursors folder and added to the library. Otherwise the report lists
+/// each failing file and nothing is written. Cancelling through `op_id` discards the batch.
+#[tauri::command]
+pub fn add_uploaded_cursors_to_library(
+    app: AppHandle,
+    files: Vec<UploadedCursorFile>,
+    op_id: Option<String>,
+) -> Result<BatchUploadReport, String> {
+    let operation = Operation::begin(&app, op_id);
+    let cursors_dir = crate::paths::cursors_dir()?;
+    let mut staging = ConversionStaging::new(&cursors_dir)?;
+    let mut report = BatchUploadReport::default();
+
+    let mut converting = operation.phase(OperationPhase::Converting, files.len());
+    for file in &files {
+        operation.checkpoint()?;
+        if let Err(error) = stage_uploaded_cursor(&mut staging, &file.filename, &file.data) {
+            report.failures.push(BatchUploadFailure {
+                filename: file.filename.clone(),
+                error,
+            });
+        }
+        converting.advance(Some(&file.filename));
+    }
+    if !report.failures.is_empty() {
+        return Ok(report);
+    }
+    operation.checkpoint()?;
+
+    let mut saving = operation.phase(OperationPhase::Saving, files.len());
+    let committed = staging.commit()?;
+    for (file, path) in files.iter().zip(committed) {
+        let final_path = path.to_string_lossy().to_string();
+        let click_point_info = get_cursor_with_click_point(final_path.clone())?;
+        let ext = Path::new(&file.filename)
+            .extension()
+            .and_then(|e| e.to_str())
+            .unwrap_or_default();
+        let name = uploaded_cursor_name(&file.filename, ext, &file.data);
+        let cursor = add_cursor_to_library(
+            app.clone(),
+            name,
+            final_path,
+            click_point_info.click_point_x,
+            click_point_info.click_point_y,
+        )?;
+        report.added.push(cursor);
+        saving.advance(Some(&file.filename));
+    }
+
+    Ok(report)
+}
@@ -7,23 +7,39 @@ pub(crate) mod browsing;
 /// This module provides file operations for cursor customization, organized into
 /// focused submodules for better maintainability and testability.
 pub(crate) mod conversion;
+pub(crate) mod hotspot_calibration;
 pub(crate) mod hotspot_update;
 pub(crate) mod library_integration;
 pub(crate) mod preview;
+pub(crate) mod preview_session;
 pub(crate) mod reading;
+pub(crate) mod staging;
 
 #[cfg(test)]
 mod tests;
 
 // Re-export public API
-pub use browsing::browse_cursor_file;
+pub use browsing::{browse_cursor_file, BrowsedCursorFile};
 pub use conversion::{
-    convert_image_bytes_to_cur, convert_image_bytes_to_cur_with_click_point, convert_image_to_cur,
-    convert_image_to_cur_with_click_point,
+    convert_directory_to_cur, convert_image_bytes_to_cur,
+    convert_image_bytes_to_cur_with_click_point, convert_image_to_cur,
+    convert_image_to_cur_with_click_point, convert_images_to_ani, DirectoryConversionFile,
+    DirectoryConversionReport,
+};
+pub use hotspot_calibration::{
+    cancel_hotspot_calibration, finish_hotspot_calibration, record_calibration_click,
+    start_hotspot_calibration, HotspotCalibrationResult, HotspotCalibrationSession,
+    HotspotCalibrationState,
 };
 pub use hotspot_update::update_library_cursor_click_point;
 pub use library_integration::{
-    add_uploaded_cursor_to_library, add_uploaded_image_with_click_point_to_library,
+    add_uploaded_cursor_to_library, add_uploaded_cursors_to_library,
+    add_uploaded_image_with_click_point_to_library, BatchUploadFailure, BatchUploadReport,
+    UploadedCursorFile,
 };
 pub use preview::{get_cursor_with_click_point, render_cursor_image_preview};
+pub use preview_session::{
+    begin_preview_session, end_preview_session, update_preview, ConversionPreviewState,
+    PreviewParams, PreviewSessionInfo,
+};
 pub use reading::{read_cursor_file_as_bytes, read_cursor_file_as_data_url};
@@ -80,23 +80,32 @@ pub fn render_cursor_image_preview(file_path: String) -> Result<String, String>
 
     if ext == "svg" {
         // Render SVG to PNG bytes to avoid WebView rendering quirks
-        match cursor_converter::svg_handler::render_svg_to_png_bytes(
-            &file_path,
-            cursor_converter::MAX_CURSOR_SIZE,
-        ) {
-            Ok(png_bytes) => {
-                let base64 = base64_encode(&png_bytes);
-                return Ok(format!("data:image/png;base64,{}", base64));
-            }
-            Err(e) => {
-                // Provide more context for the frontend so DevTools shows actionable diagnostics
-                let msg = format!("Failed to render SVG preview for '{}': {}", file_path, e);
-                cc_error!("[render_cursor_image_preview] {}", msg);
-                return Err(msg);
-            }
-        }
+        let rendered = if crate::decode_worker::is_enabled() {
+            crate::decode_worker::svg_preview(&file_path)
+        } else {
+            render_svg_preview(&file_path)
+        };
+        return rendered.map_err(|e| {
+            // Provide more context for the frontend so DevTools shows actionable diagnostics
+            let msg = format!("Failed to render SVG preview for '{}': {}", file_path, e);
+            cc_error!("[render_cursor_image_preview] {}", msg);
+            msg
+        });
     }
 
     // Non-SVG: fall back to data URL (base64)
     super::reading::read_cursor_file_as_data_url(file_path)
 }
+
+/// PNG data URL of the SVG at `file_path`, rendered in this process; what the decode worker
+/// runs.
+pub(crate) fn render_svg_preview(file_path: &str) -> Result<String, String> {
+    let png_bytes = cursor_converter::svg_handler::render_svg_to_png_bytes(
+        file_path,
+        cursor_converter::MAX_CURSOR_SIZE,
+    )?;
+    Ok(format!(
+        "data:image/png;base64,{}",
+        base64_encode(&png_bytes)
+    ))
+}
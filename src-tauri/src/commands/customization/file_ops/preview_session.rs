@@ -0,0 +1,166 @@
+/// Live conversion previews for the hotspot/scale editor.
+///
+/// `begin_preview_session` decodes the source once and keeps it in managed state;
+/// `update_preview` re-renders it with new scale and offset values from memory, and
+/// `end_preview_session` drops it. Renders use the same placement as `convert_to_cur`,
+/// so what the editor shows is what the converted file will contain.
+use crate::cursor_converter::{PreviewSource, RenderParams, MAX_CURSOR_SIZE};
+use crate::utils::encoding::base64_encode;
+use image::{codecs::png::PngEncoder, ImageEncoder};
+use serde::{Deserialize, Serialize};
+use std::sync::Mutex;
+use tauri::State;
+
+struct PreviewSession {
+    id: u64,
+    source: PreviewSource,
+}
+
+/// Managed state holding the open preview session, if any.
+#[derive(Default)]
+pub struct ConversionPreviewState {
+    session: Option<PreviewSession>,
+    next_id: u64,
+}
+
+#[derive(ts_rs::TS, Serialize, Clone, Debug)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct PreviewSessionInfo {
+    #[ts(type = "number")]
+    pub session_id: u64,
+    pub source_width: u32,
+    pub source_height: u32,
+}
+
+#[derive(ts_rs::TS, Deserialize, Clone, Copy, Debug)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct PreviewParams {
+    pub size: u32,
+    pub scale: f32,
+    pub offset_x: i32,
+    pub offset_y: i32,
+}
+
+impl From<PreviewParams> for RenderParams {
+    fn from(params: PreviewParams) -> Self {
+        Self {
+            size: params.size.min(MAX_CURSOR_SIZE),
+            scale: params.scale,
+            offset_x: params.offset_x,
+            offset_y: params.offset_y,
+        }
+    }
+}
+
+/// Decode `path` and open a preview session, replacing any previous one.
+#[tauri::command]
+pub fn begin_preview_session(
+    previews: State<'_, Mutex<ConversionPreviewState>>,
+    path: String,
+) -> Result<PreviewSessionInfo, String> {
+    if !std::path::Path::new(&path).exists() {
+        return Err(format!("File not found: {}", path));
+    }
+    // Decode before taking the lock so slow files don't block other sessions' updates
+    let source = PreviewSource::open(&path)?;
+    let (source_width, source_height) = source.dimensions();
+
+    let mut guard = previews
+        .lock()
+        .map_err(|_| "Preview session state poisoned".to_string())?;
+    guard.next_id += 1;
+    let session_id = guard.next_id;
+    guard.session = Some(PreviewSession {
+        id: session_id,
+        source,
+    });
+
+    Ok(PreviewSessionInfo {
+        session_id,
+        source_width,
+        source_height,
+    })
+}
+
+/// Re-render the session's source with `params`. Returns a PNG data URL.
+#[tauri::command]
+pub fn update_preview(
+    previews: State<'_, Mutex<ConversionPreviewState>>,
+    session_id: u64,
+    params: PreviewParams,
+) -> Result<String, String> {
+    let guard = previews
+        .lock()
+        .map_err(|_| "Preview session state poisoned".to_string())?;
+    let session = active_session(&guard, session_id)?;
+    let frame = session.source.render(params.into())?;
+
+    let (width, height) = frame.dimensions();
+    let mut png = Vec::new();
+    PngEncoder::new(&mut png)
+        .write_image(frame.as_raw(), width, height, image::ColorType::Rgba8.into())
+        .map_err(|e| format!("Failed to encode preview: {}", e))?;
+    Ok(format!("data:image/png;base64,{}", base64_encode(&png)))
+}
+
+/// Close the session. Ending a session that was already replaced is a no-op.
+#[tauri::command]
+pub fn end_preview_session(
+    previews: State<'_, Mutex<ConversionPreviewState>>,
+    session_id: u64,
+) -> Result<(), String> {
+    let mut guard = previews
+        .lock()
+        .map_err(|_| "Preview session state poisoned".to_string())?;
+    if guard.session.as_ref().is_some_and(|s| s.id == session_id) {
+        guard.session = None;
+    }
+    Ok(())
+}
+
+fn active_session(
+    state: &ConversionPreviewState,
+    session_id: u64,
+) -> Result<&PreviewSession, String> {
+    state
+        .session
+        .as_ref()
+        .filter(|s| s.id == session_id)
+        .ok_or_else(|| "Preview session has ended; open the file again".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{ImageBuffer, Rgba};
+
+    fn state_with_session(id: u64) -> ConversionPreviewState {
+        ConversionPreviewState {
+            session: Some(PreviewSession {
+                id,
+                source: PreviewSource::Raster(ImageBuffer::from_pixel(8, 8, Rgba([1, 2, 3, 255]))),
+            }),
+            next_id: id,
+        }
+    }
+
+    #[test]
+    fn stale_session_ids_are_rejected() {
+        let state = state_with_session(2);
+        assert!(active_session(&state, 2).is_ok());
+        assert!(active_session(&state, 1).is_err());
+        assert!(active_session(&ConversionPreviewState::default(), 1).is_err());
+    }
+
+    #[test]
+    fn params_are_clamped_to_cursor_limit() {
+        let params = RenderParams::from(PreviewParams {
+            size: 1024,
+            scale: 1.5,
+            offset_x: -3,
+            offset_y: 4,
+        });
+        assert_eq!(params.size, MAX_CURSOR_SIZE);
+        assert_eq!((params.offset_x, params.offset_y), (-3, 4));
+    }
+}
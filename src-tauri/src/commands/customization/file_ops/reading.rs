@@ -53,7 +53,9 @@ pub fn read_cursor_file_as_data_url(file_path: String) -> Result<String, String>
     // ANI files use RIFF container format which browsers can't display directly
     if ext == "ani" {
         // Delegate to the library preview function which has full ANI parsing
-        return crate::commands::customization::library::get_library_cursor_preview(file_path);
+        return crate::commands::customization::library::get_library_cursor_preview_data_url(
+            file_path,
+        );
     }
 
     // Non-SVG files: encode as base64 and return with an appropriate MIME type
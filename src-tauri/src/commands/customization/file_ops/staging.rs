@@ -0,0 +1,149 @@
+/// Staged conversion output
+///
+/// Converted files are written into a temporary directory next to their destination and only
+/// moved into place by [`ConversionStaging::commit`], so a conversion (or a batch of them)
+/// that fails midway never leaves partial .cur files in the cursors folder. Like
+/// `save_library`, the final step is a rename on the same volume. Dropping an uncommitted
+/// staging area deletes everything written to it.
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use tempfile::TempDir;
+
+pub(crate) struct ConversionStaging {
+    dir: TempDir,
+    target_dir: PathBuf,
+    staged: Vec<PathBuf>,
+}
+
+impl ConversionStaging {
+    /// Staging area for files that will end up in `target_dir`.
+    pub(crate) fn new(target_dir: &Path) -> Result<Self, String> {
+        fs::create_dir_all(target_dir)
+            .map_err(|e| format!("Failed to create output directory: {}", e))?;
+        // Stage beside the target rather than inside it so folder watchers never see
+        // half-written files; the parent is on the same volume, so commit is a rename.
+        let staging_parent = target_dir.parent().unwrap_or(target_dir);
+        let dir = tempfile::Builder::new()
+            .prefix(".conversion-staging-")
+            .tempdir_in(staging_parent)
+            .map_err(|e| format!("Failed to create conversion staging directory: {}", e))?;
+        Ok(Self {
+            dir,
+            target_dir: target_dir.to_path_buf(),
+            staged: Vec::new(),
+        })
+    }
+
+    /// Reserve a staged path for an output called `file_name`. Names are made unique within
+    /// the staging area; collisions with existing files are resolved at commit.
+    pub(crate) fn stage(&mut self, file_name: &str) -> PathBuf {
+        let path = unique_path(self.dir.path(), file_name);
+        self.staged.push(path.clone());
+        path
+    }
+
+    /// Forget a staged output, e.g. after its conversion failed.
+    pub(crate) fn discard(&mut self, path: &Path) {
+        self.staged.retain(|p| p != path);
+        let _ = fs::remove_file(path);
+    }
+
+    /// Move every staged output into the target directory, in staging order. If any move
+    /// fails, the outputs already moved are removed again and nothing is left behind.
+    pub(crate) fn commit(self) -> Result<Vec<PathBuf>, String> {
+        let mut committed: Vec<PathBuf> = Vec::with_capacity(self.staged.len());
+        for staged in &self.staged {
+            let file_name = staged
+                .file_name()
+                .and_then(|n| n.to_str())
+                .ok_or_else(|| "Staged output has no file name".to_string())?;
+            let destination = unique_path(&self.target_dir, file_name);
+            if let Err(e) = fs::rename(staged, &destination) {
+                for path in &committed {
+                    let _ = fs::remove_file(path);
+                }
+                return Err(format!(
+                    "Failed to move {} into place: {}",
+                    destination.display(),
+                    e
+                ));
+            }
+            committed.push(destination);
+        }
+        Ok(committed)
+    }
+}
+
+/// `dir/file_name`, or `dir/stem_2.ext`, `dir/stem_3.ext`, ... if that is taken.
+pub(crate) fn unique_path(dir: &Path, file_name: &str) -> PathBuf {
+    let candidate = dir.join(file_name);
+    if !candidate.exists() {
+        return candidate;
+    }
+
+    let name = Path::new(file_name);
+    let stem = name
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("cursor");
+    let extension = name.extension().and_then(|e| e.to_str());
+    (2u32..)
+        .map(|idx| match extension {
+            Some(ext) => dir.join(format!("{}_{}.{}", stem, idx, ext)),
+            None => dir.join(format!("{}_{}", stem, idx)),
+        })
+        .find(|path| !path.exists())
+        .unwrap_or(candidate)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn commit_moves_outputs_and_resolves_name_clashes() {
+        let root = tempfile::tempdir().expect("tempdir");
+        let target = root.path().join("cursors");
+        fs::create_dir_all(&target).unwrap();
+        fs::write(target.join("arrow.cur"), b"existing").unwrap();
+
+        let mut staging = ConversionStaging::new(&target).expect("staging");
+        let first = staging.stage("arrow.cur");
+        let second = staging.stage("arrow.cur");
+        fs::write(&first, b"one").unwrap();
+        fs::write(&second, b"two").unwrap();
+
+        let committed = staging.commit().expect("commit");
+        let names: Vec<String> = committed
+            .iter()
+            .map(|p| p.file_name().unwrap().to_string_lossy().to_string())
+            .collect();
+        assert_eq!(names, vec!["arrow_2.cur", "arrow_2_2.cur"]);
+        assert_eq!(fs::read(target.join("arrow.cur")).unwrap(), b"existing");
+        assert_eq!(fs::read(&committed[0]).unwrap(), b"one");
+    }
+
+    #[test]
+    fn dropping_uncommitted_staging_leaves_nothing_behind() {
+        let root = tempfile::tempdir().expect("tempdir");
+        let target = root.path().join("cursors");
+
+        {
+            let mut staging = ConversionStaging::new(&target).expect("staging");
+            fs::write(staging.stage("half.cur"), b"partial").unwrap();
+        }
+
+        assert_eq!(fs::read_dir(&target).unwrap().count(), 0);
+        let leftovers: Vec<_> = fs::read_dir(root.path())
+            .unwrap()
+            .flatten()
+            .filter(|e| {
+                e.file_name()
+                    .to_string_lossy()
+                    .starts_with(".conversion-staging-")
+            })
+            .collect();
+        assert!(leftovers.is_empty());
+    }
+}
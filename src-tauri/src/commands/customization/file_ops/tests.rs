@@ -144,3 +144,35 @@ fn test_read_empty_file() {
     let bytes = result.unwrap();
     assert_eq!(bytes.len(), 0, "Empty file should have 0 bytes");
 }
+
+/// Test that browsed cursor files report their largest frame, hotspot and frame count
+#[test]
+fn test_describe_browsed_cursor_files() {
+    use cursor_convert::fixtures;
+
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+
+    let cur_path = temp_dir.path().join("arrow.cur");
+    let cur_data = fixtures::multi_cur(&[(32, (4, 6)), (64, (8, 12))]);
+    fs::write(&cur_path, &cur_data).expect("Failed to write CUR");
+    let cur = browsing::describe_cursor_file(&cur_path.to_string_lossy()).unwrap();
+    assert_eq!(cur.format.as_deref(), Some("cur"));
+    assert_eq!((cur.width, cur.height), (Some(64), Some(64)));
+    assert_eq!((cur.hotspot_x, cur.hotspot_y), (Some(8), Some(12)));
+    assert_eq!(cur.file_size, cur_data.len() as u64);
+
+    let ani_path = temp_dir.path().join("busy.ani");
+    fs::write(&ani_path, fixtures::ani(3, 32)).expect("Failed to write ANI");
+    let ani = browsing::describe_cursor_file(&ani_path.to_string_lossy()).unwrap();
+    assert_eq!(ani.format.as_deref(), Some("ani"));
+    assert_eq!(ani.frame_count, Some(3));
+    assert_eq!((ani.width, ani.height), (Some(32), Some(32)));
+
+    // Unreadable files still come back, so the import can report the problem
+    let broken_path = temp_dir.path().join("broken.cur");
+    fs::write(&broken_path, b"not a cursor").expect("Failed to write file");
+    let broken = browsing::describe_cursor_file(&broken_path.to_string_lossy()).unwrap();
+    assert!(broken.format.is_none());
+    assert!(broken.error.is_some());
+    assert_eq!(broken.file_size, 12);
+}
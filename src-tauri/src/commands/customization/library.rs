@@ -3,14 +3,43 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 use std::fs;
 use std::path::{Path, PathBuf};
-use tauri::{AppHandle, Runtime};
+use tauri::{AppHandle, Emitter, Manager, Runtime};
 
 use crate::state::CustomizationMode;
 
 mod ani;
+mod collections;
+mod defaults_report;
+mod desktop_preview;
 mod export;
+mod lock;
+mod paging;
 mod preview;
+mod resize_glyphs;
+mod source_link;
 mod store;
+mod sync;
+mod variants;
+
+pub(crate) use ani::{read_ani_frame_summary, AniInfo};
+pub(crate) use preview::PreviewImage;
+pub(crate) use resize_glyphs::generated_resize_cursor;
+pub(crate) use variants::{brightness_adjusted_variant, populate_size_metadata, prescaled_variant_path};
+pub use collections::{
+    create_collection, get_library_collections, move_cursor_to_collection,
+    reorder_collection_cursors, LibraryCollection,
+};
+pub use defaults_report::{DefaultItemKind, DefaultItemResult, DefaultsInitReport};
+pub use desktop_preview::{load_scaled_cursor_image, render_desktop_context_preview};
+pub use lock::{LibraryLock, LibraryLockError};
+pub use paging::{LibraryFilter, LibraryPage, LibrarySort, MAX_PAGE_LIMIT};
+pub use source_link::{
+    hot_reload_source_paths, reload_linked_cursors, set_cursor_hot_reload, set_cursor_source_link,
+};
+pub use sync::merge_synced_library_copies;
+pub use variants::{
+    install_variant_settings, load_variant_settings, save_variant_settings, CursorVariantSettings,
+};
 
 /// ANI preview data - frames + timing for frontend animation
 /// This is more efficient than GIF conversion:
@@ -39,6 +68,12 @@ pub struct LibraryPackItem {
     pub display_name: String,
     pub file_name: String,
     pub file_path: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_url: Option<String>,
 }
 
 #[derive(ts_rs::TS, Serialize, Deserialize, Clone, Debug)]
@@ -47,11 +82,66 @@ pub struct LibraryPackMetadata {
     pub mode: CustomizationMode,
     pub archive_path: String,
     pub items: Vec<LibraryPackItem>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_sorted_previews"
+    )]
     pub previews: Option<std::collections::HashMap<String, String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[ts(skip)]
     pub previews_version: Option<u32>,
+    /// 3×3 grid of the key role previews as a PNG data URL, for the library grid.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sprite: Option<String>,
+    /// The user's own 1-5 star rating, kept only on this machine.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rating: Option<u8>,
+    /// Version label of the newest changelog entry when the pack was installed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub installed_version: Option<String>,
+    /// Events the pack bundles a sound for.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sounds: Vec<super::pack_manifest::PackSoundEvent>,
+    /// Whether the user turned this pack's sounds on. Off until they do.
+    #[serde(default)]
+    pub sounds_enabled: bool,
+    /// Download link the pack was imported from, checked for newer archives.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub update_url: Option<String>,
+    /// Hash of the archive last downloaded from `update_url`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archive_hash: Option<String>,
+    /// `ETag` the server sent with the installed archive, so checks can ask whether it changed
+    /// instead of downloading it again.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(skip)]
+    pub update_etag: Option<String>,
+    /// `Last-Modified` the server sent with the installed archive.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(skip)]
+    pub update_last_modified: Option<String>,
+    /// Whether the last check found a different archive at `update_url`.
+    #[serde(default)]
+    pub update_available: bool,
+}
+
+/// Write previews in key order so an unchanged library serializes to identical bytes.
+fn serialize_sorted_previews<S: serde::Serializer>(
+    previews: &Option<std::collections::HashMap<String, String>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    previews
+        .as_ref()
+        .map(|map| map.iter().collect::<std::collections::BTreeMap<_, _>>())
+        .serialize(serializer)
+}
+
+/// A pre-scaled copy of a library cursor generated for one of the large apply sizes.
+#[derive(ts_rs::TS, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct LibraryCursorVariant {
+    pub size: u32,
+    pub file_path: String,
 }
 
 #[derive(ts_rs::TS, Serialize, Deserialize, Clone, Debug)]
@@ -67,11 +157,98 @@ pub struct LibraryCursor {
     pub is_pack: bool,
     #[serde(default)]
     pub pack_metadata: Option<LibraryPackMetadata>,
+    /// Pixel size of the image stored in the cursor file, if it could be read.
+    #[serde(default)]
+    pub native_size: Option<u32>,
+    #[serde(default)]
+    pub scaled_variants: Vec<LibraryCursorVariant>,
+    /// Free-form notes from the user or pack author.
+    #[serde(default)]
+    pub notes: Option<String>,
+    /// Who made the cursor, preserved when packs are shared.
+    #[serde(default)]
+    pub author: Option<String>,
+    /// Where the cursor was downloaded from.
+    #[serde(default)]
+    pub source_url: Option<String>,
+    /// Image the cursor was converted from, so it can be reconverted when the image changes.
+    #[serde(default)]
+    pub source_link: Option<CursorSourceLink>,
 }
 
+/// Sent after importing an animated cursor drawn much larger than the user's cursor size, so
+/// the frontend can offer [`downscale_library_ani`].
+#[derive(ts_rs::TS, Serialize, Clone, Debug, PartialEq, Eq)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct OversizedAniPayload {
+    pub cursor_id: String,
+    pub name: String,
+    pub native_size: u32,
+    /// The user's cursor size, the size the downscaled copy would get
+    pub target_size: u32,
+}
+
+/// Source image and conversion settings of a converted library cursor. While `hot_reload`
+/// is on, saving the source reconverts the cursor and re-applies it if it is in use.
+#[derive(ts_rs::TS, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct CursorSourceLink {
+    pub source_path: String,
+    pub size: u32,
+    pub click_point_x: u16,
+    pub click_point_y: u16,
+    pub scale: f32,
+    pub offset_x: i32,
+    pub offset_y: i32,
+    #[serde(default = "default_hot_reload")]
+    pub hot_reload: bool,
+}
+
+fn default_hot_reload() -> bool {
+    true
+}
+
+/// Attribution fields for `update_cursor_metadata`. Omitted fields are left unchanged;
+/// an empty string clears the field.
+#[derive(ts_rs::TS, Serialize, Deserialize, Clone, Debug, Default)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct CursorMetadataUpdate {
+    #[serde(default)]
+    pub notes: Option<String>,
+    #[serde(default)]
+    pub author: Option<String>,
+    #[serde(default)]
+    pub source_url: Option<String>,
+}
+
+impl CursorMetadataUpdate {
+    /// Apply the provided fields to `cursor`.
+    pub fn apply_to(&self, cursor: &mut LibraryCursor) {
+        fn merge(target: &mut Option<String>, value: Option<&String>) {
+            if let Some(value) = value {
+                let trimmed = value.trim();
+                *target = (!trimmed.is_empty()).then(|| trimmed.to_string());
+            }
+        }
+        merge(&mut cursor.notes, self.notes.as_ref());
+        merge(&mut cursor.author, self.author.as_ref());
+        merge(&mut cursor.source_url, self.source_url.as_ref());
+    }
+}
+
+/// `library.json` format version written by this build.
+pub const LIBRARY_SCHEMA_VERSION: u32 = 3;
+
 #[derive(Serialize, Deserialize, Default, Debug)]
 pub struct LibraryData {
+    /// Format version; files from before versioning read as 0. Always saved as
+    /// [`LIBRARY_SCHEMA_VERSION`].
+    #[serde(default)]
+    pub schema_version: u32,
     pub cursors: Vec<LibraryCursor>,
+    /// User-defined folders, added in format 3
+    #[serde(default)]
+    pub collections: Vec<LibraryCollection>,
 }
 
 /// Load the library data from disk
@@ -79,16 +256,117 @@ pub fn load_library<R: Runtime>(app: &AppHandle<R>) -> Result<LibraryData, Strin
     store::load_library(app)
 }
 
+/// Parse the contents of `library.json` without touching the disk
+pub fn parse_library(contents: &str) -> Result<LibraryData, String> {
+    store::parse_library(contents)
+}
+
+/// Lock the library against other writers for a load-modify-save cycle
+pub fn lock_library<R: Runtime>(app: &AppHandle<R>) -> Result<LibraryLock, LibraryLockError> {
+    store::lock_library(app)
+}
+
 /// Save the library data to disk
 pub fn save_library<R: Runtime>(app: &AppHandle<R>, library: &LibraryData) -> Result<(), String> {
     store::save_library(app, library)
 }
 
+/// Rewrite every file path stored in the library with `map`, returning how many changed.
+pub(crate) fn rewrite_library_paths<R: Runtime>(
+    app: &AppHandle<R>,
+    map: impl Fn(&str) -> String,
+) -> Result<usize, String> {
+    let _lock = lock_library(app)?;
+    let mut library = load_library(app)?;
+    let changed = std::cell::Cell::new(0);
+    store::map_cursor_paths(&mut library.cursors, |path| {
+        let mapped = map(path);
+        if mapped != path {
+            changed.set(changed.get() + 1);
+        }
+        mapped
+    });
+    if changed.get() > 0 {
+        save_library(app, &library)?;
+    }
+    Ok(changed.get())
+}
+
+/// The saved library, if it has been created. Load it once for several [`find_cursor_by_path`]
+/// lookups.
+pub(crate) fn load_library_if_present<R: Runtime>(app: &AppHandle<R>) -> Option<LibraryData> {
+    store::load_library_if_present(app).ok().flatten()
+}
+
+/// Find the entry in `library` backed by `file_path`.
+pub(crate) fn find_cursor_by_path<'a>(
+    library: &'a LibraryData,
+    file_path: &str,
+) -> Option<&'a LibraryCursor> {
+    library
+        .cursors
+        .iter()
+        .find(|c| crate::paths::canonical_eq(Path::new(&c.file_path), Path::new(file_path)))
+}
+
+/// Find the library entry backed by `file_path`, if the library has been created.
+pub(crate) fn find_library_cursor_by_path<R: Runtime>(
+    app: &AppHandle<R>,
+    file_path: &str,
+) -> Option<LibraryCursor> {
+    find_cursor_by_path(&load_library_if_present(app)?, file_path).cloned()
+}
+
 /// Get all cursors in the library
 #[tauri::command]
 pub fn get_library_cursors<R: Runtime>(app: AppHandle<R>) -> Result<Vec<LibraryCursor>, String> {
-    let library = load_library(&app)?;
-    Ok(library.cursors)
+    Ok(load_library_with_sprites(&app)?.cursors)
+}
+
+fn load_library_with_sprites<R: Runtime>(app: &AppHandle<R>) -> Result<LibraryData, String> {
+    let mut library = load_library(app)?;
+    if super::pack_sprite::ensure_library_sprites(&mut library) {
+        if let Err(e) = cache_library_sprites(app) {
+            cc_warn!("[CursorChanger] Failed to cache pack preview sprites: {}", e);
+        }
+    }
+    Ok(library)
+}
+
+/// Reload under the lock before caching, so a plain read never overwrites another writer
+fn cache_library_sprites<R: Runtime>(app: &AppHandle<R>) -> Result<(), String> {
+    let _lock = lock_library(app)?;
+    let mut library = load_library(app)?;
+    if super::pack_sprite::ensure_library_sprites(&mut library) {
+        save_library(app, &library)?;
+    }
+    Ok(())
+}
+
+/// Get one page of the library for virtualized lists. `limit` is capped at
+/// `MAX_PAGE_LIMIT`; `sort` defaults to the user's custom order.
+#[tauri::command]
+pub fn get_library_page<R: Runtime>(
+    app: AppHandle<R>,
+    offset: u32,
+    limit: u32,
+    sort: Option<LibrarySort>,
+    filter: Option<LibraryFilter>,
+) -> Result<LibraryPage, String> {
+    let library = load_library_with_sprites(&app)?;
+    let filter = filter.unwrap_or_default();
+    let cursors = match filter.collection_id.as_deref() {
+        Some(id) => collections::collection_members(library, id)
+            .ok_or_else(|| format!("Collection with id {} not found", id))?,
+        None => library.cursors,
+    };
+    Ok(paging::page(
+        cursors,
+        offset,
+        limit,
+        sort.unwrap_or_default(),
+        &filter,
+    ))
 }
 
 /// Add a cursor to the library
@@ -100,6 +378,7 @@ pub fn add_cursor_to_library<R: Runtime>(
     click_point_x: u16,
     click_point_y: u16,
 ) -> Result<LibraryCursor, String> {
+    let _lock = lock_library(&app)?;
     let mut library = load_library(&app)?;
 
     // Generate unique ID using system time
@@ -108,7 +387,7 @@ pub fn add_cursor_to_library<R: Runtime>(
     // Get current timestamp as ISO-8601 string
     let created_at = crate::utils::library_meta::now_iso8601_utc();
 
-    let cursor = LibraryCursor {
+    let mut cursor = LibraryCursor {
         id: id.clone(),
         name,
         file_path,
@@ -117,14 +396,127 @@ pub fn add_cursor_to_library<R: Runtime>(
         created_at,
         is_pack: false,
         pack_metadata: None,
+        native_size: None,
+        scaled_variants: Vec::new(),
+        notes: None,
+        author: None,
+        source_url: None,
+        source_link: None,
     };
+    variants::populate_size_metadata(&mut cursor);
+    if is_ani_path(&cursor.file_path) {
+        if let Ok(data) = fs::read(&cursor.file_path) {
+            cursor.author = read_ani_info(&data).author;
+        }
+    }
 
     library.cursors.push(cursor.clone());
     save_library(&app, &library)?;
 
+    if let Some(payload) = oversized_ani(&cursor, preferred_cursor_size(&app)) {
+        let _ = app.emit(crate::events::LIBRARY_OVERSIZED_ANI, payload);
+    }
+
     Ok(cursor)
 }
 
+fn preferred_cursor_size<R: Runtime>(app: &AppHandle<R>) -> Option<u32> {
+    let state = app.try_state::<crate::state::AppState>()?;
+    let size = state.prefs.read().ok()?.cursor_size;
+    u32::try_from(size).ok()
+}
+
+fn is_ani_path(path: &str) -> bool {
+    Path::new(path)
+        .extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|e| e.eq_ignore_ascii_case("ani"))
+}
+
+/// The downscale offer for a freshly imported cursor, if it is an oversized .ani.
+fn oversized_ani(cursor: &LibraryCursor, target_size: Option<u32>) -> Option<OversizedAniPayload> {
+    let native_size = cursor.native_size?;
+    let target_size = target_size?;
+    if cursor.is_pack
+        || !is_ani_path(&cursor.file_path)
+        || native_size < ani::OVERSIZED_ANI_MIN_SIZE
+        || native_size <= target_size
+    {
+        return None;
+    }
+    Some(OversizedAniPayload {
+        cursor_id: cursor.id.clone(),
+        name: cursor.name.clone(),
+        native_size,
+        target_size,
+    })
+}
+
+/// Replace an animated library cursor with a copy whose frames are resized to `size` px
+/// (the user's cursor size by default). The old file is deleted if it lives in the library.
+#[tauri::command]
+pub fn downscale_library_ani<R: Runtime>(
+    app: AppHandle<R>,
+    id: String,
+    size: Option<u32>,
+) -> Result<LibraryCursor, String> {
+    let target = match size {
+        Some(size) => size,
+        None => preferred_cursor_size(&app)
+            .ok_or_else(|| "Failed to read the cursor size setting".to_string())?,
+    };
+
+    let _lock = lock_library(&app)?;
+    let mut library = load_library(&app)?;
+    let idx = library
+        .cursors
+        .iter()
+        .position(|c| c.id == id)
+        .ok_or_else(|| format!("Cursor with id {} not found", id))?;
+
+    let old_path = PathBuf::from(&library.cursors[idx].file_path);
+    if library.cursors[idx].is_pack || !is_ani_path(&library.cursors[idx].file_path) {
+        return Err("Only animated (.ani) cursors can be downscaled".to_string());
+    }
+    let data = fs::read(&old_path).map_err(|e| format!("Failed to read cursor file: {}", e))?;
+    let resized = ani::resize_ani(&data, target)?;
+
+    let dir = crate::paths::cursors_dir()?;
+    let stem = old_path
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("cursor");
+    let new_path = generate_unique_path(&dir, &format!("{}-{}px", stem, target), "ani");
+    fs::write(&new_path, &resized).map_err(|e| format!("Failed to write cursor file: {}", e))?;
+
+    let (click_point_x, click_point_y) = ani::extract_ani_first_frame(&resized)
+        .map(|frame| crate::utils::cursor_parser::parse_cur_click_point(&frame))
+        .unwrap_or((0, 0));
+    {
+        let cursor = &mut library.cursors[idx];
+        cursor.file_path = new_path.to_string_lossy().to_string();
+        cursor.click_point_x = click_point_x;
+        cursor.click_point_y = click_point_y;
+        variants::populate_size_metadata(cursor);
+    }
+    save_library(&app, &library)?;
+
+    if let Err(e) = try_delete_library_file(&old_path) {
+        cc_warn!(
+            "[CursorChanger] Failed to delete {} after downscaling: {}",
+            old_path.display(),
+            e
+        );
+    }
+    crate::commands::customization::cursor_apply_service::sync_active_cursor_update(
+        &app,
+        &old_path.to_string_lossy(),
+        &new_path.to_string_lossy(),
+    );
+
+    Ok(library.cursors[idx].clone())
+}
+
 /// Safely delete a library file if it's within the allowed library folders.
 /// Returns Ok(true) if file was deleted, Ok(false) if skipped (not in folder or doesn't exist),
 /// Err if deletion failed.
@@ -134,10 +526,10 @@ pub fn try_delete_library_file(file_path: &std::path::Path) -> Result<bool, std:
 
     let in_cursors = cursors_folder
         .as_ref()
-        .map_or(false, |f| file_path.starts_with(f));
+        .map_or(false, |f| crate::paths::is_within(file_path, f));
     let in_packs = packs_folder
         .as_ref()
-        .map_or(false, |f| file_path.starts_with(f));
+        .map_or(false, |f| crate::paths::is_within(file_path, f));
 
     if !in_cursors && !in_packs {
         return Ok(false); // Not in our folders, skip
@@ -154,6 +546,7 @@ pub fn try_delete_library_file(file_path: &std::path::Path) -> Result<bool, std:
 /// Remove a cursor from the library and delete the associated .CUR file
 #[tauri::command]
 pub fn remove_cursor_from_library<R: Runtime>(app: AppHandle<R>, id: String) -> Result<(), String> {
+    let _lock = lock_library(&app)?;
     let mut library = load_library(&app)?;
 
     // Find the cursor to get its file path before removing
@@ -165,6 +558,8 @@ pub fn remove_cursor_from_library<R: Runtime>(app: AppHandle<R>, id: String) ->
 
     // Delete the library file if it exists in our cursors folder
     if let Some(cursor) = cursor_to_remove {
+        variants::remove_scaled_variants(&cursor.id);
+
         let file_path = std::path::Path::new(&cursor.file_path);
         match try_delete_library_file(file_path) {
             Ok(true) => cc_debug!("[CursorChanger] Deleted cursor file: {}", cursor.file_path),
@@ -197,7 +592,7 @@ pub fn remove_cursor_from_library<R: Runtime>(app: AppHandle<R>, id: String) ->
             // Try to delete the containing folder if it's empty and within cursor-packs
             if let Some(parent_folder) = pack_file_path.parent() {
                 if let Ok(cursor_packs_root) = crate::paths::cursor_packs_dir() {
-                    if parent_folder.starts_with(&cursor_packs_root) {
+                    if crate::paths::is_within(parent_folder, &cursor_packs_root) {
                         // Only delete if the folder is empty to avoid affecting other packs
                         match std::fs::read_dir(parent_folder) {
                             Ok(mut entries) => {
@@ -222,6 +617,20 @@ pub fn remove_cursor_from_library<R: Runtime>(app: AppHandle<R>, id: String) ->
                 }
             }
             
+            // Remove files mounted for applying this pack.
+            if let Ok(mounts_root) = crate::paths::pack_mounts_dir() {
+                let mount_dir = mounts_root.join(&cursor.id);
+                if mount_dir.exists() {
+                    if let Err(e) = std::fs::remove_dir_all(&mount_dir) {
+                        cc_warn!(
+                            "[CursorChanger] Failed to delete pack mount {}: {}",
+                            mount_dir.to_string_lossy(),
+                            e
+                        );
+                    }
+                }
+            }
+
             // Also remove any extracted cache under `cursor-packs/<id>`.
             if let Ok(cache_root) = crate::paths::pack_cache_dir() {
                 let pack_dir = cache_root.join(&cursor.id);
@@ -257,6 +666,7 @@ pub fn rename_cursor_in_library<R: Runtime>(
     id: String,
     new_name: String,
 ) -> Result<(), String> {
+    let _lock = lock_library(&app)?;
     let mut library = load_library(&app)?;
 
     let cursor = library
@@ -372,6 +782,7 @@ pub fn update_cursor_in_library<R: Runtime>(
     click_point_x: u16,
     click_point_y: u16,
 ) -> Result<LibraryCursor, String> {
+    let _lock = lock_library(&app)?;
     let mut library = load_library(&app)?;
 
     // Find index so we can mutate and then drop the mutable borrow before saving
@@ -382,6 +793,7 @@ pub fn update_cursor_in_library<R: Runtime>(
             cursor.file_path = file_path.clone();
             cursor.click_point_x = click_point_x;
             cursor.click_point_y = click_point_y;
+            variants::populate_size_metadata(cursor);
         }
 
         // mutable borrow has ended; safe to save and then clone the updated entry
@@ -402,10 +814,33 @@ pub fn update_cursor_in_library<R: Runtime>(
     }
 }
 
+/// Update the notes and attribution fields of a library cursor
+#[tauri::command]
+pub fn update_cursor_metadata<R: Runtime>(
+    app: AppHandle<R>,
+    id: String,
+    fields: CursorMetadataUpdate,
+) -> Result<LibraryCursor, String> {
+    let _lock = lock_library(&app)?;
+    let mut library = load_library(&app)?;
+
+    let cursor = library
+        .cursors
+        .iter_mut()
+        .find(|c| c.id == id)
+        .ok_or_else(|| format!("Cursor with id {} not found", id))?;
+    fields.apply_to(cursor);
+    let updated = cursor.clone();
+
+    save_library(&app, &library)?;
+    Ok(updated)
+}
+
 /// Reorder the library cursors. `order` is an array of cursor IDs in the
 /// desired order. Any IDs not included will be appended in their original order.
 #[tauri::command]
 pub fn reorder_library_cursors<R: Runtime>(app: AppHandle<R>, order: Vec<String>) -> Result<(), String> {
+    let _lock = lock_library(&app)?;
     let mut library = load_library(&app)?;
 
     // Keep the original order so any missing IDs can be appended
@@ -433,20 +868,59 @@ pub fn reorder_library_cursors<R: Runtime>(app: AppHandle<R>, order: Vec<String>
     Ok(())
 }
 
-/// Get a library cursor's data as a data URL for preview
+/// Get a URL the webview loads a library cursor's preview from, served out of the preview
+/// cache by the [preview protocol](crate::preview_protocol)
 #[tauri::command]
 pub fn get_library_cursor_preview(file_path: String) -> Result<String, String> {
+    preview::get_library_cursor_preview_image(&file_path)
+        .map(|image| crate::preview_protocol::publish(&image))
+}
+
+/// [`get_library_cursor_preview`] as a self-contained data URL
+pub(crate) fn get_library_cursor_preview_data_url(file_path: String) -> Result<String, String> {
     preview::get_library_cursor_preview(file_path)
 }
 
 /// Convert raw cursor bytes (e.g., extracted from a pack) into a data URL preview.
+/// The bytes are decoded in a worker process when decode sandboxing is on.
 pub fn get_cursor_preview_from_bytes(
     bytes: &[u8],
     file_name: Option<&str>,
+) -> Result<String, String> {
+    if crate::decode_worker::is_enabled() {
+        return crate::decode_worker::preview(bytes, file_name);
+    }
+    cursor_preview_in_process(bytes, file_name)
+}
+
+/// [`get_cursor_preview_from_bytes`] without sandboxing; what the decode worker runs.
+pub(crate) fn cursor_preview_in_process(
+    bytes: &[u8],
+    file_name: Option<&str>,
 ) -> Result<String, String> {
     preview::get_library_cursor_preview_from_bytes(bytes, file_name)
 }
 
+/// Title and author embedded in an .ani, used to name imported cursors. The file is parsed
+/// in a worker process when decode sandboxing is on; one the worker fails on has neither.
+pub(crate) fn read_ani_info(data: &[u8]) -> AniInfo {
+    if crate::decode_worker::is_enabled() {
+        return crate::decode_worker::ani_info(data).unwrap_or_else(|e| {
+            cc_warn!(
+                "[CursorChanger] Could not read .ani title and author: {}",
+                e
+            );
+            AniInfo::default()
+        });
+    }
+    ani_info_in_process(data)
+}
+
+/// [`read_ani_info`] without sandboxing; what the decode worker runs.
+pub(crate) fn ani_info_in_process(data: &[u8]) -> AniInfo {
+    ani::read_ani_info(data)
+}
+
 /// Get ANI preview data as frames + timing for efficient frontend animation.
 /// This is more optimized than GIF conversion:
 /// - No server-side GIF encoding overhead
@@ -460,7 +934,57 @@ pub fn get_cursor_preview_from_bytes(
 /// - Async-compatible via spawn_blocking pattern
 #[tauri::command]
 pub async fn get_ani_preview_data(file_path: String) -> Result<AniPreviewData, String> {
-    ani::get_ani_preview_data(file_path).await
+    ani::get_ani_preview_data(file_path)
+        .await
+        .map(crate::reduced_motion::effective_ani_preview)
+}
+
+/// Export an animated library cursor as a looping GIF for sharing on the web.
+/// Frame order and timing follow the ANI `seq `/`rate` chunks; `scale` defaults to 1.0.
+#[tauri::command]
+pub async fn export_ani_as_gif<R: Runtime>(
+    app: AppHandle<R>,
+    id: String,
+    path: String,
+    scale: Option<f32>,
+) -> Result<String, String> {
+    export_ani_cursor(&app, &id, path, scale, ani::AnimationFormat::Gif).await
+}
+
+/// Export an animated library cursor as a looping APNG, keeping full alpha and exact timing.
+/// Frame order and timing follow the ANI `seq `/`rate` chunks; `scale` defaults to 1.0.
+#[tauri::command]
+pub async fn export_ani_as_apng<R: Runtime>(
+    app: AppHandle<R>,
+    id: String,
+    path: String,
+    scale: Option<f32>,
+) -> Result<String, String> {
+    export_ani_cursor(&app, &id, path, scale, ani::AnimationFormat::Apng).await
+}
+
+async fn export_ani_cursor<R: Runtime>(
+    app: &AppHandle<R>,
+    id: &str,
+    path: String,
+    scale: Option<f32>,
+    format: ani::AnimationFormat,
+) -> Result<String, String> {
+    let library = load_library(app)?;
+    let cursor = library
+        .cursors
+        .iter()
+        .find(|c| c.id == id)
+        .ok_or_else(|| format!("Cursor with id {} not found", id))?;
+
+    if cursor.is_pack || !is_ani_path(&cursor.file_path) {
+        return Err(format!(
+            "Only animated (.ani) cursors can be exported as {}",
+            format.name()
+        ));
+    }
+
+    ani::export_ani(cursor.file_path.clone(), path, scale.unwrap_or(1.0), format).await
 }
 
 /// Export all library cursors into a single ZIP archive and prompt user to save it.
@@ -489,7 +1013,10 @@ pub fn cleanup_orphaned_pack_folders<R: Runtime>(app: &AppHandle<R>) -> Result<(
                     let path = entry.path();
 
                     if path.is_dir() {
-                        if !valid_pack_dirs.contains(&path) {
+                        if !valid_pack_dirs
+                            .iter()
+                            .any(|dir| crate::paths::canonical_eq(dir, &path))
+                        {
                             match std::fs::remove_dir_all(&path) {
                                 Ok(()) => cc_debug!(
                                     "[CursorChanger] Cleaned up orphaned pack folder: {}",
@@ -574,3 +1101,9 @@ pub fn reset_library<R: Runtime>(app: AppHandle<R>) -> Result<(), String> {
     cc_debug!("[CursorChanger] Library reset to defaults");
     Ok(())
 }
+
+/// Retry default cursors and packs that failed to import, without resetting the library
+#[tauri::command]
+pub fn repair_default_library<R: Runtime>(app: AppHandle<R>) -> Result<DefaultsInitReport, String> {
+    store::repair_default_library(&app)
+}
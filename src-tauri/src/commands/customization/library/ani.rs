@@ -1,7 +1,9 @@
 
+mod export;
 mod parser;
 mod preview;
 mod render;
+mod resize;
 
 use std::fmt;
 
@@ -32,13 +34,47 @@ pub(super) async fn get_ani_preview_data(file_path: String) -> Result<AniPreview
     preview::get_ani_preview_data(file_path).await
 }
 
+pub(super) async fn export_ani(
+    source_path: String,
+    output_path: String,
+    scale: f32,
+    format: AnimationFormat,
+) -> Result<String, String> {
+    export::export_ani(source_path, output_path, scale, format).await
+}
+
+pub(super) use export::AnimationFormat;
 pub(super) use parser::AniData;
+pub(crate) use parser::AniInfo;
 
 #[allow(dead_code)]
 pub(super) fn parse_ani_file(data: &[u8]) -> Option<AniData> {
     parser::parse_ani_file(data).ok()
 }
 
+/// Title and author embedded in an .ani, parsed in this process.
+pub(crate) fn read_ani_info(data: &[u8]) -> AniInfo {
+    parser::parse_ani_info(data)
+}
+
+/// First frame of an .ani and how many frames it holds, or `None` when it cannot be parsed.
+pub(crate) fn read_ani_frame_summary(data: &[u8]) -> Option<(Vec<u8>, usize)> {
+    let ani = parser::parse_ani_file(data).ok()?;
+    let frame_count = ani.frames.len();
+    ani.frames
+        .into_iter()
+        .next()
+        .map(|first| (first, frame_count))
+}
+
+/// Animated cursors this large are offered a downscaled copy when imported.
+pub(super) const OVERSIZED_ANI_MIN_SIZE: u32 = 128;
+
+/// Re-render an .ani with every frame shrunk to `target` px.
+pub(super) fn resize_ani(data: &[u8], target: u32) -> Result<Vec<u8>, String> {
+    resize::resize_ani(data, target)
+}
+
 pub(super) fn extract_ani_first_frame(data: &[u8]) -> Option<Vec<u8>> {
     parser::extract_ani_first_frame(data)
 }
@@ -0,0 +1,271 @@
+use std::fs;
+use std::path::Path;
+
+use image::codecs::gif::{GifEncoder, Repeat};
+use image::imageops::FilterType;
+use image::{Delay, Frame, ImageBuffer, Rgba, RgbaImage};
+
+use super::AniError;
+
+pub(super) const MIN_EXPORT_SCALE: f32 = 0.25;
+pub(super) const MAX_EXPORT_SCALE: f32 = 8.0;
+
+/// Animated image formats an ANI cursor can be exported to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(in super::super) enum AnimationFormat {
+    Gif,
+    /// Animated PNG: full alpha and millisecond timing, unlike GIF
+    Apng,
+}
+
+impl AnimationFormat {
+    pub(in super::super) fn name(self) -> &'static str {
+        match self {
+            Self::Gif => "GIF",
+            Self::Apng => "APNG",
+        }
+    }
+}
+
+pub(super) async fn export_ani(
+    source_path: String,
+    output_path: String,
+    scale: f32,
+    format: AnimationFormat,
+) -> Result<String, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        export_ani_sync(&source_path, &output_path, scale, format)
+    })
+    .await
+    .map_err(|e| format!("Task join error: {}", e))?
+}
+
+fn export_ani_sync(
+    source_path: &str,
+    output_path: &str,
+    scale: f32,
+    format: AnimationFormat,
+) -> Result<String, String> {
+    let bytes = fs::read(source_path).map_err(|e| format!("Failed to read file: {}", e))?;
+    let encoded = match format {
+        AnimationFormat::Gif => encode_ani_as_gif(&bytes, scale)?,
+        AnimationFormat::Apng => encode_ani_as_apng(&bytes, scale)?,
+    };
+
+    if let Some(parent) = Path::new(output_path).parent() {
+        if !parent.as_os_str().is_empty() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create export directory: {}", e))?;
+        }
+    }
+
+    fs::write(output_path, encoded)
+        .map_err(|e| format!("Failed to write {}: {}", format.name(), e))?;
+    Ok(output_path.to_string())
+}
+
+/// Decode the playback steps of raw ANI bytes into scaled frames and their durations in ms.
+fn playback_frames(bytes: &[u8], scale: f32) -> Result<Vec<(RgbaImage, u32)>, String> {
+    if !scale.is_finite() || !(MIN_EXPORT_SCALE..=MAX_EXPORT_SCALE).contains(&scale) {
+        return Err(format!(
+            "Scale must be between {} and {}",
+            MIN_EXPORT_SCALE, MAX_EXPORT_SCALE
+        ));
+    }
+
+    let ani = super::parser::parse_ani_file(bytes)
+        .map_err(|e| format!("Failed to parse ANI file: {}", e))?;
+    let steps = super::parser::playback_steps(&ani);
+
+    let mut decoded: Vec<Option<ImageBuffer<Rgba<u8>, Vec<u8>>>> = vec![None; ani.frames.len()];
+    let mut frames = Vec::with_capacity(steps.len());
+
+    for &(frame_idx, delay_ms) in &steps {
+        let Some(frame_data) = ani.frames.get(frame_idx) else {
+            continue;
+        };
+
+        if decoded[frame_idx].is_none() {
+            let rgba = super::render::frame_to_rgba(frame_data).map_err(String::from)?;
+            decoded[frame_idx] = Some(scale_frame(rgba, scale));
+        }
+
+        if let Some(buffer) = decoded[frame_idx].clone() {
+            frames.push((buffer, delay_ms));
+        }
+    }
+
+    if frames.is_empty() {
+        return Err(AniError::NoFrames.into());
+    }
+    Ok(frames)
+}
+
+/// Encode raw ANI bytes as a looping GIF, preserving the per-step timing of the source.
+pub(super) fn encode_ani_as_gif(bytes: &[u8], scale: f32) -> Result<Vec<u8>, String> {
+    let steps = playback_frames(bytes, scale)?;
+    let delays = gif_delays_cs(&steps.iter().map(|&(_, ms)| ms).collect::<Vec<_>>());
+    let frames = steps
+        .into_iter()
+        .zip(delays)
+        .map(|((buffer, _), delay_cs)| {
+            let delay = Delay::from_numer_denom_ms(delay_cs * 10, 1);
+            Frame::from_parts(buffer, 0, 0, delay)
+        });
+
+    let mut out = Vec::new();
+    {
+        let mut encoder = GifEncoder::new_with_speed(&mut out, 10);
+        encoder
+            .set_repeat(Repeat::Infinite)
+            .map_err(|e| format!("Failed to configure GIF encoder: {}", e))?;
+        encoder
+            .encode_frames(frames)
+            .map_err(|e| format!("Failed to encode GIF: {}", e))?;
+    }
+
+    Ok(out)
+}
+
+/// Encode raw ANI bytes as a looping APNG with the exact per-step timing of the source.
+pub(super) fn encode_ani_as_apng(bytes: &[u8], scale: f32) -> Result<Vec<u8>, String> {
+    let steps = playback_frames(bytes, scale)?;
+    // Every frame fills the canvas, sized to the largest frame
+    let width = steps
+        .iter()
+        .map(|(frame, _)| frame.width())
+        .max()
+        .unwrap_or(1);
+    let height = steps
+        .iter()
+        .map(|(frame, _)| frame.height())
+        .max()
+        .unwrap_or(1);
+    let num_frames =
+        u32::try_from(steps.len()).map_err(|_| "Too many frames for APNG".to_string())?;
+    let encode_err = |e: png::EncodingError| format!("Failed to encode APNG: {}", e);
+
+    let mut out = Vec::new();
+    {
+        let mut encoder = png::Encoder::new(&mut out, width, height);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+        // Zero plays loops forever
+        encoder.set_animated(num_frames, 0).map_err(encode_err)?;
+        let mut writer = encoder.write_header().map_err(encode_err)?;
+        for (frame, delay_ms) in steps {
+            let frame = if frame.dimensions() == (width, height) {
+                frame
+            } else {
+                let mut canvas = ImageBuffer::new(width, height);
+                image::imageops::replace(&mut canvas, &frame, 0, 0);
+                canvas
+            };
+            let (numerator, denominator) = apng_delay(delay_ms);
+            writer
+                .set_frame_delay(numerator, denominator)
+                .map_err(encode_err)?;
+            writer
+                .write_image_data(frame.as_raw())
+                .map_err(encode_err)?;
+        }
+        writer.finish().map_err(encode_err)?;
+    }
+
+    Ok(out)
+}
+
+/// APNG delays are a `u16` fraction of a second. Steps too long for milliseconds fall back
+/// to centiseconds.
+fn apng_delay(delay_ms: u32) -> (u16, u16) {
+    match u16::try_from(delay_ms) {
+        Ok(ms) => (ms, 1000),
+        Err(_) => (u16::try_from(delay_ms / 10).unwrap_or(u16::MAX), 100),
+    }
+}
+
+/// GIF stores delays in centiseconds. Round against the cumulative timeline instead of per
+/// frame so the total duration stays exact even when individual steps are not multiples of 10ms.
+fn gif_delays_cs(delays_ms: &[u32]) -> Vec<u32> {
+    let mut elapsed_ms: u64 = 0;
+    let mut emitted_cs: u64 = 0;
+
+    delays_ms
+        .iter()
+        .map(|&ms| {
+            elapsed_ms += u64::from(ms);
+            let target_cs = (elapsed_ms + 5) / 10;
+            let step = target_cs.saturating_sub(emitted_cs).max(1);
+            emitted_cs += step;
+            step as u32
+        })
+        .collect()
+}
+
+fn scale_frame(
+    img: ImageBuffer<Rgba<u8>, Vec<u8>>,
+    scale: f32,
+) -> ImageBuffer<Rgba<u8>, Vec<u8>> {
+    if (scale - 1.0).abs() < f32::EPSILON {
+        return img;
+    }
+
+    let width = ((img.width() as f32) * scale).round().max(1.0) as u32;
+    let height = ((img.height() as f32) * scale).round().max(1.0) as u32;
+
+    // Integer upscales keep pixel-art cursors crisp; everything else gets a smooth filter.
+    let filter = if scale >= 1.0 && scale.fract() == 0.0 {
+        FilterType::Nearest
+    } else {
+        FilterType::Lanczos3
+    };
+
+    image::imageops::resize(&img, width, height, filter)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gif_delays_preserve_total_duration() {
+        // 17ms is the shortest ANI step (1 jiffy); naive per-frame rounding would drift.
+        let delays = gif_delays_cs(&[17, 17, 17, 17, 17, 17]);
+        assert_eq!(delays.iter().sum::<u32>(), 10);
+        assert!(delays.iter().all(|&d| d >= 1));
+    }
+
+    #[test]
+    fn encode_rejects_out_of_range_scale() {
+        assert!(encode_ani_as_gif(&[], 0.0).is_err());
+        assert!(encode_ani_as_gif(&[], 16.0).is_err());
+        assert!(encode_ani_as_gif(&[], f32::NAN).is_err());
+    }
+
+    #[test]
+    fn apng_has_one_frame_per_playback_step() {
+        let data = cursor_convert::fixtures::ani(3, 32);
+        let apng = encode_ani_as_apng(&data, 2.0).expect("encode APNG");
+
+        let decoder = png::Decoder::new(std::io::Cursor::new(apng));
+        let reader = decoder.read_info().expect("read APNG");
+        let info = reader.info();
+        assert_eq!((info.width, info.height), (64, 64));
+        let animation = info.animation_control.expect("animated");
+        assert_eq!(animation.num_frames, 3);
+        assert_eq!(animation.num_plays, 0);
+    }
+
+    #[test]
+    fn apng_delay_keeps_milliseconds_when_they_fit() {
+        assert_eq!(apng_delay(17), (17, 1000));
+        assert_eq!(apng_delay(70_000), (7_000, 100));
+    }
+
+    #[test]
+    fn scale_frame_resizes_dimensions() {
+        let img = ImageBuffer::from_pixel(32, 32, Rgba([255, 0, 0, 255]));
+        let scaled = scale_frame(img, 2.0);
+        assert_eq!(scaled.dimensions(), (64, 64));
+    }
+}
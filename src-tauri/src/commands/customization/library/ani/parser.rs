@@ -123,6 +123,103 @@ pub(super) fn parse_ani_file(data: &[u8]) -> Result<AniData, AniError> {
     })
 }
 
+/// Resolve the playback order of an ANI as `(frame_index, delay_ms)` pairs.
+///
+/// Honors the optional `seq ` chunk for ordering and the per-step `rate` chunk for timing,
+/// falling back to the `anih` default rate. Rates are expressed in jiffies (1/60 s).
+pub(super) fn playback_steps(ani: &AniData) -> Vec<(usize, u32)> {
+    let frame_indices: Vec<usize> = if ani.sequence.is_empty() {
+        (0..ani.frames.len()).collect()
+    } else {
+        ani.sequence.iter().map(|&i| i as usize).collect()
+    };
+
+    frame_indices
+        .into_iter()
+        .enumerate()
+        .map(|(step_idx, frame_idx)| {
+            let jiffies = ani.rates.get(step_idx).copied().unwrap_or(ani.default_rate);
+            (frame_idx, jiffies_to_ms(jiffies))
+        })
+        .collect()
+}
+
+pub(super) fn jiffies_to_ms(jiffies: u32) -> u32 {
+    ((jiffies as f64) * (1000.0 / 60.0)).round().max(16.0) as u32
+}
+
+/// Title and author stored in an ANI's `LIST INFO` chunk (`INAM` and `IART`).
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub(crate) struct AniInfo {
+    pub title: Option<String>,
+    pub author: Option<String>,
+}
+
+/// Read the `INFO` list of an ANI. Files without one, or that are not ANIs at all, give an
+/// empty [`AniInfo`].
+pub(super) fn parse_ani_info(data: &[u8]) -> AniInfo {
+    let mut info = AniInfo::default();
+    if data.len() < 12 || &data[0..4] != b"RIFF" || &data[8..12] != b"ACON" {
+        return info;
+    }
+
+    let mut pos = 12;
+    while pos + 8 <= data.len() {
+        let chunk_size =
+            u32::from_le_bytes([data[pos + 4], data[pos + 5], data[pos + 6], data[pos + 7]])
+                as usize;
+        let chunk_end = (pos + 8).saturating_add(chunk_size).min(data.len());
+
+        if &data[pos..pos + 4] == b"LIST"
+            && pos + 12 <= chunk_end
+            && &data[pos + 8..pos + 12] == b"INFO"
+        {
+            let mut field_pos = pos + 12;
+            while field_pos + 8 <= chunk_end {
+                let field_size = u32::from_le_bytes([
+                    data[field_pos + 4],
+                    data[field_pos + 5],
+                    data[field_pos + 6],
+                    data[field_pos + 7],
+                ]) as usize;
+                let text_start = field_pos + 8;
+                let text_end = text_start.saturating_add(field_size).min(chunk_end);
+                let text = &data[text_start..text_end];
+
+                match &data[field_pos..field_pos + 4] {
+                    b"INAM" if info.title.is_none() => info.title = info_text(text),
+                    b"IART" if info.author.is_none() => info.author = info_text(text),
+                    _ => {}
+                }
+
+                field_pos = text_start.saturating_add(field_size);
+                if field_size % 2 != 0 {
+                    field_pos = field_pos.saturating_add(1);
+                }
+            }
+        }
+
+        pos = (pos + 8).saturating_add(chunk_size);
+        if chunk_size % 2 != 0 {
+            pos = pos.saturating_add(1);
+        }
+    }
+
+    info
+}
+
+/// INFO strings are NUL-terminated and usually ANSI; text that is not UTF-8 is read as
+/// Latin-1 so accented names survive.
+fn info_text(bytes: &[u8]) -> Option<String> {
+    let bytes = bytes.split(|&b| b == 0).next().unwrap_or_default();
+    let text = match std::str::from_utf8(bytes) {
+        Ok(text) => text.to_string(),
+        Err(_) => bytes.iter().map(|&b| char::from(b)).collect(),
+    };
+    let text = text.trim();
+    (!text.is_empty()).then(|| text.to_string())
+}
+
 pub(super) fn extract_ani_first_frame(data: &[u8]) -> Option<Vec<u8>> {
     parse_ani_file(data)
         .ok()
@@ -132,43 +229,23 @@ pub(super) fn extract_ani_first_frame(data: &[u8]) -> Option<Vec<u8>> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use cursor_convert::fixtures::{self, AniBuilder, AniDefect};
 
     #[test]
     fn test_extract_ani_first_frame_invalid_data() {
         assert!(extract_ani_first_frame(&[]).is_none());
         assert!(extract_ani_first_frame(&[0; 10]).is_none());
         assert!(extract_ani_first_frame(&[0; 100]).is_none());
-
-        let mut wrong_form = vec![0u8; 100];
-        wrong_form[0..4].copy_from_slice(b"RIFF");
-        wrong_form[8..12].copy_from_slice(b"WAVE");
-        assert!(extract_ani_first_frame(&wrong_form).is_none());
+        assert!(extract_ani_first_frame(&fixtures::broken_ani(AniDefect::WrongForm)).is_none());
     }
 
     #[test]
     fn test_extract_ani_first_frame_valid_structure() {
-        let mut ani_data = Vec::new();
-
-        ani_data.extend_from_slice(b"RIFF");
-        ani_data.extend_from_slice(&0u32.to_le_bytes());
-        ani_data.extend_from_slice(b"ACON");
-
-        ani_data.extend_from_slice(b"LIST");
-        let list_size_pos = ani_data.len();
-        ani_data.extend_from_slice(&0u32.to_le_bytes());
-        ani_data.extend_from_slice(b"fram");
-
-        ani_data.extend_from_slice(b"icon");
         let fake_cur = vec![0u8; 22];
-        ani_data.extend_from_slice(&(fake_cur.len() as u32).to_le_bytes());
-        ani_data.extend_from_slice(&fake_cur);
-
-        let list_size = ani_data.len() - list_size_pos - 4;
-        ani_data[list_size_pos..list_size_pos + 4]
-            .copy_from_slice(&(list_size as u32).to_le_bytes());
-
-        let riff_size = ani_data.len() - 8;
-        ani_data[4..8].copy_from_slice(&(riff_size as u32).to_le_bytes());
+        let ani_data = AniBuilder::new()
+            .without_header()
+            .icon(fake_cur.clone())
+            .build();
 
         let result = extract_ani_first_frame(&ani_data);
         assert!(result.is_some());
@@ -180,51 +257,30 @@ mod tests {
         assert!(parse_ani_file(&[]).is_err());
         assert!(parse_ani_file(&[0; 10]).is_err());
         assert!(parse_ani_file(&[0; 100]).is_err());
+        assert!(parse_ani_file(&fixtures::broken_ani(AniDefect::WrongForm)).is_err());
+        assert!(matches!(
+            parse_ani_file(&fixtures::broken_ani(AniDefect::NoFrames)),
+            Err(AniError::NoFrames)
+        ));
+    }
 
-        let mut wrong_form = vec![0u8; 100];
-        wrong_form[0..4].copy_from_slice(b"RIFF");
-        wrong_form[8..12].copy_from_slice(b"WAVE");
-        assert!(parse_ani_file(&wrong_form).is_err());
+    #[test]
+    fn test_parse_ani_file_survives_damaged_files() {
+        for defect in AniDefect::ALL {
+            let _ = parse_ani_file(&fixtures::broken_ani(defect));
+        }
+        // Frames before the cut are still usable
+        let truncated = parse_ani_file(&fixtures::broken_ani(AniDefect::Truncated)).unwrap();
+        assert!(!truncated.frames.is_empty());
     }
 
     #[test]
     fn test_parse_ani_file_extracts_multiple_frames() {
-        let mut ani_data = Vec::new();
-
-        ani_data.extend_from_slice(b"RIFF");
-        ani_data.extend_from_slice(&0u32.to_le_bytes());
-        ani_data.extend_from_slice(b"ACON");
-
-        ani_data.extend_from_slice(b"anih");
-        ani_data.extend_from_slice(&36u32.to_le_bytes());
-        ani_data.extend_from_slice(&36u32.to_le_bytes());
-        ani_data.extend_from_slice(&3u32.to_le_bytes());
-        ani_data.extend_from_slice(&3u32.to_le_bytes());
-        ani_data.extend_from_slice(&32u32.to_le_bytes());
-        ani_data.extend_from_slice(&32u32.to_le_bytes());
-        ani_data.extend_from_slice(&32u32.to_le_bytes());
-        ani_data.extend_from_slice(&1u32.to_le_bytes());
-        ani_data.extend_from_slice(&5u32.to_le_bytes());
-        ani_data.extend_from_slice(&0u32.to_le_bytes());
-
-        ani_data.extend_from_slice(b"LIST");
-        let list_size_pos = ani_data.len();
-        ani_data.extend_from_slice(&0u32.to_le_bytes());
-        ani_data.extend_from_slice(b"fram");
-
-        for i in 0..3 {
-            ani_data.extend_from_slice(b"icon");
-            let fake_cur = vec![i as u8; 22];
-            ani_data.extend_from_slice(&(fake_cur.len() as u32).to_le_bytes());
-            ani_data.extend_from_slice(&fake_cur);
+        let mut builder = AniBuilder::new().default_rate(5);
+        for i in 0..3u8 {
+            builder = builder.icon(vec![i; 22]);
         }
-
-        let list_size = ani_data.len() - list_size_pos - 4;
-        ani_data[list_size_pos..list_size_pos + 4]
-            .copy_from_slice(&(list_size as u32).to_le_bytes());
-
-        let riff_size = ani_data.len() - 8;
-        ani_data[4..8].copy_from_slice(&(riff_size as u32).to_le_bytes());
+        let ani_data = builder.build();
 
         let ani = parse_ani_file(&ani_data).unwrap();
         assert_eq!(ani.frames.len(), 3);
@@ -237,39 +293,69 @@ mod tests {
     }
 
     #[test]
-    fn test_parse_ani_file_with_rate_chunk() {
-        let mut ani_data = Vec::new();
-
-        ani_data.extend_from_slice(b"RIFF");
-        ani_data.extend_from_slice(&0u32.to_le_bytes());
-        ani_data.extend_from_slice(b"ACON");
-
-        ani_data.extend_from_slice(b"rate");
-        ani_data.extend_from_slice(&12u32.to_le_bytes());
-        ani_data.extend_from_slice(&3u32.to_le_bytes());
-        ani_data.extend_from_slice(&6u32.to_le_bytes());
-        ani_data.extend_from_slice(&9u32.to_le_bytes());
-
-        ani_data.extend_from_slice(b"LIST");
-        let list_size_pos = ani_data.len();
-        ani_data.extend_from_slice(&0u32.to_le_bytes());
-        ani_data.extend_from_slice(b"fram");
-
-        for _ in 0..3 {
-            ani_data.extend_from_slice(b"icon");
-            let fake_cur = vec![0u8; 22];
-            ani_data.extend_from_slice(&(fake_cur.len() as u32).to_le_bytes());
-            ani_data.extend_from_slice(&fake_cur);
-        }
-
-        let list_size = ani_data.len() - list_size_pos - 4;
-        ani_data[list_size_pos..list_size_pos + 4]
-            .copy_from_slice(&(list_size as u32).to_le_bytes());
+    fn test_parse_ani_file_reads_real_cursor_frames() {
+        let ani = parse_ani_file(&fixtures::ani(4, 32)).unwrap();
+        assert_eq!(ani.frames.len(), 4);
+        assert!(ani.frames.iter().all(|f| f[0..4] == [0, 0, 2, 0]));
+    }
 
-        let riff_size = ani_data.len() - 8;
-        ani_data[4..8].copy_from_slice(&(riff_size as u32).to_le_bytes());
+    #[test]
+    fn test_parse_ani_file_with_rate_chunk() {
+        let ani_data = AniBuilder::new()
+            .without_header()
+            .rates(&[3, 6, 9])
+            .sequence(&[2, 0, 1])
+            .icon(vec![0u8; 22])
+            .icon(vec![0u8; 22])
+            .icon(vec![0u8; 22])
+            .build();
 
         let ani = parse_ani_file(&ani_data).unwrap();
         assert_eq!(ani.rates, vec![3, 6, 9]);
+        assert_eq!(ani.sequence, vec![2, 0, 1]);
+    }
+
+    #[test]
+    fn test_parse_ani_info_reads_title_and_author() {
+        let ani_data = AniBuilder::new()
+            .title("Blue Glass Busy")
+            .author("Ada")
+            .cur_frames(2, 32)
+            .build();
+
+        let info = parse_ani_info(&ani_data);
+        assert_eq!(info.title.as_deref(), Some("Blue Glass Busy"));
+        assert_eq!(info.author.as_deref(), Some("Ada"));
+        // The INFO list does not disturb frame parsing
+        assert_eq!(parse_ani_file(&ani_data).unwrap().frames.len(), 2);
+    }
+
+    #[test]
+    fn test_parse_ani_info_is_empty_without_info_list() {
+        assert_eq!(parse_ani_info(&fixtures::ani(2, 32)), AniInfo::default());
+        assert_eq!(parse_ani_info(&[]), AniInfo::default());
+        for defect in AniDefect::ALL {
+            let _ = parse_ani_info(&fixtures::broken_ani(defect));
+        }
+    }
+
+    #[test]
+    fn test_info_text_trims_and_decodes_latin1() {
+        assert_eq!(info_text(b"  Caf\xe9 \0junk").as_deref(), Some("Caf\u{e9}"));
+        assert_eq!(info_text(b"\0"), None);
+        assert_eq!(info_text(b""), None);
+    }
+
+    #[test]
+    fn test_playback_steps_honors_sequence_and_rates() {
+        let ani = AniData {
+            frames: vec![vec![0u8; 22], vec![1u8; 22]],
+            rates: vec![6, 12],
+            default_rate: 3,
+            sequence: vec![1, 0, 1],
+        };
+
+        let steps = playback_steps(&ani);
+        assert_eq!(steps, vec![(1, 100), (0, 200), (1, 50)]);
     }
 }
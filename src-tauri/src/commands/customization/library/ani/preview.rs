@@ -62,6 +62,11 @@ fn get_ani_preview_data_sync(file_path: &str) -> Result<AniPreviewData, String>
         let cached: AniPreviewData = serde_json::from_str(&manifest)
             .map_err(|e| format!("Failed to parse ANI preview manifest: {}", e))?;
         if !cached.frames.is_empty() {
+            // Bump the timestamp so cache GC treats this preview as recently used.
+            let _ = fs::File::options()
+                .append(true)
+                .open(&manifest_path)
+                .and_then(|f| f.set_modified(std::time::SystemTime::now()));
             return Ok(cached);
         }
     }
@@ -78,29 +83,12 @@ fn get_ani_preview_data_sync(file_path: &str) -> Result<AniPreviewData, String>
         return Err("ANI file has no frames".to_string());
     }
 
-    let frame_indices: Vec<usize> = if ani.sequence.is_empty() {
-        (0..ani.frames.len()).collect()
-    } else {
-        ani.sequence.iter().map(|&i| i as usize).collect()
-    };
-
-    let delays: Vec<u32> = frame_indices
-        .iter()
-        .enumerate()
-        .map(|(step_idx, _)| {
-            let jiffies = if step_idx < ani.rates.len() {
-                ani.rates[step_idx]
-            } else {
-                ani.default_rate
-            };
-            ((jiffies as f64) * (1000.0 / 60.0)).round().max(16.0) as u32
-        })
-        .collect();
+    let steps = super::parser::playback_steps(&ani);
 
-    let frame_results: Vec<Option<(String, u32)>> = frame_indices
+    let frame_results: Vec<Option<(String, u32)>> = steps
         .par_iter()
         .enumerate()
-        .map(|(step_idx, &frame_idx)| {
+        .map(|(step_idx, &(frame_idx, delay))| {
             if frame_idx >= ani.frames.len() {
                 return None;
             }
@@ -115,7 +103,7 @@ fn get_ani_preview_data_sync(file_path: &str) -> Result<AniPreviewData, String>
                 out.flush().ok();
             }
 
-            Some((frame_path.to_string_lossy().to_string(), delays[step_idx]))
+            Some((frame_path.to_string_lossy().to_string(), delay))
         })
         .collect();
 
@@ -60,3 +60,14 @@ pub(super) fn frame_to_png_bytes(frame_data: &[u8]) -> Result<Vec<u8>, AniError>
 }
 
 
+
+pub(super) fn frame_to_rgba(frame_data: &[u8]) -> Result<ImageBuffer<Rgba<u8>, Vec<u8>>, AniError> {
+    if let Some(png_data) = super::super::preview::extract_embedded_png(frame_data) {
+        if let Ok(img) = image::load_from_memory_with_format(&png_data, image::ImageFormat::Png) {
+            return Ok(img.to_rgba8());
+        }
+    }
+
+    super::super::preview::frame_to_rgba_dib_only(frame_data)
+        .ok_or(AniError::InvalidFormat("failed to decode DIB frame"))
+}
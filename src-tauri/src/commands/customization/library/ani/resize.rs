@@ -0,0 +1,125 @@
+use image::imageops::FilterType;
+use image::{ImageBuffer, Rgba};
+
+use super::super::variants::{native_size_from_cur_bytes, scale_coordinate, scaled_dimensions};
+
+/// Re-render an animated cursor with every frame resized to `target` px (Lanczos3).
+///
+/// The hotspot of the first frame is scaled with the image, and the playback order and
+/// per-step timing are kept. A `seq ` chunk is flattened: the new file lists one frame per
+/// step, so a frame shown twice is stored twice.
+pub(super) fn resize_ani(data: &[u8], target: u32) -> Result<Vec<u8>, String> {
+    let ani = super::parser::parse_ani_file(data)
+        .map_err(|e| format!("Failed to parse ANI file: {}", e))?;
+    let first = &ani.frames[0];
+    let native_size = native_size_from_cur_bytes(first)
+        .ok_or_else(|| "ANI frames are not cursor images".to_string())?;
+    if target == 0 || target >= native_size {
+        return Err(format!(
+            "Animated cursor is already {}px, nothing to shrink to {}px",
+            native_size, target
+        ));
+    }
+    let (hotspot_x, hotspot_y) = crate::utils::cursor_parser::parse_cur_click_point(first);
+
+    let order: Vec<usize> = if ani.sequence.is_empty() {
+        (0..ani.frames.len()).collect()
+    } else {
+        ani.sequence.iter().map(|&i| i as usize).collect()
+    };
+
+    let mut resized: Vec<Option<ImageBuffer<Rgba<u8>, Vec<u8>>>> = vec![None; ani.frames.len()];
+    let mut frames = Vec::with_capacity(order.len());
+    let mut rates = Vec::with_capacity(order.len());
+    let mut size = None;
+    for (step, &frame_idx) in order.iter().enumerate() {
+        let Some(frame_data) = ani.frames.get(frame_idx) else {
+            continue;
+        };
+        if resized[frame_idx].is_none() {
+            let rgba = super::render::frame_to_rgba(frame_data).map_err(String::from)?;
+            // Every frame must come out the same size; the first one decides it
+            let (width, height) = *size.get_or_insert_with(|| {
+                scaled_dimensions(rgba.width(), rgba.height(), native_size, target)
+            });
+            resized[frame_idx] = Some(image::imageops::resize(
+                &rgba,
+                width,
+                height,
+                FilterType::Lanczos3,
+            ));
+        }
+        frames.extend(resized[frame_idx].clone());
+        let rate = ani.rates.get(step).copied().unwrap_or(ani.default_rate);
+        rates.push(rate.max(1));
+    }
+
+    let (width, height) = size.ok_or_else(|| super::AniError::NoFrames.to_string())?;
+    crate::cursor_converter::generate_ani_data(
+        &frames,
+        scale_coordinate(hotspot_x, native_size, target, width),
+        scale_coordinate(hotspot_y, native_size, target, height),
+        &rates,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cursor_convert::fixtures::{self, AniBuilder};
+
+    fn cur_with_hotspot(size: u32, shade: u8, hotspot: (u16, u16)) -> Vec<u8> {
+        crate::cursor_converter::generate_cur_data(
+            &fixtures::frame(size, shade),
+            hotspot.0,
+            hotspot.1,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn frames_shrink_and_keep_hotspot_and_timing() {
+        let data = AniBuilder::new()
+            .icon(cur_with_hotspot(128, 10, (64, 32)))
+            .icon(cur_with_hotspot(128, 200, (64, 32)))
+            .rates(&[3, 9])
+            .build();
+
+        let resized = resize_ani(&data, 32).unwrap();
+        let ani = super::super::parser::parse_ani_file(&resized).unwrap();
+        assert_eq!(ani.frames.len(), 2);
+        assert_eq!(ani.rates, vec![3, 9]);
+        for frame in &ani.frames {
+            assert_eq!(native_size_from_cur_bytes(frame), Some(32));
+        }
+        assert_eq!(
+            crate::utils::cursor_parser::parse_cur_click_point(&ani.frames[0]),
+            (16, 8)
+        );
+    }
+
+    #[test]
+    fn sequence_is_flattened_into_frame_order() {
+        let data = AniBuilder::new()
+            .icon(cur_with_hotspot(64, 0, (0, 0)))
+            .icon(cur_with_hotspot(64, 255, (0, 0)))
+            .default_rate(4)
+            .sequence(&[1, 0, 1])
+            .build();
+
+        let ani = super::super::parser::parse_ani_file(&resize_ani(&data, 32).unwrap()).unwrap();
+        assert_eq!(ani.frames.len(), 3);
+        assert!(ani.sequence.is_empty());
+        assert_eq!(ani.frames[0], ani.frames[2]);
+        assert_ne!(ani.frames[0], ani.frames[1]);
+        assert_eq!(ani.default_rate, 4);
+    }
+
+    #[test]
+    fn refuses_to_grow_or_keep_the_size() {
+        let data = fixtures::ani(2, 32);
+        assert!(resize_ani(&data, 32).is_err());
+        assert!(resize_ani(&data, 48).is_err());
+        assert!(resize_ani(&data, 0).is_err());
+    }
+}
@@ -0,0 +1,323 @@
+//! User-defined collections (folders) for organizing the library.
+//!
+//! Collections nest through `parent_id` and each keeps its own ordered list of cursor IDs.
+//! A cursor is in at most one collection; cursors in none are "unfiled" and only show in the
+//! full library. Memberships of cursors that have left the library are dropped on save.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use tauri::{AppHandle, Runtime};
+
+use super::{load_library, lock_library, save_library, LibraryData};
+
+/// Longest collection name accepted, in characters.
+const MAX_COLLECTION_NAME_LEN: usize = 64;
+
+#[derive(ts_rs::TS, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct LibraryCollection {
+    pub id: String,
+    pub name: String,
+    /// Enclosing collection; `None` for top-level collections
+    #[serde(default)]
+    pub parent_id: Option<String>,
+    /// Member cursor IDs in the collection's own order
+    #[serde(default)]
+    pub cursor_ids: Vec<String>,
+}
+
+/// Add a collection named `name` under `parent_id`. Names must be unique among siblings,
+/// ignoring case.
+pub(super) fn add_collection(
+    library: &mut LibraryData,
+    name: &str,
+    parent_id: Option<String>,
+) -> Result<LibraryCollection, String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err("Collection name cannot be empty".to_string());
+    }
+    if name.chars().count() > MAX_COLLECTION_NAME_LEN {
+        return Err(format!(
+            "Collection name must be at most {} characters",
+            MAX_COLLECTION_NAME_LEN
+        ));
+    }
+    if let Some(parent) = &parent_id {
+        if !library.collections.iter().any(|c| &c.id == parent) {
+            return Err(format!("Collection with id {} not found", parent));
+        }
+    }
+    let taken = library
+        .collections
+        .iter()
+        .any(|c| c.parent_id == parent_id && c.name.eq_ignore_ascii_case(name));
+    if taken {
+        return Err(format!(
+            "A collection named \"{}\" already exists here",
+            name
+        ));
+    }
+
+    let collection = LibraryCollection {
+        id: crate::utils::library_meta::new_library_collection_id(),
+        name: name.to_string(),
+        parent_id,
+        cursor_ids: Vec::new(),
+    };
+    library.collections.push(collection.clone());
+    Ok(collection)
+}
+
+/// Move `cursor_id` out of its current collection and into `collection_id` at `position`
+/// (the end when `None` or past it). `collection_id: None` leaves the cursor unfiled.
+pub(super) fn move_cursor(
+    library: &mut LibraryData,
+    cursor_id: &str,
+    collection_id: Option<&str>,
+    position: Option<usize>,
+) -> Result<(), String> {
+    if !library.cursors.iter().any(|c| c.id == cursor_id) {
+        return Err(format!("Cursor with id {} not found", cursor_id));
+    }
+    if let Some(target) = collection_id {
+        if !library.collections.iter().any(|c| c.id == target) {
+            return Err(format!("Collection with id {} not found", target));
+        }
+    }
+
+    for collection in &mut library.collections {
+        collection.cursor_ids.retain(|id| id != cursor_id);
+    }
+    if let Some(target) = library
+        .collections
+        .iter_mut()
+        .find(|c| Some(c.id.as_str()) == collection_id)
+    {
+        let index = position.unwrap_or(usize::MAX).min(target.cursor_ids.len());
+        target.cursor_ids.insert(index, cursor_id.to_string());
+    }
+    Ok(())
+}
+
+/// Reorder the members of a collection. IDs that are not members are ignored and members
+/// missing from `order` keep their relative order at the end, like `reorder_library_cursors`.
+pub(super) fn reorder_members(
+    library: &mut LibraryData,
+    collection_id: &str,
+    order: &[String],
+) -> Result<Vec<String>, String> {
+    let collection = library
+        .collections
+        .iter_mut()
+        .find(|c| c.id == collection_id)
+        .ok_or_else(|| format!("Collection with id {} not found", collection_id))?;
+
+    let mut seen: HashSet<&str> = HashSet::new();
+    let mut reordered: Vec<String> = order
+        .iter()
+        .filter(|id| collection.cursor_ids.contains(id) && seen.insert(id.as_str()))
+        .cloned()
+        .collect();
+    reordered.extend(
+        collection
+            .cursor_ids
+            .iter()
+            .filter(|id| !seen.contains(id.as_str()))
+            .cloned(),
+    );
+    collection.cursor_ids = reordered.clone();
+    Ok(reordered)
+}
+
+/// Collections with memberships of cursors no longer in `library` removed.
+pub(super) fn pruned_collections(library: &LibraryData) -> Vec<LibraryCollection> {
+    let present: HashSet<&str> = library.cursors.iter().map(|c| c.id.as_str()).collect();
+    library
+        .collections
+        .iter()
+        .map(|collection| LibraryCollection {
+            cursor_ids: collection
+                .cursor_ids
+                .iter()
+                .filter(|id| present.contains(id.as_str()))
+                .cloned()
+                .collect(),
+            ..collection.clone()
+        })
+        .collect()
+}
+
+/// Members of `collection_id` in the collection's order. `None` if there is no such
+/// collection.
+pub(super) fn collection_members(
+    library: LibraryData,
+    collection_id: &str,
+) -> Option<Vec<super::LibraryCursor>> {
+    let order = library
+        .collections
+        .iter()
+        .find(|c| c.id == collection_id)?
+        .cursor_ids
+        .clone();
+    let mut cursors = library.cursors;
+    Some(
+        order
+            .iter()
+            .filter_map(|id| {
+                let index = cursors.iter().position(|c| &c.id == id)?;
+                Some(cursors.swap_remove(index))
+            })
+            .collect(),
+    )
+}
+
+/// All collections, parents before their children.
+#[tauri::command]
+pub fn get_library_collections<R: Runtime>(
+    app: AppHandle<R>,
+) -> Result<Vec<LibraryCollection>, String> {
+    Ok(pruned_collections(&load_library(&app)?))
+}
+
+/// Create a collection, nested under `parent_id` when given
+#[tauri::command]
+pub fn create_collection<R: Runtime>(
+    app: AppHandle<R>,
+    name: String,
+    parent_id: Option<String>,
+) -> Result<LibraryCollection, String> {
+    let _lock = lock_library(&app)?;
+    let mut library = load_library(&app)?;
+    let collection = add_collection(&mut library, &name, parent_id)?;
+    save_library(&app, &library)?;
+    Ok(collection)
+}
+
+/// Move a cursor into a collection (or out of every collection when `collection_id` is
+/// `None`), at `position` within it or at the end
+#[tauri::command]
+pub fn move_cursor_to_collection<R: Runtime>(
+    app: AppHandle<R>,
+    cursor_id: String,
+    collection_id: Option<String>,
+    position: Option<u32>,
+) -> Result<(), String> {
+    let _lock = lock_library(&app)?;
+    let mut library = load_library(&app)?;
+    move_cursor(
+        &mut library,
+        &cursor_id,
+        collection_id.as_deref(),
+        position.map(|p| p as usize),
+    )?;
+    save_library(&app, &library)
+}
+
+/// Reorder the cursors of a collection. Returns the new member order.
+#[tauri::command]
+pub fn reorder_collection_cursors<R: Runtime>(
+    app: AppHandle<R>,
+    collection_id: String,
+    order: Vec<String>,
+) -> Result<Vec<String>, String> {
+    let _lock = lock_library(&app)?;
+    let mut library = load_library(&app)?;
+    let reordered = reorder_members(&mut library, &collection_id, &order)?;
+    save_library(&app, &library)?;
+    Ok(reordered)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::LibraryCursor;
+    use super::*;
+
+    fn cursor(id: &str) -> LibraryCursor {
+        LibraryCursor {
+            id: id.to_string(),
+            name: id.to_string(),
+            file_path: format!("{id}.cur"),
+            click_point_x: 0,
+            click_point_y: 0,
+            created_at: "2025-01-01T00:00:00Z".to_string(),
+            is_pack: false,
+            pack_metadata: None,
+            native_size: None,
+            scaled_variants: Vec::new(),
+            notes: None,
+            author: None,
+            source_url: None,
+            source_link: None,
+        }
+    }
+
+    fn library(ids: &[&str]) -> LibraryData {
+        LibraryData {
+            cursors: ids.iter().map(|id| cursor(id)).collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn collections_nest_and_need_unique_sibling_names() {
+        let mut library = library(&[]);
+        let anime = add_collection(&mut library, " Anime ", None).expect("top level");
+        assert_eq!(anime.name, "Anime");
+
+        let nested =
+            add_collection(&mut library, "Minimal", Some(anime.id.clone())).expect("nested");
+        assert_eq!(nested.parent_id.as_deref(), Some(anime.id.as_str()));
+        // Same name under a different parent is fine
+        assert!(add_collection(&mut library, "Minimal", None).is_ok());
+
+        assert!(add_collection(&mut library, "anime", None).is_err());
+        assert!(add_collection(&mut library, "  ", None).is_err());
+        assert!(add_collection(&mut library, "Work", Some("missing".to_string())).is_err());
+    }
+
+    #[test]
+    fn moving_a_cursor_leaves_its_previous_collection() {
+        let mut library = library(&["a", "b", "c"]);
+        let work = add_collection(&mut library, "Work", None).unwrap().id;
+        let play = add_collection(&mut library, "Play", None).unwrap().id;
+
+        move_cursor(&mut library, "a", Some(&work), None).unwrap();
+        move_cursor(&mut library, "b", Some(&work), None).unwrap();
+        move_cursor(&mut library, "c", Some(&work), Some(0)).unwrap();
+        assert_eq!(library.collections[0].cursor_ids, ["c", "a", "b"]);
+
+        move_cursor(&mut library, "a", Some(&play), Some(7)).unwrap();
+        move_cursor(&mut library, "b", None, None).unwrap();
+        assert_eq!(library.collections[0].cursor_ids, ["c"]);
+        assert_eq!(library.collections[1].cursor_ids, ["a"]);
+
+        assert!(move_cursor(&mut library, "missing", Some(&work), None).is_err());
+        assert!(move_cursor(&mut library, "a", Some("missing"), None).is_err());
+        assert_eq!(library.collections[1].cursor_ids, ["a"]);
+    }
+
+    #[test]
+    fn members_follow_the_collection_order_and_drop_removed_cursors() {
+        let mut library = library(&["a", "b", "c"]);
+        let work = add_collection(&mut library, "Work", None).unwrap().id;
+        for id in ["a", "b", "c"] {
+            move_cursor(&mut library, id, Some(&work), None).unwrap();
+        }
+
+        let order = ["c".to_string(), "x".to_string(), "a".to_string()];
+        assert_eq!(
+            reorder_members(&mut library, &work, &order).unwrap(),
+            ["c", "a", "b"]
+        );
+
+        library.cursors.retain(|c| c.id != "a");
+        assert_eq!(pruned_collections(&library)[0].cursor_ids, ["c", "b"]);
+        let members: Vec<String> = collection_members(library, &work)
+            .unwrap()
+            .into_iter()
+            .map(|c| c.id)
+            .collect();
+        assert_eq!(members, ["c", "b"]);
+    }
+}
@@ -0,0 +1,132 @@
+/// Per-item results of copying the bundled default cursors and packs into the user library.
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+
+#[derive(ts_rs::TS, Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub enum DefaultItemKind {
+    Cursor,
+    Pack,
+}
+
+#[derive(ts_rs::TS, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct DefaultItemResult {
+    pub kind: DefaultItemKind,
+    /// Bundled file name (cursors) or folder name (packs)
+    pub name: String,
+    pub ok: bool,
+    pub error: Option<String>,
+}
+
+#[derive(ts_rs::TS, Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct DefaultsInitReport {
+    pub items: Vec<DefaultItemResult>,
+    pub imported: u32,
+    pub failed: u32,
+}
+
+impl DefaultsInitReport {
+    pub(super) fn record(&mut self, kind: DefaultItemKind, name: &str, result: Result<(), String>) {
+        if let Err(err) = &result {
+            cc_warn!("[CursorChanger] Failed to import default {:?} {}: {}", kind, name, err);
+        }
+        let ok = result.is_ok();
+        self.items.push(DefaultItemResult {
+            kind,
+            name: name.to_string(),
+            ok,
+            error: result.err(),
+        });
+        if ok {
+            self.imported += 1;
+        } else {
+            self.failed += 1;
+        }
+    }
+
+    pub(super) fn failed_names(&self, kind: DefaultItemKind) -> HashSet<String> {
+        self.items
+            .iter()
+            .filter(|item| item.kind == kind && !item.ok)
+            .map(|item| item.name.clone())
+            .collect()
+    }
+
+    /// Replace entries for items that were retried with their new results.
+    pub(super) fn merge_retry(&mut self, retry: DefaultsInitReport) {
+        for result in retry.items {
+            match self
+                .items
+                .iter_mut()
+                .find(|item| item.kind == result.kind && item.name == result.name)
+            {
+                Some(existing) => *existing = result,
+                None => self.items.push(result),
+            }
+        }
+        self.imported = self.items.iter().filter(|item| item.ok).count() as u32;
+        self.failed = self.items.len() as u32 - self.imported;
+    }
+}
+
+pub(super) fn read_report(path: &Path) -> Result<DefaultsInitReport, String> {
+    if !path.exists() {
+        return Ok(DefaultsInitReport::default());
+    }
+    let contents = fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read defaults report: {}", e))?;
+    serde_json::from_str(&contents).map_err(|e| format!("Failed to parse defaults report: {}", e))
+}
+
+pub(super) fn write_report(path: &Path, report: &DefaultsInitReport) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(report)
+        .map_err(|e| format!("Failed to serialize defaults report: {}", e))?;
+    fs::write(path, json).map_err(|e| format!("Failed to write defaults report: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn retry_results_replace_failed_entries() {
+        let mut report = DefaultsInitReport::default();
+        report.record(DefaultItemKind::Cursor, "1.cur", Ok(()));
+        report.record(DefaultItemKind::Cursor, "2.cur", Err("locked".to_string()));
+        report.record(DefaultItemKind::Pack, "Neon", Err("bad zip".to_string()));
+        assert_eq!((report.imported, report.failed), (1, 2));
+        assert_eq!(
+            report.failed_names(DefaultItemKind::Cursor),
+            HashSet::from(["2.cur".to_string()])
+        );
+
+        let mut retry = DefaultsInitReport::default();
+        retry.record(DefaultItemKind::Cursor, "2.cur", Ok(()));
+        report.merge_retry(retry);
+
+        assert_eq!((report.imported, report.failed), (2, 1));
+        assert_eq!(report.items.len(), 3);
+        assert!(report.failed_names(DefaultItemKind::Cursor).is_empty());
+        assert_eq!(
+            report.failed_names(DefaultItemKind::Pack),
+            HashSet::from(["Neon".to_string()])
+        );
+    }
+
+    #[test]
+    fn report_round_trips_and_missing_file_is_empty() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let path = temp.path().join("defaults-report.json");
+        assert_eq!(read_report(&path), Ok(DefaultsInitReport::default()));
+
+        let mut report = DefaultsInitReport::default();
+        report.record(DefaultItemKind::Pack, "Neon", Err("bad zip".to_string()));
+        write_report(&path, &report).expect("write");
+        assert_eq!(read_report(&path), Ok(report));
+    }
+}
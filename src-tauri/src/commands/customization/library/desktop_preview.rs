@@ -0,0 +1,227 @@
+//! Cursor-on-desktop preview.
+//!
+//! Draws a cursor at the size it will actually be applied at over a crop of the wallpaper
+//! (or a background image the user picks), so it can be judged against the real desktop.
+//! The wallpaper is scaled to cover the primary monitor the way Windows' "Fill" does and the
+//! crop keeps that 1:1 pixel scale, so the cursor appears exactly as large as on screen.
+
+use image::imageops::{self, FilterType};
+use image::{DynamicImage, Rgba, RgbaImage};
+use std::io::Cursor;
+use std::path::Path;
+use tauri::{AppHandle, Manager, Runtime};
+
+use super::ani::extract_ani_first_frame;
+use super::variants::decode_cur_image;
+use crate::cursor_converter;
+use crate::state::AppState;
+use crate::utils::cursor_parser::parse_cur_click_point;
+use crate::utils::encoding::base64_encode;
+
+pub const DESKTOP_PREVIEW_WIDTH: u32 = 480;
+pub const DESKTOP_PREVIEW_HEIGHT: u32 = 270;
+const FALLBACK_SCREEN: (u32, u32) = (1920, 1080);
+/// Used when there is no wallpaper image and no solid desktop colour can be read.
+const FALLBACK_BACKGROUND: Rgba<u8> = Rgba([58, 110, 165, 255]);
+
+/// Cursor image at its native size and its hotspot.
+fn load_cursor_image(path: &Path) -> Result<(RgbaImage, (u16, u16)), String> {
+    let ext = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(str::to_ascii_lowercase)
+        .unwrap_or_default();
+    match ext.as_str() {
+        "cur" | "ico" | "ani" => {
+            let bytes = std::fs::read(path).map_err(|e| format!("Failed to read cursor: {}", e))?;
+            let frame = if ext == "ani" {
+                extract_ani_first_frame(&bytes)
+                    .ok_or_else(|| "Animated cursor has no frames".to_string())?
+            } else {
+                bytes
+            };
+            let image = decode_cur_image(&frame)
+                .ok_or_else(|| "Failed to decode cursor image".to_string())?;
+            Ok((image, parse_cur_click_point(&frame)))
+        }
+        "svg" => {
+            let image = cursor_converter::load_svg(
+                &path.to_string_lossy(),
+                cursor_converter::MAX_CURSOR_SIZE,
+                1.0,
+                0,
+                0,
+            )?;
+            Ok((image, (0, 0)))
+        }
+        _ => {
+            let image = image::open(path).map_err(|e| format!("Failed to load image: {}", e))?;
+            Ok((image.to_rgba8(), (0, 0)))
+        }
+    }
+}
+
+/// Scale `cursor` so its larger side is `size`, scaling the hotspot with it.
+fn scale_cursor(cursor: &RgbaImage, hotspot: (u16, u16), size: u32) -> (RgbaImage, (u32, u32)) {
+    let native = cursor.width().max(cursor.height()).max(1);
+    let scale = |v: u32| ((u64::from(v) * u64::from(size)) / u64::from(native)) as u32;
+    let scaled = imageops::resize(
+        cursor,
+        scale(cursor.width()).max(1),
+        scale(cursor.height()).max(1),
+        FilterType::Lanczos3,
+    );
+    let hotspot = (scale(u32::from(hotspot.0)), scale(u32::from(hotspot.1)));
+    (scaled, hotspot)
+}
+
+/// Cursor file loaded and scaled so its larger side is `size`, with its scaled hotspot.
+pub fn load_scaled_cursor_image(path: &Path, size: u32) -> Result<(RgbaImage, (u32, u32)), String> {
+    let (image, hotspot) = load_cursor_image(path)?;
+    Ok(scale_cursor(&image, hotspot, size))
+}
+
+/// The centre `view` of `background` after scaling it to cover `screen`.
+fn cover_crop(background: &DynamicImage, screen: (u32, u32), view: (u32, u32)) -> RgbaImage {
+    let (bw, bh) = (background.width().max(1), background.height().max(1));
+    let scale = (screen.0 as f32 / bw as f32).max(screen.1 as f32 / bh as f32);
+    let source_w = ((view.0 as f32 / scale).round() as u32).clamp(1, bw);
+    let source_h = ((view.1 as f32 / scale).round() as u32).clamp(1, bh);
+    background
+        .crop_imm((bw - source_w) / 2, (bh - source_h) / 2, source_w, source_h)
+        .resize_exact(view.0, view.1, FilterType::Triangle)
+        .to_rgba8()
+}
+
+/// Parse a `Control Panel\Colors\Background` value such as "58 110 165".
+fn parse_desktop_color(value: &str) -> Option<Rgba<u8>> {
+    let mut parts = value.split_whitespace().map(|p| p.parse::<u8>().ok());
+    let color = Rgba([parts.next()??, parts.next()??, parts.next()??, 255]);
+    parts.next().is_none().then_some(color)
+}
+
+fn desktop_color() -> Option<Rgba<u8>> {
+    use winreg::enums::HKEY_CURRENT_USER;
+    use winreg::RegKey;
+
+    let key = RegKey::predef(HKEY_CURRENT_USER)
+        .open_subkey("Control Panel\\Colors")
+        .ok()?;
+    let value: String = key.get_value("Background").ok()?;
+    parse_desktop_color(&value)
+}
+
+fn background_image<R: Runtime>(
+    app: &AppHandle<R>,
+    background: Option<&str>,
+) -> Result<RgbaImage, String> {
+    let view = (DESKTOP_PREVIEW_WIDTH, DESKTOP_PREVIEW_HEIGHT);
+    let screen = app
+        .primary_monitor()
+        .ok()
+        .flatten()
+        .map(|m| (m.size().width, m.size().height))
+        .unwrap_or(FALLBACK_SCREEN);
+
+    let image = match background {
+        Some(path) => {
+            Some(image::open(path).map_err(|e| format!("Failed to load background: {}", e))?)
+        }
+        None => crate::cursor_brightness::wallpaper_path().and_then(|p| image::open(p).ok()),
+    };
+    Ok(match image {
+        Some(image) => cover_crop(&image, screen, view),
+        None => RgbaImage::from_pixel(
+            view.0,
+            view.1,
+            desktop_color().unwrap_or(FALLBACK_BACKGROUND),
+        ),
+    })
+}
+
+/// Draw `cursor` with its hotspot at the centre of `background`.
+fn composite(background: &mut RgbaImage, cursor: &RgbaImage, hotspot: (u32, u32)) {
+    let x = i64::from(background.width() / 2) - i64::from(hotspot.0);
+    let y = i64::from(background.height() / 2) - i64::from(hotspot.1);
+    imageops::overlay(background, cursor, x, y);
+}
+
+/// Render the cursor at `path` over the wallpaper, or over `background` when given, at the
+/// current cursor size. Returns a PNG data URL.
+#[tauri::command]
+pub fn render_desktop_context_preview<R: Runtime>(
+    app: AppHandle<R>,
+    path: String,
+    background: Option<String>,
+) -> Result<String, String> {
+    let cursor_path = Path::new(&path);
+    if !cursor_path.exists() {
+        return Err(format!("File not found: {}", path));
+    }
+    let size = app
+        .state::<AppState>()
+        .prefs
+        .read()
+        .map_err(|e| format!("Failed to lock state: {}", e))?
+        .cursor_size;
+    let size = u32::try_from(size).unwrap_or(cursor_converter::MAX_CURSOR_SIZE);
+
+    let (cursor, hotspot) = load_cursor_image(cursor_path)?;
+    let (cursor, hotspot) = scale_cursor(&cursor, hotspot, size);
+    let mut canvas = background_image(&app, background.as_deref())?;
+    composite(&mut canvas, &cursor, hotspot);
+
+    let mut png = Vec::new();
+    canvas
+        .write_to(&mut Cursor::new(&mut png), image::ImageFormat::Png)
+        .map_err(|e| format!("Failed to encode preview: {}", e))?;
+    Ok(format!("data:image/png;base64,{}", base64_encode(&png)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cover_crop_keeps_screen_scale() {
+        // A 960x540 wallpaper on a 1920x1080 screen is doubled, so a 480x270 view covers
+        // the middle 240x135 of the wallpaper.
+        let mut wallpaper = RgbaImage::from_pixel(960, 540, Rgba([0, 0, 0, 255]));
+        wallpaper.put_pixel(480, 270, Rgba([255, 0, 0, 255]));
+        let crop = cover_crop(
+            &DynamicImage::ImageRgba8(wallpaper),
+            (1920, 1080),
+            (480, 270),
+        );
+        assert_eq!(crop.dimensions(), (480, 270));
+        assert!(crop.get_pixel(241, 137).0[0] > 0);
+        assert_eq!(crop.get_pixel(0, 0).0[0], 0);
+    }
+
+    #[test]
+    fn cursor_hotspot_lands_on_the_centre() {
+        let mut background = RgbaImage::from_pixel(10, 10, Rgba([0, 0, 0, 255]));
+        let mut cursor = RgbaImage::new(4, 4);
+        cursor.put_pixel(1, 2, Rgba([255, 255, 255, 255]));
+        composite(&mut background, &cursor, (1, 2));
+        assert_eq!(background.get_pixel(5, 5).0, [255, 255, 255, 255]);
+    }
+
+    #[test]
+    fn scaling_moves_the_hotspot_with_the_image() {
+        let cursor = RgbaImage::new(32, 32);
+        let (scaled, hotspot) = scale_cursor(&cursor, (4, 8), 64);
+        assert_eq!(scaled.dimensions(), (64, 64));
+        assert_eq!(hotspot, (8, 16));
+    }
+
+    #[test]
+    fn desktop_color_parses_registry_triplets() {
+        assert_eq!(
+            parse_desktop_color("58 110 165"),
+            Some(Rgba([58, 110, 165, 255]))
+        );
+        assert_eq!(parse_desktop_color("58 110"), None);
+        assert_eq!(parse_desktop_color("300 0 0"), None);
+    }
+}
@@ -1,10 +1,26 @@
 use std::io::Cursor as IoCursor;
 use std::{fs, io::Write};
 
+use serde::Serialize;
 use tauri::{AppHandle, Runtime};
 use tauri_plugin_dialog::DialogExt;
 use zip::write::FileOptions;
 
+/// Name and attribution for each exported file, so they survive a round trip.
+const LIBRARY_METADATA_FILENAME: &str = "library-metadata.json";
+
+#[derive(Serialize)]
+struct ExportedCursorMetadata<'a> {
+    file_name: String,
+    name: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    notes: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    author: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    source_url: Option<&'a str>,
+}
+
 pub(super) async fn export_library_cursors<R: Runtime>(app: AppHandle<R>) -> Result<Option<String>, String> {
     use std::path::Path;
 
@@ -19,6 +35,8 @@ pub(super) async fn export_library_cursors<R: Runtime>(app: AppHandle<R>) -> Res
     let options: FileOptions<'_, ()> =
         FileOptions::default().compression_method(zip::CompressionMethod::Stored);
 
+    let mut metadata = Vec::new();
+
     for lib_cursor in &library.cursors {
         let path = Path::new(&lib_cursor.file_path);
         if !path.exists() {
@@ -35,6 +53,14 @@ pub(super) async fn export_library_cursors<R: Runtime>(app: AppHandle<R>) -> Res
             .unwrap_or("cursor.cur")
             .to_string();
 
+        metadata.push(ExportedCursorMetadata {
+            file_name: name.clone(),
+            name: &lib_cursor.name,
+            notes: lib_cursor.notes.as_deref(),
+            author: lib_cursor.author.as_deref(),
+            source_url: lib_cursor.source_url.as_deref(),
+        });
+
         zip_writer
             .start_file(name, options)
             .map_err(|e| format!("Failed to start zip entry: {}", e))?;
@@ -46,6 +72,15 @@ pub(super) async fn export_library_cursors<R: Runtime>(app: AppHandle<R>) -> Res
             .map_err(|e| format!("Failed to write to zip: {}", e))?;
     }
 
+    let metadata_json = serde_json::to_vec_pretty(&metadata)
+        .map_err(|e| format!("Failed to serialize library metadata: {}", e))?;
+    zip_writer
+        .start_file(LIBRARY_METADATA_FILENAME, options)
+        .map_err(|e| format!("Failed to start zip entry: {}", e))?;
+    zip_writer
+        .write_all(&metadata_json)
+        .map_err(|e| format!("Failed to write to zip: {}", e))?;
+
     let writer = zip_writer
         .finish()
         .map_err(|e| format!("Failed to finalize zip: {}", e))?;
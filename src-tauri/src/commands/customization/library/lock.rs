@@ -0,0 +1,357 @@
+//! Advisory lock around `library.json` load-modify-save cycles.
+//!
+//! The atomic rename in `save_library` keeps the file intact, but two writers (a second app
+//! instance, or a sync tool running this build) that both load, modify and save still lose
+//! one side's changes. Holders create `library.json.lock` with `create_new`, so only one
+//! process gets it; threads of this process take a mutex first. Both are retried until
+//! [`LOCK_TIMEOUT`]. The holder touches the lock file every [`HEARTBEAT`], so a file left
+//! untouched for [`STALE_AFTER`] belongs to a process that died mid-save and gets replaced;
+//! waiters take turns breaking such files through `library.json.lock.break`.
+
+use std::cell::Cell;
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::sync::{Mutex, MutexGuard, TryLockError};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant, SystemTime};
+
+use crate::error_codes::{with_code, ErrorCode};
+
+/// How long to wait for another holder before giving up with [`LibraryLockError::Busy`]
+pub(super) const LOCK_TIMEOUT: Duration = Duration::from_secs(3);
+/// Lock files untouched for this long belong to a process that died mid-save
+const STALE_AFTER: Duration = Duration::from_secs(30);
+/// How often a holder refreshes its lock file's modification time
+const HEARTBEAT: Duration = Duration::from_secs(5);
+const RETRY_INTERVAL: Duration = Duration::from_millis(50);
+
+static PROCESS_LOCK: Mutex<()> = Mutex::new(());
+
+thread_local! {
+    /// Nesting depth on this thread, so a locked cycle can call helpers that lock again
+    static DEPTH: Cell<u32> = const { Cell::new(0) };
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LibraryLockError {
+    /// Another thread or process held the lock for longer than [`LOCK_TIMEOUT`]
+    Busy,
+    Io(String),
+}
+
+impl fmt::Display for LibraryLockError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LibraryLockError::Busy => write!(
+                f,
+                "Library is busy: another Cursor Changer instance is saving it. Try again in a moment."
+            ),
+            LibraryLockError::Io(msg) => write!(f, "Failed to lock library: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for LibraryLockError {}
+
+impl From<LibraryLockError> for String {
+    fn from(value: LibraryLockError) -> Self {
+        let code = match value {
+            LibraryLockError::Busy => ErrorCode::LibraryBusy,
+            LibraryLockError::Io(_) => ErrorCode::LibraryLock,
+        };
+        with_code(code, value)
+    }
+}
+
+/// Held for the duration of a load-modify-save cycle; released on drop.
+pub struct LibraryLock {
+    /// `None` for nested guards, which leave the file to the outermost one
+    file: Option<PathBuf>,
+    heartbeat: Option<Heartbeat>,
+    _process: Option<MutexGuard<'static, ()>>,
+}
+
+impl LibraryLock {
+    /// Lock the library stored at `library_path`, retrying for up to `timeout`.
+    pub(super) fn acquire(
+        library_path: &Path,
+        timeout: Duration,
+    ) -> Result<Self, LibraryLockError> {
+        if DEPTH.with(Cell::get) > 0 {
+            DEPTH.with(|d| d.set(d.get() + 1));
+            return Ok(Self {
+                file: None,
+                heartbeat: None,
+                _process: None,
+            });
+        }
+
+        let lock_path = lock_file_path(library_path);
+        if let Some(parent) = lock_path.parent() {
+            fs::create_dir_all(parent).map_err(|e| LibraryLockError::Io(e.to_string()))?;
+        }
+
+        let deadline = Instant::now() + timeout;
+        let mut process = None;
+        loop {
+            if process.is_none() {
+                process = match PROCESS_LOCK.try_lock() {
+                    Ok(guard) => Some(guard),
+                    Err(TryLockError::Poisoned(e)) => Some(e.into_inner()),
+                    Err(TryLockError::WouldBlock) => None,
+                };
+            }
+            if process.is_some() {
+                match OpenOptions::new()
+                    .write(true)
+                    .create_new(true)
+                    .open(&lock_path)
+                {
+                    Ok(mut file) => {
+                        let _ = write!(file, "{}", std::process::id());
+                        DEPTH.with(|d| d.set(1));
+                        return Ok(Self {
+                            heartbeat: Some(Heartbeat::start(lock_path.clone(), HEARTBEAT)),
+                            file: Some(lock_path),
+                            _process: process,
+                        });
+                    }
+                    Err(e) if e.kind() == ErrorKind::AlreadyExists => {
+                        if let Some(holder) = stale_holder(&lock_path) {
+                            if break_stale_lock(&lock_path, &holder) {
+                                continue;
+                            }
+                        }
+                    }
+                    Err(e) => return Err(LibraryLockError::Io(e.to_string())),
+                }
+            }
+            if Instant::now() >= deadline {
+                return Err(LibraryLockError::Busy);
+            }
+            std::thread::sleep(RETRY_INTERVAL);
+        }
+    }
+}
+
+impl Drop for LibraryLock {
+    fn drop(&mut self) {
+        DEPTH.with(|d| d.set(d.get().saturating_sub(1)));
+        // Stop touching the file before it is removed
+        drop(self.heartbeat.take());
+        if let Some(path) = self.file.take() {
+            let _ = fs::remove_file(path);
+        }
+    }
+}
+
+fn lock_file_path(library_path: &Path) -> PathBuf {
+    let mut name = library_path.as_os_str().to_owned();
+    name.push(".lock");
+    PathBuf::from(name)
+}
+
+/// Keeps a held lock file's modification time fresh until dropped.
+struct Heartbeat {
+    stop: Option<Sender<()>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl Heartbeat {
+    fn start(lock_path: PathBuf, every: Duration) -> Self {
+        let (stop, stopped) = mpsc::channel::<()>();
+        let thread = std::thread::Builder::new()
+            .name("library-lock".to_string())
+            .spawn(move || {
+                while let Err(RecvTimeoutError::Timeout) = stopped.recv_timeout(every) {
+                    let _ = OpenOptions::new()
+                        .write(true)
+                        .open(&lock_path)
+                        .and_then(|file| file.set_modified(SystemTime::now()));
+                }
+            })
+            .ok();
+        Self {
+            stop: Some(stop),
+            thread,
+        }
+    }
+}
+
+impl Drop for Heartbeat {
+    fn drop(&mut self) {
+        drop(self.stop.take());
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// Contents of the lock file if it is stale, naming the process that left it.
+fn stale_holder(lock_path: &Path) -> Option<String> {
+    if !is_stale(lock_path) {
+        return None;
+    }
+    fs::read_to_string(lock_path).ok()
+}
+
+/// Remove the lock file if it is still the stale one whose contents were read as `holder`.
+/// Waiters breaking locks take turns through a `.break` file, so no other waiter can remove
+/// the stale file and take the lock between this check and the removal; a lock that has been
+/// replaced is left alone. Returns whether the lock was removed.
+fn break_stale_lock(lock_path: &Path, holder: &str) -> bool {
+    let mut break_path = lock_path.as_os_str().to_owned();
+    break_path.push(".break");
+    let break_path = PathBuf::from(break_path);
+    match OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&break_path)
+    {
+        Ok(_) => {}
+        Err(e) if e.kind() == ErrorKind::AlreadyExists => {
+            // Left behind by a waiter that died while breaking a lock
+            if is_stale(&break_path) {
+                let _ = fs::remove_file(&break_path);
+            }
+            return false;
+        }
+        Err(_) => return false,
+    }
+
+    let removed = stale_holder(lock_path).is_some_and(|current| current == holder)
+        && fs::remove_file(lock_path).is_ok();
+    if removed {
+        cc_warn!(
+            "[CursorChanger] Removed stale library lock {} left by process {}",
+            lock_path.display(),
+            holder
+        );
+    }
+    let _ = fs::remove_file(&break_path);
+    removed
+}
+
+fn is_stale(lock_path: &Path) -> bool {
+    fs::metadata(lock_path)
+        .and_then(|m| m.modified())
+        .ok()
+        .and_then(|modified| SystemTime::now().duration_since(modified).ok())
+        .is_some_and(|age| age > STALE_AFTER)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn lock_file_exists_while_held_and_nested_guards_share_it() {
+        let dir = tempdir().expect("tmp");
+        let library = dir.path().join("library.json");
+        let lock_path = lock_file_path(&library);
+
+        {
+            let _outer = LibraryLock::acquire(&library, LOCK_TIMEOUT).expect("outer");
+            assert!(lock_path.exists());
+            {
+                let _inner = LibraryLock::acquire(&library, LOCK_TIMEOUT).expect("nested");
+            }
+            assert!(lock_path.exists(), "nested guard must not release the lock");
+        }
+        assert!(!lock_path.exists());
+    }
+
+    #[test]
+    fn lock_held_by_another_process_is_busy() {
+        let dir = tempdir().expect("tmp");
+        let library = dir.path().join("library.json");
+        fs::write(lock_file_path(&library), "4242").expect("foreign lock");
+
+        let result = LibraryLock::acquire(&library, Duration::from_millis(120));
+        assert_eq!(result.err(), Some(LibraryLockError::Busy));
+        assert!(lock_file_path(&library).exists());
+    }
+
+    #[test]
+    fn lock_held_by_another_thread_times_out_as_busy() {
+        let dir = tempdir().expect("tmp");
+        let library = dir.path().join("library.json");
+        let _held = LibraryLock::acquire(&library, LOCK_TIMEOUT).expect("held");
+
+        let other = library.clone();
+        let result = std::thread::spawn(move || {
+            LibraryLock::acquire(&other, Duration::from_millis(120)).map(drop)
+        })
+        .join()
+        .expect("waiter");
+        assert_eq!(result, Err(LibraryLockError::Busy));
+    }
+
+    #[test]
+    fn stale_lock_is_replaced() {
+        let dir = tempdir().expect("tmp");
+        let library = dir.path().join("library.json");
+        let lock_path = lock_file_path(&library);
+        let file = fs::File::create(&lock_path).expect("stale lock");
+        file.set_modified(SystemTime::now() - STALE_AFTER * 2)
+            .expect("backdate lock");
+        drop(file);
+
+        let guard = LibraryLock::acquire(&library, LOCK_TIMEOUT).expect("stale lock replaced");
+        assert_eq!(
+            fs::read_to_string(&lock_path).expect("read lock"),
+            std::process::id().to_string()
+        );
+        drop(guard);
+        assert!(!lock_path.exists());
+    }
+
+    #[test]
+    fn held_lock_is_kept_fresh() {
+        let dir = tempdir().expect("tmp");
+        let lock_path = dir.path().join("library.json.lock");
+        let file = fs::File::create(&lock_path).expect("lock");
+        file.set_modified(SystemTime::now() - STALE_AFTER * 2)
+            .expect("backdate lock");
+        drop(file);
+        assert!(is_stale(&lock_path));
+
+        let heartbeat = Heartbeat::start(lock_path.clone(), Duration::from_millis(10));
+        std::thread::sleep(Duration::from_millis(100));
+        drop(heartbeat);
+        assert!(!is_stale(&lock_path));
+    }
+
+    #[test]
+    fn fresh_lock_survives_a_late_stale_check() {
+        let dir = tempdir().expect("tmp");
+        let lock_path = dir.path().join("library.json.lock");
+        fs::write(&lock_path, "4242").expect("fresh lock");
+
+        // A waiter that saw the previous, stale file only now gets to remove it
+        assert!(!break_stale_lock(&lock_path, "4242"));
+        assert_eq!(fs::read_to_string(&lock_path).expect("lock kept"), "4242");
+    }
+
+    #[test]
+    fn stale_lock_left_by_another_holder_is_not_removed() {
+        let dir = tempdir().expect("tmp");
+        let lock_path = dir.path().join("library.json.lock");
+        fs::write(&lock_path, "5151").expect("stale lock");
+        fs::File::options()
+            .write(true)
+            .open(&lock_path)
+            .and_then(|file| file.set_modified(SystemTime::now() - STALE_AFTER * 2))
+            .expect("backdate lock");
+
+        // The file read as stale was replaced before this waiter got to break it
+        assert!(!break_stale_lock(&lock_path, "4242"));
+        assert!(lock_path.exists());
+        assert!(break_stale_lock(&lock_path, "5151"));
+        assert!(!lock_path.exists());
+    }
+}
@@ -0,0 +1,198 @@
+//! Paged access to the library for virtualized lists.
+//!
+//! Every page is computed from the full library with the same filter and a total ordering
+//! (ties broken by ID), so consecutive pages never skip or repeat an entry while the library
+//! is unchanged.
+
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+use super::LibraryCursor;
+
+/// Largest page the backend will return, whatever `limit` asks for.
+pub const MAX_PAGE_LIMIT: u32 = 500;
+
+#[derive(ts_rs::TS, Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub enum LibrarySort {
+    /// The user's order from `reorder_library_cursors`
+    #[default]
+    Custom,
+    NameAsc,
+    NameDesc,
+    Newest,
+    Oldest,
+}
+
+#[derive(ts_rs::TS, Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct LibraryFilter {
+    /// Case-insensitive match against the name, author and notes
+    #[serde(default)]
+    pub query: Option<String>,
+    /// `true` for packs only, `false` for single cursors only
+    #[serde(default)]
+    pub is_pack: Option<bool>,
+    /// Only the members of this collection; the `custom` sort then follows its order
+    #[serde(default)]
+    pub collection_id: Option<String>,
+}
+
+impl LibraryFilter {
+    fn matches(&self, cursor: &LibraryCursor, query: Option<&str>) -> bool {
+        if self.is_pack.is_some_and(|is_pack| cursor.is_pack != is_pack) {
+            return false;
+        }
+        let Some(query) = query else {
+            return true;
+        };
+        [
+            Some(cursor.name.as_str()),
+            cursor.author.as_deref(),
+            cursor.notes.as_deref(),
+        ]
+        .into_iter()
+        .flatten()
+        .any(|field| field.to_lowercase().contains(query))
+    }
+}
+
+#[derive(ts_rs::TS, Debug, Clone, Serialize)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct LibraryPage {
+    pub items: Vec<LibraryCursor>,
+    /// Offset of the first item, after clamping to the filtered total
+    pub offset: u32,
+    /// Number of entries matching the filter
+    pub total: u32,
+}
+
+fn compare(a: &LibraryCursor, b: &LibraryCursor, sort: LibrarySort) -> Ordering {
+    let by_name = || a.name.to_lowercase().cmp(&b.name.to_lowercase());
+    let primary = match sort {
+        LibrarySort::Custom => Ordering::Equal,
+        LibrarySort::NameAsc => by_name(),
+        LibrarySort::NameDesc => by_name().reverse(),
+        // ISO-8601 timestamps sort chronologically as strings
+        LibrarySort::Newest => b.created_at.cmp(&a.created_at),
+        LibrarySort::Oldest => a.created_at.cmp(&b.created_at),
+    };
+    primary.then_with(|| a.id.cmp(&b.id))
+}
+
+/// Filter, sort and slice `cursors`.
+pub fn page(
+    cursors: Vec<LibraryCursor>,
+    offset: u32,
+    limit: u32,
+    sort: LibrarySort,
+    filter: &LibraryFilter,
+) -> LibraryPage {
+    let query = filter
+        .query
+        .as_deref()
+        .map(str::trim)
+        .filter(|q| !q.is_empty())
+        .map(str::to_lowercase);
+
+    let mut matching: Vec<LibraryCursor> = cursors
+        .into_iter()
+        .filter(|cursor| filter.matches(cursor, query.as_deref()))
+        .collect();
+    if sort != LibrarySort::Custom {
+        matching.sort_by(|a, b| compare(a, b, sort));
+    }
+
+    let total = u32::try_from(matching.len()).unwrap_or(u32::MAX);
+    let offset = offset.min(total);
+    let limit = limit.min(MAX_PAGE_LIMIT);
+    let items = matching
+        .into_iter()
+        .skip(offset as usize)
+        .take(limit as usize)
+        .collect();
+
+    LibraryPage {
+        items,
+        offset,
+        total,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cursor(id: &str, name: &str, created_at: &str, is_pack: bool) -> LibraryCursor {
+        LibraryCursor {
+            id: id.to_string(),
+            name: name.to_string(),
+            file_path: format!("C:/cursors/{}.cur", id),
+            click_point_x: 0,
+            click_point_y: 0,
+            created_at: created_at.to_string(),
+            is_pack,
+            pack_metadata: None,
+            native_size: None,
+            scaled_variants: Vec::new(),
+            notes: None,
+            author: None,
+            source_url: None,
+            source_link: None,
+        }
+    }
+
+    fn library() -> Vec<LibraryCursor> {
+        vec![
+            cursor("c", "beta", "2024-01-03T00:00:00Z", false),
+            cursor("a", "Alpha", "2024-01-01T00:00:00Z", true),
+            cursor("b", "alpha", "2024-01-02T00:00:00Z", false),
+            cursor("d", "Gamma", "2024-01-02T00:00:00Z", false),
+        ]
+    }
+
+    fn ids(page: &LibraryPage) -> Vec<&str> {
+        page.items.iter().map(|c| c.id.as_str()).collect()
+    }
+
+    #[test]
+    fn pages_are_stable_and_cover_every_entry() {
+        let filter = LibraryFilter::default();
+        let first = page(library(), 0, 2, LibrarySort::NameAsc, &filter);
+        let second = page(library(), 2, 2, LibrarySort::NameAsc, &filter);
+
+        assert_eq!(first.total, 4);
+        // Equal names fall back to ID order
+        assert_eq!(ids(&first), vec!["a", "b"]);
+        assert_eq!(ids(&second), vec!["c", "d"]);
+    }
+
+    #[test]
+    fn custom_sort_keeps_library_order() {
+        let result = page(library(), 0, 10, LibrarySort::Custom, &LibraryFilter::default());
+        assert_eq!(ids(&result), vec!["c", "a", "b", "d"]);
+
+        let newest = page(library(), 0, 10, LibrarySort::Newest, &LibraryFilter::default());
+        assert_eq!(ids(&newest), vec!["c", "b", "d", "a"]);
+    }
+
+    #[test]
+    fn filter_applies_before_counting() {
+        let filter = LibraryFilter {
+            query: Some("  ALPHA ".to_string()),
+            is_pack: Some(false),
+            ..Default::default()
+        };
+        let result = page(library(), 0, 10, LibrarySort::Custom, &filter);
+        assert_eq!(result.total, 1);
+        assert_eq!(ids(&result), vec!["b"]);
+    }
+
+    #[test]
+    fn offset_past_the_end_is_clamped() {
+        let result = page(library(), 99, 10, LibrarySort::Custom, &LibraryFilter::default());
+        assert_eq!(result.offset, 4);
+        assert!(result.items.is_empty());
+    }
+}
@@ -3,8 +3,28 @@ use std::path::Path;
 
 use crate::utils::encoding::base64_encode;
 
-pub(super) fn get_library_cursor_preview(file_path: String) -> Result<String, String> {
-    let path = Path::new(&file_path);
+/// A rendered cursor preview, before it is handed to the webview as a data URL or through
+/// the [preview protocol](crate::preview_protocol).
+pub(crate) struct PreviewImage {
+    pub mime: &'static str,
+    pub bytes: Vec<u8>,
+}
+
+impl PreviewImage {
+    fn png(bytes: Vec<u8>) -> Self {
+        Self {
+            mime: "image/png",
+            bytes,
+        }
+    }
+
+    pub fn to_data_url(&self) -> String {
+        format!("data:{};base64,{}", self.mime, base64_encode(&self.bytes))
+    }
+}
+
+pub(super) fn get_library_cursor_preview_image(file_path: &str) -> Result<PreviewImage, String> {
+    let path = Path::new(file_path);
 
     if !path.exists() {
         return Err(format!("File not found: {}", file_path));
@@ -17,15 +37,18 @@ pub(super) fn get_library_cursor_preview(file_path: String) -> Result<String, St
 
     if matches!(ext.as_deref(), Some("svg")) {
         let png_bytes = crate::cursor_converter::render_svg_to_png_bytes(
-            &file_path,
+            file_path,
             crate::cursor_converter::MAX_CURSOR_SIZE,
         )?;
-        let base64 = base64_encode(&png_bytes);
-        return Ok(format!("data:image/png;base64,{}", base64));
+        return Ok(PreviewImage::png(png_bytes));
     }
 
     let bytes = fs::read(path).map_err(|e| format!("Failed to read file: {}", e))?;
-    preview_data_from_bytes(&bytes, ext.as_deref())
+    Ok(preview_image_from_bytes(bytes, ext.as_deref()))
+}
+
+pub(super) fn get_library_cursor_preview(file_path: String) -> Result<String, String> {
+    get_library_cursor_preview_image(&file_path).map(|image| image.to_data_url())
 }
 
 pub(super) fn get_library_cursor_preview_from_bytes(
@@ -35,47 +58,38 @@ pub(super) fn get_library_cursor_preview_from_bytes(
     let ext = file_name
         .and_then(|name| Path::new(name).extension().and_then(|e| e.to_str()))
         .map(|s| s.to_lowercase());
-    preview_data_from_bytes(bytes, ext.as_deref())
+    Ok(preview_image_from_bytes(bytes.to_vec(), ext.as_deref()).to_data_url())
 }
 
-fn preview_data_from_bytes(bytes: &[u8], ext_hint: Option<&str>) -> Result<String, String> {
+fn preview_image_from_bytes(bytes: Vec<u8>, ext_hint: Option<&str>) -> PreviewImage {
     let ext = ext_hint.unwrap_or_default();
 
     if ext == "ani" {
-        if let Some(frame_data) = super::ani::extract_ani_first_frame(bytes) {
+        if let Some(frame_data) = super::ani::extract_ani_first_frame(&bytes) {
             if let Some(png) = extract_embedded_png(&frame_data) {
-                let png = ensure_square_png_bytes(&png).unwrap_or(png);
-                let base64 = base64_encode(&png);
-                return Ok(format!("data:image/png;base64,{}", base64));
+                return PreviewImage::png(ensure_square_png_bytes(&png).unwrap_or(png));
             }
             if let Some(png_bytes) = convert_cur_dib_to_png(&frame_data) {
-                let base64 = base64_encode(&png_bytes);
-                return Ok(format!("data:image/png;base64,{}", base64));
+                return PreviewImage::png(png_bytes);
             }
         }
 
-        if let Some(png) = extract_embedded_png(bytes) {
-            let png = ensure_square_png_bytes(&png).unwrap_or(png);
-            let base64 = base64_encode(&png);
-            return Ok(format!("data:image/png;base64,{}", base64));
+        if let Some(png) = extract_embedded_png(&bytes) {
+            return PreviewImage::png(ensure_square_png_bytes(&png).unwrap_or(png));
         }
     }
 
     if ext == "cur" || ext == "ico" {
-        if let Some(png) = extract_embedded_png(bytes) {
-            let png = ensure_square_png_bytes(&png).unwrap_or(png);
-            let base64 = base64_encode(&png);
-            return Ok(format!("data:image/png;base64,{}", base64));
+        if let Some(png) = extract_embedded_png(&bytes) {
+            return PreviewImage::png(ensure_square_png_bytes(&png).unwrap_or(png));
         }
 
-        if let Some(png_bytes) = convert_cur_dib_to_png(bytes) {
-            let base64 = base64_encode(&png_bytes);
-            return Ok(format!("data:image/png;base64,{}", base64));
+        if let Some(png_bytes) = convert_cur_dib_to_png(&bytes) {
+            return PreviewImage::png(png_bytes);
         }
     }
 
-    let base64 = base64_encode(bytes);
-    let mime_type = match ext {
+    let mime = match ext {
         "cur" | "ico" => "image/x-icon",
         "ani" => "application/x-navi-animation",
         "png" => "image/png",
@@ -84,7 +98,7 @@ fn preview_data_from_bytes(bytes: &[u8], ext_hint: Option<&str>) -> Result<Strin
         _ => "application/octet-stream",
     };
 
-    Ok(format!("data:{};base64,{}", mime_type, base64))
+    PreviewImage { mime, bytes }
 }
 
 pub(super) fn frame_to_rgba_dib_only(
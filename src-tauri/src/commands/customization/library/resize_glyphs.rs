@@ -0,0 +1,236 @@
+//! Resize cursors generated from a single arrow.
+//!
+//! Simple mode only has a Normal slot, so without this the resize roles would show the plain
+//! arrow. Instead the arrow is shrunk and rotated into double-headed arrows (and a four-way
+//! arrow for SizeAll) with the hotspot in the middle. Generated files are cached under the
+//! variants directory, keyed by the source path and modification time.
+
+use std::collections::hash_map::DefaultHasher;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+
+use image::{Pixel, Rgba, RgbaImage};
+
+use super::variants::decode_cur_image;
+
+const RESIZE_VARIANTS_DIR: &str = "resize";
+/// Arrows whose hotspot is this close to their centre have no usable direction.
+const MIN_POINTING_DISTANCE: f32 = 1.0;
+/// Direction of the standard Windows arrow, used when the direction can't be worked out.
+const DEFAULT_POINTING_ANGLE: f32 = -135.0;
+
+/// Angles of the arrowheads for each generated role, in degrees clockwise from east (screen
+/// coordinates, y down).
+fn arrowhead_angles(role: &str) -> Option<&'static [f32]> {
+    match role {
+        "SizeNS" => Some(&[-90.0, 90.0]),
+        "SizeWE" => Some(&[180.0, 0.0]),
+        "SizeNWSE" => Some(&[-135.0, 45.0]),
+        "SizeNESW" => Some(&[-45.0, 135.0]),
+        "SizeAll" => Some(&[-90.0, 0.0, 90.0, 180.0]),
+        _ => None,
+    }
+}
+
+/// Direction the arrow points in: from the centre of its opaque pixels towards the hotspot.
+fn pointing_angle(arrow: &RgbaImage, hotspot: (f32, f32)) -> f32 {
+    let (mut sum_x, mut sum_y, mut weight) = (0.0f32, 0.0f32, 0.0f32);
+    for (x, y, pixel) in arrow.enumerate_pixels() {
+        let alpha = f32::from(pixel.0[3]);
+        sum_x += (x as f32 + 0.5) * alpha;
+        sum_y += (y as f32 + 0.5) * alpha;
+        weight += alpha;
+    }
+    if weight == 0.0 {
+        return DEFAULT_POINTING_ANGLE;
+    }
+    let (dx, dy) = (hotspot.0 - sum_x / weight, hotspot.1 - sum_y / weight);
+    if dx.hypot(dy) < MIN_POINTING_DISTANCE {
+        return DEFAULT_POINTING_ANGLE;
+    }
+    dy.atan2(dx).to_degrees()
+}
+
+/// Distance from the hotspot to the farthest opaque pixel.
+fn reach(arrow: &RgbaImage, hotspot: (f32, f32)) -> f32 {
+    arrow
+        .enumerate_pixels()
+        .filter(|(_, _, p)| p.0[3] > 0)
+        .map(|(x, y, _)| (x as f32 + 0.5 - hotspot.0).hypot(y as f32 + 0.5 - hotspot.1))
+        .fold(0.0, f32::max)
+}
+
+/// Bilinear sample at pixel coordinates `(x, y)`, transparent outside the image. Colours are
+/// weighted by alpha so transparent neighbours don't darken edges.
+fn sample(image: &RgbaImage, x: f32, y: f32) -> Rgba<u8> {
+    let (x, y) = (x - 0.5, y - 0.5);
+    let (x0, y0) = (x.floor(), y.floor());
+    let (fx, fy) = (x - x0, y - y0);
+    let mut acc = [0.0f32; 4];
+    for (dx, dy, w) in [
+        (0, 0, (1.0 - fx) * (1.0 - fy)),
+        (1, 0, fx * (1.0 - fy)),
+        (0, 1, (1.0 - fx) * fy),
+        (1, 1, fx * fy),
+    ] {
+        let (px, py) = (x0 as i64 + dx, y0 as i64 + dy);
+        if px < 0 || py < 0 || px >= i64::from(image.width()) || py >= i64::from(image.height()) {
+            continue;
+        }
+        let p = image.get_pixel(px as u32, py as u32).0;
+        let alpha = f32::from(p[3]) * w;
+        for c in 0..3 {
+            acc[c] += f32::from(p[c]) * alpha;
+        }
+        acc[3] += alpha;
+    }
+    if acc[3] <= 0.0 {
+        return Rgba([0, 0, 0, 0]);
+    }
+    let channel = |v: f32| (v / acc[3]).round().clamp(0.0, 255.0) as u8;
+    Rgba([
+        channel(acc[0]),
+        channel(acc[1]),
+        channel(acc[2]),
+        acc[3].round().clamp(0.0, 255.0) as u8,
+    ])
+}
+
+/// Draw `arrow` onto `canvas` scaled by `scale` and rotated so its hotspot sits at `tip` and
+/// it points along `angle`.
+fn stamp(
+    canvas: &mut RgbaImage,
+    arrow: &RgbaImage,
+    hotspot: (f32, f32),
+    pointing: f32,
+    angle: f32,
+    tip: (f32, f32),
+    scale: f32,
+) {
+    let (sin, cos) = (angle - pointing).to_radians().sin_cos();
+    for (x, y, pixel) in canvas.enumerate_pixels_mut() {
+        let (dx, dy) = (x as f32 + 0.5 - tip.0, y as f32 + 0.5 - tip.1);
+        let source_x = hotspot.0 + (dx * cos + dy * sin) / scale;
+        let source_y = hotspot.1 + (dy * cos - dx * sin) / scale;
+        let sampled = sample(arrow, source_x, source_y);
+        if sampled.0[3] > 0 {
+            pixel.blend(&sampled);
+        }
+    }
+}
+
+/// Build the `role` cursor from `arrow`, on a canvas the size of the arrow. Returns the image
+/// and its hotspot (the centre), or `None` for roles that aren't generated.
+pub(super) fn resize_glyph(
+    arrow: &RgbaImage,
+    hotspot: (u16, u16),
+    role: &str,
+) -> Option<(RgbaImage, (u16, u16))> {
+    let angles = arrowhead_angles(role)?;
+    let size = arrow.width().max(arrow.height()).max(2);
+    let hotspot = (f32::from(hotspot.0) + 0.5, f32::from(hotspot.1) + 0.5);
+    let pointing = pointing_angle(arrow, hotspot);
+    let centre = size as f32 / 2.0;
+    // Each head reaches from the middle to just inside the edge
+    let arm = centre - 1.0;
+    let scale = arm / reach(arrow, hotspot).max(1.0);
+
+    let mut canvas = RgbaImage::new(size, size);
+    for &angle in angles {
+        let (sin, cos) = angle.to_radians().sin_cos();
+        let tip = (centre + arm * cos, centre + arm * sin);
+        stamp(&mut canvas, arrow, hotspot, pointing, angle, tip, scale);
+    }
+    let middle = (size / 2) as u16;
+    Some((canvas, (middle, middle)))
+}
+
+/// Cache location keyed by source path, modification time and role.
+fn generated_path(source: &Path, role: &str) -> Option<PathBuf> {
+    let modified = fs::metadata(source).and_then(|m| m.modified()).ok()?;
+    let mut hasher = DefaultHasher::new();
+    source.hash(&mut hasher);
+    modified.hash(&mut hasher);
+    let root = crate::paths::cursor_variants_dir().ok()?;
+    Some(
+        root.join(RESIZE_VARIANTS_DIR)
+            .join(format!("{:016x}_{}.cur", hasher.finish(), role)),
+    )
+}
+
+/// Resize cursor for `role` generated from the `.cur` arrow at `normal`, creating it on first
+/// use. `None` for other roles, animated cursors, or when generation fails.
+pub(crate) fn generated_resize_cursor(normal: &Path, role: &str) -> Option<PathBuf> {
+    arrowhead_angles(role)?;
+    let is_cur = normal
+        .extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|e| e.eq_ignore_ascii_case("cur"));
+    if !is_cur {
+        return None;
+    }
+    let out_path = generated_path(normal, role)?;
+    if out_path.exists() {
+        return Some(out_path);
+    }
+
+    let bytes = fs::read(normal).ok()?;
+    let arrow = decode_cur_image(&bytes)?;
+    let hotspot = crate::utils::cursor_parser::parse_cur_click_point(&bytes);
+    let (image, (hotspot_x, hotspot_y)) = resize_glyph(&arrow, hotspot, role)?;
+    let data = crate::cursor_converter::generate_cur_data(&image, hotspot_x, hotspot_y)
+        .map_err(|e| cc_warn!("[CursorChanger] Failed to build {} cursor: {}", role, e))
+        .ok()?;
+    if let Some(parent) = out_path.parent() {
+        fs::create_dir_all(parent).ok()?;
+    }
+    fs::write(&out_path, data).ok()?;
+    Some(out_path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Right-angled triangle with its corner and hotspot at the top left, pointing up-left.
+    fn corner_arrow(size: u32) -> RgbaImage {
+        RgbaImage::from_fn(size, size, |x, y| {
+            if x + y < size {
+                Rgba([0, 0, 0, 255])
+            } else {
+                Rgba([0, 0, 0, 0])
+            }
+        })
+    }
+
+    fn opaque(image: &RgbaImage, x: u32, y: u32) -> bool {
+        image.get_pixel(x, y).0[3] > 0
+    }
+
+    #[test]
+    fn arrow_direction_points_at_the_hotspot() {
+        let angle = pointing_angle(&corner_arrow(32), (0.5, 0.5));
+        assert!((angle - -135.0).abs() < 1.0, "angle was {}", angle);
+    }
+
+    #[test]
+    fn horizontal_resize_has_heads_on_both_sides() {
+        let (image, hotspot) = resize_glyph(&corner_arrow(32), (0, 0), "SizeWE").unwrap();
+        assert_eq!(image.dimensions(), (32, 32));
+        assert_eq!(hotspot, (16, 16));
+        assert!(opaque(&image, 2, 16));
+        assert!(opaque(&image, 29, 16));
+        assert!(!opaque(&image, 16, 1));
+        assert!(!opaque(&image, 16, 30));
+    }
+
+    #[test]
+    fn only_resize_roles_are_generated() {
+        assert!(resize_glyph(&corner_arrow(32), (0, 0), "Hand").is_none());
+        let (image, _) = resize_glyph(&corner_arrow(32), (0, 0), "SizeAll").unwrap();
+        for (x, y) in [(16, 2), (29, 16), (16, 29), (2, 16)] {
+            assert!(opaque(&image, x, y), "no arrowhead at ({}, {})", x, y);
+        }
+    }
+}
@@ -0,0 +1,197 @@
+//! Source links for converted library cursors.
+//!
+//! A cursor converted from a PNG/SVG can remember that image and the settings it was
+//! converted with. `crate::source_watch` watches the linked images and calls
+//! [`reload_linked_cursors`] when one is saved, which rewrites the .cur in place, rebuilds its
+//! pre-scaled variants and re-applies it wherever it is in use.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Runtime};
+
+use super::{load_library, lock_library, save_library, variants, CursorSourceLink, LibraryCursor};
+use crate::commands::customization::cursor_apply_service::sync_active_cursor_update;
+use crate::commands::customization::file_ops::conversion::{write_converted_cur, ConversionInput};
+use crate::paths::canonical_eq;
+
+fn validate_link(cursor: &LibraryCursor, link: &CursorSourceLink) -> Result<(), String> {
+    if cursor.is_pack {
+        return Err("Cursor packs cannot be linked to a source image".to_string());
+    }
+    let is_cur = Path::new(&cursor.file_path)
+        .extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|e| e.eq_ignore_ascii_case("cur"));
+    if !is_cur {
+        return Err("Only .cur cursors can be linked to a source image".to_string());
+    }
+    if !Path::new(&link.source_path).is_file() {
+        return Err(format!("Source image not found: {}", link.source_path));
+    }
+    if !link.scale.is_finite() || link.scale <= 0.0 {
+        return Err("Scale must be a finite positive number".to_string());
+    }
+    Ok(())
+}
+
+/// Link library cursor `id` to the image it was converted from, or remove the link with
+/// `None`. Restarts the source watcher so the change takes effect immediately.
+#[tauri::command]
+pub fn set_cursor_source_link(
+    app: AppHandle,
+    id: String,
+    link: Option<CursorSourceLink>,
+) -> Result<LibraryCursor, String> {
+    let _lock = lock_library(&app)?;
+    let mut library = load_library(&app)?;
+    let cursor = library
+        .cursors
+        .iter_mut()
+        .find(|c| c.id == id)
+        .ok_or_else(|| format!("Cursor with id {} not found", id))?;
+    if let Some(link) = &link {
+        validate_link(cursor, link)?;
+    }
+    cursor.source_link = link;
+    let updated = cursor.clone();
+
+    save_library(&app, &library)?;
+    crate::source_watch::install(&app)?;
+    Ok(updated)
+}
+
+/// Turn hot reload on or off for a linked library cursor.
+#[tauri::command]
+pub fn set_cursor_hot_reload(
+    app: AppHandle,
+    id: String,
+    enabled: bool,
+) -> Result<LibraryCursor, String> {
+    let _lock = lock_library(&app)?;
+    let mut library = load_library(&app)?;
+    let cursor = library
+        .cursors
+        .iter_mut()
+        .find(|c| c.id == id)
+        .ok_or_else(|| format!("Cursor with id {} not found", id))?;
+    let link = cursor
+        .source_link
+        .as_mut()
+        .ok_or_else(|| "Cursor has no linked source image".to_string())?;
+    link.hot_reload = enabled;
+    let updated = cursor.clone();
+
+    save_library(&app, &library)?;
+    crate::source_watch::install(&app)?;
+    Ok(updated)
+}
+
+/// Source images of every library cursor with hot reload on.
+pub fn hot_reload_source_paths<R: Runtime>(app: &AppHandle<R>) -> Result<Vec<PathBuf>, String> {
+    let library = load_library(app)?;
+    let mut paths: Vec<PathBuf> = Vec::new();
+    for link in library
+        .cursors
+        .iter()
+        .filter_map(|c| c.source_link.as_ref())
+    {
+        let path = PathBuf::from(&link.source_path);
+        if link.hot_reload && !paths.iter().any(|p| canonical_eq(p, &path)) {
+            paths.push(path);
+        }
+    }
+    Ok(paths)
+}
+
+/// Reconvert `cursor` from its source into a temporary file, then swap it in, so a failed
+/// conversion (e.g. the editor saved half a file) leaves the current cursor untouched.
+fn reconvert(cursor: &LibraryCursor, link: &CursorSourceLink) -> Result<(), String> {
+    let target = PathBuf::from(&cursor.file_path);
+    let temp = target.with_extension("cur.reload");
+    let result = write_converted_cur(
+        ConversionInput::Path(&link.source_path),
+        &temp.to_string_lossy(),
+        link.size,
+        Some((link.click_point_x, link.click_point_y)),
+        link.scale,
+        link.offset_x,
+        link.offset_y,
+    )
+    .and_then(|()| {
+        fs::rename(&temp, &target).map_err(|e| format!("Failed to replace cursor file: {}", e))
+    });
+    if result.is_err() {
+        let _ = fs::remove_file(&temp);
+    }
+    result
+}
+
+/// Reconvert every hot-reload cursor linked to `changed` and re-apply the ones in use.
+/// Returns the reloaded entries; failures are logged and leave their entry as it was.
+pub fn reload_linked_cursors<R: Runtime>(
+    app: &AppHandle<R>,
+    changed: &Path,
+) -> Result<Vec<LibraryCursor>, String> {
+    let _lock = lock_library(app)?;
+    let mut library = load_library(app)?;
+    let mut reloaded = Vec::new();
+    for cursor in library.cursors.iter_mut() {
+        let Some(link) = cursor.source_link.clone() else {
+            continue;
+        };
+        if !link.hot_reload || !canonical_eq(Path::new(&link.source_path), changed) {
+            continue;
+        }
+        if let Err(e) = reconvert(cursor, &link) {
+            cc_warn!(
+                "[CursorChanger] Failed to reload {} from {}: {}",
+                cursor.name,
+                link.source_path,
+                e
+            );
+            continue;
+        }
+        variants::populate_size_metadata(cursor);
+        reloaded.push(cursor.clone());
+    }
+    if reloaded.is_empty() {
+        return Ok(reloaded);
+    }
+
+    save_library(app, &library)?;
+    for cursor in &reloaded {
+        cc_info!(
+            "[CursorChanger] Reloaded {} from its source image",
+            cursor.name
+        );
+        sync_active_cursor_update(app, &cursor.file_path, &cursor.file_path);
+    }
+    Ok(reloaded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn source_paths_match_regardless_of_case() {
+        assert!(canonical_eq(
+            Path::new("C:/Art/Arrow.png"),
+            Path::new("c:/art/arrow.PNG")
+        ));
+        assert!(!canonical_eq(
+            Path::new("C:/Art/Arrow.png"),
+            Path::new("C:/Art/Arrow.svg")
+        ));
+    }
+
+    #[test]
+    fn hot_reload_defaults_to_on() {
+        let link: CursorSourceLink = serde_json::from_str(
+            r#"{"source_path":"C:/a.png","size":32,"click_point_x":1,"click_point_y":2,
+                "scale":1.0,"offset_x":0,"offset_y":0}"#,
+        )
+        .unwrap();
+        assert!(link.hot_reload);
+    }
+}
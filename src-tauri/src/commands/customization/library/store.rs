@@ -1,12 +1,19 @@
 use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 use std::{fs, io::Write, path::{Path, PathBuf}};
-use tauri::{AppHandle, Manager, Runtime};
+use tauri::{AppHandle, Emitter, Manager, Runtime};
 use tempfile::NamedTempFile;
 
-use super::{LibraryCursor, LibraryData};
-use crate::commands::customization::pack_commands::read_manifest_or_infer;
+use super::collections::pruned_collections;
+use super::defaults_report::{read_report, write_report, DefaultItemKind, DefaultsInitReport};
+use super::lock::{LibraryLock, LibraryLockError, LOCK_TIMEOUT};
+use super::{LibraryCollection, LibraryCursor, LibraryData, LIBRARY_SCHEMA_VERSION};
+use crate::commands::customization::pack_commands::{read_manifest_or_infer, PackValidationMode};
 use crate::commands::customization::pack_library::register_pack_in_library;
+use crate::error_codes::{with_code, ErrorCode};
+
+const DEFAULTS_REPORT_FILE_NAME: &str = "defaults-report.json";
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 struct LegacyLibraryCursor {
@@ -25,7 +32,18 @@ struct LegacyLibraryData {
     pub cursors: Vec<LegacyLibraryCursor>,
 }
 
-fn library_path<R: Runtime>(app: &AppHandle<R>) -> Result<PathBuf, String> {
+/// On-disk shape of `library.json`, borrowing the entries so saving does not copy them.
+#[derive(Serialize)]
+struct LibraryFile<'a> {
+    schema_version: u32,
+    cursors: &'a [LibraryCursor],
+    collections: Vec<LibraryCollection>,
+}
+
+pub(super) fn library_path<R: Runtime>(app: &AppHandle<R>) -> Result<PathBuf, String> {
+    if let Some(root) = crate::portable::data_root() {
+        return Ok(root.join("library.json"));
+    }
     let app_data_dir = match app.path().app_data_dir() {
         Ok(p) => p,
         Err(e) => {
@@ -43,6 +61,15 @@ fn library_path<R: Runtime>(app: &AppHandle<R>) -> Result<PathBuf, String> {
     Ok(library_path)
 }
 
+/// Lock `library.json` against other writers until the guard is dropped. Take it before
+/// loading in any load-modify-save cycle; nested calls on the same thread share the lock.
+pub(super) fn lock_library<R: Runtime>(
+    app: &AppHandle<R>,
+) -> Result<LibraryLock, LibraryLockError> {
+    let path = library_path(app).map_err(LibraryLockError::Io)?;
+    LibraryLock::acquire(&path, LOCK_TIMEOUT)
+}
+
 pub fn load_library<R: Runtime>(app: &AppHandle<R>) -> Result<LibraryData, String> {
     let path = library_path(app)?;
 
@@ -52,12 +79,34 @@ pub fn load_library<R: Runtime>(app: &AppHandle<R>) -> Result<LibraryData, Strin
         return initialize_library_with_defaults(app);
     }
 
-    let contents =
-        fs::read_to_string(&path).map_err(|e| format!("Failed to read library: {}", e))?;
+    let contents = fs::read_to_string(&path).map_err(|e| {
+        with_code(
+            ErrorCode::LibraryRead,
+            format!("Failed to read library: {}", e),
+        )
+    })?;
+    parse_library(&contents)
+}
 
-    match serde_json::from_str::<LibraryData>(&contents) {
-        Ok(library) => Ok(library),
-        Err(_) => match serde_json::from_str::<LegacyLibraryData>(&contents) {
+/// Parse `library.json` contents, upgrading the pre-versioning format. Stored paths are
+/// resolved, so they are ready to use.
+pub(super) fn parse_library(contents: &str) -> Result<LibraryData, String> {
+    match serde_json::from_str::<LibraryData>(contents) {
+        // Saving would drop fields a newer build (e.g. on another synced machine) relies on
+        Ok(library) if library.schema_version > LIBRARY_SCHEMA_VERSION => Err(with_code(
+            ErrorCode::LibraryNewerVersion,
+            format!(
+                "Library was written by a newer version of Cursor Changer (format {}, this build reads up to {})",
+                library.schema_version, LIBRARY_SCHEMA_VERSION
+            ),
+        )),
+        Ok(mut library) => {
+            if crate::portable::is_portable() {
+                map_cursor_paths(&mut library.cursors, crate::portable::resolve_stored);
+            }
+            Ok(library)
+        }
+        Err(_) => match serde_json::from_str::<LegacyLibraryData>(contents) {
             Ok(legacy_library) => {
                 let mut library = LibraryData::default();
                 for legacy_cursor in legacy_library.cursors {
@@ -70,6 +119,12 @@ pub fn load_library<R: Runtime>(app: &AppHandle<R>) -> Result<LibraryData, Strin
                         created_at: legacy_cursor.created_at,
                         is_pack: false,
                         pack_metadata: None,
+                        native_size: None,
+                        scaled_variants: Vec::new(),
+                        notes: None,
+                        author: None,
+                        source_url: None,
+                        source_link: None,
                     });
                 }
                 Ok(library)
@@ -79,7 +134,39 @@ pub fn load_library<R: Runtime>(app: &AppHandle<R>) -> Result<LibraryData, Strin
     }
 }
 
+/// Rewrite every file path a cursor entry holds, for converting to and from the stored form.
+pub(super) fn map_cursor_paths(cursors: &mut [LibraryCursor], map: impl Fn(&str) -> String) {
+    for cursor in cursors {
+        cursor.file_path = map(&cursor.file_path);
+        for variant in &mut cursor.scaled_variants {
+            variant.file_path = map(&variant.file_path);
+        }
+        if let Some(pack) = &mut cursor.pack_metadata {
+            pack.archive_path = map(&pack.archive_path);
+            for item in &mut pack.items {
+                if let Some(file_path) = &mut item.file_path {
+                    *file_path = map(file_path);
+                }
+            }
+        }
+        if let Some(link) = &mut cursor.source_link {
+            link.source_path = map(&link.source_path);
+        }
+    }
+}
+
+/// Load the library without triggering first-run initialization when it does not exist yet.
+pub(super) fn load_library_if_present<R: Runtime>(
+    app: &AppHandle<R>,
+) -> Result<Option<LibraryData>, String> {
+    if !library_path(app)?.exists() {
+        return Ok(None);
+    }
+    load_library(app).map(Some)
+}
+
 pub(super) fn save_library<R: Runtime>(app: &AppHandle<R>, library: &LibraryData) -> Result<(), String> {
+    let _lock = lock_library(app)?;
     let path = library_path(app)?;
     let parent_dir = path
         .parent()
@@ -89,7 +176,22 @@ pub(super) fn save_library<R: Runtime>(app: &AppHandle<R>, library: &LibraryData
     fs::create_dir_all(&parent_dir)
         .map_err(|e| format!("Failed to create library directory: {}", e))?;
 
-    let json = serde_json::to_string_pretty(library)
+    // Portable data folders move between machines, so paths inside them are stored relative
+    let stored_cursors;
+    let cursors = if crate::portable::is_portable() {
+        let mut cursors = library.cursors.clone();
+        map_cursor_paths(&mut cursors, crate::portable::to_stored);
+        stored_cursors = cursors;
+        &stored_cursors
+    } else {
+        &library.cursors
+    };
+
+    let json = serde_json::to_string_pretty(&LibraryFile {
+        schema_version: LIBRARY_SCHEMA_VERSION,
+        cursors,
+        collections: pruned_collections(library),
+    })
         .map_err(|e| format!("Failed to serialize library: {}", e))?;
 
     let mut temp_file = NamedTempFile::new_in(&parent_dir)
@@ -321,12 +423,18 @@ fn copy_dir_contents(src: &Path, dst: &Path) -> std::io::Result<()> {
     Ok(())
 }
 
-/// Initialize the library with default cursors by copying from bundled resources
-pub fn initialize_library_with_defaults<R: Runtime>(app: &AppHandle<R>) -> Result<LibraryData, String> {
+/// Bundled default cursors and packs, in the order they are imported.
+struct DefaultLibrarySources {
+    cursor_files: Vec<PathBuf>,
+    packs: Vec<DefaultPackStructure>,
+}
+
+fn resolve_default_library_sources<R: Runtime>(
+    app: &AppHandle<R>,
+) -> Result<DefaultLibrarySources, String> {
     let default_root = resolve_default_library_root_dir(app)?;
     let default_cursors_dir = default_root.join("cursors");
     let default_packs_dir = default_root.join("cursor-packs");
-    let cursors_dir = crate::paths::cursors_dir()?;
 
     cc_debug!(
         "[CursorChanger] Initializing library with defaults from: {}",
@@ -341,149 +449,230 @@ pub fn initialize_library_with_defaults<R: Runtime>(app: &AppHandle<R>) -> Resul
     };
     cursor_files.sort_by_key(|path| cursor_sort_key(path));
 
+    Ok(DefaultLibrarySources {
+        cursor_files,
+        packs: list_default_pack_structures(&default_packs_dir)?,
+    })
+}
+
+fn defaults_report_path<R: Runtime>(app: &AppHandle<R>) -> Result<PathBuf, String> {
+    let path = library_path(app)?;
+    Ok(path.with_file_name(DEFAULTS_REPORT_FILE_NAME))
+}
+
+fn save_and_emit_report<R: Runtime>(app: &AppHandle<R>, report: &DefaultsInitReport) {
+    match defaults_report_path(app).and_then(|path| write_report(&path, report)) {
+        Ok(()) => {}
+        Err(e) => cc_warn!("[CursorChanger] Failed to save defaults report: {}", e),
+    }
+    let _ = app.emit(crate::events::DEFAULTS_INIT_REPORT, report.clone());
+}
+
+fn import_default_cursor(
+    source_path: &Path,
+    cursors_dir: &Path,
+    now: DateTime<Utc>,
+    total: usize,
+    position: usize,
+) -> Result<LibraryCursor, String> {
+    let file_name = source_path
+        .file_name()
+        .and_then(|s| s.to_str())
+        .ok_or_else(|| format!("Invalid default cursor filename: {}", source_path.display()))?;
+
+    let dest_path = copy_default_cursor_to_user_dir(source_path, cursors_dir)?;
+
+    let (click_x, click_y) = read_cursor_click_point(&dest_path).unwrap_or((0, 0));
+    let (name, created_at) = derive_library_entry_metadata(file_name, now, total, position);
+
+    let mut cursor = LibraryCursor {
+        id: crate::utils::library_meta::new_library_cursor_id(),
+        name,
+        file_path: dest_path.to_string_lossy().to_string(),
+        click_point_x: click_x,
+        click_point_y: click_y,
+        created_at,
+        is_pack: false,
+        pack_metadata: None,
+        native_size: None,
+        scaled_variants: Vec::new(),
+        notes: None,
+        author: None,
+        source_url: None,
+        source_link: None,
+    };
+    super::variants::populate_size_metadata(&mut cursor);
+    Ok(cursor)
+}
+
+/// Copy default cursors into the user dir and add them to the front of the library.
+/// `only` limits the import to the given file names (used when repairing).
+fn import_default_cursors(
+    cursor_files: &[PathBuf],
+    only: Option<&HashSet<String>>,
+    library: &mut LibraryData,
+    report: &mut DefaultsInitReport,
+) -> Result<(), String> {
+    let cursors_dir = crate::paths::cursors_dir()?;
     let now = Utc::now();
     let total_cursors = cursor_files.len();
-    let mut library = LibraryData::default();
 
     // Build in chronological order (oldest -> newest) then reverse to persist newest-first,
     // so default "Custom" order matches "Date Created" (newest to oldest).
     let mut entries: Vec<LibraryCursor> = Vec::new();
-    for (position, source_path) in cursor_files.into_iter().enumerate() {
-        let file_name = match source_path.file_name().and_then(|s| s.to_str()) {
-            Some(name) => name.to_string(),
-            None => {
-                cc_warn!(
-                    "[CursorChanger] Skipping default cursor with invalid filename: {}",
-                    source_path.display()
-                );
-                continue;
-            }
-        };
-
-        let dest_path = match copy_default_cursor_to_user_dir(&source_path, &cursors_dir) {
-            Ok(dest) => dest,
-            Err(err) => {
-                cc_warn!("[CursorChanger] {err}");
-                continue;
-            }
-        };
-
-        let (click_x, click_y) = read_cursor_click_point(&dest_path).unwrap_or((0, 0));
-        let (name, created_at) =
-            derive_library_entry_metadata(&file_name, now, total_cursors, position);
-
-        let cursor = LibraryCursor {
-            id: crate::utils::library_meta::new_library_cursor_id(),
-            name,
-            file_path: dest_path.to_string_lossy().to_string(),
-            click_point_x: click_x,
-            click_point_y: click_y,
-            created_at,
-            is_pack: false,
-            pack_metadata: None,
-        };
+    for (position, source_path) in cursor_files.iter().enumerate() {
+        let name = source_path
+            .file_name()
+            .map(|s| s.to_string_lossy().to_string())
+            .unwrap_or_default();
+        if only.is_some_and(|names| !names.contains(&name)) {
+            continue;
+        }
 
-        entries.push(cursor);
+        let result =
+            import_default_cursor(source_path, &cursors_dir, now, total_cursors, position);
+        report.record(
+            DefaultItemKind::Cursor,
+            &name,
+            result.map(|cursor| entries.push(cursor)),
+        );
     }
 
     // Reverse to persist newest-first order by default
     entries.reverse();
-    library.cursors.extend(entries);
+    library.cursors.splice(0..0, entries);
+    Ok(())
+}
 
-    // Also register any bundled cursor packs (zip archives)
-    let pack_structures = list_default_pack_structures(&default_packs_dir)?;
-    let mut pack_count = 0usize;
-    
+fn import_default_pack<R: Runtime>(
+    app: &AppHandle<R>,
+    pack: &DefaultPackStructure,
+    user_packs_dir: &Path,
+    library: &mut LibraryData,
+) -> Result<(), String> {
+    let pack_dir = user_packs_dir.join(&pack.name);
+    fs::create_dir_all(&pack_dir).map_err(|e| {
+        format!(
+            "Failed to create default cursor pack folder {}: {}",
+            pack_dir.display(),
+            e
+        )
+    })?;
+
+    // Copy archive into user cursor-packs dir
+    let file_name = pack
+        .zip_path
+        .file_name()
+        .and_then(|s| s.to_str())
+        .unwrap_or("cursor-pack.zip");
+    let dest_path =
+        crate::commands::customization::pack_library::ensure_unique_filename(&pack_dir, file_name);
+    fs::copy(&pack.zip_path, &dest_path)
+        .map_err(|e| format!("Failed to copy default cursor pack {}: {}", file_name, e))?;
+
+    let manifest = read_manifest_or_infer(&dest_path, PackValidationMode::Strict)
+        .map_err(|e| format!("Manifest inference failed: {}", e))?;
+
+    // Register the pack to get the LibraryCursor and ID
+    let cursor = crate::commands::customization::pack_library::register_pack_in_library_with_data(
+        app,
+        library,
+        &dest_path,
+        manifest.mode,
+        manifest.items,
+        Some(manifest.created_at),
+    )?;
+
+    // If we have pre-extracted cursors, hydrate the cache
+    if let Some(src_cursors_dir) = &pack.extracted_cursors_path {
+        // New pack layout stores extracted files alongside the zip.
+        if let Err(e) = copy_dir_contents(src_cursors_dir, &pack_dir) {
+            cc_warn!(
+                "[CursorChanger] Failed to pre-hydrate cache for pack {}: {}",
+                cursor.name,
+                e
+            );
+        } else {
+            cc_debug!("[CursorChanger] Pre-hydrated cache for pack {}", cursor.name);
+        }
+    }
+    Ok(())
+}
+
+fn import_default_packs<R: Runtime>(
+    app: &AppHandle<R>,
+    packs: &[DefaultPackStructure],
+    only: Option<&HashSet<String>>,
+    library: &mut LibraryData,
+    report: &mut DefaultsInitReport,
+) -> Result<(), String> {
     // Ensure we have a place to put them. using cursor_packs_dir() as defined in paths.rs
     // This is typically library/cursor-packs/
     let user_packs_dir = crate::paths::cursor_packs_dir()?;
-    
-    for pack in pack_structures {
-        let pack_dir = user_packs_dir.join(&pack.name);
-        fs::create_dir_all(&pack_dir).map_err(|e| {
-            format!(
-                "Failed to create default cursor pack folder {}: {}",
-                pack_dir.display(),
-                e
-            )
-        })?;
-
-        // Copy archive into user cursor-packs dir
-        let dest_path = {
-            let file_name = pack.zip_path
-                .file_name()
-                .and_then(|s| s.to_str())
-                .unwrap_or("cursor-pack.zip");
-
-            let target_path = crate::commands::customization::pack_library::ensure_unique_filename(
-                &pack_dir,
-                file_name,
-            );
-            
-            fs::copy(&pack.zip_path, &target_path).map_err(|e| {
-                format!(
-                    "Failed to copy default cursor pack {}: {}",
-                    file_name, e
-                )
-            })?;
-            target_path
-        };
 
-        match read_manifest_or_infer(&dest_path) {
-            Ok(manifest) => {
-                // Register the pack to get the LibraryCursor and ID
-                match crate::commands::customization::pack_library::register_pack_in_library_with_data(
-                    app,
-                    &mut library,
-                    &dest_path,
-                    manifest.mode,
-                    manifest.items,
-                    Some(manifest.created_at),
-                ) {
-                    Ok(cursor) => {
-                        pack_count += 1;
-                        
-                        // If we have pre-extracted cursors, hydrate the cache
-                        if let Some(src_cursors_dir) = pack.extracted_cursors_path {
-                            // New pack layout stores extracted files alongside the zip.
-                            if let Err(e) = copy_dir_contents(&src_cursors_dir, &pack_dir) {
-                                  cc_warn!(
-                                    "[CursorChanger] Failed to pre-hydrate cache for pack {}: {}",
-                                    cursor.name,
-                                    e
-                                );
-                             } else {
-                                 cc_debug!("[CursorChanger] Pre-hydrated cache for pack {}", cursor.name);
-                             }
-                        }
-                    }
-                    Err(err) => {
-                         cc_warn!(
-                            "[CursorChanger] Failed to register default cursor pack {}: {}",
-                            pack.name,
-                            err
-                        );
-                    }
-                }
-            }
-            Err(err) => cc_warn!(
-                "[CursorChanger] Skipping default cursor pack {} (manifest inference failed): {}",
-                pack.name,
-                err
-            ),
+    for pack in packs {
+        if only.is_some_and(|names| !names.contains(&pack.name)) {
+            continue;
         }
+        let result = import_default_pack(app, pack, &user_packs_dir, library);
+        report.record(DefaultItemKind::Pack, &pack.name, result);
     }
+    Ok(())
+}
+
+/// Initialize the library with default cursors by copying from bundled resources.
+/// Emits `DEFAULTS_INIT_REPORT` with the result for each bundled file.
+pub fn initialize_library_with_defaults<R: Runtime>(app: &AppHandle<R>) -> Result<LibraryData, String> {
+    let _lock = lock_library(app)?;
+    let sources = resolve_default_library_sources(app)?;
+    let mut library = LibraryData::default();
+    let mut report = DefaultsInitReport::default();
+
+    import_default_cursors(&sources.cursor_files, None, &mut library, &mut report)?;
+    // Also register any bundled cursor packs (zip archives)
+    import_default_packs(app, &sources.packs, None, &mut library, &mut report)?;
 
     cc_debug!(
-        "[CursorChanger] Initialized library with {} default cursors and {} default cursor packs",
-        library.cursors.len(),
-        pack_count
+        "[CursorChanger] Initialized library with {} default items ({} failed)",
+        report.imported,
+        report.failed
     );
 
     save_library(app, &library)?;
+    save_and_emit_report(app, &report);
     Ok(library)
 }
 
+/// Retry the default items that failed during initialization, leaving the rest of the
+/// library untouched. Returns the updated report.
+pub fn repair_default_library<R: Runtime>(app: &AppHandle<R>) -> Result<DefaultsInitReport, String> {
+    let mut report = read_report(&defaults_report_path(app)?)?;
+    let failed_cursors = report.failed_names(DefaultItemKind::Cursor);
+    let failed_packs = report.failed_names(DefaultItemKind::Pack);
+    if failed_cursors.is_empty() && failed_packs.is_empty() {
+        return Ok(report);
+    }
+
+    let sources = resolve_default_library_sources(app)?;
+    let _lock = lock_library(app)?;
+    let mut library = load_library(app)?;
+    let mut retry = DefaultsInitReport::default();
+
+    import_default_cursors(&sources.cursor_files, Some(&failed_cursors), &mut library, &mut retry)?;
+    import_default_packs(app, &sources.packs, Some(&failed_packs), &mut library, &mut retry)?;
+
+    cc_debug!(
+        "[CursorChanger] Repaired default library: {} recovered, {} still failing",
+        retry.imported,
+        retry.failed
+    );
+
+    report.merge_retry(retry);
+    save_library(app, &library)?;
+    save_and_emit_report(app, &report);
+    Ok(report)
+}
+
 /// .CUR file format constants
 #[allow(dead_code)]
 mod cursor_format {
@@ -541,6 +730,12 @@ mod tests {
             created_at: "2025-01-01T00:00:00Z".to_string(),
             is_pack: false,
             pack_metadata: None,
+            native_size: None,
+            scaled_variants: Vec::new(),
+            notes: None,
+            author: None,
+            source_url: None,
+            source_link: None,
         };
 
         let json = serde_json::to_string(&cursor).expect("serialize");
@@ -553,6 +748,38 @@ mod tests {
         assert_eq!(parsed.click_point_y, cursor.click_point_y);
     }
 
+    #[test]
+    fn test_map_cursor_paths_covers_variants_and_packs() {
+        let mut cursor: LibraryCursor = serde_json::from_value(serde_json::json!({
+            "id": "pack_1",
+            "name": "Pack",
+            "file_path": "a.zip",
+            "click_point_x": 0,
+            "click_point_y": 0,
+            "created_at": "2025-01-01T00:00:00Z",
+            "is_pack": true,
+            "pack_metadata": {
+                "mode": "simple",
+                "archive_path": "a.zip",
+                "items": [{
+                    "cursor_name": "Normal",
+                    "display_name": "Normal",
+                    "file_name": "arrow.cur",
+                    "file_path": "arrow.cur"
+                }]
+            },
+            "scaled_variants": [{ "size": 64, "file_path": "arrow-64.cur" }]
+        }))
+        .expect("deserialize");
+
+        map_cursor_paths(std::slice::from_mut(&mut cursor), |p| format!("root/{p}"));
+        assert_eq!(cursor.file_path, "root/a.zip");
+        assert_eq!(cursor.scaled_variants[0].file_path, "root/arrow-64.cur");
+        let pack = cursor.pack_metadata.expect("pack metadata");
+        assert_eq!(pack.archive_path, "root/a.zip");
+        assert_eq!(pack.items[0].file_path.as_deref(), Some("root/arrow.cur"));
+    }
+
     #[test]
     fn test_legacy_cursor_conversion() {
         let legacy_json = r#"
@@ -0,0 +1,271 @@
+//! Folding in conflict copies of `library.json` left by file-sync tools.
+//!
+//! OneDrive (`library-PCNAME.json`), Dropbox (`library (conflicted copy ...).json`) and
+//! Syncthing (`library.sync-conflict-....json`) all keep the losing side of a conflict next
+//! to the original. At startup every such copy is merged into the live library and renamed
+//! to `*.merged` so it is only merged once.
+
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Runtime};
+
+use super::store::{library_path, load_library_if_present, lock_library, save_library};
+use super::{LibraryCollection, LibraryCursor, LibraryData};
+
+const MERGED_SUFFIX: &str = "merged";
+
+/// Sibling files in `dir` that look like sync conflict copies of `library.json`.
+fn conflict_copies(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut copies: Vec<PathBuf> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| {
+            let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+                return false;
+            };
+            let lower = name.to_ascii_lowercase();
+            lower != "library.json" && lower.starts_with("library") && lower.ends_with(".json")
+        })
+        .collect();
+    copies.sort();
+    copies
+}
+
+/// Merge `incoming` into `local`. Local entries win on ID clashes, but attribution the local
+/// copy lacks is taken from the incoming one. Incoming-only entries are appended in their
+/// own order when their file still exists. Returns how many entries were added.
+pub(super) fn merge_libraries(local: &mut LibraryData, incoming: LibraryData) -> usize {
+    let mut known: HashSet<String> = local.cursors.iter().map(|c| c.id.clone()).collect();
+    let mut added = 0;
+
+    merge_collections(local, incoming.collections);
+    for theirs in incoming.cursors {
+        if let Some(ours) = local.cursors.iter_mut().find(|c| c.id == theirs.id) {
+            fill_missing_metadata(ours, &theirs);
+            continue;
+        }
+        if !Path::new(&theirs.file_path).exists() || !known.insert(theirs.id.clone()) {
+            continue;
+        }
+        local.cursors.push(theirs);
+        added += 1;
+    }
+    added
+}
+
+/// Add the collections only `incoming` has, then file each cursor the local copy has not
+/// filed anywhere into the collection the incoming copy has it in.
+fn merge_collections(local: &mut LibraryData, incoming: Vec<LibraryCollection>) {
+    for theirs in &incoming {
+        if !local.collections.iter().any(|c| c.id == theirs.id) {
+            local.collections.push(LibraryCollection {
+                cursor_ids: Vec::new(),
+                ..theirs.clone()
+            });
+        }
+    }
+
+    let mut filed: HashSet<String> = local
+        .collections
+        .iter()
+        .flat_map(|c| c.cursor_ids.iter().cloned())
+        .collect();
+    for theirs in incoming {
+        if let Some(ours) = local.collections.iter_mut().find(|c| c.id == theirs.id) {
+            for id in theirs.cursor_ids {
+                if filed.insert(id.clone()) {
+                    ours.cursor_ids.push(id);
+                }
+            }
+        }
+    }
+}
+
+fn fill_missing_metadata(ours: &mut LibraryCursor, theirs: &LibraryCursor) {
+    for (field, value) in [
+        (&mut ours.notes, &theirs.notes),
+        (&mut ours.author, &theirs.author),
+        (&mut ours.source_url, &theirs.source_url),
+    ] {
+        if field.is_none() {
+            field.clone_from(value);
+        }
+    }
+    if let (Some(mine), Some(other)) = (&mut ours.pack_metadata, &theirs.pack_metadata) {
+        mine.rating = mine.rating.or(other.rating);
+    }
+}
+
+/// Merge and retire any sync conflict copies. Called once at startup.
+pub fn merge_synced_library_copies<R: Runtime>(app: &AppHandle<R>) -> Result<usize, String> {
+    let path = library_path(app)?;
+    let Some(dir) = path.parent() else {
+        return Ok(0);
+    };
+    let copies = conflict_copies(dir);
+    if copies.is_empty() {
+        return Ok(0);
+    }
+    let _lock = lock_library(app)?;
+    let Some(mut library) = load_library_if_present(app)? else {
+        return Ok(0);
+    };
+
+    let mut added = 0;
+    for copy in &copies {
+        let parsed = fs::read_to_string(copy)
+            .ok()
+            .and_then(|contents| serde_json::from_str::<LibraryData>(&contents).ok());
+        match parsed {
+            Some(incoming) => added += merge_libraries(&mut library, incoming),
+            None => {
+                cc_warn!(
+                    "[CursorChanger] Ignoring unreadable library copy {}",
+                    copy.display()
+                );
+                continue;
+            }
+        }
+    }
+
+    save_library(app, &library)?;
+    for copy in &copies {
+        let mut retired = copy.clone().into_os_string();
+        retired.push(".");
+        retired.push(MERGED_SUFFIX);
+        if let Err(e) = fs::rename(copy, &retired) {
+            cc_warn!(
+                "[CursorChanger] Failed to retire library copy {}: {}",
+                copy.display(),
+                e
+            );
+        }
+    }
+    cc_info!(
+        "[CursorChanger] Merged {} synced library copies ({} new entries)",
+        copies.len(),
+        added
+    );
+    Ok(added)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cursor(id: &str, file_path: &str) -> LibraryCursor {
+        LibraryCursor {
+            id: id.to_string(),
+            name: id.to_string(),
+            file_path: file_path.to_string(),
+            click_point_x: 0,
+            click_point_y: 0,
+            created_at: "2025-01-01T00:00:00Z".to_string(),
+            is_pack: false,
+            pack_metadata: None,
+            native_size: None,
+            scaled_variants: Vec::new(),
+            notes: None,
+            author: None,
+            source_url: None,
+            source_link: None,
+        }
+    }
+
+    #[test]
+    fn merge_keeps_local_entries_and_appends_existing_remote_ones() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let present = temp.path().join("present.cur");
+        fs::write(&present, b"cur").unwrap();
+        let present = present.to_string_lossy().to_string();
+        let missing = temp.path().join("missing.cur").to_string_lossy().to_string();
+
+        let mut local = LibraryData {
+            schema_version: 2,
+            cursors: vec![cursor("a", &present)],
+            ..Default::default()
+        };
+        let mut remote_a = cursor("a", "elsewhere.cur");
+        remote_a.author = Some("Remote".to_string());
+        let incoming = LibraryData {
+            schema_version: 2,
+            cursors: vec![remote_a, cursor("b", &present), cursor("c", &missing)],
+            ..Default::default()
+        };
+
+        assert_eq!(merge_libraries(&mut local, incoming), 1);
+        let ids: Vec<&str> = local.cursors.iter().map(|c| c.id.as_str()).collect();
+        assert_eq!(ids, vec!["a", "b"]);
+        assert_eq!(local.cursors[0].file_path, present);
+        assert_eq!(local.cursors[0].author.as_deref(), Some("Remote"));
+    }
+
+    #[test]
+    fn merge_adds_remote_collections_without_refiling_local_cursors() {
+        let collection = |id: &str, parent: Option<&str>, members: &[&str]| LibraryCollection {
+            id: id.to_string(),
+            name: id.to_string(),
+            parent_id: parent.map(str::to_string),
+            cursor_ids: members.iter().map(|m| m.to_string()).collect(),
+        };
+        let mut local = LibraryData {
+            collections: vec![collection("work", None, &["a"])],
+            ..Default::default()
+        };
+        let incoming = LibraryData {
+            collections: vec![
+                collection("work", None, &["b", "c"]),
+                collection("anime", None, &["a", "d"]),
+                collection("minimal", Some("anime"), &["e"]),
+            ],
+            ..Default::default()
+        };
+
+        merge_libraries(&mut local, incoming);
+        let members: Vec<(&str, Vec<&str>)> = local
+            .collections
+            .iter()
+            .map(|c| {
+                (
+                    c.id.as_str(),
+                    c.cursor_ids.iter().map(String::as_str).collect(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            members,
+            vec![
+                ("work", vec!["a", "b", "c"]),
+                ("anime", vec!["d"]),
+                ("minimal", vec!["e"]),
+            ]
+        );
+        assert_eq!(local.collections[2].parent_id.as_deref(), Some("anime"));
+    }
+
+    #[test]
+    fn recognises_common_conflict_copy_names() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        for name in [
+            "library.json",
+            "library-DESKTOP-1.json",
+            "library (conflicted copy).json",
+            "library.sync-conflict-20250101-000000-ABC.json",
+            "library-DESKTOP-2.json.merged",
+            "defaults-report.json",
+        ] {
+            fs::write(temp.path().join(name), b"{}").unwrap();
+        }
+
+        let names: Vec<String> = conflict_copies(temp.path())
+            .iter()
+            .map(|p| p.file_name().unwrap().to_string_lossy().to_string())
+            .collect();
+        assert_eq!(names.len(), 3);
+        assert!(!names.contains(&"library.json".to_string()));
+    }
+}
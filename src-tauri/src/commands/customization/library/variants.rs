@@ -0,0 +1,330 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use image::imageops::FilterType;
+use image::{ImageBuffer, Rgba};
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Runtime};
+
+use super::{LibraryCursor, LibraryCursorVariant};
+use crate::state::settings_file::{self, SettingsFile};
+
+/// Sizes that get a pre-scaled copy at import time. These are the large sizes where the
+/// runtime scaling done by Windows looks the worst.
+pub(crate) const PRESCALED_SIZES: [u32; 3] = [48, 64, 96];
+
+pub const CURSOR_VARIANTS_FILE_NAME: &str = "cursor-variants.json";
+
+static PREGENERATE: AtomicBool = AtomicBool::new(true);
+
+#[derive(ts_rs::TS, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct CursorVariantSettings {
+    /// Write pre-scaled copies of imported cursors and apply them at the matching sizes.
+    /// When off, Windows scales the original file.
+    #[serde(default = "default_pregenerate")]
+    pub pregenerate: bool,
+}
+
+fn default_pregenerate() -> bool {
+    true
+}
+
+impl Default for CursorVariantSettings {
+    fn default() -> Self {
+        Self {
+            pregenerate: default_pregenerate(),
+        }
+    }
+}
+
+impl SettingsFile for CursorVariantSettings {
+    const FILE_NAME: &'static str = CURSOR_VARIANTS_FILE_NAME;
+    const DESCRIPTION: &'static str = "cursor variant settings";
+}
+
+pub fn load_variant_settings<R: Runtime>(app: &AppHandle<R>) -> CursorVariantSettings {
+    settings_file::load(app)
+}
+
+pub fn save_variant_settings<R: Runtime>(
+    app: &AppHandle<R>,
+    settings: &CursorVariantSettings,
+) -> Result<(), String> {
+    settings_file::save(app, settings)?;
+    PREGENERATE.store(settings.pregenerate, Ordering::SeqCst);
+    Ok(())
+}
+
+/// Load the saved settings. Called once at startup.
+pub fn install_variant_settings<R: Runtime>(app: &AppHandle<R>) {
+    PREGENERATE.store(load_variant_settings(app).pregenerate, Ordering::SeqCst);
+}
+
+fn pregenerate_enabled() -> bool {
+    PREGENERATE.load(Ordering::SeqCst)
+}
+
+/// Read the pixel size of the first image in a .cur/.ani file.
+///
+/// The ICONDIRENTRY stores width as a single byte where `0` means 256.
+pub(super) fn read_native_size(path: &Path) -> Option<u32> {
+    let bytes = fs::read(path).ok()?;
+    let is_ani = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .map_or(false, |e| e.eq_ignore_ascii_case("ani"));
+
+    if is_ani {
+        let frame = super::ani::extract_ani_first_frame(&bytes)?;
+        native_size_from_cur_bytes(&frame)
+    } else {
+        native_size_from_cur_bytes(&bytes)
+    }
+}
+
+pub(super) fn native_size_from_cur_bytes(bytes: &[u8]) -> Option<u32> {
+    if bytes.len() < 22 || bytes[0] != 0 || bytes[1] != 0 {
+        return None;
+    }
+
+    let width = if bytes[6] == 0 { 256 } else { u32::from(bytes[6]) };
+    let height = if bytes[7] == 0 { 256 } else { u32::from(bytes[7]) };
+    Some(width.max(height))
+}
+
+/// Fill in `native_size` and regenerate the pre-scaled variants for a library entry.
+///
+/// Variant generation is best-effort: failures are logged and the entry simply falls back to
+/// letting Windows scale the original file. With pre-generation turned off no variants are
+/// written.
+pub(crate) fn populate_size_metadata(cursor: &mut LibraryCursor) {
+    if cursor.is_pack {
+        return;
+    }
+
+    let source = PathBuf::from(&cursor.file_path);
+    cursor.native_size = read_native_size(&source);
+    remove_scaled_variants(&cursor.id);
+    cursor.scaled_variants = match cursor.native_size {
+        Some(native) if pregenerate_enabled() => generate_scaled_variants(
+            &cursor.id,
+            &source,
+            native,
+            cursor.click_point_x,
+            cursor.click_point_y,
+        ),
+        _ => Vec::new(),
+    };
+}
+
+fn generate_scaled_variants(
+    id: &str,
+    source: &Path,
+    native_size: u32,
+    click_point_x: u16,
+    click_point_y: u16,
+) -> Vec<LibraryCursorVariant> {
+    // Animated cursors would need every frame re-encoded; leave those to Windows for now.
+    let is_cur = source
+        .extension()
+        .and_then(|e| e.to_str())
+        .map_or(false, |e| e.eq_ignore_ascii_case("cur"));
+    if !is_cur {
+        return Vec::new();
+    }
+
+    let Some(image) = fs::read(source).ok().and_then(|b| decode_cur_image(&b)) else {
+        cc_warn!(
+            "[CursorChanger] Could not decode {} for pre-scaled variants",
+            source.display()
+        );
+        return Vec::new();
+    };
+
+    let variants_dir = match crate::paths::cursor_variants_dir() {
+        Ok(dir) => dir.join(id),
+        Err(e) => {
+            cc_warn!("[CursorChanger] {}", e);
+            return Vec::new();
+        }
+    };
+
+    if let Err(e) = fs::create_dir_all(&variants_dir) {
+        cc_warn!(
+            "[CursorChanger] Failed to create variants directory {}: {}",
+            variants_dir.display(),
+            e
+        );
+        return Vec::new();
+    }
+
+    PRESCALED_SIZES
+        .iter()
+        .copied()
+        .filter(|&size| size != native_size)
+        .filter_map(|size| {
+            let (width, height) = scaled_dimensions(image.width(), image.height(), native_size, size);
+            let resized = image::imageops::resize(&image, width, height, FilterType::Lanczos3);
+            let hotspot_x = scale_coordinate(click_point_x, native_size, size, width);
+            let hotspot_y = scale_coordinate(click_point_y, native_size, size, height);
+
+            let data = crate::cursor_converter::generate_cur_data(&resized, hotspot_x, hotspot_y)
+                .map_err(|e| cc_warn!("[CursorChanger] Failed to build {}px variant: {}", size, e))
+                .ok()?;
+
+            let path = variants_dir.join(format!("{}.cur", size));
+            fs::write(&path, data)
+                .map_err(|e| {
+                    cc_warn!(
+                        "[CursorChanger] Failed to write variant {}: {}",
+                        path.display(),
+                        e
+                    )
+                })
+                .ok()?;
+
+            Some(LibraryCursorVariant {
+                size,
+                file_path: path.to_string_lossy().to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Write a copy of a `.cur` file with every pixel's brightness shifted by `amount`
+/// (`-1.0` black .. `1.0` white), keeping alpha and the hotspot. Returns the new path.
+pub(crate) fn brightness_adjusted_variant(source: &Path, amount: f32, out_path: &Path) -> Option<PathBuf> {
+    let bytes = fs::read(source).ok()?;
+    let mut image = decode_cur_image(&bytes)?;
+    let (hotspot_x, hotspot_y) = crate::utils::cursor_parser::parse_cur_click_point(&bytes);
+    adjust_brightness(&mut image, amount);
+
+    let data = crate::cursor_converter::generate_cur_data(&image, hotspot_x, hotspot_y)
+        .map_err(|e| cc_warn!("[CursorChanger] Failed to build adjusted cursor: {}", e))
+        .ok()?;
+    if let Some(parent) = out_path.parent() {
+        fs::create_dir_all(parent).ok()?;
+    }
+    fs::write(out_path, data).ok()?;
+    Some(out_path.to_path_buf())
+}
+
+fn adjust_brightness(image: &mut ImageBuffer<Rgba<u8>, Vec<u8>>, amount: f32) {
+    let amount = amount.clamp(-1.0, 1.0);
+    for pixel in image.pixels_mut() {
+        for channel in &mut pixel.0[..3] {
+            let value = f32::from(*channel);
+            let adjusted = if amount >= 0.0 {
+                value + (255.0 - value) * amount
+            } else {
+                value * (1.0 + amount)
+            };
+            *channel = adjusted.round().clamp(0.0, 255.0) as u8;
+        }
+    }
+}
+
+pub(super) fn decode_cur_image(bytes: &[u8]) -> Option<ImageBuffer<Rgba<u8>, Vec<u8>>> {
+    if let Some(png) = super::preview::extract_embedded_png(bytes) {
+        if let Ok(img) = image::load_from_memory_with_format(&png, image::ImageFormat::Png) {
+            return Some(img.to_rgba8());
+        }
+    }
+
+    super::preview::frame_to_rgba_dib_only(bytes)
+}
+
+pub(super) fn scaled_dimensions(width: u32, height: u32, native_size: u32, target: u32) -> (u32, u32) {
+    let scale = |v: u32| ((u64::from(v) * u64::from(target)) / u64::from(native_size.max(1))).max(1) as u32;
+    (scale(width), scale(height))
+}
+
+pub(super) fn scale_coordinate(value: u16, native_size: u32, target: u32, limit: u32) -> u16 {
+    let scaled = (u64::from(value) * u64::from(target)) / u64::from(native_size.max(1));
+    scaled.min(u64::from(limit.saturating_sub(1))) as u16
+}
+
+/// Delete any pre-scaled variants stored for a library entry.
+pub(super) fn remove_scaled_variants(id: &str) {
+    let Ok(root) = crate::paths::cursor_variants_dir() else {
+        return;
+    };
+    let dir = root.join(id);
+    if dir.exists() {
+        if let Err(e) = fs::remove_dir_all(&dir) {
+            cc_warn!(
+                "[CursorChanger] Failed to delete cursor variants {}: {}",
+                dir.display(),
+                e
+            );
+        }
+    }
+}
+
+/// Pick the pre-scaled file for `size` if the entry has one on disk. Variants written before
+/// pre-generation was turned off are not used.
+pub(crate) fn prescaled_variant_path(cursor: &LibraryCursor, size: i32) -> Option<String> {
+    if !pregenerate_enabled() {
+        return None;
+    }
+    let size = u32::try_from(size).ok()?;
+    if cursor.native_size == Some(size) {
+        return None;
+    }
+
+    cursor
+        .scaled_variants
+        .iter()
+        .find(|v| v.size == size && Path::new(&v.file_path).exists())
+        .map(|v| v.file_path.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cursor_convert::fixtures;
+
+    #[test]
+    fn native_size_treats_zero_as_256() {
+        let bytes = fixtures::cur(256, (0, 0));
+        assert_eq!(bytes[6], 0);
+        assert_eq!(native_size_from_cur_bytes(&bytes), Some(256));
+        assert_eq!(
+            native_size_from_cur_bytes(&fixtures::cur(32, (0, 0))),
+            Some(32)
+        );
+    }
+
+    #[test]
+    fn hotspot_scales_with_target_size() {
+        assert_eq!(scale_coordinate(16, 32, 64, 64), 32);
+        assert_eq!(scale_coordinate(31, 32, 96, 96), 93);
+        assert_eq!(scale_coordinate(255, 256, 48, 48), 47);
+    }
+
+    #[test]
+    fn brightness_moves_towards_white_or_black_and_keeps_alpha() {
+        let mut image = ImageBuffer::from_pixel(1, 1, Rgba([100, 200, 0, 128]));
+        adjust_brightness(&mut image, 0.5);
+        assert_eq!(image.get_pixel(0, 0).0, [178, 228, 128, 128]);
+
+        let mut image = ImageBuffer::from_pixel(1, 1, Rgba([100, 200, 0, 255]));
+        adjust_brightness(&mut image, -0.5);
+        assert_eq!(image.get_pixel(0, 0).0, [50, 100, 0, 255]);
+    }
+
+    #[test]
+    fn pregeneration_is_on_unless_turned_off() {
+        let settings: CursorVariantSettings = serde_json::from_str("{}").unwrap();
+        assert!(settings.pregenerate);
+        assert_eq!(settings, CursorVariantSettings::default());
+    }
+
+    #[test]
+    fn scaled_dimensions_preserve_aspect_ratio() {
+        assert_eq!(scaled_dimensions(32, 32, 32, 48), (48, 48));
+        assert_eq!(scaled_dimensions(256, 128, 256, 64), (64, 32));
+    }
+}
@@ -0,0 +1,476 @@
+//! Migrating cursors from other cursor tools.
+//!
+//! [`detect_migration_sources`] looks for what other tools left on this machine: Stardock
+//! CursorFX / CursorXP themes, YoloMouse custom cursors and classic cursor schemes installed
+//! under `HKCU\Control Panel\Cursors\Schemes`. [`run_migration`] imports one of them into the
+//! library: themes and schemes become packs, YoloMouse cursors become single library cursors.
+//! Anything that could not be carried over is listed in the report instead of failing the run.
+
+use serde::{Deserialize, Serialize};
+use std::io::{Cursor, Write};
+use std::path::{Path, PathBuf};
+use tauri::AppHandle;
+use zip::write::SimpleFileOptions;
+
+use crate::commands::cursor_commands::expand_env_vars;
+use crate::operations::{Operation, OperationPhase};
+
+use super::file_ops::library_integration::add_uploaded_cursor_to_library;
+use super::library::LibraryCursor;
+use super::pack_commands::import_pack_bytes;
+use super::pack_cursorfx::{import_theme, parse_theme};
+
+const CURSORFX_SOURCE_ID: &str = "cursorfx";
+const YOLOMOUSE_SOURCE_ID: &str = "yolomouse";
+const SCHEME_SOURCE_PREFIX: &str = "scheme:";
+
+const CURSORFX_EXTENSIONS: [&str; 2] = ["cursorfx", "curxptheme"];
+const YOLOMOUSE_EXTENSIONS: [&str; 3] = ["cur", "ani", "png"];
+/// How deep below each tool folder to look for files
+const MAX_SCAN_DEPTH: usize = 4;
+
+const SCHEMES_KEY: &str = "Control Panel\\Cursors\\Schemes";
+
+/// Windows cursor name for each position of a scheme value, in the order the Mouse control
+/// panel writes them.
+const SCHEME_VALUE_ORDER: [&str; 17] = [
+    "Normal",
+    "Help",
+    "AppStarting",
+    "Wait",
+    "Cross",
+    "IBeam",
+    "Pen",
+    "No",
+    "SizeNS",
+    "SizeWE",
+    "SizeNWSE",
+    "SizeNESW",
+    "SizeAll",
+    "Up",
+    "Hand",
+    "Pin",
+    "Person",
+];
+
+#[derive(ts_rs::TS, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+#[serde(rename_all = "snake_case")]
+pub enum MigrationSourceKind {
+    CursorFx,
+    YoloMouse,
+    InstalledScheme,
+}
+
+/// Something another tool left behind that can be imported.
+#[derive(ts_rs::TS, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct MigrationSource {
+    /// Passed back to `run_migration`, e.g. `cursorfx` or `scheme:Neon`
+    pub id: String,
+    pub kind: MigrationSourceKind,
+    pub name: String,
+    /// Folders or registry key the items were found in
+    pub locations: Vec<String>,
+    /// Themes, cursor files or scheme cursors found
+    pub item_count: u32,
+}
+
+/// Outcome of `run_migration`.
+#[derive(ts_rs::TS, Debug, Clone, Serialize, Deserialize)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct MigrationReport {
+    pub source: MigrationSource,
+    /// Packs created from themes and schemes
+    pub packs: Vec<LibraryCursor>,
+    /// Single cursors added to the library
+    pub cursors: Vec<LibraryCursor>,
+    /// Items that were skipped, and parts of imported items that were dropped
+    pub warnings: Vec<String>,
+}
+
+fn env_dir(name: &str) -> Option<PathBuf> {
+    std::env::var_os(name)
+        .filter(|value| !value.is_empty())
+        .map(PathBuf::from)
+}
+
+/// Folders Stardock installs CursorFX and CursorXP themes into.
+fn cursorfx_roots() -> Vec<PathBuf> {
+    let mut roots: Vec<PathBuf> = [
+        "ProgramFiles(x86)",
+        "ProgramFiles",
+        "ProgramData",
+        "APPDATA",
+    ]
+    .iter()
+    .filter_map(|name| env_dir(name))
+    .chain(dirs::document_dir())
+    .map(|dir| dir.join("Stardock"))
+    .collect();
+    roots.dedup();
+    roots
+}
+
+/// Folders YoloMouse keeps its custom cursors in.
+fn yolomouse_roots() -> Vec<PathBuf> {
+    let mut roots: Vec<PathBuf> = ["APPDATA", "LOCALAPPDATA"]
+        .iter()
+        .filter_map(|name| env_dir(name))
+        .map(|dir| dir.join("YoloMouse"))
+        .collect();
+    if let Some(dir) = env_dir("ProgramFiles(x86)") {
+        roots.push(dir.join("Steam\\steamapps\\common\\YoloMouse"));
+    }
+    roots
+}
+
+/// Files under `root` with one of `extensions` (lowercase, no dot), at most `depth` folders
+/// down, sorted by path.
+fn find_files(root: &Path, extensions: &[&str], depth: usize) -> Vec<PathBuf> {
+    let mut found = Vec::new();
+    let Ok(entries) = std::fs::read_dir(root) else {
+        return found;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let Ok(file_type) = entry.file_type() else {
+            continue;
+        };
+        if file_type.is_dir() {
+            if depth > 0 {
+                found.extend(find_files(&path, extensions, depth - 1));
+            }
+        } else if path
+            .extension()
+            .and_then(|e| e.to_str())
+            .is_some_and(|ext| extensions.iter().any(|x| ext.eq_ignore_ascii_case(x)))
+        {
+            found.push(path);
+        }
+    }
+    found.sort();
+    found
+}
+
+/// Files found under any of `roots`, with the roots that held at least one.
+fn scan(roots: &[PathBuf], extensions: &[&str]) -> (Vec<PathBuf>, Vec<String>) {
+    let mut files = Vec::new();
+    let mut locations = Vec::new();
+    for root in roots {
+        let found = find_files(root, extensions, MAX_SCAN_DEPTH);
+        if !found.is_empty() {
+            locations.push(root.to_string_lossy().to_string());
+            files.extend(found);
+        }
+    }
+    (files, locations)
+}
+
+fn file_source(
+    id: &str,
+    kind: MigrationSourceKind,
+    name: &str,
+    (files, locations): &(Vec<PathBuf>, Vec<String>),
+) -> Option<MigrationSource> {
+    (!files.is_empty()).then(|| MigrationSource {
+        id: id.to_string(),
+        kind,
+        name: name.to_string(),
+        locations: locations.clone(),
+        item_count: u32::try_from(files.len()).unwrap_or(u32::MAX),
+    })
+}
+
+/// Windows cursor name and file path for each non-empty entry of a scheme value.
+fn scheme_cursor_paths(value: &str) -> Vec<(&'static str, String)> {
+    SCHEME_VALUE_ORDER
+        .iter()
+        .zip(value.split(','))
+        .map(|(name, path)| (*name, path.trim()))
+        .filter(|(_, path)| !path.is_empty())
+        .map(|(name, path)| (name, path.to_string()))
+        .collect()
+}
+
+/// Scheme names and values installed for the current user.
+fn installed_schemes() -> Vec<(String, String)> {
+    use winreg::enums::HKEY_CURRENT_USER;
+    use winreg::RegKey;
+
+    let Ok(key) = RegKey::predef(HKEY_CURRENT_USER).open_subkey(SCHEMES_KEY) else {
+        return Vec::new();
+    };
+    let mut schemes: Vec<(String, String)> = key
+        .enum_values()
+        .flatten()
+        .filter_map(|(name, _)| {
+            let value = key.get_value::<String, _>(&name).ok()?;
+            Some((name, value))
+        })
+        .collect();
+    schemes.sort_by_key(|(name, _)| name.to_lowercase());
+    schemes
+}
+
+fn scheme_source(name: &str, value: &str) -> Option<MigrationSource> {
+    let count = scheme_cursor_paths(value).len();
+    (count > 0).then(|| MigrationSource {
+        id: format!("{SCHEME_SOURCE_PREFIX}{name}"),
+        kind: MigrationSourceKind::InstalledScheme,
+        name: name.to_string(),
+        locations: vec![format!("HKEY_CURRENT_USER\\{SCHEMES_KEY}")],
+        item_count: count as u32,
+    })
+}
+
+/// List the other cursor tools' themes, cursors and schemes found on this machine.
+#[tauri::command]
+pub fn detect_migration_sources() -> Result<Vec<MigrationSource>, String> {
+    let mut sources = Vec::new();
+    sources.extend(file_source(
+        CURSORFX_SOURCE_ID,
+        MigrationSourceKind::CursorFx,
+        "CursorFX themes",
+        &scan(&cursorfx_roots(), &CURSORFX_EXTENSIONS),
+    ));
+    sources.extend(file_source(
+        YOLOMOUSE_SOURCE_ID,
+        MigrationSourceKind::YoloMouse,
+        "YoloMouse cursors",
+        &scan(&yolomouse_roots(), &YOLOMOUSE_EXTENSIONS),
+    ));
+    sources.extend(
+        installed_schemes()
+            .iter()
+            .filter_map(|(name, value)| scheme_source(name, value)),
+    );
+    Ok(sources)
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default()
+}
+
+fn migrate_cursorfx(
+    app: &AppHandle,
+    operation: &Operation<tauri::Wry>,
+    report: &mut MigrationReport,
+) -> Result<(), String> {
+    let (files, _) = scan(&cursorfx_roots(), &CURSORFX_EXTENSIONS);
+    for path in files {
+        operation.checkpoint()?;
+        let filename = file_name(&path);
+        let imported = std::fs::read(&path)
+            .map_err(|e| format!("Failed to read theme: {e}"))
+            .and_then(|data| parse_theme(&data))
+            .and_then(|theme| import_theme(app, operation, &filename, theme));
+        match imported {
+            Ok(import) => {
+                report.warnings.extend(
+                    import
+                        .warnings
+                        .into_iter()
+                        .map(|w| format!("{filename}: {w}")),
+                );
+                report.packs.push(import.pack);
+            }
+            Err(e) => report.warnings.push(format!("{filename}: {e}")),
+        }
+    }
+    Ok(())
+}
+
+fn migrate_yolomouse(
+    app: &AppHandle,
+    operation: &Operation<tauri::Wry>,
+    report: &mut MigrationReport,
+) -> Result<(), String> {
+    let (files, _) = scan(&yolomouse_roots(), &YOLOMOUSE_EXTENSIONS);
+    let mut saving = operation.phase(OperationPhase::Saving, files.len());
+    for path in files {
+        operation.checkpoint()?;
+        let filename = file_name(&path);
+        let added = std::fs::read(&path)
+            .map_err(|e| format!("Failed to read cursor: {e}"))
+            .and_then(|data| add_uploaded_cursor_to_library(app.clone(), filename.clone(), data));
+        match added {
+            Ok(cursor) => report.cursors.push(cursor),
+            Err(e) => report.warnings.push(format!("{filename}: {e}")),
+        }
+        saving.advance(Some(&filename));
+    }
+    saving.finish();
+    report.warnings.push(
+        "YoloMouse per-game cursor assignments are not migrated; apply the imported cursors \
+         from the library instead"
+            .to_string(),
+    );
+    Ok(())
+}
+
+/// Pack zip holding the scheme's cursor files under this app's base names.
+fn build_scheme_zip(
+    paths: &[(&'static str, String)],
+    warnings: &mut Vec<String>,
+) -> Result<Vec<u8>, String> {
+    let mut writer = zip::ZipWriter::new(Cursor::new(Vec::new()));
+    let options = SimpleFileOptions::default();
+    for (windows_name, raw_path) in paths {
+        let Some(base_name) = cursor_changer::get_default_cursor_base_name(windows_name) else {
+            continue;
+        };
+        let path = PathBuf::from(expand_env_vars(raw_path));
+        let ext = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .map(str::to_lowercase)
+            .unwrap_or_default();
+        if ext != "cur" && ext != "ani" {
+            warnings.push(format!(
+                "{windows_name}: skipped unsupported file {raw_path}"
+            ));
+            continue;
+        }
+        let bytes = match std::fs::read(&path) {
+            Ok(bytes) => bytes,
+            Err(e) => {
+                warnings.push(format!("{windows_name}: could not read {raw_path}: {e}"));
+                continue;
+            }
+        };
+        let entry = format!("{base_name}.{ext}");
+        writer
+            .start_file(entry.as_str(), options)
+            .map_err(|e| format!("Failed to write {entry}: {e}"))?;
+        writer
+            .write_all(&bytes)
+            .map_err(|e| format!("Failed to write {entry}: {e}"))?;
+    }
+    writer
+        .finish()
+        .map(Cursor::into_inner)
+        .map_err(|e| format!("Failed to build cursor pack: {e}"))
+}
+
+fn migrate_scheme(
+    app: &AppHandle,
+    operation: &Operation<tauri::Wry>,
+    name: &str,
+    report: &mut MigrationReport,
+) -> Result<(), String> {
+    let value = installed_schemes()
+        .into_iter()
+        .find(|(scheme, _)| scheme == name)
+        .map(|(_, value)| value)
+        .ok_or_else(|| format!("Cursor scheme '{name}' is no longer installed"))?;
+
+    let pack_zip = build_scheme_zip(&scheme_cursor_paths(&value), &mut report.warnings)?;
+    let pack = import_pack_bytes(app, operation, &format!("{name}.zip"), &pack_zip)?;
+    report.packs.push(pack);
+    Ok(())
+}
+
+/// Import everything from one source returned by `detect_migration_sources`.
+///
+/// Themes and cursors that fail to convert are listed in the report's warnings; the call
+/// only fails when the source itself is gone or the run is cancelled through `op_id`.
+#[tauri::command]
+pub fn run_migration(
+    app: AppHandle,
+    source: String,
+    op_id: Option<String>,
+) -> Result<MigrationReport, String> {
+    let detected = detect_migration_sources()?
+        .into_iter()
+        .find(|s| s.id == source)
+        .ok_or_else(|| format!("Migration source '{source}' was not found"))?;
+    let operation = Operation::begin(&app, op_id);
+    let mut report = MigrationReport {
+        source: detected.clone(),
+        packs: Vec::new(),
+        cursors: Vec::new(),
+        warnings: Vec::new(),
+    };
+
+    match detected.kind {
+        MigrationSourceKind::CursorFx => migrate_cursorfx(&app, &operation, &mut report)?,
+        MigrationSourceKind::YoloMouse => migrate_yolomouse(&app, &operation, &mut report)?,
+        MigrationSourceKind::InstalledScheme => {
+            let name = &detected.id[SCHEME_SOURCE_PREFIX.len()..];
+            migrate_scheme(&app, &operation, name, &mut report)?;
+        }
+    }
+
+    for warning in &report.warnings {
+        cc_info!("[CursorChanger] Migration {}: {}", detected.id, warning);
+    }
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scheme_values_map_to_windows_cursor_names() {
+        let paths = scheme_cursor_paths(
+            "%SystemRoot%\\cursors\\arrow.cur,,C:\\x\\working.ani, , , ,,,,,,,,,C:\\x\\link.cur",
+        );
+        assert_eq!(
+            paths,
+            vec![
+                ("Normal", "%SystemRoot%\\cursors\\arrow.cur".to_string()),
+                ("AppStarting", "C:\\x\\working.ani".to_string()),
+                ("Hand", "C:\\x\\link.cur".to_string()),
+            ]
+        );
+        assert!(scheme_cursor_paths("").is_empty());
+        assert!(scheme_source("Empty", ",,,").is_none());
+
+        let source = scheme_source("Neon", "a.cur,b.cur").expect("source");
+        assert_eq!(source.id, "scheme:Neon");
+        assert_eq!(source.item_count, 2);
+    }
+
+    #[test]
+    fn finds_tool_files_by_extension_within_depth() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let themes = dir.path().join("CursorFX").join("Themes");
+        std::fs::create_dir_all(themes.join("Deep").join("Deeper")).unwrap();
+        std::fs::write(themes.join("Neon.CursorFX"), b"x").unwrap();
+        std::fs::write(themes.join("Old.curxptheme"), b"x").unwrap();
+        std::fs::write(themes.join("readme.txt"), b"x").unwrap();
+        std::fs::write(
+            themes.join("Deep").join("Deeper").join("Far.CursorFX"),
+            b"x",
+        )
+        .unwrap();
+
+        let names = |files: Vec<PathBuf>| files.iter().map(|p| file_name(p)).collect::<Vec<_>>();
+        assert_eq!(
+            names(find_files(dir.path(), &CURSORFX_EXTENSIONS, 2)),
+            ["Neon.CursorFX", "Old.curxptheme"]
+        );
+        assert_eq!(
+            names(find_files(dir.path(), &CURSORFX_EXTENSIONS, 4)),
+            ["Far.CursorFX", "Neon.CursorFX", "Old.curxptheme"]
+        );
+
+        let (files, locations) = scan(
+            &[dir.path().join("missing"), dir.path().to_path_buf()],
+            &CURSORFX_EXTENSIONS,
+        );
+        assert_eq!(files.len(), 3);
+        assert_eq!(locations, [dir.path().to_string_lossy().to_string()]);
+        assert!(file_source(
+            "cursorfx",
+            MigrationSourceKind::CursorFx,
+            "x",
+            &(vec![], vec![])
+        )
+        .is_none());
+    }
+}
@@ -5,11 +5,24 @@
 pub(super) mod cursor_apply_service;
 pub(super) mod cursor_preview_resolver;
 pub mod file_ops;
+pub mod migration;
 pub mod query;
+pub mod pack_cape;
 pub mod pack_commands;
+pub mod pack_cursorfx;
 pub mod pack_export;
+pub mod pack_import_url;
+pub mod pack_integrity;
 pub mod pack_library;
 pub mod pack_manifest;
+pub mod pack_rating;
+pub mod pack_schedule;
+pub mod pack_sounds;
+pub(crate) mod pack_sprite;
+pub(crate) mod pack_mount;
+pub mod pack_trial;
+pub mod pack_updates;
+pub mod role_groups;
 pub mod set_cursor_bulk;
 pub mod set_cursor_core;
 pub mod set_cursor_focus;
@@ -0,0 +1,399 @@
+//! Importing macOS Mousecape `.cape` files as cursor packs.
+//!
+//! A cape is an XML property list. Its `Cursors` dictionary maps macOS cursor identifiers
+//! (`com.apple.coregraphics.Arrow`, `com.apple.cursor.13`, ...) to a dictionary holding
+//! `FrameCount`, `FrameDuration` (seconds), `HotSpotX`/`HotSpotY` and `PointsWide`/`PointsHigh`
+//! in points, and `Representations`: PNG or TIFF images of the cursor at several scales.
+//! Animated cursors stack their frames top to bottom in every representation.
+//!
+//! The largest representation that fits the .cur size limit is used, with the hotspot scaled
+//! from points to its pixels. Cursors are then converted like CursorFX themes (see
+//! [`pack_cursorfx`](super::pack_cursorfx)). macOS cursors without a Windows role are
+//! skipped and counted in one warning.
+
+use base64::Engine;
+use image::{imageops, RgbaImage};
+use tauri::{AppHandle, Runtime};
+
+use crate::operations::Operation;
+
+use super::pack_cursorfx::{
+    display_name_for, import_theme, CursorThemeImport, ParsedTheme, ThemeCursor,
+    DEFAULT_FRAME_DELAY_MS,
+};
+
+/// Windows cursor name for each macOS cursor identifier. When several identifiers map to one
+/// role, the first one present in the cape wins.
+const CAPE_CURSOR_ROLES: [(&str, &str); 17] = [
+    ("com.apple.coregraphics.Arrow", "Normal"),
+    ("com.apple.cursor.40", "Help"),
+    ("com.apple.cursor.4", "AppStarting"),
+    ("com.apple.coregraphics.Wait", "Wait"),
+    ("com.apple.cursor.7", "Cross"),
+    ("com.apple.coregraphics.IBeam", "IBeam"),
+    ("com.apple.cursor.3", "No"),
+    ("com.apple.cursor.28", "SizeNS"),
+    ("com.apple.cursor.19", "SizeNS"),
+    ("com.apple.cursor.24", "SizeWE"),
+    ("com.apple.cursor.16", "SizeWE"),
+    ("com.apple.cursor.30", "SizeNWSE"),
+    ("com.apple.cursor.26", "SizeNESW"),
+    ("com.apple.coregraphics.Move", "SizeAll"),
+    ("com.apple.cursor.11", "SizeAll"),
+    ("com.apple.cursor.13", "Hand"),
+    ("com.apple.cursor.12", "Hand"),
+];
+
+/// The subset of property list values a cape uses
+#[derive(Debug, Clone, PartialEq)]
+enum PlistValue {
+    Dict(Vec<(String, PlistValue)>),
+    Array(Vec<PlistValue>),
+    String(String),
+    Data(Vec<u8>),
+    Number(f64),
+    Bool(bool),
+}
+
+impl PlistValue {
+    fn get(&self, key: &str) -> Option<&PlistValue> {
+        match self {
+            PlistValue::Dict(entries) => entries.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    fn number(&self, key: &str) -> Option<f64> {
+        match self.get(key)? {
+            PlistValue::Number(n) if n.is_finite() => Some(*n),
+            _ => None,
+        }
+    }
+}
+
+fn parse_plist_value(node: roxmltree::Node) -> Result<PlistValue, String> {
+    let text = || node.text().unwrap_or_default().trim();
+    let number = |value: &str| {
+        value
+            .parse::<f64>()
+            .map(PlistValue::Number)
+            .map_err(|_| format!("Invalid number in cape: {value}"))
+    };
+    match node.tag_name().name() {
+        "dict" => {
+            let mut entries = Vec::new();
+            let mut children = node.children().filter(roxmltree::Node::is_element);
+            while let Some(key) = children.next() {
+                if key.tag_name().name() != "key" {
+                    return Err("Cape dictionary is missing a key".to_string());
+                }
+                let value = children
+                    .next()
+                    .ok_or_else(|| "Cape dictionary key has no value".to_string())?;
+                entries.push((
+                    key.text().unwrap_or_default().to_string(),
+                    parse_plist_value(value)?,
+                ));
+            }
+            Ok(PlistValue::Dict(entries))
+        }
+        "array" => node
+            .children()
+            .filter(roxmltree::Node::is_element)
+            .map(parse_plist_value)
+            .collect::<Result<_, _>>()
+            .map(PlistValue::Array),
+        "string" | "date" => Ok(PlistValue::String(text().to_string())),
+        "data" => {
+            let encoded: String = text().split_ascii_whitespace().collect();
+            base64::engine::general_purpose::STANDARD
+                .decode(encoded)
+                .map(PlistValue::Data)
+                .map_err(|e| format!("Invalid image data in cape: {e}"))
+        }
+        "real" | "integer" => number(text()),
+        "true" => Ok(PlistValue::Bool(true)),
+        "false" => Ok(PlistValue::Bool(false)),
+        other => Err(format!("Unsupported value <{other}> in cape")),
+    }
+}
+
+/// Parse an XML property list into its top-level value.
+fn parse_plist(data: &[u8]) -> Result<PlistValue, String> {
+    if data.starts_with(b"bplist") {
+        return Err(
+            "Binary .cape files are not supported; save the cape from Mousecape again".to_string(),
+        );
+    }
+    let text = std::str::from_utf8(data).map_err(|_| "Not a Mousecape cape file".to_string())?;
+    let options = roxmltree::ParsingOptions {
+        allow_dtd: true,
+        ..roxmltree::ParsingOptions::default()
+    };
+    let doc = roxmltree::Document::parse_with_options(text, options)
+        .map_err(|e| format!("Not a Mousecape cape file: {e}"))?;
+    let root = doc.root_element();
+    if root.tag_name().name() != "plist" {
+        return Err("Not a Mousecape cape file".to_string());
+    }
+    let value = root
+        .children()
+        .find(roxmltree::Node::is_element)
+        .ok_or_else(|| "Cape file is empty".to_string())?;
+    parse_plist_value(value)
+}
+
+/// Split an image holding `frame_count` frames stacked top to bottom.
+fn split_column(image: &RgbaImage, frame_count: u32) -> Vec<RgbaImage> {
+    let frame_height = image.height() / frame_count;
+    (0..frame_count)
+        .map(|i| {
+            imageops::crop_imm(image, 0, i * frame_height, image.width(), frame_height).to_image()
+        })
+        .collect()
+}
+
+/// Convert one cape cursor dictionary.
+fn parse_cape_cursor(
+    windows_name: &'static str,
+    cursor: &PlistValue,
+) -> Result<ThemeCursor, String> {
+    let display = display_name_for(windows_name);
+    let frame_count = cursor
+        .number("FrameCount")
+        .map_or(1, |n| n.clamp(1.0, 1024.0) as u32);
+
+    let Some(PlistValue::Array(representations)) = cursor.get("Representations") else {
+        return Err(format!("{display}: skipped, the cape has no images for it"));
+    };
+    let images: Vec<RgbaImage> = representations
+        .iter()
+        .filter_map(|rep| match rep {
+            PlistValue::Data(bytes) => image::load_from_memory(bytes).ok(),
+            _ => None,
+        })
+        .map(|img| img.to_rgba8())
+        .filter(|img| img.width() > 0 && img.height() >= frame_count)
+        .collect();
+    let limit = cursor_convert::MAX_CURSOR_SIZE;
+    let image = images
+        .iter()
+        .filter(|img| img.width() <= limit && img.height() / frame_count <= limit)
+        .max_by_key(|img| img.width())
+        .ok_or_else(|| {
+            if images.is_empty() {
+                format!("{display}: skipped, its images could not be read")
+            } else {
+                format!("{display}: skipped, images are larger than the {limit}px cursor limit")
+            }
+        })?;
+
+    let frames = split_column(image, frame_count);
+    let (width, height) = frames[0].dimensions();
+    // Hotspots are in points; representations are drawn at 1x, 2x, ... of the point size
+    let scale = cursor
+        .number("PointsWide")
+        .filter(|&points| points > 0.0)
+        .map_or(1.0, |points| f64::from(width) / points);
+    let hotspot = |key: &str, max: u32| {
+        let value = cursor.number(key).unwrap_or(0.0) * scale;
+        value.round().clamp(0.0, f64::from(max.saturating_sub(1))) as u16
+    };
+
+    Ok(ThemeCursor {
+        windows_name,
+        hotspot: (hotspot("HotSpotX", width), hotspot("HotSpotY", height)),
+        frame_delay_ms: cursor
+            .number("FrameDuration")
+            .filter(|&seconds| seconds > 0.0)
+            .map_or(DEFAULT_FRAME_DELAY_MS, |seconds| {
+                (seconds * 1000.0).round() as u32
+            }),
+        frames,
+    })
+}
+
+fn parse_cape(data: &[u8]) -> Result<ParsedTheme, String> {
+    let plist = parse_plist(data)?;
+    let Some(PlistValue::Dict(cursors)) = plist.get("Cursors") else {
+        return Err("Cape file has no cursors".to_string());
+    };
+
+    let mut theme = ParsedTheme::default();
+    let mut taken: Vec<&str> = Vec::new();
+    for (identifier, windows_name) in CAPE_CURSOR_ROLES {
+        if taken.contains(&windows_name) {
+            continue;
+        }
+        let Some((_, cursor)) = cursors.iter().find(|(id, _)| id == identifier) else {
+            continue;
+        };
+        taken.push(windows_name);
+        match parse_cape_cursor(windows_name, cursor) {
+            Ok(cursor) => theme.cursors.push(cursor),
+            Err(e) => theme.warnings.push(e),
+        }
+    }
+
+    let unmapped = cursors
+        .iter()
+        .filter(|(id, _)| !CAPE_CURSOR_ROLES.iter().any(|(known, _)| known == id))
+        .count();
+    if unmapped > 0 {
+        theme.warnings.push(format!(
+            "{unmapped} macOS cursor(s) have no Windows equivalent and were skipped"
+        ));
+    }
+    Ok(theme)
+}
+
+/// Convert a Mousecape `.cape` file to a cursor pack and add it to the library.
+///
+/// The cape must provide at least the Arrow and Pointing Hand cursors, which become the
+/// Normal and Link cursors every pack needs.
+#[tauri::command]
+pub fn import_cape<R: Runtime>(
+    app: AppHandle<R>,
+    filename: String,
+    data: Vec<u8>,
+    op_id: Option<String>,
+) -> Result<CursorThemeImport, String> {
+    let operation = Operation::begin(&app, op_id);
+    import_theme(&app, &operation, &filename, parse_cape(&data)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::Rgba;
+    use std::io::Cursor;
+
+    /// `frames` frames of `size` px stacked top to bottom, each row shaded by frame.
+    fn png_column(size: u32, frames: u32) -> String {
+        let image = RgbaImage::from_fn(size, size * frames, |_, y| {
+            Rgba([(y / size) as u8 * 40, 0, 255, 255])
+        });
+        let mut out = Cursor::new(Vec::new());
+        image
+            .write_to(&mut out, image::ImageFormat::Png)
+            .expect("encode png");
+        base64::engine::general_purpose::STANDARD.encode(out.into_inner())
+    }
+
+    fn cursor_dict(frames: u32, duration: f64, hotspot: (f64, f64), reps: &[String]) -> String {
+        let data: String = reps
+            .iter()
+            .map(|r| format!("<data>\n\t{r}\n</data>"))
+            .collect();
+        format!(
+            "<dict><key>FrameCount</key><integer>{frames}</integer>\
+             <key>FrameDuration</key><real>{duration}</real>\
+             <key>HotSpotX</key><real>{}</real><key>HotSpotY</key><real>{}</real>\
+             <key>PointsHigh</key><real>32</real><key>PointsWide</key><real>32</real>\
+             <key>Representations</key><array>{data}</array></dict>",
+            hotspot.0, hotspot.1
+        )
+    }
+
+    fn cape(cursors: &[(&str, String)]) -> Vec<u8> {
+        let entries: String = cursors
+            .iter()
+            .map(|(id, dict)| format!("<key>{id}</key>{dict}"))
+            .collect();
+        format!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+             <!DOCTYPE plist PUBLIC \"-//Apple//DTD PLIST 1.0//EN\" \
+             \"http://www.apple.com/DTDs/PropertyList-1.0.dtd\">\n\
+             <plist version=\"1.0\"><dict>\
+             <key>Author</key><string>Someone</string>\
+             <key>CapeName</key><string>Neon</string>\
+             <key>Cloud</key><false/>\
+             <key>Cursors</key><dict>{entries}</dict>\
+             <key>HiDPI</key><true/>\
+             </dict></plist>"
+        )
+        .into_bytes()
+    }
+
+    #[test]
+    fn cape_cursors_map_to_windows_roles_with_scaled_hotspots() {
+        let data = cape(&[
+            (
+                "com.apple.coregraphics.Arrow",
+                cursor_dict(1, 1.0, (4.0, 5.0), &[png_column(32, 1), png_column(64, 1)]),
+            ),
+            (
+                "com.apple.coregraphics.Wait",
+                cursor_dict(3, 0.05, (16.0, 16.0), &[png_column(32, 3)]),
+            ),
+            (
+                "com.apple.cursor.12",
+                cursor_dict(1, 1.0, (0.0, 0.0), &[png_column(32, 1)]),
+            ),
+            (
+                "com.apple.cursor.13",
+                cursor_dict(1, 1.0, (9.0, 2.0), &[png_column(32, 1)]),
+            ),
+            (
+                "com.apple.cursor.21",
+                cursor_dict(1, 1.0, (0.0, 0.0), &[png_column(32, 1)]),
+            ),
+        ]);
+        let theme = parse_cape(&data).expect("parse");
+
+        let roles: Vec<&str> = theme.cursors.iter().map(|c| c.windows_name).collect();
+        assert_eq!(roles, ["Normal", "Wait", "Hand"]);
+
+        // The 2x representation was picked and the hotspot doubled with it
+        let normal = &theme.cursors[0];
+        assert_eq!(normal.frames[0].dimensions(), (64, 64));
+        assert_eq!(normal.hotspot, (8, 10));
+
+        let wait = &theme.cursors[1];
+        assert_eq!(wait.frames.len(), 3);
+        assert_eq!(wait.frames[0].dimensions(), (32, 32));
+        assert_eq!(wait.frames[2].get_pixel(0, 0)[0], 80);
+        assert_eq!(wait.frame_delay_ms, 50);
+
+        // Pointing hand wins over the open hand listed after it
+        assert_eq!(theme.cursors[2].hotspot, (9, 2));
+
+        assert_eq!(theme.warnings.len(), 1, "{:?}", theme.warnings);
+        assert!(theme.warnings[0].starts_with("1 macOS cursor(s)"));
+    }
+
+    #[test]
+    fn unreadable_and_oversized_cursors_are_skipped_with_warnings() {
+        let data = cape(&[
+            (
+                "com.apple.coregraphics.Arrow",
+                cursor_dict(1, 1.0, (0.0, 0.0), &[png_column(300, 1)]),
+            ),
+            (
+                "com.apple.coregraphics.IBeam",
+                cursor_dict(1, 1.0, (0.0, 0.0), &["aGVsbG8=".to_string()]),
+            ),
+        ]);
+        let theme = parse_cape(&data).expect("parse");
+
+        assert!(theme.cursors.is_empty());
+        assert!(
+            theme.warnings[0].contains("larger than"),
+            "{:?}",
+            theme.warnings
+        );
+        assert!(
+            theme.warnings[1].contains("could not be read"),
+            "{:?}",
+            theme.warnings
+        );
+    }
+
+    #[test]
+    fn non_cape_input_is_rejected() {
+        assert!(matches!(parse_cape(b"bplist00"), Err(e) if e.contains("Binary")));
+        assert!(parse_cape(b"not xml").is_err());
+        assert!(parse_cape(b"<html></html>").is_err());
+        assert!(parse_cape(&cape(&[])).unwrap().cursors.is_empty());
+    }
+}
@@ -8,20 +8,34 @@ use tauri::{AppHandle, Runtime, State};
 use zip::ZipArchive;
 
 use crate::commands::command_helpers;
+use crate::commands::cursor_commands::check_custom_cursors_allowed;
+use crate::error_codes::{with_code, ErrorCode};
+use crate::operations::{Operation, OperationPhase};
+use crate::pending_jobs::{self, PendingJobKind};
 use crate::state::{AppState, CustomizationMode};
 
 use super::library::{
-    get_cursor_preview_from_bytes, load_library, LibraryCursor, LibraryPackItem,
+    get_cursor_preview_from_bytes, load_library, read_ani_info, LibraryCursor, LibraryPackItem,
 };
 use super::pack_library::{
     ensure_pack_previews, prepare_pack_archive_destination,
     register_pack_in_library,
 };
-use super::pack_manifest::{CursorPackManifest, PACK_MANIFEST_FILENAME};
+use super::pack_manifest::{
+    merge_attribution, read_manifest, read_manifest_from_path, CursorPackManifest,
+    PackChangelogEntry, PackSoundEvent, PackSounds, MAX_PACK_SOUND_BYTES, PACK_MANIFEST_FILENAME,
+};
+use super::pack_mount::{lock_mounts, mount_pack_roles, prune_unreferenced};
 
-fn allowed_pack_base_names() -> HashSet<&'static str> {
+/// Windows cursor name and pack base name of every role a pack may carry, core roles first.
+fn pack_base_names() -> impl Iterator<Item = &'static (&'static str, &'static str)> {
     cursor_changer::DEFAULT_CURSOR_BASE_NAMES
         .iter()
+        .chain(cursor_changer::EXTENDED_CURSOR_BASE_NAMES.iter())
+}
+
+fn allowed_pack_base_names() -> HashSet<&'static str> {
+    pack_base_names()
         .map(|(_windows_name, base_name)| *base_name)
         .collect()
 }
@@ -30,17 +44,56 @@ fn cursor_pack_required_base_names() -> [&'static str; 2] {
     ["normal-select", "link-select"]
 }
 
+/// How strictly a pack archive is checked.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PackValidationMode {
+    /// Only cursor files named after their role, at the top of the archive
+    #[default]
+    Strict,
+    /// Skip folders, readme and license files and unrecognized cursors with a warning
+    Lenient,
+}
+
+#[derive(Debug, Default)]
+pub(super) struct ValidatedPack {
+    pub(super) items: Vec<LibraryPackItem>,
+    pub(super) warnings: Vec<String>,
+}
+
+impl ValidatedPack {
+    /// Fail in strict mode, or record `message` and skip the entry in lenient mode.
+    fn reject(&mut self, mode: PackValidationMode, message: String) -> Result<(), String> {
+        match mode {
+            PackValidationMode::Strict => Err(message),
+            PackValidationMode::Lenient => {
+                self.warnings.push(message);
+                Ok(())
+            }
+        }
+    }
+}
+
 fn validate_cursor_pack_archive<R: Read + Seek>(
     archive: &mut ZipArchive<R>,
-) -> Result<Vec<LibraryPackItem>, String> {
+    mode: PackValidationMode,
+) -> Result<ValidatedPack, String> {
     let allowed = allowed_pack_base_names();
     let required = cursor_pack_required_base_names();
+    let max_files = allowed.len();
 
+    let mut validated = ValidatedPack::default();
     let mut total_files = 0usize;
+    let mut in_folders = false;
     let mut by_base_name: HashMap<String, String> = HashMap::new();
+    let mut authors: HashMap<String, String> = HashMap::new();
+    let manifest = read_manifest(archive);
+    let sound_files: Vec<&str> = manifest
+        .as_ref()
+        .map(|m| m.sounds.file_names().collect())
+        .unwrap_or_default();
 
     for i in 0..archive.len() {
-        let entry = archive
+        let mut entry = archive
             .by_index(i)
             .map_err(|e| format!("Failed to read archive entry: {e}"))?;
 
@@ -49,21 +102,39 @@ fn validate_cursor_pack_archive<R: Read + Seek>(
         }
 
         let name_in_zip = entry.name().to_string();
+        if name_in_zip.eq_ignore_ascii_case(PACK_MANIFEST_FILENAME) {
+            continue;
+        }
+        if sound_files
+            .iter()
+            .any(|sound| sound.eq_ignore_ascii_case(&name_in_zip))
+        {
+            let is_wav = name_in_zip.to_ascii_lowercase().ends_with(".wav");
+            if !is_wav || entry.size() > MAX_PACK_SOUND_BYTES {
+                validated.reject(
+                    mode,
+                    format!(
+                        "Skipped {name_in_zip}: pack sounds must be .wav files of at most {} KB",
+                        MAX_PACK_SOUND_BYTES / 1024
+                    ),
+                )?;
+            }
+            continue;
+        }
         if name_in_zip.contains('/') || name_in_zip.contains('\\') {
-            return Err("Cursor pack zip must not contain folders".to_string());
+            if mode == PackValidationMode::Strict {
+                return Err("Cursor pack zip must not contain folders".to_string());
+            }
+            // Items keep the full name in the zip, so the entry can be found again
+            in_folders = true;
         }
 
-        let file_name = Path::new(&name_in_zip)
+        let file_name = Path::new(&name_in_zip.replace('\\', "/"))
             .file_name()
             .and_then(|s| s.to_str())
             .ok_or_else(|| "Cursor pack zip contains invalid filename".to_string())?
             .to_string();
 
-        total_files += 1;
-        if total_files > 15 {
-            return Err("Cursor pack zip contains more than 15 files".to_string());
-        }
-
         let ext = Path::new(&file_name)
             .extension()
             .and_then(|s| s.to_str())
@@ -71,7 +142,21 @@ fn validate_cursor_pack_archive<R: Read + Seek>(
             .to_ascii_lowercase();
 
         if ext != "cur" && ext != "ani" {
-            return Err("Cursor pack zip must contain only .cur or .ani files".to_string());
+            validated.reject(
+                mode,
+                format!(
+                    "Skipped {name_in_zip}: cursor pack zip must contain only .cur or .ani files"
+                ),
+            )?;
+            continue;
+        }
+
+        // Only cursor files count towards the limit, so extra files never push a pack over it
+        total_files += 1;
+        if total_files > max_files {
+            return Err(format!(
+                "Cursor pack zip contains more than {max_files} files"
+            ));
         }
 
         let stem = Path::new(&file_name)
@@ -84,20 +169,38 @@ fn validate_cursor_pack_archive<R: Read + Seek>(
         }
 
         if !allowed.contains(stem.as_str()) {
-            return Err(format!(
-                "Cursor pack zip contains an invalid cursor name: {}",
-                stem
-            ));
+            validated.reject(
+                mode,
+                format!("Cursor pack zip contains an invalid cursor name: {}", stem),
+            )?;
+            continue;
         }
 
         if by_base_name.contains_key(&stem) {
-            return Err(format!(
-                "Cursor pack zip contains duplicate cursor for: {}",
-                stem
-            ));
+            validated.reject(
+                mode,
+                format!("Cursor pack zip contains duplicate cursor for: {}", stem),
+            )?;
+            continue;
+        }
+
+        // Credit the author an animated cursor names in its INFO list
+        if ext == "ani" {
+            let mut data = Vec::new();
+            if entry.read_to_end(&mut data).is_ok() {
+                if let Some(author) = read_ani_info(&data).author {
+                    authors.insert(stem.clone(), author);
+                }
+            }
         }
 
-        by_base_name.insert(stem, file_name);
+        by_base_name.insert(stem, name_in_zip);
+    }
+
+    if in_folders {
+        validated
+            .warnings
+            .push("Cursor pack zip keeps its cursors in folders".to_string());
     }
 
     for req in required {
@@ -109,13 +212,11 @@ fn validate_cursor_pack_archive<R: Read + Seek>(
         }
     }
 
-    // Build items in stable order defined by DEFAULT_CURSOR_BASE_NAMES
+    // Build items in stable order defined by DEFAULT_CURSOR_BASE_NAMES, then the extended roles
     let mut items: Vec<LibraryPackItem> = Vec::new();
-    for (windows_name, base_name) in cursor_changer::DEFAULT_CURSOR_BASE_NAMES.iter() {
+    for (windows_name, base_name) in pack_base_names() {
         if let Some(file_name) = by_base_name.get(&base_name.to_string()) {
-            let display_name = cursor_changer::CURSOR_TYPES
-                .iter()
-                .find(|ct| ct.name == *windows_name)
+            let display_name = cursor_changer::find_cursor_type(windows_name)
                 .map(|ct| ct.display_name.to_string())
                 .unwrap_or_else(|| (*base_name).to_string());
 
@@ -124,6 +225,8 @@ fn validate_cursor_pack_archive<R: Read + Seek>(
                 display_name,
                 file_name: file_name.clone(),
                 file_path: None,
+                author: authors.get(*base_name).cloned(),
+                ..Default::default()
             });
         }
     }
@@ -132,22 +235,33 @@ fn validate_cursor_pack_archive<R: Read + Seek>(
         return Err("Cursor pack zip contains no valid cursor files".to_string());
     }
 
-    Ok(items)
+    if let Some(manifest) = &manifest {
+        merge_attribution(&mut items, manifest);
+    }
+
+    validated.items = items;
+    Ok(validated)
 }
 
-fn validate_cursor_pack_bytes(data: &[u8]) -> Result<Vec<LibraryPackItem>, String> {
+pub(super) fn validate_cursor_pack_bytes(
+    data: &[u8],
+    mode: PackValidationMode,
+) -> Result<ValidatedPack, String> {
     let cursor = std::io::Cursor::new(data);
     let mut archive = ZipArchive::new(cursor)
         .map_err(|e| format!("Failed to read archive contents: {e}"))?;
-    validate_cursor_pack_archive(&mut archive)
+    validate_cursor_pack_archive(&mut archive, mode)
 }
 
-fn validate_cursor_pack_path(archive_path: &Path) -> Result<Vec<LibraryPackItem>, String> {
+fn validate_cursor_pack_path(
+    archive_path: &Path,
+    mode: PackValidationMode,
+) -> Result<ValidatedPack, String> {
     let file = fs::File::open(archive_path)
         .map_err(|e| format!("Failed to open pack archive: {e}"))?;
     let mut archive = ZipArchive::new(file)
         .map_err(|e| format!("Failed to read archive contents: {e}"))?;
-    validate_cursor_pack_archive(&mut archive)
+    validate_cursor_pack_archive(&mut archive, mode)
 }
 
 fn is_zip(path: &Path) -> bool {
@@ -190,6 +304,10 @@ pub(crate) fn extract_pack_assets(
     let mut archive =
         ZipArchive::new(file).map_err(|e| format!("Failed to read archive contents: {e}"))?;
 
+    let item_names: Vec<&str> = manifest.items.iter().map(|i| i.file_name.as_str()).collect();
+    let needed = crate::disk_space::zip_uncompressed_size(&mut archive, Some(&item_names));
+    crate::disk_space::ensure_space(&extract_folder, needed)?;
+
     let mut extracted = HashMap::new();
 
     for item in &manifest.items {
@@ -219,8 +337,12 @@ pub(crate) fn extract_pack_assets(
 
 
 
+/// Manifest of the pack at `archive_path`, inferred from its file names when it has none.
+/// Packs already in the library are read with [`PackValidationMode::Lenient`], which accepts
+/// every pack strict mode does and describes it the same way.
 pub(crate) fn read_manifest_or_infer(
     archive_path: &Path,
+    mode: PackValidationMode,
 ) -> Result<CursorPackManifest, String> {
     let pack_name = archive_path
         .file_stem()
@@ -228,7 +350,14 @@ pub(crate) fn read_manifest_or_infer(
         .unwrap_or("cursor-pack")
         .to_string();
     let created_at = crate::utils::library_meta::now_iso8601_utc();
-    let items = validate_cursor_pack_path(archive_path)?;
+    let items = validate_cursor_pack_path(archive_path, mode)
+        .map_err(|e| with_code(ErrorCode::PackInvalid, e))?
+        .items;
+
+    // Packs exported by this app carry a manifest; keep its name and mode
+    if let Some(manifest) = read_manifest_from_path(archive_path) {
+        return Ok(CursorPackManifest { items, ..manifest });
+    }
 
     Ok(CursorPackManifest {
         version: 1,
@@ -236,12 +365,59 @@ pub(crate) fn read_manifest_or_infer(
         mode: CustomizationMode::Advanced,
         created_at,
         items,
+        description: None,
+        changelog: Vec::new(),
+        sounds: PackSounds::default(),
     })
 }
 
+/// Result of importing a pack: the new library entry and what a lenient import skipped.
+#[derive(Serialize, Deserialize, Clone, Debug, ts_rs::TS)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct PackImport {
+    pub pack: LibraryCursor,
+    /// Files a lenient import skipped and why; always empty for a strict import
+    pub warnings: Vec<String>,
+}
+
+/// Import a .zip pack. `lenient` skips files that are not cursors for this pack, such as a
+/// readme, instead of rejecting the whole pack.
 #[tauri::command]
-pub fn import_cursor_pack<R: Runtime>(app: AppHandle<R>, filename: String, data: Vec<u8>) -> Result<LibraryCursor, String> {
-    let ext = Path::new(&filename)
+pub fn import_cursor_pack<R: Runtime>(
+    app: AppHandle<R>,
+    filename: String,
+    data: Vec<u8>,
+    op_id: Option<String>,
+    lenient: Option<bool>,
+) -> Result<PackImport, String> {
+    let operation = Operation::begin(&app, op_id);
+    let mode = if lenient.unwrap_or(false) {
+        PackValidationMode::Lenient
+    } else {
+        PackValidationMode::Strict
+    };
+    import_pack_bytes_with_mode(&app, &operation, &filename, &data, mode)
+}
+
+/// Validate a .zip pack held in memory, save it to the packs folder and add it to the library.
+pub(crate) fn import_pack_bytes<R: Runtime>(
+    app: &AppHandle<R>,
+    operation: &Operation<R>,
+    filename: &str,
+    data: &[u8],
+) -> Result<LibraryCursor, String> {
+    import_pack_bytes_with_mode(app, operation, filename, data, PackValidationMode::Strict)
+        .map(|import| import.pack)
+}
+
+fn import_pack_bytes_with_mode<R: Runtime>(
+    app: &AppHandle<R>,
+    operation: &Operation<R>,
+    filename: &str,
+    data: &[u8],
+    mode: PackValidationMode,
+) -> Result<PackImport, String> {
+    let ext = Path::new(filename)
         .extension()
         .and_then(|s| s.to_str())
         .unwrap_or("");
@@ -250,29 +426,98 @@ pub fn import_cursor_pack<R: Runtime>(app: AppHandle<R>, filename: String, data:
     }
 
     // Validate first so we don't persist invalid packs.
-    let validated_items = validate_cursor_pack_bytes(&data)?;
+    let validating = operation.phase(OperationPhase::Validating, 1);
+    let validated =
+        validate_cursor_pack_bytes(data, mode).map_err(|e| with_code(ErrorCode::PackInvalid, e))?;
+    validating.finish();
+    operation.checkpoint()?;
+    let saving = operation.phase(OperationPhase::Saving, 1);
 
     let packs_dir = crate::paths::cursor_packs_dir()?;
-    let target_path = prepare_pack_archive_destination(&packs_dir, &filename)?;
+    crate::disk_space::ensure_space(&packs_dir, data.len() as u64)?;
+    let target_path = prepare_pack_archive_destination(&packs_dir, filename)?;
 
-    fs::write(&target_path, &data).map_err(|e| format!("Failed to save cursor pack: {e}"))?;
+    fs::write(&target_path, data).map_err(|e| format!("Failed to save cursor pack: {e}"))?;
 
-    register_pack_in_library(
-        &app,
+    let pack = register_pack_in_library(
+        app,
         &target_path,
         CustomizationMode::Advanced,
-        validated_items,
+        validated.items,
         Some(crate::utils::library_meta::now_iso8601_utc()),
-    )
+    )?;
+    saving.finish();
+    for warning in &validated.warnings {
+        cc_info!("[CursorChanger] Pack import {}: {}", filename, warning);
+    }
+    Ok(PackImport {
+        pack,
+        warnings: validated.warnings,
+    })
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, ts_rs::TS)]
 #[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
 pub struct PackFilePreview {
     pub file_name: String,
+    /// Preview image: a `cc-preview` URL, or a data URL when the image could not be cached
     pub data_url: String,
 }
 
+/// Description and changelog of a library pack, ready to render.
+#[derive(Serialize, Deserialize, Clone, Debug, ts_rs::TS)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct PackDetails {
+    pub id: String,
+    pub name: String,
+    pub mode: CustomizationMode,
+    pub created_at: String,
+    pub item_count: u32,
+    pub description: Option<String>,
+    /// Newest first
+    pub changelog: Vec<PackChangelogEntry>,
+    /// Distinct authors credited by the pack's items
+    pub authors: Vec<String>,
+}
+
+/// Details for the library pack `id`. Packs without a manifest report no description or
+/// changelog.
+#[tauri::command]
+pub fn get_pack_details<R: Runtime>(app: AppHandle<R>, id: String) -> Result<PackDetails, String> {
+    let pack = load_library(&app)?
+        .cursors
+        .into_iter()
+        .find(|c| c.id == id && c.is_pack)
+        .ok_or_else(|| "Cursor pack not found in library".to_string())?;
+
+    let manifest = read_manifest_from_path(Path::new(&pack.file_path));
+    let (mode, items) = match (&manifest, &pack.pack_metadata) {
+        (Some(manifest), _) => (manifest.mode, manifest.items.clone()),
+        (None, Some(metadata)) => (metadata.mode, metadata.items.clone()),
+        (None, None) => (CustomizationMode::Advanced, Vec::new()),
+    };
+    let mut authors: Vec<String> = Vec::new();
+    for author in items.iter().filter_map(|item| item.author.as_deref()) {
+        if !authors.iter().any(|a| a.eq_ignore_ascii_case(author)) {
+            authors.push(author.to_string());
+        }
+    }
+    let (description, changelog) = manifest
+        .map(|m| (m.description, m.changelog))
+        .unwrap_or_default();
+
+    Ok(PackDetails {
+        id: pack.id,
+        name: pack.name,
+        mode,
+        created_at: pack.created_at,
+        item_count: u32::try_from(items.len()).unwrap_or(u32::MAX),
+        description,
+        changelog,
+        authors,
+    })
+}
+
 #[tauri::command]
 pub fn get_cursor_pack_manifest(archive_path: String) -> Result<CursorPackManifest, String> {
     let path = PathBuf::from(&archive_path);
@@ -282,11 +527,16 @@ pub fn get_cursor_pack_manifest(archive_path: String) -> Result<CursorPackManife
     if !is_zip(&path) {
         return Err("Not a .zip cursor pack".to_string());
     }
-    read_manifest_or_infer(&path)
+    read_manifest_or_infer(&path, PackValidationMode::Lenient)
 }
 
 #[tauri::command]
-pub fn get_cursor_pack_file_previews(archive_path: String) -> Result<Vec<PackFilePreview>, String> {
+pub fn get_cursor_pack_file_previews<R: Runtime>(
+    app: AppHandle<R>,
+    archive_path: String,
+    op_id: Option<String>,
+) -> Result<Vec<PackFilePreview>, String> {
+    let operation = Operation::begin(&app, op_id);
     let path = PathBuf::from(&archive_path);
     if !path.exists() {
         return Err("Cursor pack file not found".to_string());
@@ -301,7 +551,11 @@ pub fn get_cursor_pack_file_previews(archive_path: String) -> Result<Vec<PackFil
 
     let mut previews = Vec::new();
 
-    for i in 0..archive.len() {
+    let entry_count = archive.len();
+    let mut generating = operation.phase(OperationPhase::GeneratingPreviews, entry_count);
+    for i in 0..entry_count {
+        operation.checkpoint()?;
+        generating.set(i, entry_count, None);
         let mut entry =
             archive
                 .by_index(i)
@@ -336,19 +590,82 @@ pub fn get_cursor_pack_file_previews(archive_path: String) -> Result<Vec<PackFil
             .map_err(|e| format!("Failed to read cursor file from archive: {e}"))?;
 
         let data_url = get_cursor_preview_from_bytes(&bytes, Some(&file_name))?;
-        previews.push(PackFilePreview { file_name, data_url });
+        previews.push(PackFilePreview {
+            file_name,
+            data_url: crate::preview_protocol::publish_data_url(data_url),
+        });
     }
+    generating.finish();
 
     Ok(previews)
 }
 
+/// Name in the archive of the entry whose file name is `file_name`, ignoring case and any
+/// folder it sits in.
+fn find_entry_name<'a>(names: impl Iterator<Item = &'a str>, file_name: &str) -> Option<String> {
+    let file_name = Path::new(file_name)
+        .file_name()
+        .and_then(|s| s.to_str())
+        .unwrap_or(file_name);
+    names
+        .filter(|name| !name.ends_with('/'))
+        .find(|name| {
+            Path::new(name)
+                .file_name()
+                .and_then(|s| s.to_str())
+                .is_some_and(|n| n.eq_ignore_ascii_case(file_name))
+        })
+        .map(str::to_string)
+}
+
+/// Preview of a single file in a pack archive, so the pack detail view can load previews as
+/// items scroll into view. The entry is located through the zip central directory and only
+/// its own bytes are read and decompressed, whatever the size of the rest of the pack.
+#[tauri::command]
+pub fn get_pack_item_preview(
+    archive_path: String,
+    file_name: String,
+) -> Result<PackFilePreview, String> {
+    let path = PathBuf::from(&archive_path);
+    if !path.exists() {
+        return Err("Cursor pack file not found".to_string());
+    }
+    if !is_zip(&path) {
+        return Err("Not a .zip cursor pack".to_string());
+    }
+
+    let file = fs::File::open(&path).map_err(|e| format!("Failed to open pack archive: {e}"))?;
+    let mut archive =
+        ZipArchive::new(file).map_err(|e| format!("Failed to read archive contents: {e}"))?;
+    let name_in_zip = find_entry_name(archive.file_names(), &file_name)
+        .ok_or_else(|| format!("{file_name} is not in this cursor pack"))?;
+
+    let mut entry = archive
+        .by_name(&name_in_zip)
+        .map_err(|e| format!("Failed to read archive entry: {e}"))?;
+    let mut bytes = Vec::with_capacity(entry.size() as usize);
+    entry
+        .read_to_end(&mut bytes)
+        .map_err(|e| format!("Failed to read cursor file from archive: {e}"))?;
+
+    let data_url = get_cursor_preview_from_bytes(&bytes, Some(&file_name))?;
+    Ok(PackFilePreview {
+        file_name,
+        data_url: crate::preview_protocol::publish_data_url(data_url),
+    })
+}
+
 pub(super) fn extract_entry_to_folder<R: Read>(
     mut entry: R,
     file_name: &str,
     folder: &Path,
 ) -> Result<PathBuf, String> {
     fs::create_dir_all(folder).map_err(|e| format!("Failed to create pack folder: {e}"))?;
-    let out_path = folder.join(file_name);
+    // Lenient packs name entries inside folders; those are extracted flat
+    let out_name = Path::new(file_name)
+        .file_name()
+        .ok_or_else(|| format!("Invalid file name in pack: {file_name}"))?;
+    let out_path = folder.join(out_name);
     let mut out = fs::File::create(&out_path).map_err(|e| format!("Failed to create file: {e}"))?;
     std::io::copy(&mut entry, &mut out).map_err(|e| format!("Failed to write file: {e}"))?;
     out.flush().ok();
@@ -357,6 +674,7 @@ pub(super) fn extract_entry_to_folder<R: Read>(
 
 #[tauri::command]
 pub fn apply_cursor_pack<R: Runtime>(app: AppHandle<R>, state: State<'_, AppState>, id: String) -> Result<(), String> {
+    check_custom_cursors_allowed(&state)?;
     let library = load_library(&app)?;
     let pack = library
         .cursors
@@ -368,6 +686,12 @@ pub fn apply_cursor_pack<R: Runtime>(app: AppHandle<R>, state: State<'_, AppStat
     if !pack.is_pack {
         return Err("Selected library item is not a cursor pack".to_string());
     }
+    let _job = pending_jobs::track(
+        PendingJobKind::ApplyPack,
+        &pack.id,
+        &format!("Apply {}", pack.name),
+        None,
+    );
 
     let archive_path = PathBuf::from(&pack.file_path);
     if !archive_path.exists() {
@@ -377,45 +701,39 @@ pub fn apply_cursor_pack<R: Runtime>(app: AppHandle<R>, state: State<'_, AppStat
         return Err("Cursor pack file is not a .zip".to_string());
     }
 
-    let manifest = read_manifest_or_infer(&archive_path)?;
+    let manifest = read_manifest_or_infer(&archive_path, PackValidationMode::Lenient)?;
     let pack_mode = manifest.mode.clone();
 
-    let extract_folder = archive_path
-        .parent()
-        .ok_or_else(|| "Cursor pack archive missing parent folder".to_string())?
-        .to_path_buf();
-    fs::create_dir_all(&extract_folder)
-        .map_err(|e| format!("Failed to prepare pack folder: {e}"))?;
-
+    // Only stream out the roles this pack mode will actually apply.
+    let roles: Vec<(String, String)> = manifest
+        .items
+        .iter()
+        .filter(|item| !item.cursor_name.trim().is_empty())
+        .map(|item| {
+            // Convert kebab-case cursor_name back to Windows cursor name for application
+            let windows_cursor_name = pack_base_names()
+                .find(|(_, base_name)| *base_name == item.cursor_name)
+                .map(|(windows_name, _)| windows_name.to_string())
+                .unwrap_or_else(|| item.cursor_name.clone());
+            (windows_cursor_name, item.file_name.clone())
+        })
+        .filter(|(role, _)| match pack_mode {
+            CustomizationMode::Simple => role == "Normal" || role == "Hand",
+            CustomizationMode::Advanced => true,
+        })
+        .collect();
+
+    let mount_dir = crate::paths::pack_mounts_dir()?.join(&pack.id);
+    // Cache GC must not prune the new files before cursor state references them
+    let _mounts = lock_mounts();
     let file = fs::File::open(&archive_path).map_err(|e| format!("Failed to open pack archive: {e}"))?;
     let mut archive =
         ZipArchive::new(file).map_err(|e| format!("Failed to read archive contents: {e}"))?;
 
-    let mut cursor_paths: HashMap<String, String> = HashMap::new();
-    for item in &manifest.items {
-        if item.cursor_name.trim().is_empty() {
-            continue;
-        }
-
-        let mut zip_file = match archive.by_name(&item.file_name) {
-            Ok(f) => f,
-            Err(_) => continue,
-        };
-
-        let extracted_path = extract_entry_to_folder(&mut zip_file, &item.file_name, &extract_folder)?;
-        
-        // Convert kebab-case cursor_name back to Windows cursor name for application
-        let windows_cursor_name = cursor_changer::DEFAULT_CURSOR_BASE_NAMES
-            .iter()
-            .find(|(_, base_name)| *base_name == item.cursor_name)
-            .map(|(windows_name, _)| windows_name.to_string())
-            .unwrap_or_else(|| item.cursor_name.clone());
-            
-        cursor_paths.insert(
-            windows_cursor_name,
-            extracted_path.to_string_lossy().to_string(),
-        );
-    }
+    let cursor_paths: HashMap<String, String> = mount_pack_roles(&mut archive, &roles, &mount_dir)?
+        .into_iter()
+        .map(|(role, path)| (role, path.to_string_lossy().to_string()))
+        .collect();
 
     if cursor_paths.is_empty() {
         return Err("Cursor pack contains no recognized cursor files".to_string());
@@ -447,5 +765,178 @@ pub fn apply_cursor_pack<R: Runtime>(app: AppHandle<R>, state: State<'_, AppStat
         Ok(())
     })?;
 
+    // Drop mounted files from earlier applies that nothing points at any more.
+    let referenced: HashSet<PathBuf> = {
+        let guard = state.read_all()?;
+        guard
+            .cursor
+            .cursor_paths
+            .values()
+            .chain(guard.modes.simple_mode_cursor_paths.values())
+            .chain(guard.modes.advanced_mode_cursor_paths.values())
+            .map(PathBuf::from)
+            .collect()
+    };
+    crate::hotkey_press::remember_applied_pack(&app, &pack.id);
+    crate::usage_stats::record_pack_apply(&pack.id);
+    crate::audio::play_pack_sound(&app, Some(&pack.id), PackSoundEvent::Apply);
+
+    let removed = prune_unreferenced(&mount_dir, &referenced);
+    if removed > 0 {
+        cc_debug!(
+            "[CursorCustomization] Removed {} unused files from pack mount {}",
+            removed,
+            mount_dir.display()
+        );
+    }
+
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn entry_lookup_ignores_case_and_folders() {
+        let names = [
+            "manifest.json",
+            "Arrows/",
+            "Arrows/Normal-Select.cur",
+            "busy.ani",
+        ];
+
+        assert_eq!(
+            find_entry_name(names.into_iter(), "normal-select.CUR").as_deref(),
+            Some("Arrows/Normal-Select.cur")
+        );
+        assert_eq!(
+            find_entry_name(names.into_iter(), "busy.ani").as_deref(),
+            Some("busy.ani")
+        );
+        assert_eq!(find_entry_name(names.into_iter(), "Arrows"), None);
+        assert_eq!(find_entry_name(names.into_iter(), "help.cur"), None);
+    }
+
+    fn pack_zip(names: &[&str]) -> Vec<u8> {
+        use cursor_convert::fixtures;
+        use std::io::Write;
+
+        let mut writer = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+        for name in names {
+            writer
+                .start_file(*name, zip::write::SimpleFileOptions::default())
+                .unwrap();
+            let data = if name.ends_with(".ani") {
+                fixtures::AniBuilder::new()
+                    .author("Ada")
+                    .cur_frames(2, 32)
+                    .build()
+            } else {
+                fixtures::cur(32, (0, 0))
+            };
+            writer.write_all(&data).unwrap();
+        }
+        writer.finish().unwrap().into_inner()
+    }
+
+    #[test]
+    fn packs_may_carry_extended_roles_after_the_core_ones() {
+        let data = pack_zip(&["person-select.cur", "normal-select.cur", "link-select.cur"]);
+        let items = validate_cursor_pack_bytes(&data, PackValidationMode::Strict)
+            .expect("valid pack")
+            .items;
+
+        let roles: Vec<&str> = items.iter().map(|i| i.cursor_name.as_str()).collect();
+        assert_eq!(roles, ["normal-select", "link-select", "person-select"]);
+        assert_eq!(items[2].display_name, "Person select");
+    }
+
+    #[test]
+    fn animated_items_credit_the_author_in_their_info_list() {
+        let data = pack_zip(&["normal-select.cur", "link-select.ani"]);
+        let items = validate_cursor_pack_bytes(&data, PackValidationMode::Strict)
+            .expect("valid pack")
+            .items;
+
+        assert_eq!(items[0].author, None);
+        assert_eq!(items[1].author.as_deref(), Some("Ada"));
+    }
+
+    #[test]
+    fn lenient_mode_skips_extra_files_and_folders_with_warnings() {
+        let data = pack_zip(&[
+            "Neon/normal-select.cur",
+            "Neon/link-select.cur",
+            "Neon/sparkle.cur",
+            "Neon/Normal-Select.ani",
+            "readme.txt",
+        ]);
+        let err = validate_cursor_pack_bytes(&data, PackValidationMode::Strict).unwrap_err();
+        assert_eq!(err, "Cursor pack zip must not contain folders");
+
+        let pack = validate_cursor_pack_bytes(&data, PackValidationMode::Lenient).unwrap();
+        let files: Vec<&str> = pack.items.iter().map(|i| i.file_name.as_str()).collect();
+        assert_eq!(files, ["Neon/normal-select.cur", "Neon/link-select.cur"]);
+        assert_eq!(pack.warnings.len(), 4, "{:?}", pack.warnings);
+        assert!(pack.warnings[2].starts_with("Skipped readme.txt: "));
+    }
+
+    #[test]
+    fn declared_sounds_are_allowed_alongside_the_cursors() {
+        use std::io::Write;
+
+        let mut writer = zip::ZipWriter::new_append(std::io::Cursor::new(pack_zip(&[
+            "normal-select.cur",
+            "link-select.cur",
+        ])))
+        .unwrap();
+        let mut add = |name: &str, data: &[u8]| {
+            writer
+                .start_file(name, zip::write::SimpleFileOptions::default())
+                .unwrap();
+            writer.write_all(data).unwrap();
+        };
+        add(
+            PACK_MANIFEST_FILENAME,
+            br#"{"version":1,"pack_name":"Neon","mode":"advanced","created_at":"","items":[],
+                "sounds":{"toggle":"sounds/blip.wav"}}"#,
+        );
+        add("sounds/blip.wav", b"RIFF");
+        let with_sound = writer.finish().unwrap().into_inner();
+        let pack = validate_cursor_pack_bytes(&with_sound, PackValidationMode::Strict)
+            .expect("declared sounds are part of the pack");
+        assert_eq!(pack.items.len(), 2);
+
+        // A sound the manifest does not declare is just an unexpected file
+        let mut writer = zip::ZipWriter::new_append(std::io::Cursor::new(pack_zip(&[
+            "normal-select.cur",
+            "link-select.cur",
+        ])))
+        .unwrap();
+        writer
+            .start_file("blip.wav", zip::write::SimpleFileOptions::default())
+            .unwrap();
+        writer.write_all(b"RIFF").unwrap();
+        let stray = writer.finish().unwrap().into_inner();
+        assert!(validate_cursor_pack_bytes(&stray, PackValidationMode::Strict).is_err());
+    }
+
+    #[test]
+    fn lenient_mode_still_requires_the_core_cursors() {
+        let data = pack_zip(&["normal-select.cur", "license.txt"]);
+        let err = validate_cursor_pack_bytes(&data, PackValidationMode::Lenient).unwrap_err();
+        assert_eq!(
+            err,
+            "Cursor pack zip must include link-select.cur or link-select.ani"
+        );
+    }
+
+    #[test]
+    fn nested_entries_extract_flat() {
+        let dir = tempfile::tempdir().unwrap();
+        let path =
+            extract_entry_to_folder(&b"cursor"[..], "Neon/normal-select.cur", dir.path()).unwrap();
+        assert_eq!(path, dir.path().join("normal-select.cur"));
+    }
+}
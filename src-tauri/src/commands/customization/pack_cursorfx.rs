@@ -0,0 +1,684 @@
+//! Importing CursorFX / Stardock themes as cursor packs.
+//!
+//! Two containers are read:
+//! - `.CurXPTheme` (CursorXP): a zip with a `Scheme.ini` and one PNG frame strip per cursor.
+//! - `.CursorFX`: a binary header followed by a zlib-compressed payload of pointer entries
+//!   carrying raw BGRA frame strips (layout below).
+//!
+//! Static frames and frame-strip animations are converted to .cur/.ani, packed into a regular
+//! pack zip and registered through [`import_pack_bytes`], so the result behaves like any other
+//! pack. Effect scripting (animation scripts, trails, click effects) has no .ani equivalent:
+//! frames play in order at the theme's frame delay and every dropped feature is reported as a
+//! warning.
+//!
+//! `.CursorFX` layout, all integers little-endian `u32`:
+//! - header: `version`, `header_size`, `payload_size` (uncompressed), `theme_type`
+//! - payload (zlib, starting at `header_size`): `info_size`, then `info_size` bytes of
+//!   NUL-separated UTF-16LE name/author/description, then entries
+//! - entry: `kind`, `entry_size` (including these two fields), body. Kind 2 is a pointer:
+//!   `pointer_id`, `frame_count`, `width`, `height`, `frame_delay_ms`, `hotspot_x`,
+//!   `hotspot_y`, `script_size`, the UTF-16LE script, then `width * height` BGRA pixels
+//!   (top row first) holding the frames side by side.
+
+use std::collections::HashMap;
+use std::io::{Cursor, Read, Write};
+use std::path::Path;
+
+use flate2::read::ZlibDecoder;
+use image::{imageops, RgbaImage};
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Runtime};
+use zip::write::SimpleFileOptions;
+use zip::ZipArchive;
+
+use crate::operations::{Operation, OperationPhase};
+
+use super::library::LibraryCursor;
+use super::pack_commands::import_pack_bytes;
+
+/// Largest uncompressed `.CursorFX` payload accepted
+const MAX_PAYLOAD_BYTES: u64 = 64 * 1024 * 1024;
+const SCHEME_INI: &str = "Scheme.ini";
+const POINTER_ENTRY: u32 = 2;
+pub(super) const DEFAULT_FRAME_DELAY_MS: u32 = 100;
+
+/// Windows cursor name of each CursorFX pointer ID, in Windows scheme order
+const CURSORFX_POINTER_ROLES: [&str; 15] = [
+    "Normal",
+    "Help",
+    "AppStarting",
+    "Wait",
+    "Cross",
+    "IBeam",
+    "Pen",
+    "No",
+    "SizeNS",
+    "SizeWE",
+    "SizeNWSE",
+    "SizeNESW",
+    "SizeAll",
+    "Up",
+    "Hand",
+];
+
+/// CursorXP section names that differ from this app's Windows cursor names
+const SCHEME_SECTION_ALIASES: [(&str, &str); 6] = [
+    ("Arrow", "Normal"),
+    ("Crosshair", "Cross"),
+    ("NWPen", "Pen"),
+    ("Handwriting", "Pen"),
+    ("UpArrow", "Up"),
+    ("Link", "Hand"),
+];
+
+/// Result of importing a theme: the new pack and what did not survive conversion.
+#[derive(Serialize, Deserialize, Clone, Debug, ts_rs::TS)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct CursorThemeImport {
+    pub pack: LibraryCursor,
+    /// Pack base names of the converted cursors, e.g. `normal-select`
+    pub imported: Vec<String>,
+    /// Parts of the theme that were skipped or simplified
+    pub warnings: Vec<String>,
+}
+
+/// One cursor read from a theme, before conversion
+pub(super) struct ThemeCursor {
+    pub(super) windows_name: &'static str,
+    pub(super) frames: Vec<RgbaImage>,
+    pub(super) hotspot: (u16, u16),
+    pub(super) frame_delay_ms: u32,
+}
+
+#[derive(Default)]
+pub(super) struct ParsedTheme {
+    pub(super) cursors: Vec<ThemeCursor>,
+    pub(super) warnings: Vec<String>,
+}
+
+fn base_name_for(windows_name: &str) -> Option<&'static str> {
+    cursor_changer::DEFAULT_CURSOR_BASE_NAMES
+        .iter()
+        .find(|(name, _)| *name == windows_name)
+        .map(|(_, base_name)| *base_name)
+}
+
+pub(super) fn display_name_for(windows_name: &str) -> String {
+    cursor_changer::find_cursor_type(windows_name).map_or_else(
+        || windows_name.to_string(),
+        |ct| ct.display_name.to_string(),
+    )
+}
+
+/// Windows cursor name for a `Scheme.ini` section: a CursorXP name, a Windows cursor name
+/// or a pack base name.
+fn role_for_section(section: &str) -> Option<&'static str> {
+    let section = section.trim();
+    SCHEME_SECTION_ALIASES
+        .iter()
+        .find(|(alias, _)| alias.eq_ignore_ascii_case(section))
+        .map(|(_, name)| *name)
+        .or_else(|| {
+            cursor_changer::DEFAULT_CURSOR_BASE_NAMES
+                .iter()
+                .find(|(name, base_name)| {
+                    name.eq_ignore_ascii_case(section) || base_name.eq_ignore_ascii_case(section)
+                })
+                .map(|(name, _)| *name)
+        })
+}
+
+fn read_u32(data: &[u8], pos: usize) -> Option<u32> {
+    data.get(pos..pos + 4)
+        .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+}
+
+fn decode_utf16(data: &[u8]) -> String {
+    let units: Vec<u16> = data
+        .chunks_exact(2)
+        .map(|b| u16::from_le_bytes([b[0], b[1]]))
+        .take_while(|&u| u != 0)
+        .collect();
+    String::from_utf16_lossy(&units)
+}
+
+/// Split a strip of `frame_count` side-by-side frames. Frames over the .cur size limit are
+/// rejected.
+fn split_strip(strip: &RgbaImage, frame_count: u32) -> Result<Vec<RgbaImage>, String> {
+    let frame_count = frame_count.max(1);
+    let frame_width = strip.width() / frame_count;
+    if frame_width == 0 || strip.height() == 0 {
+        return Err("image is smaller than its frame count".to_string());
+    }
+    if frame_width > cursor_convert::MAX_CURSOR_SIZE
+        || strip.height() > cursor_convert::MAX_CURSOR_SIZE
+    {
+        return Err(format!(
+            "frames are {}x{}, larger than the {}px cursor limit",
+            frame_width,
+            strip.height(),
+            cursor_convert::MAX_CURSOR_SIZE
+        ));
+    }
+    Ok((0..frame_count)
+        .map(|i| {
+            imageops::crop_imm(strip, i * frame_width, 0, frame_width, strip.height()).to_image()
+        })
+        .collect())
+}
+
+fn is_theme_zip(data: &[u8]) -> bool {
+    data.starts_with(b"PK\x03\x04")
+}
+
+pub(super) fn parse_theme(data: &[u8]) -> Result<ParsedTheme, String> {
+    if is_theme_zip(data) {
+        parse_curxp_theme(data)
+    } else {
+        parse_cursorfx_theme(data)
+    }
+}
+
+/// Sections of an INI file as lowercase-keyed maps, in file order
+fn parse_ini(text: &str) -> Vec<(String, HashMap<String, String>)> {
+    let mut sections: Vec<(String, HashMap<String, String>)> = Vec::new();
+    for line in text.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with(';') || line.starts_with('#') {
+            continue;
+        }
+        if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            sections.push((name.trim().to_string(), HashMap::new()));
+        } else if let (Some((key, value)), Some((_, section))) =
+            (line.split_once('='), sections.last_mut())
+        {
+            section.insert(key.trim().to_ascii_lowercase(), value.trim().to_string());
+        }
+    }
+    sections
+}
+
+fn parse_curxp_theme(data: &[u8]) -> Result<ParsedTheme, String> {
+    let mut archive = ZipArchive::new(Cursor::new(data))
+        .map_err(|e| format!("Failed to read theme archive: {e}"))?;
+    let names: Vec<String> = archive.file_names().map(str::to_string).collect();
+    let find = |wanted: &str| {
+        names
+            .iter()
+            .find(|n| {
+                Path::new(n)
+                    .file_name()
+                    .and_then(|s| s.to_str())
+                    .is_some_and(|s| s.eq_ignore_ascii_case(wanted))
+            })
+            .cloned()
+    };
+
+    let scheme_name = find(SCHEME_INI).ok_or("Theme archive has no Scheme.ini")?;
+    let mut scheme = String::new();
+    archive
+        .by_name(&scheme_name)
+        .map_err(|e| format!("Failed to read Scheme.ini: {e}"))?
+        .read_to_string(&mut scheme)
+        .map_err(|e| format!("Failed to read Scheme.ini: {e}"))?;
+
+    let mut theme = ParsedTheme::default();
+    for (section, keys) in parse_ini(&scheme) {
+        if section.eq_ignore_ascii_case("General") {
+            continue;
+        }
+        let Some(windows_name) = role_for_section(&section) else {
+            theme
+                .warnings
+                .push(format!("Skipped \"{section}\": not a Windows cursor role"));
+            continue;
+        };
+        let display = display_name_for(windows_name);
+        if keys.contains_key("script") {
+            theme.warnings.push(format!(
+                "{display}: animation script ignored; frames play in order"
+            ));
+        }
+        if keys
+            .keys()
+            .any(|k| k.starts_with("trail") || k.starts_with("effect"))
+        {
+            theme.warnings.push(format!(
+                "{display}: trail and click effects are not supported"
+            ));
+        }
+
+        let number = |key: &str| keys.get(key).and_then(|v| v.parse::<u32>().ok());
+        let image_name = keys
+            .get("image")
+            .cloned()
+            .unwrap_or_else(|| format!("{section}.png"));
+        let Some(entry_name) = find(&image_name) else {
+            theme
+                .warnings
+                .push(format!("{display}: image {image_name} is missing"));
+            continue;
+        };
+        let mut bytes = Vec::new();
+        archive
+            .by_name(&entry_name)
+            .map_err(|e| e.to_string())
+            .and_then(|mut entry| entry.read_to_end(&mut bytes).map_err(|e| e.to_string()))
+            .map_err(|e| format!("Failed to read {entry_name}: {e}"))?;
+
+        let frames = image::load_from_memory(&bytes)
+            .map_err(|e| e.to_string())
+            .and_then(|img| split_strip(&img.to_rgba8(), number("frames").unwrap_or(1)));
+        match frames {
+            Ok(frames) => theme.cursors.push(ThemeCursor {
+                windows_name,
+                frames,
+                hotspot: (
+                    number("hotspotx").unwrap_or(0).min(u32::from(u16::MAX)) as u16,
+                    number("hotspoty").unwrap_or(0).min(u32::from(u16::MAX)) as u16,
+                ),
+                frame_delay_ms: number("interval").unwrap_or(DEFAULT_FRAME_DELAY_MS),
+            }),
+            Err(e) => theme.warnings.push(format!("{display}: skipped, {e}")),
+        }
+    }
+    Ok(theme)
+}
+
+fn parse_cursorfx_theme(data: &[u8]) -> Result<ParsedTheme, String> {
+    let invalid = || "Not a CursorFX theme file".to_string();
+    let header_size = read_u32(data, 4).ok_or_else(invalid)? as usize;
+    let payload_size = read_u32(data, 8).ok_or_else(invalid)?;
+    let compressed = data
+        .get(header_size..)
+        .filter(|d| !d.is_empty())
+        .ok_or_else(invalid)?;
+
+    let limit = u64::from(payload_size).min(MAX_PAYLOAD_BYTES);
+    let mut payload = Vec::new();
+    ZlibDecoder::new(compressed)
+        .take(limit)
+        .read_to_end(&mut payload)
+        .map_err(|e| format!("Failed to decompress CursorFX theme: {e}"))?;
+
+    let info_size = read_u32(&payload, 0).ok_or_else(invalid)? as usize;
+    let mut pos = 4 + info_size;
+    let mut theme = ParsedTheme::default();
+    let mut skipped_effects = false;
+
+    while pos + 8 <= payload.len() {
+        let kind = read_u32(&payload, pos).ok_or_else(invalid)?;
+        let entry_size = read_u32(&payload, pos + 4).ok_or_else(invalid)? as usize;
+        if entry_size < 8 || pos + entry_size > payload.len() {
+            theme
+                .warnings
+                .push("Theme data ends early; remaining cursors were skipped".to_string());
+            break;
+        }
+        let entry = &payload[pos + 8..pos + entry_size];
+        pos += entry_size;
+
+        if kind != POINTER_ENTRY {
+            skipped_effects = true;
+            continue;
+        }
+        match parse_pointer_entry(entry) {
+            Ok((cursor, script)) => {
+                if !script.trim().is_empty() {
+                    theme.warnings.push(format!(
+                        "{}: animation script ignored; frames play in order",
+                        display_name_for(cursor.windows_name)
+                    ));
+                }
+                theme.cursors.push(cursor);
+            }
+            Err(e) => theme.warnings.push(e),
+        }
+    }
+
+    if skipped_effects {
+        theme
+            .warnings
+            .push("Trail and click effects in this theme are not supported".to_string());
+    }
+    Ok(theme)
+}
+
+/// Decode a pointer entry body into a cursor and its animation script.
+fn parse_pointer_entry(entry: &[u8]) -> Result<(ThemeCursor, String), String> {
+    let field = |i: usize| read_u32(entry, i * 4).ok_or("Truncated CursorFX pointer entry");
+    let pointer_id = field(0)?;
+    let Some(&windows_name) = CURSORFX_POINTER_ROLES.get(pointer_id as usize) else {
+        return Err(format!(
+            "Skipped pointer {pointer_id}: no matching Windows cursor role"
+        ));
+    };
+    let display = display_name_for(windows_name);
+    let (frame_count, width, height) = (field(1)?, field(2)?, field(3)?);
+    let (frame_delay_ms, hotspot_x, hotspot_y) = (field(4)?, field(5)?, field(6)?);
+    let script_size = field(7)? as usize;
+
+    let script_end = 32 + script_size;
+    let script = entry
+        .get(32..script_end)
+        .map(decode_utf16)
+        .ok_or_else(|| format!("{display}: skipped, entry is truncated"))?;
+    let pixel_len = (width as usize)
+        .checked_mul(height as usize)
+        .and_then(|n| n.checked_mul(4))
+        .ok_or_else(|| format!("{display}: skipped, image size is invalid"))?;
+    let mut pixels = entry
+        .get(script_end..script_end + pixel_len)
+        .ok_or_else(|| format!("{display}: skipped, image data is truncated"))?
+        .to_vec();
+    for px in pixels.chunks_exact_mut(4) {
+        px.swap(0, 2); // BGRA -> RGBA
+    }
+    let strip = RgbaImage::from_raw(width, height, pixels)
+        .ok_or_else(|| format!("{display}: skipped, image size is invalid"))?;
+    let frames =
+        split_strip(&strip, frame_count).map_err(|e| format!("{display}: skipped, {e}"))?;
+
+    Ok((
+        ThemeCursor {
+            windows_name,
+            frames,
+            hotspot: (
+                hotspot_x.min(u32::from(u16::MAX)) as u16,
+                hotspot_y.min(u32::from(u16::MAX)) as u16,
+            ),
+            frame_delay_ms,
+        },
+        script,
+    ))
+}
+
+/// Convert parsed cursors into a pack zip. Returns the zip and the base names it holds.
+fn build_pack_zip(
+    cursors: &[ThemeCursor],
+    warnings: &mut Vec<String>,
+    mut on_cursor: impl FnMut(&str) -> Result<(), String>,
+) -> Result<(Vec<u8>, Vec<String>), String> {
+    let mut writer = zip::ZipWriter::new(Cursor::new(Vec::new()));
+    let options = SimpleFileOptions::default();
+    let mut imported: Vec<String> = Vec::new();
+
+    for cursor in cursors {
+        let Some(base_name) = base_name_for(cursor.windows_name) else {
+            continue;
+        };
+        on_cursor(base_name)?;
+        if imported.iter().any(|b| b == base_name) {
+            warnings.push(format!(
+                "{}: theme has more than one image; the first was kept",
+                display_name_for(cursor.windows_name)
+            ));
+            continue;
+        }
+
+        let (hotspot_x, hotspot_y) = cursor.hotspot;
+        let (file_name, bytes) = if cursor.frames.len() == 1 {
+            (
+                format!("{base_name}.cur"),
+                cursor_convert::generate_cur_data(&cursor.frames[0], hotspot_x, hotspot_y)?,
+            )
+        } else {
+            let rate = cursor_convert::ms_to_jiffies(cursor.frame_delay_ms);
+            (
+                format!("{base_name}.ani"),
+                cursor_convert::generate_ani_data(&cursor.frames, hotspot_x, hotspot_y, &[rate])?,
+            )
+        };
+        writer
+            .start_file(file_name.as_str(), options)
+            .map_err(|e| format!("Failed to write {file_name}: {e}"))?;
+        writer
+            .write_all(&bytes)
+            .map_err(|e| format!("Failed to write {file_name}: {e}"))?;
+        imported.push(base_name.to_string());
+    }
+
+    let data = writer
+        .finish()
+        .map_err(|e| format!("Failed to build cursor pack: {e}"))?
+        .into_inner();
+    Ok((data, imported))
+}
+
+/// Convert a `.CursorFX` or `.CurXPTheme` theme to a cursor pack and add it to the library.
+///
+/// The theme must provide at least the Normal and Link cursors, like any pack.
+#[tauri::command]
+pub fn import_cursorfx_theme<R: Runtime>(
+    app: AppHandle<R>,
+    filename: String,
+    data: Vec<u8>,
+    op_id: Option<String>,
+) -> Result<CursorThemeImport, String> {
+    let operation = Operation::begin(&app, op_id);
+    import_theme(&app, &operation, &filename, parse_theme(&data)?)
+}
+
+/// Convert the cursors of a parsed theme to a pack zip named after `filename` and add it to
+/// the library.
+pub(super) fn import_theme<R: Runtime>(
+    app: &AppHandle<R>,
+    operation: &Operation<R>,
+    filename: &str,
+    theme: ParsedTheme,
+) -> Result<CursorThemeImport, String> {
+    let ParsedTheme {
+        cursors,
+        mut warnings,
+    } = theme;
+    if cursors.is_empty() {
+        return Err("The theme contains no cursors that can be converted".to_string());
+    }
+
+    let mut converting = operation.phase(OperationPhase::Converting, cursors.len());
+    let (pack_zip, imported) = build_pack_zip(&cursors, &mut warnings, |base_name| {
+        operation.checkpoint()?;
+        converting.advance(Some(base_name));
+        Ok(())
+    })?;
+    converting.finish();
+
+    let stem = Path::new(filename)
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .filter(|s| !s.trim().is_empty())
+        .unwrap_or("cursor-theme");
+    let pack = import_pack_bytes(app, operation, &format!("{stem}.zip"), &pack_zip)?;
+
+    for warning in &warnings {
+        cc_info!("[CursorChanger] Theme import {}: {}", filename, warning);
+    }
+    Ok(CursorThemeImport {
+        pack,
+        imported,
+        warnings,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::write::ZlibEncoder;
+    use flate2::Compression;
+    use image::Rgba;
+
+    fn strip(width: u32, height: u32) -> RgbaImage {
+        RgbaImage::from_fn(width, height, |x, _| Rgba([x as u8, 0, 255, 255]))
+    }
+
+    fn png(image: &RgbaImage) -> Vec<u8> {
+        let mut out = Cursor::new(Vec::new());
+        image
+            .write_to(&mut out, image::ImageFormat::Png)
+            .expect("encode png");
+        out.into_inner()
+    }
+
+    fn zip_of(files: &[(&str, Vec<u8>)]) -> Vec<u8> {
+        let mut writer = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        for (name, bytes) in files {
+            writer
+                .start_file(*name, SimpleFileOptions::default())
+                .unwrap();
+            writer.write_all(bytes).unwrap();
+        }
+        writer.finish().unwrap().into_inner()
+    }
+
+    fn utf16(text: &str) -> Vec<u8> {
+        text.encode_utf16().flat_map(u16::to_le_bytes).collect()
+    }
+
+    fn pointer_entry(pointer_id: u32, frames: u32, image: &RgbaImage, script: &str) -> Vec<u8> {
+        let script = utf16(script);
+        let mut body: Vec<u8> = [
+            pointer_id,
+            frames,
+            image.width(),
+            image.height(),
+            50,
+            3,
+            4,
+            script.len() as u32,
+        ]
+        .iter()
+        .flat_map(|v| v.to_le_bytes())
+        .collect();
+        body.extend_from_slice(&script);
+        for px in image.pixels() {
+            body.extend_from_slice(&[px[2], px[1], px[0], px[3]]);
+        }
+        entry(POINTER_ENTRY, &body)
+    }
+
+    fn entry(kind: u32, body: &[u8]) -> Vec<u8> {
+        let mut out = kind.to_le_bytes().to_vec();
+        out.extend_from_slice(&(body.len() as u32 + 8).to_le_bytes());
+        out.extend_from_slice(body);
+        out
+    }
+
+    fn cursorfx(entries: &[Vec<u8>]) -> Vec<u8> {
+        let info = utf16("Neon\0Someone\0");
+        let mut payload = (info.len() as u32).to_le_bytes().to_vec();
+        payload.extend_from_slice(&info);
+        for e in entries {
+            payload.extend_from_slice(e);
+        }
+        let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(&payload).unwrap();
+        let compressed = encoder.finish().unwrap();
+
+        let mut data: Vec<u8> = [1u32, 16, payload.len() as u32, 1]
+            .iter()
+            .flat_map(|v| v.to_le_bytes())
+            .collect();
+        data.extend_from_slice(&compressed);
+        data
+    }
+
+    #[test]
+    fn cursorfx_pointers_become_cursors_and_effects_are_reported() {
+        let data = cursorfx(&[
+            pointer_entry(0, 1, &strip(32, 32), ""),
+            pointer_entry(3, 4, &strip(128, 32), "1-4,repeat"),
+            entry(3, b"trail effect"),
+            pointer_entry(99, 1, &strip(32, 32), ""),
+        ]);
+        let theme = parse_theme(&data).expect("parse");
+
+        assert_eq!(theme.cursors.len(), 2);
+        let normal = &theme.cursors[0];
+        assert_eq!(normal.windows_name, "Normal");
+        assert_eq!(normal.hotspot, (3, 4));
+        // BGRA was swapped back to RGBA
+        assert_eq!(normal.frames[0].get_pixel(5, 0), &Rgba([5, 0, 255, 255]));
+
+        let busy = &theme.cursors[1];
+        assert_eq!(busy.windows_name, "Wait");
+        assert_eq!(busy.frames.len(), 4);
+        assert_eq!(busy.frames[1].get_pixel(0, 0)[0], 32);
+        assert_eq!(busy.frame_delay_ms, 50);
+
+        assert_eq!(theme.warnings.len(), 3, "{:?}", theme.warnings);
+        assert!(theme.warnings[0].contains("animation script"));
+        assert!(theme.warnings[1].contains("pointer 99"));
+        assert!(theme.warnings[2].contains("effects"));
+    }
+
+    #[test]
+    fn curxp_scheme_maps_sections_to_roles() {
+        let scheme = "[General]\nName=Neon\n\n[Arrow]\nFrames=1\nHotspotX=2\n\n\
+                      [Hand]\nImage=link.png\nFrames=2\nInterval=200\nScript=loop\n\n\
+                      [Wait]\nFrames=1\n\n[Sparkles]\nFrames=1\n";
+        let data = zip_of(&[
+            ("Scheme.ini", scheme.as_bytes().to_vec()),
+            ("Arrow.png", png(&strip(32, 32))),
+            ("images/LINK.PNG", png(&strip(64, 32))),
+        ]);
+        let theme = parse_theme(&data).expect("parse");
+
+        let roles: Vec<&str> = theme.cursors.iter().map(|c| c.windows_name).collect();
+        assert_eq!(roles, ["Normal", "Hand"]);
+        assert_eq!(theme.cursors[0].hotspot, (2, 0));
+        assert_eq!(theme.cursors[1].frames.len(), 2);
+        assert_eq!(theme.cursors[1].frame_delay_ms, 200);
+
+        let warnings = theme.warnings.join("\n");
+        assert!(warnings.contains("animation script"), "{warnings}");
+        assert!(warnings.contains("Wait.png is missing"), "{warnings}");
+        assert!(warnings.contains("\"Sparkles\""), "{warnings}");
+    }
+
+    #[test]
+    fn converted_pack_holds_cur_and_ani_files() {
+        let cursors = vec![
+            ThemeCursor {
+                windows_name: "Normal",
+                frames: vec![strip(32, 32)],
+                hotspot: (1, 1),
+                frame_delay_ms: 100,
+            },
+            ThemeCursor {
+                windows_name: "Hand",
+                frames: vec![strip(32, 32), strip(32, 32)],
+                hotspot: (0, 0),
+                frame_delay_ms: 100,
+            },
+            ThemeCursor {
+                windows_name: "Hand",
+                frames: vec![strip(32, 32)],
+                hotspot: (0, 0),
+                frame_delay_ms: 100,
+            },
+        ];
+        let mut warnings = Vec::new();
+        let (data, imported) = build_pack_zip(&cursors, &mut warnings, |_| Ok(())).unwrap();
+
+        assert_eq!(imported, ["normal-select", "link-select"]);
+        assert_eq!(warnings.len(), 1);
+        let mut archive = ZipArchive::new(Cursor::new(data)).unwrap();
+        let mut names: Vec<&str> = archive.file_names().collect();
+        names.sort_unstable();
+        assert_eq!(names, ["link-select.ani", "normal-select.cur"]);
+
+        let mut ani = Vec::new();
+        archive
+            .by_name("link-select.ani")
+            .unwrap()
+            .read_to_end(&mut ani)
+            .unwrap();
+        assert_eq!(&ani[8..12], b"ACON");
+    }
+
+    #[test]
+    fn oversized_frames_and_garbage_are_rejected() {
+        assert!(split_strip(&strip(600, 300), 2).is_err());
+        assert_eq!(split_strip(&strip(600, 200), 3).unwrap().len(), 3);
+        assert!(parse_theme(b"not a theme").is_err());
+    }
+}
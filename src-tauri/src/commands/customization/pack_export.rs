@@ -5,9 +5,14 @@ use tauri::{AppHandle, Runtime, State};
 use zip::write::FileOptions;
 
 use crate::cursor_defaults::populate_missing_cursor_paths_with_defaults;
+use crate::operations::{Operation, OperationPhase};
 use crate::state::{AppState, CustomizationMode};
 
-use super::library::LibraryPackItem;
+use super::library::{load_library, LibraryPackItem};
+use super::pack_manifest::{
+    normalize_pack_notes, CursorPackManifest, PackChangelogEntry, PackSounds,
+    PACK_MANIFEST_FILENAME,
+};
 use super::pack_library::{prepare_pack_archive_destination, register_pack_in_library};
 
 const SIMPLE_MODE_EXPORT_NAMES: [&str; 2] = ["Normal", "Hand"];
@@ -19,6 +24,7 @@ fn resolve_export_names(mode: CustomizationMode) -> Vec<&'static str> {
         CustomizationMode::Simple => SIMPLE_MODE_EXPORT_NAMES.to_vec(),
         CustomizationMode::Advanced => cursor_changer::DEFAULT_CURSOR_BASE_NAMES
             .iter()
+            .chain(cursor_changer::EXTENDED_CURSOR_BASE_NAMES.iter())
             .map(|(cursor_name, _)| *cursor_name)
             .collect(),
     }
@@ -81,7 +87,11 @@ fn collect_cursor_entries(
     let export_names = resolve_export_names(mode);
     let mut entries = Vec::new();
 
-    for (cursor_name, base_name) in cursor_changer::DEFAULT_CURSOR_BASE_NAMES.iter() {
+    // Extended roles are only exported when one is assigned
+    for (cursor_name, base_name) in cursor_changer::DEFAULT_CURSOR_BASE_NAMES
+        .iter()
+        .chain(cursor_changer::EXTENDED_CURSOR_BASE_NAMES.iter())
+    {
         if !export_names.contains(cursor_name) {
             continue;
         }
@@ -116,7 +126,11 @@ pub async fn export_active_cursor_pack<R: Runtime>(
     app: AppHandle<R>,
     state: State<'_, AppState>,
     pack_name: Option<String>,
+    description: Option<String>,
+    changelog: Option<Vec<PackChangelogEntry>>,
+    op_id: Option<String>,
 ) -> Result<Option<String>, String> {
+    let operation = Operation::begin(&app, op_id);
     let (mut cursor_paths, current_mode, cursor_style) = {
         let guard = state
             .read_all()
@@ -140,36 +154,71 @@ pub async fn export_active_cursor_pack<R: Runtime>(
     }
 
     let packs_dir = crate::paths::cursor_packs_dir()?;
+    // The archive is stored uncompressed and then extracted next to itself
+    let source_bytes: u64 = entries
+        .iter()
+        .filter_map(|(_, _, path)| fs::metadata(path).ok())
+        .map(|meta| meta.len())
+        .sum();
+    crate::disk_space::ensure_space(&packs_dir, source_bytes.saturating_mul(2))?;
     let desired_filename = determine_target_filename(current_mode, pack_name);
     let target_path = prepare_pack_archive_destination(&packs_dir, &desired_filename)?;
     let archive_path_str = target_path.to_string_lossy().to_string();
 
     let created_at = crate::utils::library_meta::now_iso8601_utc();
+    let (description, changelog) = normalize_pack_notes(description, changelog.unwrap_or_default());
+
+    let library_cursors = load_library(&app)?.cursors;
 
     let items: Vec<LibraryPackItem> = entries
         .iter()
-        .map(|(cursor_name, file_name, _source_path)| {
-            let display_name = cursor_changer::CURSOR_TYPES
-                .iter()
-                .find(|ct| ct.name == cursor_name)
+        .map(|(cursor_name, file_name, source_path)| {
+            let display_name = cursor_changer::find_cursor_type(cursor_name)
                 .map(|ct| ct.display_name.to_string())
                 .unwrap_or_else(|| cursor_name.clone());
 
+            // Carry attribution over from the library cursor the file came from
+            let source = library_cursors.iter().find(|c| {
+                !c.is_pack && std::path::Path::new(&c.file_path) == source_path.as_path()
+            });
+
             LibraryPackItem {
                 cursor_name: cursor_name.clone(),
                 display_name,
                 file_name: file_name.clone(),
                 file_path: None,
+                notes: source.and_then(|c| c.notes.clone()),
+                author: source.and_then(|c| c.author.clone()),
+                source_url: source.and_then(|c| c.source_url.clone()),
             }
         })
         .collect();
 
+    let manifest = CursorPackManifest {
+        version: 1,
+        pack_name: target_path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or("cursor-pack")
+            .to_string(),
+        mode: current_mode,
+        created_at: created_at.clone(),
+        items: items.clone(),
+        description,
+        changelog,
+        sounds: PackSounds::default(),
+    };
+    let manifest_json = serde_json::to_vec_pretty(&manifest)
+        .map_err(|e| format!("Failed to serialize pack manifest: {}", e))?;
+
     let cursor = IoCursor::new(Vec::new());
     let mut zip_writer = zip::ZipWriter::new(cursor);
     let options: FileOptions<'_, ()> =
         FileOptions::default().compression_method(zip::CompressionMethod::Stored);
 
+    let mut exporting = operation.phase(OperationPhase::Exporting, entries.len());
     for (_cursor_name, pack_filename, source_path) in &entries {
+        operation.checkpoint()?;
         let data =
             fs::read(source_path).map_err(|e| format!("Failed to read file {}: {}", source_path.display(), e))?;
 
@@ -179,8 +228,19 @@ pub async fn export_active_cursor_pack<R: Runtime>(
         zip_writer
             .write_all(&data)
             .map_err(|e| format!("Failed to write {} to zip: {}", pack_filename, e))?;
+        exporting.advance(Some(pack_filename));
     }
+    exporting.finish();
+
+    zip_writer
+        .start_file(PACK_MANIFEST_FILENAME, options)
+        .map_err(|e| format!("Failed to start zip entry {}: {}", PACK_MANIFEST_FILENAME, e))?;
+    zip_writer
+        .write_all(&manifest_json)
+        .map_err(|e| format!("Failed to write {} to zip: {}", PACK_MANIFEST_FILENAME, e))?;
 
+    // Last chance to back out before anything is written to the library
+    operation.checkpoint()?;
     let writer = zip_writer
         .finish()
         .map_err(|e| format!("Failed to finalize cursor pack zip: {}", e))?;
@@ -198,6 +258,7 @@ pub async fn export_active_cursor_pack<R: Runtime>(
         let mut archive = zip::ZipArchive::new(archive_file)
             .map_err(|e| format!("Failed to read pack archive for extraction: {}", e))?;
 
+        let mut extracting = operation.phase(OperationPhase::Extracting, archive.len());
         for i in 0..archive.len() {
             let mut entry = archive
                 .by_index(i)
@@ -214,9 +275,12 @@ pub async fn export_active_cursor_pack<R: Runtime>(
                 .map_err(|e| format!("Failed to create extracted file {}: {}", entry_name, e))?;
             std::io::copy(&mut entry, &mut out_file)
                 .map_err(|e| format!("Failed to extract file {}: {}", entry_name, e))?;
+            extracting.advance(Some(&entry_name));
         }
+        extracting.finish();
     }
 
+    let saving = operation.phase(OperationPhase::Saving, 1);
     register_pack_in_library(
         &app,
         &target_path,
@@ -224,6 +288,7 @@ pub async fn export_active_cursor_pack<R: Runtime>(
         items,
         Some(created_at),
     )?;
+    saving.finish();
 
     Ok(Some(archive_path_str))
 }
@@ -0,0 +1,419 @@
+//! Importing cursor packs straight from a download link.
+//!
+//! The archive is downloaded into memory (capped at [`MAX_PACK_DOWNLOAD_BYTES`]) and then goes
+//! through the same validation and registration as a pack picked from disk, so nothing is
+//! written unless the download is a valid pack.
+
+use std::io::Read;
+use std::time::Duration;
+
+use tauri::{AppHandle, Runtime};
+
+use crate::error_codes::{with_code, ErrorCode};
+use crate::operations::{Operation, OperationPhase};
+use crate::pending_jobs::{self, PendingJobKind};
+use crate::state::policy::check_marketplace_allowed;
+
+use super::library::LibraryCursor;
+use super::pack_commands::import_pack_bytes;
+use super::pack_updates::record_update_source;
+
+pub const MAX_PACK_DOWNLOAD_BYTES: u64 = 50 * 1024 * 1024;
+/// Time to establish the connection. Reads have their own timeout, so a large pack on a slow
+/// but steady link is not cut off part way.
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(15);
+/// Longest the server may go without sending anything once connected.
+const READ_TIMEOUT: Duration = Duration::from_secs(30);
+const DOWNLOAD_CHUNK_BYTES: usize = 64 * 1024;
+const FALLBACK_FILE_NAME: &str = "cursor-pack.zip";
+
+/// Content types servers use for .zip downloads. A missing header is accepted too; the
+/// archive itself is validated afterwards either way.
+const ZIP_CONTENT_TYPES: &[&str] = &[
+    "application/zip",
+    "application/x-zip",
+    "application/x-zip-compressed",
+    "application/octet-stream",
+    "binary/octet-stream",
+    "multipart/x-zip",
+];
+
+fn validate_url(url: &str) -> Result<&str, String> {
+    let url = url.trim();
+    let lower = url.to_ascii_lowercase();
+    if !(lower.starts_with("https://") || lower.starts_with("http://")) {
+        return Err("Enter an http:// or https:// link to a .zip cursor pack".to_string());
+    }
+    Ok(url)
+}
+
+fn check_content_type(content_type: Option<&str>) -> Result<(), String> {
+    let Some(content_type) = content_type else {
+        return Ok(());
+    };
+    let mime = content_type
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase();
+    if ZIP_CONTENT_TYPES.contains(&mime.as_str()) {
+        return Ok(());
+    }
+    if mime == "text/html" {
+        return Err(
+            "The link opens a web page, not a download. Copy the direct .zip download link instead."
+                .to_string(),
+        );
+    }
+    Err(format!("The link does not point to a .zip file ({mime})"))
+}
+
+/// Last path segment of `url` if it names a .zip, otherwise a generic name.
+fn file_name_from_url(url: &str) -> String {
+    let path = url
+        .split(['?', '#'])
+        .next()
+        .unwrap_or_default()
+        .split_once("://")
+        .map_or("", |(_, rest)| rest);
+    path.split('/')
+        .skip(1)
+        .last()
+        .filter(|segment| {
+            std::path::Path::new(segment)
+                .extension()
+                .is_some_and(|ext| ext.eq_ignore_ascii_case("zip"))
+        })
+        .map_or_else(|| FALLBACK_FILE_NAME.to_string(), str::to_string)
+}
+
+/// Read at most `limit` bytes, failing rather than truncating if there is more. `on_chunk`
+/// receives the running total and can abort the download by returning an error.
+fn read_capped<Rd: Read>(
+    mut reader: Rd,
+    limit: u64,
+    mut on_chunk: impl FnMut(usize) -> Result<(), String>,
+) -> Result<Vec<u8>, String> {
+    let mut data = Vec::new();
+    let mut chunk = vec![0u8; DOWNLOAD_CHUNK_BYTES];
+    loop {
+        let read = reader.read(&mut chunk).map_err(|e| {
+            with_code(
+                ErrorCode::PackDownload,
+                format!("Download interrupted: {e}"),
+            )
+        })?;
+        if read == 0 {
+            return Ok(data);
+        }
+        data.extend_from_slice(&chunk[..read]);
+        if data.len() as u64 > limit {
+            return Err(with_code(
+                ErrorCode::PackTooLarge,
+                format!("Cursor pack is larger than {} MB", limit / (1024 * 1024)),
+            ));
+        }
+        on_chunk(data.len())?;
+    }
+}
+
+/// Cache validators a server sent with a download, used to ask later whether it changed.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct PackValidators {
+    pub etag: Option<String>,
+    pub last_modified: Option<String>,
+}
+
+impl PackValidators {
+    fn from_response(response: &ureq::Response) -> Self {
+        let header = |name: &str| response.header(name).map(str::to_string);
+        Self {
+            etag: header("etag"),
+            last_modified: header("last-modified"),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.etag.is_none() && self.last_modified.is_none()
+    }
+}
+
+/// A downloaded archive and the validators that came with it.
+pub(crate) struct DownloadedPack {
+    pub data: Vec<u8>,
+    pub validators: PackValidators,
+}
+
+fn agent() -> ureq::Agent {
+    ureq::AgentBuilder::new()
+        .timeout_connect(CONNECT_TIMEOUT)
+        .timeout_read(READ_TIMEOUT)
+        .build()
+}
+
+fn content_length(response: &ureq::Response) -> Option<u64> {
+    response
+        .header("content-length")
+        .and_then(|len| len.trim().parse::<u64>().ok())
+}
+
+/// Download the .zip at `url` into memory, reporting progress (bytes) through `operation`.
+/// Cancelling the operation stops the download.
+pub(crate) fn download_pack<R: Runtime>(
+    operation: &Operation<R>,
+    url: &str,
+) -> Result<DownloadedPack, String> {
+    download_pack_if_changed(operation, url, &PackValidators::default(), None)?
+        .ok_or_else(|| with_code(ErrorCode::PackDownload, "Server sent no archive"))
+}
+
+/// [`download_pack`], but `None` when the server says the archive is unchanged since it sent
+/// `known`. Servers that gave no validators are asked for the size with a HEAD request
+/// instead, and an archive of `known_size` bytes is taken as unchanged.
+pub(crate) fn download_pack_if_changed<R: Runtime>(
+    operation: &Operation<R>,
+    url: &str,
+    known: &PackValidators,
+    known_size: Option<u64>,
+) -> Result<Option<DownloadedPack>, String> {
+    let url = validate_url(url)?;
+
+    operation.phase(OperationPhase::Connecting, 0);
+    let agent = agent();
+    if let (true, Some(size)) = (known.is_empty(), known_size) {
+        // A failed HEAD is not an error; plenty of servers only answer GET
+        if let Ok(head) = agent.head(url).call() {
+            if content_length(&head) == Some(size) {
+                return Ok(None);
+            }
+        }
+    }
+
+    let mut request = agent.get(url);
+    if let Some(etag) = &known.etag {
+        request = request.set("If-None-Match", etag);
+    }
+    if let Some(last_modified) = &known.last_modified {
+        request = request.set("If-Modified-Since", last_modified);
+    }
+    let response = request
+        .call()
+        .map_err(|e| with_code(ErrorCode::PackDownload, format!("Download failed: {e}")))?;
+    if response.status() == 304 {
+        return Ok(None);
+    }
+    check_content_type(response.header("content-type"))?;
+
+    let content_length = content_length(&response);
+    if content_length.is_some_and(|len| len > MAX_PACK_DOWNLOAD_BYTES) {
+        return Err(with_code(
+            ErrorCode::PackTooLarge,
+            format!(
+                "Cursor pack is larger than {} MB",
+                MAX_PACK_DOWNLOAD_BYTES / (1024 * 1024)
+            ),
+        ));
+    }
+    let total = usize::try_from(content_length.unwrap_or(0)).unwrap_or(usize::MAX);
+    let validators = PackValidators::from_response(&response);
+
+    let mut downloading = operation.phase(OperationPhase::Downloading, total);
+    let data = read_capped(response.into_reader(), MAX_PACK_DOWNLOAD_BYTES, |done| {
+        operation.checkpoint()?;
+        downloading.set(done, total, None);
+        Ok(())
+    })?;
+    downloading.finish();
+    Ok(Some(DownloadedPack { data, validators }))
+}
+
+/// Download a .zip cursor pack from `url`, validate it and add it to the library. The link
+/// is kept with the pack so later versions can be picked up (see `pack_updates`).
+///
+/// Reports download progress (bytes) through `op_id` and can be cancelled while downloading.
+/// Refused while the administrator has turned off the marketplace.
+#[tauri::command]
+pub async fn import_cursor_pack_from_url<R: Runtime>(
+    app: AppHandle<R>,
+    url: String,
+    op_id: Option<String>,
+) -> Result<LibraryCursor, String> {
+    tauri::async_runtime::spawn_blocking(move || import_pack_from_url(&app, &url, op_id))
+        .await
+        .map_err(|e| format!("Task join error: {}", e))?
+}
+
+/// Blocking body of [`import_cursor_pack_from_url`], also used to resume interrupted imports.
+pub(crate) fn import_pack_from_url<R: Runtime>(
+    app: &AppHandle<R>,
+    url: &str,
+    op_id: Option<String>,
+) -> Result<LibraryCursor, String> {
+    check_marketplace_allowed(&app)?;
+    let _job = pending_jobs::track(
+        PendingJobKind::ImportPackFromUrl,
+        url,
+        &format!("Import cursor pack from {}", url),
+        op_id.as_deref(),
+    );
+    let operation = Operation::begin(app, op_id);
+    let url = validate_url(url)?;
+    let download = download_pack(&operation, url)?;
+
+    let filename = file_name_from_url(url);
+    cc_info!(
+        "[CursorChanger] Downloaded cursor pack {} ({} bytes)",
+        filename,
+        download.data.len()
+    );
+    let pack = import_pack_bytes(app, &operation, &filename, &download.data)?;
+    match record_update_source(app, &pack.id, url, &download) {
+        Ok(pack) => Ok(pack),
+        Err(e) => {
+            cc_warn!(
+                "[CursorChanger] Updates for {} will not be checked: {}",
+                pack.name,
+                e
+            );
+            Ok(pack)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_web_links_are_accepted() {
+        assert!(validate_url(" https://example.com/pack.zip ").is_ok());
+        assert!(validate_url("HTTP://example.com/pack.zip").is_ok());
+        assert!(validate_url("file:///C:/pack.zip").is_err());
+        assert!(validate_url("example.com/pack.zip").is_err());
+    }
+
+    #[test]
+    fn content_type_check_rejects_web_pages() {
+        assert!(check_content_type(None).is_ok());
+        assert!(check_content_type(Some("application/zip")).is_ok());
+        assert!(check_content_type(Some("application/octet-stream; charset=binary")).is_ok());
+        assert!(check_content_type(Some("text/html; charset=utf-8")).is_err());
+        assert!(check_content_type(Some("image/png")).is_err());
+    }
+
+    #[test]
+    fn file_name_comes_from_zip_links_only() {
+        assert_eq!(
+            file_name_from_url("https://example.com/packs/Neon.zip?download=1"),
+            "Neon.zip"
+        );
+        assert_eq!(
+            file_name_from_url("https://example.com/download/1234"),
+            FALLBACK_FILE_NAME
+        );
+        assert_eq!(
+            file_name_from_url("https://example.com"),
+            FALLBACK_FILE_NAME
+        );
+    }
+
+    #[test]
+    fn downloads_are_refused_when_the_marketplace_is_disabled() {
+        use crate::state::AppState;
+        use tauri::Manager;
+
+        let app = tauri::test::mock_app();
+        let handle = app.handle().clone();
+        handle.manage(AppState::default());
+        handle
+            .state::<AppState>()
+            .policy
+            .write()
+            .unwrap()
+            .disable_marketplace = true;
+
+        let err = import_pack_from_url(&handle, "https://example.com/pack.zip", None).unwrap_err();
+        assert!(err.contains("disabled by your administrator"));
+    }
+
+    /// Answer one connection per entry of `responses` and hand back the requests received.
+    fn serve(responses: Vec<String>) -> (String, std::thread::JoinHandle<Vec<String>>) {
+        use std::io::Write;
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").expect("bind");
+        let url = format!("http://{}/pack.zip", listener.local_addr().unwrap());
+        let server = std::thread::spawn(move || {
+            responses
+                .into_iter()
+                .map(|response| {
+                    let (mut stream, _) = listener.accept().expect("accept");
+                    let mut request = Vec::new();
+                    let mut buf = [0u8; 1024];
+                    while !request.ends_with(b"\r\n\r\n") {
+                        let read = stream.read(&mut buf).expect("read request");
+                        if read == 0 {
+                            break;
+                        }
+                        request.extend_from_slice(&buf[..read]);
+                    }
+                    stream.write_all(response.as_bytes()).expect("respond");
+                    String::from_utf8_lossy(&request).to_ascii_lowercase()
+                })
+                .collect()
+        });
+        (url, server)
+    }
+
+    #[test]
+    fn unchanged_archives_are_not_downloaded_again() {
+        let app = tauri::test::mock_app();
+        let operation = Operation::begin(app.handle(), None);
+        let (url, server) = serve(vec![
+            "HTTP/1.1 200 OK\r\nContent-Type: application/zip\r\nContent-Length: 4\r\n\
+             ETag: \"v1\"\r\nConnection: close\r\n\r\nPK\x03\x04"
+                .to_string(),
+            "HTTP/1.1 304 Not Modified\r\nETag: \"v1\"\r\nConnection: close\r\n\r\n".to_string(),
+        ]);
+
+        let first = download_pack(&operation, &url).unwrap();
+        assert_eq!(first.data, b"PK\x03\x04");
+        assert_eq!(first.validators.etag.as_deref(), Some("\"v1\""));
+
+        let second = download_pack_if_changed(&operation, &url, &first.validators, Some(4));
+        assert!(second.unwrap().is_none());
+
+        let requests = server.join().unwrap();
+        assert!(requests[1].contains("if-none-match: \"v1\""));
+    }
+
+    #[test]
+    fn servers_without_validators_are_asked_for_the_size() {
+        let app = tauri::test::mock_app();
+        let operation = Operation::begin(app.handle(), None);
+        let (url, server) = serve(vec![
+            "HTTP/1.1 200 OK\r\nContent-Length: 4\r\nConnection: close\r\n\r\n".to_string(),
+        ]);
+
+        let result =
+            download_pack_if_changed(&operation, &url, &PackValidators::default(), Some(4));
+        assert!(result.unwrap().is_none());
+        assert!(server.join().unwrap()[0].starts_with("head "));
+    }
+
+    #[test]
+    fn downloads_over_the_cap_fail() {
+        let data = vec![7u8; 300];
+        let mut seen = 0;
+        let read = read_capped(data.as_slice(), 300, |done| {
+            seen = done;
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!(read.len(), 300);
+        assert_eq!(seen, 300);
+
+        let err = read_capped(data.as_slice(), 299, |_| Ok(())).unwrap_err();
+        assert!(err.starts_with("E-PACK-003: "));
+    }
+}
@@ -0,0 +1,276 @@
+/// Integrity checks for library cursor packs.
+///
+/// Cloud-synced library folders can leave an archive half downloaded, which only shows up
+/// later as a pack that applies with missing roles. `verify_pack_integrity` re-reads every
+/// manifest item from the archive, makes sure it decodes, and compares it with the copies
+/// extracted next to the archive and mounted under `pack-mounts/<pack id>/`.
+use std::fs;
+use std::io::{Read, Seek};
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+use tauri::{AppHandle, Runtime};
+use zip::ZipArchive;
+
+use super::library::{get_cursor_preview_from_bytes, load_library};
+use super::pack_manifest::read_manifest_from_path;
+use super::pack_mount::content_addressed_name;
+
+#[derive(ts_rs::TS, Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub enum PackIntegrityIssueKind {
+    /// Listed in the manifest but not in the archive
+    Missing,
+    /// In the archive but unreadable (bad checksum, truncated) or not a valid cursor
+    Corrupt,
+    /// An extracted or mounted copy differs from the archive
+    CacheMismatch,
+}
+
+#[derive(ts_rs::TS, Debug, Clone, PartialEq, Eq, Serialize)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct PackIntegrityIssue {
+    pub file_name: String,
+    pub kind: PackIntegrityIssueKind,
+    pub detail: String,
+}
+
+#[derive(ts_rs::TS, Debug, Clone, PartialEq, Eq, Serialize)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct PackIntegrityReport {
+    pub pack_id: String,
+    /// Manifest items checked
+    pub checked: u32,
+    pub issues: Vec<PackIntegrityIssue>,
+}
+
+fn issue(file_name: &str, kind: PackIntegrityIssueKind, detail: String) -> PackIntegrityIssue {
+    PackIntegrityIssue {
+        file_name: file_name.to_string(),
+        kind,
+        detail,
+    }
+}
+
+/// Check each of `items` in `archive`. `decode` rejects bytes that are not a usable cursor.
+/// Copies found in `extracted_dir` (same file name) are compared with the archive bytes.
+fn verify_items<R: Read + Seek>(
+    archive: &mut ZipArchive<R>,
+    items: &[String],
+    extracted_dir: Option<&Path>,
+    decode: impl Fn(&[u8], &str) -> Result<(), String>,
+) -> Vec<PackIntegrityIssue> {
+    let mut issues = Vec::new();
+    for file_name in items {
+        let mut entry = match archive.by_name(file_name) {
+            Ok(entry) => entry,
+            Err(e) => {
+                issues.push(issue(
+                    file_name,
+                    PackIntegrityIssueKind::Missing,
+                    e.to_string(),
+                ));
+                continue;
+            }
+        };
+        // Reading to the end verifies the entry's CRC
+        let mut bytes = Vec::new();
+        if let Err(e) = entry.read_to_end(&mut bytes) {
+            issues.push(issue(
+                file_name,
+                PackIntegrityIssueKind::Corrupt,
+                e.to_string(),
+            ));
+            continue;
+        }
+        if let Err(e) = decode(&bytes, file_name) {
+            issues.push(issue(file_name, PackIntegrityIssueKind::Corrupt, e));
+            continue;
+        }
+
+        let Some(cached) = extracted_dir
+            .map(|dir| dir.join(file_name))
+            .and_then(|path| fs::read(path).ok())
+        else {
+            continue;
+        };
+        if content_addressed_name(&cached, file_name) != content_addressed_name(&bytes, file_name) {
+            issues.push(issue(
+                file_name,
+                PackIntegrityIssueKind::CacheMismatch,
+                "Extracted copy differs from the archive".to_string(),
+            ));
+        }
+    }
+    issues
+}
+
+/// Mounted files are named after the hash of their contents, so any file whose contents no
+/// longer match its name is damaged.
+fn verify_mounts(mount_dir: &Path) -> Vec<PackIntegrityIssue> {
+    let Ok(entries) = fs::read_dir(mount_dir) else {
+        return Vec::new();
+    };
+    let mut issues = Vec::new();
+    for path in entries.flatten().map(|e| e.path()) {
+        let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+            continue;
+        };
+        let Ok(bytes) = fs::read(&path) else {
+            continue;
+        };
+        if content_addressed_name(&bytes, name) != name.to_ascii_lowercase() {
+            issues.push(issue(
+                name,
+                PackIntegrityIssueKind::CacheMismatch,
+                "Mounted copy no longer matches its content hash".to_string(),
+            ));
+        }
+    }
+    issues
+}
+
+/// Re-open the archive of library pack `id` and report missing, corrupt or stale files.
+/// Read-only: nothing is repaired, so the UI can offer to re-import instead.
+#[tauri::command]
+pub fn verify_pack_integrity<R: Runtime>(
+    app: AppHandle<R>,
+    id: String,
+) -> Result<PackIntegrityReport, String> {
+    let pack = load_library(&app)?
+        .cursors
+        .into_iter()
+        .find(|c| c.id == id && c.is_pack)
+        .ok_or_else(|| "Cursor pack not found in library".to_string())?;
+    let archive_path = PathBuf::from(&pack.file_path);
+    let archive_name = archive_path
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or("archive")
+        .to_string();
+
+    let items: Vec<String> = read_manifest_from_path(&archive_path)
+        .map(|m| m.items)
+        .or_else(|| pack.pack_metadata.map(|m| m.items))
+        .unwrap_or_default()
+        .into_iter()
+        .map(|item| item.file_name)
+        .filter(|name| !name.trim().is_empty())
+        .collect();
+    let checked = u32::try_from(items.len()).unwrap_or(u32::MAX);
+
+    let archive = fs::File::open(&archive_path)
+        .map_err(|e| e.to_string())
+        .and_then(|file| ZipArchive::new(file).map_err(|e| e.to_string()));
+    let mut issues = match archive {
+        Ok(mut archive) => verify_items(
+            &mut archive,
+            &items,
+            archive_path.parent(),
+            |bytes, name| get_cursor_preview_from_bytes(bytes, Some(name)).map(|_| ()),
+        ),
+        Err(e) => vec![issue(
+            &archive_name,
+            if archive_path.exists() {
+                PackIntegrityIssueKind::Corrupt
+            } else {
+                PackIntegrityIssueKind::Missing
+            },
+            e,
+        )],
+    };
+    if let Ok(mounts) = crate::paths::pack_mounts_dir() {
+        issues.extend(verify_mounts(&mounts.join(&pack.id)));
+    }
+
+    if issues.is_empty() {
+        cc_debug!(
+            "[CursorCustomization] Pack {} passed integrity check",
+            pack.id
+        );
+    } else {
+        cc_warn!(
+            "[CursorCustomization] Pack {} has {} integrity issue(s)",
+            pack.id,
+            issues.len()
+        );
+    }
+    Ok(PackIntegrityReport {
+        pack_id: pack.id,
+        checked,
+        issues,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Cursor, Write};
+    use zip::write::SimpleFileOptions;
+
+    fn build_zip(files: &[(&str, &[u8])]) -> ZipArchive<Cursor<Vec<u8>>> {
+        let mut writer = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        for (name, bytes) in files {
+            writer
+                .start_file(*name, SimpleFileOptions::default())
+                .expect("start file");
+            writer.write_all(bytes).expect("write file");
+        }
+        let buffer = writer.finish().expect("finish zip");
+        ZipArchive::new(buffer).expect("read zip")
+    }
+
+    fn decode_unless_bad(bytes: &[u8], _name: &str) -> Result<(), String> {
+        if bytes == b"bad" {
+            Err("not a cursor".to_string())
+        } else {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn reports_missing_undecodable_and_stale_items() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        fs::write(temp.path().join("normal.cur"), b"arrow").unwrap();
+        fs::write(temp.path().join("hand.cur"), b"old hand").unwrap();
+        let mut archive = build_zip(&[
+            ("normal.cur", b"arrow"),
+            ("hand.cur", b"hand"),
+            ("busy.ani", b"bad"),
+        ]);
+        let items: Vec<String> = ["normal.cur", "hand.cur", "busy.ani", "text.cur"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+
+        let issues = verify_items(&mut archive, &items, Some(temp.path()), decode_unless_bad);
+        let kinds: Vec<(&str, PackIntegrityIssueKind)> = issues
+            .iter()
+            .map(|i| (i.file_name.as_str(), i.kind))
+            .collect();
+
+        assert_eq!(
+            kinds,
+            vec![
+                ("hand.cur", PackIntegrityIssueKind::CacheMismatch),
+                ("busy.ani", PackIntegrityIssueKind::Corrupt),
+                ("text.cur", PackIntegrityIssueKind::Missing),
+            ]
+        );
+    }
+
+    #[test]
+    fn mounted_files_must_match_their_hash() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let good = content_addressed_name(b"arrow", "normal.cur");
+        fs::write(temp.path().join(&good), b"arrow").unwrap();
+        let damaged = content_addressed_name(b"hand", "hand.cur");
+        fs::write(temp.path().join(&damaged), b"han").unwrap();
+
+        let issues = verify_mounts(temp.path());
+
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].file_name, damaged);
+    }
+}
@@ -10,10 +10,11 @@ use crate::state::CustomizationMode;
 use crate::utils::library_meta::now_iso8601_utc;
 
 use super::library::{
-    get_cursor_preview_from_bytes, load_library, save_library, LibraryCursor, LibraryData,
-    LibraryPackItem, LibraryPackMetadata,
+    get_cursor_preview_from_bytes, load_library, lock_library, save_library, LibraryCursor,
+    LibraryData, LibraryPackItem, LibraryPackMetadata,
 };
-use super::pack_manifest::{CursorPackManifest, PACK_MANIFEST_FILENAME};
+use super::pack_manifest::{read_manifest_from_path, CursorPackManifest, PACK_MANIFEST_FILENAME};
+use super::pack_sprite::build_pack_sprite;
 
 pub(crate) const CURRENT_PREVIEW_CACHE_VERSION: u32 = 1;
 
@@ -222,6 +223,7 @@ pub fn register_pack_in_library<R: Runtime>(
     items: Vec<LibraryPackItem>,
     created_at_override: Option<String>,
 ) -> Result<LibraryCursor, String> {
+    let _lock = lock_library(app)?;
     let mut library = load_library(app)?;
     register_pack_in_library_with_data(app, &mut library, pack_path, mode, items, created_at_override)
 }
@@ -247,12 +249,23 @@ pub fn register_pack_in_library_with_data<R: Runtime>(
         }
     };
 
+    let manifest = read_manifest_from_path(pack_path);
     let mut metadata = LibraryPackMetadata {
         mode,
         archive_path: archive_path.clone(),
         items,
+        sprite: previews.as_ref().and_then(build_pack_sprite),
         previews,
         previews_version,
+        rating: None,
+        installed_version: manifest.as_ref().and_then(|m| m.latest_version()),
+        sounds: manifest.map(|m| m.sounds.events()).unwrap_or_default(),
+        sounds_enabled: false,
+        update_url: None,
+        archive_hash: None,
+        update_etag: None,
+        update_last_modified: None,
+        update_available: false,
     };
 
     ensure_pack_files_present(pack_path, &mut metadata.items)?;
@@ -266,6 +279,12 @@ pub fn register_pack_in_library_with_data<R: Runtime>(
         created_at,
         is_pack: true,
         pack_metadata: Some(metadata),
+        native_size: None,
+        scaled_variants: Vec::new(),
+        notes: None,
+        author: None,
+        source_url: None,
+        source_link: None,
     };
 
     library.cursors.push(cursor.clone());
@@ -277,6 +296,7 @@ pub fn ensure_pack_previews<R: Runtime>(
     app: &AppHandle<R>,
     pack_id: &str,
 ) -> Result<HashMap<String, String>, String> {
+    let _lock = lock_library(app)?;
     let mut library = load_library(app)?;
     let cursor = library
         .cursors
@@ -301,6 +321,7 @@ pub fn ensure_pack_previews<R: Runtime>(
 
     let archive_path = PathBuf::from(&metadata.archive_path);
     let previews = generate_pack_previews_from_archive(&archive_path)?;
+    metadata.sprite = build_pack_sprite(&previews);
     metadata.previews = Some(previews.clone());
     metadata.previews_version = Some(CURRENT_PREVIEW_CACHE_VERSION);
     save_library(app, &library)?;
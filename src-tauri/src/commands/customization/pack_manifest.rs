@@ -10,6 +10,65 @@ use crate::state::CustomizationMode;
 use super::library::LibraryPackItem;
 
 pub const PACK_MANIFEST_FILENAME: &str = "cursor-pack.json";
+/// Largest sound file a pack may bundle, so cues stay short.
+pub const MAX_PACK_SOUND_BYTES: u64 = 512 * 1024;
+
+/// One release note in a pack's changelog, newest first in the manifest.
+#[derive(ts_rs::TS, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct PackChangelogEntry {
+    /// Pack author's version label, e.g. "1.2"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    /// ISO 8601 date of the change
+    pub date: String,
+    pub notes: String,
+}
+
+/// Moments a pack can play a bundled sound for.
+#[derive(ts_rs::TS, Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub enum PackSoundEvent {
+    /// The pack was applied
+    Apply,
+    /// The cursor was hidden or shown
+    Toggle,
+}
+
+/// WAV files in the pack archive, by name, played for each [`PackSoundEvent`].
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct PackSounds {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub apply: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub toggle: Option<String>,
+}
+
+impl PackSounds {
+    pub fn is_empty(&self) -> bool {
+        self.apply.is_none() && self.toggle.is_none()
+    }
+
+    pub fn get(&self, event: PackSoundEvent) -> Option<&str> {
+        match event {
+            PackSoundEvent::Apply => self.apply.as_deref(),
+            PackSoundEvent::Toggle => self.toggle.as_deref(),
+        }
+    }
+
+    /// Events this pack has a sound for.
+    pub fn events(&self) -> Vec<PackSoundEvent> {
+        [PackSoundEvent::Apply, PackSoundEvent::Toggle]
+            .into_iter()
+            .filter(|event| self.get(*event).is_some())
+            .collect()
+    }
+
+    pub fn file_names(&self) -> impl Iterator<Item = &str> {
+        self.apply.as_deref().into_iter().chain(self.toggle.as_deref())
+    }
+}
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct CursorPackManifest {
@@ -18,8 +77,202 @@ pub struct CursorPackManifest {
     pub mode: CustomizationMode,
     pub created_at: String,
     pub items: Vec<LibraryPackItem>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub changelog: Vec<PackChangelogEntry>,
+    #[serde(default, skip_serializing_if = "PackSounds::is_empty")]
+    pub sounds: PackSounds,
 }
 
+impl CursorPackManifest {
+    /// Version label of the newest changelog entry that has one.
+    pub fn latest_version(&self) -> Option<String> {
+        self.changelog
+            .iter()
+            .find_map(|entry| entry.version.clone())
+    }
+}
 
+/// Trimmed description and changelog for a manifest: blank text is dropped and entries are
+/// ordered newest first.
+pub fn normalize_pack_notes(
+    description: Option<String>,
+    changelog: Vec<PackChangelogEntry>,
+) -> (Option<String>, Vec<PackChangelogEntry>) {
+    let description = description
+        .map(|d| d.trim().to_string())
+        .filter(|d| !d.is_empty());
+    let mut changelog: Vec<PackChangelogEntry> = changelog
+        .into_iter()
+        .filter(|entry| !entry.notes.trim().is_empty())
+        .map(|entry| PackChangelogEntry {
+            version: entry
+                .version
+                .map(|v| v.trim().to_string())
+                .filter(|v| !v.is_empty()),
+            date: entry.date.trim().to_string(),
+            notes: entry.notes.trim().to_string(),
+        })
+        .collect();
+    // ISO 8601 dates sort lexically; the sort is stable for same-day entries
+    changelog.sort_by(|a, b| b.date.cmp(&a.date));
+    (description, changelog)
+}
 
+/// Read `cursor-pack.json` from an archive, if present and valid.
+pub fn read_manifest<R: Read + std::io::Seek>(
+    archive: &mut ZipArchive<R>,
+) -> Option<CursorPackManifest> {
+    let mut entry = archive.by_name(PACK_MANIFEST_FILENAME).ok()?;
+    let mut contents = String::new();
+    entry.read_to_string(&mut contents).ok()?;
+    serde_json::from_str(&contents).ok()
+}
 
+/// Read the manifest from a pack archive on disk.
+pub fn read_manifest_from_path(archive_path: &Path) -> Option<CursorPackManifest> {
+    load_manifest_from_path(archive_path).ok().flatten()
+}
+
+/// Like [`read_manifest_from_path`], but tells a pack without a manifest (`Ok(None)`) from one
+/// whose archive or manifest cannot be read.
+pub fn load_manifest_from_path(archive_path: &Path) -> Result<Option<CursorPackManifest>, String> {
+    let file = File::open(archive_path).map_err(|e| format!("Failed to open pack: {}", e))?;
+    let mut archive =
+        ZipArchive::new(file).map_err(|e| format!("Pack is not a valid ZIP archive: {}", e))?;
+    let mut entry = match archive.by_name(PACK_MANIFEST_FILENAME) {
+        Ok(entry) => entry,
+        Err(zip::result::ZipError::FileNotFound) => return Ok(None),
+        Err(e) => return Err(format!("Failed to read {}: {}", PACK_MANIFEST_FILENAME, e)),
+    };
+    let mut contents = String::new();
+    entry
+        .read_to_string(&mut contents)
+        .map_err(|e| format!("Failed to read {}: {}", PACK_MANIFEST_FILENAME, e))?;
+    serde_json::from_str(&contents)
+        .map(Some)
+        .map_err(|e| format!("Invalid {}: {}", PACK_MANIFEST_FILENAME, e))
+}
+
+/// Copy notes and attribution from manifest items onto `items` with the same file name.
+pub fn merge_attribution(items: &mut [LibraryPackItem], manifest: &CursorPackManifest) {
+    for item in items.iter_mut() {
+        let Some(source) = manifest
+            .items
+            .iter()
+            .find(|m| m.file_name.eq_ignore_ascii_case(&item.file_name))
+        else {
+            continue;
+        };
+        item.notes = source.notes.clone();
+        item.author = source.author.clone();
+        item.source_url = source.source_url.clone();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn attribution_is_matched_by_file_name() {
+        let manifest = CursorPackManifest {
+            version: 1,
+            pack_name: "Neon".to_string(),
+            mode: CustomizationMode::Advanced,
+            created_at: String::new(),
+            items: vec![LibraryPackItem {
+                file_name: "Normal-Select.cur".to_string(),
+                author: Some("Ada".to_string()),
+                source_url: Some("https://example.com/neon".to_string()),
+                ..Default::default()
+            }],
+            description: None,
+            changelog: Vec::new(),
+            sounds: PackSounds::default(),
+        };
+        let mut items = vec![
+            LibraryPackItem {
+                file_name: "normal-select.cur".to_string(),
+                ..Default::default()
+            },
+            LibraryPackItem {
+                file_name: "link-select.cur".to_string(),
+                ..Default::default()
+            },
+        ];
+
+        merge_attribution(&mut items, &manifest);
+
+        assert_eq!(items[0].author.as_deref(), Some("Ada"));
+        assert_eq!(items[0].source_url.as_deref(), Some("https://example.com/neon"));
+        assert_eq!(items[1].author, None);
+    }
+
+    #[test]
+    fn older_manifests_without_notes_still_parse() {
+        let manifest: CursorPackManifest = serde_json::from_str(
+            r#"{"version":1,"pack_name":"Neon","mode":"advanced","created_at":"","items":[]}"#,
+        )
+        .unwrap();
+        assert_eq!(manifest.description, None);
+        assert!(manifest.changelog.is_empty());
+        assert_eq!(manifest.latest_version(), None);
+        assert!(manifest.sounds.is_empty());
+    }
+
+    #[test]
+    fn sounds_are_listed_per_event() {
+        let manifest: CursorPackManifest = serde_json::from_str(
+            r#"{"version":1,"pack_name":"Neon","mode":"advanced","created_at":"","items":[],
+                "sounds":{"toggle":"sounds/blip.wav"}}"#,
+        )
+        .unwrap();
+        assert_eq!(manifest.sounds.events(), vec![PackSoundEvent::Toggle]);
+        assert_eq!(
+            manifest.sounds.get(PackSoundEvent::Toggle),
+            Some("sounds/blip.wav")
+        );
+        assert_eq!(manifest.sounds.get(PackSoundEvent::Apply), None);
+        assert_eq!(
+            manifest.sounds.file_names().collect::<Vec<_>>(),
+            vec!["sounds/blip.wav"]
+        );
+    }
+
+    #[test]
+    fn latest_version_skips_unversioned_entries() {
+        let manifest: CursorPackManifest = serde_json::from_str(
+            r#"{"version":1,"pack_name":"Neon","mode":"advanced","created_at":"","items":[],
+                "changelog":[{"date":"2025-03-01","notes":"Fix"},
+                             {"version":"1.2","date":"2025-02-01","notes":"Hand"},
+                             {"version":"1.0","date":"2025-01-01","notes":"First"}]}"#,
+        )
+        .unwrap();
+        assert_eq!(manifest.latest_version().as_deref(), Some("1.2"));
+    }
+
+    #[test]
+    fn notes_are_trimmed_and_sorted_newest_first() {
+        let entry = |date: &str, notes: &str| PackChangelogEntry {
+            version: Some(" ".to_string()),
+            date: date.to_string(),
+            notes: notes.to_string(),
+        };
+        let (description, changelog) = normalize_pack_notes(
+            Some("   ".to_string()),
+            vec![
+                entry("2025-01-01", "First release"),
+                entry("2025-03-01", " Bigger hand cursor "),
+                entry("2025-02-01", "  "),
+            ],
+        );
+
+        assert_eq!(description, None);
+        assert_eq!(changelog.len(), 2);
+        assert_eq!(changelog[0].notes, "Bigger hand cursor");
+        assert_eq!(changelog[0].version, None);
+        assert_eq!(changelog[1].date, "2025-01-01");
+    }
+}
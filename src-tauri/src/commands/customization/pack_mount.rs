@@ -0,0 +1,186 @@
+/// Apply cursor packs straight from the archive.
+///
+/// Instead of extracting every file, only the entries for the roles being applied are
+/// streamed into `library/pack-mounts/<pack id>/` under content-addressed names. Identical
+/// content maps to the same file, so re-applying a pack writes nothing, and files no longer
+/// referenced by any cursor path are removed afterwards.
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::io::{Read, Seek};
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard};
+use zip::ZipArchive;
+
+/// Serializes writers and cleaners of mounted files. Applying a pack mounts its files before
+/// cursor state points at them, so a cleanup in between would delete them as unreferenced.
+static MOUNT_LOCK: Mutex<()> = Mutex::new(());
+
+/// Hold while mounting files and updating cursor state, or while deleting mounted files.
+pub(crate) fn lock_mounts() -> MutexGuard<'static, ()> {
+    MOUNT_LOCK.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// 64-bit FNV-1a of `bytes`. FNV is stable across builds, unlike `DefaultHasher`, so hashes
+/// can be stored and compared after a restart.
+pub(crate) fn fnv1a_64(bytes: &[u8]) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in bytes {
+        hash ^= u64::from(*byte);
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+    hash
+}
+
+/// Content-addressed file name: [`fnv1a_64`] of the bytes plus the original extension, so
+/// names survive restarts.
+pub(crate) fn content_addressed_name(bytes: &[u8], original_name: &str) -> String {
+    let hash = fnv1a_64(bytes);
+    let ext = Path::new(original_name)
+        .extension()
+        .and_then(|s| s.to_str())
+        .map(|s| s.to_ascii_lowercase())
+        .unwrap_or_else(|| "cur".to_string());
+    format!("{:016x}.{}", hash, ext)
+}
+
+/// Stream the archive entries for the requested roles into `mount_dir`.
+/// `roles` maps cursor role name -> archive entry name; missing entries are skipped.
+pub(crate) fn mount_pack_roles<R: Read + Seek>(
+    archive: &mut ZipArchive<R>,
+    roles: &[(String, String)],
+    mount_dir: &Path,
+) -> Result<HashMap<String, PathBuf>, String> {
+    fs::create_dir_all(mount_dir).map_err(|e| format!("Failed to prepare pack folder: {e}"))?;
+
+    let mut mounted = HashMap::new();
+    for (role, entry_name) in roles {
+        let mut entry = match archive.by_name(entry_name) {
+            Ok(entry) => entry,
+            Err(err) => {
+                cc_warn!(
+                    "[CursorCustomization] Cursor file {} missing from archive: {}",
+                    entry_name,
+                    err
+                );
+                continue;
+            }
+        };
+
+        let mut bytes = Vec::with_capacity(entry.size() as usize);
+        entry
+            .read_to_end(&mut bytes)
+            .map_err(|e| format!("Failed to read {entry_name} from pack: {e}"))?;
+
+        let out_path = mount_dir.join(content_addressed_name(&bytes, entry_name));
+        if !out_path.exists() {
+            let tmp_path = out_path.with_extension("tmp");
+            fs::write(&tmp_path, &bytes).map_err(|e| format!("Failed to write file: {e}"))?;
+            fs::rename(&tmp_path, &out_path).map_err(|e| format!("Failed to write file: {e}"))?;
+        }
+        mounted.insert(role.clone(), out_path);
+    }
+
+    Ok(mounted)
+}
+
+/// Whether any path in `referenced` is a file mounted in `mount_dir`.
+pub(crate) fn holds_referenced(mount_dir: &Path, referenced: &HashSet<PathBuf>) -> bool {
+    referenced
+        .iter()
+        .filter_map(|path| path.parent())
+        .any(|parent| crate::paths::canonical_eq(parent, mount_dir))
+}
+
+/// Delete files in `mount_dir` that are not in `referenced`. Returns how many were removed.
+pub(crate) fn prune_unreferenced(mount_dir: &Path, referenced: &HashSet<PathBuf>) -> usize {
+    let Ok(entries) = fs::read_dir(mount_dir) else {
+        return 0;
+    };
+
+    let mut removed = 0;
+    for path in entries.flatten().map(|e| e.path()) {
+        if !path.is_file()
+            || referenced
+                .iter()
+                .any(|r| crate::paths::canonical_eq(r, &path))
+        {
+            continue;
+        }
+        match fs::remove_file(&path) {
+            Ok(()) => removed += 1,
+            Err(e) => cc_warn!(
+                "[CursorCustomization] Failed to remove unused pack file {}: {}",
+                path.display(),
+                e
+            ),
+        }
+    }
+    removed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Cursor, Write};
+    use zip::write::SimpleFileOptions;
+
+    fn build_zip(files: &[(&str, &[u8])]) -> ZipArchive<Cursor<Vec<u8>>> {
+        let mut writer = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        for (name, bytes) in files {
+            writer
+                .start_file(*name, SimpleFileOptions::default())
+                .expect("start file");
+            writer.write_all(bytes).expect("write file");
+        }
+        let buffer = writer.finish().expect("finish zip");
+        ZipArchive::new(buffer).expect("read zip")
+    }
+
+    #[test]
+    fn mounts_only_requested_roles_and_dedupes_content() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let mut archive = build_zip(&[
+            ("normal.cur", b"arrow"),
+            ("hand.cur", b"arrow"),
+            ("busy.ani", b"spinner"),
+        ]);
+
+        let roles = vec![
+            ("Normal".to_string(), "normal.cur".to_string()),
+            ("Hand".to_string(), "hand.cur".to_string()),
+            ("Missing".to_string(), "nope.cur".to_string()),
+        ];
+        let mounted = mount_pack_roles(&mut archive, &roles, temp.path()).expect("mount");
+
+        assert_eq!(mounted.len(), 2);
+        assert_eq!(mounted["Normal"], mounted["Hand"]);
+        assert_eq!(fs::read_dir(temp.path()).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn prune_keeps_referenced_files() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let keep = temp.path().join("keep.cur");
+        let stale = temp.path().join("stale.cur");
+        fs::write(&keep, b"a").unwrap();
+        fs::write(&stale, b"b").unwrap();
+
+        let removed = prune_unreferenced(temp.path(), &HashSet::from([keep.clone()]));
+
+        assert_eq!(removed, 1);
+        assert!(keep.exists());
+        assert!(!stale.exists());
+    }
+
+    #[test]
+    fn content_addressed_name_is_stable() {
+        assert_eq!(
+            content_addressed_name(b"", "Arrow.CUR"),
+            "cbf29ce484222325.cur"
+        );
+        assert_ne!(
+            content_addressed_name(b"a", "x.ani"),
+            content_addressed_name(b"b", "x.ani")
+        );
+    }
+}
@@ -0,0 +1,85 @@
+//! Local ratings and notes for library packs.
+//!
+//! Both live in the library file next to the pack, so they show up in `get_library_cursors`
+//! and never leave the machine unless the library is synced or exported.
+
+use tauri::{AppHandle, Runtime};
+
+use super::library::{load_library, lock_library, save_library, LibraryCursor, LibraryData};
+
+pub const MAX_PACK_RATING: u8 = 5;
+
+pub(super) fn find_pack<'a>(
+    library: &'a mut LibraryData,
+    id: &str,
+) -> Result<&'a mut LibraryCursor, String> {
+    library
+        .cursors
+        .iter_mut()
+        .find(|c| c.id == id && c.is_pack)
+        .ok_or_else(|| "Cursor pack not found in library".to_string())
+}
+
+fn validate_rating(stars: Option<u8>) -> Result<Option<u8>, String> {
+    match stars {
+        None | Some(0) => Ok(None),
+        Some(stars) if stars <= MAX_PACK_RATING => Ok(Some(stars)),
+        Some(stars) => Err(format!(
+            "Rating must be between 1 and {} stars, got {}",
+            MAX_PACK_RATING, stars
+        )),
+    }
+}
+
+/// Rate the library pack `id` from 1 to 5 stars. `None` or 0 clears the rating.
+#[tauri::command]
+pub fn rate_pack<R: Runtime>(
+    app: AppHandle<R>,
+    id: String,
+    stars: Option<u8>,
+) -> Result<LibraryCursor, String> {
+    let rating = validate_rating(stars)?;
+    let _lock = lock_library(&app)?;
+    let mut library = load_library(&app)?;
+    let pack = find_pack(&mut library, &id)?;
+    let metadata = pack
+        .pack_metadata
+        .as_mut()
+        .ok_or_else(|| "Cursor is not a pack".to_string())?;
+    metadata.rating = rating;
+    let pack = pack.clone();
+    save_library(&app, &library)?;
+    Ok(pack)
+}
+
+/// Replace the notes on the library pack `id`. Blank notes are removed.
+#[tauri::command]
+pub fn set_pack_notes<R: Runtime>(
+    app: AppHandle<R>,
+    id: String,
+    notes: Option<String>,
+) -> Result<LibraryCursor, String> {
+    let _lock = lock_library(&app)?;
+    let mut library = load_library(&app)?;
+    let pack = find_pack(&mut library, &id)?;
+    pack.notes = notes
+        .map(|notes| notes.trim().to_string())
+        .filter(|notes| !notes.is_empty());
+    let pack = pack.clone();
+    save_library(&app, &library)?;
+    Ok(pack)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ratings_are_one_to_five_stars() {
+        assert_eq!(validate_rating(None), Ok(None));
+        assert_eq!(validate_rating(Some(0)), Ok(None));
+        assert_eq!(validate_rating(Some(1)), Ok(Some(1)));
+        assert_eq!(validate_rating(Some(5)), Ok(Some(5)));
+        assert!(validate_rating(Some(6)).is_err());
+    }
+}
@@ -0,0 +1,352 @@
+/// Pack applies scheduled for a set time, such as a Halloween pack on the morning of Oct 31.
+///
+/// Each schedule is a one-shot job kept in `scheduled-applies.json` until it runs or is
+/// cancelled. One chain of timers on the shared timer service watches the wall clock: it never
+/// sleeps longer than `RECHECK_INTERVAL`, so a schedule that comes due while the machine sleeps
+/// runs soon after it wakes, and schedules missed while the app was closed run at the next
+/// startup. `APPLY_SCHEDULED` carries the pending schedules whenever they change, and
+/// `APPLY_COUNTDOWN` fires as each one passes the `COUNTDOWN_MARKS`.
+use crate::commands::customization::library::load_library;
+use crate::commands::customization::pack_commands::apply_cursor_pack;
+use crate::events;
+use crate::state::settings_file::{self, SettingsFile};
+use crate::state::AppState;
+use crate::timers::{self, TimerPriority};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tauri::{AppHandle, Emitter, Manager};
+
+pub const SCHEDULED_APPLIES_FILE_NAME: &str = "scheduled-applies.json";
+/// Longest the watcher sleeps before reading the clock again.
+const RECHECK_INTERVAL: Duration = Duration::from_secs(60);
+/// Seconds before a schedule runs at which `APPLY_COUNTDOWN` is emitted.
+const COUNTDOWN_MARKS: [u64; 4] = [3600, 600, 60, 10];
+
+/// Bumped whenever the watcher is re-armed; an older chain stops once it no longer matches.
+static GENERATION: AtomicU64 = AtomicU64::new(0);
+/// Serializes reads and writes of the schedule file.
+static SCHEDULE_LOCK: Mutex<()> = Mutex::new(());
+/// Smallest countdown mark already announced for each schedule.
+static ANNOUNCED: Mutex<Option<HashMap<String, u64>>> = Mutex::new(None);
+
+#[derive(ts_rs::TS, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct ScheduledApply {
+    pub id: String,
+    pub pack_id: String,
+    /// Name of the pack when it was scheduled
+    pub pack_name: String,
+    /// When to apply, in milliseconds since the Unix epoch
+    #[ts(type = "number")]
+    pub run_at_ms: u64,
+}
+
+#[derive(ts_rs::TS, Serialize, Clone, Debug, PartialEq, Eq)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct ScheduledApplyCountdown {
+    pub id: String,
+    pub pack_id: String,
+    pub pack_name: String,
+    #[ts(type = "number")]
+    pub seconds_left: u64,
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| u64::try_from(d.as_millis()).unwrap_or(u64::MAX))
+        .unwrap_or(0)
+}
+
+/// On-disk form of the schedule list.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(transparent)]
+pub struct ScheduledApplies(pub Vec<ScheduledApply>);
+
+impl SettingsFile for ScheduledApplies {
+    const FILE_NAME: &'static str = SCHEDULED_APPLIES_FILE_NAME;
+    const DESCRIPTION: &'static str = "scheduled applies";
+}
+
+fn load_schedules(app: &AppHandle) -> Vec<ScheduledApply> {
+    settings_file::load::<ScheduledApplies, _>(app).0
+}
+
+fn save_schedules(app: &AppHandle, schedules: &[ScheduledApply]) -> Result<(), String> {
+    settings_file::save(app, &ScheduledApplies(schedules.to_vec()))
+}
+
+/// Split `schedules` into those due at `now` and those still waiting, both soonest first.
+fn split_due(
+    mut schedules: Vec<ScheduledApply>,
+    now: u64,
+) -> (Vec<ScheduledApply>, Vec<ScheduledApply>) {
+    schedules.sort_by_key(|s| s.run_at_ms);
+    let split = schedules.partition_point(|s| s.run_at_ms <= now);
+    let pending = schedules.split_off(split);
+    (schedules, pending)
+}
+
+/// The countdown mark reached with `ms_left` to go, if it is smaller than `announced`.
+/// Marks passed without a check in between are skipped in favour of the latest one.
+fn countdown_mark(ms_left: u64, announced: Option<u64>) -> Option<u64> {
+    let mark = COUNTDOWN_MARKS
+        .iter()
+        .copied()
+        .filter(|&mark| ms_left <= mark * 1000)
+        .min()?;
+    announced.is_none_or(|a| mark < a).then_some(mark)
+}
+
+/// How long until the next schedule runs or passes a countdown mark, capped at
+/// `RECHECK_INTERVAL`. `None` when nothing is scheduled.
+fn next_wait(pending: &[ScheduledApply], now: u64) -> Option<Duration> {
+    pending
+        .iter()
+        .map(|schedule| {
+            let ms_left = schedule.run_at_ms.saturating_sub(now);
+            COUNTDOWN_MARKS
+                .iter()
+                .map(|mark| mark * 1000)
+                .filter(|&mark| mark < ms_left)
+                .max()
+                .map_or(ms_left, |mark| ms_left - mark)
+        })
+        .min()
+        .map(|ms| Duration::from_millis(ms).min(RECHECK_INTERVAL))
+}
+
+fn run_schedule(app: &AppHandle, schedule: &ScheduledApply, now: u64) {
+    let late_by = Duration::from_millis(now.saturating_sub(schedule.run_at_ms));
+    if late_by > RECHECK_INTERVAL {
+        cc_info!(
+            "[CursorChanger] Scheduled apply of {} was missed; running it {}s late",
+            schedule.pack_name,
+            late_by.as_secs()
+        );
+    }
+    match apply_cursor_pack(
+        app.clone(),
+        app.state::<AppState>(),
+        schedule.pack_id.clone(),
+    ) {
+        Ok(()) => cc_info!(
+            "[CursorChanger] Applied scheduled cursor pack {}",
+            schedule.pack_name
+        ),
+        Err(e) => {
+            cc_warn!(
+                "[CursorChanger] Scheduled apply of {} failed: {}",
+                schedule.pack_name,
+                e
+            );
+            let _ = app.emit(events::CURSOR_ERROR, e);
+        }
+    }
+}
+
+fn announce_countdowns(app: &AppHandle, pending: &[ScheduledApply], now: u64) {
+    let Ok(mut announced) = ANNOUNCED.lock() else {
+        return;
+    };
+    let announced = announced.get_or_insert_with(HashMap::new);
+    announced.retain(|id, _| pending.iter().any(|s| &s.id == id));
+    for schedule in pending {
+        let ms_left = schedule.run_at_ms.saturating_sub(now);
+        let Some(mark) = countdown_mark(ms_left, announced.get(&schedule.id).copied()) else {
+            continue;
+        };
+        announced.insert(schedule.id.clone(), mark);
+        let _ = app.emit(
+            events::APPLY_COUNTDOWN,
+            ScheduledApplyCountdown {
+                id: schedule.id.clone(),
+                pack_id: schedule.pack_id.clone(),
+                pack_name: schedule.pack_name.clone(),
+                seconds_left: ms_left.div_ceil(1000),
+            },
+        );
+    }
+}
+
+/// Run what is due, announce countdowns and sleep until the next thing to do.
+fn tick(app: AppHandle, generation: u64) {
+    if GENERATION.load(Ordering::SeqCst) != generation {
+        return;
+    }
+    let now = now_ms();
+    let (due, pending) = {
+        let Ok(_lock) = SCHEDULE_LOCK.lock() else {
+            return;
+        };
+        let (due, pending) = split_due(load_schedules(&app), now);
+        if !due.is_empty() {
+            // Forget them first, so a crash mid-apply doesn't repeat them at every startup
+            if let Err(e) = save_schedules(&app, &pending) {
+                cc_warn!("[CursorChanger] {}", e);
+            }
+        }
+        (due, pending)
+    };
+
+    for schedule in &due {
+        run_schedule(&app, schedule, now);
+    }
+    if !due.is_empty() {
+        let _ = app.emit(events::APPLY_SCHEDULED, &pending);
+    }
+    announce_countdowns(&app, &pending, now);
+
+    if let Some(wait) = next_wait(&pending, now) {
+        timers::after(wait, TimerPriority::Essential, move || {
+            tick(app, generation)
+        });
+    }
+}
+
+/// Restart the watcher for the current schedule file. Called at startup, where schedules
+/// missed while the app was closed run straight away, and after every change.
+pub fn install(app: &AppHandle) {
+    let generation = GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+    let app = app.clone();
+    timers::after(Duration::ZERO, TimerPriority::Essential, move || {
+        tick(app, generation)
+    });
+}
+
+/// Apply the library pack `id` at `run_at_ms` (milliseconds since the Unix epoch).
+#[tauri::command]
+pub fn schedule_pack_apply(
+    app: AppHandle,
+    id: String,
+    run_at_ms: u64,
+) -> Result<ScheduledApply, String> {
+    if run_at_ms <= now_ms() {
+        return Err("The scheduled time has already passed".to_string());
+    }
+    let pack = load_library(&app)?
+        .cursors
+        .into_iter()
+        .find(|c| c.id == id && c.is_pack)
+        .ok_or_else(|| "Cursor pack not found in library".to_string())?;
+
+    let schedule = ScheduledApply {
+        id: format!("sched_{}", uuid::Uuid::now_v7().simple()),
+        pack_id: pack.id,
+        pack_name: pack.name,
+        run_at_ms,
+    };
+    let schedules = {
+        let _lock = SCHEDULE_LOCK
+            .lock()
+            .map_err(|_| "Schedule state poisoned".to_string())?;
+        let mut schedules = load_schedules(&app);
+        schedules.push(schedule.clone());
+        schedules.sort_by_key(|s| s.run_at_ms);
+        save_schedules(&app, &schedules)?;
+        schedules
+    };
+    let _ = app.emit(events::APPLY_SCHEDULED, &schedules);
+    install(&app);
+    Ok(schedule)
+}
+
+/// Pending scheduled applies, soonest first.
+#[tauri::command]
+pub fn get_scheduled_pack_applies(app: AppHandle) -> Vec<ScheduledApply> {
+    let _lock = SCHEDULE_LOCK.lock();
+    let mut schedules = load_schedules(&app);
+    schedules.sort_by_key(|s| s.run_at_ms);
+    schedules
+}
+
+/// Drop the scheduled apply `schedule_id`. Returns the schedules still pending.
+#[tauri::command]
+pub fn cancel_scheduled_pack_apply(
+    app: AppHandle,
+    schedule_id: String,
+) -> Result<Vec<ScheduledApply>, String> {
+    let schedules = {
+        let _lock = SCHEDULE_LOCK
+            .lock()
+            .map_err(|_| "Schedule state poisoned".to_string())?;
+        let mut schedules = load_schedules(&app);
+        let before = schedules.len();
+        schedules.retain(|s| s.id != schedule_id);
+        if schedules.len() == before {
+            return Err("Scheduled apply not found".to_string());
+        }
+        save_schedules(&app, &schedules)?;
+        schedules
+    };
+    let _ = app.emit(events::APPLY_SCHEDULED, &schedules);
+    install(&app);
+    Ok(schedules)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schedule(id: &str, run_at_ms: u64) -> ScheduledApply {
+        ScheduledApply {
+            id: id.to_string(),
+            pack_id: format!("pack-{id}"),
+            pack_name: "Halloween".to_string(),
+            run_at_ms,
+        }
+    }
+
+    #[test]
+    fn due_and_missed_schedules_run_soonest_first() {
+        let now = 100_000_000;
+        let (due, pending) = split_due(
+            vec![
+                schedule("later", now + 5),
+                schedule("missed", now - 86_400_000),
+                schedule("now", now),
+            ],
+            now,
+        );
+        let ids = |list: &[ScheduledApply]| list.iter().map(|s| s.id.clone()).collect::<Vec<_>>();
+        assert_eq!(ids(&due), ["missed", "now"]);
+        assert_eq!(ids(&pending), ["later"]);
+    }
+
+    #[test]
+    fn countdown_announces_each_mark_once() {
+        // An hour and a half out: no mark reached yet
+        assert_eq!(countdown_mark(5_400_000, None), None);
+        assert_eq!(countdown_mark(3_600_000, None), Some(3600));
+        assert_eq!(countdown_mark(3_000_000, Some(3600)), None);
+        assert_eq!(countdown_mark(600_000, Some(3600)), Some(600));
+        // Checking late skips straight to the latest mark
+        assert_eq!(countdown_mark(5_000, Some(3600)), Some(10));
+        assert_eq!(countdown_mark(4_000, Some(10)), None);
+    }
+
+    #[test]
+    fn watcher_wakes_for_the_next_mark_or_run() {
+        let now = 0;
+        assert_eq!(next_wait(&[], now), None);
+        // Far off: re-check the clock regularly
+        assert_eq!(
+            next_wait(&[schedule("a", 86_400_000)], now),
+            Some(RECHECK_INTERVAL)
+        );
+        // 65s out: wake at the 60s mark
+        assert_eq!(
+            next_wait(&[schedule("a", 65_000)], now),
+            Some(Duration::from_secs(5))
+        );
+        // Past the last mark: wake when it is due
+        assert_eq!(
+            next_wait(&[schedule("a", 8_000), schedule("b", 30_000)], now),
+            Some(Duration::from_secs(8))
+        );
+        assert_eq!(next_wait(&[schedule("a", 0)], now), Some(Duration::ZERO));
+    }
+}
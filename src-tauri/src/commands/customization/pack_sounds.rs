@@ -0,0 +1,51 @@
+//! Turning a pack's bundled sounds on and off (see `crate::audio`).
+
+use std::path::Path;
+
+use tauri::{AppHandle, Runtime};
+
+use super::library::{load_library, lock_library, save_library, LibraryCursor};
+use super::pack_manifest::PackSoundEvent;
+use super::pack_rating::find_pack;
+
+/// Play the library pack `id`'s sounds from now on, or stop playing them.
+#[tauri::command]
+pub fn set_pack_sounds_enabled<R: Runtime>(
+    app: AppHandle<R>,
+    id: String,
+    enabled: bool,
+) -> Result<LibraryCursor, String> {
+    let _lock = lock_library(&app)?;
+    let mut library = load_library(&app)?;
+    let pack = find_pack(&mut library, &id)?;
+    let metadata = pack
+        .pack_metadata
+        .as_mut()
+        .ok_or_else(|| "Cursor is not a pack".to_string())?;
+    if enabled && metadata.sounds.is_empty() {
+        return Err("This pack has no sounds".to_string());
+    }
+    metadata.sounds_enabled = enabled;
+    let pack = pack.clone();
+    save_library(&app, &library)?;
+    Ok(pack)
+}
+
+/// Play the library pack `id`'s sound for `event` once, whether or not its sounds are on.
+#[tauri::command]
+pub fn preview_pack_sound<R: Runtime>(
+    app: AppHandle<R>,
+    id: String,
+    event: PackSoundEvent,
+) -> Result<(), String> {
+    let library = load_library(&app)?;
+    let pack = library
+        .cursors
+        .iter()
+        .find(|c| c.is_pack && c.id == id)
+        .ok_or_else(|| "Cursor pack not found in library".to_string())?;
+    let wav = crate::audio::read_pack_sound(Path::new(&pack.file_path), event)?
+        .ok_or_else(|| "This pack has no sound for that event".to_string())?;
+    crate::audio::play_in_background(wav);
+    Ok(())
+}
@@ -0,0 +1,183 @@
+//! Composite preview sprite for cursor packs in the library grid.
+//!
+//! The sprite is a 3×3 grid of the key role previews, encoded once as a PNG data URL and
+//! cached on the pack metadata, so the grid can render every pack from the
+//! `get_library_cursors` payload instead of fetching per-pack previews while scrolling.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use base64::Engine;
+use image::{imageops, ImageBuffer, Rgba, RgbaImage};
+
+use crate::utils::encoding::base64_encode;
+
+use super::library::{LibraryData, LibraryPackMetadata};
+use super::pack_library::{generate_pack_previews_from_archive, CURRENT_PREVIEW_CACHE_VERSION};
+
+/// Roles shown in the sprite, row by row.
+const SPRITE_ROLES: [&str; 9] = [
+    "normal-select",
+    "link-select",
+    "text-select",
+    "busy",
+    "working-in-background",
+    "precision-select",
+    "move",
+    "help-select",
+    "unavailable",
+];
+const SPRITE_COLUMNS: u32 = 3;
+const SPRITE_CELL_SIZE: u32 = 32;
+
+fn decode_png_data_url(data_url: &str) -> Option<RgbaImage> {
+    let encoded = data_url.strip_prefix("data:image/png;base64,")?;
+    let bytes = base64::engine::general_purpose::STANDARD
+        .decode(encoded)
+        .ok()?;
+    image::load_from_memory_with_format(&bytes, image::ImageFormat::Png)
+        .ok()
+        .map(|img| img.to_rgba8())
+}
+
+fn preview_for_role<'a>(previews: &'a HashMap<String, String>, role: &str) -> Option<&'a str> {
+    previews.iter().find_map(|(file_name, url)| {
+        let stem = Path::new(file_name).file_stem()?.to_str()?;
+        stem.eq_ignore_ascii_case(role).then_some(url.as_str())
+    })
+}
+
+/// Fit `img` into a square cell, keeping its aspect ratio.
+fn fit_to_cell(img: &RgbaImage) -> RgbaImage {
+    let (width, height) = img.dimensions();
+    if width <= SPRITE_CELL_SIZE && height <= SPRITE_CELL_SIZE {
+        return img.clone();
+    }
+    let scale = f64::from(SPRITE_CELL_SIZE) / f64::from(width.max(height));
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let (w, h) = (
+        ((f64::from(width) * scale).round() as u32).max(1),
+        ((f64::from(height) * scale).round() as u32).max(1),
+    );
+    imageops::resize(img, w, h, imageops::FilterType::Lanczos3)
+}
+
+/// Build the sprite from per-file previews. Roles without a preview leave their cell empty;
+/// returns `None` when none of the roles could be decoded.
+pub fn build_pack_sprite(previews: &HashMap<String, String>) -> Option<String> {
+    let side = SPRITE_COLUMNS * SPRITE_CELL_SIZE;
+    let mut sprite: RgbaImage = ImageBuffer::from_pixel(side, side, Rgba([0, 0, 0, 0]));
+    let mut drawn = 0;
+
+    for (index, role) in (0u32..).zip(SPRITE_ROLES) {
+        let Some(cell) = preview_for_role(previews, role)
+            .and_then(decode_png_data_url)
+            .map(|img| fit_to_cell(&img))
+        else {
+            continue;
+        };
+        let x = (index % SPRITE_COLUMNS) * SPRITE_CELL_SIZE + (SPRITE_CELL_SIZE - cell.width()) / 2;
+        let y = (index / SPRITE_COLUMNS) * SPRITE_CELL_SIZE + (SPRITE_CELL_SIZE - cell.height()) / 2;
+        imageops::overlay(&mut sprite, &cell, i64::from(x), i64::from(y));
+        drawn += 1;
+    }
+
+    if drawn == 0 {
+        return None;
+    }
+
+    let mut png = Vec::new();
+    sprite
+        .write_to(&mut std::io::Cursor::new(&mut png), image::ImageFormat::Png)
+        .ok()?;
+    Some(format!("data:image/png;base64,{}", base64_encode(&png)))
+}
+
+/// Make sure `metadata` has current previews and a sprite. Returns whether anything changed.
+pub fn ensure_pack_sprite(metadata: &mut LibraryPackMetadata) -> bool {
+    if metadata.sprite.is_some() {
+        return false;
+    }
+
+    let mut changed = false;
+    let previews_current =
+        metadata.previews_version.unwrap_or_default() >= CURRENT_PREVIEW_CACHE_VERSION;
+    if metadata.previews.is_none() || !previews_current {
+        match generate_pack_previews_from_archive(&PathBuf::from(&metadata.archive_path)) {
+            Ok(previews) => {
+                metadata.previews = Some(previews);
+                metadata.previews_version = Some(CURRENT_PREVIEW_CACHE_VERSION);
+                changed = true;
+            }
+            Err(e) => {
+                cc_warn!(
+                    "[CursorChanger] Failed to generate pack previews for {}: {}",
+                    metadata.archive_path,
+                    e
+                );
+                return false;
+            }
+        }
+    }
+
+    if let Some(previews) = &metadata.previews {
+        metadata.sprite = build_pack_sprite(previews);
+        changed |= metadata.sprite.is_some();
+    }
+    changed
+}
+
+/// Fill in missing sprites for every pack in the library. Returns whether anything changed.
+pub fn ensure_library_sprites(library: &mut LibraryData) -> bool {
+    let mut changed = false;
+    for metadata in library
+        .cursors
+        .iter_mut()
+        .filter(|c| c.is_pack)
+        .filter_map(|c| c.pack_metadata.as_mut())
+    {
+        changed |= ensure_pack_sprite(metadata);
+    }
+    changed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn png_data_url(size: u32, color: [u8; 4]) -> String {
+        let img: RgbaImage = ImageBuffer::from_pixel(size, size, Rgba(color));
+        let mut png = Vec::new();
+        img.write_to(&mut std::io::Cursor::new(&mut png), image::ImageFormat::Png)
+            .unwrap();
+        format!("data:image/png;base64,{}", base64_encode(&png))
+    }
+
+    fn decode(url: &str) -> RgbaImage {
+        decode_png_data_url(url).expect("sprite should be a PNG data URL")
+    }
+
+    #[test]
+    fn sprite_places_roles_in_grid_order() {
+        let mut previews = HashMap::new();
+        previews.insert("Normal-Select.cur".to_string(), png_data_url(32, [255, 0, 0, 255]));
+        previews.insert("unavailable.ani".to_string(), png_data_url(64, [0, 0, 255, 255]));
+
+        let sprite = decode(&build_pack_sprite(&previews).unwrap());
+
+        assert_eq!(sprite.dimensions(), (96, 96));
+        assert_eq!(sprite.get_pixel(16, 16).0, [255, 0, 0, 255]);
+        // Missing roles stay transparent
+        assert_eq!(sprite.get_pixel(48, 16).0[3], 0);
+        // Oversized previews are scaled into the last cell
+        assert_eq!(sprite.get_pixel(80, 80).0, [0, 0, 255, 255]);
+    }
+
+    #[test]
+    fn no_sprite_without_decodable_previews() {
+        let mut previews = HashMap::new();
+        previews.insert("normal-select.cur".to_string(), "data:image/x-icon;base64,AA==".to_string());
+        assert_eq!(build_pack_sprite(&previews), None);
+        assert_eq!(build_pack_sprite(&HashMap::new()), None);
+    }
+}
@@ -42,7 +42,7 @@ mod tests {
         
         // Action: Export the pack
         let pack_name = Some("TestPack".to_string());
-        let result = export_active_cursor_pack(handle.clone(), state.clone(), pack_name).await;
+        let result = export_active_cursor_pack(handle.clone(), state.clone(), pack_name, None, None, None).await;
         
         // Assert
         if let Err(e) = &result {
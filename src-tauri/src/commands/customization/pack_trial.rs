@@ -0,0 +1,251 @@
+/// Temporary "try before you apply" mode for cursor packs.
+///
+/// `start_pack_trial` snapshots the active cursors, applies the pack and schedules a revert.
+/// Starting another trial while one is running keeps the original snapshot, so the user can
+/// flip between packs (A/B) and still land back where they started.
+use crate::commands::command_helpers;
+use crate::commands::customization::pack_commands::apply_cursor_pack;
+use crate::state::{AppState, CustomizationMode};
+use crate::system;
+use crate::timers::{self, TimerPriority};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager, State};
+
+/// Longest trial we allow; anything above this is clamped.
+pub(crate) const MAX_TRIAL_SECONDS: u32 = 300;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct CursorSnapshot {
+    customization_mode: CustomizationMode,
+    cursor_paths: HashMap<String, String>,
+    last_loaded_cursor_path: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+struct PackTrial {
+    pack_id: String,
+    generation: u64,
+    snapshot: CursorSnapshot,
+}
+
+/// Managed state holding the running pack trial, if any.
+#[derive(Default)]
+pub struct PackTrialState {
+    trial: Option<PackTrial>,
+    // Bumped on every start so a stale timer can tell it has been superseded
+    generation: u64,
+}
+
+impl PackTrialState {
+    fn begin(&mut self, pack_id: String, current: CursorSnapshot) -> u64 {
+        self.generation += 1;
+        let snapshot = match self.trial.take() {
+            Some(previous) => previous.snapshot,
+            None => current,
+        };
+        self.trial = Some(PackTrial {
+            pack_id,
+            generation: self.generation,
+            snapshot,
+        });
+        self.generation
+    }
+
+    /// Take the trial only if it is still the one started with `generation`.
+    fn take_if_current(&mut self, generation: u64) -> Option<PackTrial> {
+        if self.trial.as_ref().map(|t| t.generation) == Some(generation) {
+            self.trial.take()
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(ts_rs::TS, Serialize, Clone, Debug)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct PackTrialStatus {
+    pub pack_id: String,
+    /// Seconds until the previous cursors are restored
+    pub seconds: u32,
+}
+
+/// Apply a pack temporarily; the previous cursors come back after `seconds` unless
+/// `commit_pack_trial` is called first.
+#[tauri::command]
+pub fn start_pack_trial(
+    app: AppHandle,
+    state: State<AppState>,
+    trial: State<'_, Mutex<PackTrialState>>,
+    id: String,
+    seconds: u32,
+) -> Result<PackTrialStatus, String> {
+    if seconds == 0 {
+        return Err("Trial duration must be at least one second".to_string());
+    }
+    let seconds = seconds.min(MAX_TRIAL_SECONDS);
+
+    let current = {
+        let guard = state.read_all()?;
+        CursorSnapshot {
+            customization_mode: guard.modes.customization_mode,
+            cursor_paths: guard.cursor.cursor_paths.clone(),
+            last_loaded_cursor_path: guard.cursor.last_loaded_cursor_path.clone(),
+        }
+    };
+
+    // Hold the lock across the apply so a timer firing mid-switch can't revert under us.
+    let generation = {
+        let mut guard = trial
+            .lock()
+            .map_err(|_| "Pack trial state poisoned".to_string())?;
+        apply_cursor_pack(app.clone(), state, id.clone())?;
+        guard.begin(id.clone(), current)
+    };
+
+    let app_handle = app.clone();
+    timers::after(
+        Duration::from_secs(u64::from(seconds)),
+        TimerPriority::Essential,
+        move || revert_if_current(&app_handle, generation),
+    );
+
+    cc_info!(
+        "[CursorChanger] Started {}s trial of cursor pack {}",
+        seconds,
+        id
+    );
+
+    Ok(PackTrialStatus {
+        pack_id: id,
+        seconds,
+    })
+}
+
+/// Keep the pack that is being trialled.
+#[tauri::command]
+pub fn commit_pack_trial(trial: State<'_, Mutex<PackTrialState>>) -> Result<(), String> {
+    let mut guard = trial
+        .lock()
+        .map_err(|_| "Pack trial state poisoned".to_string())?;
+    let committed = guard
+        .trial
+        .take()
+        .ok_or_else(|| "No cursor pack trial in progress".to_string())?;
+    cc_info!("[CursorChanger] Kept cursor pack {}", committed.pack_id);
+    Ok(())
+}
+
+/// End the trial early and restore the previous cursors.
+#[tauri::command]
+pub fn cancel_pack_trial(
+    app: AppHandle,
+    trial: State<'_, Mutex<PackTrialState>>,
+) -> Result<(), String> {
+    let mut guard = trial
+        .lock()
+        .map_err(|_| "Pack trial state poisoned".to_string())?;
+    let ended = guard
+        .trial
+        .take()
+        .ok_or_else(|| "No cursor pack trial in progress".to_string())?;
+    restore_snapshot(&app, &ended)
+}
+
+fn revert_if_current(app: &AppHandle, generation: u64) {
+    let trial = app.state::<Mutex<PackTrialState>>();
+    let Ok(mut guard) = trial.lock() else {
+        cc_error!("[CursorChanger] Pack trial state poisoned; cannot revert");
+        return;
+    };
+    let Some(ended) = guard.take_if_current(generation) else {
+        return;
+    };
+
+    if let Err(e) = restore_snapshot(app, &ended) {
+        cc_error!(
+            "[CursorChanger] Failed to revert cursor pack trial {}: {}",
+            ended.pack_id,
+            e
+        );
+    }
+}
+
+fn restore_snapshot(app: &AppHandle, ended: &PackTrial) -> Result<(), String> {
+    let state = app.state::<AppState>();
+    let snapshot = ended.snapshot.clone();
+
+    // Start from the system cursors so roles the pack set but the snapshot didn't are undone.
+    if !system::restore_system_cursors() {
+        return Err("Failed to restore system cursors".to_string());
+    }
+
+    let cursor_size = state
+        .prefs
+        .read()
+        .map_err(|e| format!("Failed to lock state: {e}"))?
+        .cursor_size;
+    match snapshot.customization_mode {
+        CustomizationMode::Simple => {
+            crate::cursor_defaults::apply_cursor_paths_simple(&snapshot.cursor_paths, cursor_size)
+        }
+        CustomizationMode::Advanced => {
+            crate::cursor_defaults::apply_cursor_paths_advanced(&snapshot.cursor_paths, cursor_size)
+        }
+    }
+
+    command_helpers::update_state_and_emit(app, &state, false, |guard| {
+        guard.modes.customization_mode = snapshot.customization_mode;
+        guard.cursor.cursor_paths = snapshot.cursor_paths;
+        guard.cursor.last_loaded_cursor_path = snapshot.last_loaded_cursor_path;
+        Ok(())
+    })?;
+
+    let _ = app.emit(crate::events::PACK_TRIAL_REVERTED, ended.pack_id.clone());
+    cc_info!("[CursorChanger] Reverted cursor pack trial {}", ended.pack_id);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(path: &str) -> CursorSnapshot {
+        let mut cursor_paths = HashMap::new();
+        cursor_paths.insert("Normal".to_string(), path.to_string());
+        CursorSnapshot {
+            customization_mode: CustomizationMode::Advanced,
+            cursor_paths,
+            last_loaded_cursor_path: None,
+        }
+    }
+
+    #[test]
+    fn switching_packs_keeps_original_snapshot() {
+        let mut state = PackTrialState::default();
+        let first = state.begin("pack-a".to_string(), snapshot("original.cur"));
+        let second = state.begin("pack-b".to_string(), snapshot("pack-a.cur"));
+
+        assert_ne!(first, second);
+        let trial = state.trial.as_ref().expect("trial");
+        assert_eq!(trial.pack_id, "pack-b");
+        assert_eq!(trial.snapshot, snapshot("original.cur"));
+    }
+
+    #[test]
+    fn stale_timer_does_not_take_newer_trial() {
+        let mut state = PackTrialState::default();
+        let first = state.begin("pack-a".to_string(), snapshot("original.cur"));
+        let second = state.begin("pack-b".to_string(), snapshot("pack-a.cur"));
+
+        assert!(state.take_if_current(first).is_none());
+        assert!(state.trial.is_some());
+        assert_eq!(
+            state.take_if_current(second).map(|t| t.pack_id),
+            Some("pack-b".to_string())
+        );
+        assert!(state.trial.is_none());
+    }
+}
@@ -0,0 +1,655 @@
+//! Updates for cursor packs imported from a download link.
+//!
+//! `import_cursor_pack_from_url` keeps the link, a hash of the downloaded archive and the
+//! server's `ETag`/`Last-Modified` with the pack. [`schedule`] asks the server once a day
+//! whether each such archive changed, downloading it only when it did, and flags the ones
+//! that did, emitting `PACK_UPDATES_AVAILABLE`; `update_all_packs` extracts changed archives
+//! beside the installed ones and swaps them in under the library lock. A downloaded archive
+//! counts as changed when its hash differs, so a re-uploaded fix that kept its version label
+//! is still picked up. A swapped pack keeps its id, its place in the library and its
+//! collections, its rating and sound setting, and the user's notes.
+//!
+//! Nothing is checked or downloaded while the administrator has turned off the marketplace.
+
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::io::Cursor;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+use serde::Serialize;
+use tauri::{AppHandle, Emitter, Manager, Runtime};
+use zip::ZipArchive;
+
+use crate::operations::{Operation, OperationPhase};
+use crate::state::policy::check_marketplace_allowed;
+use crate::state::AppState;
+use crate::timers::{self, TimerPriority};
+
+use super::library::{
+    load_library, lock_library, save_library, LibraryCursor, LibraryData, LibraryPackItem,
+};
+use super::pack_commands::{validate_cursor_pack_bytes, PackValidationMode};
+use super::pack_import_url::{download_pack_if_changed, DownloadedPack, PackValidators};
+use super::pack_library::{
+    ensure_pack_files_present, generate_pack_previews_from_archive, CURRENT_PREVIEW_CACHE_VERSION,
+};
+use super::pack_manifest::{read_manifest, read_manifest_from_path, CursorPackManifest};
+use super::pack_mount::fnv1a_64;
+use super::pack_sprite::build_pack_sprite;
+
+/// Delay before the first check, so it stays out of the way of startup.
+const FIRST_CHECK_DELAY: Duration = Duration::from_secs(10 * 60);
+const CHECK_INTERVAL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Set while a scheduled check is downloading, so a slow one is never run twice at once.
+static CHECK_RUNNING: AtomicBool = AtomicBool::new(false);
+
+#[derive(ts_rs::TS, Debug, Clone, PartialEq, Eq, Serialize)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct PackUpdateStatus {
+    pub pack_id: String,
+    pub name: String,
+    pub installed_version: Option<String>,
+    /// Version label of the downloaded archive, when its manifest has one
+    pub remote_version: Option<String>,
+    pub update_available: bool,
+    /// Why the pack could not be checked or updated
+    pub error: Option<String>,
+}
+
+#[derive(ts_rs::TS, Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct PackUpdateReport {
+    /// Packs now on the downloaded archive; `installed_version` is the version they had before
+    pub updated: Vec<PackUpdateStatus>,
+    /// Packs whose download matched the installed archive
+    pub up_to_date: u32,
+    /// Packs that could not be downloaded or whose new archive was rejected
+    pub failed: Vec<PackUpdateStatus>,
+}
+
+/// A library pack with a download link.
+struct UpdatablePack {
+    id: String,
+    name: String,
+    url: String,
+    archive_hash: Option<String>,
+    /// Size of the installed archive, for servers that send no validators
+    archive_size: Option<u64>,
+    validators: PackValidators,
+    installed_version: Option<String>,
+}
+
+struct RemotePack {
+    data: Vec<u8>,
+    hash: String,
+    version: Option<String>,
+    validators: PackValidators,
+}
+
+/// What a check learned about one pack, stored back into the library.
+struct CheckedPack {
+    id: String,
+    update_available: bool,
+    /// Validators of a download that matched the installed archive, so the next check can be
+    /// answered without downloading it
+    validators: Option<PackValidators>,
+}
+
+fn archive_hash(data: &[u8]) -> String {
+    format!("{:016x}", fnv1a_64(data))
+}
+
+fn updatable_packs(library: &LibraryData) -> Vec<UpdatablePack> {
+    library
+        .cursors
+        .iter()
+        .filter(|cursor| cursor.is_pack)
+        .filter_map(|cursor| {
+            let metadata = cursor.pack_metadata.as_ref()?;
+            Some(UpdatablePack {
+                id: cursor.id.clone(),
+                name: cursor.name.clone(),
+                url: metadata.update_url.clone()?,
+                archive_hash: metadata.archive_hash.clone(),
+                archive_size: fs::metadata(&metadata.archive_path).ok().map(|m| m.len()),
+                validators: PackValidators {
+                    etag: metadata.update_etag.clone(),
+                    last_modified: metadata.update_last_modified.clone(),
+                },
+                installed_version: metadata.installed_version.clone(),
+            })
+        })
+        .collect()
+}
+
+/// Download the archive of `pack` if the server says it changed; `None` when it did not.
+fn fetch<R: Runtime>(
+    operation: &Operation<R>,
+    pack: &UpdatablePack,
+) -> Result<Option<RemotePack>, String> {
+    let Some(DownloadedPack { data, validators }) =
+        download_pack_if_changed(operation, &pack.url, &pack.validators, pack.archive_size)?
+    else {
+        return Ok(None);
+    };
+    let version = ZipArchive::new(Cursor::new(data.as_slice()))
+        .ok()
+        .and_then(|mut archive| read_manifest(&mut archive))
+        .and_then(|manifest| manifest.latest_version());
+    Ok(Some(RemotePack {
+        hash: archive_hash(&data),
+        data,
+        version,
+        validators,
+    }))
+}
+
+/// The library record for `pack` after `remote` was fetched, or found unchanged when `None`.
+fn checked(pack: &UpdatablePack, remote: Option<&RemotePack>) -> CheckedPack {
+    let update_available = remote.is_some_and(|remote| is_update(pack, remote));
+    CheckedPack {
+        id: pack.id.clone(),
+        update_available,
+        validators: remote
+            .filter(|_| !update_available)
+            .map(|remote| remote.validators.clone()),
+    }
+}
+
+fn is_update(pack: &UpdatablePack, remote: &RemotePack) -> bool {
+    pack.archive_hash.as_deref() != Some(remote.hash.as_str())
+}
+
+fn status(
+    pack: &UpdatablePack,
+    remote: Option<&RemotePack>,
+    error: Option<String>,
+) -> PackUpdateStatus {
+    PackUpdateStatus {
+        pack_id: pack.id.clone(),
+        name: pack.name.clone(),
+        installed_version: pack.installed_version.clone(),
+        remote_version: remote.and_then(|remote| remote.version.clone()),
+        update_available: remote.is_some_and(|remote| is_update(pack, remote)),
+        error,
+    }
+}
+
+/// Remember where pack `pack_id` was downloaded from and what the download was, so it can be
+/// checked for updates. Returns the updated library entry.
+pub(crate) fn record_update_source<R: Runtime>(
+    app: &AppHandle<R>,
+    pack_id: &str,
+    url: &str,
+    download: &DownloadedPack,
+) -> Result<LibraryCursor, String> {
+    let _lock = lock_library(app)?;
+    let mut library = load_library(app)?;
+    let cursor = library
+        .cursors
+        .iter_mut()
+        .find(|c| c.id == pack_id && c.is_pack)
+        .ok_or_else(|| "Cursor pack not found in library".to_string())?;
+    let metadata = cursor
+        .pack_metadata
+        .as_mut()
+        .ok_or_else(|| "Cursor pack has no metadata".to_string())?;
+    metadata.update_url = Some(url.to_string());
+    metadata.archive_hash = Some(archive_hash(&download.data));
+    metadata.update_etag = download.validators.etag.clone();
+    metadata.update_last_modified = download.validators.last_modified.clone();
+    metadata.update_available = false;
+    let cursor = cursor.clone();
+    save_library(app, &library)?;
+    Ok(cursor)
+}
+
+/// Store the `update_available` flag and fresh validators of each checked pack, saving only
+/// if something changed.
+fn record_availability<R: Runtime>(
+    app: &AppHandle<R>,
+    checked: &[CheckedPack],
+) -> Result<(), String> {
+    let _lock = lock_library(app)?;
+    let mut library = load_library(app)?;
+    let mut changed = false;
+    for cursor in &mut library.cursors {
+        let Some(metadata) = cursor.pack_metadata.as_mut() else {
+            continue;
+        };
+        let Some(pack) = checked.iter().find(|pack| pack.id == cursor.id) else {
+            continue;
+        };
+        changed |= metadata.update_available != pack.update_available;
+        metadata.update_available = pack.update_available;
+        if let Some(validators) = &pack.validators {
+            changed |= metadata.update_etag != validators.etag
+                || metadata.update_last_modified != validators.last_modified;
+            metadata.update_etag = validators.etag.clone();
+            metadata.update_last_modified = validators.last_modified.clone();
+        }
+    }
+    if changed {
+        save_library(app, &library)?;
+    }
+    Ok(())
+}
+
+fn check_for_updates<R: Runtime>(
+    app: &AppHandle<R>,
+    operation: &Operation<R>,
+) -> Result<Vec<PackUpdateStatus>, String> {
+    check_marketplace_allowed(app)?;
+    let packs = updatable_packs(&load_library(app)?);
+    let mut statuses = Vec::with_capacity(packs.len());
+    let mut records = Vec::with_capacity(packs.len());
+    for pack in &packs {
+        operation.checkpoint()?;
+        statuses.push(match fetch(operation, pack) {
+            Ok(remote) => {
+                records.push(checked(pack, remote.as_ref()));
+                status(pack, remote.as_ref(), None)
+            }
+            Err(e) => status(pack, None, Some(e)),
+        });
+    }
+
+    record_availability(app, &records)?;
+    Ok(statuses)
+}
+
+/// Keep notes on items of the new archive that no longer carry their own.
+fn keep_item_notes(items: &mut [LibraryPackItem], previous: &[LibraryPackItem]) {
+    for item in items.iter_mut().filter(|item| item.notes.is_none()) {
+        item.notes = previous
+            .iter()
+            .find(|old| old.file_name == item.file_name)
+            .and_then(|old| old.notes.clone());
+    }
+}
+
+/// A new archive written and extracted next to the installed one, ready to be swapped in.
+struct StagedUpdate {
+    /// Deleted, with anything not moved out of it, when the update is done or abandoned
+    _dir: tempfile::TempDir,
+    installed_archive: PathBuf,
+    archive_path: PathBuf,
+    items: Vec<LibraryPackItem>,
+    previews: Option<HashMap<String, String>>,
+    manifest: Option<CursorPackManifest>,
+}
+
+/// Validate `remote`, then write and extract it into a staging folder beside the installed
+/// archive and build its previews. Runs without the library lock; nothing installed changes.
+fn stage_update<R: Runtime>(
+    app: &AppHandle<R>,
+    operation: &Operation<R>,
+    pack: &UpdatablePack,
+    remote: &RemotePack,
+) -> Result<StagedUpdate, String> {
+    let validating = operation.phase(OperationPhase::Validating, 1);
+    let validated = validate_cursor_pack_bytes(&remote.data, PackValidationMode::Strict)?;
+    validating.finish();
+    operation.checkpoint()?;
+
+    let library = load_library(app)?;
+    let installed_archive = library
+        .cursors
+        .iter()
+        .find(|c| c.id == pack.id && c.is_pack)
+        .and_then(|c| c.pack_metadata.as_ref())
+        .map(|metadata| PathBuf::from(&metadata.archive_path))
+        .ok_or_else(|| "Cursor pack was removed from the library".to_string())?;
+    let (Some(folder), Some(archive_name)) =
+        (installed_archive.parent(), installed_archive.file_name())
+    else {
+        return Err("Cursor pack archive missing parent folder".to_string());
+    };
+    crate::disk_space::ensure_space(folder, remote.data.len() as u64)?;
+
+    let saving = operation.phase(OperationPhase::Saving, 1);
+    // Inside the pack folder, so moving the files in is a rename on the same volume
+    let dir = tempfile::Builder::new()
+        .prefix(".update-")
+        .tempdir_in(folder)
+        .map_err(|e| format!("Failed to create cursor pack update folder: {}", e))?;
+    let archive_path = dir.path().join(archive_name);
+    fs::write(&archive_path, &remote.data)
+        .map_err(|e| format!("Failed to write cursor pack: {}", e))?;
+    let mut items = validated.items;
+    ensure_pack_files_present(&archive_path, &mut items)?;
+    saving.finish();
+    operation.checkpoint()?;
+
+    let previewing = operation.phase(OperationPhase::GeneratingPreviews, 1);
+    let previews = match generate_pack_previews_from_archive(&archive_path) {
+        Ok(previews) => Some(previews),
+        Err(e) => {
+            cc_warn!(
+                "[CursorChanger] Failed to generate pack previews for {}: {}",
+                pack.name,
+                e
+            );
+            None
+        }
+    };
+    previewing.finish();
+
+    Ok(StagedUpdate {
+        manifest: read_manifest_from_path(&archive_path),
+        _dir: dir,
+        installed_archive,
+        archive_path,
+        items,
+        previews,
+    })
+}
+
+/// Cursor files the current cursor state points at, or `None` when that is unknown.
+fn applied_paths<R: Runtime>(app: &AppHandle<R>) -> Option<HashSet<PathBuf>> {
+    let state = app.try_state::<AppState>()?;
+    let guard = state.read_all().ok()?;
+    Some(
+        guard
+            .cursor
+            .cursor_paths
+            .values()
+            .chain(guard.modes.simple_mode_cursor_paths.values())
+            .chain(guard.modes.advanced_mode_cursor_paths.values())
+            .map(PathBuf::from)
+            .collect(),
+    )
+}
+
+/// Move a staged update over the installed pack, keeping the library entry's id, position
+/// and user settings. Only renames happen under the library lock: the new cursors replace
+/// the old ones file by file, then the archive, which marks the update as installed. Old
+/// cursors the new archive dropped are deleted unless one is applied.
+fn swap_archive<R: Runtime>(
+    app: &AppHandle<R>,
+    pack: &UpdatablePack,
+    remote: &RemotePack,
+    staged: StagedUpdate,
+) -> Result<(), String> {
+    let _lock = lock_library(app)?;
+    let mut library = load_library(app)?;
+    let metadata = library
+        .cursors
+        .iter_mut()
+        .find(|c| c.id == pack.id && c.is_pack)
+        .and_then(|c| c.pack_metadata.as_mut())
+        .ok_or_else(|| "Cursor pack was removed from the library".to_string())?;
+    let archive_path = PathBuf::from(&metadata.archive_path);
+    if archive_path != staged.installed_archive {
+        return Err("Cursor pack was moved while it was updating".to_string());
+    }
+    let folder = archive_path
+        .parent()
+        .ok_or_else(|| "Cursor pack archive missing parent folder".to_string())?;
+
+    let mut items = staged.items;
+    for item in &mut items {
+        let Some(staged_path) = item.file_path.as_ref().map(PathBuf::from) else {
+            continue;
+        };
+        let Some(name) = staged_path.file_name() else {
+            continue;
+        };
+        let target = folder.join(name);
+        fs::rename(&staged_path, &target)
+            .map_err(|e| format!("Failed to install updated {}: {}", item.file_name, e))?;
+        item.file_path = Some(target.to_string_lossy().to_string());
+    }
+    fs::rename(&staged.archive_path, &archive_path)
+        .map_err(|e| format!("Failed to save cursor pack: {}", e))?;
+
+    let applied = applied_paths(app);
+    for old in metadata
+        .items
+        .iter()
+        .filter_map(|item| item.file_path.as_deref())
+        .map(Path::new)
+    {
+        let replaced = items
+            .iter()
+            .any(|item| item.file_path.as_deref().map(Path::new) == Some(old));
+        let in_use = applied.as_ref().is_none_or(|applied| {
+            applied
+                .iter()
+                .any(|path| crate::paths::canonical_eq(path, old))
+        });
+        if old.parent() == Some(folder) && old != archive_path && !replaced && !in_use {
+            let _ = fs::remove_file(old);
+        }
+    }
+    keep_item_notes(&mut items, &metadata.items);
+
+    let previews = staged.previews;
+    let manifest = staged.manifest;
+    metadata.items = items;
+    metadata.sprite = previews.as_ref().and_then(build_pack_sprite);
+    metadata.previews_version = previews.as_ref().map(|_| CURRENT_PREVIEW_CACHE_VERSION);
+    metadata.previews = previews;
+    metadata.installed_version = manifest.as_ref().and_then(|m| m.latest_version());
+    metadata.sounds = manifest.map(|m| m.sounds.events()).unwrap_or_default();
+    metadata.archive_hash = Some(remote.hash.clone());
+    metadata.update_etag = remote.validators.etag.clone();
+    metadata.update_last_modified = remote.validators.last_modified.clone();
+    metadata.update_available = false;
+    save_library(app, &library)
+}
+
+/// Check every pack imported from a link and report which have a newer archive. The result is
+/// also stored as each pack's `update_available` flag.
+#[tauri::command]
+pub async fn check_pack_updates<R: Runtime>(
+    app: AppHandle<R>,
+    op_id: Option<String>,
+) -> Result<Vec<PackUpdateStatus>, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        let operation = Operation::begin(&app, op_id);
+        check_for_updates(&app, &operation)
+    })
+    .await
+    .map_err(|e| format!("Task join error: {}", e))?
+}
+
+/// Download every changed pack imported from a link and swap in the new archives. A pack that
+/// fails to download or validate keeps its installed archive and does not stop the rest.
+#[tauri::command]
+pub async fn update_all_packs<R: Runtime>(
+    app: AppHandle<R>,
+    op_id: Option<String>,
+) -> Result<PackUpdateReport, String> {
+    tauri::async_runtime::spawn_blocking(move || update_packs(&app, op_id))
+        .await
+        .map_err(|e| format!("Task join error: {}", e))?
+}
+
+fn update_packs<R: Runtime>(
+    app: &AppHandle<R>,
+    op_id: Option<String>,
+) -> Result<PackUpdateReport, String> {
+    check_marketplace_allowed(app)?;
+    let operation = Operation::begin(app, op_id);
+    let packs = updatable_packs(&load_library(app)?);
+    let mut report = PackUpdateReport::default();
+    let mut unchanged = Vec::new();
+    for pack in &packs {
+        operation.checkpoint()?;
+        let remote = match fetch(&operation, pack) {
+            Ok(Some(remote)) if is_update(pack, &remote) => remote,
+            Ok(remote) => {
+                report.up_to_date += 1;
+                unchanged.push(checked(pack, remote.as_ref()));
+                continue;
+            }
+            Err(e) => {
+                report.failed.push(status(pack, None, Some(e)));
+                continue;
+            }
+        };
+        let installed = stage_update(app, &operation, pack, &remote)
+            .and_then(|staged| swap_archive(app, pack, &remote, staged));
+        match installed {
+            Ok(()) => {
+                cc_info!("[CursorChanger] Updated cursor pack {}", pack.name);
+                report.updated.push(PackUpdateStatus {
+                    update_available: false,
+                    ..status(pack, Some(&remote), None)
+                });
+            }
+            Err(e) => report.failed.push(status(pack, Some(&remote), Some(e))),
+        }
+    }
+    record_availability(app, &unchanged)?;
+    Ok(report)
+}
+
+fn run_scheduled_check<R: Runtime>(app: &AppHandle<R>) {
+    if check_marketplace_allowed(app).is_err() {
+        return;
+    }
+    let operation = Operation::begin(app, None);
+    match check_for_updates(app, &operation) {
+        Ok(statuses) => {
+            let available: Vec<PackUpdateStatus> = statuses
+                .into_iter()
+                .filter(|status| status.update_available)
+                .collect();
+            if !available.is_empty() {
+                let _ = app.emit(crate::events::PACK_UPDATES_AVAILABLE, available);
+            }
+        }
+        Err(e) => cc_warn!("[CursorChanger] Pack update check failed: {}", e),
+    }
+}
+
+/// Check for pack updates `FIRST_CHECK_DELAY` after startup and then every `CHECK_INTERVAL`.
+/// The check is deferrable, so it waits while battery saver is on.
+pub fn schedule<R: Runtime>(app: AppHandle<R>) {
+    timers::every(
+        FIRST_CHECK_DELAY,
+        CHECK_INTERVAL,
+        TimerPriority::Deferrable,
+        move || {
+            if CHECK_RUNNING.swap(true, Ordering::SeqCst) {
+                return;
+            }
+            let app = app.clone();
+            // Downloads can take minutes; keep them off the shared timer thread
+            let spawned = std::thread::Builder::new()
+                .name("pack-updates".to_string())
+                .spawn(move || {
+                    run_scheduled_check(&app);
+                    CHECK_RUNNING.store(false, Ordering::SeqCst);
+                });
+            if let Err(e) = spawned {
+                CHECK_RUNNING.store(false, Ordering::SeqCst);
+                cc_warn!("[CursorChanger] Failed to start pack update check: {}", e);
+            }
+        },
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pack(hash: Option<&str>) -> UpdatablePack {
+        UpdatablePack {
+            id: "neon".to_string(),
+            name: "Neon".to_string(),
+            url: "https://example.com/neon.zip".to_string(),
+            archive_hash: hash.map(str::to_string),
+            archive_size: None,
+            validators: PackValidators::default(),
+            installed_version: Some("1.0".to_string()),
+        }
+    }
+
+    fn remote(data: &[u8]) -> RemotePack {
+        RemotePack {
+            data: data.to_vec(),
+            hash: archive_hash(data),
+            version: Some("1.1".to_string()),
+            validators: PackValidators {
+                etag: Some("\"abc\"".to_string()),
+                last_modified: None,
+            },
+        }
+    }
+
+    #[test]
+    fn only_a_different_archive_is_an_update() {
+        let installed = archive_hash(b"v1");
+        assert!(!is_update(&pack(Some(&installed)), &remote(b"v1")));
+        assert!(is_update(&pack(Some(&installed)), &remote(b"v2")));
+        assert!(is_update(&pack(None), &remote(b"v1")));
+    }
+
+    #[test]
+    fn status_reports_both_versions() {
+        let checked = status(
+            &pack(Some(&archive_hash(b"v1"))),
+            Some(&remote(b"v2")),
+            None,
+        );
+        assert_eq!(checked.installed_version.as_deref(), Some("1.0"));
+        assert_eq!(checked.remote_version.as_deref(), Some("1.1"));
+        assert!(checked.update_available);
+
+        let failed = status(&pack(None), None, Some("offline".to_string()));
+        assert!(!failed.update_available);
+        assert_eq!(failed.remote_version, None);
+    }
+
+    #[test]
+    fn validators_are_kept_only_for_the_installed_archive() {
+        let installed = pack(Some(&archive_hash(b"v1")));
+
+        // Unchanged on the server: nothing new to store
+        let unchanged = checked(&installed, None);
+        assert!(!unchanged.update_available);
+        assert!(unchanged.validators.is_none());
+
+        // Same archive downloaded: its validators answer the next check
+        let same = checked(&installed, Some(&remote(b"v1")));
+        assert!(!same.update_available);
+        assert_eq!(
+            same.validators.and_then(|v| v.etag).as_deref(),
+            Some("\"abc\"")
+        );
+
+        // A new archive is not installed yet, so its validators must not be stored
+        let newer = checked(&installed, Some(&remote(b"v2")));
+        assert!(newer.update_available);
+        assert!(newer.validators.is_none());
+    }
+
+    #[test]
+    fn item_notes_survive_an_update() {
+        let previous = vec![LibraryPackItem {
+            file_name: "Arrow.cur".to_string(),
+            notes: Some("My favourite".to_string()),
+            ..Default::default()
+        }];
+        let mut items = vec![
+            LibraryPackItem {
+                file_name: "Arrow.cur".to_string(),
+                ..Default::default()
+            },
+            LibraryPackItem {
+                file_name: "Wait.ani".to_string(),
+                notes: Some("New in 1.1".to_string()),
+                ..Default::default()
+            },
+        ];
+        keep_item_notes(&mut items, &previous);
+        assert_eq!(items[0].notes.as_deref(), Some("My favourite"));
+        assert_eq!(items[1].notes.as_deref(), Some("New in 1.1"));
+    }
+}
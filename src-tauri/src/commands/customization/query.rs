@@ -1,13 +1,14 @@
 use crate::state::{AppState, CursorInfo, DefaultCursorStyle};
 /// Cursor query commands - get information about cursors
+use serde::{Deserialize, Serialize};
 use tauri::State;
 
 use super::cursor_preview_resolver::{CursorPreviewResolver, TauriCursorPreviewDeps};
+use super::library::AniPreviewData;
 
 /// Get list of all cursor types with their current image paths
 #[tauri::command]
 pub fn get_available_cursors(state: State<AppState>) -> Result<Vec<CursorInfo>, String> {
-    let cursor_types = &cursor_changer::CURSOR_TYPES;
     let mut result = Vec::new();
 
     let cursor = state
@@ -15,7 +16,7 @@ pub fn get_available_cursors(state: State<AppState>) -> Result<Vec<CursorInfo>,
         .read()
         .map_err(|_| "Failed to lock state".to_string())?;
 
-    for cursor_type in cursor_types {
+    for cursor_type in cursor_changer::all_cursor_types() {
         // Get cursor path from in-memory state instead of registry
         let image_path = cursor.cursor_paths.get(cursor_type.name).cloned();
         result.push(CursorInfo {
@@ -32,21 +33,15 @@ pub fn get_available_cursors(state: State<AppState>) -> Result<Vec<CursorInfo>,
 /// Get cursor image path from registry (legacy)
 #[tauri::command]
 pub fn get_cursor_image(cursor_name: String) -> Result<Option<String>, String> {
-    let cursor_types = &cursor_changer::CURSOR_TYPES;
+    let cursor_type = cursor_changer::find_cursor_type(&cursor_name)
+        .ok_or_else(|| format!("Cursor type '{}' not found", cursor_name))?;
 
-    for cursor_type in cursor_types {
-        if cursor_type.name == cursor_name {
-            return Ok(cursor_changer::read_cursor_image_from_registry(cursor_type));
-        }
-    }
-
-    Err(format!("Cursor type '{}' not found", cursor_name))
+    Ok(cursor_changer::read_cursor_image_from_registry(cursor_type))
 }
 
 /// Get all custom cursors (excluding default cursors)
 #[tauri::command]
 pub fn get_custom_cursors(state: State<AppState>) -> Result<Vec<CursorInfo>, String> {
-    let cursor_types = &cursor_changer::CURSOR_TYPES;
     let mut result = Vec::new();
 
     let cursor = state
@@ -54,7 +49,7 @@ pub fn get_custom_cursors(state: State<AppState>) -> Result<Vec<CursorInfo>, Str
         .read()
         .map_err(|_| "Failed to lock state".to_string())?;
 
-    for cursor_type in cursor_types {
+    for cursor_type in cursor_changer::all_cursor_types() {
         if let Some(image_path) = cursor.cursor_paths.get(cursor_type.name) {
             // Only include custom cursors, not app defaults
             if !image_path.contains("default-cursors") {
@@ -78,9 +73,7 @@ pub fn get_system_cursor_preview(
     state: State<AppState>,
     app: tauri::AppHandle,
 ) -> Result<String, String> {
-    let cursor_types = &cursor_changer::CURSOR_TYPES;
-
-    for cursor_type in cursor_types {
+    for cursor_type in cursor_changer::all_cursor_types() {
         if cursor_type.name == cursor_name {
             // Try to get from default cursor files using Tauri resource directory
             // Use name-based lookup to support both .cur and .ani files
@@ -103,6 +96,135 @@ pub fn get_system_cursor_preview(
     Err(format!("Cursor type '{}' not found", cursor_name))
 }
 
+/// Where a default-cursor preview came from.
+#[derive(ts_rs::TS, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub enum DefaultPreviewSource {
+    /// The app's bundled default cursors for the requested style
+    Bundled,
+    /// The stock Windows scheme (black, inverted, magnified) the style is read from
+    SystemScheme,
+    /// Windows' own "Windows Default" (aero) scheme
+    SystemAero,
+    Placeholder,
+}
+
+#[derive(ts_rs::TS, Debug, Clone, Serialize, Deserialize)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct DefaultCursorPreview {
+    pub cursor_name: String,
+    pub display_name: String,
+    pub data_url: String,
+    pub source: DefaultPreviewSource,
+}
+
+/// Previews of what "restore defaults" would apply for `style`, one per cursor role.
+/// Stock-scheme styles show the scheme's own files; roles the style has no file for fall
+/// back to the bundled set, then to Windows' aero scheme files.
+#[tauri::command]
+pub fn get_default_cursor_previews(
+    style: DefaultCursorStyle,
+    state: State<AppState>,
+    app: tauri::AppHandle,
+) -> Result<Vec<DefaultCursorPreview>, String> {
+    let deps = TauriCursorPreviewDeps::new(&*state, &app);
+    let resolver = CursorPreviewResolver::new(deps);
+
+    let previews = cursor_changer::CURSOR_TYPES
+        .iter()
+        .map(|cursor_type| {
+            let system_preview = |scheme: cursor_changer::SystemCursorScheme| {
+                cursor_changer::find_system_cursor_file(cursor_type.name, scheme).and_then(|path| {
+                    super::library::get_library_cursor_preview(path.to_string_lossy().to_string())
+                        .ok()
+                })
+            };
+
+            let scheme_preview = style.system_scheme().and_then(&system_preview);
+            let (data_url, source) = match scheme_preview {
+                Some(url) => (url, DefaultPreviewSource::SystemScheme),
+                None => match resolver.try_default_preview(style.as_str(), cursor_type.name)? {
+                    Some(url) => (url, DefaultPreviewSource::Bundled),
+                    None => match system_preview(cursor_changer::SystemCursorScheme::Aero) {
+                        Some(url) => (url, DefaultPreviewSource::SystemAero),
+                        None => (
+                            resolver.placeholder_data_url(cursor_type.display_name),
+                            DefaultPreviewSource::Placeholder,
+                        ),
+                    },
+                },
+            };
+
+            Ok(DefaultCursorPreview {
+                cursor_name: cursor_type.name.to_string(),
+                display_name: cursor_type.display_name.to_string(),
+                data_url,
+                source,
+            })
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    Ok(previews)
+}
+
+/// What Windows is currently using for one cursor role, read from the registry.
+#[derive(ts_rs::TS, Debug, Clone, Serialize, Deserialize)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct AppliedCursorPreview {
+    pub cursor_name: String,
+    pub display_name: String,
+    /// Registry value with `%VAR%` expanded; `None` when Windows uses its built-in cursor
+    pub file_path: Option<String>,
+    /// Still preview (first frame of animated cursors); `None` if the file is missing or
+    /// unreadable
+    pub data_url: Option<String>,
+    /// Frames and timing when the applied file is an .ani
+    pub animation: Option<AniPreviewData>,
+}
+
+/// Registry cursor value as a usable path. Empty values mean the built-in cursor.
+fn applied_cursor_path(value: Option<String>) -> Option<String> {
+    value
+        .map(|v| super::super::cursor_commands::expand_env_vars(v.trim()))
+        .filter(|v| !v.is_empty())
+}
+
+/// Previews of the cursors currently applied in Windows, one per role, read straight from
+/// the registry so changes made outside the app show up too.
+#[tauri::command]
+pub async fn get_applied_cursor_previews() -> Result<Vec<AppliedCursorPreview>, String> {
+    let mut previews = Vec::new();
+    for cursor_type in cursor_changer::all_cursor_types() {
+        let file_path =
+            applied_cursor_path(cursor_changer::read_cursor_image_from_registry(cursor_type));
+        let data_url = file_path
+            .clone()
+            .and_then(|path| super::library::get_library_cursor_preview(path).ok());
+
+        let is_ani = file_path.as_deref().is_some_and(|path| {
+            std::path::Path::new(path)
+                .extension()
+                .is_some_and(|ext| ext.eq_ignore_ascii_case("ani"))
+        });
+        let animation = match (&file_path, is_ani) {
+            (Some(path), true) => super::library::get_ani_preview_data(path.clone())
+                .await
+                .ok(),
+            _ => None,
+        };
+
+        previews.push(AppliedCursorPreview {
+            cursor_name: cursor_type.name.to_string(),
+            display_name: cursor_type.display_name.to_string(),
+            file_path,
+            data_url,
+            animation,
+        });
+    }
+    Ok(previews)
+}
+
 /// Create a placeholder SVG for cursors without previews
 pub(super) fn create_placeholder_svg(cursor_name: &str) -> String {
     let svg_template = r#"<svg width="32" height="32" xmlns="http://www.w3.org/2000/svg">
@@ -119,3 +241,18 @@ pub(super) fn create_placeholder_svg(cursor_name: &str) -> String {
             &cursor_name.chars().take(4).collect::<String>(),
         )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_registry_values_mean_the_built_in_cursor() {
+        assert_eq!(applied_cursor_path(None), None);
+        assert_eq!(applied_cursor_path(Some("  ".to_string())), None);
+        assert_eq!(
+            applied_cursor_path(Some(" C:\\Cursors\\arrow.ani ".to_string())).as_deref(),
+            Some("C:\\Cursors\\arrow.ani")
+        );
+    }
+}
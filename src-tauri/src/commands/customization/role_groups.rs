@@ -0,0 +1,167 @@
+/// Cursor role groups - one uploaded cursor applied to several related roles
+///
+/// Simple mode only tracks the Normal and Hand slots: Normal is applied to every role except
+/// Hand. Its groups therefore collapse to `Pointer` (everything but Hand) and `Link` (Hand).
+/// Advanced mode exposes the finer groups so related roles can be set in one step.
+use super::cursor_apply_service;
+use crate::cursor_defaults::SIMPLE_MODE_CURSOR_NAMES;
+use crate::state::{AppState, CursorInfo, CustomizationMode};
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, State};
+
+#[derive(ts_rs::TS, Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub enum RoleGroup {
+    Pointer,
+    Link,
+    Text,
+    Busy,
+    Precision,
+    Resize,
+    Unavailable,
+}
+
+#[derive(ts_rs::TS, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct RoleGroupInfo {
+    pub group: RoleGroup,
+    pub display_name: String,
+    /// Cursor role names (`CURSOR_TYPES` names) the group applies to
+    pub roles: Vec<String>,
+}
+
+impl RoleGroup {
+    pub const ALL: [RoleGroup; 7] = [
+        RoleGroup::Pointer,
+        RoleGroup::Link,
+        RoleGroup::Text,
+        RoleGroup::Busy,
+        RoleGroup::Precision,
+        RoleGroup::Resize,
+        RoleGroup::Unavailable,
+    ];
+
+    pub fn display_name(self) -> &'static str {
+        match self {
+            Self::Pointer => "Pointer",
+            Self::Link => "Link",
+            Self::Text => "Text",
+            Self::Busy => "Busy",
+            Self::Precision => "Precision",
+            Self::Resize => "Resize",
+            Self::Unavailable => "Unavailable",
+        }
+    }
+
+    /// Roles covered by the group in `mode`. Empty when the group is not offered in that mode.
+    pub fn roles(self, mode: CustomizationMode) -> Vec<&'static str> {
+        match (mode, self) {
+            (CustomizationMode::Simple, Self::Pointer) => SIMPLE_MODE_CURSOR_NAMES.to_vec(),
+            (CustomizationMode::Simple, Self::Link) => vec!["Hand"],
+            (CustomizationMode::Simple, _) => Vec::new(),
+            (CustomizationMode::Advanced, Self::Pointer) => vec!["Normal", "Help", "Up"],
+            (CustomizationMode::Advanced, Self::Link) => vec!["Hand"],
+            (CustomizationMode::Advanced, Self::Text) => vec!["IBeam", "Pen"],
+            (CustomizationMode::Advanced, Self::Busy) => vec!["Wait", "AppStarting"],
+            (CustomizationMode::Advanced, Self::Precision) => vec!["Cross"],
+            (CustomizationMode::Advanced, Self::Resize) => {
+                vec!["SizeNS", "SizeWE", "SizeNWSE", "SizeNESW", "SizeAll"]
+            }
+            (CustomizationMode::Advanced, Self::Unavailable) => vec!["No"],
+        }
+    }
+}
+
+/// Groups offered in `mode`, in display order.
+pub fn role_groups_for_mode(mode: CustomizationMode) -> Vec<RoleGroupInfo> {
+    RoleGroup::ALL
+        .iter()
+        .filter_map(|group| {
+            let roles = group.roles(mode);
+            (!roles.is_empty()).then(|| RoleGroupInfo {
+                group: *group,
+                display_name: group.display_name().to_string(),
+                roles: roles.into_iter().map(str::to_string).collect(),
+            })
+        })
+        .collect()
+}
+
+/// Role groups for the current customization mode
+#[tauri::command]
+pub fn get_role_groups(state: State<AppState>) -> Result<Vec<RoleGroupInfo>, String> {
+    let mode = state.read_all()?.modes.customization_mode;
+    Ok(role_groups_for_mode(mode))
+}
+
+/// Apply a cursor file to every role in a group at the current cursor size
+#[tauri::command]
+pub fn apply_cursor_to_group(
+    group: RoleGroup,
+    image_path: String,
+    state: State<AppState>,
+    app: AppHandle,
+) -> Result<Vec<CursorInfo>, String> {
+    let (mode, size) = {
+        let guard = state.read_all()?;
+        (guard.modes.customization_mode, guard.prefs.cursor_size)
+    };
+
+    let roles = group.roles(mode);
+    if roles.is_empty() {
+        return Err(format!(
+            "The {} group is not available in {} mode",
+            group.display_name(),
+            mode.as_str()
+        ));
+    }
+
+    // Simple mode stores the whole Pointer group under the Normal slot, like an upload does
+    let cursor_names = match (mode, group) {
+        (CustomizationMode::Simple, RoleGroup::Pointer) => vec!["Normal".to_string()],
+        _ => roles.into_iter().map(str::to_string).collect(),
+    };
+    let applied = cursor_apply_service::set_multiple_cursors_with_size(
+        cursor_names,
+        image_path,
+        size,
+        state.clone(),
+        app,
+    )?;
+
+    if mode == CustomizationMode::Simple && group == RoleGroup::Pointer {
+        let guard = state.read_all()?;
+        crate::cursor_defaults::apply_cursor_paths_simple(&guard.cursor.cursor_paths, size);
+    }
+    Ok(applied)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn advanced_groups_cover_every_role_once() {
+        let roles: Vec<&str> = RoleGroup::ALL
+            .iter()
+            .flat_map(|g| g.roles(CustomizationMode::Advanced))
+            .collect();
+        let unique: HashSet<&str> = roles.iter().copied().collect();
+        assert_eq!(roles.len(), unique.len());
+        assert_eq!(unique.len(), cursor_changer::CURSOR_TYPES.len());
+        for cursor_type in cursor_changer::CURSOR_TYPES.iter() {
+            assert!(unique.contains(cursor_type.name), "{} is ungrouped", cursor_type.name);
+        }
+    }
+
+    #[test]
+    fn simple_mode_offers_pointer_and_link_only() {
+        let groups = role_groups_for_mode(CustomizationMode::Simple);
+        let names: Vec<RoleGroup> = groups.iter().map(|g| g.group).collect();
+        assert_eq!(names, vec![RoleGroup::Pointer, RoleGroup::Link]);
+        assert!(!groups[0].roles.contains(&"Hand".to_string()));
+        assert_eq!(groups[1].roles, vec!["Hand".to_string()]);
+    }
+}
@@ -11,6 +11,7 @@ pub fn set_all_cursors(
     app: AppHandle,
 ) -> Result<Vec<CursorInfo>, String> {
     cursor_apply_service::set_all_cursors(image_path, state, app)
+        .inspect(|_| crate::usage_stats::record_apply())
 }
 
 /// Apply a cursor file to all cursor types with an explicit size
@@ -22,6 +23,7 @@ pub fn set_all_cursors_with_size(
     app: AppHandle,
 ) -> Result<Vec<CursorInfo>, String> {
     cursor_apply_service::set_all_cursors_with_size(image_path, size, state, app)
+        .inspect(|_| crate::usage_stats::record_apply())
 }
 
 /// Apply a cursor file to a single cursor type with explicit size
@@ -34,6 +36,7 @@ pub fn set_single_cursor_with_size(
     app: AppHandle,
 ) -> Result<CursorInfo, String> {
     cursor_apply_service::set_single_cursor_with_size(cursor_name, image_path, size, state, app)
+        .inspect(|_| crate::usage_stats::record_apply())
 }
 
 /// Apply a cursor file to multiple cursor types with explicit size
@@ -46,4 +49,5 @@ pub fn set_multiple_cursors_with_size(
     app: AppHandle,
 ) -> Result<Vec<CursorInfo>, String> {
     cursor_apply_service::set_multiple_cursors_with_size(cursor_names, image_path, size, state, app)
+        .inspect(|_| crate::usage_stats::record_apply())
 }
@@ -2,7 +2,7 @@
 use crate::commands::command_helpers;
 use crate::commands::customization::set_cursor_validation::validate_cursor_file;
 use crate::state::{AppState, CursorInfo};
-use cursor_changer::CURSOR_TYPES;
+use cursor_changer::find_cursor_type;
 use tauri::{AppHandle, State};
 
 /// Set a single cursor image
@@ -14,9 +14,7 @@ pub fn set_cursor_image(
     app: AppHandle,
 ) -> Result<CursorInfo, String> {
     // Find the cursor type
-    let cursor_type = CURSOR_TYPES
-        .iter()
-        .find(|ct| ct.name == cursor_name)
+    let cursor_type = find_cursor_type(&cursor_name)
         .ok_or_else(|| format!("Cursor type '{}' not found", cursor_name))?;
 
     // Validate and process the file
@@ -49,5 +47,6 @@ pub fn set_cursor_image(
             })
         })?;
 
+    crate::usage_stats::record_apply();
     Ok(info)
 }
@@ -1,7 +1,7 @@
 use super::cursor_apply_service;
 use crate::state::{AppState, CursorStatePayload};
 /// Cursor size management operations
-use tauri::{AppHandle, State};
+use tauri::{AppHandle, Manager, State};
 
 /// Change the size of the currently loaded cursor
 #[tauri::command]
@@ -12,3 +12,12 @@ pub fn set_cursor_size(
 ) -> Result<CursorStatePayload, String> {
     cursor_apply_service::set_cursor_size(size, state, app)
 }
+
+/// Change the cursor size without bringing the main window to the front, for size changes
+/// made while another app has focus.
+pub fn resize_cursor_in_background(
+    size: i32,
+    app: &AppHandle,
+) -> Result<CursorStatePayload, String> {
+    cursor_apply_service::resize_cursor(size, app.state::<AppState>(), app.clone(), false)
+}
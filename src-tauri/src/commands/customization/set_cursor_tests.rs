@@ -217,6 +217,33 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn custom_cursors_are_refused_while_the_style_is_forced() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let cur_path = temp.path().join("test.cur");
+        std::fs::write(&cur_path, []).expect("write cur");
+
+        let (_app, handle, state) = prepare_app_state();
+        state.policy.write().unwrap().force_default_cursor_style =
+            Some(cursor_changer_tauri::state::DefaultCursorStyle::Windows);
+
+        let _apply_guard = set_apply_cursor_from_file_with_size_mock_guard(|_, _, _| {
+            panic!("no cursor may be applied while the style is forced")
+        });
+
+        let result = set_single_cursor_with_size(
+            "Normal".into(),
+            cur_path.to_string_lossy().to_string(),
+            32,
+            state,
+            handle,
+        );
+        assert_eq!(
+            result.unwrap_err(),
+            "The default cursor style is locked by your administrator"
+        );
+    }
+
     #[test]
     fn set_all_cursors_returns_all_15_cursor_types() {
         let temp = tempfile::tempdir().expect("tempdir");
@@ -1,8 +1,10 @@
 use super::file_ops::convert_image_to_cur;
+use crate::commands::cursor_commands::check_custom_cursors_allowed;
 use crate::cursor_converter;
+use crate::state::AppState;
 /// File validation utilities for cursor operations
 use std::path::Path;
-use tauri::{AppHandle, Runtime};
+use tauri::{AppHandle, Manager, Runtime};
 
 const SUPPORTED_IMAGE_EXTS: [&str; 6] = ["svg", "png", "ico", "bmp", "jpg", "jpeg"];
 const SUPPORTED_CURSOR_EXTS: [&str; 2] = ["cur", "ani"];
@@ -27,6 +29,9 @@ pub fn validate_cursor_file<R: Runtime>(image_path: &str, app: &AppHandle<R>) ->
     if image_path.is_empty() {
         return Ok(image_path.to_string());
     }
+    if let Some(state) = app.try_state::<AppState>() {
+        check_custom_cursors_allowed(&state)?;
+    }
 
     let path = Path::new(image_path);
     if !path.exists() {
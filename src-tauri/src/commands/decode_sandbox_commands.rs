@@ -0,0 +1,16 @@
+/// Worker-process decoding of untrusted cursor files (see `crate::decode_worker`).
+use crate::decode_worker::{self, DecodeSandboxSettings};
+use tauri::AppHandle;
+
+#[tauri::command]
+pub fn get_decode_sandbox(app: AppHandle) -> DecodeSandboxSettings {
+    decode_worker::load_settings(&app)
+}
+
+/// Turn worker-process decoding of previews, imports and uploads on or off.
+#[tauri::command]
+pub fn set_decode_sandbox(app: AppHandle, enabled: bool) -> Result<DecodeSandboxSettings, String> {
+    let settings = DecodeSandboxSettings { enabled };
+    decode_worker::save_settings(&app, &settings)?;
+    Ok(settings)
+}
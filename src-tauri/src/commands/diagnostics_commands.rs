@@ -0,0 +1,296 @@
+/// Diagnostics bundle for bug reports.
+///
+/// `export_diagnostics_bundle` zips everything usually asked for in a bug report into one
+/// file: recent log lines and crash logs, the config folder, library metadata, a summary of
+/// the current state and how the cursor registry changed since startup. Cursor files are only
+/// included on request. Text is scrubbed of the user profile path and account names first.
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Runtime, State};
+use zip::write::SimpleFileOptions;
+
+use crate::commands::customization::library::load_library;
+use crate::state::AppState;
+
+#[derive(Serialize)]
+struct DiagnosticsReport {
+    app_version: &'static str,
+    os: &'static str,
+    arch: &'static str,
+    customization_mode: String,
+    cursor_size: i32,
+    cursor_hidden: bool,
+    /// Role name -> applied file
+    active_cursors: BTreeMap<String, String>,
+    library_cursors: usize,
+    library_packs: usize,
+    pending_crash_reports: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct RegistryChange {
+    key: String,
+    at_startup: Option<String>,
+    current: Option<String>,
+}
+
+#[derive(Serialize)]
+struct RegistryDiagnostics {
+    /// `None` when no snapshot was taken at startup
+    at_startup: Option<BTreeMap<String, Option<String>>>,
+    current: BTreeMap<String, Option<String>>,
+    changed: Vec<RegistryChange>,
+}
+
+/// Replace the account folder after every `Users` path segment, in plain and JSON-escaped
+/// paths, so other profiles mentioned in logs or config are scrubbed too.
+fn scrub_user_dirs(text: &str) -> String {
+    let mut scrubbed = text.to_string();
+    // Escaped separators first: "\Users\" also matches inside "\\Users\\"
+    for separator in ["\\\\", "\\", "/"] {
+        let marker = format!("{separator}users{separator}");
+        let lower = scrubbed.to_ascii_lowercase();
+        let mut result = String::with_capacity(scrubbed.len());
+        let mut copied = 0;
+        let mut search = 0;
+        while let Some(found) = lower[search..].find(&marker) {
+            let start = search + found + marker.len();
+            let end = scrubbed[start..]
+                .find(|c: char| c == '\\' || c == '/' || c == '"' || c.is_whitespace())
+                .map_or(scrubbed.len(), |i| start + i);
+            if end > start {
+                result.push_str(&scrubbed[copied..start]);
+                result.push_str("<user>");
+                copied = end;
+            }
+            search = end.max(start);
+        }
+        result.push_str(&scrubbed[copied..]);
+        scrubbed = result;
+    }
+    scrubbed
+}
+
+fn scrub(text: &str) -> String {
+    let mut scrubbed = crate::crash_reports::sanitize(text);
+    if let Ok(profile) = std::env::var("USERPROFILE") {
+        if !profile.is_empty() {
+            scrubbed = scrubbed.replace(&profile.replace('\\', "\\\\"), "%USERPROFILE%");
+        }
+    }
+    scrub_user_dirs(&scrubbed)
+}
+
+fn to_json<T: Serialize>(value: &T, what: &str) -> Result<Vec<u8>, String> {
+    serde_json::to_string_pretty(value)
+        .map(|json| scrub(&json).into_bytes())
+        .map_err(|e| format!("Failed to serialize {}: {}", what, e))
+}
+
+fn registry_diagnostics(
+    at_startup: Option<HashMap<String, Option<String>>>,
+    current: HashMap<String, Option<String>>,
+) -> RegistryDiagnostics {
+    let changed = at_startup
+        .as_ref()
+        .map(|startup| {
+            cursor_changer::diff_cursor_registry_entries(startup, &current)
+                .into_iter()
+                .map(|key| RegistryChange {
+                    at_startup: startup.get(&key).cloned().flatten(),
+                    current: current.get(&key).cloned().flatten(),
+                    key,
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    RegistryDiagnostics {
+        at_startup: at_startup.map(|s| s.into_iter().collect()),
+        current: current.into_iter().collect(),
+        changed,
+    }
+}
+
+/// Text files directly inside `dir` (config and settings JSON), scrubbed, keyed by file name.
+fn text_files(dir: &Path, extensions: &[&str]) -> Vec<(String, Vec<u8>)> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut files: Vec<(String, Vec<u8>)> = entries
+        .flatten()
+        .map(|e| e.path())
+        .filter(|p| {
+            p.extension()
+                .and_then(|e| e.to_str())
+                .is_some_and(|e| extensions.iter().any(|x| e.eq_ignore_ascii_case(x)))
+        })
+        .filter_map(|p| {
+            let name = p.file_name()?.to_str()?.to_string();
+            let text = fs::read_to_string(&p).ok()?;
+            Some((name, scrub(&text).into_bytes()))
+        })
+        .collect();
+    files.sort_by(|a, b| a.0.cmp(&b.0));
+    files
+}
+
+fn write_bundle(path: &Path, entries: &[(String, Vec<u8>)]) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("Failed to create folder: {}", e))?;
+    }
+    let file = fs::File::create(path)
+        .map_err(|e| format!("Failed to create {}: {}", path.display(), e))?;
+    let mut zip_writer = zip::ZipWriter::new(file);
+    let options = SimpleFileOptions::default();
+    for (name, data) in entries {
+        zip_writer
+            .start_file(name.as_str(), options)
+            .map_err(|e| format!("Failed to start zip entry {}: {}", name, e))?;
+        zip_writer
+            .write_all(data)
+            .map_err(|e| format!("Failed to write {} to zip: {}", name, e))?;
+    }
+    zip_writer
+        .finish()
+        .map_err(|e| format!("Failed to finish diagnostics bundle: {}", e))?;
+    Ok(())
+}
+
+/// Write a diagnostics bundle to `path`. Library cursor files are added under `cursors/` only
+/// when `include_cursors` is set. Returns the names of the files in the bundle.
+#[tauri::command]
+pub fn export_diagnostics_bundle<R: Runtime>(
+    app: AppHandle<R>,
+    state: State<'_, AppState>,
+    path: String,
+    include_cursors: Option<bool>,
+) -> Result<Vec<String>, String> {
+    let (mut report, registry_at_startup) = {
+        let guard = state
+            .read_all()
+            .map_err(|e| format!("Failed to lock state: {}", e))?;
+        (
+            DiagnosticsReport {
+                app_version: env!("CARGO_PKG_VERSION"),
+                os: std::env::consts::OS,
+                arch: std::env::consts::ARCH,
+                customization_mode: guard.modes.customization_mode.as_str().to_string(),
+                cursor_size: guard.prefs.cursor_size,
+                cursor_hidden: guard.cursor.visibility.is_hidden(),
+                active_cursors: guard.cursor.cursor_paths.clone().into_iter().collect(),
+                library_cursors: 0,
+                library_packs: 0,
+                pending_crash_reports: Vec::new(),
+            },
+            guard.restoration.cursor_registry_snapshot.clone(),
+        )
+    };
+
+    let mut entries: Vec<(String, Vec<u8>)> = Vec::new();
+
+    let log = crate::logging::log_tail().join("\n");
+    entries.push(("logs/recent.log".to_string(), scrub(&log).into_bytes()));
+    if let Ok(dir) = crate::paths::crash_reports_dir() {
+        for report_entry in crate::crash_reports::list_reports(&dir) {
+            report.pending_crash_reports.push(report_entry.id);
+        }
+        for (name, data) in text_files(&dir, &["log"]) {
+            entries.push((format!("logs/{}", name), data));
+        }
+    }
+
+    if let Ok(dir) = crate::state::config::config_path(&app) {
+        for (name, data) in text_files(&dir, &["json"]) {
+            entries.push((format!("config/{}", name), data));
+        }
+    }
+
+    let library = load_library(&app)?;
+    report.library_packs = library.cursors.iter().filter(|c| c.is_pack).count();
+    report.library_cursors = library.cursors.len() - report.library_packs;
+    entries.push(("library.json".to_string(), to_json(&library, "library")?));
+    if include_cursors.unwrap_or(false) {
+        for cursor in library.cursors.iter().filter(|c| !c.is_pack) {
+            let source = PathBuf::from(&cursor.file_path);
+            let (Some(name), Ok(data)) = (source.file_name(), fs::read(&source)) else {
+                continue;
+            };
+            entries.push((
+                format!("cursors/{}-{}", cursor.id, name.to_string_lossy()),
+                data,
+            ));
+        }
+    }
+
+    let registry = registry_diagnostics(
+        registry_at_startup,
+        cursor_changer::snapshot_cursor_registry_entries(),
+    );
+    entries.push(("registry.json".to_string(), to_json(&registry, "registry")?));
+    entries.push(("diagnostics.json".to_string(), to_json(&report, "report")?));
+
+    write_bundle(Path::new(&path), &entries)?;
+    cc_info!(
+        "[CursorChanger] Exported diagnostics bundle with {} files",
+        entries.len()
+    );
+    Ok(entries.into_iter().map(|(name, _)| name).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn user_folders_are_scrubbed_in_plain_and_escaped_paths() {
+        let text = r#"C:\Users\alice\cursors\a.cur "C:\\Users\\bob\\b.cur" /Users/carol/c.cur"#;
+        assert_eq!(
+            scrub_user_dirs(text),
+            r#"C:\Users\<user>\cursors\a.cur "C:\\Users\\<user>\\b.cur" /Users/<user>/c.cur"#
+        );
+    }
+
+    #[test]
+    fn registry_changes_list_both_values() {
+        let startup = HashMap::from([
+            ("Arrow".to_string(), Some("aero_arrow.cur".to_string())),
+            ("Hand".to_string(), Some("aero_link.cur".to_string())),
+        ]);
+        let current = HashMap::from([
+            ("Arrow".to_string(), Some("custom.cur".to_string())),
+            ("Hand".to_string(), Some("aero_link.cur".to_string())),
+        ]);
+
+        let registry = registry_diagnostics(Some(startup), current);
+
+        assert_eq!(registry.changed.len(), 1);
+        assert_eq!(registry.changed[0].key, "Arrow");
+        assert_eq!(
+            registry.changed[0].at_startup.as_deref(),
+            Some("aero_arrow.cur")
+        );
+        assert_eq!(registry.changed[0].current.as_deref(), Some("custom.cur"));
+    }
+
+    #[test]
+    fn bundle_contains_every_entry() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let path = temp.path().join("out").join("diagnostics.zip");
+        let entries = vec![
+            ("logs/recent.log".to_string(), b"[INFO] hello".to_vec()),
+            ("diagnostics.json".to_string(), b"{}".to_vec()),
+        ];
+
+        write_bundle(&path, &entries).unwrap();
+
+        let mut archive = zip::ZipArchive::new(fs::File::open(&path).unwrap()).unwrap();
+        assert_eq!(archive.len(), 2);
+        let mut log = String::new();
+        std::io::Read::read_to_string(&mut archive.by_name("logs/recent.log").unwrap(), &mut log)
+            .unwrap();
+        assert_eq!(log, "[INFO] hello");
+    }
+}
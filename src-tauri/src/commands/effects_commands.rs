@@ -1,20 +1,48 @@
+use crate::commands::command_helpers;
+use crate::state::{AppState, CustomizationMode};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs;
-use std::path::PathBuf;
-use tauri::{AppHandle, Manager};
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Emitter, Manager, State};
 
-#[derive(ts_rs::TS, Serialize, Deserialize, Debug, Clone)]
-#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
-pub struct EffectsConfig {
-    pub enabled: Vec<String>,
+pub use crate::state::EffectsConfig;
+use crate::effects_quality::{self, EffectQuality, EffectQualitySettings, EffectQualityStatus};
+use crate::reduced_motion::{self, ReducedMotionSettings, ReducedMotionStatus};
+
+/// On-disk layout of `effects.json`.
+///
+/// Effects are stored per customization profile under `profiles`. The top-level `enabled`
+/// list is the pre-profile format; it is kept as the fallback for profiles that have not
+/// saved their own effects yet, so existing users keep their effects after upgrading.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+struct EffectsStore {
+    #[serde(default)]
+    enabled: Vec<String>,
+    #[serde(default)]
+    profiles: HashMap<String, EffectsConfig>,
+}
+
+impl EffectsStore {
+    fn for_profile(&self, mode: CustomizationMode) -> EffectsConfig {
+        self.profiles
+            .get(mode.as_str())
+            .cloned()
+            .unwrap_or_else(|| EffectsConfig {
+                enabled: self.enabled.clone(),
+            })
+    }
 }
 
 /// Get the path to the effects config file
 fn get_effects_config_path(app: &AppHandle) -> Result<PathBuf, String> {
-    let app_data_dir = app
-        .path()
-        .app_data_dir()
-        .map_err(|e| format!("Failed to get app data dir: {}", e))?;
+    let app_data_dir = match crate::portable::data_root() {
+        Some(root) => root.to_path_buf(),
+        None => app
+            .path()
+            .app_data_dir()
+            .map_err(|e| format!("Failed to get app data dir: {}", e))?,
+    };
 
     // Ensure the directory exists
     fs::create_dir_all(&app_data_dir)
@@ -23,36 +51,194 @@ fn get_effects_config_path(app: &AppHandle) -> Result<PathBuf, String> {
     Ok(app_data_dir.join("effects.json"))
 }
 
-/// Save effects configuration to disk
+fn read_effects_store(path: &Path) -> Result<EffectsStore, String> {
+    // Return default config if file doesn't exist
+    if !path.exists() {
+        return Ok(EffectsStore::default());
+    }
+
+    let json =
+        fs::read_to_string(path).map_err(|e| format!("Failed to read config file: {}", e))?;
+
+    serde_json::from_str(&json).map_err(|e| format!("Failed to parse config file: {}", e))
+}
+
+fn write_effects_store(path: &Path, store: &EffectsStore) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(store)
+        .map_err(|e| format!("Failed to serialize config: {}", e))?;
+
+    fs::write(path, json).map_err(|e| format!("Failed to write config file: {}", e))
+}
+
+fn current_mode(state: &State<AppState>) -> Result<CustomizationMode, String> {
+    state
+        .modes
+        .read()
+        .map(|modes| modes.customization_mode)
+        .map_err(|_| "Application state poisoned".to_string())
+}
+
+/// Read the effects saved for `mode`'s profile. Errors are logged and treated as "no effects".
+pub(crate) fn load_effects_for_mode(app: &AppHandle, mode: CustomizationMode) -> EffectsConfig {
+    match get_effects_config_path(app).and_then(|path| read_effects_store(&path)) {
+        Ok(store) => store.for_profile(mode),
+        Err(e) => {
+            cc_warn!(
+                "[CursorChanger] Failed to load effects for {} profile: {}",
+                mode.as_str(),
+                e
+            );
+            EffectsConfig::default()
+        }
+    }
+}
+
+/// Save effects configuration for the active profile
 #[tauri::command]
-pub fn save_effects_config(app: AppHandle, config: EffectsConfig) -> Result<(), String> {
+pub fn save_effects_config(
+    app: AppHandle,
+    state: State<AppState>,
+    config: EffectsConfig,
+) -> Result<(), String> {
     let config_path = get_effects_config_path(&app)?;
+    let mode = current_mode(&state)?;
 
-    let json = serde_json::to_string_pretty(&config)
-        .map_err(|e| format!("Failed to serialize config: {}", e))?;
+    let mut store = read_effects_store(&config_path).unwrap_or_else(|e| {
+        cc_warn!("[CursorChanger] Replacing unreadable effects config: {}", e);
+        EffectsStore::default()
+    });
+    store
+        .profiles
+        .insert(mode.as_str().to_string(), config.clone());
+    write_effects_store(&config_path, &store)?;
 
-    fs::write(&config_path, json).map_err(|e| format!("Failed to write config file: {}", e))?;
+    command_helpers::update_state_and_emit(&app, &state, false, |guard| {
+        guard.modes.effects = config;
+        Ok(())
+    })?;
 
     Ok(())
 }
 
-/// Load effects configuration from disk
+/// Load effects configuration for the active profile
 #[tauri::command]
-pub fn load_effects_config(app: AppHandle) -> Result<EffectsConfig, String> {
+pub fn load_effects_config(
+    app: AppHandle,
+    state: State<AppState>,
+) -> Result<EffectsConfig, String> {
     let config_path = get_effects_config_path(&app)?;
+    let mode = current_mode(&state)?;
+    let config = read_effects_store(&config_path)?.for_profile(mode);
 
-    // Return default config if file doesn't exist
-    if !config_path.exists() {
-        return Ok(EffectsConfig {
-            enabled: Vec::new(),
-        });
+    if let Ok(mut modes) = state.modes.write() {
+        modes.effects = config.clone();
     }
 
-    let json = fs::read_to_string(&config_path)
-        .map_err(|e| format!("Failed to read config file: {}", e))?;
+    Ok(config)
+}
 
-    let config: EffectsConfig =
-        serde_json::from_str(&json).map_err(|e| format!("Failed to parse config file: {}", e))?;
+/// Re-check the Windows animation setting. The UI calls this when the window regains focus.
+#[tauri::command]
+pub fn get_reduced_motion_status(
+    app: AppHandle,
+    state: State<AppState>,
+) -> Result<ReducedMotionStatus, String> {
+    let was_active = reduced_motion::is_active();
+    let status = reduced_motion::refresh(&app);
+    if status.active != was_active {
+        command_helpers::emit_state(&app, &state)?;
+    }
+    Ok(status)
+}
 
-    Ok(config)
+/// Choose whether effects and ANI previews follow the Windows reduced-motion setting
+#[tauri::command]
+pub fn set_reduced_motion_follow_system(
+    app: AppHandle,
+    state: State<AppState>,
+    follow_system: bool,
+) -> Result<ReducedMotionStatus, String> {
+    reduced_motion::save_settings(&app, &ReducedMotionSettings { follow_system })?;
+    let status = reduced_motion::refresh(&app);
+    command_helpers::emit_state(&app, &state)?;
+    Ok(status)
+}
+
+/// Current effect quality setting and the render profile the overlay should use
+#[tauri::command]
+pub fn get_effect_quality(app: AppHandle) -> EffectQualityStatus {
+    effects_quality::status(&app)
+}
+
+/// Set effect quality to Low, Medium, High or Auto
+#[tauri::command]
+pub fn set_effect_quality(
+    app: AppHandle,
+    quality: EffectQuality,
+) -> Result<EffectQualityStatus, String> {
+    effects_quality::save_settings(&app, &EffectQualitySettings { quality })?;
+    let status = effects_quality::set_quality(&app, quality);
+    let _ = app.emit(crate::events::EFFECT_QUALITY_CHANGED, status.clone());
+    Ok(status)
+}
+
+/// Report effect render times (milliseconds per frame) from the overlay. Under Auto the
+/// returned profile may step down or up; changes are also emitted as
+/// `effect-quality-changed`.
+#[tauri::command]
+pub fn report_effect_frame_times(app: AppHandle, frame_ms: Vec<f32>) -> EffectQualityStatus {
+    let (status, changed) = effects_quality::record_frames(&app, &frame_ms);
+    if changed {
+        let _ = app.emit(crate::events::EFFECT_QUALITY_CHANGED, status.clone());
+    }
+    status
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn legacy_effects_file_applies_to_every_profile() {
+        let store: EffectsStore =
+            serde_json::from_str(r#"{ "enabled": ["trail"] }"#).expect("parse legacy");
+
+        assert_eq!(
+            store.for_profile(CustomizationMode::Simple).enabled,
+            vec!["trail".to_string()]
+        );
+        assert_eq!(
+            store.for_profile(CustomizationMode::Advanced).enabled,
+            vec!["trail".to_string()]
+        );
+    }
+
+    #[test]
+    fn profile_effects_override_legacy_list() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let path = temp.path().join("effects.json");
+
+        let mut store = EffectsStore {
+            enabled: vec!["trail".to_string()],
+            ..Default::default()
+        };
+        store.profiles.insert(
+            CustomizationMode::Advanced.as_str().to_string(),
+            EffectsConfig {
+                enabled: vec!["ripple".to_string(), "spotlight".to_string()],
+            },
+        );
+        write_effects_store(&path, &store).expect("write");
+
+        let loaded = read_effects_store(&path).expect("read");
+        assert_eq!(loaded, store);
+        assert_eq!(
+            loaded.for_profile(CustomizationMode::Advanced).enabled,
+            vec!["ripple".to_string(), "spotlight".to_string()]
+        );
+        assert_eq!(
+            loaded.for_profile(CustomizationMode::Simple).enabled,
+            vec!["trail".to_string()]
+        );
+    }
 }
@@ -0,0 +1,9 @@
+/// Translated error messages (see `crate::error_codes`).
+use crate::error_codes::{self, LocalizedError};
+
+/// Translate a command error into `locale` (a BCP 47 tag such as `navigator.language`),
+/// falling back to English. Errors without a code are returned unchanged.
+#[tauri::command]
+pub fn localize_error(message: String, locale: Option<String>) -> LocalizedError {
+    error_codes::localize(&message, locale.as_deref())
+}
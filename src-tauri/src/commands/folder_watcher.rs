@@ -1,6 +1,7 @@
 use notify::RecommendedWatcher;
 /// File system watcher for the library cursors folder.
-/// Watches for added/removed .cur/.ani files and emits events to the frontend.
+/// Watches for added/removed .cur/.ani files, imports them in batches and emits events to the
+/// frontend once the library is synced.
 use std::sync::mpsc;
 use std::sync::Mutex;
 use tauri::{AppHandle, State};
@@ -9,7 +10,8 @@ use notify::RecursiveMode;
 /// Global state to track and control the watcher
 pub struct FolderWatcherState {
     watcher: Option<RecommendedWatcher>,
-    stop_tx: Option<mpsc::Sender<()>>,
+    /// Sends `None` on the event channel, which wakes the watch loop to stop it
+    stop_tx: Option<mpsc::Sender<Option<notify::Event>>>,
     join_handle: Option<std::thread::JoinHandle<()>>,
     running: bool,
 }
@@ -68,6 +70,29 @@ pub(crate) fn stop_watcher_for_shutdown(state: &Mutex<FolderWatcherState>) -> Re
     watcher::stop_watcher(state)
 }
 
+/// Stop the watcher while `work` moves the library folders, then watch the folders the
+/// library is in afterwards if the watcher was running before.
+pub(crate) fn pause_library_folder_watcher<T>(
+    app: &AppHandle,
+    state: &Mutex<FolderWatcherState>,
+    work: impl FnOnce() -> Result<T, String>,
+) -> Result<T, String> {
+    let was_running = state
+        .lock()
+        .map_err(|e| format!("Lock error: {}", e))?
+        .running;
+    watcher::stop_watcher(state)?;
+    let result = work();
+    if was_running {
+        let folders = vec![
+            (crate::paths::cursors_dir()?, RecursiveMode::NonRecursive),
+            (crate::paths::cursor_packs_dir()?, RecursiveMode::Recursive),
+        ];
+        watcher::start_watcher(app.clone(), state, folders)?;
+    }
+    result
+}
+
 /// Sync the library with files currently in the cursors folder.
 /// This scans the folder for .cur/.ani files and ensures the library JSON reflects them.
 #[tauri::command]
@@ -81,6 +106,8 @@ mod watcher;
 #[path = "folder_watcher/sync.rs"]
 mod sync;
 
+pub use sync::LibraryImportStatus;
+
 /// Read the hotspot from a .cur file
 fn read_cursor_hotspot(file_path: &str) -> Option<(u16, u16)> {
     let bytes = std::fs::read(file_path).ok()?;
@@ -1,19 +1,59 @@
 use super::read_cursor_hotspot;
+use serde::Serialize;
 use std::collections::HashSet;
-use std::path::PathBuf;
-use tauri::AppHandle;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use tauri::{AppHandle, Emitter};
+
+use crate::commands::customization::pack_commands::{
+    extract_pack_assets, read_manifest_or_infer, PackValidationMode,
+};
+use crate::commands::customization::library::populate_size_metadata;
+use crate::commands::customization::{pack_library, pack_sprite};
+use crate::events;
+use crate::paths::canonical_key;
+
+/// Files imported at the same time. Pack archives are extracted and previewed, which is worth
+/// overlapping, but a folder of hundreds of new files shouldn't get hundreds of threads.
+const MAX_CONCURRENT_IMPORTS: usize = 4;
+
+/// Held for a whole sync so the watcher and the sync command never import a file twice.
+static SYNC_LOCK: Mutex<()> = Mutex::new(());
+
+/// Progress of the files being imported by a sync, emitted as `library:import-queue`.
+#[derive(ts_rs::TS, Serialize, Clone, Debug, Default, PartialEq, Eq)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct LibraryImportStatus {
+    /// Files waiting for a free import slot
+    pub queued: u32,
+    pub in_progress: u32,
+    pub imported: u32,
+    pub total: u32,
+}
 
-use crate::commands::customization::pack_commands::{extract_pack_assets, read_manifest_or_infer};
-use crate::commands::customization::pack_library;
+impl LibraryImportStatus {
+    fn new(total: usize, imported: usize) -> Self {
+        let in_progress = (total - imported).min(MAX_CONCURRENT_IMPORTS);
+        Self {
+            queued: (total - imported - in_progress) as u32,
+            in_progress: in_progress as u32,
+            imported: imported as u32,
+            total: total as u32,
+        }
+    }
+}
 
 pub(super) fn sync_library_with_folder_inner(app: &AppHandle) -> Result<(), String> {
-    use super::super::customization::library::{load_library, save_library};
+    use super::super::customization::library::{load_library, lock_library, save_library};
+
+    let _sync = SYNC_LOCK.lock().map_err(|e| format!("Lock error: {}", e))?;
 
     let cursors_folder = crate::paths::cursors_dir()?;
     let packs_folder = crate::paths::cursor_packs_dir()?;
 
     let files_on_disk = scan_library_files(&cursors_folder, &packs_folder);
-    let mut library = load_library(app)?;
+    let library = load_library(app)?;
 
     let (files_to_add, files_to_remove) = diff_library_vs_disk(&library, &files_on_disk);
     
@@ -22,9 +62,28 @@ pub(super) fn sync_library_with_folder_inner(app: &AppHandle) -> Result<(), Stri
     let mut files_to_remove_combined = files_to_remove;
     files_to_remove_combined.extend(packs_in_deleted_dirs);
 
-    let changed = apply_folder_diff(&mut library, files_to_add, files_to_remove_combined);
-
-    if changed {
+    let total = files_to_add.len();
+    let report = |imported: usize| {
+        let _ = app.emit(
+            events::LIBRARY_IMPORT_QUEUE,
+            LibraryImportStatus::new(total, imported),
+        );
+    };
+    if total > 0 {
+        report(0);
+    }
+    let _heavy_work = (total > 0).then(crate::memory_policy::HeavyWork::begin);
+    let imported = import_capped(
+        &files_to_add,
+        MAX_CONCURRENT_IMPORTS,
+        |file_path| import_file(file_path),
+        report,
+    );
+
+    // Imports can take a while, so only merging them into the current library is locked
+    let _lock = lock_library(app)?;
+    let mut library = load_library(app)?;
+    if apply_folder_diff(&mut library, imported, &files_to_remove_combined) {
         save_library(app, &library)?;
         cc_debug!("[FolderWatcher] Library synced with folder");
     }
@@ -127,108 +186,277 @@ fn diff_library_vs_disk(
         .map(|c| c.file_path.clone())
         .collect();
 
-    let files_to_add: Vec<_> = files_on_disk.difference(&library_paths).cloned().collect();
-    let files_to_remove: Vec<_> = library_paths.difference(files_on_disk).cloned().collect();
+    // Compare canonical forms so a casing or short-name difference is not a remove + add
+    let keys = |paths: &HashSet<String>| -> HashSet<String> {
+        paths.iter().map(|p| canonical_key(Path::new(p))).collect()
+    };
+    let (library_keys, disk_keys) = (keys(&library_paths), keys(files_on_disk));
+
+    let files_to_add: Vec<_> = files_on_disk
+        .iter()
+        .filter(|f| !library_keys.contains(&canonical_key(Path::new(f))))
+        .cloned()
+        .collect();
+    let files_to_remove: Vec<_> = library_paths
+        .iter()
+        .filter(|f| !disk_keys.contains(&canonical_key(Path::new(f))))
+        .cloned()
+        .collect();
 
     (files_to_add, files_to_remove)
 }
 
+/// Run `import` over `items` on at most `limit` threads, keeping their order. `on_done` gets
+/// the number of finished items each time one finishes.
+fn import_capped<T: Sync, R: Send>(
+    items: &[T],
+    limit: usize,
+    import: impl Fn(&T) -> R + Sync,
+    on_done: impl Fn(usize) + Sync,
+) -> Vec<R> {
+    let next = AtomicUsize::new(0);
+    let finished = AtomicUsize::new(0);
+    let results: Mutex<Vec<Option<R>>> = Mutex::new(items.iter().map(|_| None).collect());
+
+    std::thread::scope(|scope| {
+        for _ in 0..limit.clamp(1, items.len().max(1)) {
+            scope.spawn(|| loop {
+                let index = next.fetch_add(1, Ordering::SeqCst);
+                let Some(item) = items.get(index) else {
+                    break;
+                };
+                let result = import(item);
+                if let Ok(mut results) = results.lock() {
+                    results[index] = Some(result);
+                }
+                on_done(finished.fetch_add(1, Ordering::SeqCst) + 1);
+            });
+        }
+    });
+
+    results
+        .into_inner()
+        .unwrap_or_default()
+        .into_iter()
+        .flatten()
+        .collect()
+}
+
+/// Add the imported entries and drop the removed files. Entries another writer added while
+/// the import ran are kept as they are. Returns whether the library changed.
 fn apply_folder_diff(
     library: &mut super::super::customization::library::LibraryData,
-    files_to_add: Vec<String>,
-    files_to_remove: Vec<String>,
+    imported: Vec<super::super::customization::library::LibraryCursor>,
+    files_to_remove: &[String],
 ) -> bool {
+    let before = library.cursors.len();
+    let removed: HashSet<String> = files_to_remove
+        .iter()
+        .map(|f| canonical_key(Path::new(f)))
+        .collect();
+    library
+        .cursors
+        .retain(|c| !removed.contains(&canonical_key(Path::new(&c.file_path))));
+    let mut changed = library.cursors.len() != before;
+
+    let mut present: HashSet<String> = library
+        .cursors
+        .iter()
+        .map(|c| canonical_key(Path::new(&c.file_path)))
+        .collect();
+    for cursor in imported {
+        if present.insert(canonical_key(Path::new(&cursor.file_path))) {
+            library.cursors.push(cursor);
+            changed = true;
+        }
+    }
+
+    changed
+}
+
+fn import_file(file_path: &str) -> super::super::customization::library::LibraryCursor {
     use super::super::customization::library::LibraryCursor;
     use super::super::customization::library::LibraryPackMetadata;
 
-    let mut changed = false;
-
-    for file_path in files_to_add {
-        let path = std::path::Path::new(&file_path);
-        let ext = path
-            .extension()
-            .and_then(|s| s.to_str())
-            .unwrap_or("")
-            .to_ascii_lowercase();
-        let mut display_name = path
-            .file_stem()
-            .and_then(|n| n.to_str())
-            .unwrap_or("Cursor")
-            .to_string();
-
-        let id = crate::utils::library_meta::new_library_cursor_id();
-
-        let (is_pack, pack_metadata, hotspot_x, hotspot_y) = if ext == "zip" {
-            let meta = match read_manifest_or_infer(path) {
-                Ok(mut manifest) => {
-                    display_name = manifest.pack_name.clone();
-
-                    match extract_pack_assets(&id, path, &manifest) {
-                        Ok(extracted_map) => {
-                            for item in manifest.items.iter_mut() {
-                                if let Some(extracted_path) = extracted_map.get(&item.file_name) {
-                                    item.file_path =
-                                        Some(extracted_path.to_string_lossy().to_string());
-                                }
+    let file_path = file_path.to_string();
+    let path = std::path::Path::new(&file_path);
+    let ext = path
+        .extension()
+        .and_then(|s| s.to_str())
+        .unwrap_or("")
+        .to_ascii_lowercase();
+    let mut display_name = path
+        .file_stem()
+        .and_then(|n| n.to_str())
+        .unwrap_or("Cursor")
+        .to_string();
+
+    let id = crate::utils::library_meta::new_library_cursor_id();
+
+    let (is_pack, pack_metadata, hotspot_x, hotspot_y) = if ext == "zip" {
+        let meta = match read_manifest_or_infer(path, PackValidationMode::Strict) {
+            Ok(mut manifest) => {
+                display_name = manifest.pack_name.clone();
+
+                match extract_pack_assets(&id, path, &manifest) {
+                    Ok(extracted_map) => {
+                        for item in manifest.items.iter_mut() {
+                            if let Some(extracted_path) = extracted_map.get(&item.file_name) {
+                                item.file_path = Some(extracted_path.to_string_lossy().to_string());
                             }
                         }
-                        Err(err) => {
-                            cc_warn!(
-                                "[FolderWatcher] Failed to extract pack {} assets: {}",
-                                file_path,
-                                err
-                            );
-                        }
                     }
-
-                    let previews = pack_library::generate_pack_previews_from_archive(path).ok();
-                    Some(LibraryPackMetadata {
-                        mode: manifest.mode,
-                        archive_path: file_path.clone(),
-                        items: manifest.items,
-                        previews_version: previews
-                            .as_ref()
-                            .map(|_| pack_library::CURRENT_PREVIEW_CACHE_VERSION),
-                        previews,
-                    })
-                }
-                Err(err) => {
-                    cc_warn!(
-                        "[FolderWatcher] Failed to infer cursor pack manifest for {}: {}",
-                        file_path,
-                        err
-                    );
-                    None
+                    Err(err) => {
+                        cc_warn!(
+                            "[FolderWatcher] Failed to extract pack {} assets: {}",
+                            file_path,
+                            err
+                        );
+                    }
                 }
-            };
 
-            (true, meta, 0, 0)
-        } else {
-            let (hotspot_x, hotspot_y) = read_cursor_hotspot(&file_path).unwrap_or((0, 0));
-            (false, None, hotspot_x, hotspot_y)
+                let installed_version = manifest.latest_version();
+                let sounds = manifest.sounds.events();
+                let previews = pack_library::generate_pack_previews_from_archive(path).ok();
+                Some(LibraryPackMetadata {
+                    mode: manifest.mode,
+                    archive_path: file_path.clone(),
+                    items: manifest.items,
+                    previews_version: previews
+                        .as_ref()
+                        .map(|_| pack_library::CURRENT_PREVIEW_CACHE_VERSION),
+                    sprite: previews.as_ref().and_then(pack_sprite::build_pack_sprite),
+                    previews,
+                    rating: None,
+                    installed_version,
+                    sounds,
+                    sounds_enabled: false,
+                    update_url: None,
+                    archive_hash: None,
+                    update_etag: None,
+                    update_last_modified: None,
+                    update_available: false,
+                })
+            }
+            Err(err) => {
+                cc_warn!(
+                    "[FolderWatcher] Failed to infer cursor pack manifest for {}: {}",
+                    file_path,
+                    err
+                );
+                None
+            }
         };
 
-        let cursor = LibraryCursor {
-            id,
-            name: display_name,
-            file_path,
-            click_point_x: hotspot_x,
-            click_point_y: hotspot_y,
-            created_at: crate::utils::library_meta::now_iso8601_utc(),
-            is_pack,
-            pack_metadata,
-        };
+        (true, meta, 0, 0)
+    } else {
+        let (hotspot_x, hotspot_y) = read_cursor_hotspot(&file_path).unwrap_or((0, 0));
+        (false, None, hotspot_x, hotspot_y)
+    };
+
+    let mut cursor = LibraryCursor {
+        id,
+        name: display_name,
+        file_path,
+        click_point_x: hotspot_x,
+        click_point_y: hotspot_y,
+        created_at: crate::utils::library_meta::now_iso8601_utc(),
+        is_pack,
+        pack_metadata,
+        native_size: None,
+        scaled_variants: Vec::new(),
+        notes: None,
+        author: None,
+        source_url: None,
+        source_link: None,
+    };
+    populate_size_metadata(&mut cursor);
+    cursor
+}
 
-        library.cursors.push(cursor);
-        changed = true;
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn capped_imports_keep_order_and_limit() {
+        let running = AtomicUsize::new(0);
+        let peak = AtomicUsize::new(0);
+        let done = Mutex::new(Vec::new());
+        let items: Vec<u32> = (0..20).collect();
+
+        let results = import_capped(
+            &items,
+            3,
+            |item| {
+                let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+                peak.fetch_max(now, Ordering::SeqCst);
+                std::thread::sleep(std::time::Duration::from_millis(2));
+                running.fetch_sub(1, Ordering::SeqCst);
+                item * 2
+            },
+            |finished| done.lock().unwrap().push(finished),
+        );
+
+        assert_eq!(results, items.iter().map(|i| i * 2).collect::<Vec<_>>());
+        assert!(peak.load(Ordering::SeqCst) <= 3);
+        let mut done = done.into_inner().unwrap();
+        done.sort_unstable();
+        assert_eq!(done, (1..=20).collect::<Vec<_>>());
     }
 
-    if !files_to_remove.is_empty() {
-        library
-            .cursors
-            .retain(|c| !files_to_remove.contains(&c.file_path));
-        changed = true;
+    #[test]
+    fn import_status_counts_free_slots() {
+        assert_eq!(
+            LibraryImportStatus::new(10, 3),
+            LibraryImportStatus {
+                queued: 3,
+                in_progress: MAX_CONCURRENT_IMPORTS as u32,
+                imported: 3,
+                total: 10,
+            }
+        );
+        assert_eq!(LibraryImportStatus::new(5, 5).in_progress, 0);
     }
 
-    changed
+    #[test]
+    fn folder_diff_keeps_entries_added_during_the_import() {
+        use crate::commands::customization::library::{LibraryCursor, LibraryData};
+
+        let entry = |id: &str, path: &str| LibraryCursor {
+            id: id.to_string(),
+            name: id.to_string(),
+            file_path: path.to_string(),
+            click_point_x: 0,
+            click_point_y: 0,
+            created_at: "2025-01-01T00:00:00Z".to_string(),
+            is_pack: false,
+            pack_metadata: None,
+            native_size: None,
+            scaled_variants: Vec::new(),
+            notes: None,
+            author: None,
+            source_url: None,
+            source_link: None,
+        };
+        let mut library = LibraryData::default();
+        library.cursors.push(entry("kept", "C:/lib/kept.cur"));
+        library.cursors.push(entry("gone", "C:/lib/gone.cur"));
+        // Another writer imported this one while the sync was converting
+        library.cursors.push(entry("theirs", "C:/lib/new.cur"));
+
+        let imported = vec![
+            entry("ours", "C:/lib/new.cur"),
+            entry("other", "C:/lib/other.cur"),
+        ];
+        assert!(apply_folder_diff(
+            &mut library,
+            imported,
+            &["C:/lib/gone.cur".to_string()]
+        ));
+
+        let ids: Vec<&str> = library.cursors.iter().map(|c| c.id.as_str()).collect();
+        assert_eq!(ids, ["kept", "theirs", "other"]);
+        assert!(!apply_folder_diff(&mut library, Vec::new(), &[]));
+    }
 }
@@ -1,11 +1,110 @@
 use super::{is_cursor_file, FolderWatcherState};
 use crate::events;
 use notify::{EventKind, RecursiveMode, Watcher};
+use std::collections::BTreeSet;
 use std::path::PathBuf;
 use std::sync::{mpsc, Mutex};
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tauri::{AppHandle, Emitter};
 
+/// Quiet period that ends a batch of file events. Unzipping hundreds of cursors into the
+/// folder produces one import and one library save instead of one per file.
+const BATCH_WINDOW: Duration = Duration::from_millis(500);
+/// A batch is imported after this long even if events keep arriving.
+const MAX_BATCH_DELAY: Duration = Duration::from_secs(5);
+
+/// Paths changed since the last import. A path added then removed again (or the other way
+/// round) only counts its latest state.
+struct PendingBatch {
+    started: Instant,
+    last_event: Instant,
+    added: BTreeSet<PathBuf>,
+    removed: BTreeSet<PathBuf>,
+}
+
+impl PendingBatch {
+    fn new(now: Instant) -> Self {
+        Self {
+            started: now,
+            last_event: now,
+            added: BTreeSet::new(),
+            removed: BTreeSet::new(),
+        }
+    }
+
+    fn record(&mut self, path: PathBuf, added: bool, now: Instant) {
+        self.last_event = now;
+        if added {
+            self.removed.remove(&path);
+            self.added.insert(path);
+        } else {
+            self.added.remove(&path);
+            self.removed.insert(path);
+        }
+    }
+
+    fn flush_at(&self) -> Instant {
+        (self.last_event + BATCH_WINDOW).min(self.started + MAX_BATCH_DELAY)
+    }
+}
+
+/// Library paths touched by `event`, with whether each now exists (`true`) or is gone.
+fn library_changes(event: &notify::Event) -> Vec<(PathBuf, bool)> {
+    let mut changes = Vec::new();
+    for path in event.paths.iter() {
+        // Folders under cursor-packs may hold (or have held) packs; elsewhere only cursor
+        // files matter
+        if path.is_dir() {
+            let in_packs = crate::paths::cursor_packs_dir()
+                .map(|root| crate::paths::is_within(path, &root))
+                .unwrap_or(false);
+            if !in_packs {
+                continue;
+            }
+        } else if !is_cursor_file(path.as_path()) {
+            continue;
+        }
+
+        match &event.kind {
+            EventKind::Create(_) => changes.push((path.clone(), true)),
+            EventKind::Remove(_) => changes.push((path.clone(), false)),
+            // Renames arrive as Modify(Name) for both the old and the new path
+            EventKind::Modify(notify::event::ModifyKind::Name(_)) => {
+                changes.push((path.clone(), path.exists()))
+            }
+            _ => {}
+        }
+    }
+    changes
+}
+
+fn paths_payload(paths: &BTreeSet<PathBuf>) -> serde_json::Value {
+    let paths: Vec<String> = paths
+        .iter()
+        .map(|p| p.to_string_lossy().to_string())
+        .collect();
+    serde_json::json!({ "count": paths.len(), "paths": paths })
+}
+
+/// Import a finished batch with a single sync, then tell the frontend what changed.
+fn flush_batch(app: &AppHandle, batch: PendingBatch) {
+    cc_debug!(
+        "[FolderWatcher] Syncing batch: {} added, {} removed",
+        batch.added.len(),
+        batch.removed.len()
+    );
+    if let Err(e) = super::sync::sync_library_with_folder_inner(app) {
+        cc_warn!("[FolderWatcher] Failed to sync library batch: {}", e);
+        return;
+    }
+    if !batch.added.is_empty() {
+        let _ = app.emit(events::LIBRARY_FILE_ADDED, paths_payload(&batch.added));
+    }
+    if !batch.removed.is_empty() {
+        let _ = app.emit(events::LIBRARY_FILE_REMOVED, paths_payload(&batch.removed));
+    }
+}
+
 pub(super) fn start_watcher(
     app: AppHandle,
     state: &Mutex<FolderWatcherState>,
@@ -19,13 +118,14 @@ pub(super) fn start_watcher(
     cc_debug!("[FolderWatcher] Starting watcher for folders: {:?}", folders);
 
     let app_handle = app.clone();
-    let (event_tx, event_rx) = mpsc::channel();
-    let (stop_tx, stop_rx) = mpsc::channel::<()>();
+    // Events and the stop request share one channel so the loop can block on it
+    let (event_tx, event_rx) = mpsc::channel::<Option<notify::Event>>();
+    let stop_tx = event_tx.clone();
 
     let mut watcher =
         notify::recommended_watcher(move |res: Result<notify::Event, notify::Error>| {
             if let Ok(event) = res {
-                let _ = event_tx.send(event);
+                let _ = event_tx.send(Some(event));
             }
         })
         .map_err(|e| format!("Failed to create watcher: {}", e))?;
@@ -44,120 +144,44 @@ pub(super) fn start_watcher(
         }
     }
 
-    let join_handle = std::thread::spawn(move || loop {
-        if stop_rx.try_recv().is_ok() {
-            break;
-        }
-
-        match event_rx.recv_timeout(Duration::from_millis(200)) {
-            Ok(event) => {
-                for path in event.paths.iter() {
-                    let path_str = path.to_string_lossy().to_string();
-                    let is_dir = path.is_dir();
-                    
-                    // For directories, we need to handle them specially in cursor-packs
-                    if is_dir {
-                        if let Ok(cursor_packs_root) = crate::paths::cursor_packs_dir() {
-                            if path.starts_with(&cursor_packs_root) {
-                                // This is a directory under cursor-packs
-                                match &event.kind {
-                                    EventKind::Create(_) => {
-                                        cc_debug!("[FolderWatcher] Pack folder created: {}", path_str);
-                                        // Trigger sync to detect new packs in this folder
-                                        let _ = app_handle.emit(
-                                            events::LIBRARY_FILE_ADDED,
-                                            serde_json::json!({
-                                                "path": path_str,
-                                                "name": path.file_name().and_then(|n| n.to_str()).unwrap_or("unknown")
-                                            }),
-                                        );
-                                    }
-                                    EventKind::Remove(_) => {
-                                        cc_debug!("[FolderWatcher] Pack folder removed: {}", path_str);
-                                        // Trigger sync to remove packs that were in this folder
-                                        let _ = app_handle.emit(
-                                            events::LIBRARY_FILE_REMOVED,
-                                            serde_json::json!({
-                                                "path": path_str,
-                                                "name": path.file_name().and_then(|n| n.to_str()).unwrap_or("unknown")
-                                            }),
-                                        );
-                                    }
-                                    _ => {}
-                                }
-                            }
-                        }
-                        // Skip further processing for directories that aren't cursor files
-                        if !is_cursor_file(path.as_path()) {
-                            continue;
-                        }
-                    }
-                    
-                    if !is_cursor_file(path.as_path()) {
-                        continue;
+    let join_handle = std::thread::spawn(move || {
+        let mut batch: Option<PendingBatch> = None;
+        loop {
+            // A steady stream never goes quiet, so check the deadline before waiting again
+            if batch
+                .as_ref()
+                .is_some_and(|b| b.flush_at() <= Instant::now())
+            {
+                if let Some(batch) = batch.take() {
+                    flush_batch(&app_handle, batch);
+                }
+            }
+            let received = match &batch {
+                Some(pending) => event_rx
+                    .recv_timeout(pending.flush_at().saturating_duration_since(Instant::now())),
+                None => event_rx
+                    .recv()
+                    .map_err(|_| mpsc::RecvTimeoutError::Disconnected),
+            };
+            match received {
+                Ok(Some(event)) => {
+                    let now = Instant::now();
+                    for (path, added) in library_changes(&event) {
+                        batch
+                            .get_or_insert_with(|| PendingBatch::new(now))
+                            .record(path, added, now);
                     }
-
-                    let file_name = path
-                        .file_name()
-                        .and_then(|n| n.to_str())
-                        .unwrap_or("unknown")
-                        .to_string();
-
-                    match &event.kind {
-                        EventKind::Create(_) => {
-                            cc_debug!("[FolderWatcher] File added: {}", file_name);
-                            let _ = app_handle.emit(
-                                events::LIBRARY_FILE_ADDED,
-                                serde_json::json!({
-                                    "path": path_str,
-                                    "name": file_name
-                                }),
-                            );
-                        }
-                        EventKind::Remove(_) => {
-                            cc_debug!("[FolderWatcher] File removed: {}", file_name);
-                            let _ = app_handle.emit(
-                                events::LIBRARY_FILE_REMOVED,
-                                serde_json::json!({
-                                    "path": path_str,
-                                    "name": file_name
-                                }),
-                            );
-                        }
-                        // Handle file renames - notify crate reports rename as Modify with RenameMode
-                        // When a file is renamed, we get events for both old and new paths
-                        // Triggering both events ensures the sync process updates the library correctly
-                        EventKind::Modify(notify::event::ModifyKind::Name(_)) => {
-                            cc_debug!("[FolderWatcher] File renamed: {}", file_name);
-                            // Check if the file exists at this path
-                            if path.exists() {
-                                // New file path after rename - emit file added
-                                let _ = app_handle.emit(
-                                    events::LIBRARY_FILE_ADDED,
-                                    serde_json::json!({
-                                        "path": path_str,
-                                        "name": file_name
-                                    }),
-                                );
-                            } else {
-                                // Old file path before rename - emit file removed
-                                let _ = app_handle.emit(
-                                    events::LIBRARY_FILE_REMOVED,
-                                    serde_json::json!({
-                                        "path": path_str,
-                                        "name": file_name
-                                    }),
-                                );
-                            }
-                        }
-                        _ => {}
+                }
+                Ok(None) => break,
+                Err(mpsc::RecvTimeoutError::Timeout) => {
+                    if let Some(batch) = batch.take() {
+                        flush_batch(&app_handle, batch);
                     }
                 }
-            }
-            Err(mpsc::RecvTimeoutError::Timeout) => {}
-            Err(mpsc::RecvTimeoutError::Disconnected) => {
-                cc_debug!("[FolderWatcher] Channel disconnected, stopping watch loop");
-                break;
+                Err(mpsc::RecvTimeoutError::Disconnected) => {
+                    cc_debug!("[FolderWatcher] Channel disconnected, stopping watch loop");
+                    break;
+                }
             }
         }
     });
@@ -191,7 +215,7 @@ pub(super) fn stop_watcher(state: &Mutex<FolderWatcherState>) -> Result<(), Stri
     };
 
     if let Some(stop_tx) = stop_tx {
-        let _ = stop_tx.send(());
+        let _ = stop_tx.send(None);
     }
 
     if let Some(join_handle) = join_handle {
@@ -201,3 +225,42 @@ pub(super) fn stop_watcher(state: &Mutex<FolderWatcherState>) -> Result<(), Stri
     cc_debug!("[FolderWatcher] Watcher stopped");
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn batch_waits_for_a_quiet_period() {
+        let start = Instant::now();
+        let mut batch = PendingBatch::new(start);
+        batch.record(PathBuf::from("a.cur"), true, start);
+        let later = start + Duration::from_millis(300);
+        batch.record(PathBuf::from("b.cur"), true, later);
+
+        assert_eq!(batch.flush_at(), later + BATCH_WINDOW);
+        assert_eq!(batch.added.len(), 2);
+    }
+
+    #[test]
+    fn steady_events_still_flush_after_the_max_delay() {
+        let start = Instant::now();
+        let mut batch = PendingBatch::new(start);
+        batch.record(PathBuf::from("a.cur"), true, start + MAX_BATCH_DELAY);
+
+        assert_eq!(batch.flush_at(), start + MAX_BATCH_DELAY);
+    }
+
+    #[test]
+    fn latest_change_to_a_path_wins() {
+        let now = Instant::now();
+        let mut batch = PendingBatch::new(now);
+        batch.record(PathBuf::from("a.cur"), true, now);
+        batch.record(PathBuf::from("a.cur"), false, now);
+        batch.record(PathBuf::from("b.cur"), false, now);
+        batch.record(PathBuf::from("b.cur"), true, now);
+
+        assert_eq!(batch.removed, BTreeSet::from([PathBuf::from("a.cur")]));
+        assert_eq!(batch.added, BTreeSet::from([PathBuf::from("b.cur")]));
+    }
+}
@@ -0,0 +1,22 @@
+/// Localhost health endpoint for kiosk monitoring (see `crate::health_endpoint`).
+use crate::health_endpoint::{self, HealthEndpointSettings};
+use tauri::AppHandle;
+
+#[tauri::command]
+pub fn get_health_endpoint(app: AppHandle) -> HealthEndpointSettings {
+    health_endpoint::load_settings(&app)
+}
+
+/// Enable, disable or move the health endpoint. The listener restarts straight away.
+#[tauri::command]
+pub fn set_health_endpoint(
+    app: AppHandle,
+    enabled: bool,
+    port: Option<u16>,
+) -> Result<HealthEndpointSettings, String> {
+    let port = port.unwrap_or_else(|| health_endpoint::load_settings(&app).port);
+    let settings = HealthEndpointSettings { enabled, port };
+    health_endpoint::save_settings(&app, &settings)?;
+    health_endpoint::restart(&app)?;
+    Ok(settings)
+}
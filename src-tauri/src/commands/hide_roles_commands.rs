@@ -0,0 +1,23 @@
+/// Cursor roles that stay visible while the cursor is hidden (see `crate::hide_roles`).
+use crate::hide_roles::{self, HideRolesSettings};
+use tauri::AppHandle;
+
+#[tauri::command]
+pub fn get_hide_roles(app: AppHandle) -> HideRolesSettings {
+    hide_roles::load_settings(&app)
+}
+
+/// Choose which roles hiding leaves visible, or hide every role with `strict`.
+#[tauri::command]
+pub fn set_hide_roles(
+    app: AppHandle,
+    strict: bool,
+    visible_roles: Vec<String>,
+) -> Result<HideRolesSettings, String> {
+    let settings = HideRolesSettings {
+        strict,
+        visible_roles,
+    };
+    hide_roles::save_settings(&app, &settings)?;
+    Ok(settings)
+}
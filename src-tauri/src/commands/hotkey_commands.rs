@@ -1,6 +1,12 @@
 use crate::commands::cursor_commands::show_cursor_if_hidden_with_shared_state;
 use crate::events;
+use crate::hot_corners::{self, HotCornerSettings};
+use crate::hotkey_chords::{self, HotkeyChordSettings};
+use crate::hotkey_press::{self, HotkeyPressSettings};
+use crate::monitor_schemes::{self, MonitorSchemeSettings};
+use crate::pointer_control::{self, PointerControlSettings};
 use crate::shortcuts::{self, DEFAULT_SHORTCUT};
+use crate::size_gesture::{self, SizeGestureSettings, MAX_STEP_PX};
 use crate::state::config::{persist_config, PersistedConfig};
 use crate::state::{AppState, CursorStatePayload};
 use tauri::{AppHandle, Emitter, State};
@@ -96,6 +102,148 @@ pub fn set_hotkey_temporarily_enabled(
     Ok(())
 }
 
+#[tauri::command]
+pub fn get_hotkey_chord_settings(app: AppHandle) -> HotkeyChordSettings {
+    hotkey_chords::load_settings(&app)
+}
+
+/// Save chord settings and re-register the prefix shortcut.
+#[tauri::command]
+pub fn set_hotkey_chord_settings(
+    app: AppHandle,
+    state: State<AppState>,
+    settings: HotkeyChordSettings,
+) -> Result<HotkeyChordSettings, String> {
+    let prefix = settings.prefix.trim().to_string();
+    if prefix.is_empty() {
+        return Err("Chord prefix cannot be empty".into());
+    }
+    prefix
+        .parse::<tauri_plugin_global_shortcut::Shortcut>()
+        .map_err(|e| format!("{:?}", e))?;
+
+    let (main_shortcut, shortcut_enabled) = {
+        let prefs = state
+            .prefs
+            .read()
+            .map_err(|_| "Application state poisoned".to_string())?;
+        (prefs.shortcut.clone(), prefs.shortcut_enabled)
+    };
+    if settings.enabled
+        && main_shortcut
+            .as_deref()
+            .is_some_and(|s| s.trim().eq_ignore_ascii_case(&prefix))
+    {
+        return Err("Chord prefix must differ from the hide/show shortcut".into());
+    }
+
+    let previous = hotkey_chords::load_settings(&app);
+    let settings = HotkeyChordSettings {
+        prefix,
+        timeout_ms: settings.timeout_ms.clamp(500, 10_000),
+        ..settings
+    };
+    hotkey_chords::save_settings(&app, &settings)?;
+
+    hotkey_chords::unregister_prefix(&app, &previous.prefix);
+    if shortcut_enabled {
+        hotkey_chords::register_prefix(&app)?;
+    }
+    Ok(settings)
+}
+
+#[tauri::command]
+pub fn get_hotkey_press_settings(app: AppHandle) -> HotkeyPressSettings {
+    hotkey_press::load_settings(&app)
+}
+
+/// Save the single/double press actions. Takes effect on the next press.
+#[tauri::command]
+pub fn set_hotkey_press_settings(
+    app: AppHandle,
+    settings: HotkeyPressSettings,
+) -> Result<HotkeyPressSettings, String> {
+    let previous = hotkey_press::load_settings(&app);
+    let settings = HotkeyPressSettings {
+        double_press_window_ms: settings.double_press_window_ms.clamp(
+            hotkey_press::MIN_DOUBLE_PRESS_WINDOW_MS,
+            hotkey_press::MAX_DOUBLE_PRESS_WINDOW_MS,
+        ),
+        // Recorded by pack applies, not by the settings UI
+        last_pack_id: previous.last_pack_id,
+        ..settings
+    };
+    hotkey_press::save_settings(&app, &settings)?;
+    Ok(settings)
+}
+
+#[tauri::command]
+pub fn get_size_gesture_settings(app: AppHandle) -> SizeGestureSettings {
+    size_gesture::load_settings(&app)
+}
+
+/// Save the wheel size gesture settings and restart the mouse hook to match.
+#[tauri::command]
+pub fn set_size_gesture_settings(
+    app: AppHandle,
+    settings: SizeGestureSettings,
+) -> Result<SizeGestureSettings, String> {
+    if settings.step_px == 0 || settings.step_px > MAX_STEP_PX {
+        return Err(format!("Step must be between 1 and {} pixels", MAX_STEP_PX));
+    }
+    size_gesture::save_settings(&app, &settings)?;
+    size_gesture::install(&app)?;
+    Ok(settings)
+}
+
+#[tauri::command]
+pub fn get_pointer_control_settings(app: AppHandle) -> PointerControlSettings {
+    pointer_control::load_settings(&app)
+}
+
+/// Save the keyboard pointer control settings and restart the keyboard hook to match.
+#[tauri::command]
+pub fn set_pointer_control_settings(
+    app: AppHandle,
+    settings: PointerControlSettings,
+) -> Result<PointerControlSettings, String> {
+    pointer_control::save_settings(&app, &settings)?;
+    pointer_control::install(&app)?;
+    Ok(settings)
+}
+
+#[tauri::command]
+pub fn get_hot_corner_settings(app: AppHandle) -> HotCornerSettings {
+    hot_corners::load_settings(&app)
+}
+
+/// Save the hot corner settings and restart the corner poller to match.
+#[tauri::command]
+pub fn set_hot_corner_settings(
+    app: AppHandle,
+    settings: HotCornerSettings,
+) -> Result<HotCornerSettings, String> {
+    hot_corners::save_settings(&app, &settings)?;
+    hot_corners::install(&app)?;
+    Ok(settings)
+}
+
+#[tauri::command]
+pub fn get_monitor_scheme_settings(app: AppHandle) -> MonitorSchemeSettings {
+    monitor_schemes::load_settings(&app)
+}
+
+/// Save the per-monitor scheme settings and restart the cursor overlay to match.
+#[tauri::command]
+pub fn set_monitor_scheme_settings(
+    app: AppHandle,
+    settings: MonitorSchemeSettings,
+) -> Result<MonitorSchemeSettings, String> {
+    monitor_schemes::save_settings(&app, &settings)?;
+    monitor_schemes::install(&app)?;
+    Ok(settings)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,16 @@
+/// Log file export and cleanup (see `crate::log_files`).
+use crate::log_files;
+use std::path::PathBuf;
+
+/// Write the log lines since `since` (RFC 3339, all when omitted) to `path`, for attaching
+/// to an issue. Returns how many lines were written.
+#[tauri::command]
+pub fn export_logs(path: String, since: Option<String>) -> Result<usize, String> {
+    log_files::export(&PathBuf::from(path), since.as_deref())
+}
+
+/// Delete all log files.
+#[tauri::command]
+pub fn clear_logs() -> Result<(), String> {
+    log_files::clear()
+}
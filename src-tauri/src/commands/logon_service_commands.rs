@@ -0,0 +1,118 @@
+/// Install/uninstall the optional logon service that applies the cursor scheme before the
+/// tray app starts. The service ships as a separate binary (`cursor_changer_service`, built
+/// with `--features logon-service`) next to the main executable.
+use crate::logon_scheme::{logon_scheme_path, save_logon_scheme, LogonScheme};
+use crate::state::AppState;
+use serde::Serialize;
+use std::path::PathBuf;
+use std::process::Command;
+use tauri::State;
+
+pub const LOGON_SERVICE_NAME: &str = "CursorChangerLogon";
+const SERVICE_EXE_NAME: &str = "cursor_changer_service.exe";
+
+#[derive(ts_rs::TS, Serialize, Clone, Debug, PartialEq, Eq)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct LogonServiceStatus {
+    /// The service binary is present next to the app executable
+    pub available: bool,
+    pub installed: bool,
+}
+
+fn service_exe_path() -> Result<PathBuf, String> {
+    let exe = std::env::current_exe().map_err(|e| e.to_string())?;
+    let dir = exe
+        .parent()
+        .ok_or_else(|| "Failed to resolve application directory".to_string())?;
+    Ok(dir.join(SERVICE_EXE_NAME))
+}
+
+fn is_service_installed() -> bool {
+    Command::new("sc")
+        .args(["query", LOGON_SERVICE_NAME])
+        .output()
+        .map(|output| output.status.success())
+        .unwrap_or(false)
+}
+
+/// Run the service binary elevated (installing a service requires admin rights).
+fn run_service_exe_elevated(exe: &PathBuf, action: &str) -> Result<(), String> {
+    let script = format!(
+        "$p = Start-Process -FilePath '{}' -ArgumentList '{}' -Verb RunAs -Wait -PassThru; exit $p.ExitCode",
+        exe.display().to_string().replace('\'', "''"),
+        action
+    );
+    let status = Command::new("powershell")
+        .args(["-NoProfile", "-NonInteractive", "-Command", &script])
+        .status()
+        .map_err(|e| format!("Failed to launch service installer: {}", e))?;
+
+    if status.success() {
+        Ok(())
+    } else {
+        Err(format!(
+            "Logon service {} failed (exit code {:?})",
+            action,
+            status.code()
+        ))
+    }
+}
+
+#[tauri::command]
+pub fn get_logon_service_status() -> Result<LogonServiceStatus, String> {
+    let available = service_exe_path().map(|p| p.exists()).unwrap_or(false);
+    Ok(LogonServiceStatus {
+        available,
+        installed: available && is_service_installed(),
+    })
+}
+
+#[tauri::command]
+pub fn install_logon_service(state: State<AppState>) -> Result<LogonServiceStatus, String> {
+    let exe = service_exe_path()?;
+    if !exe.exists() {
+        return Err("The logon service is not included in this build".to_string());
+    }
+
+    // Seed the scheme file first; its presence also turns on mirroring from app state.
+    let scheme = {
+        let guard = state.read_all()?;
+        LogonScheme {
+            cursor_size: guard.prefs.cursor_size,
+            cursor_paths: guard.cursor.cursor_paths.clone(),
+        }
+    };
+    save_logon_scheme(&scheme)?;
+
+    if let Err(e) = run_service_exe_elevated(&exe, "install") {
+        if let Ok(path) = logon_scheme_path() {
+            let _ = std::fs::remove_file(path);
+        }
+        return Err(e);
+    }
+    cc_info!("[CursorChanger] Installed logon service");
+    get_logon_service_status()
+}
+
+#[tauri::command]
+pub fn uninstall_logon_service() -> Result<LogonServiceStatus, String> {
+    let exe = service_exe_path()?;
+    if exe.exists() && is_service_installed() {
+        run_service_exe_elevated(&exe, "uninstall")?;
+    }
+
+    if let Ok(path) = logon_scheme_path() {
+        if path.exists() {
+            if let Err(e) = std::fs::remove_file(&path) {
+                cc_warn!(
+                    "[CursorChanger] Failed to remove logon scheme {}: {}",
+                    path.display(),
+                    e
+                );
+            }
+        }
+    }
+
+    cc_info!("[CursorChanger] Uninstalled logon service");
+    get_logon_service_status()
+}
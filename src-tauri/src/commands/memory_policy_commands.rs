@@ -0,0 +1,20 @@
+/// Working-set trimming after heavy work (see `crate::memory_policy`).
+use crate::memory_policy::{self, MemoryPolicy, MemoryPolicySettings};
+use tauri::AppHandle;
+
+#[tauri::command]
+pub fn get_memory_policy(app: AppHandle) -> MemoryPolicySettings {
+    memory_policy::load_settings(&app)
+}
+
+/// Choose how eagerly the app gives memory back after pack extraction, conversion and other
+/// heavy operations.
+#[tauri::command]
+pub fn set_memory_policy(
+    app: AppHandle,
+    policy: MemoryPolicy,
+) -> Result<MemoryPolicySettings, String> {
+    let settings = MemoryPolicySettings { policy };
+    memory_policy::save_settings(&app, &settings)?;
+    Ok(settings)
+}
@@ -1,14 +1,33 @@
+pub mod benchmark_commands;
+pub mod cache_commands;
+pub mod crash_report_commands;
 pub mod cursor_commands;
 pub mod customization; // Refactored from customization_commands
+pub mod diagnostics_commands;
 pub mod effects_commands;
+pub mod error_commands;
 pub mod file_commands;
 pub mod folder_watcher;
+pub mod log_commands;
+#[cfg(not(test))]
+pub mod logon_service_commands;
 #[cfg(not(test))]
 pub mod hotkey_commands;
 pub mod mode_commands;
+pub mod operation_commands;
+pub mod screen_color_commands;
 #[cfg(not(test))]
 pub mod settings_commands;
 pub mod shutdown;
+pub mod status_file_commands;
+pub mod storage_commands;
+pub mod health_endpoint_commands;
+pub mod decode_sandbox_commands;
+pub mod hide_roles_commands;
+pub mod memory_policy_commands;
+pub mod conversion_output_commands;
+pub mod usage_stats_commands;
+pub mod uninstall_cleanup_commands;
 #[cfg(not(test))]
 pub mod theme_commands;
 #[cfg(not(test))]
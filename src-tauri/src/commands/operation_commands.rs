@@ -0,0 +1,49 @@
+/// Cancellation for long-running commands (see `crate::operations`) and resuming jobs cut off
+/// by an exit (see `crate::pending_jobs`).
+use crate::commands::customization::pack_commands::apply_cursor_pack;
+use crate::commands::customization::pack_import_url::import_pack_from_url;
+use crate::operations::CancellationRegistry;
+use crate::pending_jobs::{self, PendingJob, PendingJobKind};
+use crate::state::AppState;
+use tauri::{AppHandle, Manager, State};
+
+/// Request cancellation of a running operation. Returns false if it already finished.
+#[tauri::command]
+pub fn cancel_operation(registry: State<'_, CancellationRegistry>, op_id: String) -> bool {
+    registry.cancel(&op_id)
+}
+
+/// Apply and import jobs the last exit interrupted, oldest first.
+#[tauri::command]
+pub fn get_pending_jobs() -> Vec<PendingJob> {
+    pending_jobs::interrupted()
+}
+
+/// Run an interrupted job again. Progress is reported with the job ID as `op_id`. A job that
+/// fails again stays in the list.
+#[tauri::command]
+pub async fn resume_pending_job(app: AppHandle, id: String) -> Result<(), String> {
+    let job = pending_jobs::take(&id).ok_or_else(|| format!("Pending job {} not found", id))?;
+    tauri::async_runtime::spawn_blocking(move || {
+        let result = match job.kind {
+            PendingJobKind::ImportPackFromUrl => {
+                import_pack_from_url(&app, &job.target, Some(job.id.clone())).map(|_| ())
+            }
+            PendingJobKind::ApplyPack => {
+                apply_cursor_pack(app.clone(), app.state::<AppState>(), job.target.clone())
+            }
+        };
+        if result.is_err() {
+            pending_jobs::restore(job);
+        }
+        result
+    })
+    .await
+    .map_err(|e| format!("Task join error: {}", e))?
+}
+
+/// Forget every interrupted job. Returns how many were discarded.
+#[tauri::command]
+pub fn discard_pending_jobs() -> usize {
+    pending_jobs::discard_interrupted()
+}
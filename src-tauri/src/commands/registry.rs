@@ -3,16 +3,48 @@ pub fn register(builder: tauri::Builder<tauri::Wry>) -> tauri::Builder<tauri::Wr
         crate::commands::cursor_commands::get_status,
         crate::commands::cursor_commands::toggle_cursor,
         crate::commands::cursor_commands::restore_cursor,
+        crate::commands::cursor_commands::restore_cursor_role,
+        crate::commands::cursor_commands::force_cursor_refresh,
+        crate::commands::cursor_commands::get_cursor_cache_flush_enabled,
+        crate::commands::cursor_commands::set_cursor_cache_flush_enabled,
         crate::commands::hotkey_commands::set_hotkey,
+        crate::commands::hotkey_commands::get_hotkey_chord_settings,
+        crate::commands::hotkey_commands::set_hotkey_chord_settings,
+        crate::commands::hotkey_commands::get_hotkey_press_settings,
+        crate::commands::hotkey_commands::set_hotkey_press_settings,
+        crate::commands::hotkey_commands::get_size_gesture_settings,
+        crate::commands::hotkey_commands::set_size_gesture_settings,
+        crate::commands::hotkey_commands::get_pointer_control_settings,
+        crate::commands::hotkey_commands::set_pointer_control_settings,
+        crate::commands::hotkey_commands::get_hot_corner_settings,
+        crate::commands::hotkey_commands::set_hot_corner_settings,
+        crate::commands::hotkey_commands::get_monitor_scheme_settings,
+        crate::commands::hotkey_commands::set_monitor_scheme_settings,
         crate::commands::hotkey_commands::set_hotkey_temporarily_enabled,
         crate::commands::hotkey_commands::set_shortcut_enabled,
         crate::commands::settings_commands::set_minimize_to_tray,
         crate::commands::settings_commands::set_run_on_startup,
         crate::commands::settings_commands::set_accent_color,
+        crate::commands::settings_commands::get_svg_resource_settings,
+        crate::commands::settings_commands::set_svg_load_system_fonts,
+        crate::commands::settings_commands::get_cursor_variant_settings,
+        crate::commands::settings_commands::set_cursor_variant_pregeneration,
+        crate::commands::settings_commands::get_adaptive_brightness_settings,
+        crate::commands::settings_commands::set_adaptive_brightness,
+        crate::commands::settings_commands::take_config_validation_report,
         crate::commands::settings_commands::reset_all_settings,
         crate::commands::window_commands::reset_window_size_to_default,
+        crate::commands::window_commands::get_last_settings_page,
+        crate::commands::window_commands::set_last_settings_page,
         crate::commands::settings_commands::set_default_cursor_style,
+        crate::commands::logon_service_commands::get_logon_service_status,
+        crate::commands::logon_service_commands::install_logon_service,
+        crate::commands::logon_service_commands::uninstall_logon_service,
         crate::commands::window_commands::quit_app,
+        crate::commands::operation_commands::cancel_operation,
+        crate::commands::operation_commands::get_pending_jobs,
+        crate::commands::operation_commands::resume_pending_job,
+        crate::commands::operation_commands::discard_pending_jobs,
         crate::commands::theme_commands::set_theme_mode,
         crate::commands::theme_commands::get_theme_mode,
         crate::commands::mode_commands::switch_customization_mode,
@@ -23,8 +55,46 @@ pub fn register(builder: tauri::Builder<tauri::Wry>) -> tauri::Builder<tauri::Wr
         crate::commands::file_commands::get_library_cursors_folder,
         crate::commands::file_commands::show_library_cursors_folder,
         crate::commands::file_commands::read_file_content,
+        crate::commands::cache_commands::run_cache_gc,
+        crate::commands::cache_commands::get_cache_quota_mb,
+        crate::commands::cache_commands::set_cache_quota_mb,
+        crate::commands::status_file_commands::get_status_file,
+        crate::commands::status_file_commands::set_status_file,
+        crate::commands::health_endpoint_commands::get_health_endpoint,
+        crate::commands::health_endpoint_commands::set_health_endpoint,
+        crate::commands::decode_sandbox_commands::get_decode_sandbox,
+        crate::commands::decode_sandbox_commands::set_decode_sandbox,
+        crate::commands::hide_roles_commands::get_hide_roles,
+        crate::commands::hide_roles_commands::set_hide_roles,
+        crate::commands::memory_policy_commands::get_memory_policy,
+        crate::commands::memory_policy_commands::set_memory_policy,
+        crate::commands::conversion_output_commands::get_conversion_output_settings,
+        crate::commands::conversion_output_commands::set_conversion_output_settings,
+        crate::commands::storage_commands::get_cursors_storage_dir,
+        crate::commands::storage_commands::set_cursors_storage_dir,
+        crate::commands::screen_color_commands::pick_screen_color,
+        crate::commands::crash_report_commands::get_crash_report_settings,
+        crate::commands::crash_report_commands::set_crash_reporting_enabled,
+        crate::commands::crash_report_commands::get_pending_crash_reports,
+        crate::commands::crash_report_commands::show_crash_reports_folder,
+        crate::commands::crash_report_commands::dismiss_crash_reports,
+        crate::commands::log_commands::export_logs,
+        crate::commands::log_commands::clear_logs,
+        crate::commands::error_commands::localize_error,
+        crate::commands::usage_stats_commands::get_usage_stats,
+        crate::commands::usage_stats_commands::set_usage_stats_enabled,
+        crate::commands::usage_stats_commands::purge_usage_stats,
+        crate::commands::uninstall_cleanup_commands::preview_uninstall_cleanup,
+        crate::commands::uninstall_cleanup_commands::run_uninstall_cleanup,
+        crate::commands::diagnostics_commands::export_diagnostics_bundle,
+        crate::commands::benchmark_commands::run_benchmarks,
         crate::commands::effects_commands::save_effects_config,
         crate::commands::effects_commands::load_effects_config,
+        crate::commands::effects_commands::get_reduced_motion_status,
+        crate::commands::effects_commands::set_reduced_motion_follow_system,
+        crate::commands::effects_commands::get_effect_quality,
+        crate::commands::effects_commands::set_effect_quality,
+        crate::commands::effects_commands::report_effect_frame_times,
         crate::commands::folder_watcher::start_library_folder_watcher,
         crate::commands::folder_watcher::stop_library_folder_watcher,
         crate::commands::folder_watcher::sync_library_with_folder,
@@ -32,21 +102,35 @@ pub fn register(builder: tauri::Builder<tauri::Wry>) -> tauri::Builder<tauri::Wr
         crate::commands::customization::query::get_custom_cursors,
         crate::commands::customization::query::get_cursor_image,
         crate::commands::customization::query::get_system_cursor_preview,
+        crate::commands::customization::query::get_default_cursor_previews,
+        crate::commands::customization::query::get_applied_cursor_previews,
         crate::commands::customization::file_ops::browsing::browse_cursor_file,
         crate::commands::customization::file_ops::preview::get_cursor_with_click_point,
         crate::commands::customization::file_ops::preview::render_cursor_image_preview,
+        crate::commands::customization::file_ops::preview_session::begin_preview_session,
+        crate::commands::customization::file_ops::preview_session::update_preview,
+        crate::commands::customization::file_ops::preview_session::end_preview_session,
         crate::commands::customization::file_ops::reading::read_cursor_file_as_data_url,
         crate::commands::customization::file_ops::reading::read_cursor_file_as_bytes,
         crate::commands::customization::file_ops::reading::convert_bytes_to_data_url,
+        crate::commands::customization::file_ops::conversion::convert_directory_to_cur,
+        crate::commands::customization::file_ops::conversion::convert_images_to_ani,
         crate::commands::customization::file_ops::conversion::convert_image_to_cur_with_click_point,
         crate::commands::customization::file_ops::library_integration::add_uploaded_cursor_to_library,
+        crate::commands::customization::file_ops::library_integration::add_uploaded_cursors_to_library,
         crate::commands::customization::file_ops::library_integration::add_uploaded_image_with_click_point_to_library,
         crate::commands::customization::file_ops::hotspot_update::update_library_cursor_click_point,
+        crate::commands::customization::file_ops::hotspot_calibration::start_hotspot_calibration,
+        crate::commands::customization::file_ops::hotspot_calibration::record_calibration_click,
+        crate::commands::customization::file_ops::hotspot_calibration::finish_hotspot_calibration,
+        crate::commands::customization::file_ops::hotspot_calibration::cancel_hotspot_calibration,
         crate::commands::customization::set_cursor_core::set_cursor_image,
         crate::commands::customization::set_cursor_bulk::set_all_cursors,
         crate::commands::customization::set_cursor_bulk::set_all_cursors_with_size,
         crate::commands::customization::set_cursor_bulk::set_single_cursor_with_size,
         crate::commands::customization::set_cursor_bulk::set_multiple_cursors_with_size,
+        crate::commands::customization::role_groups::get_role_groups,
+        crate::commands::customization::role_groups::apply_cursor_to_group,
         crate::commands::customization::set_cursor_size::set_cursor_size,
         crate::commands::customization::defaults::set_cursors_to_windows_defaults,
         crate::commands::customization::defaults::load_app_default_cursors,
@@ -55,19 +139,52 @@ pub fn register(builder: tauri::Builder<tauri::Wry>) -> tauri::Builder<tauri::Wr
         crate::commands::customization::defaults::delete_custom_cursor,
         crate::commands::customization::pack_export::export_active_cursor_pack,
         crate::commands::customization::pack_commands::import_cursor_pack,
+        crate::commands::customization::pack_import_url::import_cursor_pack_from_url,
+        crate::commands::customization::pack_updates::check_pack_updates,
+        crate::commands::customization::pack_updates::update_all_packs,
+        crate::commands::customization::pack_cursorfx::import_cursorfx_theme,
+        crate::commands::customization::pack_cape::import_cape,
+        crate::commands::customization::migration::detect_migration_sources,
+        crate::commands::customization::migration::run_migration,
         crate::commands::customization::pack_commands::apply_cursor_pack,
+        crate::commands::customization::pack_trial::start_pack_trial,
+        crate::commands::customization::pack_trial::commit_pack_trial,
+        crate::commands::customization::pack_trial::cancel_pack_trial,
+        crate::commands::customization::pack_schedule::schedule_pack_apply,
+        crate::commands::customization::pack_schedule::get_scheduled_pack_applies,
+        crate::commands::customization::pack_schedule::cancel_scheduled_pack_apply,
         crate::commands::customization::pack_commands::get_cursor_pack_manifest,
+        crate::commands::customization::pack_commands::get_pack_details,
+        crate::commands::customization::pack_rating::rate_pack,
+        crate::commands::customization::pack_rating::set_pack_notes,
+        crate::commands::customization::pack_sounds::set_pack_sounds_enabled,
+        crate::commands::customization::pack_sounds::preview_pack_sound,
+        crate::commands::customization::pack_integrity::verify_pack_integrity,
         crate::commands::customization::pack_commands::get_cached_pack_previews,
         crate::commands::customization::pack_commands::get_cursor_pack_file_previews,
+        crate::commands::customization::pack_commands::get_pack_item_preview,
         crate::commands::customization::library::get_library_cursors,
+        crate::commands::customization::library::get_library_page,
         crate::commands::customization::library::reorder_library_cursors,
+        crate::commands::customization::library::get_library_collections,
+        crate::commands::customization::library::create_collection,
+        crate::commands::customization::library::move_cursor_to_collection,
+        crate::commands::customization::library::reorder_collection_cursors,
         crate::commands::customization::library::export_library_cursors,
         crate::commands::customization::library::add_cursor_to_library,
         crate::commands::customization::library::update_cursor_in_library,
         crate::commands::customization::library::remove_cursor_from_library,
         crate::commands::customization::library::rename_cursor_in_library,
+        crate::commands::customization::library::update_cursor_metadata,
+        crate::commands::customization::library::set_cursor_source_link,
+        crate::commands::customization::library::set_cursor_hot_reload,
+        crate::commands::customization::library::render_desktop_context_preview,
         crate::commands::customization::library::get_library_cursor_preview,
         crate::commands::customization::library::get_ani_preview_data,
+        crate::commands::customization::library::export_ani_as_gif,
+        crate::commands::customization::library::export_ani_as_apng,
+        crate::commands::customization::library::downscale_library_ani,
         crate::commands::customization::library::reset_library,
+        crate::commands::customization::library::repair_default_library,
     ])
 }
@@ -0,0 +1,44 @@
+/// Eyedropper for the accent colour and recolour pickers.
+use serde::Serialize;
+
+#[derive(ts_rs::TS, Debug, Clone, PartialEq, Eq, Serialize)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct PickedScreenColor {
+    /// "#rrggbb", the format used for `accent_color`
+    pub hex: String,
+    pub red: u8,
+    pub green: u8,
+    pub blue: u8,
+    /// Screen position the colour was sampled at
+    pub x: i32,
+    pub y: i32,
+}
+
+fn to_hex(red: u8, green: u8, blue: u8) -> String {
+    format!("#{:02x}{:02x}{:02x}", red, green, blue)
+}
+
+/// Sample the colour of the screen pixel under the mouse cursor.
+#[tauri::command]
+pub fn pick_screen_color() -> Result<PickedScreenColor, String> {
+    let color = cursor_changer::pick_screen_color()?;
+    Ok(PickedScreenColor {
+        hex: to_hex(color.red, color.green, color.blue),
+        red: color.red,
+        green: color.green,
+        blue: color.blue,
+        x: color.x,
+        y: color.y,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hex_matches_accent_color_format() {
+        assert_eq!(to_hex(0x7c, 0x3a, 0xed), "#7c3aed");
+        assert_eq!(to_hex(0, 0, 0), "#000000");
+    }
+}
@@ -1,7 +1,11 @@
 use crate::commands::command_helpers;
+use crate::commands::customization::library::{self, CursorVariantSettings};
+use crate::cursor_brightness::{self, AdaptiveBrightnessSettings, AdaptiveBrightnessSource};
 use crate::events;
 use crate::startup;
+use crate::svg_resources::{self, SvgResourceSettings};
 use crate::state::app_state::{ModeCustomizationState, PreferencesState};
+use crate::state::config::ConfigValidationReport;
 use crate::state::{AppState, CursorStatePayload, DefaultCursorStyle, MinimizePreference};
 use std::sync::atomic::Ordering;
 use tauri::{AppHandle, Emitter, State};
@@ -89,6 +93,8 @@ pub fn set_default_cursor_style(
     style: DefaultCursorStyle,
 ) -> Result<CursorStatePayload, String> {
     command_helpers::update_state_and_emit(&app, &state, true, |guard| {
+        guard.policy.check_cursor_style()?;
+
         let old_style = guard.prefs.default_cursor_style;
         cc_debug!(
             "[CursorChanger] set_default_cursor_style called with style={} (was {})",
@@ -110,6 +116,76 @@ pub fn set_default_cursor_style(
     })
 }
 
+#[tauri::command]
+pub fn get_svg_resource_settings(app: AppHandle) -> Result<SvgResourceSettings, String> {
+    Ok(svg_resources::load_settings(&app))
+}
+
+/// Allow or block installed fonts for SVG text. Takes effect for the next conversion.
+#[tauri::command]
+pub fn set_svg_load_system_fonts(
+    app: AppHandle,
+    enabled: bool,
+) -> Result<SvgResourceSettings, String> {
+    let settings = SvgResourceSettings {
+        load_system_fonts: enabled,
+    };
+    svg_resources::save_settings(&app, &settings)?;
+    svg_resources::apply(&settings);
+    Ok(settings)
+}
+
+#[tauri::command]
+pub fn get_cursor_variant_settings(app: AppHandle) -> CursorVariantSettings {
+    library::load_variant_settings(&app)
+}
+
+/// Turn pre-scaled cursor copies on or off. Imports and applies from now on follow the setting.
+#[tauri::command]
+pub fn set_cursor_variant_pregeneration(
+    app: AppHandle,
+    enabled: bool,
+) -> Result<CursorVariantSettings, String> {
+    let settings = CursorVariantSettings {
+        pregenerate: enabled,
+    };
+    library::save_variant_settings(&app, &settings)?;
+    Ok(settings)
+}
+
+#[tauri::command]
+pub fn get_adaptive_brightness_settings(app: AppHandle) -> AdaptiveBrightnessSettings {
+    cursor_brightness::load_settings(&app)
+}
+
+/// Choose how applied cursors adapt their brightness. Takes effect on the next apply.
+#[tauri::command]
+pub fn set_adaptive_brightness(
+    app: AppHandle,
+    source: AdaptiveBrightnessSource,
+    strength_percent: u8,
+) -> Result<AdaptiveBrightnessSettings, String> {
+    if strength_percent > cursor_brightness::MAX_STRENGTH_PERCENT {
+        return Err(format!(
+            "Strength must be at most {}%",
+            cursor_brightness::MAX_STRENGTH_PERCENT
+        ));
+    }
+    let settings = AdaptiveBrightnessSettings {
+        source,
+        strength_percent,
+    };
+    cursor_brightness::save_settings(&app, &settings)?;
+    cursor_brightness::clear_variants();
+    Ok(settings)
+}
+
+/// Problems found in `config.json` at startup, returned once so the notice is not repeated.
+#[tauri::command]
+pub fn take_config_validation_report() -> Option<ConfigValidationReport> {
+    crate::state::config::take_validation_report()
+}
+
 #[tauri::command]
 pub fn reset_all_settings(
     app: AppHandle,
@@ -122,14 +198,15 @@ pub fn reset_all_settings(
     let _ = app.global_shortcut().unregister_all();
 
     let payload = command_helpers::update_state(&app, &state, true, |guard| {
-        let hidden = guard.cursor.hidden;
-
         *guard.prefs = PreferencesState::default();
         *guard.modes = ModeCustomizationState::default();
 
+        if let Some(style) = guard.policy.force_default_cursor_style {
+            guard.prefs.default_cursor_style = style;
+        }
+
         guard.cursor.cursor_paths.clear();
         guard.cursor.last_loaded_cursor_path = None;
-        guard.cursor.hidden = hidden;
 
         preference
             .0
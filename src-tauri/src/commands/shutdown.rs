@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Mutex;
 use tauri::{AppHandle, Manager};
@@ -10,6 +11,16 @@ use crate::system;
 
 static EXIT_REQUESTED: AtomicBool = AtomicBool::new(false);
 
+/// Read the registry back after restoring it from `snapshot` (or clearing it when absent).
+fn verify_restored_registry(
+    snapshot: Option<&HashMap<String, Option<String>>>,
+) -> Vec<crate::apply_verification::ApplyVerificationWarning> {
+    let expected = snapshot
+        .cloned()
+        .unwrap_or_else(crate::apply_verification::cleared_registry_entries);
+    crate::apply_verification::verify_registry(&expected)
+}
+
 pub fn quit_app(app: AppHandle) {
     request_exit(app);
 }
@@ -36,6 +47,8 @@ pub fn request_exit(app: AppHandle) {
 
     cc_debug!("[CursorChanger] Starting shutdown");
 
+    crate::window_events::persist_window_state(&app);
+
     if let Some(watcher_state) = app.try_state::<Mutex<FolderWatcherState>>() {
         let _ = stop_watcher_for_shutdown(&*watcher_state);
     }
@@ -48,7 +61,11 @@ pub fn request_exit(app: AppHandle) {
 
 #[allow(dead_code)]
 pub fn restore_state(state: &AppState) -> bool {
-    let was_hidden = state.cursor.read().map(|c| c.hidden).unwrap_or(false);
+    let was_hidden = state
+        .cursor
+        .read()
+        .map(|c| c.visibility.is_hidden())
+        .unwrap_or(false);
     if was_hidden {
         if let Err(err) = show_cursor(state) {
             cc_error!("{err}");
@@ -71,6 +88,9 @@ pub fn restore_state(state: &AppState) -> bool {
     if !restored_registry {
         cc_warn!("[CursorChanger] Warning: Failed to restore cursor registry entries");
     }
+    for warning in verify_restored_registry(cursor_registry_snapshot.as_ref()) {
+        cc_warn!("[CursorChanger] Apply verification failed: {}", warning.detail);
+    }
 
     // Tell Windows to reload cursors from registry (which now has empty values = defaults)
     let restored = system::restore_system_cursors();
@@ -104,6 +124,10 @@ pub fn restore_on_exit(app: &AppHandle) {
         if !restored_registry {
             cc_warn!("[CursorChanger] Warning: Failed to restore cursor registry entries");
         }
+        crate::apply_verification::report(
+            app,
+            verify_restored_registry(cursor_registry_snapshot.as_ref()),
+        );
 
         // Tell Windows to reload cursors from registry (which now has empty values = defaults)
         let restored = system::restore_system_cursors();
@@ -113,7 +137,7 @@ pub fn restore_on_exit(app: &AppHandle) {
 
         // Clear in-memory state after system calls complete.
         if let Ok(mut cursor) = state.cursor.write() {
-            cursor.hidden = false;
+            cursor.visibility.reset();
             cursor.cursor_paths.clear();
             cursor.last_loaded_cursor_path = None;
         }
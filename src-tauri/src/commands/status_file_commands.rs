@@ -0,0 +1,34 @@
+/// Status file for desktop widgets (see `crate::status_file`).
+use crate::status_file::{self, StatusFileSettings};
+use tauri::AppHandle;
+
+#[tauri::command]
+pub fn get_status_file(app: AppHandle) -> StatusFileSettings {
+    status_file::load_settings(&app)
+}
+
+/// Enable or disable the status file. Writes it straight away when enabled.
+#[tauri::command]
+pub fn set_status_file(
+    app: AppHandle,
+    path: Option<String>,
+    enabled: bool,
+) -> Result<StatusFileSettings, String> {
+    let path = path
+        .map(|p| p.trim().to_string())
+        .filter(|p| !p.is_empty())
+        .or_else(|| status_file::load_settings(&app).path);
+    if enabled && path.is_none() {
+        return Err("Choose a path for the status file".to_string());
+    }
+    if let Some(p) = &path {
+        if !std::path::Path::new(p).is_absolute() {
+            return Err("Status file path must be absolute".to_string());
+        }
+    }
+
+    let settings = StatusFileSettings { enabled, path };
+    status_file::save_settings(&app, &settings)?;
+    status_file::write_now(&app)?;
+    Ok(settings)
+}
@@ -0,0 +1,28 @@
+/// Moving the cursor library to another folder (see `crate::storage_location`).
+use crate::commands::folder_watcher::{pause_library_folder_watcher, FolderWatcherState};
+use crate::operations::Operation;
+use crate::storage_location::{self, StorageLocationSettings, StorageRelocationReport};
+use std::path::Path;
+use std::sync::Mutex;
+use tauri::{AppHandle, State};
+
+#[tauri::command]
+pub fn get_cursors_storage_dir(app: AppHandle) -> StorageLocationSettings {
+    storage_location::load_settings(&app)
+}
+
+/// Move the library's cursors, packs and caches to `path`, an empty or new folder, and point
+/// the library, the active cursors and the Windows cursor settings at the moved files.
+/// Reports `moving` progress through `op_id` and can be cancelled until the copy is complete.
+#[tauri::command]
+pub async fn set_cursors_storage_dir(
+    app: AppHandle,
+    watcher: State<'_, Mutex<FolderWatcherState>>,
+    path: String,
+    op_id: Option<String>,
+) -> Result<StorageRelocationReport, String> {
+    let operation = Operation::begin(&app, op_id);
+    pause_library_folder_watcher(&app, &watcher, || {
+        storage_location::relocate(&app, &operation, Path::new(path.trim()))
+    })
+}
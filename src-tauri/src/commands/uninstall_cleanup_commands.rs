@@ -0,0 +1,19 @@
+/// Dry-run and removal of everything the app created (see `crate::uninstall_cleanup`).
+use crate::uninstall_cleanup::{self, CleanupItem, UninstallCleanupReport};
+use tauri::AppHandle;
+
+/// List what `run_uninstall_cleanup` would remove, without changing anything.
+#[tauri::command]
+pub fn preview_uninstall_cleanup(app: AppHandle) -> Vec<CleanupItem> {
+    uninstall_cleanup::plan(&app)
+}
+
+/// Remove the items with the given locations, or everything the app can remove when
+/// `locations` is omitted. The app should quit afterwards; its data folders are gone.
+#[tauri::command]
+pub fn run_uninstall_cleanup(
+    app: AppHandle,
+    locations: Option<Vec<String>>,
+) -> UninstallCleanupReport {
+    uninstall_cleanup::run(&app, locations.as_deref())
+}
@@ -0,0 +1,37 @@
+/// Opt-in local usage statistics (see `crate::usage_stats`).
+use crate::usage_stats::{self, UsageStats, UsageStatsSettings};
+use tauri::AppHandle;
+
+use super::customization::library::load_library;
+
+/// Counts for the insights page, with pack IDs resolved to library names.
+#[tauri::command]
+pub fn get_usage_stats(app: AppHandle) -> Result<UsageStats, String> {
+    let library = load_library(&app)?;
+    let stats = usage_stats::counts().to_stats(usage_stats::is_enabled(), |pack_id| {
+        library
+            .cursors
+            .iter()
+            .find(|c| c.id == pack_id)
+            .map(|c| c.name.clone())
+    });
+    Ok(stats)
+}
+
+/// Start or stop counting. Collected counts are kept until purged.
+#[tauri::command]
+pub fn set_usage_stats_enabled(
+    app: AppHandle,
+    enabled: bool,
+) -> Result<UsageStatsSettings, String> {
+    let settings = UsageStatsSettings { enabled };
+    usage_stats::save_settings(&app, &settings)?;
+    usage_stats::set_enabled(enabled);
+    Ok(settings)
+}
+
+/// Delete all collected usage statistics.
+#[tauri::command]
+pub fn purge_usage_stats(app: AppHandle) -> Result<(), String> {
+    usage_stats::purge(&app)
+}
@@ -8,6 +8,18 @@ pub fn reset_window_size_to_default(app: AppHandle) -> Result<(), String> {
     crate::window_setup::reset_main_window_size(&app)
 }
 
+/// ID of the settings page that was open when the app last closed.
+#[tauri::command]
+pub fn get_last_settings_page(app: AppHandle) -> Result<Option<String>, String> {
+    Ok(crate::window_state::last_page(&app))
+}
+
+/// Remember the open settings page; `None` forgets it.
+#[tauri::command]
+pub fn set_last_settings_page(app: AppHandle, page: Option<String>) -> Result<(), String> {
+    crate::window_state::set_last_page(&app, page)
+}
+
 #[tauri::command]
 pub fn quit_app(app: AppHandle) {
     shutdown::quit_app(app)
@@ -0,0 +1,396 @@
+//! `--validate-config`: dry-run check of the saved settings, for fleet management and for
+//! comparing an install before and after an upgrade.
+//!
+//! Loads and normalizes `config.json`, every feature settings file, `library.json` and the
+//! manifest of every pack in the library the way startup does, but without starting the GUI or
+//! writing anything. The report
+//! is printed to stdout as JSON; the exit code is 1 if any file has errors and 2 if the check
+//! could not run. Missing files are not errors: startup falls back to defaults for them.
+
+use serde::Serialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::commands::customization::library::parse_library;
+use crate::commands::customization::pack_manifest::{
+    load_manifest_from_path, PACK_MANIFEST_FILENAME,
+};
+use crate::state::config::{normalize_persisted_config, parse_config};
+use crate::state::settings_file;
+use crate::state::PersistedConfig;
+
+pub const VALIDATE_CONFIG_FLAG: &str = "--validate-config";
+
+/// `identifier` from `tauri.conf.json`. Tauri keeps app config and data in
+/// `%APPDATA%\<identifier>`, and the check runs without Tauri to ask.
+const BUNDLE_IDENTIFIER: &str = "com.cursorchanger.application";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckedFileKind {
+    Config,
+    Settings,
+    Library,
+    PackManifest,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CheckedFile {
+    pub kind: CheckedFileKind,
+    pub path: String,
+    pub exists: bool,
+    /// Problems startup would repair by dropping data, or that stop the file loading
+    pub errors: Vec<String>,
+    /// Problems the app tolerates, such as library entries whose file is gone
+    pub warnings: Vec<String>,
+}
+
+impl CheckedFile {
+    fn new(kind: CheckedFileKind, path: &Path) -> Self {
+        Self {
+            kind,
+            path: path.to_string_lossy().to_string(),
+            exists: path.exists(),
+            errors: Vec::new(),
+            warnings: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ConfigCheckReport {
+    pub ok: bool,
+    pub files: Vec<CheckedFile>,
+    /// `config.json` as startup would use it: invalid fields dropped, defaults filled in
+    pub normalized_config: PersistedConfig,
+}
+
+/// Where `config.json` and `library.json` live, resolved without an app handle.
+struct DataPaths {
+    config_dir: PathBuf,
+    library_file: PathBuf,
+}
+
+impl DataPaths {
+    fn locate() -> Result<Self, String> {
+        if let Some(root) = crate::portable::data_root() {
+            return Ok(Self {
+                config_dir: root.join("config"),
+                library_file: root.join("library.json"),
+            });
+        }
+        let app_data = std::env::var("APPDATA")
+            .map_err(|_| "Failed to get APPDATA environment variable".to_string())?;
+        let dir = PathBuf::from(app_data)
+            .join(BUNDLE_IDENTIFIER)
+            .join("cursor-changer");
+        Ok(Self {
+            config_dir: dir.clone(),
+            library_file: dir.join("library.json"),
+        })
+    }
+}
+
+fn check_config(config_dir: &Path) -> (CheckedFile, PersistedConfig) {
+    let path = config_dir.join("config.json");
+    let mut file = CheckedFile::new(CheckedFileKind::Config, &path);
+    if !file.exists {
+        return (file, normalize_persisted_config(PersistedConfig::default()));
+    }
+
+    let config = match fs::read_to_string(&path) {
+        Ok(contents) => {
+            let (config, issues, reset_all) = parse_config(&contents);
+            file.errors.extend(issues.iter().map(|issue| {
+                format!(
+                    "{}: expected {}, found {}",
+                    issue.field, issue.expected, issue.actual
+                )
+            }));
+            if reset_all {
+                file.errors
+                    .push("Every setting would be reset to its default".to_string());
+            }
+            config
+        }
+        Err(e) => {
+            file.errors.push(format!("Failed to read config: {}", e));
+            PersistedConfig::default()
+        }
+    };
+    (file, normalize_persisted_config(config))
+}
+
+/// Check each feature settings file the way [`settings_file::load_from`] reads it.
+fn check_settings_files(config_dir: &Path) -> Vec<CheckedFile> {
+    settings_file::registered_files()
+        .into_iter()
+        .map(|(file_name, check)| {
+            let mut file = CheckedFile::new(CheckedFileKind::Settings, &config_dir.join(file_name));
+            if let Err(e) = check(config_dir) {
+                file.errors
+                    .push(format!("{} (would be reset to defaults)", e));
+            }
+            file
+        })
+        .filter(|file| file.exists)
+        .collect()
+}
+
+/// Check `library.json`, then the manifest of every pack it lists.
+fn check_library(library_file: &Path) -> Vec<CheckedFile> {
+    let mut file = CheckedFile::new(CheckedFileKind::Library, library_file);
+    if !file.exists {
+        return vec![file];
+    }
+    let library = match fs::read_to_string(library_file)
+        .map_err(|e| format!("Failed to read library: {}", e))
+        .and_then(|contents| parse_library(&contents))
+    {
+        Ok(library) => library,
+        Err(e) => {
+            file.errors.push(e);
+            return vec![file];
+        }
+    };
+
+    let mut seen_ids = std::collections::HashSet::new();
+    let mut packs = Vec::new();
+    for cursor in &library.cursors {
+        if !seen_ids.insert(cursor.id.as_str()) {
+            file.warnings.push(format!(
+                "Duplicate cursor id {} ({})",
+                cursor.id, cursor.name
+            ));
+        }
+        if !Path::new(&cursor.file_path).exists() {
+            file.warnings.push(format!(
+                "File of '{}' is missing: {}",
+                cursor.name, cursor.file_path
+            ));
+        } else if let Some(meta) = cursor.pack_metadata.as_ref().filter(|_| cursor.is_pack) {
+            packs.push(check_pack_manifest(Path::new(&meta.archive_path)));
+        }
+    }
+
+    let mut files = vec![file];
+    files.extend(packs);
+    files
+}
+
+/// Check the manifest inside a pack archive. Packs without one are fine: the pack is then
+/// described by its file names.
+fn check_pack_manifest(archive_path: &Path) -> CheckedFile {
+    let mut file = CheckedFile::new(CheckedFileKind::PackManifest, archive_path);
+    match load_manifest_from_path(archive_path) {
+        Ok(Some(manifest)) => {
+            if manifest.pack_name.trim().is_empty() {
+                file.warnings
+                    .push(format!("{} has no pack name", PACK_MANIFEST_FILENAME));
+            }
+            if manifest.items.is_empty() {
+                file.warnings
+                    .push(format!("{} lists no cursors", PACK_MANIFEST_FILENAME));
+            }
+        }
+        Ok(None) => {}
+        Err(e) => file.errors.push(e),
+    }
+    file
+}
+
+fn build_report(paths: &DataPaths) -> ConfigCheckReport {
+    let (config, normalized_config) = check_config(&paths.config_dir);
+    let mut files = vec![config];
+    files.extend(check_settings_files(&paths.config_dir));
+    files.extend(check_library(&paths.library_file));
+    ConfigCheckReport {
+        ok: files.iter().all(|f| f.errors.is_empty()),
+        files,
+        normalized_config,
+    }
+}
+
+/// Run the check, print the report and return the process exit code.
+pub fn run() -> i32 {
+    cursor_changer::cli_output::attach_parent_console();
+    let paths = match DataPaths::locate() {
+        Ok(paths) => paths,
+        Err(e) => {
+            eprintln!("{}", e);
+            return 2;
+        }
+    };
+    let report = build_report(&paths);
+    match serde_json::to_string_pretty(&report) {
+        Ok(json) => println!("{}", json),
+        Err(e) => {
+            eprintln!("Failed to serialize validation report: {}", e);
+            return 2;
+        }
+    }
+    i32::from(!report.ok)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    fn paths(dir: &Path) -> DataPaths {
+        DataPaths {
+            config_dir: dir.join("config"),
+            library_file: dir.join("library.json"),
+        }
+    }
+
+    fn write_pack(path: &Path, manifest: Option<&str>) {
+        let mut zip = zip::ZipWriter::new(fs::File::create(path).unwrap());
+        let options = zip::write::SimpleFileOptions::default();
+        zip.start_file("normal-select.cur", options).unwrap();
+        zip.write_all(b"cursor").unwrap();
+        if let Some(manifest) = manifest {
+            zip.start_file(PACK_MANIFEST_FILENAME, options).unwrap();
+            zip.write_all(manifest.as_bytes()).unwrap();
+        }
+        zip.finish().unwrap();
+    }
+
+    fn library_with_pack(archive_path: &Path) -> String {
+        serde_json::json!({
+            "schema_version": 3,
+            "cursors": [
+                {
+                    "id": "pack",
+                    "name": "Neon",
+                    "file_path": archive_path,
+                    "click_point_x": 0,
+                    "click_point_y": 0,
+                    "created_at": "",
+                    "is_pack": true,
+                    "pack_metadata": {
+                        "mode": "simple",
+                        "archive_path": archive_path,
+                        "items": []
+                    }
+                },
+                {
+                    "id": "gone",
+                    "name": "Gone",
+                    "file_path": "C:/nowhere/gone.cur",
+                    "click_point_x": 0,
+                    "click_point_y": 0,
+                    "created_at": ""
+                }
+            ]
+        })
+        .to_string()
+    }
+
+    #[test]
+    fn fresh_install_is_valid() {
+        let dir = tempfile::tempdir().unwrap();
+        let report = build_report(&paths(dir.path()));
+        assert!(report.ok);
+        assert_eq!(report.files.len(), 2);
+        assert!(report.files.iter().all(|f| !f.exists));
+        assert_eq!(report.normalized_config.cursor_size, Some(32));
+    }
+
+    #[test]
+    fn invalid_config_fields_are_errors_and_normalized() {
+        let dir = tempfile::tempdir().unwrap();
+        let paths = paths(dir.path());
+        fs::create_dir_all(&paths.config_dir).unwrap();
+        fs::write(
+            paths.config_dir.join("config.json"),
+            r#"{"cursor_size": 9000, "minimize_to_tray": false}"#,
+        )
+        .unwrap();
+
+        let report = build_report(&paths);
+        assert!(!report.ok);
+        assert_eq!(report.files[0].errors.len(), 1);
+        assert!(report.files[0].errors[0].starts_with("cursor_size: "));
+        assert_eq!(report.normalized_config.cursor_size, Some(32));
+        assert_eq!(report.normalized_config.minimize_to_tray, Some(false));
+        // A dry run leaves no backup behind
+        assert_eq!(fs::read_dir(&paths.config_dir).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn invalid_settings_files_are_errors() {
+        let dir = tempfile::tempdir().unwrap();
+        let paths = paths(dir.path());
+        fs::create_dir_all(&paths.config_dir).unwrap();
+        fs::write(paths.config_dir.join("hot-corners.json"), "{ nope").unwrap();
+        fs::write(
+            paths.config_dir.join("health-endpoint.json"),
+            r#"{"enabled": true, "port": 80}"#,
+        )
+        .unwrap();
+        fs::write(
+            paths.config_dir.join("cache-gc.json"),
+            r#"{"quota_mb": 64}"#,
+        )
+        .unwrap();
+
+        let report = build_report(&paths);
+        assert!(!report.ok);
+        let settings: Vec<&CheckedFile> = report
+            .files
+            .iter()
+            .filter(|f| f.kind == CheckedFileKind::Settings)
+            .collect();
+        assert_eq!(settings.len(), 3);
+        let failed: Vec<&str> = settings
+            .iter()
+            .filter(|f| !f.errors.is_empty())
+            .map(|f| f.path.rsplit(['/', '\\']).next().unwrap())
+            .collect();
+        assert_eq!(failed, ["health-endpoint.json", "hot-corners.json"]);
+        // A dry run leaves no backup behind
+        assert_eq!(fs::read_dir(&paths.config_dir).unwrap().count(), 3);
+    }
+
+    #[test]
+    fn library_checks_files_and_pack_manifests() {
+        let dir = tempfile::tempdir().unwrap();
+        let paths = paths(dir.path());
+        let archive = dir.path().join("neon.zip");
+        fs::write(&paths.library_file, library_with_pack(&archive)).unwrap();
+
+        write_pack(&archive, None);
+        let report = build_report(&paths);
+        assert!(report.ok, "{:?}", report.files);
+        assert_eq!(report.files[1].warnings.len(), 1);
+        assert_eq!(report.files[2].kind, CheckedFileKind::PackManifest);
+
+        write_pack(&archive, Some("{ not json"));
+        let report = build_report(&paths);
+        assert!(!report.ok);
+        assert!(report.files[2].errors[0].starts_with("Invalid cursor-pack.json"));
+    }
+
+    #[test]
+    fn library_from_a_newer_build_is_an_error() {
+        let dir = tempfile::tempdir().unwrap();
+        let paths = paths(dir.path());
+        fs::write(
+            &paths.library_file,
+            r#"{"schema_version": 99, "cursors": []}"#,
+        )
+        .unwrap();
+
+        let report = build_report(&paths);
+        assert!(!report.ok);
+        assert!(report.files[1].errors[0].starts_with("E-LIB-004: "));
+    }
+
+    #[test]
+    fn bundle_identifier_matches_tauri_conf() {
+        let conf: serde_json::Value =
+            serde_json::from_str(include_str!("../tauri.conf.json")).unwrap();
+        assert_eq!(conf["identifier"], BUNDLE_IDENTIFIER);
+    }
+}
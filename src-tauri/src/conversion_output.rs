@@ -0,0 +1,259 @@
+//! Where converted cursors are written and what they are called.
+//!
+//! Conversions land in the cursors folder under the source file's name unless the user picks
+//! an output folder and a file name template such as `{name}-{size}px-{hash}`. Uploads added
+//! to the library follow the template too but always stay in the cursors folder, which the
+//! library reads from.
+
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+use std::sync::RwLock;
+use tauri::{AppHandle, Runtime};
+
+use crate::commands::customization::pack_mount::fnv1a_64;
+use crate::state::settings_file::{self, SettingsFile};
+
+pub const CONVERSION_OUTPUT_FILE_NAME: &str = "conversion-output.json";
+
+/// Converted files are named after their source unless the user picks a template
+pub const DEFAULT_NAME_TEMPLATE: &str = "{name}";
+
+/// Placeholders a template can use
+const PLACEHOLDERS: [&str; 3] = ["name", "size", "hash"];
+
+/// Used when a template renders to nothing usable as a file name
+const FALLBACK_NAME: &str = "cursor";
+
+static SETTINGS: RwLock<Option<ConversionOutputSettings>> = RwLock::new(None);
+
+#[derive(ts_rs::TS, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct ConversionOutputSettings {
+    /// Folder converted cursors are written to; the cursors folder when unset
+    #[serde(default)]
+    pub output_dir: Option<String>,
+    /// File name without extension. `{name}` is the source file name, `{size}` the cursor
+    /// size in pixels and `{hash}` a short hash of the source file.
+    #[serde(default = "default_name_template")]
+    pub name_template: String,
+}
+
+fn default_name_template() -> String {
+    DEFAULT_NAME_TEMPLATE.to_string()
+}
+
+impl Default for ConversionOutputSettings {
+    fn default() -> Self {
+        Self {
+            output_dir: None,
+            name_template: default_name_template(),
+        }
+    }
+}
+
+impl SettingsFile for ConversionOutputSettings {
+    const FILE_NAME: &'static str = CONVERSION_OUTPUT_FILE_NAME;
+    const DESCRIPTION: &'static str = "conversion output settings";
+
+    fn validate(&self) -> Result<(), String> {
+        validate_template(&self.name_template)
+    }
+
+    fn map_paths(&mut self, map: fn(&str) -> String) {
+        self.output_dir = self.output_dir.as_deref().map(map);
+    }
+}
+
+pub fn load_settings<R: Runtime>(app: &AppHandle<R>) -> ConversionOutputSettings {
+    settings_file::load(app)
+}
+
+pub fn save_settings<R: Runtime>(
+    app: &AppHandle<R>,
+    settings: &ConversionOutputSettings,
+) -> Result<(), String> {
+    settings_file::save(app, settings)?;
+    set_current(settings.clone());
+    Ok(())
+}
+
+fn set_current(settings: ConversionOutputSettings) {
+    if let Ok(mut current) = SETTINGS.write() {
+        *current = Some(settings);
+    }
+}
+
+/// Load the saved settings. Called once at startup.
+pub fn install<R: Runtime>(app: &AppHandle<R>) {
+    set_current(load_settings(app));
+}
+
+pub fn current() -> ConversionOutputSettings {
+    SETTINGS
+        .read()
+        .ok()
+        .and_then(|settings| settings.clone())
+        .unwrap_or_default()
+}
+
+/// Folder standalone conversions are written to.
+pub fn output_dir() -> Result<PathBuf, String> {
+    match current().output_dir {
+        Some(dir) => Ok(PathBuf::from(dir)),
+        None => crate::paths::cursors_dir(),
+    }
+}
+
+/// Split `template` into literal text and placeholder names.
+fn parse_template(template: &str) -> Result<Vec<(bool, &str)>, String> {
+    let mut parts = Vec::new();
+    let mut rest = template;
+    while let Some(start) = rest.find(['{', '}']) {
+        if rest[start..].starts_with('}') {
+            return Err("Unmatched '}' in file name template".to_string());
+        }
+        if start > 0 {
+            parts.push((false, &rest[..start]));
+        }
+        let after = &rest[start + 1..];
+        let end = after
+            .find('}')
+            .ok_or_else(|| "Unclosed '{' in file name template".to_string())?;
+        let placeholder = &after[..end];
+        if !PLACEHOLDERS.contains(&placeholder) {
+            return Err(format!(
+                "Unknown placeholder {{{}}} in file name template; use {{name}}, {{size}} or {{hash}}",
+                placeholder
+            ));
+        }
+        parts.push((true, placeholder));
+        rest = &after[end + 1..];
+    }
+    if !rest.is_empty() {
+        parts.push((false, rest));
+    }
+    Ok(parts)
+}
+
+/// Check `template` only uses known placeholders and cannot name a file outside the output
+/// folder.
+pub fn validate_template(template: &str) -> Result<(), String> {
+    if template.trim().is_empty() {
+        return Err("File name template cannot be empty".to_string());
+    }
+    if template.contains(['/', '\\']) {
+        return Err("File name template cannot contain folder separators".to_string());
+    }
+    parse_template(template).map(|_| ())
+}
+
+/// Characters Windows does not allow in file names become `_`; trailing dots and spaces,
+/// which Windows strips, are dropped.
+fn sanitize_file_name(name: &str) -> String {
+    let cleaned: String = name
+        .chars()
+        .map(|c| match c {
+            '<' | '>' | ':' | '"' | '/' | '\\' | '|' | '?' | '*' => '_',
+            c if c.is_control() => '_',
+            c => c,
+        })
+        .collect();
+    let trimmed = cleaned.trim_end_matches(['.', ' ']).trim_start();
+    if trimmed.is_empty() {
+        FALLBACK_NAME.to_string()
+    } else {
+        trimmed.to_string()
+    }
+}
+
+/// Fill in `template` for a source named `name` converted at `size` pixels. `source_hash` is
+/// only called when the template uses `{hash}`, so callers can read the source lazily.
+fn render(template: &str, name: &str, size: u32, source_hash: impl FnOnce() -> u64) -> String {
+    let parts = parse_template(template).unwrap_or_else(|_| vec![(true, "name")]);
+    let mut source_hash = Some(source_hash);
+    let mut hash = None;
+    let mut rendered = String::new();
+    for (is_placeholder, text) in parts {
+        match (is_placeholder, text) {
+            (false, literal) => rendered.push_str(literal),
+            (true, "name") => rendered.push_str(name),
+            (true, "size") => rendered.push_str(&size.to_string()),
+            (true, _) => {
+                let value = *hash.get_or_insert_with(|| {
+                    source_hash.take().map_or(0, |hash_source| hash_source())
+                });
+                rendered.push_str(&format!("{:08x}", value >> 32));
+            }
+        }
+    }
+    sanitize_file_name(&rendered)
+}
+
+/// File name, with `extension`, for a conversion of the source named `name` at `size`
+/// pixels, following the saved template.
+pub fn file_name(
+    name: &str,
+    size: u32,
+    extension: &str,
+    source_hash: impl FnOnce() -> u64,
+) -> String {
+    let stem = render(&current().name_template, name, size, source_hash);
+    format!("{}.{}", stem, extension)
+}
+
+/// [`file_name`] for a source file on disk, hashing its contents when the template asks.
+pub fn file_name_for_path(path: &str, name: &str, size: u32, extension: &str) -> String {
+    file_name(name, size, extension, || {
+        fs::read(path).map(|bytes| fnv1a_64(&bytes)).unwrap_or(0)
+    })
+}
+
+/// [`file_name`] for a source already in memory.
+pub fn file_name_for_bytes(bytes: &[u8], name: &str, size: u32, extension: &str) -> String {
+    file_name(name, size, extension, || fnv1a_64(bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn templates_fill_in_name_size_and_hash() {
+        let hash = || 0x0123_4567_89ab_cdef;
+        assert_eq!(render("{name}", "arrow", 32, hash), "arrow");
+        assert_eq!(
+            render("{name}-{size}px-{hash}", "arrow", 48, hash),
+            "arrow-48px-01234567"
+        );
+        assert_eq!(
+            render("{hash}_{hash}", "arrow", 48, hash),
+            "01234567_01234567"
+        );
+    }
+
+    #[test]
+    fn hash_is_only_computed_when_used() {
+        let rendered = render("{name}-{size}", "arrow", 32, || panic!("hashed"));
+        assert_eq!(rendered, "arrow-32");
+    }
+
+    #[test]
+    fn rendered_names_are_valid_file_names() {
+        assert_eq!(render("{name}", "a:b*c?", 32, || 0), "a_b_c_");
+        assert_eq!(render("{name}...", "arrow", 32, || 0), "arrow");
+        assert_eq!(render("{name}", "", 32, || 0), FALLBACK_NAME);
+    }
+
+    #[test]
+    fn invalid_templates_are_rejected() {
+        assert!(validate_template("{name}-{size}px-{hash}").is_ok());
+        assert!(validate_template("").is_err());
+        assert!(validate_template("  ").is_err());
+        assert!(validate_template("{date}").is_err());
+        assert!(validate_template("{name").is_err());
+        assert!(validate_template("name}").is_err());
+        assert!(validate_template("../{name}").is_err());
+        assert!(validate_template("sub\\{name}").is_err());
+    }
+}
@@ -0,0 +1,212 @@
+//! Opt-in crash reporting.
+//!
+//! When enabled, a fatal native exception or a Rust panic restores the system cursors first,
+//! then writes a minidump plus a sanitized tail of the recent log lines to
+//! `%APPDATA%\cursor-changer\crash-reports`. Nothing is uploaded; the UI lists pending
+//! reports via `get_pending_crash_reports` and offers to open the folder.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Once;
+use tauri::{AppHandle, Runtime};
+
+use crate::state::settings_file::{self, SettingsFile};
+
+pub const CRASH_REPORT_SETTINGS_FILE_NAME: &str = "crash-reports.json";
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static INSTALL: Once = Once::new();
+
+#[derive(ts_rs::TS, Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct CrashReportSettings {
+    #[serde(default)]
+    pub enabled: bool,
+}
+
+/// A crash that has not been dismissed yet. Either file may be missing if writing it failed.
+#[derive(ts_rs::TS, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct CrashReport {
+    /// File stem shared by the dump and the log, e.g. `crash-1734000000000`
+    pub id: String,
+    /// Milliseconds since the Unix epoch, taken from the file name
+    #[ts(type = "number")]
+    pub created_ms: u64,
+    pub dump_path: Option<String>,
+    pub log_path: Option<String>,
+}
+
+impl SettingsFile for CrashReportSettings {
+    const FILE_NAME: &'static str = CRASH_REPORT_SETTINGS_FILE_NAME;
+    const DESCRIPTION: &'static str = "crash report settings";
+}
+
+pub fn load_settings<R: Runtime>(app: &AppHandle<R>) -> CrashReportSettings {
+    settings_file::load(app)
+}
+
+pub fn save_settings<R: Runtime>(
+    app: &AppHandle<R>,
+    settings: &CrashReportSettings,
+) -> Result<(), String> {
+    settings_file::save(app, settings)
+}
+
+/// Apply the persisted setting. Called once at startup.
+pub fn init<R: Runtime>(app: &AppHandle<R>) {
+    set_enabled(load_settings(app).enabled);
+}
+
+/// Turn crash capture on or off for this process. The handlers are installed the first time
+/// capture is enabled and stay installed; turning it off makes them skip the report.
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::SeqCst);
+    if enabled {
+        INSTALL.call_once(install_handlers);
+    }
+}
+
+fn install_handlers() {
+    let dump_dir = match crate::paths::crash_reports_dir() {
+        Ok(dir) => dir,
+        Err(e) => {
+            cc_warn!("[CursorChanger] Crash reporting unavailable: {}", e);
+            return;
+        }
+    };
+
+    // SAFETY: `on_native_crash` only restores cursors and writes files; it never panics.
+    if !unsafe { cursor_changer::install_crash_handler(dump_dir.clone(), on_native_crash) } {
+        cc_warn!("[CursorChanger] Failed to install the native crash handler");
+    }
+
+    let previous = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        if ENABLED.load(Ordering::SeqCst) {
+            let _ = crate::system::restore_system_cursors();
+            let dump_path = cursor_changer::new_dump_path(&dump_dir);
+            write_log_tail(&dump_path, &format!("panic: {}", info));
+            // SAFETY: a null exception pointer dumps the current thread state.
+            let _ = unsafe { cursor_changer::write_minidump(&dump_path, std::ptr::null_mut()) };
+        }
+        previous(info);
+    }));
+}
+
+fn on_native_crash(dump_path: &Path) -> bool {
+    if !ENABLED.load(Ordering::SeqCst) {
+        return false;
+    }
+    let _ = crate::system::restore_system_cursors();
+    write_log_tail(dump_path, "fatal native exception");
+    true
+}
+
+fn write_log_tail(dump_path: &Path, reason: &str) {
+    let mut contents = format!(
+        "Cursor Changer {} crashed: {}\n\n",
+        env!("CARGO_PKG_VERSION"),
+        reason
+    );
+    for line in crate::logging::log_tail() {
+        contents.push_str(&line);
+        contents.push('\n');
+    }
+    let _ = fs::write(dump_path.with_extension("log"), sanitize(&contents));
+}
+
+/// Strip the user profile path and account name so reports can be shared as-is.
+pub(crate) fn sanitize(text: &str) -> String {
+    let mut sanitized = text.to_string();
+    if let Ok(profile) = std::env::var("USERPROFILE") {
+        if !profile.is_empty() {
+            sanitized = sanitized.replace(&profile, "%USERPROFILE%");
+        }
+    }
+    if let Ok(user) = std::env::var("USERNAME") {
+        if user.len() > 2 {
+            sanitized = sanitized.replace(&user, "<user>");
+        }
+    }
+    sanitized
+}
+
+/// Reports in `dir`, newest first.
+pub fn list_reports(dir: &Path) -> Vec<CrashReport> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+
+    let mut reports: BTreeMap<String, CrashReport> = BTreeMap::new();
+    for path in entries.flatten().map(|entry| entry.path()) {
+        let Some(stem) = path.file_stem().and_then(|s| s.to_str()) else {
+            continue;
+        };
+        let Some(created_ms) = stem
+            .strip_prefix("crash-")
+            .and_then(|stamp| stamp.parse::<u64>().ok())
+        else {
+            continue;
+        };
+        let report = reports.entry(stem.to_string()).or_insert_with(|| CrashReport {
+            id: stem.to_string(),
+            created_ms,
+            dump_path: None,
+            log_path: None,
+        });
+        let path_string = path.to_string_lossy().into_owned();
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("dmp") => report.dump_path = Some(path_string),
+            Some("log") => report.log_path = Some(path_string),
+            _ => {}
+        }
+    }
+
+    let mut reports: Vec<CrashReport> = reports
+        .into_values()
+        .filter(|r| r.dump_path.is_some() || r.log_path.is_some())
+        .collect();
+    reports.sort_by(|a, b| b.created_ms.cmp(&a.created_ms));
+    reports
+}
+
+/// Delete every report in `dir`. Returns how many reports were removed.
+pub fn dismiss_reports(dir: &Path) -> Result<usize, String> {
+    let reports = list_reports(dir);
+    for report in &reports {
+        for path in report.dump_path.iter().chain(report.log_path.iter()) {
+            fs::remove_file(path)
+                .map_err(|e| format!("Failed to delete crash report {}: {}", report.id, e))?;
+        }
+    }
+    Ok(reports.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lists_reports_grouped_by_stem_newest_first() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        fs::write(temp.path().join("crash-100.dmp"), b"dump").unwrap();
+        fs::write(temp.path().join("crash-100.log"), b"log").unwrap();
+        fs::write(temp.path().join("crash-200.log"), b"log").unwrap();
+        fs::write(temp.path().join("notes.txt"), b"ignored").unwrap();
+
+        let reports = list_reports(temp.path());
+        assert_eq!(reports.len(), 2);
+        assert_eq!(reports[0].id, "crash-200");
+        assert!(reports[0].dump_path.is_none());
+        assert_eq!(reports[1].created_ms, 100);
+        assert!(reports[1].dump_path.is_some() && reports[1].log_path.is_some());
+
+        assert_eq!(dismiss_reports(temp.path()).unwrap(), 2);
+        assert!(list_reports(temp.path()).is_empty());
+        assert!(temp.path().join("notes.txt").exists());
+    }
+}
@@ -0,0 +1,235 @@
+//! Adaptive cursor brightness.
+//!
+//! When enabled, `.cur` files are nudged brighter on dark backgrounds and darker on light
+//! ones at apply time. The background is judged either from the app/system theme or from
+//! the average luminance of the desktop wallpaper, sampled when the cursor is applied. The
+//! adjusted copies live under the variants directory and are regenerated when the source
+//! file, the direction or the strength changes; the original path is what state keeps.
+
+use serde::{Deserialize, Serialize};
+use std::collections::hash_map::DefaultHasher;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Manager, Runtime};
+
+use crate::state::settings_file::{self, SettingsFile};
+use crate::state::{AppState, ThemeMode};
+
+pub const ADAPTIVE_BRIGHTNESS_FILE_NAME: &str = "adaptive-brightness.json";
+const ADAPTIVE_VARIANTS_DIR: &str = "adaptive";
+/// Upper bound for `strength_percent`; stronger shifts wash out cursor detail.
+pub const MAX_STRENGTH_PERCENT: u8 = 40;
+/// Wallpapers between these luminances are treated as neutral and leave cursors untouched.
+const NEUTRAL_LUMINANCE: std::ops::RangeInclusive<f32> = 0.4..=0.6;
+
+#[derive(ts_rs::TS, Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub enum AdaptiveBrightnessSource {
+    #[default]
+    Off,
+    Theme,
+    Wallpaper,
+}
+
+#[derive(ts_rs::TS, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct AdaptiveBrightnessSettings {
+    #[serde(default)]
+    pub source: AdaptiveBrightnessSource,
+    /// How far pixels move towards white or black, in percent
+    #[serde(default = "default_strength_percent")]
+    pub strength_percent: u8,
+}
+
+fn default_strength_percent() -> u8 {
+    15
+}
+
+impl Default for AdaptiveBrightnessSettings {
+    fn default() -> Self {
+        Self {
+            source: AdaptiveBrightnessSource::Off,
+            strength_percent: default_strength_percent(),
+        }
+    }
+}
+
+impl SettingsFile for AdaptiveBrightnessSettings {
+    const FILE_NAME: &'static str = ADAPTIVE_BRIGHTNESS_FILE_NAME;
+    const DESCRIPTION: &'static str = "adaptive brightness settings";
+}
+
+pub fn load_settings<R: Runtime>(app: &AppHandle<R>) -> AdaptiveBrightnessSettings {
+    settings_file::load(app)
+}
+
+pub fn save_settings<R: Runtime>(
+    app: &AppHandle<R>,
+    settings: &AdaptiveBrightnessSettings,
+) -> Result<(), String> {
+    settings_file::save(app, settings)
+}
+
+/// Signed brightness shift for a background: positive brightens (dark background),
+/// negative darkens (light background), zero leaves the cursor alone.
+pub fn shift_for_background(dark_background: Option<bool>, strength_percent: u8) -> f32 {
+    let strength = f32::from(strength_percent.min(MAX_STRENGTH_PERCENT)) / 100.0;
+    match dark_background {
+        Some(true) => strength,
+        Some(false) => -strength,
+        None => 0.0,
+    }
+}
+
+fn theme_is_dark<R: Runtime>(app: &AppHandle<R>) -> Option<bool> {
+    let theme = app.state::<AppState>().prefs.read().ok()?.theme_mode;
+    match theme {
+        ThemeMode::Dark => Some(true),
+        ThemeMode::Light => Some(false),
+        ThemeMode::System => system_uses_dark_theme(),
+    }
+}
+
+fn system_uses_dark_theme() -> Option<bool> {
+    use winreg::enums::HKEY_CURRENT_USER;
+    use winreg::RegKey;
+
+    let key = RegKey::predef(HKEY_CURRENT_USER)
+        .open_subkey("Software\\Microsoft\\Windows\\CurrentVersion\\Themes\\Personalize")
+        .ok()?;
+    let light: u32 = key.get_value("AppsUseLightTheme").ok()?;
+    Some(light == 0)
+}
+
+/// Image file of the current desktop wallpaper, if one is set.
+pub(crate) fn wallpaper_path() -> Option<PathBuf> {
+    use winreg::enums::HKEY_CURRENT_USER;
+    use winreg::RegKey;
+
+    let key = RegKey::predef(HKEY_CURRENT_USER)
+        .open_subkey("Control Panel\\Desktop")
+        .ok()?;
+    let wallpaper: String = key.get_value("WallPaper").ok()?;
+    let wallpaper = wallpaper.trim();
+    (!wallpaper.is_empty()).then(|| PathBuf::from(wallpaper))
+}
+
+fn wallpaper_is_dark() -> Option<bool> {
+    let image = image::open(wallpaper_path()?).ok()?;
+    // A thumbnail is plenty for an average and keeps large wallpapers cheap
+    let thumbnail = image.thumbnail(64, 64).to_rgb8();
+    classify_luminance(average_luminance(&thumbnail)?)
+}
+
+fn average_luminance(image: &image::RgbImage) -> Option<f32> {
+    let count = image.pixels().len();
+    if count == 0 {
+        return None;
+    }
+    let total: f32 = image
+        .pixels()
+        .map(|p| {
+            (0.2126 * f32::from(p.0[0]) + 0.7152 * f32::from(p.0[1]) + 0.0722 * f32::from(p.0[2]))
+                / 255.0
+        })
+        .sum();
+    Some(total / count as f32)
+}
+
+fn classify_luminance(luminance: f32) -> Option<bool> {
+    if NEUTRAL_LUMINANCE.contains(&luminance) {
+        None
+    } else {
+        Some(luminance < *NEUTRAL_LUMINANCE.start())
+    }
+}
+
+/// Path to hand to Windows for `path`: an adjusted copy when adaptive brightness applies,
+/// otherwise `path` unchanged.
+pub fn adapt_apply_path<R: Runtime>(app: &AppHandle<R>, path: &str) -> String {
+    let settings = load_settings(app);
+    let dark_background = match settings.source {
+        AdaptiveBrightnessSource::Off => return path.to_string(),
+        AdaptiveBrightnessSource::Theme => theme_is_dark(app),
+        AdaptiveBrightnessSource::Wallpaper => wallpaper_is_dark(),
+    };
+    let shift = shift_for_background(dark_background, settings.strength_percent);
+    if shift == 0.0 {
+        return path.to_string();
+    }
+
+    let source = Path::new(path);
+    let is_cur = source
+        .extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|e| e.eq_ignore_ascii_case("cur"));
+    if !is_cur {
+        return path.to_string();
+    }
+
+    let Some(out_path) = variant_path(source, shift) else {
+        return path.to_string();
+    };
+    if out_path.exists() {
+        return out_path.to_string_lossy().to_string();
+    }
+    crate::commands::customization::library::brightness_adjusted_variant(source, shift, &out_path)
+        .map(|p| p.to_string_lossy().to_string())
+        .unwrap_or_else(|| path.to_string())
+}
+
+/// Cache location keyed by source path, modification time and shift.
+fn variant_path(source: &Path, shift: f32) -> Option<PathBuf> {
+    let modified = fs::metadata(source).and_then(|m| m.modified()).ok()?;
+    let mut hasher = DefaultHasher::new();
+    source.hash(&mut hasher);
+    modified.hash(&mut hasher);
+    let root = crate::paths::cursor_variants_dir().ok()?;
+    Some(root.join(ADAPTIVE_VARIANTS_DIR).join(format!(
+        "{:016x}_{:+}.cur",
+        hasher.finish(),
+        (shift * 100.0).round() as i32
+    )))
+}
+
+/// Delete all adjusted copies, e.g. after the settings change.
+pub fn clear_variants() {
+    let Ok(root) = crate::paths::cursor_variants_dir() else {
+        return;
+    };
+    let dir = root.join(ADAPTIVE_VARIANTS_DIR);
+    if dir.exists() {
+        if let Err(e) = fs::remove_dir_all(&dir) {
+            cc_warn!(
+                "[CursorChanger] Failed to clear adaptive cursor variants: {}",
+                e
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shift_direction_follows_background_and_is_capped() {
+        assert_eq!(shift_for_background(Some(true), 15), 0.15);
+        assert_eq!(shift_for_background(Some(false), 15), -0.15);
+        assert_eq!(shift_for_background(None, 15), 0.0);
+        assert_eq!(shift_for_background(Some(true), 90), 0.4);
+    }
+
+    #[test]
+    fn wallpaper_luminance_has_a_neutral_band() {
+        let black = image::RgbImage::from_pixel(4, 4, image::Rgb([0, 0, 0]));
+        let white = image::RgbImage::from_pixel(4, 4, image::Rgb([255, 255, 255]));
+        let grey = image::RgbImage::from_pixel(4, 4, image::Rgb([128, 128, 128]));
+
+        assert_eq!(classify_luminance(average_luminance(&black).unwrap()), Some(true));
+        assert_eq!(classify_luminance(average_luminance(&white).unwrap()), Some(false));
+        assert_eq!(classify_luminance(average_luminance(&grey).unwrap()), None);
+    }
+}
@@ -0,0 +1,4 @@
+//! The converter lives in the standalone `cursor-convert` crate so other tools can share it.
+//! Re-exported here so existing `crate::cursor_converter::...` paths keep working.
+
+pub use cursor_convert::*;
@@ -1,7 +1,10 @@
-use std::{collections::HashMap, path::PathBuf};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 
 use tauri::{AppHandle, Manager, Runtime};
 
+use crate::commands::customization::library::generated_resize_cursor;
+use crate::state::DefaultCursorStyle;
 use crate::system;
 
 pub const SIMPLE_MODE_CURSOR_NAMES: [&str; 14] = [
@@ -21,8 +24,20 @@ pub const SIMPLE_MODE_CURSOR_NAMES: [&str; 14] = [
     "Pen",
 ];
 
+/// File for `cursor_name` that `cursor_style` provides itself rather than from a bundled
+/// directory: a stock Windows scheme file, or a generated touch cursor.
+fn find_system_scheme_cursor(cursor_style: &str, cursor_name: &str) -> Option<PathBuf> {
+    match DefaultCursorStyle::from_str(cursor_style)? {
+        DefaultCursorStyle::Touch => crate::touch_cursors::touch_cursor_file(cursor_name),
+        style => cursor_changer::find_system_cursor_file(cursor_name, style.system_scheme()?),
+    }
+}
+
 fn default_cursors_dir_candidates<R: Runtime>(app: &AppHandle<R>, cursor_style: &str) -> Vec<PathBuf> {
     let mut candidates = Vec::new();
+    let cursor_style = DefaultCursorStyle::from_str(cursor_style)
+        .map(DefaultCursorStyle::bundled_dir_name)
+        .unwrap_or(cursor_style);
 
     if let Ok(resource_dir) = app.path().resource_dir() {
         candidates.push(
@@ -49,10 +64,9 @@ fn default_cursors_dir_candidates<R: Runtime>(app: &AppHandle<R>, cursor_style:
         );
     }
 
-    if let Ok(app_data_dir) = std::env::var("APPDATA") {
+    if let Ok(app_dir) = crate::portable::app_dir() {
         candidates.push(
-            PathBuf::from(app_data_dir)
-                .join("cursor-changer")
+            app_dir
                 .join("default-assets")
                 .join("default-cursors")
                 .join(cursor_style),
@@ -92,7 +106,9 @@ pub fn resolve_default_cursor_paths<R: Runtime>(
     let base_name_mapping = &cursor_changer::DEFAULT_CURSOR_BASE_NAMES;
     let mut cursor_paths = HashMap::new();
     for &(cursor_name, base_name) in base_name_mapping.iter() {
-        if let Some(cur_path) = cursor_changer::find_cursor_file_in_dir(&cur_dir, base_name) {
+        let found = find_system_scheme_cursor(cursor_style, cursor_name)
+            .or_else(|| cursor_changer::find_cursor_file_in_dir(&cur_dir, base_name));
+        if let Some(cur_path) = found {
             cursor_paths.insert(cursor_name.to_string(), cur_path);
         } else {
             cc_warn!(
@@ -111,6 +127,10 @@ pub fn resolve_default_cursor_path<R: Runtime>(
     cursor_style: &str,
     cursor_name: &str,
 ) -> Result<Option<PathBuf>, String> {
+    if let Some(found) = find_system_scheme_cursor(cursor_style, cursor_name) {
+        return Ok(Some(found));
+    }
+
     let candidates = default_cursors_dir_candidates(app, cursor_style);
 
     for cur_dir in candidates {
@@ -140,7 +160,9 @@ pub fn populate_missing_cursor_paths_with_defaults<R: Runtime>(
             continue;
         }
 
-        if let Some(cur_path) = cursor_changer::find_cursor_file_in_dir(&cur_dir, base_name) {
+        let found = find_system_scheme_cursor(cursor_style, cursor_name)
+            .or_else(|| cursor_changer::find_cursor_file_in_dir(&cur_dir, base_name));
+        if let Some(cur_path) = found {
             cursor_paths.insert(
                 cursor_name.to_string(),
                 cur_path.to_string_lossy().to_string(),
@@ -161,6 +183,7 @@ pub fn apply_cursor_paths_advanced(cursor_paths: &HashMap<String, String>, curso
             }
         }
     }
+    crate::cursor_refresh::flush_after_apply();
 }
 
 pub fn apply_cursor_paths_simple(cursor_paths: &HashMap<String, String>, cursor_size: i32) {
@@ -170,11 +193,11 @@ pub fn apply_cursor_paths_simple(cursor_paths: &HashMap<String, String>, cursor_
                 .iter()
                 .find(|ct| ct.name == cursor_name)
             {
-                if !system::apply_cursor_from_file_with_size(
-                    normal_path,
-                    cursor_type.id,
-                    cursor_size,
-                ) {
+                // Resize roles get double-headed arrows built from Normal when possible
+                let path = generated_resize_cursor(Path::new(normal_path), cursor_name)
+                    .map(|p| p.to_string_lossy().to_string())
+                    .unwrap_or_else(|| normal_path.clone());
+                if !system::apply_cursor_from_file_with_size(&path, cursor_type.id, cursor_size) {
                     cc_warn!("Warning: Failed to apply Normal cursor to {}", cursor_name);
                 }
             }
@@ -191,4 +214,5 @@ pub fn apply_cursor_paths_simple(cursor_paths: &HashMap<String, String>, cursor_
             }
         }
     }
+    crate::cursor_refresh::flush_after_apply();
 }
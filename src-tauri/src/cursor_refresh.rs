@@ -0,0 +1,114 @@
+//! Work around Windows showing stale cursors after an apply.
+//!
+//! `SetSystemCursor` updates the cursor table, but some shells and apps keep their own cached
+//! handles (and the icon cache can hold on to old images) until the next reboot. Two remedies:
+//! an opt-in post-apply broadcast of the cursor setting change, and `force_cursor_refresh`,
+//! which reloads every role from scratch for troubleshooting.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use tauri::{AppHandle, Runtime};
+
+use crate::error_codes::{with_code, ErrorCode};
+use crate::state::settings_file::{self, SettingsFile};
+use crate::state::CustomizationMode;
+use crate::system;
+
+pub const CURSOR_REFRESH_FILE_NAME: &str = "cursor-refresh.json";
+
+static FLUSH_AFTER_APPLY: AtomicBool = AtomicBool::new(false);
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CursorRefreshSettings {
+    #[serde(default)]
+    pub flush_after_apply: bool,
+}
+
+impl SettingsFile for CursorRefreshSettings {
+    const FILE_NAME: &'static str = CURSOR_REFRESH_FILE_NAME;
+    const DESCRIPTION: &'static str = "cursor refresh settings";
+}
+
+/// Load the saved setting into memory. Called once at startup.
+pub fn load_refresh_settings<R: Runtime>(app: &AppHandle<R>) {
+    let settings: CursorRefreshSettings = settings_file::load(app);
+    FLUSH_AFTER_APPLY.store(settings.flush_after_apply, Ordering::SeqCst);
+}
+
+pub fn flush_after_apply_enabled() -> bool {
+    FLUSH_AFTER_APPLY.load(Ordering::SeqCst)
+}
+
+pub fn set_flush_after_apply<R: Runtime>(app: &AppHandle<R>, enable: bool) -> Result<(), String> {
+    settings_file::save(
+        app,
+        &CursorRefreshSettings {
+            flush_after_apply: enable,
+        },
+    )?;
+    FLUSH_AFTER_APPLY.store(enable, Ordering::SeqCst);
+    Ok(())
+}
+
+/// Post-apply step; a no-op unless the user opted in.
+pub fn flush_after_apply() {
+    if flush_after_apply_enabled() && !system::broadcast_cursor_setting_change() {
+        cc_warn!("[CursorChanger] Cursor setting change broadcast timed out");
+    }
+}
+
+/// Reload every cursor role: reset all roles to the registry scheme, set each custom role
+/// again with a fresh handle, then tell running apps to drop their cached cursors.
+pub fn force_refresh(
+    mode: CustomizationMode,
+    cursor_paths: &HashMap<String, String>,
+    cursor_size: i32,
+) -> Result<(), String> {
+    system::reload_system_cursors().map_err(|e| {
+        if e.is_timeout() {
+            with_code(ErrorCode::CursorReloadTimeout, e)
+        } else {
+            "Failed to reload system cursors".to_string()
+        }
+    })?;
+
+    if !cursor_paths.is_empty() {
+        match mode {
+            CustomizationMode::Simple => {
+                crate::cursor_defaults::apply_cursor_paths_simple(cursor_paths, cursor_size)
+            }
+            CustomizationMode::Advanced => {
+                crate::cursor_defaults::apply_cursor_paths_advanced(cursor_paths, cursor_size)
+            }
+        }
+    }
+
+    // The apply above has already broadcast if the post-apply flush is on
+    let already_broadcast = !cursor_paths.is_empty() && flush_after_apply_enabled();
+    if !already_broadcast && !system::broadcast_cursor_setting_change() {
+        cc_warn!("[CursorChanger] Cursor setting change broadcast timed out");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn settings_round_trip_and_default_off() {
+        let temp = tempfile::tempdir().expect("tempdir");
+
+        assert_eq!(
+            settings_file::read(temp.path()),
+            Ok(CursorRefreshSettings::default())
+        );
+
+        let settings = CursorRefreshSettings {
+            flush_after_apply: true,
+        };
+        settings_file::save_to(temp.path(), &settings).expect("write");
+        assert_eq!(settings_file::read(temp.path()), Ok(settings));
+    }
+}
@@ -0,0 +1,422 @@
+//! `--decode-worker`: decoding untrusted cursor files in a separate process.
+//!
+//! Files that are not in the library yet are decoded in-process by default. With decode
+//! sandboxing on, each [`DecodeTask`] is run by a short-lived copy of this executable started
+//! with `--decode-worker` instead: previews of pack and download files, the title and author
+//! read from an imported .ani, the details and SVG preview the import dialog shows for a
+//! picked file, and the conversion of an uploaded image or SVG. The request goes in over stdin
+//! (one JSON header line, then the file bytes) and the result comes back over stdout as JSON,
+//! so a decoder crash or exploit stays inside the worker. A worker that crashes, hangs past the
+//! timeout or answers garbage fails that task, not the app.
+//!
+//! The worker drops to low integrity and removes its privileges before it reads the request,
+//! and the app puts it in a job object (killed with the job, memory capped at
+//! [`WORKER_MEMORY_LIMIT`], no child processes) before sending anything.
+
+use base64::Engine;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::process::{Command, Stdio};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Runtime};
+
+use crate::commands::customization::file_ops::browsing::CursorFileDetails;
+use crate::commands::customization::library::AniInfo;
+use crate::state::settings_file::{self, SettingsFile};
+
+pub const DECODE_WORKER_FLAG: &str = "--decode-worker";
+pub const DECODE_SANDBOX_SETTINGS_FILE_NAME: &str = "decode-sandbox.json";
+const WORKER_TIMEOUT: Duration = Duration::from_secs(10);
+const WORKER_POLL: Duration = Duration::from_millis(10);
+/// Largest request or response exchanged with a worker
+const MAX_MESSAGE_BYTES: u64 = 64 * 1024 * 1024;
+/// Memory a worker may commit; room for the largest request and the images decoded from it
+const WORKER_MEMORY_LIMIT: usize = 512 * 1024 * 1024;
+/// Exit code of a worker that could not give up its rights
+const EXIT_SANDBOX_FAILED: i32 = 3;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+#[derive(ts_rs::TS, Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct DecodeSandboxSettings {
+    /// Decode untrusted files (previews, imports and uploads) in a worker process
+    #[serde(default)]
+    pub enabled: bool,
+}
+
+/// Header line of a worker request; the file bytes follow it up to end of input.
+#[derive(Debug, Serialize, Deserialize)]
+struct DecodeRequest {
+    file_name: Option<String>,
+    #[serde(default)]
+    task: DecodeTask,
+    /// The app's SVG resource policy, which the worker does not load itself
+    #[serde(default)]
+    load_system_fonts: bool,
+}
+
+/// What a worker does with the bytes it is sent.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum DecodeTask {
+    /// Preview data URL
+    #[default]
+    Preview,
+    /// Title and author of an .ani
+    AniInfo,
+    /// Import dialog details of the file at `path`, which the worker reads itself
+    Describe { path: String },
+    /// PNG data URL of the SVG at `path`, which the worker reads itself
+    SvgPreview { path: String },
+    /// .cur bytes converted from an uploaded image
+    Convert(UploadConversion),
+}
+
+/// How an uploaded image is converted; see `file_ops::conversion::convert_uploaded_image`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UploadConversion {
+    pub size: u32,
+    pub click_point: Option<(u16, u16)>,
+    pub scale: f32,
+    pub offset_x: i32,
+    pub offset_y: i32,
+}
+
+impl SettingsFile for DecodeSandboxSettings {
+    const FILE_NAME: &'static str = DECODE_SANDBOX_SETTINGS_FILE_NAME;
+    const DESCRIPTION: &'static str = "decode sandbox settings";
+}
+
+pub fn load_settings<R: Runtime>(app: &AppHandle<R>) -> DecodeSandboxSettings {
+    settings_file::load(app)
+}
+
+pub fn save_settings<R: Runtime>(
+    app: &AppHandle<R>,
+    settings: &DecodeSandboxSettings,
+) -> Result<(), String> {
+    settings_file::save(app, settings)?;
+    ENABLED.store(settings.enabled, Ordering::SeqCst);
+    Ok(())
+}
+
+/// Load the saved setting. Called once at startup.
+pub fn install<R: Runtime>(app: &AppHandle<R>) {
+    ENABLED.store(load_settings(app).enabled, Ordering::SeqCst);
+}
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::SeqCst)
+}
+
+/// Entry point of the worker process. Returns the process exit code.
+pub fn run() -> i32 {
+    #[cfg(target_os = "windows")]
+    if cursor_changer::lower_own_token().is_err() {
+        // Never decode with the full token
+        return EXIT_SANDBOX_FAILED;
+    }
+    match serve(std::io::stdin().lock(), std::io::stdout().lock(), run_task) {
+        Ok(()) => 0,
+        Err(_) => 2,
+    }
+}
+
+/// Run `request` in this process; what the worker does for each request.
+fn run_task(request: &DecodeRequest, bytes: &[u8]) -> Result<serde_json::Value, String> {
+    use crate::commands::customization::{file_ops, library};
+
+    crate::svg_resources::apply(&crate::svg_resources::SvgResourceSettings {
+        load_system_fonts: request.load_system_fonts,
+    });
+    let file_name = request.file_name.as_deref();
+    let value = match &request.task {
+        DecodeTask::Preview => {
+            serde_json::to_value(library::cursor_preview_in_process(bytes, file_name)?)
+        }
+        DecodeTask::AniInfo => serde_json::to_value(library::ani_info_in_process(bytes)),
+        DecodeTask::Describe { path } => {
+            serde_json::to_value(file_ops::browsing::read_cursor_file_details(path)?)
+        }
+        DecodeTask::SvgPreview { path } => {
+            serde_json::to_value(file_ops::preview::render_svg_preview(path)?)
+        }
+        DecodeTask::Convert(conversion) => {
+            let cur = file_ops::conversion::convert_uploaded_image(
+                bytes,
+                file_name.unwrap_or_default(),
+                conversion,
+            )?;
+            serde_json::to_value(base64::engine::general_purpose::STANDARD.encode(cur))
+        }
+    };
+    value.map_err(|e| format!("Failed to encode decoder result: {}", e))
+}
+
+/// Answer one request read from `input` on `output`.
+fn serve(
+    input: impl Read,
+    mut output: impl Write,
+    decode: impl FnOnce(&DecodeRequest, &[u8]) -> Result<serde_json::Value, String>,
+) -> std::io::Result<()> {
+    let mut reader = BufReader::new(input.take(MAX_MESSAGE_BYTES));
+    let mut header = String::new();
+    reader.read_line(&mut header)?;
+    let request: DecodeRequest = serde_json::from_str(&header)?;
+    let mut data = Vec::new();
+    reader.read_to_end(&mut data)?;
+
+    let result = decode(&request, &data);
+    serde_json::to_writer(&mut output, &result)?;
+    output.flush()
+}
+
+/// Preview data URL for `bytes`, decoded by a worker process.
+pub fn preview(bytes: &[u8], file_name: Option<&str>) -> Result<String, String> {
+    run_in_worker(DecodeTask::Preview, bytes, file_name)
+}
+
+/// Title and author of the .ani `bytes`, parsed by a worker process.
+pub(crate) fn ani_info(bytes: &[u8]) -> Result<AniInfo, String> {
+    run_in_worker(DecodeTask::AniInfo, bytes, None)
+}
+
+/// Import dialog details of the file at `path`, read by a worker process.
+pub(crate) fn describe(path: &str) -> Result<CursorFileDetails, String> {
+    run_in_worker(
+        DecodeTask::Describe {
+            path: path.to_string(),
+        },
+        &[],
+        None,
+    )
+}
+
+/// PNG data URL of the SVG at `path`, rendered by a worker process.
+pub fn svg_preview(path: &str) -> Result<String, String> {
+    run_in_worker(
+        DecodeTask::SvgPreview {
+            path: path.to_string(),
+        },
+        &[],
+        None,
+    )
+}
+
+/// .cur bytes for the uploaded image `bytes` named `file_name`, converted by a worker process.
+pub fn convert_upload(
+    bytes: &[u8],
+    file_name: &str,
+    conversion: UploadConversion,
+) -> Result<Vec<u8>, String> {
+    let encoded: String = run_in_worker(DecodeTask::Convert(conversion), bytes, Some(file_name))?;
+    base64::engine::general_purpose::STANDARD
+        .decode(encoded)
+        .map_err(|_| "Cursor decoder returned an invalid response".to_string())
+}
+
+fn run_in_worker<T: DeserializeOwned>(
+    task: DecodeTask,
+    bytes: &[u8],
+    file_name: Option<&str>,
+) -> Result<T, String> {
+    let exe =
+        std::env::current_exe().map_err(|e| format!("Failed to locate cursor decoder: {}", e))?;
+    let mut command = Command::new(exe);
+    command.arg(DECODE_WORKER_FLAG);
+    #[cfg(target_os = "windows")]
+    {
+        use std::os::windows::process::CommandExt;
+        const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+        command.creation_flags(CREATE_NO_WINDOW);
+    }
+    let request = DecodeRequest {
+        file_name: file_name.map(str::to_string),
+        task,
+        load_system_fonts: crate::cursor_converter::svg_handler::resource_policy()
+            .load_system_fonts,
+    };
+    exchange(command, &request, bytes, WORKER_TIMEOUT)
+}
+
+/// Send one request to the worker `command` starts and wait up to `timeout` for its answer.
+fn exchange<T: DeserializeOwned>(
+    mut command: Command,
+    request: &DecodeRequest,
+    bytes: &[u8],
+    timeout: Duration,
+) -> Result<T, String> {
+    let header = serde_json::to_string(request)
+        .map_err(|e| format!("Failed to encode decoder request: {}", e))?;
+    let request = [header.as_bytes(), b"\n", bytes].concat();
+
+    let mut child = command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .map_err(|e| format!("Failed to start cursor decoder: {}", e))?;
+    let (Some(mut stdin), Some(stdout)) = (child.stdin.take(), child.stdout.take()) else {
+        let _ = child.kill();
+        return Err("Failed to connect to cursor decoder".to_string());
+    };
+    // Before the request goes out: the worker blocks on stdin until then
+    #[cfg(target_os = "windows")]
+    let _job = {
+        use std::os::windows::io::AsRawHandle;
+        match cursor_changer::confine_process(child.as_raw_handle(), WORKER_MEMORY_LIMIT) {
+            Ok(job) => job,
+            Err(e) => {
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err(format!("Failed to sandbox cursor decoder: {}", e));
+            }
+        }
+    };
+
+    // Write and read on their own threads so a worker that stops reading cannot stall us
+    let writer = std::thread::spawn(move || {
+        let _ = stdin.write_all(&request);
+    });
+    let reader = std::thread::spawn(move || {
+        let mut output = Vec::new();
+        stdout
+            .take(MAX_MESSAGE_BYTES)
+            .read_to_end(&mut output)
+            .map(|_| output)
+    });
+
+    let deadline = Instant::now() + timeout;
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break status,
+            Ok(None) if Instant::now() < deadline => std::thread::sleep(WORKER_POLL),
+            Ok(None) => {
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err("Cursor decoder timed out on this file".to_string());
+            }
+            Err(e) => {
+                let _ = child.kill();
+                return Err(format!("Failed to wait for cursor decoder: {}", e));
+            }
+        }
+    };
+    let _ = writer.join();
+    let output = reader.join().ok().and_then(Result::ok).unwrap_or_default();
+
+    if status.code() == Some(EXIT_SANDBOX_FAILED) {
+        return Err(
+            "Cursor decoder could not drop its rights; the file was not decoded".to_string(),
+        );
+    }
+    if !status.success() {
+        cc_warn!("[CursorChanger] Cursor decoder exited with {}", status);
+        return Err("Cursor decoder crashed on this file; it may be damaged or unsafe".to_string());
+    }
+    read_response(&output)
+}
+
+fn read_response<T: DeserializeOwned>(output: &[u8]) -> Result<T, String> {
+    serde_json::from_slice::<Result<T, String>>(output)
+        .map_err(|_| "Cursor decoder returned an invalid response".to_string())?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    fn request(task: DecodeTask, file_name: Option<&str>, data: &[u8]) -> Vec<u8> {
+        let header = serde_json::to_string(&DecodeRequest {
+            file_name: file_name.map(str::to_string),
+            task,
+            load_system_fonts: false,
+        })
+        .unwrap();
+        [header.as_bytes(), b"\n", data].concat()
+    }
+
+    #[test]
+    fn worker_round_trips_bytes_and_results() {
+        let data = [0u8, 10, 13, 255, b'\n', 7];
+        let mut output = Vec::new();
+        serve(
+            Cursor::new(request(DecodeTask::Preview, Some("arrow.cur"), &data)),
+            &mut output,
+            |request, bytes| {
+                assert_eq!(request.task, DecodeTask::Preview);
+                assert_eq!(bytes, data);
+                let name = request.file_name.as_deref().unwrap_or_default();
+                Ok(format!("{}:{}", name, bytes.len()).into())
+            },
+        )
+        .expect("serve");
+        assert_eq!(read_response(&output), Ok("arrow.cur:6".to_string()));
+
+        let mut output = Vec::new();
+        serve(
+            Cursor::new(request(DecodeTask::AniInfo, None, b"")),
+            &mut output,
+            |request, _| {
+                assert!(request.file_name.is_none());
+                Err("Not a cursor".to_string())
+            },
+        )
+        .expect("serve");
+        assert_eq!(
+            read_response::<String>(&output),
+            Err("Not a cursor".to_string())
+        );
+    }
+
+    #[test]
+    fn tasks_reach_the_worker_and_default_to_previews() {
+        let describe = DecodeTask::Describe {
+            path: "C:\\cursors\\arrow.cur".to_string(),
+        };
+        let mut output = Vec::new();
+        serve(
+            Cursor::new(request(describe.clone(), None, b"")),
+            &mut output,
+            |request, _| {
+                assert_eq!(request.task, describe);
+                Ok(serde_json::json!({ "width": 32 }))
+            },
+        )
+        .expect("serve");
+        assert_eq!(
+            read_response::<serde_json::Value>(&output),
+            Ok(serde_json::json!({ "width": 32 }))
+        );
+
+        let header: DecodeRequest = serde_json::from_str(r#"{"file_name":null}"#).unwrap();
+        assert_eq!(header.task, DecodeTask::Preview);
+        assert!(!header.load_system_fonts);
+    }
+
+    #[test]
+    fn malformed_requests_and_responses_are_rejected() {
+        let mut output = Vec::new();
+        assert!(
+            serve(Cursor::new(b"not json\n".to_vec()), &mut output, |_, _| {
+                Ok(serde_json::Value::Null)
+            })
+            .is_err()
+        );
+        assert!(output.is_empty());
+
+        assert!(read_response::<String>(b"").is_err());
+        assert!(read_response::<String>(b"{\"Ok\":").is_err());
+    }
+
+    #[test]
+    fn sandboxing_is_off_by_default() {
+        let settings: DecodeSandboxSettings = serde_json::from_str("{}").unwrap();
+        assert!(!settings.enabled);
+        assert_eq!(settings, DecodeSandboxSettings::default());
+    }
+}
@@ -0,0 +1,105 @@
+//! Free-space preflight for pack operations.
+//!
+//! Pack import, extraction and export estimate how many bytes they are about to write and
+//! call [`ensure_space`] first, so a nearly full drive fails up front with
+//! [`InsufficientDiskSpace`] instead of halfway through with a write error.
+
+use std::fmt;
+use std::io::{Read, Seek};
+use std::path::{Path, PathBuf};
+
+use zip::ZipArchive;
+
+/// Headroom on top of the estimate for library metadata, previews and filesystem overhead.
+pub const SPACE_MARGIN_BYTES: u64 = 4 * 1024 * 1024;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InsufficientDiskSpace {
+    pub target: PathBuf,
+    pub needed_bytes: u64,
+    pub available_bytes: u64,
+}
+
+impl fmt::Display for InsufficientDiskSpace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Insufficient disk space for {}: {} bytes needed, {} bytes available",
+            self.target.display(),
+            self.needed_bytes,
+            self.available_bytes
+        )
+    }
+}
+
+impl std::error::Error for InsufficientDiskSpace {}
+
+impl From<InsufficientDiskSpace> for String {
+    fn from(err: InsufficientDiskSpace) -> Self {
+        err.to_string()
+    }
+}
+
+/// Compare an estimate (plus [`SPACE_MARGIN_BYTES`]) against the space available.
+pub fn check_space(
+    target: &Path,
+    estimated_bytes: u64,
+    available_bytes: u64,
+) -> Result<(), InsufficientDiskSpace> {
+    let needed_bytes = estimated_bytes.saturating_add(SPACE_MARGIN_BYTES);
+    if needed_bytes > available_bytes {
+        return Err(InsufficientDiskSpace {
+            target: target.to_path_buf(),
+            needed_bytes,
+            available_bytes,
+        });
+    }
+    Ok(())
+}
+
+/// Fail if the volume holding `target` cannot take `estimated_bytes` more.
+///
+/// When the free space cannot be queried the operation is allowed to go ahead.
+pub fn ensure_space(target: &Path, estimated_bytes: u64) -> Result<(), String> {
+    match cursor_changer::available_disk_space(target) {
+        Ok(available) => check_space(target, estimated_bytes, available).map_err(String::from),
+        Err(e) => {
+            cc_warn!("[CursorChanger] Skipping disk space check: {}", e);
+            Ok(())
+        }
+    }
+}
+
+/// Total uncompressed size of the named entries (all file entries when `names` is `None`).
+pub fn zip_uncompressed_size<R: Read + Seek>(
+    archive: &mut ZipArchive<R>,
+    names: Option<&[&str]>,
+) -> u64 {
+    match names {
+        Some(names) => names
+            .iter()
+            .filter_map(|name| archive.by_name(name).ok().map(|entry| entry.size()))
+            .sum(),
+        None => (0..archive.len())
+            .filter_map(|i| archive.by_index(i).ok())
+            .filter(|entry| !entry.is_dir())
+            .map(|entry| entry.size())
+            .sum(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_space_includes_margin_and_reports_both_sizes() {
+        let target = Path::new("C:\\packs");
+        assert!(check_space(target, 1000, 1000 + SPACE_MARGIN_BYTES).is_ok());
+
+        let err = check_space(target, 1001, 1000 + SPACE_MARGIN_BYTES).unwrap_err();
+        assert_eq!(err.needed_bytes, 1001 + SPACE_MARGIN_BYTES);
+        assert_eq!(err.available_bytes, 1000 + SPACE_MARGIN_BYTES);
+        assert!(String::from(err).starts_with("Insufficient disk space"));
+    }
+}
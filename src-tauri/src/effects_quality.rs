@@ -0,0 +1,282 @@
+//! Effect quality and the frame-budget governor.
+//!
+//! Overlay effects share the GPU with whatever is in the foreground, often a game. The user
+//! picks Low, Medium or High, or Auto. Under Auto the overlay reports how long its frames take
+//! and the governor steps the level down when frames run over budget, and back up once they
+//! have been comfortably under it for a while. The overlay draws with the
+//! [`EffectRenderProfile`] of the current level.
+
+use serde::{Deserialize, Serialize};
+use std::sync::Mutex;
+use tauri::{AppHandle, Runtime};
+
+use crate::state::settings_file::{self, SettingsFile};
+
+pub const EFFECT_QUALITY_FILE_NAME: &str = "effect-quality.json";
+
+/// Render time per overlay frame the effects may use (a quarter of a 60 Hz frame)
+const FRAME_BUDGET_MS: f32 = 4.0;
+/// Frames averaged before the governor decides anything
+const WINDOW_FRAMES: usize = 30;
+/// Windows in a row over budget before stepping down
+const DOWNGRADE_AFTER: u32 = 2;
+/// Windows in a row under half the budget before stepping back up
+const UPGRADE_AFTER: u32 = 10;
+
+static GOVERNOR: Mutex<Option<FrameGovernor>> = Mutex::new(None);
+
+#[derive(ts_rs::TS, Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub enum EffectQuality {
+    Low,
+    Medium,
+    High,
+    /// Start at High and let the governor adjust to the frame budget
+    #[default]
+    Auto,
+}
+
+impl EffectQuality {
+    fn lower(self) -> Self {
+        match self {
+            Self::High | Self::Auto => Self::Medium,
+            Self::Medium | Self::Low => Self::Low,
+        }
+    }
+
+    fn higher(self) -> Self {
+        match self {
+            Self::Low => Self::Medium,
+            Self::Medium | Self::High | Self::Auto => Self::High,
+        }
+    }
+}
+
+/// How the overlay should draw effects at one quality level.
+#[derive(ts_rs::TS, Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct EffectRenderProfile {
+    /// Low, Medium or High; never Auto
+    pub level: EffectQuality,
+    /// Segments kept in the cursor trail
+    pub trail_segments: u32,
+    /// Overlay redraws per second
+    pub refresh_hz: u32,
+}
+
+impl EffectRenderProfile {
+    pub fn for_level(level: EffectQuality) -> Self {
+        let (level, trail_segments, refresh_hz) = match level {
+            EffectQuality::Low => (EffectQuality::Low, 6, 30),
+            EffectQuality::Medium => (EffectQuality::Medium, 12, 60),
+            EffectQuality::High | EffectQuality::Auto => (EffectQuality::High, 24, 120),
+        };
+        Self {
+            level,
+            trail_segments,
+            refresh_hz,
+        }
+    }
+}
+
+#[derive(ts_rs::TS, Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct EffectQualitySettings {
+    #[serde(default)]
+    pub quality: EffectQuality,
+}
+
+#[derive(ts_rs::TS, Debug, Clone, PartialEq, Serialize)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct EffectQualityStatus {
+    /// The user's setting
+    pub quality: EffectQuality,
+    /// What the overlay should draw with now
+    pub profile: EffectRenderProfile,
+    /// Average effect render time over the last full window, in milliseconds
+    pub average_frame_ms: Option<f32>,
+}
+
+/// Steps the level between Low and High from reported frame times. Fixed qualities ignore
+/// the reports.
+#[derive(Debug, Clone)]
+struct FrameGovernor {
+    quality: EffectQuality,
+    level: EffectQuality,
+    samples: Vec<f32>,
+    average_ms: Option<f32>,
+    over_budget_windows: u32,
+    under_budget_windows: u32,
+}
+
+impl FrameGovernor {
+    fn new(quality: EffectQuality) -> Self {
+        Self {
+            quality,
+            level: EffectRenderProfile::for_level(quality).level,
+            samples: Vec::with_capacity(WINDOW_FRAMES),
+            average_ms: None,
+            over_budget_windows: 0,
+            under_budget_windows: 0,
+        }
+    }
+
+    /// Feed frame render times. Returns true when the level changed.
+    fn record(&mut self, frame_ms: &[f32]) -> bool {
+        let before = self.level;
+        for &ms in frame_ms.iter().filter(|ms| ms.is_finite() && **ms >= 0.0) {
+            self.samples.push(ms);
+            if self.samples.len() == WINDOW_FRAMES {
+                let average = self.samples.iter().sum::<f32>() / WINDOW_FRAMES as f32;
+                self.samples.clear();
+                self.average_ms = Some(average);
+                if self.quality == EffectQuality::Auto {
+                    self.judge_window(average);
+                }
+            }
+        }
+        self.level != before
+    }
+
+    fn judge_window(&mut self, average_ms: f32) {
+        if average_ms > FRAME_BUDGET_MS {
+            self.under_budget_windows = 0;
+            self.over_budget_windows += 1;
+            if self.over_budget_windows >= DOWNGRADE_AFTER {
+                self.over_budget_windows = 0;
+                self.level = self.level.lower();
+            }
+        } else if average_ms < FRAME_BUDGET_MS / 2.0 {
+            self.over_budget_windows = 0;
+            self.under_budget_windows += 1;
+            if self.under_budget_windows >= UPGRADE_AFTER {
+                self.under_budget_windows = 0;
+                self.level = self.level.higher();
+            }
+        } else {
+            self.over_budget_windows = 0;
+            self.under_budget_windows = 0;
+        }
+    }
+
+    fn status(&self) -> EffectQualityStatus {
+        EffectQualityStatus {
+            quality: self.quality,
+            profile: EffectRenderProfile::for_level(self.level),
+            average_frame_ms: self.average_ms,
+        }
+    }
+}
+
+impl SettingsFile for EffectQualitySettings {
+    const FILE_NAME: &'static str = EFFECT_QUALITY_FILE_NAME;
+    const DESCRIPTION: &'static str = "effect quality settings";
+}
+
+pub fn load_settings<R: Runtime>(app: &AppHandle<R>) -> EffectQualitySettings {
+    settings_file::load(app)
+}
+
+pub fn save_settings<R: Runtime>(
+    app: &AppHandle<R>,
+    settings: &EffectQualitySettings,
+) -> Result<(), String> {
+    settings_file::save(app, settings)
+}
+
+fn with_governor<R: Runtime, T>(app: &AppHandle<R>, f: impl FnOnce(&mut FrameGovernor) -> T) -> T {
+    let mut guard = GOVERNOR.lock().unwrap_or_else(|e| e.into_inner());
+    let governor = guard.get_or_insert_with(|| FrameGovernor::new(load_settings(app).quality));
+    f(governor)
+}
+
+pub fn status<R: Runtime>(app: &AppHandle<R>) -> EffectQualityStatus {
+    with_governor(app, |governor| governor.status())
+}
+
+/// Switch quality. Auto restarts from High with fresh measurements.
+pub fn set_quality<R: Runtime>(app: &AppHandle<R>, quality: EffectQuality) -> EffectQualityStatus {
+    with_governor(app, |governor| {
+        *governor = FrameGovernor::new(quality);
+        governor.status()
+    })
+}
+
+/// Record frame render times from the overlay. The status is returned with whether the
+/// level changed.
+pub fn record_frames<R: Runtime>(
+    app: &AppHandle<R>,
+    frame_ms: &[f32],
+) -> (EffectQualityStatus, bool) {
+    with_governor(app, |governor| {
+        let changed = governor.record(frame_ms);
+        if changed {
+            cc_info!(
+                "[CursorChanger] Effect quality adjusted to {:?} ({:.1} ms per frame)",
+                governor.level,
+                governor.average_ms.unwrap_or_default()
+            );
+        }
+        (governor.status(), changed)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn windows(governor: &mut FrameGovernor, ms: f32, count: usize) -> bool {
+        governor.record(&vec![ms; WINDOW_FRAMES * count])
+    }
+
+    #[test]
+    fn auto_steps_down_under_sustained_load_and_recovers_slowly() {
+        let mut governor = FrameGovernor::new(EffectQuality::Auto);
+        assert_eq!(governor.level, EffectQuality::High);
+
+        // One slow window is not enough
+        assert!(!windows(&mut governor, 9.0, 1));
+        assert!(windows(&mut governor, 9.0, 1));
+        assert_eq!(governor.level, EffectQuality::Medium);
+        windows(&mut governor, 9.0, 10);
+        assert_eq!(governor.level, EffectQuality::Low);
+
+        windows(&mut governor, 1.0, UPGRADE_AFTER as usize - 1);
+        assert_eq!(governor.level, EffectQuality::Low);
+        assert!(windows(&mut governor, 1.0, 1));
+        assert_eq!(governor.level, EffectQuality::Medium);
+        assert_eq!(governor.status().average_frame_ms, Some(1.0));
+    }
+
+    #[test]
+    fn frames_near_the_budget_hold_the_level() {
+        let mut governor = FrameGovernor::new(EffectQuality::Auto);
+        windows(&mut governor, 9.0, 1);
+        windows(&mut governor, 3.0, 1);
+        windows(&mut governor, 9.0, 1);
+        assert_eq!(governor.level, EffectQuality::High);
+    }
+
+    #[test]
+    fn fixed_quality_ignores_frame_times() {
+        let mut governor = FrameGovernor::new(EffectQuality::Medium);
+        assert!(!windows(&mut governor, 50.0, 20));
+        assert_eq!(
+            governor.status().profile,
+            EffectRenderProfile::for_level(EffectQuality::Medium)
+        );
+        // Bad samples are dropped
+        assert!(!governor.record(&[f32::NAN, -1.0]));
+    }
+
+    #[test]
+    fn quality_defaults_to_auto() {
+        let settings: EffectQualitySettings = serde_json::from_str("{}").unwrap();
+        assert_eq!(settings.quality, EffectQuality::Auto);
+        assert_eq!(
+            EffectRenderProfile::for_level(EffectQuality::Auto).level,
+            EffectQuality::High
+        );
+    }
+}
@@ -0,0 +1,336 @@
+//! Stable error codes and their translations.
+//!
+//! Commands still return `Result<_, String>`, but errors the user can act on are prefixed with
+//! a code (`"E-LIB-001: Library is busy: ..."`). Logs keep that English string as is; the
+//! frontend passes it to `localize_error` for a translated message and a hint on what to try.
+//! Codes never change meaning once released; retire a code rather than reuse it.
+
+use serde::Serialize;
+
+/// Locale used when the requested one has no table
+pub const FALLBACK_LOCALE: &str = "en";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorCode {
+    /// Windows refused to set a cursor from a file
+    CursorApply,
+    CursorHide,
+    CursorRestore,
+    /// Reloading the cursor scheme hung, typically on a shell extension in the hook chain
+    CursorReloadTimeout,
+    /// Windows could not reload the cursor scheme from the registry
+    RegistryReload,
+    /// Another instance held the library lock too long
+    LibraryBusy,
+    LibraryLock,
+    LibraryRead,
+    /// Library written by a newer build
+    LibraryNewerVersion,
+    PackInvalid,
+    PackDownload,
+    PackTooLarge,
+}
+
+impl ErrorCode {
+    pub const ALL: [Self; 12] = [
+        Self::CursorApply,
+        Self::CursorHide,
+        Self::CursorRestore,
+        Self::CursorReloadTimeout,
+        Self::RegistryReload,
+        Self::LibraryBusy,
+        Self::LibraryLock,
+        Self::LibraryRead,
+        Self::LibraryNewerVersion,
+        Self::PackInvalid,
+        Self::PackDownload,
+        Self::PackTooLarge,
+    ];
+
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::CursorApply => "E-CUR-001",
+            Self::CursorHide => "E-CUR-002",
+            Self::CursorRestore => "E-CUR-003",
+            Self::CursorReloadTimeout => "E-CUR-004",
+            Self::RegistryReload => "E-REG-001",
+            Self::LibraryBusy => "E-LIB-001",
+            Self::LibraryLock => "E-LIB-002",
+            Self::LibraryRead => "E-LIB-003",
+            Self::LibraryNewerVersion => "E-LIB-004",
+            Self::PackInvalid => "E-PACK-001",
+            Self::PackDownload => "E-PACK-002",
+            Self::PackTooLarge => "E-PACK-003",
+        }
+    }
+
+    pub fn parse(code: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|c| c.as_str() == code)
+    }
+}
+
+/// `detail` prefixed with `code`, as returned by commands.
+pub fn with_code(code: ErrorCode, detail: impl std::fmt::Display) -> String {
+    format!("{}: {}", code.as_str(), detail)
+}
+
+/// The code of a command error and the English detail after it.
+pub fn split_code(message: &str) -> (Option<ErrorCode>, &str) {
+    message
+        .split_once(": ")
+        .and_then(|(code, detail)| Some((Some(ErrorCode::parse(code)?), detail)))
+        .unwrap_or((None, message))
+}
+
+#[derive(ts_rs::TS, Debug, Clone, PartialEq, Eq, Serialize)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct LocalizedError {
+    /// e.g. `"E-REG-001"`; `None` for errors without a code
+    pub code: Option<String>,
+    /// Translated message, or the English detail for errors without a code
+    pub message: String,
+    /// What the user can try next
+    pub hint: Option<String>,
+    /// English detail, for logs and bug reports
+    pub detail: String,
+}
+
+/// `(message, hint)` for `code` in `language`, if translated.
+fn text(code: ErrorCode, language: &str) -> Option<(&'static str, &'static str)> {
+    use ErrorCode::*;
+    let text = match (language, code) {
+        ("en", CursorApply) => (
+            "Windows refused to apply the cursor",
+            "Try closing other cursor tools, then apply again",
+        ),
+        ("en", CursorHide) => (
+            "The cursor could not be hidden",
+            "Try closing other cursor tools, then try again",
+        ),
+        ("en", CursorRestore) => (
+            "The cursor could not be restored",
+            "Choose Reset cursors in Settings, or sign out and back in",
+        ),
+        ("en", CursorReloadTimeout) => (
+            "Windows stopped responding while reloading the cursors",
+            "Another program is blocking cursor changes; restart Windows Explorer or sign out and back in",
+        ),
+        ("en", RegistryReload) => (
+            "Windows could not reload the cursor scheme",
+            "Try closing other cursor tools, or sign out and back in",
+        ),
+        ("en", LibraryBusy) => (
+            "The library is busy",
+            "Another Cursor Changer window is saving it; try again in a moment",
+        ),
+        ("en", LibraryLock) => (
+            "The library could not be locked",
+            "Check that the library folder is not read-only",
+        ),
+        ("en", LibraryRead) => (
+            "The library could not be read",
+            "Check that the library file exists and is not open in another program",
+        ),
+        ("en", LibraryNewerVersion) => (
+            "The library was saved by a newer version",
+            "Update Cursor Changer to open this library",
+        ),
+        ("en", PackInvalid) => (
+            "This is not a valid cursor pack",
+            "A pack is a .zip of .cur or .ani files named after cursor roles",
+        ),
+        ("en", PackDownload) => (
+            "The cursor pack could not be downloaded",
+            "Check your connection and the link, then try again",
+        ),
+        ("en", PackTooLarge) => (
+            "The cursor pack is too large",
+            "Download it in a browser and check what it contains",
+        ),
+
+        ("de", CursorApply) => (
+            "Windows hat den Cursor nicht übernommen",
+            "Schließe andere Cursor-Programme und versuche es erneut",
+        ),
+        ("de", CursorHide) => (
+            "Der Cursor konnte nicht ausgeblendet werden",
+            "Schließe andere Cursor-Programme und versuche es erneut",
+        ),
+        ("de", CursorRestore) => (
+            "Der Cursor konnte nicht wiederhergestellt werden",
+            "Wähle in den Einstellungen „Cursor zurücksetzen“ oder melde dich neu an",
+        ),
+        ("de", CursorReloadTimeout) => (
+            "Windows reagiert beim Neuladen der Cursor nicht mehr",
+            "Ein anderes Programm blockiert Cursoränderungen; starte den Windows-Explorer neu oder melde dich neu an",
+        ),
+        ("de", RegistryReload) => (
+            "Windows konnte das Cursorschema nicht neu laden",
+            "Schließe andere Cursor-Programme oder melde dich neu an",
+        ),
+        ("de", LibraryBusy) => (
+            "Die Bibliothek ist gerade belegt",
+            "Ein anderes Cursor-Changer-Fenster speichert sie; versuche es gleich noch einmal",
+        ),
+        ("de", LibraryLock) => (
+            "Die Bibliothek konnte nicht gesperrt werden",
+            "Prüfe, ob der Bibliotheksordner schreibgeschützt ist",
+        ),
+        ("de", LibraryRead) => (
+            "Die Bibliothek konnte nicht gelesen werden",
+            "Prüfe, ob die Bibliotheksdatei existiert und nicht in einem anderen Programm geöffnet ist",
+        ),
+        ("de", LibraryNewerVersion) => (
+            "Die Bibliothek wurde von einer neueren Version gespeichert",
+            "Aktualisiere Cursor Changer, um diese Bibliothek zu öffnen",
+        ),
+        ("de", PackInvalid) => (
+            "Das ist kein gültiges Cursorpaket",
+            "Ein Paket ist eine .zip-Datei mit .cur- oder .ani-Dateien, benannt nach Cursorrollen",
+        ),
+        ("de", PackDownload) => (
+            "Das Cursorpaket konnte nicht heruntergeladen werden",
+            "Prüfe deine Verbindung und den Link und versuche es erneut",
+        ),
+        ("de", PackTooLarge) => (
+            "Das Cursorpaket ist zu groß",
+            "Lade es im Browser herunter und prüfe seinen Inhalt",
+        ),
+
+        ("es", CursorApply) => (
+            "Windows no aplicó el cursor",
+            "Cierra otras herramientas de cursores y vuelve a aplicarlo",
+        ),
+        ("es", CursorHide) => (
+            "No se pudo ocultar el cursor",
+            "Cierra otras herramientas de cursores y vuelve a intentarlo",
+        ),
+        ("es", CursorRestore) => (
+            "No se pudo restaurar el cursor",
+            "Elige Restablecer cursores en Ajustes o cierra sesión y vuelve a entrar",
+        ),
+        ("es", CursorReloadTimeout) => (
+            "Windows dejó de responder al recargar los cursores",
+            "Otro programa está bloqueando los cambios de cursor; reinicia el Explorador de Windows o cierra sesión y vuelve a entrar",
+        ),
+        ("es", RegistryReload) => (
+            "Windows no pudo recargar el esquema de cursores",
+            "Cierra otras herramientas de cursores o cierra sesión y vuelve a entrar",
+        ),
+        ("es", LibraryBusy) => (
+            "La biblioteca está ocupada",
+            "Otra ventana de Cursor Changer la está guardando; inténtalo de nuevo en un momento",
+        ),
+        ("es", LibraryLock) => (
+            "No se pudo bloquear la biblioteca",
+            "Comprueba que la carpeta de la biblioteca no sea de solo lectura",
+        ),
+        ("es", LibraryRead) => (
+            "No se pudo leer la biblioteca",
+            "Comprueba que el archivo de la biblioteca existe y no está abierto en otro programa",
+        ),
+        ("es", LibraryNewerVersion) => (
+            "La biblioteca se guardó con una versión más reciente",
+            "Actualiza Cursor Changer para abrir esta biblioteca",
+        ),
+        ("es", PackInvalid) => (
+            "No es un paquete de cursores válido",
+            "Un paquete es un .zip con archivos .cur o .ani con el nombre de cada rol",
+        ),
+        ("es", PackDownload) => (
+            "No se pudo descargar el paquete de cursores",
+            "Comprueba la conexión y el enlace y vuelve a intentarlo",
+        ),
+        ("es", PackTooLarge) => (
+            "El paquete de cursores es demasiado grande",
+            "Descárgalo en el navegador y revisa su contenido",
+        ),
+        _ => return None,
+    };
+    Some(text)
+}
+
+/// Language part of a BCP 47 tag, e.g. `"de"` for `"de-AT"`.
+fn language(locale: &str) -> String {
+    locale
+        .split(['-', '_'])
+        .next()
+        .unwrap_or_default()
+        .to_ascii_lowercase()
+}
+
+/// Translate a command error into `locale`, falling back to English.
+pub fn localize(message: &str, locale: Option<&str>) -> LocalizedError {
+    let (code, detail) = split_code(message);
+    let Some(code) = code else {
+        return LocalizedError {
+            code: None,
+            message: message.to_string(),
+            hint: None,
+            detail: message.to_string(),
+        };
+    };
+    let (text, hint) = locale
+        .and_then(|locale| text(code, &language(locale)))
+        .or_else(|| text(code, FALLBACK_LOCALE))
+        .expect("every code has an English text");
+    LocalizedError {
+        code: Some(code.as_str().to_string()),
+        message: format!("{}: {}", code.as_str(), text),
+        hint: Some(hint.to_string()),
+        detail: detail.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn codes_are_unique_and_round_trip() {
+        for code in ErrorCode::ALL {
+            assert_eq!(ErrorCode::parse(code.as_str()), Some(code));
+            assert_eq!(
+                ErrorCode::ALL
+                    .iter()
+                    .filter(|c| c.as_str() == code.as_str())
+                    .count(),
+                1
+            );
+        }
+    }
+
+    #[test]
+    fn every_code_is_translated() {
+        for code in ErrorCode::ALL {
+            for language in ["en", "de", "es"] {
+                assert!(text(code, language).is_some(), "{code:?} in {language}");
+            }
+        }
+    }
+
+    #[test]
+    fn localize_uses_the_language_and_keeps_the_detail() {
+        let error = with_code(ErrorCode::LibraryBusy, "Library is busy");
+        assert_eq!(error, "E-LIB-001: Library is busy");
+
+        let german = localize(&error, Some("de-AT"));
+        assert_eq!(german.code.as_deref(), Some("E-LIB-001"));
+        assert_eq!(german.message, "E-LIB-001: Die Bibliothek ist gerade belegt");
+        assert_eq!(german.detail, "Library is busy");
+
+        let fallback = localize(&error, Some("ja"));
+        assert_eq!(fallback.message, "E-LIB-001: The library is busy");
+        assert!(fallback.hint.is_some());
+    }
+
+    #[test]
+    fn errors_without_a_code_pass_through() {
+        let plain = localize("Download failed: timed out", Some("de"));
+        assert_eq!(plain.code, None);
+        assert_eq!(plain.message, "Download failed: timed out");
+        assert_eq!(plain.hint, None);
+        // Only known codes count
+        assert_eq!(split_code("E-XYZ-999: nope").0, None);
+    }
+}
@@ -9,6 +9,23 @@ pub const RESET_CURSORS_AFTER_SETTINGS: &str = "reset-cursors-after-settings";
 pub const SHOW_CLOSE_CONFIRMATION: &str = "show-close-confirmation";
 pub const LIBRARY_FILE_ADDED: &str = "library:file-added";
 pub const LIBRARY_FILE_REMOVED: &str = "library:file-removed";
+pub const LIBRARY_IMPORT_QUEUE: &str = "library:import-queue";
+pub const CURSOR_ROLE_RESTORED: &str = "cursor-role-restored";
+pub const PACK_TRIAL_REVERTED: &str = "pack-trial-reverted";
+pub const DEFAULTS_INIT_REPORT: &str = "library:defaults-init-report";
+pub const CACHE_GC_REPORT: &str = "cache-gc-report";
+pub const HOTKEY_CHORD_HINT: &str = "hotkey-chord-hint";
+pub const APPLY_VERIFICATION_FAILED: &str = "apply-verification-failed";
+pub const OPERATION_PROGRESS: &str = "operation-progress";
+pub const OPERATION_CANCELLED: &str = "operation-cancelled";
+pub const CURSOR_SIZE_GESTURE: &str = "cursor-size-gesture";
+pub const CONFIG_VALIDATION_FAILED: &str = "config-validation-failed";
+pub const CURSOR_SOURCE_RELOADED: &str = "cursor-source-reloaded";
+pub const EFFECT_QUALITY_CHANGED: &str = "effect-quality-changed";
+pub const LIBRARY_OVERSIZED_ANI: &str = "library:oversized-ani";
+pub const APPLY_SCHEDULED: &str = "apply-scheduled";
+pub const APPLY_COUNTDOWN: &str = "apply-countdown";
+pub const PACK_UPDATES_AVAILABLE: &str = "pack-updates-available";
 
 #[cfg(test)]
 mod tests {
@@ -23,5 +40,22 @@ mod tests {
         assert_eq!(SHOW_CLOSE_CONFIRMATION, "show-close-confirmation");
         assert_eq!(LIBRARY_FILE_ADDED, "library:file-added");
         assert_eq!(LIBRARY_FILE_REMOVED, "library:file-removed");
+        assert_eq!(LIBRARY_IMPORT_QUEUE, "library:import-queue");
+        assert_eq!(CURSOR_ROLE_RESTORED, "cursor-role-restored");
+        assert_eq!(PACK_TRIAL_REVERTED, "pack-trial-reverted");
+        assert_eq!(DEFAULTS_INIT_REPORT, "library:defaults-init-report");
+        assert_eq!(CACHE_GC_REPORT, "cache-gc-report");
+        assert_eq!(HOTKEY_CHORD_HINT, "hotkey-chord-hint");
+        assert_eq!(APPLY_VERIFICATION_FAILED, "apply-verification-failed");
+        assert_eq!(OPERATION_PROGRESS, "operation-progress");
+        assert_eq!(OPERATION_CANCELLED, "operation-cancelled");
+        assert_eq!(CURSOR_SIZE_GESTURE, "cursor-size-gesture");
+        assert_eq!(CONFIG_VALIDATION_FAILED, "config-validation-failed");
+        assert_eq!(CURSOR_SOURCE_RELOADED, "cursor-source-reloaded");
+        assert_eq!(EFFECT_QUALITY_CHANGED, "effect-quality-changed");
+        assert_eq!(LIBRARY_OVERSIZED_ANI, "library:oversized-ani");
+        assert_eq!(APPLY_SCHEDULED, "apply-scheduled");
+        assert_eq!(APPLY_COUNTDOWN, "apply-countdown");
+        assert_eq!(PACK_UPDATES_AVAILABLE, "pack-updates-available");
     }
 }
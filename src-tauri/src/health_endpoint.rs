@@ -0,0 +1,449 @@
+//! Localhost health endpoint for kiosk monitoring.
+//!
+//! When enabled, `GET /health` on `127.0.0.1:<port>` answers with a small JSON report (app
+//! alive, cursor hidden, last apply time) so a monitoring agent can confirm the cursor
+//! configuration is still in force and restart the app when it stops answering. The listener
+//! never binds outside loopback.
+
+use serde::{Deserialize, Serialize};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tauri::{AppHandle, Manager, Runtime};
+
+use crate::state::settings_file::{self, SettingsFile};
+use crate::state::AppState;
+
+pub const HEALTH_ENDPOINT_SETTINGS_FILE_NAME: &str = "health-endpoint.json";
+pub const DEFAULT_HEALTH_PORT: u16 = 47821;
+/// Pause after a failed accept, so a persistent error (out of sockets) cannot spin the thread.
+const ACCEPT_RETRY: Duration = Duration::from_millis(250);
+const CLIENT_TIMEOUT: Duration = Duration::from_secs(2);
+/// Cap on the request line plus headers; longer requests are answered with 431.
+const MAX_REQUEST_HEAD: u64 = 8 * 1024;
+/// Connections served at once. Each has its own thread so a client that stalls cannot hold up
+/// the monitor; past this many, new connections are closed unanswered.
+const MAX_CONNECTIONS: usize = 8;
+
+/// Bumped whenever the listener is restarted; an older listener thread exits on its next
+/// connection.
+static GENERATION: AtomicU64 = AtomicU64::new(0);
+static LISTENER: Mutex<Option<RunningListener>> = Mutex::new(None);
+static STARTED_AT_MS: AtomicU64 = AtomicU64::new(0);
+static LAST_APPLY_MS: AtomicU64 = AtomicU64::new(0);
+
+struct RunningListener {
+    thread: JoinHandle<()>,
+    addr: SocketAddr,
+}
+
+impl RunningListener {
+    /// Stop the thread blocked in `accept`, waiting for it to release the socket.
+    fn stop(self) {
+        // The listener only checks its generation once a connection arrives, so connect to
+        // it ourselves to wake it
+        match TcpStream::connect(self.addr) {
+            Ok(_) => {
+                let _ = self.thread.join();
+            }
+            Err(e) => cc_warn!(
+                "[CursorChanger] Failed to wake the health endpoint to stop it: {}",
+                e
+            ),
+        }
+    }
+}
+
+#[derive(ts_rs::TS, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct HealthEndpointSettings {
+    #[serde(default)]
+    pub enabled: bool,
+    /// Loopback port the endpoint listens on
+    #[serde(default = "default_port")]
+    pub port: u16,
+}
+
+fn default_port() -> u16 {
+    DEFAULT_HEALTH_PORT
+}
+
+impl Default for HealthEndpointSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            port: DEFAULT_HEALTH_PORT,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct HealthReport {
+    pub alive: bool,
+    pub app_enabled: bool,
+    pub hidden: bool,
+    pub started_at_ms: u64,
+    /// When a cursor scheme was last applied this session, as Unix milliseconds
+    pub last_apply_ms: Option<u64>,
+}
+
+impl SettingsFile for HealthEndpointSettings {
+    const FILE_NAME: &'static str = HEALTH_ENDPOINT_SETTINGS_FILE_NAME;
+    const DESCRIPTION: &'static str = "health endpoint settings";
+
+    fn validate(&self) -> Result<(), String> {
+        if self.port < 1024 {
+            return Err("Health endpoint port must be between 1024 and 65535".to_string());
+        }
+        Ok(())
+    }
+}
+
+pub fn load_settings<R: Runtime>(app: &AppHandle<R>) -> HealthEndpointSettings {
+    settings_file::load(app)
+}
+
+pub fn save_settings<R: Runtime>(
+    app: &AppHandle<R>,
+    settings: &HealthEndpointSettings,
+) -> Result<(), String> {
+    settings_file::save(app, settings)
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| u64::try_from(d.as_millis()).unwrap_or(u64::MAX))
+        .unwrap_or(0)
+}
+
+/// Note that a cursor scheme was just applied.
+pub fn record_apply() {
+    LAST_APPLY_MS.store(now_ms(), Ordering::SeqCst);
+}
+
+/// Start the endpoint if it is enabled. Called once at startup.
+pub fn install<R: Runtime>(app: &AppHandle<R>) -> Result<(), String> {
+    STARTED_AT_MS.store(now_ms(), Ordering::SeqCst);
+    restart(app)
+}
+
+/// Stop any running listener and start a new one from the saved settings.
+pub fn restart<R: Runtime>(app: &AppHandle<R>) -> Result<(), String> {
+    let settings = load_settings(app);
+    let app = app.clone();
+    replace_listener(settings.enabled.then_some(settings.port), move || {
+        current_report(&app)
+    })
+}
+
+/// Stop the running listener, waiting for its thread to release the socket, then listen on
+/// `port` if one is given.
+fn replace_listener(
+    port: Option<u16>,
+    report: impl Fn() -> Result<HealthReport, String> + Send + Sync + 'static,
+) -> Result<(), String> {
+    let mut running = LISTENER.lock().map_err(|e| e.to_string())?;
+    let generation = GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+    // The old thread drops its listener before the join returns, so rebinding the same port
+    // cannot fail with "address in use"
+    if let Some(previous) = running.take() {
+        previous.stop();
+    }
+    let Some(port) = port else {
+        return Ok(());
+    };
+
+    let listener = TcpListener::bind(("127.0.0.1", port))
+        .map_err(|e| format!("Failed to listen on port {}: {}", port, e))?;
+    let addr = listener
+        .local_addr()
+        .map_err(|e| format!("Failed to configure health endpoint: {}", e))?;
+
+    let thread = std::thread::Builder::new()
+        .name("health-endpoint".to_string())
+        .spawn(move || {
+            accept_loop(listener, report, || {
+                GENERATION.load(Ordering::SeqCst) == generation
+            })
+        })
+        .map_err(|e| format!("Failed to start health endpoint: {}", e))?;
+    *running = Some(RunningListener { thread, addr });
+
+    cc_info!(
+        "[CursorChanger] Health endpoint listening on http://127.0.0.1:{}/health",
+        port
+    );
+    Ok(())
+}
+
+/// Serve connections until one arrives after `is_current` turns false.
+fn accept_loop(
+    listener: TcpListener,
+    report: impl Fn() -> Result<HealthReport, String> + Send + Sync + 'static,
+    is_current: impl Fn() -> bool,
+) {
+    let report = Arc::new(report);
+    let active = Arc::new(AtomicUsize::new(0));
+    for stream in listener.incoming() {
+        if !is_current() {
+            break;
+        }
+        match stream {
+            Ok(stream) => {
+                if active.fetch_add(1, Ordering::SeqCst) >= MAX_CONNECTIONS {
+                    active.fetch_sub(1, Ordering::SeqCst);
+                    cc_debug!("[CursorChanger] Health endpoint busy; dropping connection");
+                    continue;
+                }
+                let report = Arc::clone(&report);
+                let active_for_thread = Arc::clone(&active);
+                let spawned = std::thread::Builder::new()
+                    .name("health-request".to_string())
+                    .spawn(move || {
+                        if let Err(e) = handle_connection(stream, || report()) {
+                            cc_debug!("[CursorChanger] Health request failed: {}", e);
+                        }
+                        active_for_thread.fetch_sub(1, Ordering::SeqCst);
+                    });
+                if let Err(e) = spawned {
+                    active.fetch_sub(1, Ordering::SeqCst);
+                    cc_warn!("[CursorChanger] Failed to serve health request: {}", e);
+                }
+            }
+            Err(e) => {
+                cc_warn!("[CursorChanger] Health endpoint accept failed: {}", e);
+                std::thread::sleep(ACCEPT_RETRY);
+            }
+        }
+    }
+}
+
+fn current_report<R: Runtime>(app: &AppHandle<R>) -> Result<HealthReport, String> {
+    let state = app.state::<AppState>();
+    let guard = state.read_all()?;
+    let last_apply = LAST_APPLY_MS.load(Ordering::SeqCst);
+    Ok(HealthReport {
+        alive: true,
+        app_enabled: guard.prefs.app_enabled,
+        hidden: guard.cursor.visibility.is_hidden(),
+        started_at_ms: STARTED_AT_MS.load(Ordering::SeqCst),
+        last_apply_ms: (last_apply != 0).then_some(last_apply),
+    })
+}
+
+fn handle_connection(
+    stream: TcpStream,
+    report: impl FnOnce() -> Result<HealthReport, String>,
+) -> std::io::Result<()> {
+    stream.set_read_timeout(Some(CLIENT_TIMEOUT))?;
+    stream.set_write_timeout(Some(CLIENT_TIMEOUT))?;
+
+    let response = match read_request_head(&stream)? {
+        Some(request_line) => respond(&request_line, report),
+        None => http_response(
+            "431 Request Header Fields Too Large",
+            r#"{"error":"request too large"}"#,
+            false,
+        ),
+    };
+    (&stream).write_all(response.as_bytes())?;
+    (&stream).flush()
+}
+
+/// Read the request line, then the headers through the blank line that ends them, so closing
+/// the socket after answering cannot reset a client still sending. `None` when the head runs
+/// past [`MAX_REQUEST_HEAD`].
+fn read_request_head(stream: &TcpStream) -> std::io::Result<Option<String>> {
+    let mut reader = BufReader::new(stream).take(MAX_REQUEST_HEAD);
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+
+    let mut line = request_line.clone();
+    loop {
+        // A line without its newline is the end of the stream or of the size cap
+        if !line.ends_with('\n') {
+            return Ok((reader.limit() > 0).then_some(request_line));
+        }
+        line.clear();
+        reader.read_line(&mut line)?;
+        if line == "\r\n" || line == "\n" {
+            return Ok(Some(request_line));
+        }
+    }
+}
+
+/// Full HTTP response for a request line such as `GET /health HTTP/1.1`.
+fn respond(request_line: &str, report: impl FnOnce() -> Result<HealthReport, String>) -> String {
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().unwrap_or_default();
+    let target = parts.next().unwrap_or_default();
+    let path = target.split('?').next().unwrap_or_default();
+
+    let (status, body) = if path != "/health" {
+        ("404 Not Found", r#"{"error":"not found"}"#.to_string())
+    } else if method != "GET" && method != "HEAD" {
+        (
+            "405 Method Not Allowed",
+            r#"{"error":"method not allowed"}"#.to_string(),
+        )
+    } else {
+        match report().and_then(|r| serde_json::to_string(&r).map_err(|e| e.to_string())) {
+            Ok(json) => ("200 OK", json),
+            // The process answered but its state is unusable; monitors should restart it
+            Err(_) => ("503 Service Unavailable", r#"{"alive":false}"#.to_string()),
+        }
+    };
+
+    http_response(status, &body, method == "HEAD")
+}
+
+fn http_response(status: &str, body: &str, head_only: bool) -> String {
+    let mut response = format!(
+        "HTTP/1.1 {status}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nCache-Control: no-store\r\nConnection: close\r\n\r\n",
+        body.len()
+    );
+    if !head_only {
+        response.push_str(body);
+    }
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report() -> Result<HealthReport, String> {
+        Ok(HealthReport {
+            alive: true,
+            app_enabled: true,
+            hidden: true,
+            started_at_ms: 1_000,
+            last_apply_ms: Some(2_000),
+        })
+    }
+
+    fn body(response: &str) -> serde_json::Value {
+        let (_, body) = response.split_once("\r\n\r\n").expect("header end");
+        serde_json::from_str(body).expect("json body")
+    }
+
+    #[test]
+    fn health_reports_state_as_json() {
+        let response = respond("GET /health?probe=1 HTTP/1.1\r\n", report);
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+        let value = body(&response);
+        assert_eq!(value["alive"], true);
+        assert_eq!(value["hidden"], true);
+        assert_eq!(value["last_apply_ms"], 2_000);
+
+        let head = respond("HEAD /health HTTP/1.1\r\n", report);
+        assert!(head.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(head.ends_with("\r\n\r\n"));
+    }
+
+    #[test]
+    fn other_paths_methods_and_failures_are_rejected() {
+        assert!(respond("GET / HTTP/1.1", report).starts_with("HTTP/1.1 404 "));
+        assert!(respond("", report).starts_with("HTTP/1.1 404 "));
+        assert!(respond("POST /health HTTP/1.1", report).starts_with("HTTP/1.1 405 "));
+
+        let failed = respond("GET /health HTTP/1.1", || Err("poisoned".to_string()));
+        assert!(failed.starts_with("HTTP/1.1 503 "));
+        assert_eq!(body(&failed)["alive"], false);
+    }
+
+    #[test]
+    fn serves_a_request_over_loopback() {
+        let listener = TcpListener::bind(("127.0.0.1", 0)).expect("bind");
+        let addr = listener.local_addr().unwrap();
+        let client = std::thread::spawn(move || {
+            let mut stream = TcpStream::connect(addr).expect("connect");
+            stream
+                .write_all(b"GET /health HTTP/1.1\r\nHost: localhost\r\n\r\n")
+                .unwrap();
+            let mut response = String::new();
+            stream.read_to_string(&mut response).unwrap();
+            response
+        });
+
+        let (stream, _) = listener.accept().expect("accept");
+        handle_connection(stream, report).expect("handle");
+        let response = client.join().unwrap();
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert_eq!(body(&response)["app_enabled"], true);
+    }
+
+    #[test]
+    fn oversized_requests_are_answered_with_431() {
+        let listener = TcpListener::bind(("127.0.0.1", 0)).expect("bind");
+        let addr = listener.local_addr().unwrap();
+        let client = std::thread::spawn(move || {
+            let mut stream = TcpStream::connect(addr).expect("connect");
+            let mut request = b"GET /health HTTP/1.1\r\nX-Padding: ".to_vec();
+            request.resize(MAX_REQUEST_HEAD as usize, b'a');
+            stream.write_all(&request).unwrap();
+            let mut response = String::new();
+            stream.read_to_string(&mut response).unwrap();
+            response
+        });
+
+        let (stream, _) = listener.accept().expect("accept");
+        handle_connection(stream, report).expect("handle");
+        assert!(client.join().unwrap().starts_with("HTTP/1.1 431 "));
+    }
+
+    #[test]
+    fn a_stalled_client_does_not_hold_up_other_requests() {
+        let listener = TcpListener::bind(("127.0.0.1", 0)).expect("bind");
+        let addr = listener.local_addr().unwrap();
+        std::thread::spawn(move || accept_loop(listener, report, || true));
+
+        // Connects and never sends its request
+        let _stalled = TcpStream::connect(addr).expect("connect");
+        let mut stream = TcpStream::connect(addr).expect("connect");
+        stream.set_read_timeout(Some(CLIENT_TIMEOUT / 2)).unwrap();
+        stream.write_all(b"GET /health HTTP/1.1\r\n\r\n").unwrap();
+        let mut response = String::new();
+        stream
+            .read_to_string(&mut response)
+            .expect("answered while the other client stalls");
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+    }
+
+    #[test]
+    fn restarting_on_the_same_port_rebinds_after_the_old_listener_stops() {
+        let port = TcpListener::bind(("127.0.0.1", 0))
+            .and_then(|probe| probe.local_addr())
+            .expect("free port")
+            .port();
+
+        replace_listener(Some(port), report).expect("first start");
+        replace_listener(Some(port), report).expect("restart on the same port");
+
+        let mut stream = TcpStream::connect(("127.0.0.1", port)).expect("connect");
+        stream.write_all(b"GET /health HTTP/1.1\r\n\r\n").unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+
+        replace_listener(None, report).expect("stop");
+        assert!(TcpListener::bind(("127.0.0.1", port)).is_ok());
+    }
+
+    #[test]
+    fn settings_default_to_disabled_and_reject_privileged_ports() {
+        let settings: HealthEndpointSettings = serde_json::from_str("{}").unwrap();
+        assert_eq!(settings, HealthEndpointSettings::default());
+        assert_eq!(settings.port, DEFAULT_HEALTH_PORT);
+        assert!(HealthEndpointSettings {
+            enabled: true,
+            port: 80
+        }
+        .validate()
+        .is_err());
+    }
+}
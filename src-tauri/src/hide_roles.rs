@@ -0,0 +1,129 @@
+//! Which cursor roles hiding replaces with the blank cursor.
+//!
+//! By default hiding leaves the text-select (IBeam) cursor alone, so the pointer still shows
+//! where a click lands in a text field. Users can keep other roles visible as well, or turn on
+//! strict mode to blank every role.
+
+use cursor_changer::BlankRoleMask;
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicU32, Ordering};
+use tauri::{AppHandle, Runtime};
+
+use crate::state::settings_file::{self, SettingsFile};
+
+pub const HIDE_ROLES_SETTINGS_FILE_NAME: &str = "hide-roles.json";
+
+static MASK: AtomicU32 = AtomicU32::new(BlankRoleMask::DEFAULT.bits());
+
+#[derive(ts_rs::TS, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct HideRolesSettings {
+    /// Hide every cursor role, ignoring `visible_roles`
+    #[serde(default)]
+    pub strict: bool,
+    /// Cursor roles (by name) that stay visible while the cursor is hidden
+    #[serde(default = "default_visible_roles")]
+    pub visible_roles: Vec<String>,
+}
+
+fn default_visible_roles() -> Vec<String> {
+    vec!["IBeam".to_string()]
+}
+
+impl Default for HideRolesSettings {
+    fn default() -> Self {
+        Self {
+            strict: false,
+            visible_roles: default_visible_roles(),
+        }
+    }
+}
+
+impl HideRolesSettings {
+    /// Roles hiding blanks under these settings.
+    pub fn mask(&self) -> BlankRoleMask {
+        if self.strict {
+            BlankRoleMask::ALL
+        } else {
+            BlankRoleMask::all_except(self.visible_roles.iter().map(String::as_str))
+        }
+    }
+}
+
+impl SettingsFile for HideRolesSettings {
+    const FILE_NAME: &'static str = HIDE_ROLES_SETTINGS_FILE_NAME;
+    const DESCRIPTION: &'static str = "hide role settings";
+
+    fn validate(&self) -> Result<(), String> {
+        for name in &self.visible_roles {
+            if cursor_changer::find_cursor_type(name).is_none() {
+                return Err(format!("Unknown cursor role: {}", name));
+            }
+            if name == "Normal" {
+                return Err("The Normal pointer cannot stay visible while hidden".to_string());
+            }
+        }
+        Ok(())
+    }
+}
+
+pub fn load_settings<R: Runtime>(app: &AppHandle<R>) -> HideRolesSettings {
+    settings_file::load(app)
+}
+
+pub fn save_settings<R: Runtime>(
+    app: &AppHandle<R>,
+    settings: &HideRolesSettings,
+) -> Result<(), String> {
+    settings_file::save(app, settings)?;
+    MASK.store(settings.mask().bits(), Ordering::SeqCst);
+    Ok(())
+}
+
+/// Load the saved roles. Called once at startup.
+pub fn install<R: Runtime>(app: &AppHandle<R>) {
+    MASK.store(load_settings(app).mask().bits(), Ordering::SeqCst);
+}
+
+/// Roles the next hide should blank.
+pub fn current_mask() -> BlankRoleMask {
+    BlankRoleMask::from_bits(MASK.load(Ordering::SeqCst))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn defaults_keep_only_the_text_cursor_visible() {
+        let settings: HideRolesSettings = serde_json::from_str("{}").unwrap();
+        assert_eq!(settings, HideRolesSettings::default());
+        assert_eq!(settings.mask(), BlankRoleMask::DEFAULT);
+        assert_eq!(current_mask(), BlankRoleMask::DEFAULT);
+    }
+
+    #[test]
+    fn strict_mode_hides_every_role() {
+        let settings = HideRolesSettings {
+            strict: true,
+            visible_roles: vec!["IBeam".to_string(), "Hand".to_string()],
+        };
+        assert_eq!(settings.mask(), BlankRoleMask::ALL);
+
+        let relaxed = HideRolesSettings {
+            strict: false,
+            ..settings
+        };
+        assert!(!relaxed.mask().names().any(|name| name == "Hand"));
+    }
+
+    #[test]
+    fn validation_rejects_unknown_roles_and_the_normal_pointer() {
+        let mut settings = HideRolesSettings::default();
+        assert!(settings.validate().is_ok());
+        settings.visible_roles.push("Sparkles".to_string());
+        assert!(settings.validate().is_err());
+        settings.visible_roles = vec!["Normal".to_string()];
+        assert!(settings.validate().is_err());
+    }
+}
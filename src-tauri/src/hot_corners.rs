@@ -0,0 +1,341 @@
+//! Hot corners: resting the pointer in a screen corner triggers an action.
+//!
+//! When enabled, a poller checks the pointer position every `POLL_INTERVAL`. Each monitor has
+//! four corners, a square of `corner_size_px` pixels. Once the pointer has stayed inside a
+//! corner for `dwell_ms`, that corner's action runs, and it runs again only after the pointer has
+//! left the corner. Corners can hide or show the cursor or open the quick menu at the pointer.
+
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Runtime};
+
+use cursor_changer::win_mouse::{self, MonitorRect};
+
+use crate::state::settings_file::{self, SettingsFile};
+use crate::timers::{self, TimerPriority};
+
+pub const HOT_CORNERS_FILE_NAME: &str = "hot-corners.json";
+pub const DEFAULT_DWELL_MS: u32 = 500;
+pub const MIN_DWELL_MS: u32 = 100;
+pub const MAX_DWELL_MS: u32 = 5000;
+pub const DEFAULT_CORNER_SIZE_PX: u32 = 4;
+pub const MAX_CORNER_SIZE_PX: u32 = 64;
+/// How often the pointer position is read while any corner has an action.
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Bumped by every `install`; a poller stops once it no longer matches.
+static GENERATION: AtomicU64 = AtomicU64::new(0);
+
+#[derive(ts_rs::TS, Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub enum HotCornerAction {
+    #[default]
+    None,
+    /// Hide or show the cursor
+    ToggleCursor,
+    /// Open the quick menu at the pointer
+    QuickMenu,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ScreenCorner {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+#[derive(ts_rs::TS, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct HotCornerSettings {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default)]
+    pub top_left: HotCornerAction,
+    #[serde(default)]
+    pub top_right: HotCornerAction,
+    #[serde(default)]
+    pub bottom_left: HotCornerAction,
+    #[serde(default)]
+    pub bottom_right: HotCornerAction,
+    /// How long the pointer must rest in a corner before its action runs
+    #[serde(default = "default_dwell_ms")]
+    pub dwell_ms: u32,
+    /// Side of the square that counts as the corner; larger is easier to hit
+    #[serde(default = "default_corner_size_px")]
+    pub corner_size_px: u32,
+}
+
+fn default_dwell_ms() -> u32 {
+    DEFAULT_DWELL_MS
+}
+
+fn default_corner_size_px() -> u32 {
+    DEFAULT_CORNER_SIZE_PX
+}
+
+impl Default for HotCornerSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            top_left: HotCornerAction::None,
+            top_right: HotCornerAction::None,
+            bottom_left: HotCornerAction::None,
+            bottom_right: HotCornerAction::None,
+            dwell_ms: DEFAULT_DWELL_MS,
+            corner_size_px: DEFAULT_CORNER_SIZE_PX,
+        }
+    }
+}
+
+impl HotCornerSettings {
+    fn action(&self, corner: ScreenCorner) -> HotCornerAction {
+        match corner {
+            ScreenCorner::TopLeft => self.top_left,
+            ScreenCorner::TopRight => self.top_right,
+            ScreenCorner::BottomLeft => self.bottom_left,
+            ScreenCorner::BottomRight => self.bottom_right,
+        }
+    }
+
+    fn any_action(&self) -> bool {
+        [
+            self.top_left,
+            self.top_right,
+            self.bottom_left,
+            self.bottom_right,
+        ]
+        .iter()
+        .any(|&action| action != HotCornerAction::None)
+    }
+}
+
+impl SettingsFile for HotCornerSettings {
+    const FILE_NAME: &'static str = HOT_CORNERS_FILE_NAME;
+    const DESCRIPTION: &'static str = "hot corner settings";
+
+    fn validate(&self) -> Result<(), String> {
+        if !(MIN_DWELL_MS..=MAX_DWELL_MS).contains(&self.dwell_ms) {
+            return Err(format!(
+                "Dwell time must be between {} and {} ms",
+                MIN_DWELL_MS, MAX_DWELL_MS
+            ));
+        }
+        if self.corner_size_px == 0 || self.corner_size_px > MAX_CORNER_SIZE_PX {
+            return Err(format!(
+                "Corner size must be between 1 and {} pixels",
+                MAX_CORNER_SIZE_PX
+            ));
+        }
+        Ok(())
+    }
+}
+
+pub fn load_settings<R: Runtime>(app: &AppHandle<R>) -> HotCornerSettings {
+    settings_file::load(app)
+}
+
+pub fn save_settings<R: Runtime>(
+    app: &AppHandle<R>,
+    settings: &HotCornerSettings,
+) -> Result<(), String> {
+    settings_file::save(app, settings)
+}
+
+/// The corner of `monitor` the point `x`, `y` is in, if any.
+fn corner_at(x: i32, y: i32, monitor: MonitorRect, size_px: u32) -> Option<ScreenCorner> {
+    let size = i32::try_from(size_px).unwrap_or(i32::MAX);
+    let left = x < monitor.left.saturating_add(size);
+    let right = x >= monitor.right.saturating_sub(size);
+    let top = y < monitor.top.saturating_add(size);
+    let bottom = y >= monitor.bottom.saturating_sub(size);
+    match (left, right, top, bottom) {
+        (true, _, true, _) => Some(ScreenCorner::TopLeft),
+        (_, true, true, _) => Some(ScreenCorner::TopRight),
+        (true, _, _, true) => Some(ScreenCorner::BottomLeft),
+        (_, true, _, true) => Some(ScreenCorner::BottomRight),
+        _ => None,
+    }
+}
+
+/// Which corner the pointer is resting in, and whether its action already ran.
+#[derive(Debug, Default)]
+struct DwellTracker {
+    current: Option<(ScreenCorner, Instant)>,
+    fired: bool,
+}
+
+impl DwellTracker {
+    /// Record where the pointer is at `now`. Returns the corner whose action should run.
+    fn update(
+        &mut self,
+        corner: Option<ScreenCorner>,
+        now: Instant,
+        dwell: Duration,
+    ) -> Option<ScreenCorner> {
+        let Some(corner) = corner else {
+            self.current = None;
+            return None;
+        };
+        match self.current {
+            Some((current, _)) if current == corner => {}
+            _ => {
+                self.current = Some((corner, now));
+                self.fired = false;
+            }
+        }
+        let (_, since) = self.current?;
+        if self.fired || now.saturating_duration_since(since) < dwell {
+            return None;
+        }
+        self.fired = true;
+        Some(corner)
+    }
+}
+
+fn run_action(app: &AppHandle, action: HotCornerAction) {
+    match action {
+        HotCornerAction::None => {}
+        HotCornerAction::ToggleCursor => crate::hotkey_press::toggle_cursor(app),
+        HotCornerAction::QuickMenu => {
+            if let Err(e) = crate::tray::show_quick_menu(app) {
+                cc_warn!("[CursorChanger] Failed to open the quick menu: {}", e);
+            }
+        }
+    }
+}
+
+/// Start or stop the corner poller to match the saved settings. Called at startup and whenever
+/// the settings change.
+pub fn install(app: &AppHandle) -> Result<(), String> {
+    let settings = load_settings(app);
+    // Any running poller sees the new generation and stops
+    let generation = GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+    if !settings.enabled || !settings.any_action() {
+        return Ok(());
+    }
+
+    let app = app.clone();
+    let dwell = Duration::from_millis(u64::from(settings.dwell_ms));
+    let mut tracker = DwellTracker::default();
+    // Essential: a corner has to answer on time even with battery saver on
+    timers::every_while(
+        POLL_INTERVAL,
+        POLL_INTERVAL,
+        TimerPriority::Essential,
+        move || {
+            if GENERATION.load(Ordering::SeqCst) != generation {
+                return false;
+            }
+            let corner = win_mouse::pointer_position().and_then(|(x, y)| {
+                let monitor = win_mouse::monitor_rect_at(x, y)?;
+                corner_at(x, y, monitor, settings.corner_size_px)
+            });
+            if let Some(corner) = tracker.update(corner, Instant::now(), dwell) {
+                run_action(&app, settings.action(corner));
+            }
+            true
+        },
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MONITOR: MonitorRect = MonitorRect {
+        left: -1920,
+        top: 0,
+        right: 0,
+        bottom: 1080,
+    };
+
+    #[test]
+    fn corners_are_found_on_any_monitor() {
+        assert_eq!(corner_at(-1920, 0, MONITOR, 4), Some(ScreenCorner::TopLeft));
+        assert_eq!(corner_at(-1, 3, MONITOR, 4), Some(ScreenCorner::TopRight));
+        assert_eq!(
+            corner_at(-1917, 1079, MONITOR, 4),
+            Some(ScreenCorner::BottomLeft)
+        );
+        assert_eq!(
+            corner_at(-1, 1079, MONITOR, 4),
+            Some(ScreenCorner::BottomRight)
+        );
+        // On an edge but outside the corner square
+        assert_eq!(corner_at(-1916, 0, MONITOR, 4), None);
+        assert_eq!(corner_at(-960, 540, MONITOR, 4), None);
+        // A larger corner is easier to hit
+        assert_eq!(
+            corner_at(-1916, 0, MONITOR, 16),
+            Some(ScreenCorner::TopLeft)
+        );
+    }
+
+    #[test]
+    fn action_fires_once_per_visit_after_the_dwell() {
+        let dwell = Duration::from_millis(300);
+        let start = Instant::now();
+        let mut tracker = DwellTracker::default();
+        let top_left = Some(ScreenCorner::TopLeft);
+
+        assert_eq!(tracker.update(top_left, start, dwell), None);
+        assert_eq!(
+            tracker.update(top_left, start + Duration::from_millis(200), dwell),
+            None
+        );
+        assert_eq!(
+            tracker.update(top_left, start + Duration::from_millis(300), dwell),
+            top_left
+        );
+        // Staying put does not repeat it
+        assert_eq!(
+            tracker.update(top_left, start + Duration::from_secs(5), dwell),
+            None
+        );
+
+        // Leaving and coming back starts a new visit
+        let back = start + Duration::from_secs(6);
+        assert_eq!(tracker.update(None, back, dwell), None);
+        assert_eq!(tracker.update(top_left, back, dwell), None);
+        assert_eq!(tracker.update(top_left, back + dwell, dwell), top_left);
+
+        // Moving straight to another corner restarts the dwell
+        let other = back + Duration::from_secs(1);
+        let top_right = Some(ScreenCorner::TopRight);
+        assert_eq!(tracker.update(top_right, other, dwell), None);
+        assert_eq!(tracker.update(top_right, other + dwell, dwell), top_right);
+    }
+
+    #[test]
+    fn settings_default_to_disabled_and_validate() {
+        let settings: HotCornerSettings = serde_json::from_str("{}").unwrap();
+        assert_eq!(settings, HotCornerSettings::default());
+        assert!(!settings.any_action());
+        assert!(settings.validate().is_ok());
+
+        let configured = HotCornerSettings {
+            bottom_right: HotCornerAction::QuickMenu,
+            ..settings.clone()
+        };
+        assert!(configured.any_action());
+        assert_eq!(
+            configured.action(ScreenCorner::BottomRight),
+            HotCornerAction::QuickMenu
+        );
+
+        let twitchy = HotCornerSettings {
+            dwell_ms: 10,
+            ..settings.clone()
+        };
+        assert!(twitchy.validate().is_err());
+        let huge = HotCornerSettings {
+            corner_size_px: MAX_CORNER_SIZE_PX + 1,
+            ..settings
+        };
+        assert!(huge.validate().is_err());
+    }
+}
@@ -0,0 +1,307 @@
+//! Two-step hotkeys ("chords"), e.g. Ctrl+Alt+C then 1..9 to apply a cursor pack.
+//!
+//! Global shortcuts can only be single key combinations, so only the prefix is registered with
+//! `tauri_plugin_global_shortcut`. When it fires, a low-level keyboard hook captures the next
+//! key press for `timeout_ms`; digits pick the Nth pack in the library, Escape cancels, and any
+//! other key ends the chord and is passed through. The UI shows a hint while the chord is open.
+
+use serde::{Deserialize, Serialize};
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager, Runtime};
+use tauri_plugin_global_shortcut::{GlobalShortcutExt, ShortcutState};
+
+use cursor_changer::win_keyboard::KeyCapture;
+
+use crate::commands::customization::library::load_library;
+use crate::events;
+use crate::state::settings_file::{self, SettingsFile};
+use crate::state::AppState;
+use crate::timers::{self, TimerPriority};
+
+pub const HOTKEY_CHORDS_FILE_NAME: &str = "hotkey-chords.json";
+pub const DEFAULT_CHORD_PREFIX: &str = "Ctrl+Alt+C";
+pub const DEFAULT_CHORD_TIMEOUT_MS: u32 = 1500;
+/// Digits 1..9 select the first nine packs.
+pub const MAX_CHORD_OPTIONS: usize = 9;
+
+const VK_ESCAPE: u32 = 0x1B;
+
+#[derive(ts_rs::TS, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct HotkeyChordSettings {
+    #[serde(default)]
+    pub enabled: bool,
+    /// Accelerator that opens the chord, e.g. "Ctrl+Alt+C"
+    #[serde(default = "default_prefix")]
+    pub prefix: String,
+    /// How long the chord waits for the second key
+    #[serde(default = "default_timeout_ms")]
+    pub timeout_ms: u32,
+}
+
+fn default_prefix() -> String {
+    DEFAULT_CHORD_PREFIX.to_string()
+}
+
+fn default_timeout_ms() -> u32 {
+    DEFAULT_CHORD_TIMEOUT_MS
+}
+
+impl Default for HotkeyChordSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            prefix: default_prefix(),
+            timeout_ms: DEFAULT_CHORD_TIMEOUT_MS,
+        }
+    }
+}
+
+#[derive(ts_rs::TS, Debug, Clone, PartialEq, Eq, Serialize)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct HotkeyChordOption {
+    /// Key to press, "1".."9"
+    pub key: String,
+    pub pack_id: String,
+    pub pack_name: String,
+}
+
+/// Payload of `hotkey-chord-hint`: shown while `active`, hidden otherwise.
+#[derive(ts_rs::TS, Debug, Clone, PartialEq, Eq, Serialize)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct HotkeyChordHint {
+    pub active: bool,
+    pub prefix: String,
+    pub options: Vec<HotkeyChordOption>,
+    pub timeout_ms: u32,
+}
+
+/// What a key press means while a chord is open.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ChordInput {
+    /// Zero-based option index
+    Select(usize),
+    Cancel,
+    /// Any other key: close the chord and let the key through
+    Abort,
+    /// Modifiers still held from the prefix
+    Ignore,
+}
+
+fn resolve_key(vk: u32) -> ChordInput {
+    match vk {
+        // Shift, Ctrl, Alt (generic, left and right) and the Windows keys
+        0x10..=0x12 | 0xA0..=0xA5 | 0x5B | 0x5C => ChordInput::Ignore,
+        VK_ESCAPE => ChordInput::Cancel,
+        // Top-row 1..9 and numpad 1..9
+        0x31..=0x39 => ChordInput::Select((vk - 0x31) as usize),
+        0x61..=0x69 => ChordInput::Select((vk - 0x61) as usize),
+        _ => ChordInput::Abort,
+    }
+}
+
+struct ActiveChord {
+    generation: u64,
+    options: Vec<HotkeyChordOption>,
+    _capture: KeyCapture,
+}
+
+#[derive(Default)]
+struct ChordRuntime {
+    active: Option<ActiveChord>,
+    generation: u64,
+}
+
+static RUNTIME: Mutex<Option<ChordRuntime>> = Mutex::new(None);
+
+impl SettingsFile for HotkeyChordSettings {
+    const FILE_NAME: &'static str = HOTKEY_CHORDS_FILE_NAME;
+    const DESCRIPTION: &'static str = "hotkey chord settings";
+}
+
+pub fn load_settings<R: Runtime>(app: &AppHandle<R>) -> HotkeyChordSettings {
+    settings_file::load(app)
+}
+
+pub fn save_settings<R: Runtime>(
+    app: &AppHandle<R>,
+    settings: &HotkeyChordSettings,
+) -> Result<(), String> {
+    settings_file::save(app, settings)
+}
+
+/// Register the chord prefix if chords are enabled.
+///
+/// `shortcuts::update_shortcut` unregisters every shortcut, so it calls this afterwards.
+pub fn register_prefix(app: &AppHandle) -> Result<(), String> {
+    let settings = load_settings(app);
+    if !settings.enabled {
+        return Ok(());
+    }
+
+    let app_for_chord = app.clone();
+    app.global_shortcut()
+        .on_shortcut(settings.prefix.as_str(), move |_app, _shortcut, event| {
+            if event.state != ShortcutState::Pressed {
+                return;
+            }
+            if let Err(e) = open_chord(&app_for_chord, &settings) {
+                cc_warn!("[CursorChanger] Failed to start hotkey chord: {}", e);
+            }
+        })
+        .map_err(|e| format!("Failed to register chord prefix '{}': {}", settings.prefix, e))
+}
+
+/// Remove the prefix shortcut and close any open chord.
+pub fn unregister_prefix(app: &AppHandle, prefix: &str) {
+    let _ = app.global_shortcut().unregister(prefix);
+    close_chord(app, None);
+}
+
+fn chord_options(app: &AppHandle) -> Result<Vec<HotkeyChordOption>, String> {
+    let library = load_library(app)?;
+    Ok(library
+        .cursors
+        .into_iter()
+        .filter(|c| c.is_pack)
+        .take(MAX_CHORD_OPTIONS)
+        .enumerate()
+        .map(|(index, pack)| HotkeyChordOption {
+            key: (index + 1).to_string(),
+            pack_id: pack.id,
+            pack_name: pack.name,
+        })
+        .collect())
+}
+
+fn open_chord(app: &AppHandle, settings: &HotkeyChordSettings) -> Result<(), String> {
+    let options = chord_options(app)?;
+    if options.is_empty() {
+        return Err("No cursor packs in the library".to_string());
+    }
+
+    let mut runtime = RUNTIME
+        .lock()
+        .map_err(|_| "Hotkey chord state poisoned".to_string())?;
+    let runtime = runtime.get_or_insert_with(ChordRuntime::default);
+    // Pressing the prefix again restarts the chord
+    runtime.active = None;
+    runtime.generation += 1;
+    let generation = runtime.generation;
+
+    let app_for_keys = app.clone();
+    let mut finished = false;
+    let capture = KeyCapture::start(move |vk| {
+        if finished {
+            return false;
+        }
+        let input = resolve_key(vk);
+        if input == ChordInput::Ignore {
+            return false;
+        }
+        finished = true;
+        // The hook must return quickly, so the work happens on another thread
+        let app = app_for_keys.clone();
+        std::thread::spawn(move || finish_chord(&app, generation, input));
+        input != ChordInput::Abort
+    })?;
+
+    runtime.active = Some(ActiveChord {
+        generation,
+        options: options.clone(),
+        _capture: capture,
+    });
+
+    let _ = app.emit(
+        events::HOTKEY_CHORD_HINT,
+        HotkeyChordHint {
+            active: true,
+            prefix: settings.prefix.clone(),
+            options,
+            timeout_ms: settings.timeout_ms,
+        },
+    );
+
+    let app_for_timeout = app.clone();
+    let timeout = Duration::from_millis(u64::from(settings.timeout_ms));
+    timers::after(timeout, TimerPriority::Essential, move || {
+        close_chord(&app_for_timeout, Some(generation));
+    });
+
+    Ok(())
+}
+
+/// Close the chord (only if it is still `generation`, when given) and hide the hint.
+fn close_chord(app: &AppHandle, generation: Option<u64>) -> Option<ActiveChord> {
+    let closed = {
+        let mut runtime = RUNTIME.lock().ok()?;
+        let runtime = runtime.as_mut()?;
+        let current = runtime.active.as_ref().map(|chord| chord.generation);
+        if current.is_none() || (generation.is_some() && generation != current) {
+            return None;
+        }
+        runtime.active.take()
+    };
+
+    let _ = app.emit(
+        events::HOTKEY_CHORD_HINT,
+        HotkeyChordHint {
+            active: false,
+            prefix: String::new(),
+            options: Vec::new(),
+            timeout_ms: 0,
+        },
+    );
+    closed
+}
+
+fn finish_chord(app: &AppHandle, generation: u64, input: ChordInput) {
+    let Some(chord) = close_chord(app, Some(generation)) else {
+        return;
+    };
+    let ChordInput::Select(index) = input else {
+        return;
+    };
+    let Some(option) = chord.options.get(index) else {
+        return;
+    };
+
+    let state = app.state::<AppState>();
+    let result = crate::commands::customization::pack_commands::apply_cursor_pack(
+        app.clone(),
+        state,
+        option.pack_id.clone(),
+    );
+    match result {
+        Ok(()) => cc_info!("[CursorChanger] Applied cursor pack {} via hotkey chord", option.pack_name),
+        Err(e) => {
+            let _ = app.emit(events::CURSOR_ERROR, e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn digits_select_and_modifiers_are_ignored() {
+        assert_eq!(resolve_key(0x31), ChordInput::Select(0));
+        assert_eq!(resolve_key(0x39), ChordInput::Select(8));
+        assert_eq!(resolve_key(0x63), ChordInput::Select(2));
+        assert_eq!(resolve_key(0xA2), ChordInput::Ignore);
+        assert_eq!(resolve_key(0x12), ChordInput::Ignore);
+        assert_eq!(resolve_key(VK_ESCAPE), ChordInput::Cancel);
+        assert_eq!(resolve_key(0x30), ChordInput::Abort);
+        assert_eq!(resolve_key(0x41), ChordInput::Abort);
+    }
+
+    #[test]
+    fn settings_default_when_fields_missing() {
+        let settings: HotkeyChordSettings = serde_json::from_str(r#"{"enabled":true}"#).unwrap();
+        assert!(settings.enabled);
+        assert_eq!(settings.prefix, DEFAULT_CHORD_PREFIX);
+        assert_eq!(settings.timeout_ms, DEFAULT_CHORD_TIMEOUT_MS);
+    }
+}
@@ -0,0 +1,271 @@
+//! Single vs double press of the hide/show shortcut.
+//!
+//! With no double-press action configured the single-press action runs immediately. Otherwise
+//! the first press waits `double_press_window_ms` for a second one: a second press inside the
+//! window runs the double-press action, and the single-press action only runs once the window
+//! has passed without one.
+
+use serde::{Deserialize, Serialize};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter, Manager, Runtime};
+
+use crate::commands::cursor_commands::toggle_cursor_with_shared_state;
+use crate::commands::customization::pack_manifest::PackSoundEvent;
+use crate::events;
+use crate::state::settings_file::{self, SettingsFile};
+use crate::state::AppState;
+use crate::timers::{self, TimerPriority};
+
+pub const HOTKEY_PRESS_FILE_NAME: &str = "hotkey-press.json";
+pub const DEFAULT_DOUBLE_PRESS_WINDOW_MS: u32 = 350;
+pub const MIN_DOUBLE_PRESS_WINDOW_MS: u32 = 150;
+pub const MAX_DOUBLE_PRESS_WINDOW_MS: u32 = 1000;
+
+#[derive(ts_rs::TS, Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub enum HotkeyPressAction {
+    None,
+    /// Hide or show the cursor
+    #[default]
+    ToggleCursor,
+    ShowMainWindow,
+    /// Re-apply the cursor pack that was applied most recently
+    ApplyLastPack,
+}
+
+#[derive(ts_rs::TS, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct HotkeyPressSettings {
+    #[serde(default)]
+    pub single_press: HotkeyPressAction,
+    /// `None` keeps single presses instant
+    #[serde(default = "default_double_press")]
+    pub double_press: HotkeyPressAction,
+    /// How long to wait for the second press
+    #[serde(default = "default_window_ms")]
+    pub double_press_window_ms: u32,
+    /// Pack used by `ApplyLastPack`, recorded whenever a pack is applied
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(skip)]
+    pub last_pack_id: Option<String>,
+}
+
+fn default_double_press() -> HotkeyPressAction {
+    HotkeyPressAction::None
+}
+
+fn default_window_ms() -> u32 {
+    DEFAULT_DOUBLE_PRESS_WINDOW_MS
+}
+
+impl Default for HotkeyPressSettings {
+    fn default() -> Self {
+        Self {
+            single_press: HotkeyPressAction::ToggleCursor,
+            double_press: default_double_press(),
+            double_press_window_ms: DEFAULT_DOUBLE_PRESS_WINDOW_MS,
+            last_pack_id: None,
+        }
+    }
+}
+
+impl SettingsFile for HotkeyPressSettings {
+    const FILE_NAME: &'static str = HOTKEY_PRESS_FILE_NAME;
+    const DESCRIPTION: &'static str = "hotkey press settings";
+}
+
+pub fn load_settings<R: Runtime>(app: &AppHandle<R>) -> HotkeyPressSettings {
+    settings_file::load(app)
+}
+
+pub fn save_settings<R: Runtime>(
+    app: &AppHandle<R>,
+    settings: &HotkeyPressSettings,
+) -> Result<(), String> {
+    settings_file::save(app, settings)
+}
+
+/// Remember `pack_id` as the target of `ApplyLastPack`.
+pub fn remember_applied_pack<R: Runtime>(app: &AppHandle<R>, pack_id: &str) {
+    let mut settings = load_settings(app);
+    if settings.last_pack_id.as_deref() == Some(pack_id) {
+        return;
+    }
+    settings.last_pack_id = Some(pack_id.to_string());
+    if let Err(e) = save_settings(app, &settings) {
+        cc_warn!(
+            "[CursorChanger] Failed to remember last applied pack: {}",
+            e
+        );
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PressOutcome {
+    /// Second press inside the window
+    Double,
+    /// First press; run the single-press action if `generation` is still pending later
+    Pending(u64),
+}
+
+#[derive(Debug, Default)]
+struct PressTracker {
+    pending: Option<(u64, Instant)>,
+    generation: u64,
+}
+
+impl PressTracker {
+    fn press(&mut self, now: Instant, window: Duration) -> PressOutcome {
+        self.generation += 1;
+        match self.pending.take() {
+            Some((_, first)) if now.duration_since(first) <= window => PressOutcome::Double,
+            _ => {
+                self.pending = Some((self.generation, now));
+                PressOutcome::Pending(self.generation)
+            }
+        }
+    }
+
+    /// Close the window for `generation`. False when a second press already claimed it.
+    fn expire(&mut self, generation: u64) -> bool {
+        match self.pending {
+            Some((pending, _)) if pending == generation => {
+                self.pending = None;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+static TRACKER: Mutex<Option<PressTracker>> = Mutex::new(None);
+
+/// Handle a press of the hide/show shortcut.
+pub fn handle_press(app: &AppHandle) {
+    let settings = load_settings(app);
+    if settings.double_press == HotkeyPressAction::None {
+        run_action(app, settings.single_press, &settings);
+        return;
+    }
+
+    let window = Duration::from_millis(u64::from(settings.double_press_window_ms));
+    let outcome = {
+        let Ok(mut tracker) = TRACKER.lock() else {
+            return;
+        };
+        tracker
+            .get_or_insert_with(PressTracker::default)
+            .press(Instant::now(), window)
+    };
+
+    match outcome {
+        PressOutcome::Double => run_action(app, settings.double_press, &settings),
+        PressOutcome::Pending(generation) => {
+            let app = app.clone();
+            timers::after(window, TimerPriority::Essential, move || {
+                let expired = TRACKER
+                    .lock()
+                    .ok()
+                    .and_then(|mut tracker| tracker.as_mut().map(|t| t.expire(generation)))
+                    .unwrap_or(false);
+                if expired {
+                    run_action(&app, settings.single_press, &settings);
+                }
+            });
+        }
+    }
+}
+
+/// Hide or show the cursor and tell the frontend, as the hide/show shortcut does.
+pub fn toggle_cursor<R: Runtime>(app: &AppHandle<R>) {
+    let Some(shared) = app.try_state::<AppState>() else {
+        return;
+    };
+    match toggle_cursor_with_shared_state(&shared) {
+        Ok(payload) => {
+            crate::audio::play_pack_sound(app, None, PackSoundEvent::Toggle);
+            let _ = app.emit(events::CURSOR_STATE, payload);
+        }
+        Err(err) => {
+            let _ = app.emit(events::CURSOR_ERROR, err);
+        }
+    }
+}
+
+fn run_action(app: &AppHandle, action: HotkeyPressAction, settings: &HotkeyPressSettings) {
+    match action {
+        HotkeyPressAction::None => {}
+        HotkeyPressAction::ToggleCursor => toggle_cursor(app),
+        HotkeyPressAction::ShowMainWindow => {
+            crate::commands::window_commands::show_main_window(app);
+        }
+        HotkeyPressAction::ApplyLastPack => {
+            let Some(pack_id) = settings.last_pack_id.clone() else {
+                let _ = app.emit(
+                    events::CURSOR_ERROR,
+                    "No cursor pack has been applied yet".to_string(),
+                );
+                return;
+            };
+            let result = crate::commands::customization::pack_commands::apply_cursor_pack(
+                app.clone(),
+                app.state::<AppState>(),
+                pack_id,
+            );
+            if let Err(e) = result {
+                let _ = app.emit(events::CURSOR_ERROR, e);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn second_press_inside_window_is_a_double_press() {
+        let window = Duration::from_millis(300);
+        let start = Instant::now();
+        let mut tracker = PressTracker::default();
+
+        let PressOutcome::Pending(first) = tracker.press(start, window) else {
+            panic!("first press should wait");
+        };
+        assert_eq!(
+            tracker.press(start + Duration::from_millis(200), window),
+            PressOutcome::Double
+        );
+        // The timer for the first press finds nothing left to do
+        assert!(!tracker.expire(first));
+    }
+
+    #[test]
+    fn presses_outside_window_stay_single() {
+        let window = Duration::from_millis(300);
+        let start = Instant::now();
+        let mut tracker = PressTracker::default();
+
+        let PressOutcome::Pending(first) = tracker.press(start, window) else {
+            panic!("first press should wait");
+        };
+        assert!(tracker.expire(first));
+        assert!(matches!(
+            tracker.press(start + Duration::from_millis(500), window),
+            PressOutcome::Pending(_)
+        ));
+    }
+
+    #[test]
+    fn settings_default_to_instant_toggle() {
+        let settings: HotkeyPressSettings = serde_json::from_str("{}").unwrap();
+        assert_eq!(settings.single_press, HotkeyPressAction::ToggleCursor);
+        assert_eq!(settings.double_press, HotkeyPressAction::None);
+        assert_eq!(
+            settings.double_press_window_ms,
+            DEFAULT_DOUBLE_PRESS_WINDOW_MS
+        );
+    }
+}
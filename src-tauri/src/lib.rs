@@ -5,16 +5,101 @@
 #[macro_use]
 mod logging;
 
-// Include the cursor_converter module from main.rs
-#[path = "cursor_converter/mod.rs"]
+#[path = "log_files.rs"]
+pub mod log_files;
+
+// Re-export of the cursor-convert crate
+#[path = "cursor_converter.rs"]
 pub mod cursor_converter;
 
+#[path = "error_codes.rs"]
+pub mod error_codes;
+
 #[path = "paths.rs"]
 pub mod paths;
 
+#[path = "portable.rs"]
+pub mod portable;
+
 #[path = "cursor_defaults.rs"]
 pub mod cursor_defaults;
 
+#[path = "cursor_refresh.rs"]
+pub mod cursor_refresh;
+
+#[path = "cursor_brightness.rs"]
+pub mod cursor_brightness;
+
+#[path = "apply_verification.rs"]
+pub mod apply_verification;
+
+#[path = "cache_gc.rs"]
+pub mod cache_gc;
+
+#[path = "status_file.rs"]
+pub mod status_file;
+
+#[path = "health_endpoint.rs"]
+pub mod health_endpoint;
+
+// `--decode-worker` process for decoding untrusted cursor files
+#[path = "decode_worker.rs"]
+pub mod decode_worker;
+
+// Cursor roles that stay visible while the cursor is hidden
+#[path = "hide_roles.rs"]
+pub mod hide_roles;
+
+#[path = "memory_policy.rs"]
+pub mod memory_policy;
+
+// Output folder and file name template for converted cursors
+#[path = "conversion_output.rs"]
+pub mod conversion_output;
+
+// Folder the cursor library files live in, when moved off the default location
+#[path = "storage_location.rs"]
+pub mod storage_location;
+
+// Sound cues bundled with cursor packs
+#[path = "audio.rs"]
+pub mod audio;
+
+#[path = "crash_reports.rs"]
+pub mod crash_reports;
+
+#[path = "usage_stats.rs"]
+pub mod usage_stats;
+
+#[path = "uninstall_cleanup.rs"]
+pub mod uninstall_cleanup;
+
+// `cc-preview` URI scheme serving cached preview images to the webview
+#[path = "preview_protocol.rs"]
+pub mod preview_protocol;
+
+#[path = "operations.rs"]
+pub mod operations;
+
+#[path = "pending_jobs.rs"]
+pub mod pending_jobs;
+
+#[path = "disk_space.rs"]
+pub mod disk_space;
+
+#[path = "effects_quality.rs"]
+pub mod effects_quality;
+
+#[path = "reduced_motion.rs"]
+pub mod reduced_motion;
+
+#[path = "svg_resources.rs"]
+pub mod svg_resources;
+
+// Active cursor scheme mirrored for the optional logon service
+#[path = "logon_scheme.rs"]
+pub mod logon_scheme;
+
 // Include the state module for testing
 #[path = "state/mod.rs"]
 pub mod state;
@@ -39,6 +124,45 @@ pub mod window_setup;
 #[path = "system.rs"]
 pub mod system;
 
+// Two-step hotkeys layered on the global shortcut plugin
+#[path = "hotkey_chords.rs"]
+pub mod hotkey_chords;
+
+// Single vs double press of the hide/show shortcut
+#[path = "hotkey_press.rs"]
+pub mod hotkey_press;
+
+// Modifier + mouse wheel cursor size gesture
+#[path = "size_gesture.rs"]
+pub mod size_gesture;
+
+#[path = "pointer_control.rs"]
+pub mod pointer_control;
+
+// `--validate-config` dry-run check of config, library and pack manifests
+#[path = "config_check.rs"]
+pub mod config_check;
+
+// Actions triggered by resting the pointer in a screen corner
+#[path = "hot_corners.rs"]
+pub mod hot_corners;
+
+// Experimental per-monitor cursor schemes drawn by a pointer overlay
+#[path = "monitor_schemes.rs"]
+pub mod monitor_schemes;
+
+// Hot reload of converted cursors when their source image changes
+#[path = "source_watch.rs"]
+pub mod source_watch;
+
+// Generated extra-large cursors for the "touch" default style
+#[path = "touch_cursors.rs"]
+pub mod touch_cursors;
+
+// Shared, power-aware timer service for debounces and periodic jobs
+#[path = "timers.rs"]
+pub mod timers;
+
 // Include shortcuts module for commands
 #[cfg(not(test))]
 #[path = "shortcuts.rs"]
@@ -58,6 +182,9 @@ pub mod startup_config;
 #[path = "window_events.rs"]
 pub mod window_events;
 
+#[path = "window_state.rs"]
+pub mod window_state;
+
 // Include window module for commands
 #[cfg(not(test))]
 #[path = "window/mod.rs"]
@@ -0,0 +1,269 @@
+//! Size-bounded log files.
+//!
+//! Info, warning and error lines are appended with a UTC timestamp to `cursor-changer.log` in
+//! the logs folder. Once that file passes [`MAX_FILE_BYTES`] it is renamed to
+//! `cursor-changer-<millis>.log` and a fresh one started; the oldest rotated files are deleted
+//! while the folder holds more than [`MAX_TOTAL_BYTES`]. `export_logs` copies the lines since a
+//! given time into one file for attaching to an issue.
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+pub const LOG_FILE_NAME: &str = "cursor-changer.log";
+const ROTATED_PREFIX: &str = "cursor-changer-";
+/// Size at which the current file is rotated
+const MAX_FILE_BYTES: u64 = 1024 * 1024;
+/// Size of all log files together, the current one included
+const MAX_TOTAL_BYTES: u64 = 5 * 1024 * 1024;
+
+static WRITER: Mutex<Option<RotatingLog>> = Mutex::new(None);
+
+struct RotatingLog {
+    dir: PathBuf,
+    file: File,
+    size: u64,
+    max_file_bytes: u64,
+    max_total_bytes: u64,
+}
+
+impl RotatingLog {
+    fn open(dir: &Path, max_file_bytes: u64, max_total_bytes: u64) -> Result<Self, String> {
+        fs::create_dir_all(dir).map_err(|e| format!("Failed to create logs directory: {}", e))?;
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(dir.join(LOG_FILE_NAME))
+            .map_err(|e| format!("Failed to open log file: {}", e))?;
+        let size = file.metadata().map(|m| m.len()).unwrap_or(0);
+        Ok(Self {
+            dir: dir.to_path_buf(),
+            file,
+            size,
+            max_file_bytes,
+            max_total_bytes,
+        })
+    }
+
+    fn write_line(&mut self, line: &str) -> Result<(), String> {
+        if self.size > 0 && self.size + line.len() as u64 > self.max_file_bytes {
+            self.rotate()?;
+        }
+        self.file
+            .write_all(line.as_bytes())
+            .map_err(|e| format!("Failed to write log file: {}", e))?;
+        self.size += line.len() as u64;
+        Ok(())
+    }
+
+    fn rotate(&mut self) -> Result<(), String> {
+        let mut millis = Utc::now().timestamp_millis();
+        let mut target = self.dir.join(format!("{ROTATED_PREFIX}{millis}.log"));
+        // Two rotations within a millisecond
+        while target.exists() {
+            millis += 1;
+            target = self.dir.join(format!("{ROTATED_PREFIX}{millis}.log"));
+        }
+        fs::rename(self.dir.join(LOG_FILE_NAME), &target)
+            .map_err(|e| format!("Failed to rotate log file: {}", e))?;
+        *self = Self::open(&self.dir, self.max_file_bytes, self.max_total_bytes)?;
+        self.prune();
+        Ok(())
+    }
+
+    /// Leave room for a full current file next to the rotated ones.
+    fn prune(&self) {
+        prune(
+            &self.dir,
+            self.max_total_bytes.saturating_sub(self.max_file_bytes),
+        );
+    }
+}
+
+/// Rotated log files, oldest first.
+fn rotated_files(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut files: Vec<(i64, PathBuf)> = entries
+        .flatten()
+        .filter_map(|entry| {
+            let name = entry.file_name().to_string_lossy().into_owned();
+            let millis = name
+                .strip_prefix(ROTATED_PREFIX)?
+                .strip_suffix(".log")?
+                .parse()
+                .ok()?;
+            Some((millis, entry.path()))
+        })
+        .collect();
+    files.sort();
+    files.into_iter().map(|(_, path)| path).collect()
+}
+
+/// All log files, oldest first.
+fn log_files(dir: &Path) -> Vec<PathBuf> {
+    let mut files = rotated_files(dir);
+    let current = dir.join(LOG_FILE_NAME);
+    if current.is_file() {
+        files.push(current);
+    }
+    files
+}
+
+/// Delete the oldest rotated files until they fit in `max_rotated_bytes`.
+fn prune(dir: &Path, max_rotated_bytes: u64) {
+    let file_size = |path: &Path| fs::metadata(path).map(|m| m.len()).unwrap_or(0);
+    let rotated = rotated_files(dir);
+    let mut total: u64 = rotated.iter().map(|p| file_size(p)).sum();
+    for path in rotated {
+        if total <= max_rotated_bytes {
+            break;
+        }
+        let size = file_size(&path);
+        if fs::remove_file(&path).is_ok() {
+            total = total.saturating_sub(size);
+        }
+    }
+}
+
+/// Start writing log files. Called once at startup.
+pub fn init() {
+    let opened = crate::paths::logs_dir()
+        .and_then(|dir| RotatingLog::open(&dir, MAX_FILE_BYTES, MAX_TOTAL_BYTES));
+    match opened {
+        Ok(log) => {
+            log.prune();
+            *WRITER.lock().unwrap_or_else(|e| e.into_inner()) = Some(log);
+        }
+        Err(e) => eprintln!("[CursorChanger] Log files unavailable: {}", e),
+    }
+}
+
+fn format_line(at: DateTime<Utc>, level: &str, line: &str) -> String {
+    format!(
+        "{} [{}] {}\n",
+        at.to_rfc3339_opts(SecondsFormat::Millis, true),
+        level,
+        line
+    )
+}
+
+#[doc(hidden)]
+pub fn append(level: &str, line: &str) {
+    // try_lock for the same reason as the in-memory tail; a contended line is dropped
+    if let Ok(mut writer) = WRITER.try_lock() {
+        if let Some(log) = writer.as_mut() {
+            // Logging the failure would recurse
+            let _ = log.write_line(&format_line(Utc::now(), level, line));
+        }
+    }
+}
+
+fn line_time(line: &str) -> Option<DateTime<Utc>> {
+    let stamp = line.split(' ').next()?;
+    DateTime::parse_from_rfc3339(stamp)
+        .ok()
+        .map(|t| t.with_timezone(&Utc))
+}
+
+/// Copy the lines logged at or after `since` (all lines when `None`) into `dest`, oldest
+/// first. Lines without a timestamp go with the line before them. Returns the lines written.
+fn export_from(dir: &Path, dest: &Path, since: Option<DateTime<Utc>>) -> Result<usize, String> {
+    let mut out = String::new();
+    let mut count = 0;
+    let mut keep = since.is_none();
+    for path in log_files(dir) {
+        let Ok(contents) = fs::read_to_string(&path) else {
+            continue;
+        };
+        for line in contents.lines() {
+            if let (Some(since), Some(at)) = (since, line_time(line)) {
+                keep = at >= since;
+            }
+            if keep {
+                out.push_str(line);
+                out.push('\n');
+                count += 1;
+            }
+        }
+    }
+    fs::write(dest, out).map_err(|e| format!("Failed to write exported logs: {}", e))?;
+    Ok(count)
+}
+
+/// Export the logs since `since` (RFC 3339) to `dest`. Returns the number of lines written.
+pub fn export(dest: &Path, since: Option<&str>) -> Result<usize, String> {
+    let since = since
+        .map(|s| {
+            DateTime::parse_from_rfc3339(s)
+                .map(|t| t.with_timezone(&Utc))
+                .map_err(|e| format!("Invalid time {}: {}", s, e))
+        })
+        .transpose()?;
+    let dir = crate::paths::logs_dir()?;
+    // Hold the writer so a rotation cannot move a file mid-export
+    let _writer = WRITER.lock().unwrap_or_else(|e| e.into_inner());
+    export_from(&dir, dest, since)
+}
+
+/// Delete every log file and start an empty one.
+pub fn clear() -> Result<(), String> {
+    let dir = crate::paths::logs_dir()?;
+    let mut writer = WRITER.lock().unwrap_or_else(|e| e.into_inner());
+    // Close the current file first; Windows cannot recreate a file that is pending deletion
+    let was_open = writer.take().is_some();
+    for path in log_files(&dir) {
+        fs::remove_file(&path).map_err(|e| format!("Failed to delete log file: {}", e))?;
+    }
+    if was_open {
+        *writer = Some(RotatingLog::open(&dir, MAX_FILE_BYTES, MAX_TOTAL_BYTES)?);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn rotation_keeps_the_folder_under_the_total_limit() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let mut log = RotatingLog::open(dir.path(), 100, 350).expect("open");
+        let line = format!("{}\n", "x".repeat(39));
+        for _ in 0..40 {
+            log.write_line(&line).expect("write");
+        }
+
+        let files = log_files(dir.path());
+        assert!(files.len() > 1);
+        let total: u64 = files.iter().map(|p| fs::metadata(p).unwrap().len()).sum();
+        assert!(total <= 350, "total {}", total);
+        assert!(fs::metadata(dir.path().join(LOG_FILE_NAME)).unwrap().len() <= 100);
+    }
+
+    #[test]
+    fn export_keeps_lines_since_the_given_time() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let at = |h| Utc.with_ymd_and_hms(2026, 1, 1, h, 0, 0).unwrap();
+        fs::write(
+            dir.path().join(format!("{ROTATED_PREFIX}1.log")),
+            format_line(at(8), "INFO", "old"),
+        )
+        .unwrap();
+        let current = format_line(at(10), "WARN", "new") + "  continued\n";
+        fs::write(dir.path().join(LOG_FILE_NAME), current).unwrap();
+
+        let dest = dir.path().join("export.txt");
+        assert_eq!(export_from(dir.path(), &dest, Some(at(9))).unwrap(), 2);
+        let exported = fs::read_to_string(&dest).unwrap();
+        assert!(exported.starts_with("2026-01-01T10:00:00.000Z [WARN] new\n"));
+        assert!(exported.ends_with("  continued\n"));
+
+        assert_eq!(export_from(dir.path(), &dest, None).unwrap(), 3);
+        assert!(fs::read_to_string(&dest).unwrap().contains("[INFO] old"));
+    }
+}
@@ -1,3 +1,35 @@
+//! Logging macros. Info, warning and error lines are also kept in a small in-memory tail
+//! so crash reports can include what happened just before the crash, and written to the
+//! log files (see `crate::log_files`).
+
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+/// Number of recent lines kept for crash reports.
+pub const LOG_TAIL_LINES: usize = 200;
+
+static LOG_TAIL: Mutex<VecDeque<String>> = Mutex::new(VecDeque::new());
+
+#[doc(hidden)]
+pub fn record_line(level: &str, line: &str) {
+    // try_lock: never block (or deadlock) a thread that logs while a crash is being reported
+    if let Ok(mut tail) = LOG_TAIL.try_lock() {
+        if tail.len() == LOG_TAIL_LINES {
+            tail.pop_front();
+        }
+        tail.push_back(format!("[{}] {}", level, line));
+    }
+    crate::log_files::append(level, line);
+}
+
+/// The most recent log lines, oldest first.
+pub fn log_tail() -> Vec<String> {
+    LOG_TAIL
+        .try_lock()
+        .map(|tail| tail.iter().cloned().collect())
+        .unwrap_or_default()
+}
+
 #[macro_export]
 macro_rules! cc_debug {
     ($($arg:tt)*) => {{
@@ -19,14 +51,18 @@ macro_rules! cc_debug_if {
 #[macro_export]
 macro_rules! cc_info {
     ($($arg:tt)*) => {{
-        println!($($arg)*);
+        let line = format!($($arg)*);
+        $crate::logging::record_line("INFO", &line);
+        println!("{}", line);
     }};
 }
 
 #[macro_export]
 macro_rules! cc_warn {
     ($($arg:tt)*) => {{
-        eprintln!($($arg)*);
+        let line = format!($($arg)*);
+        $crate::logging::record_line("WARN", &line);
+        eprintln!("{}", line);
     }};
 }
 
@@ -42,6 +78,8 @@ macro_rules! cc_warn_if {
 #[macro_export]
 macro_rules! cc_error {
     ($($arg:tt)*) => {{
-        eprintln!($($arg)*);
+        let line = format!($($arg)*);
+        $crate::logging::record_line("ERROR", &line);
+        eprintln!("{}", line);
     }};
 }
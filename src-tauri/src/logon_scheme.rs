@@ -0,0 +1,137 @@
+//! Snapshot of the active cursor scheme for the optional logon service.
+//!
+//! The tray app keeps cursor paths in memory only. The logon service runs before the app
+//! starts, so the app mirrors the active scheme to `%APPDATA%\cursor-changer\logon-scheme.json`
+//! whenever it changes and the service's `apply` step reads it back.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+pub const LOGON_SCHEME_FILE_NAME: &str = "logon-scheme.json";
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LogonScheme {
+    pub cursor_size: i32,
+    /// Map of cursor name -> image path, same shape as `CursorRuntimeState::cursor_paths`
+    pub cursor_paths: HashMap<String, String>,
+}
+
+static LAST_SAVED: Mutex<Option<LogonScheme>> = Mutex::new(None);
+
+pub fn logon_scheme_path() -> Result<PathBuf, String> {
+    let app_data = std::env::var("APPDATA")
+        .map_err(|_| "Failed to get APPDATA environment variable".to_string())?;
+    Ok(PathBuf::from(app_data)
+        .join("cursor-changer")
+        .join(LOGON_SCHEME_FILE_NAME))
+}
+
+pub fn load_logon_scheme() -> Result<Option<LogonScheme>, String> {
+    let path = logon_scheme_path()?;
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let contents = std::fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read logon scheme: {}", e))?;
+    serde_json::from_str(&contents)
+        .map(Some)
+        .map_err(|e| format!("Failed to parse logon scheme: {}", e))
+}
+
+/// Keep the on-disk scheme in sync with app state. Only runs once the logon service has been
+/// installed (which creates the file), so users who never opt in get no extra writes.
+pub fn mirror_logon_scheme(cursor_size: i32, cursor_paths: &HashMap<String, String>) {
+    let Ok(path) = logon_scheme_path() else {
+        return;
+    };
+    if !path.exists() {
+        return;
+    }
+
+    let scheme = LogonScheme {
+        cursor_size,
+        cursor_paths: cursor_paths.clone(),
+    };
+    if let Err(e) = save_logon_scheme_if_changed(&scheme) {
+        cc_warn!("[CursorChanger] Failed to update logon scheme: {}", e);
+    }
+}
+
+/// Write the scheme if it differs from the last one written by this process.
+pub fn save_logon_scheme_if_changed(scheme: &LogonScheme) -> Result<(), String> {
+    let unchanged = LAST_SAVED
+        .lock()
+        .map_err(|_| "Logon scheme cache poisoned".to_string())?
+        .as_ref()
+        == Some(scheme);
+    if unchanged {
+        return Ok(());
+    }
+    save_logon_scheme(scheme)
+}
+
+pub fn save_logon_scheme(scheme: &LogonScheme) -> Result<(), String> {
+    let mut last = LAST_SAVED
+        .lock()
+        .map_err(|_| "Logon scheme cache poisoned".to_string())?;
+    write_scheme(&logon_scheme_path()?, scheme)?;
+    *last = Some(scheme.clone());
+    Ok(())
+}
+
+fn write_scheme(path: &std::path::Path, scheme: &LogonScheme) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create logon scheme directory: {}", e))?;
+    }
+
+    let json = serde_json::to_string_pretty(scheme)
+        .map_err(|e| format!("Failed to serialize logon scheme: {}", e))?;
+    let tmp_path = path.with_extension("json.tmp");
+    std::fs::write(&tmp_path, json)
+        .map_err(|e| format!("Failed to write logon scheme: {}", e))?;
+    std::fs::rename(&tmp_path, path).map_err(|e| format!("Failed to save logon scheme: {}", e))
+}
+
+/// Apply a saved scheme to the current session. Files that no longer exist are skipped so a
+/// deleted library entry falls back to the Windows cursor instead of failing the whole apply.
+/// Returns the roles that were applied, sorted by name.
+pub fn apply_logon_scheme(scheme: &LogonScheme) -> Vec<String> {
+    let existing: HashMap<String, String> = scheme
+        .cursor_paths
+        .iter()
+        .filter(|(_, path)| std::path::Path::new(path).exists())
+        .map(|(name, path)| (name.clone(), path.clone()))
+        .collect();
+
+    crate::cursor_defaults::apply_cursor_paths_advanced(&existing, scheme.cursor_size);
+    let mut applied: Vec<String> = existing.into_keys().collect();
+    applied.sort();
+    applied
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn write_scheme_round_trips() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let path = temp.path().join(LOGON_SCHEME_FILE_NAME);
+        let mut cursor_paths = HashMap::new();
+        cursor_paths.insert("Normal".to_string(), "C:\\cursors\\arrow.cur".to_string());
+        let scheme = LogonScheme {
+            cursor_size: 48,
+            cursor_paths,
+        };
+
+        write_scheme(&path, &scheme).expect("write");
+        let loaded: LogonScheme =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).expect("parse");
+        assert_eq!(loaded, scheme);
+        assert!(!path.with_extension("json.tmp").exists());
+    }
+}
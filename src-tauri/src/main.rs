@@ -2,26 +2,66 @@
 
 #[macro_use]
 mod logging;
+mod log_files;
 
+mod apply_verification;
+mod audio;
+mod cache_gc;
 mod cleanup_hooks;
 mod commands;
+mod config_check;
+mod conversion_output;
+mod crash_reports;
+mod decode_worker;
 mod events;
+mod health_endpoint;
+mod hide_roles;
+mod hot_corners;
+mod hotkey_chords;
+mod hotkey_press;
+mod logon_scheme;
+mod memory_policy;
+mod monitor_schemes;
+mod operations;
 pub mod cursor_converter;
+mod cursor_brightness;
 mod cursor_defaults;
+mod cursor_refresh;
+mod disk_space;
+mod effects_quality;
+mod error_codes;
 mod paths;
+mod pending_jobs;
+mod pointer_control;
+mod portable;
+mod preview_protocol;
+mod reduced_motion;
 mod shortcuts;
+mod size_gesture;
+mod source_watch;
 mod startup;
 mod startup_config;
+mod status_file;
+mod storage_location;
+mod svg_resources;
 mod state;
 mod system;
 mod tests;
+mod timers;
+mod touch_cursors;
 mod tray;
+mod uninstall_cleanup;
+mod usage_stats;
 mod utils;
 mod window;
 mod window_events;
 mod window_setup; // Extracted test modules
+mod window_state;
 
+use commands::customization::file_ops::{ConversionPreviewState, HotspotCalibrationState};
+use commands::customization::pack_trial::PackTrialState;
 use commands::folder_watcher::FolderWatcherState;
+use operations::CancellationRegistry;
 use state::{AppState, MinimizePreference};
 
 use std::sync::Mutex;
@@ -29,13 +69,29 @@ use std::sync::Mutex;
 
 
 fn main() {
+    // Dry-run check for scripts; never opens a window
+    if std::env::args().skip(1).any(|arg| arg == config_check::VALIDATE_CONFIG_FLAG) {
+        std::process::exit(config_check::run());
+    }
+    // Sandboxed preview decoding of one untrusted file over stdio
+    if std::env::args().skip(1).any(|arg| arg == decode_worker::DECODE_WORKER_FLAG) {
+        std::process::exit(decode_worker::run());
+    }
+
     let builder = tauri::Builder::default()
         .manage(AppState::default())
         .manage(MinimizePreference::default())
+        .manage(CancellationRegistry::default())
         .manage(Mutex::new(FolderWatcherState::default()))
+        .manage(Mutex::new(HotspotCalibrationState::default()))
+        .manage(Mutex::new(ConversionPreviewState::default()))
+        .manage(Mutex::new(PackTrialState::default()))
         .plugin(tauri_plugin_global_shortcut::Builder::new().build())
         .plugin(tauri_plugin_dialog::init())
-        .plugin(tauri_plugin_shell::init());
+        .plugin(tauri_plugin_shell::init())
+        .register_uri_scheme_protocol(preview_protocol::PREVIEW_SCHEME, |_ctx, request| {
+            preview_protocol::handle(&request)
+        });
 
 
 
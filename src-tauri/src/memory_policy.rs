@@ -0,0 +1,186 @@
+//! Giving memory back after heavy work.
+//!
+//! Pack extraction, batch conversion and the other long [`Operation`]s decode many images and
+//! leave the tray-resident app holding tens of MB it will not touch again. Each operation holds
+//! a [`HeavyWork`] guard; once the last one finishes and nothing else has started for a while,
+//! the process working set is trimmed. Under [`MemoryPolicy::Aggressive`] the trim comes sooner
+//! and the operations themselves run in background mode, at lower CPU, I/O and memory
+//! priority.
+//!
+//! [`Operation`]: crate::operations::Operation
+
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicU64, AtomicU8, AtomicUsize, Ordering};
+use std::time::Duration;
+use tauri::{AppHandle, Runtime};
+
+use crate::state::settings_file::{self, SettingsFile};
+
+pub const MEMORY_POLICY_FILE_NAME: &str = "memory-policy.json";
+
+/// Quiet time after the last heavy operation before a trim under Balanced
+const BALANCED_TRIM_DELAY: Duration = Duration::from_secs(15);
+/// Quiet time after the last heavy operation before a trim under Aggressive
+const AGGRESSIVE_TRIM_DELAY: Duration = Duration::from_secs(2);
+
+static POLICY: AtomicU8 = AtomicU8::new(MemoryPolicy::Balanced as u8);
+/// Heavy operations running now
+static ACTIVE: AtomicUsize = AtomicUsize::new(0);
+/// Bumped whenever a heavy operation starts, so a pending trim can tell it went stale
+static GENERATION: AtomicU64 = AtomicU64::new(0);
+
+#[derive(ts_rs::TS, Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub enum MemoryPolicy {
+    /// Run heavy work in background mode and trim right after it
+    Aggressive,
+    /// Trim once the app has been idle for a little while after heavy work
+    #[default]
+    Balanced,
+    /// Leave memory and priorities to Windows
+    Off,
+}
+
+impl MemoryPolicy {
+    fn from_u8(value: u8) -> Self {
+        match value {
+            v if v == Self::Aggressive as u8 => Self::Aggressive,
+            v if v == Self::Off as u8 => Self::Off,
+            _ => Self::Balanced,
+        }
+    }
+
+    fn trim_delay(self) -> Option<Duration> {
+        match self {
+            Self::Aggressive => Some(AGGRESSIVE_TRIM_DELAY),
+            Self::Balanced => Some(BALANCED_TRIM_DELAY),
+            Self::Off => None,
+        }
+    }
+}
+
+#[derive(ts_rs::TS, Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct MemoryPolicySettings {
+    #[serde(default)]
+    pub policy: MemoryPolicy,
+}
+
+impl SettingsFile for MemoryPolicySettings {
+    const FILE_NAME: &'static str = MEMORY_POLICY_FILE_NAME;
+    const DESCRIPTION: &'static str = "memory policy";
+}
+
+pub fn load_settings<R: Runtime>(app: &AppHandle<R>) -> MemoryPolicySettings {
+    settings_file::load(app)
+}
+
+pub fn save_settings<R: Runtime>(
+    app: &AppHandle<R>,
+    settings: &MemoryPolicySettings,
+) -> Result<(), String> {
+    settings_file::save(app, settings)?;
+    POLICY.store(settings.policy as u8, Ordering::SeqCst);
+    Ok(())
+}
+
+/// Load the saved policy. Called once at startup.
+pub fn install<R: Runtime>(app: &AppHandle<R>) {
+    POLICY.store(load_settings(app).policy as u8, Ordering::SeqCst);
+}
+
+pub fn current_policy() -> MemoryPolicy {
+    MemoryPolicy::from_u8(POLICY.load(Ordering::SeqCst))
+}
+
+/// Held for the duration of one heavy operation. Dropping the last one schedules a trim.
+pub struct HeavyWork {
+    background: bool,
+}
+
+impl HeavyWork {
+    pub fn begin() -> Self {
+        ACTIVE.fetch_add(1, Ordering::SeqCst);
+        GENERATION.fetch_add(1, Ordering::SeqCst);
+        // Nested operations on the same thread fail to re-enter and leave it to the outer one
+        let background = current_policy() == MemoryPolicy::Aggressive
+            && cursor_changer::set_thread_background_mode(true);
+        Self { background }
+    }
+}
+
+impl Drop for HeavyWork {
+    fn drop(&mut self) {
+        if self.background {
+            let _ = cursor_changer::set_thread_background_mode(false);
+        }
+        let still_active = ACTIVE.fetch_sub(1, Ordering::SeqCst) > 1;
+        if still_active {
+            return;
+        }
+        if let Some(delay) = current_policy().trim_delay() {
+            schedule_trim(delay);
+        }
+    }
+}
+
+/// Trim after `delay` unless another heavy operation starts in the meantime.
+fn schedule_trim(delay: Duration) {
+    let generation = GENERATION.load(Ordering::SeqCst);
+    let spawned = std::thread::Builder::new()
+        .name("memory-trim".to_string())
+        .spawn(move || {
+            std::thread::sleep(delay);
+            if trim_due(generation) && !cursor_changer::trim_working_set() {
+                cc_debug!("[CursorChanger] Working set trim was refused");
+            }
+        });
+    if let Err(e) = spawned {
+        cc_warn!("[CursorChanger] Failed to schedule working set trim: {}", e);
+    }
+}
+
+fn trim_due(scheduled_at: u64) -> bool {
+    ACTIVE.load(Ordering::SeqCst) == 0 && GENERATION.load(Ordering::SeqCst) == scheduled_at
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn policy_defaults_to_balanced_and_round_trips() {
+        let settings: MemoryPolicySettings = serde_json::from_str("{}").unwrap();
+        assert_eq!(settings.policy, MemoryPolicy::Balanced);
+        let json = serde_json::to_string(&MemoryPolicySettings {
+            policy: MemoryPolicy::Aggressive,
+        })
+        .unwrap();
+        assert_eq!(json, r#"{"policy":"aggressive"}"#);
+
+        for policy in [
+            MemoryPolicy::Aggressive,
+            MemoryPolicy::Balanced,
+            MemoryPolicy::Off,
+        ] {
+            assert_eq!(MemoryPolicy::from_u8(policy as u8), policy);
+        }
+    }
+
+    #[test]
+    fn only_off_skips_trimming() {
+        assert!(MemoryPolicy::Off.trim_delay().is_none());
+        assert!(
+            MemoryPolicy::Aggressive.trim_delay().unwrap()
+                < MemoryPolicy::Balanced.trim_delay().unwrap()
+        );
+    }
+
+    #[test]
+    fn a_newer_operation_cancels_a_pending_trim() {
+        let generation = GENERATION.load(Ordering::SeqCst);
+        GENERATION.fetch_add(1, Ordering::SeqCst);
+        assert!(!trim_due(generation));
+    }
+}
@@ -0,0 +1,384 @@
+//! Per-monitor cursor schemes (experimental).
+//!
+//! Windows applies one cursor scheme to every monitor, so a different cursor on one monitor is
+//! faked: while the pointer is on a monitor with its own scheme, the system cursors are blanked
+//! and a click-through overlay draws that monitor's cursor under the pointer. Moving to any
+//! other monitor restores the active scheme. The replica follows a low-level mouse hook, so it
+//! can trail the pointer slightly, and it is not drawn over elevated or full-screen exclusive
+//! windows.
+//!
+//! Roles a monitor's scheme leaves out are drawn with the active scheme's file as it was when
+//! the mode started, or with the monitor's own `Normal` cursor if the active scheme has none.
+
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use std::sync::mpsc::{self, Receiver};
+use std::sync::{Arc, Mutex};
+use tauri::{AppHandle, Manager, Runtime};
+
+use cursor_changer::win_mouse;
+use cursor_changer::win_overlay::{self, CursorOverlay, OverlayImage};
+use cursor_changer::{CURSOR_TYPES, EXTENDED_CURSOR_TYPES};
+
+use crate::commands::cursor_commands::{hide_disabled_by_policy, show_cursor_system};
+use crate::commands::customization::library::load_scaled_cursor_image;
+use crate::state::settings_file::{self, SettingsFile};
+use crate::state::{AppState, CursorVisibility};
+use crate::system;
+
+pub const MONITOR_SCHEMES_FILE_NAME: &str = "monitor-schemes.json";
+pub const DEFAULT_CURSOR_SIZE: u32 = 64;
+pub const MIN_CURSOR_SIZE: u32 = 32;
+pub const MAX_CURSOR_SIZE: u32 = 256;
+/// The one role every monitor scheme must provide; it stands in for roles it leaves out.
+const REQUIRED_ROLE: &str = "Normal";
+
+static OVERLAY: Mutex<Option<CursorOverlay>> = Mutex::new(None);
+
+#[derive(ts_rs::TS, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct MonitorSchemeOverride {
+    /// Device name of the monitor, such as `\\.\DISPLAY2`
+    pub monitor: String,
+    /// Cursor file for each role (`Normal`, `IBeam`, ...); `Normal` is required
+    #[serde(default)]
+    pub cursor_paths: HashMap<String, String>,
+    /// Size the cursor is drawn at on this monitor
+    #[serde(default = "default_cursor_size")]
+    pub cursor_size: u32,
+}
+
+fn default_cursor_size() -> u32 {
+    DEFAULT_CURSOR_SIZE
+}
+
+#[derive(ts_rs::TS, Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct MonitorSchemeSettings {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default)]
+    pub overrides: Vec<MonitorSchemeOverride>,
+}
+
+/// `OCR_*` id of the cursor role called `name`.
+fn role_id(name: &str) -> Option<u32> {
+    CURSOR_TYPES
+        .iter()
+        .chain(EXTENDED_CURSOR_TYPES.iter())
+        .find(|cursor_type| cursor_type.name == name)
+        .map(|cursor_type| cursor_type.id)
+}
+
+impl SettingsFile for MonitorSchemeSettings {
+    const FILE_NAME: &'static str = MONITOR_SCHEMES_FILE_NAME;
+    const DESCRIPTION: &'static str = "monitor scheme settings";
+
+    fn validate(&self) -> Result<(), String> {
+        let mut monitors = HashSet::new();
+        for entry in &self.overrides {
+            let monitor = entry.monitor.trim();
+            if monitor.is_empty() {
+                return Err("Each monitor scheme needs a monitor".to_string());
+            }
+            if !monitors.insert(monitor.to_ascii_lowercase()) {
+                return Err(format!("Monitor {} has more than one scheme", monitor));
+            }
+            if !(MIN_CURSOR_SIZE..=MAX_CURSOR_SIZE).contains(&entry.cursor_size) {
+                return Err(format!(
+                    "Cursor size must be between {} and {} pixels",
+                    MIN_CURSOR_SIZE, MAX_CURSOR_SIZE
+                ));
+            }
+            if !entry.cursor_paths.contains_key(REQUIRED_ROLE) {
+                return Err(format!(
+                    "The scheme for {} needs a {} cursor",
+                    monitor, REQUIRED_ROLE
+                ));
+            }
+            if let Some(role) = entry
+                .cursor_paths
+                .keys()
+                .find(|role| role_id(role).is_none())
+            {
+                return Err(format!("Unknown cursor role: {}", role));
+            }
+        }
+        Ok(())
+    }
+}
+
+pub fn load_settings<R: Runtime>(app: &AppHandle<R>) -> MonitorSchemeSettings {
+    settings_file::load(app)
+}
+
+pub fn save_settings<R: Runtime>(
+    app: &AppHandle<R>,
+    settings: &MonitorSchemeSettings,
+) -> Result<(), String> {
+    settings_file::save(app, settings)
+}
+
+fn overlay_image(path: &str, size: u32) -> Result<Arc<OverlayImage>, String> {
+    let (image, hotspot) = load_scaled_cursor_image(Path::new(path), size)?;
+    OverlayImage::from_rgba(image.width(), image.height(), image.as_raw(), hotspot).map(Arc::new)
+}
+
+/// The images drawn on one monitor, loaded up front because the mouse hook must not wait on
+/// disk.
+struct MonitorImages {
+    monitor: String,
+    by_role: HashMap<u32, Arc<OverlayImage>>,
+    normal: Arc<OverlayImage>,
+}
+
+impl MonitorImages {
+    fn load(
+        entry: &MonitorSchemeOverride,
+        active_paths: &HashMap<String, String>,
+    ) -> Result<Self, String> {
+        let normal_path = entry
+            .cursor_paths
+            .get(REQUIRED_ROLE)
+            .ok_or_else(|| format!("The scheme for {} has no Normal cursor", entry.monitor))?;
+        let normal = overlay_image(normal_path, entry.cursor_size)?;
+
+        let mut by_role = HashMap::new();
+        for cursor_type in CURSOR_TYPES.iter().chain(EXTENDED_CURSOR_TYPES.iter()) {
+            let own = entry.cursor_paths.get(cursor_type.name);
+            let Some(path) = own.or_else(|| active_paths.get(cursor_type.name)) else {
+                continue;
+            };
+            match overlay_image(path, entry.cursor_size) {
+                Ok(image) => {
+                    by_role.insert(cursor_type.id, image);
+                }
+                // The monitor's own files were chosen for it, so those must load
+                Err(e) if own.is_some() => return Err(format!("{}: {}", path, e)),
+                Err(e) => cc_warn!(
+                    "[CursorChanger] Monitor scheme falls back to Normal for {}: {}",
+                    cursor_type.name,
+                    e
+                ),
+            }
+        }
+        Ok(Self {
+            monitor: entry.monitor.trim().to_string(),
+            by_role,
+            normal,
+        })
+    }
+
+    /// Image for the system cursor `cursor_id`, or `None` when there is no system cursor to
+    /// replace (it is hidden, or the app under the pointer draws its own).
+    fn image(&self, cursor_id: Option<u32>) -> Option<Arc<OverlayImage>> {
+        let id = cursor_id?;
+        Some(Arc::clone(self.by_role.get(&id).unwrap_or(&self.normal)))
+    }
+}
+
+fn monitor_images<'a>(monitors: &'a [MonitorImages], name: &str) -> Option<&'a MonitorImages> {
+    monitors
+        .iter()
+        .find(|images| images.monitor.eq_ignore_ascii_case(name))
+}
+
+fn cursor_hidden_by_user(app: &AppHandle) -> bool {
+    app.try_state::<AppState>()
+        .and_then(|state| {
+            state
+                .cursor
+                .read()
+                .ok()
+                .map(|cursor| cursor.visibility.is_hidden())
+        })
+        .unwrap_or(false)
+}
+
+/// Blank or restore the system cursors as the overlay asks. Applying a scheme takes far too
+/// long for the mouse hook, so it happens here. Once the overlay stops, the active scheme is
+/// put back.
+fn sync_system_cursors(app: &AppHandle, requests: Receiver<bool>) {
+    let mut blanked = false;
+    for blank in requests {
+        if blank != blanked {
+            set_blanked(app, blank);
+            blanked = blank;
+        }
+    }
+    if blanked {
+        set_blanked(app, false);
+    }
+}
+
+fn set_blanked(app: &AppHandle, blank: bool) {
+    if blank {
+        // The overlay draws the pointer, so every role is blanked
+        if !system::apply_blank_system_cursors(cursor_changer::BlankRoleMask::ALL) {
+            cc_warn!("[CursorChanger] Failed to hide the system cursor for a monitor scheme");
+        }
+        return;
+    }
+    let state = app.state::<AppState>();
+    let (paths, size) = match (state.cursor.read(), state.prefs.read()) {
+        (Ok(cursor), Ok(prefs)) => {
+            // The user hid the cursor meanwhile, or a hide or restore is running; that one
+            // decides what the cursor shows
+            if cursor.visibility != CursorVisibility::Visible {
+                return;
+            }
+            (cursor.cursor_paths.clone(), prefs.cursor_size)
+        }
+        _ => return,
+    };
+    if !show_cursor_system(&paths, size) {
+        cc_warn!("[CursorChanger] Failed to restore the cursor after leaving a monitor scheme");
+    }
+}
+
+/// Start or stop the overlay to match the saved settings. Called at startup and whenever the
+/// settings change.
+pub fn install(app: &AppHandle) -> Result<(), String> {
+    let settings = load_settings(app);
+    let mut overlay = OVERLAY
+        .lock()
+        .map_err(|_| "Monitor scheme state poisoned".to_string())?;
+    // Dropping the old overlay also ends its worker, which restores the active scheme
+    *overlay = None;
+    if !settings.enabled || settings.overrides.is_empty() {
+        return Ok(());
+    }
+    // The overlay works by blanking the system cursors, which the hide lock forbids
+    if hide_disabled_by_policy(&app.state::<AppState>())? {
+        return Err(
+            "Per-monitor cursor schemes have been disabled by your administrator".to_string(),
+        );
+    }
+
+    let active_paths = app
+        .state::<AppState>()
+        .cursor
+        .read()
+        .map(|cursor| cursor.cursor_paths.clone())
+        .map_err(|_| "Cursor state poisoned".to_string())?;
+    let monitors = settings
+        .overrides
+        .iter()
+        .map(|entry| MonitorImages::load(entry, &active_paths))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let (blank_tx, blank_rx) = mpsc::channel();
+    let worker_app = app.clone();
+    std::thread::Builder::new()
+        .name("monitor-schemes".to_string())
+        .spawn(move || sync_system_cursors(&worker_app, blank_rx))
+        .map_err(|e| format!("Failed to start monitor scheme worker: {}", e))?;
+
+    let hook_app = app.clone();
+    let mut want_blank = false;
+    *overlay = Some(CursorOverlay::start(move |x, y| {
+        let images =
+            win_mouse::monitor_name_at(x, y).and_then(|name| monitor_images(&monitors, &name));
+        let hidden = cursor_hidden_by_user(&hook_app);
+        // Also re-sent after the user shows the cursor again, which reapplies the scheme
+        let blank = images.is_some() && !hidden;
+        if blank != want_blank {
+            want_blank = blank;
+            let _ = blank_tx.send(blank);
+        }
+        if hidden {
+            return None;
+        }
+        images?.image(win_overlay::current_cursor_id())
+    })?);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scheme(monitor: &str, roles: &[&str]) -> MonitorSchemeOverride {
+        MonitorSchemeOverride {
+            monitor: monitor.to_string(),
+            cursor_paths: roles
+                .iter()
+                .map(|role| (role.to_string(), format!("C:/cursors/{}.cur", role)))
+                .collect(),
+            cursor_size: DEFAULT_CURSOR_SIZE,
+        }
+    }
+
+    fn image(size: u32) -> Arc<OverlayImage> {
+        let pixels = vec![255; (size * size * 4) as usize];
+        Arc::new(OverlayImage::from_rgba(size, size, &pixels, (0, 0)).unwrap())
+    }
+
+    #[test]
+    fn settings_default_to_disabled_and_validate() {
+        let settings: MonitorSchemeSettings = serde_json::from_str("{}").unwrap();
+        assert_eq!(settings, MonitorSchemeSettings::default());
+        assert!(settings.validate().is_ok());
+
+        let tv: MonitorSchemeOverride = serde_json::from_str(
+            r#"{"monitor": "\\\\.\\DISPLAY2", "cursor_paths": {"Normal": "C:/tv.cur"}}"#,
+        )
+        .unwrap();
+        assert_eq!(tv.monitor, r"\\.\DISPLAY2");
+        assert_eq!(tv.cursor_size, DEFAULT_CURSOR_SIZE);
+        let settings = MonitorSchemeSettings {
+            enabled: true,
+            overrides: vec![tv, scheme("DISPLAY3", &["Normal", "IBeam"])],
+        };
+        assert!(settings.validate().is_ok());
+    }
+
+    #[test]
+    fn invalid_schemes_are_rejected() {
+        let invalid = |entry: MonitorSchemeOverride| {
+            MonitorSchemeSettings {
+                enabled: true,
+                overrides: vec![entry],
+            }
+            .validate()
+            .is_err()
+        };
+        assert!(invalid(scheme("  ", &["Normal"])));
+        assert!(invalid(scheme("DISPLAY2", &["IBeam"])));
+        assert!(invalid(scheme("DISPLAY2", &["Normal", "Sparkles"])));
+        assert!(invalid(MonitorSchemeOverride {
+            cursor_size: MAX_CURSOR_SIZE + 1,
+            ..scheme("DISPLAY2", &["Normal"])
+        }));
+
+        let duplicate = MonitorSchemeSettings {
+            enabled: true,
+            overrides: vec![
+                scheme("DISPLAY2", &["Normal"]),
+                scheme("display2", &["Normal"]),
+            ],
+        };
+        assert!(duplicate.validate().is_err());
+    }
+
+    #[test]
+    fn roles_without_an_image_draw_normal() {
+        let ibeam = role_id("IBeam").unwrap();
+        let hand = role_id("Hand").unwrap();
+        let images = MonitorImages {
+            monitor: r"\\.\DISPLAY2".to_string(),
+            by_role: HashMap::from([(ibeam, image(2))]),
+            normal: image(1),
+        };
+
+        assert_eq!(images.image(Some(ibeam)).unwrap().size(), (2, 2));
+        assert_eq!(images.image(Some(hand)).unwrap().size(), (1, 1));
+        // No system cursor showing: nothing to replace
+        assert!(images.image(None).is_none());
+
+        let monitors = [images];
+        assert!(monitor_images(&monitors, r"\\.\display2").is_some());
+        assert!(monitor_images(&monitors, r"\\.\DISPLAY1").is_none());
+    }
+}
@@ -0,0 +1,289 @@
+//! Cancellation and progress for long-running commands.
+//!
+//! A command that accepts an `op_id` from the frontend starts an [`Operation`] under that id.
+//! Each stage of the work gets a [`Progress`] handle from [`Operation::phase`], which reports
+//! `operation-progress` events tagged with an [`OperationPhase`], so every long command
+//! (conversion, import, export, extraction, preview generation) feeds one event stream that
+//! the UI renders the same way. Commands call [`Operation::checkpoint`] between steps; once
+//! `cancel_operation(op_id)` has been invoked the next checkpoint emits `operation-cancelled`
+//! and fails with [`OPERATION_CANCELLED_ERROR`]. Commands called without an `op_id` run
+//! untracked. Every operation, tracked or not, also counts as heavy work for the
+//! [memory policy](crate::memory_policy).
+
+use crate::memory_policy::HeavyWork;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use tauri::{AppHandle, Emitter, Manager, Runtime};
+
+/// Error returned by a command whose operation was cancelled.
+pub const OPERATION_CANCELLED_ERROR: &str = "Operation cancelled";
+
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
+}
+
+/// Tokens for the operations currently running, keyed by the frontend's `op_id`.
+#[derive(Debug, Default)]
+pub struct CancellationRegistry {
+    tokens: Mutex<HashMap<String, CancellationToken>>,
+}
+
+impl CancellationRegistry {
+    /// Start tracking `op_id`. Reusing an id that is still running replaces its token.
+    pub fn register(&self, op_id: &str) -> CancellationToken {
+        let token = CancellationToken::default();
+        if let Ok(mut tokens) = self.tokens.lock() {
+            tokens.insert(op_id.to_string(), token.clone());
+        }
+        token
+    }
+
+    /// Request cancellation. Returns false when no operation with that id is running.
+    pub fn cancel(&self, op_id: &str) -> bool {
+        let token = self
+            .tokens
+            .lock()
+            .ok()
+            .and_then(|tokens| tokens.get(op_id).cloned());
+        match token {
+            Some(token) => {
+                token.cancel();
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn finish(&self, op_id: &str, token: &CancellationToken) {
+        if let Ok(mut tokens) = self.tokens.lock() {
+            // Only drop our own token; a newer operation may have reused the id
+            if tokens
+                .get(op_id)
+                .is_some_and(|current| Arc::ptr_eq(&current.0, &token.0))
+            {
+                tokens.remove(op_id);
+            }
+        }
+    }
+
+    pub fn running(&self) -> Vec<String> {
+        self.tokens
+            .lock()
+            .map(|tokens| tokens.keys().cloned().collect())
+            .unwrap_or_default()
+    }
+}
+
+/// Stage of a long operation, so the UI can label progress consistently.
+#[derive(ts_rs::TS, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub enum OperationPhase {
+    Connecting,
+    Downloading,
+    Validating,
+    Converting,
+    Exporting,
+    Extracting,
+    Saving,
+    GeneratingPreviews,
+    Moving,
+}
+
+#[derive(ts_rs::TS, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct OperationProgressPayload {
+    pub op_id: String,
+    pub phase: OperationPhase,
+    pub done: u32,
+    pub total: u32,
+    /// Short description of the current step, e.g. the file being processed
+    pub message: Option<String>,
+}
+
+#[derive(ts_rs::TS, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct OperationCancelledPayload {
+    pub op_id: String,
+}
+
+/// A running long command. Unregisters itself when dropped.
+pub struct Operation<R: Runtime> {
+    app: AppHandle<R>,
+    op_id: Option<String>,
+    token: CancellationToken,
+    _heavy_work: HeavyWork,
+}
+
+impl<R: Runtime> Operation<R> {
+    pub fn begin(app: &AppHandle<R>, op_id: Option<String>) -> Self {
+        let op_id = op_id.filter(|id| !id.trim().is_empty());
+        let token = match (&op_id, app.try_state::<CancellationRegistry>()) {
+            (Some(id), Some(registry)) => registry.register(id),
+            _ => CancellationToken::default(),
+        };
+        Self {
+            app: app.clone(),
+            op_id,
+            token,
+            _heavy_work: HeavyWork::begin(),
+        }
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.token.is_cancelled()
+    }
+
+    /// Fail with [`OPERATION_CANCELLED_ERROR`] if cancellation was requested.
+    pub fn checkpoint(&self) -> Result<(), String> {
+        if !self.is_cancelled() {
+            return Ok(());
+        }
+        if let Some(op_id) = &self.op_id {
+            let _ = self.app.emit(
+                crate::events::OPERATION_CANCELLED,
+                OperationCancelledPayload {
+                    op_id: op_id.clone(),
+                },
+            );
+        }
+        Err(OPERATION_CANCELLED_ERROR.to_string())
+    }
+
+    /// Start reporting `phase` with `total` steps (0 when unknown). Emits 0 of `total` right
+    /// away so the UI can switch its label before the first step completes.
+    pub fn phase(&self, phase: OperationPhase, total: usize) -> Progress<'_, R> {
+        let progress = Progress {
+            operation: self,
+            phase,
+            done: 0,
+            total,
+        };
+        progress.emit(None);
+        progress
+    }
+}
+
+/// Progress of one phase of an [`Operation`]. Does nothing for untracked operations.
+pub struct Progress<'a, R: Runtime> {
+    operation: &'a Operation<R>,
+    phase: OperationPhase,
+    done: usize,
+    total: usize,
+}
+
+impl<R: Runtime> Progress<'_, R> {
+    /// Report `done` of `total`, e.g. bytes downloaded so far.
+    pub fn set(&mut self, done: usize, total: usize, message: Option<&str>) {
+        self.done = done;
+        self.total = total;
+        self.emit(message);
+    }
+
+    /// Report one more step finished; `message` names the item just handled.
+    pub fn advance(&mut self, message: Option<&str>) {
+        self.done = self.done.saturating_add(1);
+        self.emit(message);
+    }
+
+    /// Report the phase complete.
+    pub fn finish(mut self) {
+        self.done = self.done.max(self.total);
+        self.emit(None);
+    }
+
+    fn emit(&self, message: Option<&str>) {
+        let Some(op_id) = &self.operation.op_id else {
+            return;
+        };
+        let _ = self.operation.app.emit(
+            crate::events::OPERATION_PROGRESS,
+            progress_payload(op_id, self.phase, self.done, self.total, message),
+        );
+    }
+}
+
+fn progress_payload(
+    op_id: &str,
+    phase: OperationPhase,
+    done: usize,
+    total: usize,
+    message: Option<&str>,
+) -> OperationProgressPayload {
+    OperationProgressPayload {
+        op_id: op_id.to_string(),
+        phase,
+        done: u32::try_from(done).unwrap_or(u32::MAX),
+        total: u32::try_from(total).unwrap_or(u32::MAX),
+        message: message.map(str::to_string),
+    }
+}
+
+impl<R: Runtime> Drop for Operation<R> {
+    fn drop(&mut self) {
+        if let (Some(op_id), Some(registry)) =
+            (&self.op_id, self.app.try_state::<CancellationRegistry>())
+        {
+            registry.finish(op_id, &self.token);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cancel_only_reaches_running_operations() {
+        let registry = CancellationRegistry::default();
+        assert!(!registry.cancel("missing"));
+
+        let token = registry.register("export");
+        assert!(registry.cancel("export"));
+        assert!(token.is_cancelled());
+
+        registry.finish("export", &token);
+        assert!(registry.running().is_empty());
+        assert!(!registry.cancel("export"));
+    }
+
+    #[test]
+    fn finishing_a_stale_token_keeps_the_newer_one() {
+        let registry = CancellationRegistry::default();
+        let old = registry.register("import");
+        let new = registry.register("import");
+
+        registry.finish("import", &old);
+        assert_eq!(registry.running(), vec!["import".to_string()]);
+        registry.finish("import", &new);
+        assert!(registry.running().is_empty());
+    }
+
+    #[test]
+    fn progress_payload_serializes_phase_and_clamps_counts() {
+        let payload = progress_payload(
+            "export",
+            OperationPhase::GeneratingPreviews,
+            usize::MAX,
+            3,
+            Some("Normal.cur"),
+        );
+        assert_eq!(payload.done, u32::MAX);
+        assert_eq!(payload.total, 3);
+
+        let json = serde_json::to_value(&payload).unwrap();
+        assert_eq!(json["phase"], "generating_previews");
+        assert_eq!(json["message"], "Normal.cur");
+    }
+}
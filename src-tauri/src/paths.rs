@@ -1,15 +1,23 @@
 use std::fs;
-use std::path::{Path, PathBuf};
+use std::path::{Component, Path, PathBuf, Prefix};
 
-pub fn library_root_dir() -> Result<PathBuf, String> {
-    let app_data = std::env::var("APPDATA")
-        .map_err(|_| "Failed to get APPDATA environment variable".to_string())?;
-
-    let app_dir = PathBuf::from(&app_data).join("cursor-changer");
-    let library_dir = app_dir.join("library");
-    let legacy_cursors_dir = app_dir.join("cursors");
+/// Library folder inside the app folder, used unless the user moved the library elsewhere.
+pub fn default_library_root_dir() -> Result<PathBuf, String> {
+    Ok(crate::portable::app_dir()?.join("library"))
+}
 
-    migrate_legacy_cursors_dir(&legacy_cursors_dir, &library_dir)?;
+/// Folder holding the library files: the one chosen through
+/// [`storage_location`](crate::storage_location), or the default one.
+pub fn library_root_dir() -> Result<PathBuf, String> {
+    let library_dir = match crate::storage_location::library_dir() {
+        Some(library_dir) => library_dir,
+        None => {
+            let library_dir = default_library_root_dir()?;
+            let legacy_cursors_dir = crate::portable::app_dir()?.join("cursors");
+            migrate_legacy_cursors_dir(&legacy_cursors_dir, &library_dir)?;
+            library_dir
+        }
+    };
 
     fs::create_dir_all(&library_dir)
         .map_err(|e| format!("Failed to create library directory: {}", e))?;
@@ -39,6 +47,15 @@ pub fn pack_cache_dir() -> Result<PathBuf, String> {
     cursor_packs_dir()
 }
 
+/// Per-pack folders holding the cursor files applied straight from pack archives.
+pub fn pack_mounts_dir() -> Result<PathBuf, String> {
+    let library_dir = library_root_dir()?;
+    let mounts_dir = library_dir.join("pack-mounts");
+    fs::create_dir_all(&mounts_dir)
+        .map_err(|e| format!("Failed to create pack mounts directory: {}", e))?;
+    Ok(mounts_dir)
+}
+
 pub fn ani_preview_cache_dir() -> Result<PathBuf, String> {
     let library_dir = library_root_dir()?;
     let previews_dir = library_dir.join("ani-previews");
@@ -47,6 +64,121 @@ pub fn ani_preview_cache_dir() -> Result<PathBuf, String> {
     Ok(previews_dir)
 }
 
+/// Rendered previews the webview loads through the [preview protocol](crate::preview_protocol).
+pub fn preview_cache_dir() -> Result<PathBuf, String> {
+    let library_dir = library_root_dir()?;
+    let previews_dir = library_dir.join("previews");
+    fs::create_dir_all(&previews_dir)
+        .map_err(|e| format!("Failed to create previews directory: {}", e))?;
+    Ok(previews_dir)
+}
+
+pub fn cursor_variants_dir() -> Result<PathBuf, String> {
+    let library_dir = library_root_dir()?;
+    let variants_dir = library_dir.join("variants");
+    fs::create_dir_all(&variants_dir)
+        .map_err(|e| format!("Failed to create cursor variants directory: {}", e))?;
+    Ok(variants_dir)
+}
+
+/// Crash reports (minidumps and log tails), outside the library so they are never synced.
+pub fn crash_reports_dir() -> Result<PathBuf, String> {
+    let reports_dir = crate::portable::app_dir()?.join("crash-reports");
+    fs::create_dir_all(&reports_dir)
+        .map_err(|e| format!("Failed to create crash reports directory: {}", e))?;
+    Ok(reports_dir)
+}
+
+/// Rotating log files, outside the library so they are never synced.
+pub fn logs_dir() -> Result<PathBuf, String> {
+    let logs_dir = crate::portable::app_dir()?.join("logs");
+    fs::create_dir_all(&logs_dir)
+        .map_err(|e| format!("Failed to create logs directory: {}", e))?;
+    Ok(logs_dir)
+}
+
+/// Whether `a` and `b` name the same file or folder.
+///
+/// Both are canonicalized as far as they exist, so `..`, symlinks and 8.3 short names
+/// resolve, then compared component by component ignoring case and separator style, the way
+/// Windows compares them. Paths to files that no longer exist still compare by their
+/// existing parent folder.
+pub fn canonical_eq(a: &Path, b: &Path) -> bool {
+    a == b || canonical_key(a) == canonical_key(b)
+}
+
+/// Key that two paths share exactly when they are [`canonical_eq`], for sets and maps of
+/// paths.
+pub fn canonical_key(path: &Path) -> String {
+    // No path component can contain '/', so the joined key is unambiguous
+    comparable_components(path).join("/")
+}
+
+/// Whether `path` is `root` or lies inside it, compared like [`canonical_eq`].
+pub fn is_within(path: &Path, root: &Path) -> bool {
+    let path = comparable_components(path);
+    let root = comparable_components(root);
+    !root.is_empty() && path.starts_with(&root)
+}
+
+/// `path` canonicalized from its longest existing ancestor, with the missing rest appended.
+fn resolve_existing(path: &Path) -> PathBuf {
+    let mut existing = path;
+    let mut missing = Vec::new();
+    loop {
+        if let Ok(canonical) = fs::canonicalize(existing) {
+            return missing
+                .iter()
+                .rev()
+                .fold(canonical, |resolved, name| resolved.join(name));
+        }
+        match (existing.parent(), existing.components().next_back()) {
+            (Some(parent), Some(last)) => {
+                missing.push(last.as_os_str().to_os_string());
+                existing = parent;
+            }
+            _ => return path.to_path_buf(),
+        }
+    }
+}
+
+fn comparable_components(path: &Path) -> Vec<String> {
+    let mut parts: Vec<String> = Vec::new();
+    let mut names = 0;
+    for component in resolve_existing(path).components() {
+        match component {
+            // `canonicalize` returns verbatim `\\?\` paths on Windows; fold them into the
+            // plain form
+            Component::Prefix(prefix) => parts.push(match prefix.kind() {
+                Prefix::Disk(drive) | Prefix::VerbatimDisk(drive) => {
+                    format!("{}:", drive.to_ascii_lowercase() as char)
+                }
+                Prefix::UNC(server, share) | Prefix::VerbatimUNC(server, share) => format!(
+                    "\\\\{}\\{}",
+                    server.to_string_lossy(),
+                    share.to_string_lossy()
+                )
+                .to_lowercase(),
+                _ => prefix.as_os_str().to_string_lossy().to_lowercase(),
+            }),
+            Component::RootDir => parts.push(std::path::MAIN_SEPARATOR.to_string()),
+            Component::CurDir => {}
+            // Only reachable in the missing tail; never climb above the root
+            Component::ParentDir => {
+                if names > 0 {
+                    parts.pop();
+                    names -= 1;
+                }
+            }
+            Component::Normal(name) => {
+                parts.push(name.to_string_lossy().to_lowercase());
+                names += 1;
+            }
+        }
+    }
+    parts
+}
+
 fn ensure_library_layout(library_dir: &Path) -> Result<(), String> {
     let cursors_dir = library_dir.join("cursors");
     let packs_dir = library_dir.join("cursor-packs");
@@ -104,7 +236,7 @@ fn migrate_library_files(
     for entry in entries.flatten() {
         let path = entry.path();
 
-        if path == cursors_dir || path == packs_dir {
+        if canonical_eq(&path, cursors_dir) || canonical_eq(&path, packs_dir) {
             continue;
         }
         if path.is_dir() {
@@ -210,3 +342,51 @@ fn relocate_files_with_extensions(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn paths_compare_through_dot_segments_case_and_missing_files() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let cursors = temp.path().join("Library").join("cursors");
+        fs::create_dir_all(&cursors).unwrap();
+        let file = cursors.join("Arrow.cur");
+        fs::write(&file, b"x").unwrap();
+
+        let dotted = temp
+            .path()
+            .join("Library")
+            .join(".")
+            .join("cursors")
+            .join("..")
+            .join("cursors");
+        assert!(canonical_eq(&dotted.join("Arrow.cur"), &file));
+        assert!(canonical_eq(&cursors.join("ARROW.CUR"), &file));
+        assert!(canonical_eq(
+            &cursors.join("gone.cur"),
+            &dotted.join("Gone.cur")
+        ));
+        assert!(!canonical_eq(&cursors.join("Arrow.ani"), &file));
+    }
+
+    #[test]
+    fn within_checks_whole_components() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let packs = temp.path().join("cursor-packs");
+        fs::create_dir_all(&packs).unwrap();
+
+        assert!(is_within(&packs.join("Neon").join("neon.zip"), &packs));
+        assert!(is_within(&packs, &packs));
+        assert!(is_within(
+            &temp.path().join("CURSOR-PACKS").join("a.zip"),
+            &packs
+        ));
+        assert!(!is_within(
+            &temp.path().join("cursor-packs-old").join("a.zip"),
+            &packs
+        ));
+        assert!(!is_within(&packs.join("..").join("escape.zip"), &packs));
+    }
+}
@@ -0,0 +1,264 @@
+//! Journal of apply and import jobs, so work cut off by an exit can be resumed.
+//!
+//! A job is written to `pending-jobs.json` when it starts and removed when it finishes, fails
+//! or is cancelled (the [`JobGuard`] is dropped). Jobs still in the file at startup were
+//! interrupted; `get_pending_jobs` lists them and the UI offers to resume or discard them.
+
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use tauri::{AppHandle, Runtime};
+
+pub const PENDING_JOBS_FILE_NAME: &str = "pending-jobs.json";
+
+static JOURNAL: Mutex<Option<Journal>> = Mutex::new(None);
+
+#[derive(ts_rs::TS, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub enum PendingJobKind {
+    /// `target` is the pack URL
+    ImportPackFromUrl,
+    /// `target` is the library pack ID
+    ApplyPack,
+}
+
+#[derive(ts_rs::TS, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct PendingJob {
+    /// Also used as the `op_id` when the job is resumed
+    pub id: String,
+    pub kind: PendingJobKind,
+    pub target: String,
+    /// What the job was doing, for the resume prompt
+    pub description: String,
+    pub queued_at: String,
+}
+
+#[derive(Debug, Default)]
+struct Journal {
+    path: PathBuf,
+    /// Left over from an earlier run, waiting for resume or discard
+    interrupted: Vec<PendingJob>,
+    /// Started by this run
+    running: Vec<PendingJob>,
+}
+
+impl Journal {
+    /// Write both lists so jobs survive another interrupted run.
+    fn save(&self) {
+        let jobs: Vec<&PendingJob> = self.interrupted.iter().chain(&self.running).collect();
+        if let Err(e) = write_jobs(&self.path, &jobs) {
+            cc_debug!("[CursorChanger] {}", e);
+        }
+    }
+
+    /// Put a job taken for resuming back among the interrupted ones, keeping them oldest
+    /// first.
+    fn restore(&mut self, job: PendingJob) {
+        self.interrupted.retain(|existing| existing.id != job.id);
+        let index = self
+            .interrupted
+            .iter()
+            .position(|existing| existing.queued_at > job.queued_at)
+            .unwrap_or(self.interrupted.len());
+        self.interrupted.insert(index, job);
+        self.save();
+    }
+}
+
+fn read_jobs(path: &Path) -> Vec<PendingJob> {
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+fn write_jobs(path: &Path, jobs: &[&PendingJob]) -> Result<(), String> {
+    if jobs.is_empty() {
+        if path.exists() {
+            fs::remove_file(path).map_err(|e| format!("Failed to delete pending jobs: {}", e))?;
+        }
+        return Ok(());
+    }
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create config directory: {}", e))?;
+    }
+    let json = serde_json::to_string_pretty(jobs)
+        .map_err(|e| format!("Failed to serialize pending jobs: {}", e))?;
+    let tmp_path = path.with_extension("json.tmp");
+    fs::write(&tmp_path, json).map_err(|e| format!("Failed to write pending jobs: {}", e))?;
+    fs::rename(&tmp_path, path).map_err(|e| format!("Failed to save pending jobs: {}", e))
+}
+
+fn with_journal<T>(f: impl FnOnce(&mut Journal) -> T) -> Option<T> {
+    let mut guard = JOURNAL.lock().unwrap_or_else(|e| e.into_inner());
+    guard.as_mut().map(f)
+}
+
+/// Load the jobs interrupted by the last exit. Called once at startup.
+pub fn init<R: Runtime>(app: &AppHandle<R>) {
+    match crate::state::config::config_path(app) {
+        Ok(dir) => {
+            let path = dir.join(PENDING_JOBS_FILE_NAME);
+            let interrupted = read_jobs(&path);
+            if !interrupted.is_empty() {
+                cc_info!(
+                    "[CursorChanger] {} job(s) were interrupted by the last exit",
+                    interrupted.len()
+                );
+            }
+            *JOURNAL.lock().unwrap_or_else(|e| e.into_inner()) = Some(Journal {
+                path,
+                interrupted,
+                running: Vec::new(),
+            });
+        }
+        Err(e) => cc_warn!("[CursorChanger] Pending jobs unavailable: {}", e),
+    }
+}
+
+/// Removes its job from the journal when dropped.
+#[must_use = "the job is forgotten as soon as the guard is dropped"]
+pub struct JobGuard {
+    id: String,
+}
+
+impl Drop for JobGuard {
+    fn drop(&mut self) {
+        with_journal(|journal| {
+            journal.running.retain(|job| job.id != self.id);
+            journal.save();
+        });
+    }
+}
+
+/// Journal a job until the returned guard is dropped. `op_id` becomes the job ID when given.
+pub fn track(
+    kind: PendingJobKind,
+    target: &str,
+    description: &str,
+    op_id: Option<&str>,
+) -> JobGuard {
+    let id = op_id.filter(|id| !id.trim().is_empty()).map_or_else(
+        || format!("job_{}", uuid::Uuid::now_v7().simple()),
+        str::to_string,
+    );
+    let job = PendingJob {
+        id: id.clone(),
+        kind,
+        target: target.to_string(),
+        description: description.to_string(),
+        queued_at: crate::utils::library_meta::now_iso8601_utc(),
+    };
+    with_journal(|journal| {
+        journal.running.retain(|existing| existing.id != job.id);
+        journal.running.push(job);
+        journal.save();
+    });
+    JobGuard { id }
+}
+
+/// Jobs interrupted by the last exit, oldest first.
+pub fn interrupted() -> Vec<PendingJob> {
+    with_journal(|journal| journal.interrupted.clone()).unwrap_or_default()
+}
+
+/// Remove an interrupted job to run it again.
+pub fn take(id: &str) -> Option<PendingJob> {
+    with_journal(|journal| {
+        let index = journal.interrupted.iter().position(|job| job.id == id)?;
+        let job = journal.interrupted.remove(index);
+        journal.save();
+        Some(job)
+    })
+    .flatten()
+}
+
+/// Return a job from [`take`] whose resume failed, so it can be resumed or discarded later.
+pub fn restore(job: PendingJob) {
+    with_journal(|journal| journal.restore(job));
+}
+
+/// Forget every interrupted job. Returns how many were dropped.
+pub fn discard_interrupted() -> usize {
+    with_journal(|journal| {
+        let count = journal.interrupted.len();
+        journal.interrupted.clear();
+        journal.save();
+        count
+    })
+    .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn job(id: &str) -> PendingJob {
+        PendingJob {
+            id: id.to_string(),
+            kind: PendingJobKind::ApplyPack,
+            target: "lib_1".to_string(),
+            description: "Apply Neon".to_string(),
+            queued_at: "2026-01-01T00:00:00Z".to_string(),
+        }
+    }
+
+    #[test]
+    fn journal_keeps_interrupted_and_running_jobs() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir.path().join(PENDING_JOBS_FILE_NAME);
+        let mut journal = Journal {
+            path: path.clone(),
+            interrupted: vec![job("old")],
+            running: vec![job("new")],
+        };
+        journal.save();
+        let ids: Vec<String> = read_jobs(&path).into_iter().map(|j| j.id).collect();
+        assert_eq!(ids, ["old", "new"]);
+
+        journal.interrupted.clear();
+        journal.running.clear();
+        journal.save();
+        assert!(!path.exists());
+        assert!(read_jobs(&path).is_empty());
+    }
+
+    #[test]
+    fn restored_jobs_keep_their_place() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir.path().join(PENDING_JOBS_FILE_NAME);
+        let queued = |id: &str, at: &str| PendingJob {
+            queued_at: at.to_string(),
+            ..job(id)
+        };
+        let mut journal = Journal {
+            path: path.clone(),
+            interrupted: vec![
+                queued("first", "2026-01-01T00:00:00Z"),
+                queued("third", "2026-01-03T00:00:00Z"),
+            ],
+            running: Vec::new(),
+        };
+
+        journal.restore(queued("second", "2026-01-02T00:00:00Z"));
+        journal.restore(queued("second", "2026-01-02T00:00:00Z"));
+
+        let ids: Vec<String> = read_jobs(&path).into_iter().map(|j| j.id).collect();
+        assert_eq!(ids, ["first", "second", "third"]);
+        assert!(!path.with_extension("json.tmp").exists());
+    }
+
+    #[test]
+    fn job_kinds_serialize_in_snake_case() {
+        let json = serde_json::to_value(PendingJob {
+            kind: PendingJobKind::ImportPackFromUrl,
+            ..job("a")
+        })
+        .unwrap();
+        assert_eq!(json["kind"], "import_pack_from_url");
+    }
+}
@@ -0,0 +1,344 @@
+//! Keyboard pointer control ("mouse keys lite").
+//!
+//! An accessibility option for users who find the mouse hard to handle. When enabled, a
+//! low-level keyboard hook turns the bound keys (the numeric keypad by default) into pointer
+//! moves and clicks and swallows them. A move key nudges the pointer by `step_px`; while it is
+//! held, auto-repeat keeps nudging and the step grows to `max_step_px` over `acceleration_ms`.
+//! Clicks fire once per press.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Runtime};
+
+use cursor_changer::win_keyboard::KeyCapture;
+use cursor_changer::win_mouse::{self, MouseButton};
+
+use crate::state::settings_file::{self, SettingsFile};
+use crate::timers::{self, TimerPriority};
+
+pub const POINTER_CONTROL_FILE_NAME: &str = "pointer-control.json";
+pub const DEFAULT_STEP_PX: u32 = 4;
+pub const DEFAULT_MAX_STEP_PX: u32 = 32;
+pub const MAX_STEP_PX: u32 = 200;
+pub const DEFAULT_ACCELERATION_MS: u32 = 1500;
+
+const VK_NUMPAD0: u32 = 0x60;
+const VK_NUMPAD2: u32 = 0x62;
+const VK_NUMPAD4: u32 = 0x64;
+const VK_NUMPAD5: u32 = 0x65;
+const VK_NUMPAD6: u32 = 0x66;
+const VK_NUMPAD8: u32 = 0x68;
+
+static CAPTURE: Mutex<Option<KeyCapture>> = Mutex::new(None);
+
+/// Virtual-key codes bound to each pointer action.
+#[derive(ts_rs::TS, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct PointerKeyBindings {
+    pub up: u32,
+    pub down: u32,
+    pub left: u32,
+    pub right: u32,
+    pub left_click: u32,
+    pub right_click: u32,
+}
+
+impl Default for PointerKeyBindings {
+    fn default() -> Self {
+        Self {
+            up: VK_NUMPAD8,
+            down: VK_NUMPAD2,
+            left: VK_NUMPAD4,
+            right: VK_NUMPAD6,
+            left_click: VK_NUMPAD5,
+            right_click: VK_NUMPAD0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PointerAction {
+    /// Unit direction of the move
+    Move(i32, i32),
+    Click(MouseButton),
+}
+
+impl PointerKeyBindings {
+    fn all(&self) -> [u32; 6] {
+        [
+            self.up,
+            self.down,
+            self.left,
+            self.right,
+            self.left_click,
+            self.right_click,
+        ]
+    }
+
+    fn action(&self, vk: u32) -> Option<PointerAction> {
+        match vk {
+            _ if vk == self.up => Some(PointerAction::Move(0, -1)),
+            _ if vk == self.down => Some(PointerAction::Move(0, 1)),
+            _ if vk == self.left => Some(PointerAction::Move(-1, 0)),
+            _ if vk == self.right => Some(PointerAction::Move(1, 0)),
+            _ if vk == self.left_click => Some(PointerAction::Click(MouseButton::Left)),
+            _ if vk == self.right_click => Some(PointerAction::Click(MouseButton::Right)),
+            _ => None,
+        }
+    }
+}
+
+#[derive(ts_rs::TS, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct PointerControlSettings {
+    #[serde(default)]
+    pub enabled: bool,
+    /// Pixels per nudge when a move key is first pressed
+    #[serde(default = "default_step_px")]
+    pub step_px: u32,
+    /// Pixels per nudge once a move key has been held for `acceleration_ms`
+    #[serde(default = "default_max_step_px")]
+    pub max_step_px: u32,
+    /// Hold time to reach full speed; 0 keeps the step constant
+    #[serde(default = "default_acceleration_ms")]
+    pub acceleration_ms: u32,
+    #[serde(default)]
+    pub keys: PointerKeyBindings,
+}
+
+fn default_step_px() -> u32 {
+    DEFAULT_STEP_PX
+}
+
+fn default_max_step_px() -> u32 {
+    DEFAULT_MAX_STEP_PX
+}
+
+fn default_acceleration_ms() -> u32 {
+    DEFAULT_ACCELERATION_MS
+}
+
+impl Default for PointerControlSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            step_px: DEFAULT_STEP_PX,
+            max_step_px: DEFAULT_MAX_STEP_PX,
+            acceleration_ms: DEFAULT_ACCELERATION_MS,
+            keys: PointerKeyBindings::default(),
+        }
+    }
+}
+
+impl PointerControlSettings {
+    /// Pixels per nudge after a move key has been held for `held`.
+    fn step_for(&self, held: Duration) -> i32 {
+        let step = self.step_px.min(MAX_STEP_PX);
+        let max = self.max_step_px.clamp(step, MAX_STEP_PX);
+        let ramp = Duration::from_millis(u64::from(self.acceleration_ms));
+        let step = if ramp.is_zero() {
+            step
+        } else if held >= ramp {
+            max
+        } else {
+            let progress = held.as_secs_f32() / ramp.as_secs_f32();
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            let extra = ((max - step) as f32 * progress).round() as u32;
+            step + extra
+        };
+        i32::try_from(step).unwrap_or(i32::MAX)
+    }
+}
+
+impl SettingsFile for PointerControlSettings {
+    const FILE_NAME: &'static str = POINTER_CONTROL_FILE_NAME;
+    const DESCRIPTION: &'static str = "pointer control settings";
+
+    fn validate(&self) -> Result<(), String> {
+        if self.step_px == 0 || self.step_px > MAX_STEP_PX {
+            return Err(format!("Step must be between 1 and {} pixels", MAX_STEP_PX));
+        }
+        if self.max_step_px < self.step_px || self.max_step_px > MAX_STEP_PX {
+            return Err(format!(
+                "Maximum step must be between the step and {} pixels",
+                MAX_STEP_PX
+            ));
+        }
+        let keys = self.keys.all();
+        if keys.iter().any(|&vk| vk == 0 || vk > 0xFE) {
+            return Err("Every pointer action needs a key".to_string());
+        }
+        if keys
+            .iter()
+            .enumerate()
+            .any(|(i, vk)| keys[i + 1..].contains(vk))
+        {
+            return Err("Each pointer action needs a different key".to_string());
+        }
+        Ok(())
+    }
+}
+
+pub fn load_settings<R: Runtime>(app: &AppHandle<R>) -> PointerControlSettings {
+    settings_file::load(app)
+}
+
+pub fn save_settings<R: Runtime>(
+    app: &AppHandle<R>,
+    settings: &PointerControlSettings,
+) -> Result<(), String> {
+    settings_file::save(app, settings)
+}
+
+/// Tracks which bound keys are held and since when, to tell presses from auto-repeat.
+#[derive(Debug, Default)]
+struct HeldKeys {
+    since: HashMap<u32, Instant>,
+}
+
+impl HeldKeys {
+    /// Record a key-down. Returns whether it is a fresh press and how long the key has been
+    /// held.
+    fn press(&mut self, vk: u32, now: Instant) -> (bool, Duration) {
+        let fresh = !self.since.contains_key(&vk);
+        let since = *self.since.entry(vk).or_insert(now);
+        (fresh, now.saturating_duration_since(since))
+    }
+
+    fn release(&mut self, vk: u32) {
+        self.since.remove(&vk);
+    }
+}
+
+/// The pointer input for one key-down, if any.
+fn input_for(
+    settings: &PointerControlSettings,
+    action: PointerAction,
+    fresh: bool,
+    held: Duration,
+) -> Option<PointerAction> {
+    match action {
+        PointerAction::Move(dx, dy) => {
+            let step = settings.step_for(held);
+            Some(PointerAction::Move(dx * step, dy * step))
+        }
+        PointerAction::Click(_) if fresh => Some(action),
+        PointerAction::Click(_) => None,
+    }
+}
+
+fn send(input: PointerAction) {
+    let result = match input {
+        PointerAction::Move(dx, dy) => win_mouse::move_pointer_by(dx, dy),
+        PointerAction::Click(button) => win_mouse::click(button),
+    };
+    if let Err(e) = result {
+        cc_debug!("[CursorChanger] Pointer control: {}", e);
+    }
+}
+
+/// Start or stop the keyboard hook to match the saved settings. Called at startup and whenever
+/// the settings change.
+pub fn install<R: Runtime>(app: &AppHandle<R>) -> Result<(), String> {
+    let settings = load_settings(app);
+    let mut capture = CAPTURE
+        .lock()
+        .map_err(|_| "Pointer control state poisoned".to_string())?;
+    // Drop any previous hook first so a settings change never leaves two running
+    *capture = None;
+    if !settings.enabled {
+        return Ok(());
+    }
+
+    let mut held = HeldKeys::default();
+    *capture = Some(KeyCapture::start_with_releases(move |vk, down| {
+        let Some(action) = settings.keys.action(vk) else {
+            return false;
+        };
+        if !down {
+            held.release(vk);
+            return true;
+        }
+        let (fresh, held_for) = held.press(vk, Instant::now());
+        if let Some(input) = input_for(&settings, action, fresh, held_for) {
+            // The hook must return quickly, so the input is sent from the timer service
+            timers::after(Duration::ZERO, TimerPriority::Essential, move || {
+                send(input)
+            });
+        }
+        true
+    })?);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn step_accelerates_while_held() {
+        let settings = PointerControlSettings {
+            step_px: 4,
+            max_step_px: 24,
+            acceleration_ms: 1000,
+            ..PointerControlSettings::default()
+        };
+        assert_eq!(settings.step_for(Duration::ZERO), 4);
+        assert_eq!(settings.step_for(Duration::from_millis(500)), 14);
+        assert_eq!(settings.step_for(Duration::from_secs(5)), 24);
+
+        let constant = PointerControlSettings {
+            acceleration_ms: 0,
+            ..settings
+        };
+        assert_eq!(constant.step_for(Duration::from_secs(5)), 4);
+    }
+
+    #[test]
+    fn clicks_fire_once_per_press_and_moves_repeat() {
+        let settings = PointerControlSettings::default();
+        let keys = settings.keys;
+        let mut held = HeldKeys::default();
+        let start = Instant::now();
+
+        let click = keys.action(keys.left_click).unwrap();
+        let (fresh, held_for) = held.press(keys.left_click, start);
+        assert_eq!(
+            input_for(&settings, click, fresh, held_for),
+            Some(PointerAction::Click(MouseButton::Left))
+        );
+        let (fresh, held_for) = held.press(keys.left_click, start + Duration::from_millis(40));
+        assert_eq!(input_for(&settings, click, fresh, held_for), None);
+        held.release(keys.left_click);
+        assert!(held.press(keys.left_click, start).0);
+
+        let left = keys.action(keys.left).unwrap();
+        let (fresh, held_for) = held.press(keys.left, start);
+        assert_eq!(
+            input_for(&settings, left, fresh, held_for),
+            Some(PointerAction::Move(-(DEFAULT_STEP_PX as i32), 0))
+        );
+        assert_eq!(keys.action(0x41), None);
+    }
+
+    #[test]
+    fn settings_validation() {
+        let settings: PointerControlSettings = serde_json::from_str("{}").unwrap();
+        assert!(!settings.enabled);
+        assert_eq!(settings.keys, PointerKeyBindings::default());
+        assert!(settings.validate().is_ok());
+
+        let slow_max = PointerControlSettings {
+            step_px: 10,
+            max_step_px: 5,
+            ..PointerControlSettings::default()
+        };
+        assert!(slow_max.validate().is_err());
+
+        let mut shared_key = PointerControlSettings::default();
+        shared_key.keys.right_click = shared_key.keys.up;
+        assert!(shared_key.validate().is_err());
+    }
+}
@@ -0,0 +1,139 @@
+//! Portable mode.
+//!
+//! A `portable` marker file next to the executable (e.g. on a USB stick) keeps all settings and
+//! the library in a `data` folder beside it instead of `%APPDATA%`. Paths inside that folder are
+//! stored relative to it and resolved again on load, so the whole folder can move between
+//! machines and drive letters.
+
+use std::path::{Component, Path, PathBuf};
+use std::sync::OnceLock;
+
+pub const PORTABLE_MARKER_FILE_NAME: &str = "portable";
+const PORTABLE_DATA_DIR_NAME: &str = "data";
+
+static DATA_ROOT: OnceLock<Option<PathBuf>> = OnceLock::new();
+
+fn detect(exe_dir: &Path) -> Option<PathBuf> {
+    exe_dir
+        .join(PORTABLE_MARKER_FILE_NAME)
+        .is_file()
+        .then(|| exe_dir.join(PORTABLE_DATA_DIR_NAME))
+}
+
+/// The portable data folder, or `None` for a regular install. Detected once per process.
+pub fn data_root() -> Option<&'static Path> {
+    DATA_ROOT
+        .get_or_init(|| {
+            let exe = std::env::current_exe().ok()?;
+            let root = detect(exe.parent()?)?;
+            cc_info!("[CursorChanger] Portable mode, data in {}", root.display());
+            Some(root)
+        })
+        .as_deref()
+}
+
+pub fn is_portable() -> bool {
+    data_root().is_some()
+}
+
+/// Root of the app's own files: the portable data folder or `%APPDATA%\cursor-changer`.
+pub fn app_dir() -> Result<PathBuf, String> {
+    if let Some(root) = data_root() {
+        return Ok(root.to_path_buf());
+    }
+    let app_data = std::env::var("APPDATA")
+        .map_err(|_| "Failed to get APPDATA environment variable".to_string())?;
+    Ok(PathBuf::from(app_data).join("cursor-changer"))
+}
+
+/// `path` relative to `root` with `/` separators, or unchanged when it lies outside `root`.
+fn relative_to(root: &Path, path: &str) -> String {
+    match Path::new(path).strip_prefix(root) {
+        Ok(relative)
+            if !relative.as_os_str().is_empty()
+                && relative
+                    .components()
+                    .all(|c| matches!(c, Component::Normal(_))) =>
+        {
+            relative
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/")
+        }
+        _ => path.to_string(),
+    }
+}
+
+/// A stored path made absolute again. Absolute and empty paths are returned unchanged.
+fn resolve_against(root: &Path, stored: &str) -> String {
+    if stored.is_empty() || Path::new(stored).is_absolute() {
+        return stored.to_string();
+    }
+    root.join(stored).to_string_lossy().into_owned()
+}
+
+/// Form of `path` to write to disk: relative to the data root in portable mode.
+pub fn to_stored(path: &str) -> String {
+    match data_root() {
+        Some(root) => relative_to(root, path),
+        None => path.to_string(),
+    }
+}
+
+/// Absolute path for a value written by [`to_stored`].
+pub fn resolve_stored(stored: &str) -> String {
+    match data_root() {
+        Some(root) => resolve_against(root, stored),
+        None => stored.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn marker_file_enables_portable_mode() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        assert_eq!(detect(dir.path()), None);
+
+        std::fs::write(dir.path().join(PORTABLE_MARKER_FILE_NAME), "").unwrap();
+        assert_eq!(
+            detect(dir.path()),
+            Some(dir.path().join(PORTABLE_DATA_DIR_NAME))
+        );
+    }
+
+    #[test]
+    fn paths_under_the_root_round_trip_as_relative() {
+        let root = std::env::temp_dir().join("stick").join("data");
+        let inside = root.join("library").join("cursors").join("arrow.cur");
+        let inside = inside.to_string_lossy();
+
+        let stored = relative_to(&root, &inside);
+        assert_eq!(stored, "library/cursors/arrow.cur");
+        assert_eq!(
+            PathBuf::from(resolve_against(&root, &stored)),
+            root.join("library/cursors/arrow.cur")
+        );
+
+        // Moved to another drive or folder
+        let moved = std::env::temp_dir().join("other").join("data");
+        assert_eq!(
+            PathBuf::from(resolve_against(&moved, &stored)),
+            moved.join("library/cursors/arrow.cur")
+        );
+    }
+
+    #[test]
+    fn paths_outside_the_root_stay_absolute() {
+        let root = std::env::temp_dir().join("stick").join("data");
+        let outside = std::env::temp_dir().join("elsewhere").join("arrow.cur");
+        let outside = outside.to_string_lossy();
+
+        assert_eq!(relative_to(&root, &outside), outside);
+        assert_eq!(resolve_against(&root, &outside), outside);
+        assert_eq!(resolve_against(&root, ""), "");
+    }
+}
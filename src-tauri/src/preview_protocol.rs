@@ -0,0 +1,223 @@
+//! `cc-preview` URI scheme for cursor previews.
+//!
+//! Base64 data URLs make every preview payload a third larger than the image it carries.
+//! Preview commands instead [`publish`] the image into the preview cache under a hash of its
+//! content and return a short URL; the webview then loads the bytes through [`handle`], which
+//! reads them straight from the cache. Identical previews share one file and one URL, so the
+//! webview's own image cache keeps working across calls. Files published this session are
+//! never evicted by cache GC, so a URL the webview holds keeps resolving.
+
+use base64::Engine;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::BTreeSet;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::io::Write;
+use std::path::Path;
+use std::sync::Mutex;
+use std::time::SystemTime;
+use tauri::http::{header, Request, Response, StatusCode};
+
+use crate::commands::customization::library::PreviewImage;
+
+pub const PREVIEW_SCHEME: &str = "cc-preview";
+
+/// Cache file names handed out as URLs since startup.
+static PUBLISHED: Mutex<BTreeSet<String>> = Mutex::new(BTreeSet::new());
+
+/// Image types worth serving, with the extension their cache files get. Anything else (SVG
+/// placeholders, raw ANI files) stays a data URL.
+const SERVED_TYPES: [(&str, &str); 6] = [
+    ("image/png", "png"),
+    ("image/x-icon", "ico"),
+    ("image/jpeg", "jpg"),
+    ("image/bmp", "bmp"),
+    ("image/webp", "webp"),
+    ("image/gif", "gif"),
+];
+
+fn extension_for(mime: &str) -> Option<&'static str> {
+    SERVED_TYPES
+        .iter()
+        .find(|(served, _)| served.eq_ignore_ascii_case(mime))
+        .map(|(_, extension)| *extension)
+}
+
+fn mime_for(extension: &str) -> Option<&'static str> {
+    SERVED_TYPES
+        .iter()
+        .find(|(_, served)| *served == extension)
+        .map(|(mime, _)| *mime)
+}
+
+/// URL the webview loads the cache file `file_name` from. WebView2 reaches custom schemes
+/// through `http://<scheme>.localhost`, the other webviews through the scheme itself.
+pub fn preview_url(file_name: &str) -> String {
+    if cfg!(windows) {
+        format!("http://{PREVIEW_SCHEME}.localhost/{file_name}")
+    } else {
+        format!("{PREVIEW_SCHEME}://localhost/{file_name}")
+    }
+}
+
+/// Cache file name for `bytes`: a hash of the content plus the extension for its type.
+fn cache_file_name(bytes: &[u8], extension: &str) -> String {
+    let mut hasher = DefaultHasher::new();
+    bytes.hash(&mut hasher);
+    format!("{:016x}.{}", hasher.finish(), extension)
+}
+
+/// The cache file a request path names and its type, if [`cache_file_name`] could have
+/// produced it. Nothing else under the cache folder, or outside it, can be requested.
+fn requested_file(path: &str) -> Option<(&str, &'static str)> {
+    let name = path.strip_prefix('/').unwrap_or(path);
+    let (hash, extension) = name.split_once('.')?;
+    if hash.len() != 16 || !hash.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    Some((name, mime_for(extension)?))
+}
+
+fn store(bytes: &[u8], extension: &str) -> Result<String, String> {
+    let dir = crate::paths::preview_cache_dir()?;
+    let file_name = cache_file_name(bytes, extension);
+    let path = dir.join(&file_name);
+    if path.exists() {
+        // Bump the timestamp so cache GC treats this preview as recently used.
+        let _ = fs::File::options()
+            .append(true)
+            .open(&path)
+            .and_then(|f| f.set_modified(SystemTime::now()));
+        return Ok(file_name);
+    }
+
+    let mut temp_file = tempfile::NamedTempFile::new_in(&dir)
+        .map_err(|e| format!("Failed to create preview file: {}", e))?;
+    temp_file
+        .write_all(bytes)
+        .map_err(|e| format!("Failed to write preview file: {}", e))?;
+    temp_file
+        .persist(&path)
+        .map_err(|e| format!("Failed to save preview file: {}", e))?;
+    Ok(file_name)
+}
+
+/// Whether `path` is a preview file whose URL was returned this session.
+pub fn is_published_this_session(path: &Path) -> bool {
+    let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+        return false;
+    };
+    PUBLISHED
+        .lock()
+        .map(|published| published.contains(name))
+        .unwrap_or(true)
+}
+
+fn served_url(mime: &str, bytes: &[u8]) -> Option<String> {
+    let extension = extension_for(mime)?;
+    match store(bytes, extension) {
+        Ok(file_name) => {
+            if let Ok(mut published) = PUBLISHED.lock() {
+                published.insert(file_name.clone());
+            }
+            Some(preview_url(&file_name))
+        }
+        Err(e) => {
+            cc_warn!("[CursorChanger] Sending preview inline: {}", e);
+            None
+        }
+    }
+}
+
+/// URL for `image`, or its data URL when it is not a servable image or cannot be cached.
+pub fn publish(image: &PreviewImage) -> String {
+    served_url(image.mime, &image.bytes).unwrap_or_else(|| image.to_data_url())
+}
+
+/// [`publish`] for a preview that is already a base64 data URL, such as one from the decode
+/// worker. Other data URLs, e.g. percent-encoded SVG placeholders, are returned unchanged.
+pub fn publish_data_url(data_url: String) -> String {
+    let Some((mime, payload)) = data_url
+        .strip_prefix("data:")
+        .and_then(|rest| rest.split_once(";base64,"))
+    else {
+        return data_url;
+    };
+    if extension_for(mime).is_none() {
+        return data_url;
+    }
+    let Ok(bytes) = base64::engine::general_purpose::STANDARD.decode(payload) else {
+        return data_url;
+    };
+    served_url(mime, &bytes).unwrap_or(data_url)
+}
+
+/// Serve a published preview from the cache. Anything else is a 404.
+pub fn handle(request: &Request<Vec<u8>>) -> Response<Vec<u8>> {
+    let found = requested_file(request.uri().path()).and_then(|(name, mime)| {
+        let bytes = fs::read(crate::paths::preview_cache_dir().ok()?.join(name)).ok()?;
+        Some((mime, bytes))
+    });
+    let response = match found {
+        // The name is a hash of the content, so a URL never changes what it serves
+        Some((mime, bytes)) => Response::builder()
+            .header(header::CONTENT_TYPE, mime)
+            .header(header::CACHE_CONTROL, "max-age=31536000, immutable")
+            .body(bytes),
+        None => Response::builder()
+            .status(StatusCode::NOT_FOUND)
+            .body(Vec::new()),
+    };
+    response.unwrap_or_else(|_| Response::new(Vec::new()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cache_names_follow_content_and_type() {
+        let name = cache_file_name(b"png bytes", "png");
+        assert_eq!(name, cache_file_name(b"png bytes", "png"));
+        assert_ne!(name, cache_file_name(b"other bytes", "png"));
+        assert!(name.ends_with(".png"));
+        assert_eq!(
+            requested_file(&format!("/{name}")),
+            Some((&name[..], "image/png"))
+        );
+    }
+
+    #[test]
+    fn only_cache_file_names_can_be_requested() {
+        assert_eq!(
+            requested_file("/0123456789abcdef.ico"),
+            Some(("0123456789abcdef.ico", "image/x-icon"))
+        );
+        assert_eq!(requested_file("/0123456789abcdef.exe"), None);
+        assert_eq!(requested_file("/../0123456789abcdef.png"), None);
+        assert_eq!(requested_file("/0123456789abcdeg.png"), None);
+        assert_eq!(requested_file("/library.json"), None);
+        assert_eq!(requested_file("/"), None);
+    }
+
+    #[test]
+    fn published_files_are_remembered_for_the_session() {
+        let path = std::path::PathBuf::from("previews").join("00000000000000aa.png");
+        assert!(!is_published_this_session(&path));
+        PUBLISHED
+            .lock()
+            .unwrap()
+            .insert("00000000000000aa.png".to_string());
+        assert!(is_published_this_session(&path));
+    }
+
+    #[test]
+    fn data_urls_that_are_not_served_images_pass_through() {
+        let svg = "data:image/svg+xml;charset=utf-8,%3Csvg%3E".to_string();
+        assert_eq!(publish_data_url(svg.clone()), svg);
+        let ani = "data:application/x-navi-animation;base64,UklGRg==".to_string();
+        assert_eq!(publish_data_url(ani.clone()), ani);
+        let broken = "data:image/png;base64,not base64!".to_string();
+        assert_eq!(publish_data_url(broken.clone()), broken);
+    }
+}
@@ -0,0 +1,167 @@
+//! Reduced-motion support.
+//!
+//! When Windows "Animation effects" is turned off, motion effects (trails, ripples) are left out
+//! of the effects reported to the UI and ANI previews are served as their first frame only.
+//! The stored effects are not touched, so they come back when the setting is turned on again.
+//! `follow_system: false` in `reduced-motion.json` keeps everything animated regardless.
+
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicBool, Ordering};
+use tauri::{AppHandle, Runtime};
+
+use crate::commands::customization::library::AniPreviewData;
+use crate::state::settings_file::{self, SettingsFile};
+use crate::state::EffectsConfig;
+use crate::system;
+
+pub const REDUCED_MOTION_FILE_NAME: &str = "reduced-motion.json";
+/// Effects that animate across the screen and are dropped under reduced motion.
+pub const MOTION_EFFECTS: [&str; 2] = ["trail", "ripple"];
+
+static ACTIVE: AtomicBool = AtomicBool::new(false);
+
+#[derive(ts_rs::TS, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct ReducedMotionSettings {
+    /// Follow the Windows animation setting; when false, effects and previews always animate
+    #[serde(default = "default_follow_system")]
+    pub follow_system: bool,
+}
+
+fn default_follow_system() -> bool {
+    true
+}
+
+impl Default for ReducedMotionSettings {
+    fn default() -> Self {
+        Self {
+            follow_system: true,
+        }
+    }
+}
+
+#[derive(ts_rs::TS, Debug, Clone, PartialEq, Eq, Serialize)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct ReducedMotionStatus {
+    /// Windows "Animation effects" is turned off
+    pub system_reduce_motion: bool,
+    pub follow_system: bool,
+    /// Motion effects and animated previews are currently suppressed
+    pub active: bool,
+}
+
+impl SettingsFile for ReducedMotionSettings {
+    const FILE_NAME: &'static str = REDUCED_MOTION_FILE_NAME;
+    const DESCRIPTION: &'static str = "reduced motion settings";
+}
+
+pub fn load_settings<R: Runtime>(app: &AppHandle<R>) -> ReducedMotionSettings {
+    settings_file::load(app)
+}
+
+pub fn save_settings<R: Runtime>(
+    app: &AppHandle<R>,
+    settings: &ReducedMotionSettings,
+) -> Result<(), String> {
+    settings_file::save(app, settings)
+}
+
+/// Re-read the Windows setting and the override. Called at startup and whenever the UI asks,
+/// since Windows doesn't notify us when the setting changes.
+pub fn refresh<R: Runtime>(app: &AppHandle<R>) -> ReducedMotionStatus {
+    let settings = load_settings(app);
+    let system_reduce_motion = system::system_reduce_motion();
+    let active = settings.follow_system && system_reduce_motion;
+    if ACTIVE.swap(active, Ordering::SeqCst) != active {
+        cc_info!(
+            "[CursorChanger] Reduced motion {}",
+            if active { "enabled" } else { "disabled" }
+        );
+    }
+    ReducedMotionStatus {
+        system_reduce_motion,
+        follow_system: settings.follow_system,
+        active,
+    }
+}
+
+pub fn is_active() -> bool {
+    ACTIVE.load(Ordering::SeqCst)
+}
+
+/// Effects to report to the UI for `config`.
+pub fn effective_effects(config: &EffectsConfig) -> EffectsConfig {
+    strip_motion_effects(config, is_active())
+}
+
+fn strip_motion_effects(config: &EffectsConfig, reduce_motion: bool) -> EffectsConfig {
+    if !reduce_motion {
+        return config.clone();
+    }
+    EffectsConfig {
+        enabled: config
+            .enabled
+            .iter()
+            .filter(|effect| !MOTION_EFFECTS.contains(&effect.as_str()))
+            .cloned()
+            .collect(),
+    }
+}
+
+/// ANI preview to return to the UI: the first frame only under reduced motion.
+pub fn effective_ani_preview(data: AniPreviewData) -> AniPreviewData {
+    if is_active() {
+        first_frame_only(data)
+    } else {
+        data
+    }
+}
+
+fn first_frame_only(mut data: AniPreviewData) -> AniPreviewData {
+    data.frames.truncate(1);
+    data.delays.truncate(1);
+    data.total_duration = data.delays.first().copied().unwrap_or(0);
+    data
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn motion_effects_are_stripped_only_when_reducing() {
+        let config = EffectsConfig {
+            enabled: vec![
+                "trail".to_string(),
+                "spotlight".to_string(),
+                "ripple".to_string(),
+            ],
+        };
+        assert_eq!(strip_motion_effects(&config, false), config);
+        assert_eq!(
+            strip_motion_effects(&config, true).enabled,
+            vec!["spotlight".to_string()]
+        );
+    }
+
+    #[test]
+    fn ani_preview_keeps_first_frame() {
+        let data = AniPreviewData {
+            frames: vec!["a".to_string(), "b".to_string(), "c".to_string()],
+            frames_are_paths: true,
+            delays: vec![50, 60, 70],
+            total_duration: 180,
+        };
+        let still = first_frame_only(data);
+        assert_eq!(still.frames, vec!["a".to_string()]);
+        assert_eq!(still.delays, vec![50]);
+        assert_eq!(still.total_duration, 50);
+        assert!(still.frames_are_paths);
+    }
+
+    #[test]
+    fn follows_system_by_default() {
+        let settings: ReducedMotionSettings = serde_json::from_str("{}").unwrap();
+        assert!(settings.follow_system);
+    }
+}
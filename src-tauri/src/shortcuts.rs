@@ -1,8 +1,6 @@
-use crate::commands::cursor_commands::toggle_cursor_with_shared_state;
-use crate::events;
 use crate::state::config::{persist_config, PersistedConfig};
 use crate::state::{AppState, CursorStatePayload};
-use tauri::{AppHandle, Emitter, Manager, State};
+use tauri::{AppHandle, State};
 use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut};
 
 /// Default application keyboard shortcut used for hide/show cursor
@@ -74,19 +72,7 @@ pub fn update_shortcut(
                 return;
             }
 
-            let payload = app_for_hotkey.try_state::<AppState>().and_then(|shared| {
-                match toggle_cursor_with_shared_state(&shared) {
-                    Ok(payload) => Some(payload),
-                    Err(err) => {
-                        let _ = app_for_hotkey.emit(events::CURSOR_ERROR, err);
-                        None
-                    }
-                }
-            });
-
-            if let Some(payload) = payload {
-                let _ = app_for_hotkey.emit(events::CURSOR_STATE, payload);
-            }
+            crate::hotkey_press::handle_press(&app_for_hotkey);
         }) {
         Ok(_) => {}
         Err(e) => {
@@ -95,6 +81,11 @@ pub fn update_shortcut(
         }
     }
 
+    // unregister_all above also removed the chord prefix
+    if let Err(e) = crate::hotkey_chords::register_prefix(app) {
+        cc_warn!("[shortcuts] {e}");
+    }
+
     {
         let mut prefs = state
             .prefs
@@ -143,14 +134,15 @@ pub fn initialize_shortcut(
             let shortcut_to_register = if s.trim() == "Ctrl+Shift+C" || s.trim() == "Ctrl+Shift+F9"
             {
                 let new = "Ctrl+Shift+X".to_string();
-                let config = if let Ok(mut prefs) = state.prefs.write() {
-                    prefs.shortcut = Some(new.clone());
-                    Some(PersistedConfig::from(&**state))
-                } else {
-                    None
-                };
-
-                if let Some(config) = config {
+                let migrated = state
+                    .prefs
+                    .write()
+                    .map(|mut prefs| prefs.shortcut = Some(new.clone()))
+                    .is_ok();
+
+                // Read the config back once the change is published
+                if migrated {
+                    let config = PersistedConfig::from(&**state);
                     if let Err(e) = persist_config(app, &config) {
                         cc_error!("Failed to persist migrated shortcut: {e}");
                     }
@@ -0,0 +1,231 @@
+//! Modifier + mouse wheel gesture for changing the cursor size.
+//!
+//! When enabled, a low-level mouse hook watches the wheel. While the configured modifiers are
+//! held, each wheel event moves a target size by `step_px` and is swallowed; otherwise the wheel
+//! behaves normally. Every step emits `cursor-size-gesture` so the UI can show the size right
+//! away, and the target is applied once scrolling pauses, so a fast scroll reloads the cursors
+//! only once.
+
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager, Runtime};
+
+use cursor_changer::win_mouse::{is_key_down, WheelCapture};
+
+use crate::commands::customization::set_cursor_size::resize_cursor_in_background;
+use crate::cursor_converter;
+use crate::events;
+use crate::state::settings_file::{self, SettingsFile};
+use crate::state::AppState;
+use crate::timers::{self, TimerPriority};
+
+pub const SIZE_GESTURE_FILE_NAME: &str = "size-gesture.json";
+pub const DEFAULT_STEP_PX: u32 = 8;
+pub const MAX_STEP_PX: u32 = 64;
+const MIN_CURSOR_SIZE: i32 = 32;
+/// Quiet time after the last wheel step before the size is applied.
+const APPLY_DEBOUNCE: Duration = Duration::from_millis(200);
+
+const VK_SHIFT: u32 = 0x10;
+const VK_CONTROL: u32 = 0x11;
+const VK_MENU: u32 = 0x12;
+const VK_LWIN: u32 = 0x5B;
+const VK_RWIN: u32 = 0x5C;
+
+#[derive(ts_rs::TS, Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub enum SizeGestureModifier {
+    #[default]
+    WinCtrl,
+    WinAlt,
+    WinShift,
+    CtrlAlt,
+    CtrlShift,
+}
+
+impl SizeGestureModifier {
+    /// Keys that must be held, as groups of which any one key counts (left or right Win).
+    fn key_groups(self) -> &'static [&'static [u32]] {
+        const WIN: &[u32] = &[VK_LWIN, VK_RWIN];
+        match self {
+            Self::WinCtrl => &[WIN, &[VK_CONTROL]],
+            Self::WinAlt => &[WIN, &[VK_MENU]],
+            Self::WinShift => &[WIN, &[VK_SHIFT]],
+            Self::CtrlAlt => &[&[VK_CONTROL], &[VK_MENU]],
+            Self::CtrlShift => &[&[VK_CONTROL], &[VK_SHIFT]],
+        }
+    }
+
+    fn is_held(self) -> bool {
+        self.key_groups()
+            .iter()
+            .all(|group| group.iter().any(|&vk| is_key_down(vk)))
+    }
+}
+
+#[derive(ts_rs::TS, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct SizeGestureSettings {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default)]
+    pub modifier: SizeGestureModifier,
+    /// Pixels added or removed per wheel step
+    #[serde(default = "default_step_px")]
+    pub step_px: u32,
+}
+
+fn default_step_px() -> u32 {
+    DEFAULT_STEP_PX
+}
+
+impl Default for SizeGestureSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            modifier: SizeGestureModifier::WinCtrl,
+            step_px: DEFAULT_STEP_PX,
+        }
+    }
+}
+
+/// Payload of `cursor-size-gesture`, emitted for every wheel step.
+#[derive(ts_rs::TS, Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct SizeGesturePayload {
+    /// Size that will be applied once scrolling pauses
+    pub size: i32,
+    pub min: i32,
+    pub max: i32,
+}
+
+/// Size the gesture is heading for, ahead of the debounced apply.
+static PENDING_SIZE: Mutex<Option<i32>> = Mutex::new(None);
+static GENERATION: AtomicU64 = AtomicU64::new(0);
+static CAPTURE: Mutex<Option<WheelCapture>> = Mutex::new(None);
+
+impl SettingsFile for SizeGestureSettings {
+    const FILE_NAME: &'static str = SIZE_GESTURE_FILE_NAME;
+    const DESCRIPTION: &'static str = "size gesture settings";
+}
+
+pub fn load_settings<R: Runtime>(app: &AppHandle<R>) -> SizeGestureSettings {
+    settings_file::load(app)
+}
+
+pub fn save_settings<R: Runtime>(
+    app: &AppHandle<R>,
+    settings: &SizeGestureSettings,
+) -> Result<(), String> {
+    settings_file::save(app, settings)
+}
+
+#[allow(clippy::cast_possible_wrap)]
+const fn max_cursor_size() -> i32 {
+    cursor_converter::MAX_CURSOR_SIZE as i32
+}
+
+/// One wheel step from `current`: away from the user grows the cursor, towards shrinks it.
+fn next_size(current: i32, delta: i16, step_px: u32) -> i32 {
+    let step = i32::try_from(step_px).unwrap_or(i32::MAX);
+    let next = if delta > 0 {
+        current.saturating_add(step)
+    } else {
+        current.saturating_sub(step)
+    };
+    next.clamp(MIN_CURSOR_SIZE, max_cursor_size())
+}
+
+/// Start or stop the wheel hook to match the saved settings. Called at startup and whenever
+/// the settings change.
+pub fn install(app: &AppHandle) -> Result<(), String> {
+    let settings = load_settings(app);
+    let mut capture = CAPTURE
+        .lock()
+        .map_err(|_| "Size gesture state poisoned".to_string())?;
+    // Drop any previous hook first so a settings change never leaves two running
+    *capture = None;
+    if !settings.enabled {
+        return Ok(());
+    }
+
+    let app_for_wheel = app.clone();
+    *capture = Some(WheelCapture::start(move |delta| {
+        if delta == 0 || !settings.modifier.is_held() {
+            return false;
+        }
+        on_wheel_step(&app_for_wheel, delta, settings.step_px);
+        true
+    })?);
+    Ok(())
+}
+
+fn on_wheel_step(app: &AppHandle, delta: i16, step_px: u32) {
+    let Ok(mut pending) = PENDING_SIZE.lock() else {
+        return;
+    };
+    let current = pending.or_else(|| {
+        let state = app.state::<AppState>();
+        let prefs = state.prefs.read().ok()?;
+        Some(prefs.cursor_size)
+    });
+    let Some(current) = current else {
+        return;
+    };
+    let size = next_size(current, delta, step_px);
+    *pending = Some(size);
+    drop(pending);
+
+    let generation = GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+    // The hook must return quickly, so feedback and the apply happen on the timer service
+    let feedback_app = app.clone();
+    timers::after(Duration::ZERO, TimerPriority::Essential, move || {
+        let _ = feedback_app.emit(
+            events::CURSOR_SIZE_GESTURE,
+            SizeGesturePayload {
+                size,
+                min: MIN_CURSOR_SIZE,
+                max: max_cursor_size(),
+            },
+        );
+    });
+    let app = app.clone();
+    timers::after(APPLY_DEBOUNCE, TimerPriority::Essential, move || {
+        if GENERATION.load(Ordering::SeqCst) != generation {
+            return;
+        }
+        if let Err(e) = resize_cursor_in_background(size, &app) {
+            cc_warn!("[CursorChanger] Failed to apply gesture cursor size: {}", e);
+            let _ = app.emit(events::CURSOR_ERROR, e);
+        }
+        if GENERATION.load(Ordering::SeqCst) == generation {
+            if let Ok(mut pending) = PENDING_SIZE.lock() {
+                *pending = None;
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wheel_steps_move_size_within_bounds() {
+        assert_eq!(next_size(48, 120, 8), 56);
+        assert_eq!(next_size(48, -120, 8), 40);
+        assert_eq!(next_size(36, -120, 8), MIN_CURSOR_SIZE);
+        assert_eq!(next_size(max_cursor_size() - 2, 120, 8), max_cursor_size());
+    }
+
+    #[test]
+    fn settings_default_to_disabled_win_ctrl() {
+        let settings: SizeGestureSettings = serde_json::from_str("{}").unwrap();
+        assert!(!settings.enabled);
+        assert_eq!(settings.modifier, SizeGestureModifier::WinCtrl);
+        assert_eq!(settings.step_px, DEFAULT_STEP_PX);
+    }
+}
@@ -0,0 +1,118 @@
+//! Hot reload of library cursors from their source images.
+//!
+//! Watches the folders of every source image linked with hot reload on (see
+//! `CursorSourceLink`). Folders rather than files are watched because most editors save by
+//! writing a new file and renaming it over the old one. Changes are collected until the
+//! folder has been quiet for a moment, then each changed image is reconverted once and
+//! `cursor-source-reloaded` is emitted with the updated library entries.
+
+use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::{mpsc, Mutex};
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter};
+
+use crate::commands::customization::library::{hot_reload_source_paths, reload_linked_cursors};
+use crate::events;
+
+/// Quiet time after the last change before reconverting, so an editor's several writes per
+/// save trigger one reload.
+const RELOAD_DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Dropping the watcher closes its channel, which ends the reload thread.
+static WATCHER: Mutex<Option<RecommendedWatcher>> = Mutex::new(None);
+
+fn watch_key(path: &Path) -> String {
+    path.to_string_lossy().to_ascii_lowercase()
+}
+
+/// Start or restart watching the linked source images. Called at startup and whenever a
+/// link or its hot reload toggle changes.
+pub fn install(app: &AppHandle) -> Result<(), String> {
+    let sources = hot_reload_source_paths(app)?;
+    let mut guard = WATCHER
+        .lock()
+        .map_err(|_| "Source watcher state poisoned".to_string())?;
+    *guard = None;
+    if sources.is_empty() {
+        return Ok(());
+    }
+
+    let watched: HashSet<String> = sources.iter().map(|p| watch_key(p)).collect();
+    let (tx, rx) = mpsc::channel::<PathBuf>();
+    let mut watcher =
+        notify::recommended_watcher(move |res: Result<notify::Event, notify::Error>| {
+            let Ok(event) = res else {
+                return;
+            };
+            if !matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_)) {
+                return;
+            }
+            for path in event.paths {
+                if watched.contains(&watch_key(&path)) {
+                    let _ = tx.send(path);
+                }
+            }
+        })
+        .map_err(|e| format!("Failed to create source watcher: {}", e))?;
+
+    let folders: HashSet<PathBuf> = sources
+        .iter()
+        .filter_map(|p| p.parent().map(Path::to_path_buf))
+        .collect();
+    for folder in &folders {
+        if let Err(e) = watcher.watch(folder, RecursiveMode::NonRecursive) {
+            cc_warn!("[SourceWatch] Failed to watch {}: {}", folder.display(), e);
+        }
+    }
+
+    let app = app.clone();
+    std::thread::spawn(move || reload_loop(&app, &rx));
+    *guard = Some(watcher);
+    cc_debug!(
+        "[SourceWatch] Watching {} source image(s) in {} folder(s)",
+        sources.len(),
+        folders.len()
+    );
+    Ok(())
+}
+
+fn reload_loop(app: &AppHandle, rx: &mpsc::Receiver<PathBuf>) {
+    let mut pending: Vec<PathBuf> = Vec::new();
+    let mut last_change = Instant::now();
+    loop {
+        // Block until something changes; only wake on a timeout while a reload is pending
+        let received = if pending.is_empty() {
+            rx.recv().map_err(|_| mpsc::RecvTimeoutError::Disconnected)
+        } else {
+            rx.recv_timeout(RELOAD_DEBOUNCE.saturating_sub(last_change.elapsed()))
+        };
+        match received {
+            Ok(path) => {
+                if !pending.iter().any(|p| watch_key(p) == watch_key(&path)) {
+                    pending.push(path);
+                }
+                last_change = Instant::now();
+            }
+            Err(mpsc::RecvTimeoutError::Timeout) => {}
+            Err(mpsc::RecvTimeoutError::Disconnected) => break,
+        }
+        if pending.is_empty() || last_change.elapsed() < RELOAD_DEBOUNCE {
+            continue;
+        }
+        for path in pending.drain(..) {
+            match reload_linked_cursors(app, &path) {
+                Ok(reloaded) if !reloaded.is_empty() => {
+                    let _ = app.emit(events::CURSOR_SOURCE_RELOADED, reloaded);
+                }
+                Ok(_) => {}
+                Err(e) => cc_warn!(
+                    "[SourceWatch] Failed to reload cursors from {}: {}",
+                    path.display(),
+                    e
+                ),
+            }
+        }
+    }
+}
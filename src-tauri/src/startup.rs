@@ -180,6 +180,9 @@ pub fn setup_app(app: &mut tauri::App) -> tauri::Result<()> {
     #[cfg(debug_assertions)]
     crate::cleanup_hooks::initialize_cleanup_hooks(&app_handle);
 
+    // Before anything resolves a library path
+    crate::storage_location::install(&app_handle);
+
     crate::tray::build_tray(&app_handle)?;
 
     let state = app.state::<AppState>();
@@ -197,6 +200,16 @@ pub fn setup_app(app: &mut tauri::App) -> tauri::Result<()> {
         }
     }
 
+    // Fingerprint the untouched Normal cursor so the first apply can be verified
+    crate::apply_verification::record_baseline();
+
+    crate::reduced_motion::refresh(&app_handle);
+    crate::svg_resources::init(&app_handle);
+    crate::log_files::init();
+    crate::crash_reports::init(&app_handle);
+    crate::usage_stats::init(&app_handle);
+    crate::pending_jobs::init(&app_handle);
+
     let persisted_config =
         crate::startup_config::load_and_apply_config(&app_handle, &state, &preference);
 
@@ -208,10 +221,45 @@ pub fn setup_app(app: &mut tauri::App) -> tauri::Result<()> {
         shortcut_enabled,
     );
 
+    // Fold in library.json conflict copies left by OneDrive/Dropbox before anything reads it
+    if let Err(e) =
+        crate::commands::customization::library::merge_synced_library_copies(&app_handle)
+    {
+        cc_warn!("[CursorChanger] Failed to merge synced library copies: {}", e);
+    }
+
     // Ensure default cursor paths are loaded before the frontend requests available cursors.
     // This avoids empty previews on initial load/refresh.
     crate::startup_config::load_default_cursors(app_handle.clone(), state.clone());
 
+    crate::cache_gc::schedule(app_handle.clone());
+    crate::commands::customization::pack_updates::schedule(app_handle.clone());
+    crate::status_file::install(&app_handle);
+    if let Err(e) = crate::health_endpoint::install(&app_handle) {
+        cc_warn!("[CursorChanger] Failed to start health endpoint: {}", e);
+    }
+    crate::decode_worker::install(&app_handle);
+    crate::commands::customization::library::install_variant_settings(&app_handle);
+    crate::hide_roles::install(&app_handle);
+    crate::memory_policy::install(&app_handle);
+    crate::conversion_output::install(&app_handle);
+    if let Err(e) = crate::size_gesture::install(&app_handle) {
+        cc_warn!("[CursorChanger] Failed to start cursor size gesture: {}", e);
+    }
+    if let Err(e) = crate::pointer_control::install(&app_handle) {
+        cc_warn!("[CursorChanger] Failed to start keyboard pointer control: {}", e);
+    }
+    if let Err(e) = crate::hot_corners::install(&app_handle) {
+        cc_warn!("[CursorChanger] Failed to start hot corners: {}", e);
+    }
+    crate::commands::customization::pack_schedule::install(&app_handle);
+    if let Err(e) = crate::monitor_schemes::install(&app_handle) {
+        cc_warn!("[CursorChanger] Failed to start per-monitor cursor schemes: {}", e);
+    }
+    if let Err(e) = crate::source_watch::install(&app_handle) {
+        cc_warn!("[CursorChanger] Failed to watch cursor source images: {}", e);
+    }
+
     crate::window_setup::initialize_main_window(&app_handle);
 
     Ok(())
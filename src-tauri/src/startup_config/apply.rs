@@ -1,4 +1,4 @@
-use crate::state::{MinimizePreference, PersistedConfig};
+use crate::state::{AdminPolicy, MinimizePreference, PersistedConfig};
 use std::sync::atomic::Ordering;
 
 pub(super) fn apply_minimize_to_tray_config(
@@ -117,6 +117,19 @@ pub(super) fn apply_accent_color_config(
     }
 }
 
+pub(super) fn apply_window_config(
+    guard: &mut crate::state::app_state::AppStateWriteGuard<'_>,
+    config: &PersistedConfig,
+) {
+    cc_debug!(
+        "[CursorChanger] Applying persisted window_geometry={:?} last_page={:?} to state",
+        config.window_geometry,
+        config.last_page
+    );
+    guard.prefs.window_geometry = config.window_geometry;
+    guard.prefs.last_page = config.last_page.clone();
+}
+
 pub(super) fn apply_theme_mode_config(
     guard: &mut crate::state::app_state::AppStateWriteGuard<'_>,
     config: &PersistedConfig,
@@ -207,6 +220,40 @@ pub(super) fn apply_run_on_startup_config(
     }
 }
 
+/// Install the admin policy and force any locked settings over the user's values.
+///
+/// Runs after the persisted config has been applied so the policy always wins. Returns `true`
+/// when the policy forces a default cursor style; the caller applies that style's cursors once
+/// the state lock is released, since cursors the user applied earlier are still live.
+pub(super) fn apply_admin_policy(
+    guard: &mut crate::state::app_state::AppStateWriteGuard<'_>,
+    policy: AdminPolicy,
+) -> bool {
+    let forced_style = policy.force_default_cursor_style;
+    if let Some(style) = forced_style {
+        if guard.prefs.default_cursor_style != style {
+            cc_info!(
+                "[CursorChanger] Policy forces default_cursor_style={} (was {})",
+                style.as_str(),
+                guard.prefs.default_cursor_style.as_str()
+            );
+        }
+        // Saved cursors of either mode would otherwise come back over the forced style
+        guard.modes.simple_mode_cursor_paths.clear();
+        guard.modes.advanced_mode_cursor_paths.clear();
+        guard.cursor.cursor_paths.clear();
+        guard.prefs.default_cursor_style = style;
+    }
+
+    if policy.disable_hide && guard.cursor.visibility.is_hidden() {
+        cc_info!("[CursorChanger] Policy disables hiding; marking cursor visible");
+        guard.cursor.visibility.reset();
+    }
+
+    *guard.policy = policy;
+    forced_style.is_some()
+}
+
 pub(super) fn snapshot_persisted_config_from_state(
     state: &crate::state::app_state::AppStateWriteGuard<'_>,
 ) -> PersistedConfig {
@@ -223,5 +270,7 @@ pub(super) fn snapshot_persisted_config_from_state(
         theme_mode: Some(state.prefs.theme_mode),
         default_cursor_style: Some(state.prefs.default_cursor_style),
         customization_mode: Some(state.modes.customization_mode),
+        window_geometry: state.prefs.window_geometry,
+        last_page: state.prefs.last_page.clone(),
     }
 }
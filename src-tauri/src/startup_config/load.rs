@@ -1,14 +1,19 @@
 use super::normalize;
-use crate::state::config::load_persisted_config;
+use crate::state::config::{load_persisted_config, pending_validation_report};
 use crate::state::PersistedConfig;
-use tauri::AppHandle;
+use tauri::{AppHandle, Emitter};
 
 pub fn load(app: &AppHandle) -> PersistedConfig {
-    match load_persisted_config(app) {
+    let config = match load_persisted_config(app) {
         Ok(cfg) => normalize::normalize(cfg),
         Err(err) => {
             cc_error!("Failed to load persisted config: {err}");
             normalize::normalize(PersistedConfig::default())
         }
+    };
+    // The UI may not be listening yet; it also asks via `take_config_validation_report`
+    if let Some(report) = pending_validation_report() {
+        let _ = app.emit(crate::events::CONFIG_VALIDATION_FAILED, report);
     }
+    config
 }
@@ -3,8 +3,11 @@ mod autostart;
 mod load;
 mod normalize;
 
-use crate::commands::customization::defaults::load_app_default_cursors;
+use crate::commands::customization::defaults::{
+    load_app_default_cursors, reset_current_mode_cursors,
+};
 use crate::state::config::persist_config;
+use crate::state::policy::load_admin_policy;
 use crate::state::{AppState, MinimizePreference, PersistedConfig};
 use tauri::{AppHandle, Emitter, State};
 
@@ -14,10 +17,13 @@ pub fn load_and_apply_config(
     preference: &State<MinimizePreference>,
 ) -> PersistedConfig {
     let persisted_config = load::load(app);
+    let policy = load_admin_policy();
+    crate::cursor_refresh::load_refresh_settings(app);
 
     let mut needs_autostart_validation = false;
     let mut repaired_autostart = false;
     let mut snapshot_for_persist: Option<PersistedConfig> = None;
+    let mut apply_forced_style = false;
 
     if let Ok(prefs) = state.prefs.read() {
         needs_autostart_validation = prefs.run_on_startup;
@@ -44,11 +50,22 @@ pub fn load_and_apply_config(
         apply::apply_customization_mode_config(&mut guard, &persisted_config);
         apply::apply_run_on_startup_config(&mut guard, &persisted_config);
         apply::apply_default_cursor_style_config(&mut guard, &persisted_config);
+        apply::apply_window_config(&mut guard, &persisted_config);
 
         if repaired_autostart {
             guard.prefs.run_on_startup = false;
         }
+        // Snapshot before the policy is applied so admin-forced values don't overwrite the
+        // user's own choices in config.json.
         snapshot_for_persist = Some(apply::snapshot_persisted_config_from_state(&guard));
+        apply_forced_style = apply::apply_admin_policy(&mut guard, policy);
+    }
+
+    // Same path as resetting to defaults, so the forced style replaces whatever is live now
+    if apply_forced_style {
+        if let Err(e) = reset_current_mode_cursors(app.clone(), state.clone()) {
+            cc_error!("[CursorChanger] Failed to apply the policy's cursor style: {}", e);
+        }
     }
 
     if repaired_autostart {
@@ -203,6 +220,83 @@ mod tests {
         assert_eq!(preference.0.load(Ordering::SeqCst), false);
     }
 
+    #[test]
+    fn test_apply_admin_policy_overrides_persisted_style() {
+        use crate::state::{AdminPolicy, DefaultCursorStyle};
+
+        let state = AppState::default();
+        let config = PersistedConfig {
+            default_cursor_style: Some(DefaultCursorStyle::Mac),
+            ..Default::default()
+        };
+
+        let mut guard = state.write_all().expect("write state");
+        guard
+            .cursor
+            .cursor_paths
+            .insert("Normal".to_string(), "C:\\mac\\arrow.cur".to_string());
+        apply::apply_default_cursor_style_config(&mut guard, &config);
+        let apply_forced_style = apply::apply_admin_policy(
+            &mut guard,
+            AdminPolicy {
+                force_default_cursor_style: Some(DefaultCursorStyle::Windows),
+                disable_hide: true,
+                ..Default::default()
+            },
+        );
+
+        assert_eq!(guard.prefs.default_cursor_style, DefaultCursorStyle::Windows);
+        assert!(guard.cursor.cursor_paths.is_empty());
+        assert!(guard.policy.locks().default_cursor_style_locked);
+        assert!(guard.policy.locks().hide_disabled);
+        assert!(apply_forced_style);
+    }
+
+    #[test]
+    fn test_apply_admin_policy_forced_style_wins_over_saved_cursors() {
+        use crate::state::{AdminPolicy, DefaultCursorStyle};
+
+        // The saved style already matches the policy, but the user applied their own cursors
+        let state = AppState::default();
+        let config = PersistedConfig {
+            default_cursor_style: Some(DefaultCursorStyle::Windows),
+            ..Default::default()
+        };
+        let custom = "C:\\custom\\arrow.cur".to_string();
+
+        let mut guard = state.write_all().expect("write state");
+        apply::apply_default_cursor_style_config(&mut guard, &config);
+        guard
+            .cursor
+            .cursor_paths
+            .insert("Normal".to_string(), custom.clone());
+        guard
+            .modes
+            .simple_mode_cursor_paths
+            .insert("Normal".to_string(), custom.clone());
+        guard
+            .modes
+            .advanced_mode_cursor_paths
+            .insert("Normal".to_string(), custom);
+
+        let apply_forced_style = apply::apply_admin_policy(
+            &mut guard,
+            AdminPolicy {
+                force_default_cursor_style: Some(DefaultCursorStyle::Windows),
+                ..Default::default()
+            },
+        );
+
+        assert!(apply_forced_style);
+        assert_eq!(guard.prefs.default_cursor_style, DefaultCursorStyle::Windows);
+        assert!(guard.cursor.cursor_paths.is_empty());
+        assert!(guard.modes.simple_mode_cursor_paths.is_empty());
+        assert!(guard.modes.advanced_mode_cursor_paths.is_empty());
+
+        // Without a forced style there is nothing to apply
+        assert!(!apply::apply_admin_policy(&mut guard, AdminPolicy::default()));
+    }
+
     #[test]
     fn test_apply_accent_color_config() {
         let state = AppState::default();
@@ -1,10 +1,15 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::marker::PhantomData;
 use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
-use std::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard};
 
-use super::models::{CustomizationMode, DefaultCursorStyle, ThemeMode};
+use super::models::{
+    CustomizationMode, DefaultCursorStyle, EffectsConfig, ThemeMode, WindowGeometry,
+};
+use super::policy::AdminPolicy;
+use super::store::{LayerSet, Layers, Staged, StateCell, Store, WriteToken};
+use super::visibility::CursorVisibility;
 
 pub const DEFAULT_SHORTCUT: &str = "Ctrl+Shift+X";
 pub const DEFAULT_APP_SHORTCUT: &str = "Ctrl+Shift+Q";
@@ -19,9 +24,10 @@ pub struct CursorInfo {
     pub image_path: Option<String>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct CursorRuntimeState {
-    pub hidden: bool,
+    // Hide/restore lifecycle; only changed through `CursorVisibility::apply` or `reset`
+    pub visibility: CursorVisibility,
     pub last_loaded_cursor_path: Option<String>,
     // Track cursor paths in memory (not in registry)
     // Map of cursor name -> image path
@@ -31,7 +37,7 @@ pub struct CursorRuntimeState {
 impl Default for CursorRuntimeState {
     fn default() -> Self {
         Self {
-            hidden: false,
+            visibility: CursorVisibility::default(),
             last_loaded_cursor_path: None,
             cursor_paths: HashMap::new(),
         }
@@ -45,6 +51,8 @@ pub struct ModeCustomizationState {
     pub advanced_mode_cursor_paths: HashMap<String, String>,
     // Current customization mode: "simple" or "advanced"
     pub customization_mode: CustomizationMode,
+    // Effects for the active mode's profile (swapped on mode switch)
+    pub effects: EffectsConfig,
 }
 
 impl Default for ModeCustomizationState {
@@ -53,6 +61,7 @@ impl Default for ModeCustomizationState {
             simple_mode_cursor_paths: HashMap::new(),
             advanced_mode_cursor_paths: HashMap::new(),
             customization_mode: CustomizationMode::Simple,
+            effects: EffectsConfig::default(),
         }
     }
 }
@@ -73,6 +82,10 @@ pub struct PreferencesState {
     pub theme_mode: ThemeMode,
     // Default cursor style: "windows"
     pub default_cursor_style: DefaultCursorStyle,
+    // Main window rectangle to restore on the next launch
+    pub window_geometry: Option<WindowGeometry>,
+    // ID of the settings page that was open last
+    pub last_page: Option<String>,
 }
 
 impl Default for PreferencesState {
@@ -89,6 +102,8 @@ impl Default for PreferencesState {
             accent_color: "#7c3aed".to_string(),
             theme_mode: ThemeMode::default(),
             default_cursor_style: DefaultCursorStyle::default(),
+            window_geometry: None,
+            last_page: None,
         }
     }
 }
@@ -106,90 +121,129 @@ impl Default for RestorationState {
     }
 }
 
+/// Durable preferences and admin policy form the config layer; mode, cursor and restoration
+/// state form the runtime layer. Each field reads as an immutable snapshot, and every write
+/// (single field or [`AppState::write_all`]) is applied by the store's state actor. A write
+/// claims all its layers at once; a thread that is already writing gets
+/// `StateError::NestedWrite` instead of a second guard.
 #[derive(Debug)]
 pub struct AppState {
-    pub prefs: RwLock<PreferencesState>,
-    pub modes: RwLock<ModeCustomizationState>,
-    pub cursor: RwLock<CursorRuntimeState>,
+    pub prefs: StateCell<PreferencesState>,
+    pub modes: StateCell<ModeCustomizationState>,
+    pub cursor: StateCell<CursorRuntimeState>,
     #[allow(dead_code)]
-    pub restoration: RwLock<RestorationState>,
+    pub restoration: StateCell<RestorationState>,
+    // Machine-wide admin policy loaded at startup; never persisted to the user config
+    pub policy: StateCell<AdminPolicy>,
+    store: Arc<Store>,
 }
 
 impl Default for AppState {
     fn default() -> Self {
+        let store = Arc::new(Store::default());
         Self {
-            prefs: RwLock::new(PreferencesState::default()),
-            modes: RwLock::new(ModeCustomizationState::default()),
-            cursor: RwLock::new(CursorRuntimeState::default()),
-            restoration: RwLock::new(RestorationState::default()),
+            prefs: StateCell::new(
+                store.clone(),
+                LayerSet::PREFS,
+                |l| &l.prefs,
+                |l| &mut l.prefs,
+            ),
+            modes: StateCell::new(
+                store.clone(),
+                LayerSet::MODES,
+                |l| &l.modes,
+                |l| &mut l.modes,
+            ),
+            cursor: StateCell::new(
+                store.clone(),
+                LayerSet::CURSOR,
+                |l| &l.cursor,
+                |l| &mut l.cursor,
+            ),
+            restoration: StateCell::new(
+                store.clone(),
+                LayerSet::RESTORATION,
+                |l| &l.restoration,
+                |l| &mut l.restoration,
+            ),
+            policy: StateCell::new(
+                store.clone(),
+                LayerSet::POLICY,
+                |l| &l.policy,
+                |l| &mut l.policy,
+            ),
+            store,
         }
     }
 }
 
+/// A consistent view of every layer taken from a single snapshot.
 pub struct AppStateReadGuard<'a> {
-    pub prefs: RwLockReadGuard<'a, PreferencesState>,
-    pub modes: RwLockReadGuard<'a, ModeCustomizationState>,
-    pub cursor: RwLockReadGuard<'a, CursorRuntimeState>,
+    pub prefs: Arc<PreferencesState>,
+    pub modes: Arc<ModeCustomizationState>,
+    pub cursor: Arc<CursorRuntimeState>,
     #[allow(dead_code)]
-    pub restoration: RwLockReadGuard<'a, RestorationState>,
+    pub restoration: Arc<RestorationState>,
+    pub policy: Arc<AdminPolicy>,
+    _state: PhantomData<&'a AppState>,
 }
 
+/// Staged copies of every layer, published together when the guard drops.
 pub struct AppStateWriteGuard<'a> {
-    pub prefs: RwLockWriteGuard<'a, PreferencesState>,
-    pub modes: RwLockWriteGuard<'a, ModeCustomizationState>,
-    pub cursor: RwLockWriteGuard<'a, CursorRuntimeState>,
+    pub prefs: Staged<PreferencesState>,
+    pub modes: Staged<ModeCustomizationState>,
+    pub cursor: Staged<CursorRuntimeState>,
     #[allow(dead_code)]
-    pub restoration: RwLockWriteGuard<'a, RestorationState>,
+    pub restoration: Staged<RestorationState>,
+    pub policy: Staged<AdminPolicy>,
+    token: WriteToken<'a>,
+}
+
+impl Drop for AppStateWriteGuard<'_> {
+    fn drop(&mut self) {
+        // A panicking writer leaves the last committed snapshot untouched.
+        if std::thread::panicking() {
+            return;
+        }
+        let prefs = self.prefs.publish();
+        let modes = self.modes.publish();
+        let cursor = self.cursor.publish();
+        let restoration = self.restoration.publish();
+        let policy = self.policy.publish();
+        self.token
+            .release(Some(Box::new(move |layers: &mut Layers| {
+                layers.prefs = prefs;
+                layers.modes = modes;
+                layers.cursor = cursor;
+                layers.restoration = restoration;
+                layers.policy = policy;
+            })));
+    }
 }
 
 impl AppState {
     pub fn read_all(&self) -> Result<AppStateReadGuard<'_>, String> {
-        let prefs = self
-            .prefs
-            .read()
-            .map_err(|_| "Application state poisoned".to_string())?;
-        let modes = self
-            .modes
-            .read()
-            .map_err(|_| "Application state poisoned".to_string())?;
-        let cursor = self
-            .cursor
-            .read()
-            .map_err(|_| "Application state poisoned".to_string())?;
-        let restoration = self
-            .restoration
-            .read()
-            .map_err(|_| "Application state poisoned".to_string())?;
+        let layers = self.store.snapshot()?;
         Ok(AppStateReadGuard {
-            prefs,
-            modes,
-            cursor,
-            restoration,
+            prefs: Arc::clone(&layers.prefs),
+            modes: Arc::clone(&layers.modes),
+            cursor: Arc::clone(&layers.cursor),
+            restoration: Arc::clone(&layers.restoration),
+            policy: Arc::clone(&layers.policy),
+            _state: PhantomData,
         })
     }
 
     pub fn write_all(&self) -> Result<AppStateWriteGuard<'_>, String> {
-        let prefs = self
-            .prefs
-            .write()
-            .map_err(|_| "Application state poisoned".to_string())?;
-        let modes = self
-            .modes
-            .write()
-            .map_err(|_| "Application state poisoned".to_string())?;
-        let cursor = self
-            .cursor
-            .write()
-            .map_err(|_| "Application state poisoned".to_string())?;
-        let restoration = self
-            .restoration
-            .write()
-            .map_err(|_| "Application state poisoned".to_string())?;
+        let token = self.store.begin_write(LayerSet::ALL)?;
+        let layers = self.store.snapshot()?;
         Ok(AppStateWriteGuard {
-            prefs,
-            modes,
-            cursor,
-            restoration,
+            prefs: Staged::of(&layers.prefs),
+            modes: Staged::of(&layers.modes),
+            cursor: Staged::of(&layers.cursor),
+            restoration: Staged::of(&layers.restoration),
+            policy: Staged::of(&layers.policy),
+            token,
         })
     }
 }
@@ -207,6 +261,7 @@ impl Default for MinimizePreference {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::state::visibility::{HideSnapshot, VisibilityEvent};
     use serde_json;
     use std::sync::atomic::Ordering;
 
@@ -215,7 +270,7 @@ mod tests {
         let state = AppState::default();
         let cursor = state.cursor.read().unwrap();
         let prefs = state.prefs.read().unwrap();
-        assert_eq!(cursor.hidden, false);
+        assert_eq!(cursor.visibility.is_hidden(), false);
         assert_eq!(prefs.shortcut, Some(DEFAULT_SHORTCUT.to_string()));
         assert_eq!(prefs.run_on_startup, false);
         assert_eq!(prefs.minimize_to_tray, true);
@@ -234,7 +289,7 @@ mod tests {
 
     #[test]
     fn test_app_state_cursor_size_can_be_changed() {
-        let mut state = AppState::default();
+        let state = AppState::default();
         {
             let prefs = state.prefs.read().unwrap();
             assert_eq!(prefs.cursor_size, 32);
@@ -259,6 +314,173 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_readers_keep_their_snapshot_while_a_write_is_staged() {
+        let state = Arc::new(AppState::default());
+        let before = state.prefs.read().unwrap();
+        {
+            let mut prefs = state.prefs.write().unwrap();
+            prefs.cursor_size = 96;
+            let other = Arc::clone(&state);
+            let seen = std::thread::spawn(move || other.prefs.read().unwrap().cursor_size)
+                .join()
+                .unwrap();
+            assert_eq!(seen, 32);
+        }
+        assert_eq!(before.cursor_size, 32);
+        assert_eq!(state.prefs.read().unwrap().cursor_size, 96);
+    }
+
+    #[test]
+    fn test_the_writing_thread_reads_its_changes_through_the_guard() {
+        let state = AppState::default();
+        {
+            let mut prefs = state.prefs.write().unwrap();
+            prefs.cursor_size = 96;
+            assert_eq!(prefs.cursor_size, 96);
+            // Plain reads only see published state, even on the writing thread
+            assert_eq!(state.prefs.read().unwrap().cursor_size, 32);
+            assert_eq!(state.read_all().unwrap().prefs.cursor_size, 32);
+        }
+        assert_eq!(state.read_all().unwrap().prefs.cursor_size, 96);
+    }
+
+    #[test]
+    fn test_writers_of_disjoint_layers_do_not_wait_for_each_other() {
+        let state = Arc::new(AppState::default());
+        let mut cursor = state.cursor.write().unwrap();
+        cursor.last_loaded_cursor_path = Some("arrow.cur".to_string());
+        // Another thread writes a different layer while this one is still writing.
+        let other = Arc::clone(&state);
+        std::thread::spawn(move || other.prefs.write().unwrap().cursor_size = 48)
+            .join()
+            .unwrap();
+        assert_eq!(state.prefs.read().unwrap().cursor_size, 48);
+        drop(cursor);
+        assert_eq!(
+            state
+                .cursor
+                .read()
+                .unwrap()
+                .last_loaded_cursor_path
+                .as_deref(),
+            Some("arrow.cur")
+        );
+    }
+
+    #[test]
+    fn test_overlapping_writer_waits_for_the_holder() {
+        let state = Arc::new(AppState::default());
+        let mut prefs = state.prefs.write().unwrap();
+        prefs.cursor_size = 40;
+        let other = Arc::clone(&state);
+        let writer = std::thread::spawn(move || {
+            let mut guard = other.write_all().unwrap();
+            guard.prefs.cursor_size += 8;
+        });
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        assert!(!writer.is_finished());
+        drop(prefs);
+        writer.join().unwrap();
+        assert_eq!(state.prefs.read().unwrap().cursor_size, 48);
+    }
+
+    #[test]
+    fn test_a_writing_thread_cannot_claim_more_layers() {
+        let state = AppState::default();
+        {
+            let mut cursor = state.cursor.write().unwrap();
+            cursor
+                .visibility
+                .apply(VisibilityEvent::Hid(HideSnapshot::default()))
+                .unwrap();
+            // Claiming layers one at a time could deadlock against a thread claiming them in
+            // the other order, so every layer must be claimed up front
+            assert_eq!(
+                state.prefs.write().err(),
+                Some(crate::state::store::StateError::NestedWrite)
+            );
+            assert!(state.cursor.write().is_err());
+            assert!(state.write_all().is_err());
+        }
+        assert!(state.cursor.read().unwrap().visibility.is_hidden());
+        // Every layer was released with the guard
+        assert!(state.prefs.write().is_ok());
+    }
+
+    #[test]
+    fn test_nested_write_inside_write_all_is_rejected_and_keeps_the_outer_changes() {
+        let state = AppState::default();
+        {
+            let mut guard = state.write_all().unwrap();
+            guard.prefs.cursor_size = 64;
+            assert_eq!(
+                state.prefs.write().err(),
+                Some(crate::state::store::StateError::NestedWrite)
+            );
+            assert!(state.write_all().is_err());
+            guard.cursor.last_loaded_cursor_path = Some("arrow.cur".to_string());
+        }
+        let all = state.read_all().unwrap();
+        assert_eq!(all.prefs.cursor_size, 64);
+        assert_eq!(
+            all.cursor.last_loaded_cursor_path.as_deref(),
+            Some("arrow.cur")
+        );
+    }
+
+    #[test]
+    fn test_write_all_publishes_every_layer_together() {
+        let state = Arc::new(AppState::default());
+        let writer = {
+            let state = Arc::clone(&state);
+            std::thread::spawn(move || {
+                for size in 33..=200 {
+                    let mut guard = state.write_all().unwrap();
+                    guard.prefs.cursor_size = size;
+                    guard.modes.effects = EffectsConfig::default();
+                    guard.cursor.last_loaded_cursor_path = Some(size.to_string());
+                }
+            })
+        };
+        for _ in 0..500 {
+            let all = state.read_all().unwrap();
+            let expected = (all.prefs.cursor_size != 32).then(|| all.prefs.cursor_size.to_string());
+            assert_eq!(all.cursor.last_loaded_cursor_path, expected);
+        }
+        writer.join().unwrap();
+        assert_eq!(state.prefs.read().unwrap().cursor_size, 200);
+    }
+
+    #[test]
+    fn test_panicking_writer_discards_its_changes() {
+        let state = Arc::new(AppState::default());
+        let result = {
+            let state = Arc::clone(&state);
+            std::thread::spawn(move || {
+                let mut guard = state.write_all().unwrap();
+                guard
+                    .cursor
+                    .visibility
+                    .apply(VisibilityEvent::Hid(HideSnapshot::default()))
+                    .unwrap();
+                panic!("writer failed");
+            })
+            .join()
+        };
+        assert!(result.is_err());
+        assert!(!state.cursor.read().unwrap().visibility.is_hidden());
+        // The claimed layers were released, so later writes still go through.
+        state
+            .cursor
+            .write()
+            .unwrap()
+            .visibility
+            .apply(VisibilityEvent::Hid(HideSnapshot::default()))
+            .unwrap();
+        assert!(state.cursor.read().unwrap().visibility.is_hidden());
+    }
+
     #[test]
     fn test_minimize_preference_default() {
         let pref = MinimizePreference::default();
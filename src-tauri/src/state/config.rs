@@ -1,7 +1,9 @@
 use super::app_state::AppState;
-use super::models::{CustomizationMode, DefaultCursorStyle, ThemeMode};
+use super::models::{CustomizationMode, DefaultCursorStyle, ThemeMode, WindowGeometry};
 use serde::{Deserialize, Serialize};
-use std::{fs, path::PathBuf};
+use serde_json::Value;
+use std::sync::Mutex;
+use std::{fs, path::Path, path::PathBuf};
 use tauri::{AppHandle, Manager, Runtime};
 
 #[derive(Serialize, Deserialize, Default, Debug)]
@@ -21,6 +23,10 @@ pub struct PersistedConfig {
     pub default_cursor_style: Option<DefaultCursorStyle>,
     #[serde(default, deserialize_with = "deserialize_customization_mode_opt")]
     pub customization_mode: Option<CustomizationMode>,
+    #[serde(default, deserialize_with = "deserialize_window_geometry_opt")]
+    pub window_geometry: Option<WindowGeometry>,
+    #[serde(default)]
+    pub last_page: Option<String>,
 }
 
 fn deserialize_theme_mode_opt<'de, D>(deserializer: D) -> Result<Option<ThemeMode>, D::Error>
@@ -51,6 +57,17 @@ where
     Ok(opt.map(|s| CustomizationMode::from_str(&s).unwrap_or_default()))
 }
 
+// A malformed rectangle only loses the saved window position, not the rest of the file.
+fn deserialize_window_geometry_opt<'de, D>(
+    deserializer: D,
+) -> Result<Option<WindowGeometry>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let opt = Option::<Value>::deserialize(deserializer)?;
+    Ok(opt.and_then(|v| serde_json::from_value(v).ok()))
+}
+
 impl From<&AppState> for PersistedConfig {
     fn from(state: &AppState) -> Self {
         let prefs = state.prefs.read().expect("Application state poisoned");
@@ -68,18 +85,26 @@ impl From<&AppState> for PersistedConfig {
             theme_mode: Some(prefs.theme_mode),
             default_cursor_style: Some(prefs.default_cursor_style),
             customization_mode: Some(modes.customization_mode),
+            window_geometry: prefs.window_geometry,
+            last_page: prefs.last_page.clone(),
         }
     }
 }
 
 pub fn config_path<R: Runtime>(app: &AppHandle<R>) -> Result<PathBuf, String> {
+    if let Some(root) = crate::portable::data_root() {
+        return Ok(root.join("config"));
+    }
     app.path()
         .app_config_dir()
         .map_err(|e| e.to_string())
         .map(|p| p.join("cursor-changer"))
 }
 
-pub fn persist_config<R: Runtime>(app: &AppHandle<R>, config: &PersistedConfig) -> Result<(), String> {
+pub fn persist_config<R: Runtime>(
+    app: &AppHandle<R>,
+    config: &PersistedConfig,
+) -> Result<(), String> {
     let dir = config_path(app)?;
     write_config(&dir, config)
 }
@@ -109,8 +134,28 @@ pub fn load_persisted_config<R: Runtime>(app: &AppHandle<R>) -> Result<Persisted
 
     let s = fs::read_to_string(&file).map_err(|e| e.to_string())?;
 
+    // Invalid fields are reset individually; the original file is kept as a backup.
+    let (config, issues, reset_all) = parse_config(&s);
+    if !issues.is_empty() {
+        for issue in &issues {
+            cc_warn!(
+                "[cursor-changer] Invalid config.json field '{}': expected {}, found {}",
+                issue.field,
+                issue.expected,
+                issue.actual
+            );
+        }
+        let backup_path = backup_invalid_config(&file);
+        if let Ok(mut report) = VALIDATION_REPORT.lock() {
+            *report = Some(ConfigValidationReport {
+                issues,
+                reset_all,
+                backup_path: backup_path.map(|p| p.to_string_lossy().to_string()),
+            });
+        }
+    }
+
     // Older releases might have stored only the shortcut field; default missing values.
-    let config: PersistedConfig = serde_json::from_str(&s).map_err(|e| e.to_string())?;
     let config = normalize_persisted_config(config);
 
     cc_debug!("[cursor-changer] Loaded persisted config: {:?}", config);
@@ -118,6 +163,203 @@ pub fn load_persisted_config<R: Runtime>(app: &AppHandle<R>) -> Result<Persisted
     Ok(config)
 }
 
+/// One `config.json` field that failed validation and was reset to its default.
+#[derive(ts_rs::TS, Debug, Clone, PartialEq, Eq, Serialize)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct ConfigFieldIssue {
+    pub field: String,
+    /// What the field should hold, e.g. "boolean" or "integer between 32 and 256"
+    pub expected: String,
+    /// The offending value as found in the file
+    pub actual: String,
+}
+
+/// What was reset when `config.json` was loaded, and why.
+#[derive(ts_rs::TS, Debug, Clone, PartialEq, Eq, Serialize)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct ConfigValidationReport {
+    pub issues: Vec<ConfigFieldIssue>,
+    /// The file could not be read at all, so every setting is back to its default
+    pub reset_all: bool,
+    /// Copy of the invalid file, if it could be made
+    pub backup_path: Option<String>,
+}
+
+static VALIDATION_REPORT: Mutex<Option<ConfigValidationReport>> = Mutex::new(None);
+
+/// The report from the last load that found problems, if the UI has not taken it yet.
+pub fn pending_validation_report() -> Option<ConfigValidationReport> {
+    VALIDATION_REPORT.lock().ok().and_then(|r| r.clone())
+}
+
+/// Add a problem found in one of the feature settings files to the pending report.
+pub fn report_settings_issue(issue: ConfigFieldIssue) {
+    if let Ok(mut report) = VALIDATION_REPORT.lock() {
+        report
+            .get_or_insert_with(|| ConfigValidationReport {
+                issues: Vec::new(),
+                reset_all: false,
+                backup_path: None,
+            })
+            .issues
+            .push(issue);
+    }
+}
+
+/// Like [`pending_validation_report`], but clears it so the UI shows the notice only once.
+pub fn take_validation_report() -> Option<ConfigValidationReport> {
+    VALIDATION_REPORT.lock().ok().and_then(|mut r| r.take())
+}
+
+enum FieldRule {
+    Bool,
+    String,
+    Integer { min: i64, max: i64 },
+    OneOf(&'static [&'static str]),
+}
+
+impl FieldRule {
+    fn accepts(&self, value: &Value) -> bool {
+        match self {
+            Self::Bool => value.is_boolean(),
+            Self::String => value.is_string(),
+            Self::Integer { min, max } => {
+                value.as_i64().is_some_and(|n| (*min..=*max).contains(&n))
+            }
+            Self::OneOf(allowed) => value
+                .as_str()
+                .is_some_and(|s| allowed.contains(&s.trim().to_lowercase().as_str())),
+        }
+    }
+
+    fn expected(&self) -> String {
+        match self {
+            Self::Bool => "boolean".to_string(),
+            Self::String => "string".to_string(),
+            Self::Integer { min, max } => format!("integer between {} and {}", min, max),
+            Self::OneOf(allowed) => format!("one of {}", allowed.join(", ")),
+        }
+    }
+}
+
+/// Expected shape of every known field. `null` is always accepted and means "use the default";
+/// unknown fields are ignored so files from newer releases still load.
+const CONFIG_SCHEMA: &[(&str, FieldRule)] = &[
+    ("shortcut", FieldRule::String),
+    ("shortcut_enabled", FieldRule::Bool),
+    ("app_shortcut", FieldRule::String),
+    ("app_shortcut_enabled", FieldRule::Bool),
+    ("app_enabled", FieldRule::Bool),
+    ("minimize_to_tray", FieldRule::Bool),
+    ("run_on_startup", FieldRule::Bool),
+    ("cursor_size", FieldRule::Integer { min: 32, max: 256 }),
+    ("accent_color", FieldRule::String),
+    ("theme_mode", FieldRule::OneOf(&["light", "dark", "system"])),
+    (
+        "default_cursor_style",
+        FieldRule::OneOf(&[
+            "windows",
+            "mac",
+            "windows-black",
+            "windows-inverted",
+            "windows-magnified",
+            "touch",
+        ]),
+    ),
+    (
+        "customization_mode",
+        FieldRule::OneOf(&["simple", "advanced"]),
+    ),
+    ("last_page", FieldRule::String),
+];
+
+/// Short rendering of a JSON value for messages.
+fn describe_value(value: &Value) -> String {
+    const MAX_CHARS: usize = 60;
+    let rendered = value.to_string();
+    if rendered.chars().count() > MAX_CHARS {
+        let truncated: String = rendered.chars().take(MAX_CHARS).collect();
+        format!("{}...", truncated)
+    } else {
+        rendered
+    }
+}
+
+/// Fields of `value` that do not match [`CONFIG_SCHEMA`].
+pub fn validate_config_value(value: &Value) -> Vec<ConfigFieldIssue> {
+    let Some(object) = value.as_object() else {
+        return vec![ConfigFieldIssue {
+            field: "(file)".to_string(),
+            expected: "JSON object".to_string(),
+            actual: describe_value(value),
+        }];
+    };
+    CONFIG_SCHEMA
+        .iter()
+        .filter_map(|(field, rule)| {
+            let value = object.get(*field).filter(|v| !v.is_null())?;
+            (!rule.accepts(value)).then(|| ConfigFieldIssue {
+                field: (*field).to_string(),
+                expected: rule.expected(),
+                actual: describe_value(value),
+            })
+        })
+        .collect()
+}
+
+/// Parse `config.json` contents, dropping invalid fields so they fall back to their defaults.
+/// Returns the config, the issues found and whether the whole file had to be discarded.
+pub fn parse_config(contents: &str) -> (PersistedConfig, Vec<ConfigFieldIssue>, bool) {
+    let mut value: Value = match serde_json::from_str(contents) {
+        Ok(value) => value,
+        Err(e) => {
+            let issue = ConfigFieldIssue {
+                field: "(file)".to_string(),
+                expected: "valid JSON".to_string(),
+                actual: format!("parse error at line {}, column {}", e.line(), e.column()),
+            };
+            return (PersistedConfig::default(), vec![issue], true);
+        }
+    };
+
+    let issues = validate_config_value(&value);
+    let Some(object) = value.as_object_mut() else {
+        return (PersistedConfig::default(), issues, true);
+    };
+    for issue in &issues {
+        object.remove(&issue.field);
+    }
+    match serde_json::from_value(value) {
+        Ok(config) => (config, issues, false),
+        // The schema should have caught everything; fail safe if it ever falls behind
+        Err(e) => {
+            let mut issues = issues;
+            issues.push(ConfigFieldIssue {
+                field: "(file)".to_string(),
+                expected: "settings object".to_string(),
+                actual: e.to_string(),
+            });
+            (PersistedConfig::default(), issues, true)
+        }
+    }
+}
+
+/// Copy an invalid `config.json` to `config.invalid-<timestamp>.json` next to it.
+fn backup_invalid_config(file: &Path) -> Option<PathBuf> {
+    let stamp = chrono::Local::now().format("%Y%m%d-%H%M%S");
+    let backup = file.with_file_name(format!("config.invalid-{}.json", stamp));
+    match fs::copy(file, &backup) {
+        Ok(_) => Some(backup),
+        Err(e) => {
+            cc_warn!(
+                "[cursor-changer] Failed to back up invalid config.json: {}",
+                e
+            );
+            None
+        }
+    }
+}
+
 // Normalize persisted config values to provide sane defaults for older releases.
 pub fn normalize_persisted_config(mut config: PersistedConfig) -> PersistedConfig {
     fn fill_nulls(target: &mut serde_json::Value, defaults: &serde_json::Value) {
@@ -172,4 +414,72 @@ mod tests {
             from_state.default_cursor_style
         );
     }
+
+    #[test]
+    fn invalid_fields_are_reset_individually() {
+        let (config, issues, reset_all) = parse_config(
+            r##"{"cursor_size": "huge", "minimize_to_tray": false, "theme_mode": "purple", "accent_color": "#112233"}"##,
+        );
+
+        assert!(!reset_all);
+        let fields: Vec<&str> = issues.iter().map(|i| i.field.as_str()).collect();
+        assert_eq!(fields, vec!["cursor_size", "theme_mode"]);
+        assert_eq!(issues[0].expected, "integer between 32 and 256");
+        assert_eq!(issues[0].actual, "\"huge\"");
+        assert_eq!(config.cursor_size, None);
+        assert_eq!(config.minimize_to_tray, Some(false));
+        assert_eq!(config.accent_color.as_deref(), Some("#112233"));
+    }
+
+    #[test]
+    fn unreadable_file_resets_everything() {
+        let (config, issues, reset_all) = parse_config("{\"shortcut\": ");
+        assert!(reset_all);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].field, "(file)");
+        assert_eq!(config.shortcut, None);
+
+        let (_, issues, reset_all) = parse_config("[1, 2]");
+        assert!(reset_all);
+        assert_eq!(issues[0].expected, "JSON object");
+    }
+
+    #[test]
+    fn valid_config_has_no_issues() {
+        let config = PersistedConfig::from(&AppState::default());
+        let json = serde_json::to_string(&config).unwrap();
+        let (_, issues, reset_all) = parse_config(&json);
+        assert!(issues.is_empty());
+        assert!(!reset_all);
+    }
+
+    #[test]
+    fn window_state_round_trips_and_a_bad_rectangle_is_dropped_alone() {
+        let state = AppState::default();
+        {
+            let mut prefs = state.prefs.write().unwrap();
+            prefs.window_geometry = Some(WindowGeometry {
+                x: 10,
+                y: 20,
+                width: 800,
+                height: 600,
+                maximized: true,
+            });
+            prefs.last_page = Some("effects".to_string());
+        }
+        let json = serde_json::to_string(&PersistedConfig::from(&state)).unwrap();
+        let (config, issues, _) = parse_config(&json);
+        assert!(issues.is_empty());
+        assert_eq!(
+            config.window_geometry,
+            state.prefs.read().unwrap().window_geometry
+        );
+        assert_eq!(config.last_page.as_deref(), Some("effects"));
+
+        let (config, _, reset_all) =
+            parse_config(r#"{"cursor_size": 48, "window_geometry": {"x": "left"}}"#);
+        assert!(!reset_all);
+        assert_eq!(config.window_geometry, None);
+        assert_eq!(config.cursor_size, Some(48));
+    }
 }
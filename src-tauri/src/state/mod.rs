@@ -1,9 +1,15 @@
 pub mod app_state;
 pub mod config;
 pub mod models;
+pub mod policy;
+pub mod settings_file;
+pub mod store;
 pub mod types;
+pub mod visibility;
 
 pub use app_state::{AppState, CursorInfo, MinimizePreference};
 pub use config::PersistedConfig;
-pub use models::{CustomizationMode, DefaultCursorStyle, ThemeMode};
+pub use models::{CustomizationMode, DefaultCursorStyle, EffectsConfig, ThemeMode, WindowGeometry};
+pub use policy::{AdminPolicy, PolicyLocks};
 pub use types::CursorStatePayload;
+pub use visibility::{CursorVisibility, HideSnapshot, VisibilityEvent};
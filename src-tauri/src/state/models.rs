@@ -67,11 +67,19 @@ impl Default for ThemeMode {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
-#[serde(rename_all = "lowercase")]
+#[serde(rename_all = "kebab-case")]
 #[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
 pub enum DefaultCursorStyle {
     Windows,
     Mac,
+    /// Windows' stock "Windows Black" scheme from `%WINDIR%\Cursors`
+    WindowsBlack,
+    /// Windows' stock "Windows Inverted" scheme from `%WINDIR%\Cursors`
+    WindowsInverted,
+    /// Windows' stock "Magnified" scheme from `%WINDIR%\Cursors`
+    WindowsMagnified,
+    /// Extra-large, high-contrast set drawn by the app for tablets and pen displays
+    Touch,
 }
 
 impl DefaultCursorStyle {
@@ -79,6 +87,10 @@ impl DefaultCursorStyle {
         match self {
             Self::Windows => "windows",
             Self::Mac => "mac",
+            Self::WindowsBlack => "windows-black",
+            Self::WindowsInverted => "windows-inverted",
+            Self::WindowsMagnified => "windows-magnified",
+            Self::Touch => "touch",
         }
     }
 
@@ -86,9 +98,32 @@ impl DefaultCursorStyle {
         match s.trim().to_lowercase().as_str() {
             "windows" => Some(Self::Windows),
             "mac" => Some(Self::Mac),
+            "windows-black" => Some(Self::WindowsBlack),
+            "windows-inverted" => Some(Self::WindowsInverted),
+            "windows-magnified" => Some(Self::WindowsMagnified),
+            "touch" => Some(Self::Touch),
             _ => None,
         }
     }
+
+    /// The stock Windows scheme the style is read from, for styles that are not bundled.
+    pub fn system_scheme(self) -> Option<cursor_changer::SystemCursorScheme> {
+        match self {
+            Self::Windows | Self::Mac | Self::Touch => None,
+            Self::WindowsBlack => Some(cursor_changer::SystemCursorScheme::Black),
+            Self::WindowsInverted => Some(cursor_changer::SystemCursorScheme::Inverted),
+            Self::WindowsMagnified => Some(cursor_changer::SystemCursorScheme::Magnified),
+        }
+    }
+
+    /// Name of the bundled `default-cursors` directory the style uses. Stock schemes fall
+    /// back to the Windows set for roles they do not cover.
+    pub fn bundled_dir_name(self) -> &'static str {
+        match self {
+            Self::Mac => "mac",
+            _ => "windows",
+        }
+    }
 }
 
 impl Default for DefaultCursorStyle {
@@ -96,3 +131,21 @@ impl Default for DefaultCursorStyle {
         Self::Windows
     }
 }
+
+/// The main window's normal (restored) rectangle in physical pixels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WindowGeometry {
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+    #[serde(default)]
+    pub maximized: bool,
+}
+
+/// Cursor effects (trail, ripple, spotlight, ...) enabled for one customization profile.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct EffectsConfig {
+    pub enabled: Vec<String>,
+}
@@ -0,0 +1,186 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Manager, Runtime};
+
+use super::models::DefaultCursorStyle;
+use super::AppState;
+
+pub const POLICY_FILE_NAME: &str = "policy.json";
+
+/// Settings an administrator can lock through `%ProgramData%\cursor-changer\policy.json`.
+///
+/// Every field is optional in the file; anything missing leaves the setting under user control.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AdminPolicy {
+    /// Prevent the cursor from being hidden (toggle, hotkey and tray).
+    pub disable_hide: bool,
+    /// Pin the default cursor pack; users cannot switch styles while this is set.
+    pub force_default_cursor_style: Option<DefaultCursorStyle>,
+    /// Turn off browsing and downloading packs from the marketplace.
+    pub disable_marketplace: bool,
+}
+
+/// Locked settings as seen by the UI, so it can grey out the matching controls.
+#[derive(ts_rs::TS, Serialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct PolicyLocks {
+    pub hide_disabled: bool,
+    pub default_cursor_style_locked: bool,
+    pub marketplace_disabled: bool,
+}
+
+impl AdminPolicy {
+    pub fn locks(&self) -> PolicyLocks {
+        PolicyLocks {
+            hide_disabled: self.disable_hide,
+            default_cursor_style_locked: self.force_default_cursor_style.is_some(),
+            marketplace_disabled: self.disable_marketplace,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Refuse custom cursors and style switches while the default cursor style is pinned.
+    pub fn check_cursor_style(&self) -> Result<(), String> {
+        if self.force_default_cursor_style.is_some() {
+            Err("The default cursor style is locked by your administrator".to_string())
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Refuse pack downloads while the marketplace is turned off.
+    pub fn check_marketplace(&self) -> Result<(), String> {
+        if self.disable_marketplace {
+            Err("Downloading cursor packs has been disabled by your administrator".to_string())
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// [`AdminPolicy::check_marketplace`] against the policy the app loaded at startup.
+pub fn check_marketplace_allowed<R: Runtime>(app: &AppHandle<R>) -> Result<(), String> {
+    let Some(state) = app.try_state::<AppState>() else {
+        return Ok(());
+    };
+    let policy = state
+        .policy
+        .read()
+        .map_err(|_| "Application state poisoned".to_string())?;
+    policy.check_marketplace()
+}
+
+pub fn policy_file_path() -> Option<PathBuf> {
+    let program_data = std::env::var("ProgramData")
+        .or_else(|_| std::env::var("PROGRAMDATA"))
+        .ok()?;
+    Some(
+        PathBuf::from(program_data)
+            .join("cursor-changer")
+            .join(POLICY_FILE_NAME),
+    )
+}
+
+/// Load the machine-wide policy. A missing file means no policy; a malformed one is logged
+/// and ignored so a bad deployment never keeps the app from starting.
+pub fn load_admin_policy() -> AdminPolicy {
+    match policy_file_path() {
+        Some(path) => load_admin_policy_from(&path),
+        None => AdminPolicy::default(),
+    }
+}
+
+pub fn load_admin_policy_from(path: &Path) -> AdminPolicy {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return AdminPolicy::default(),
+        Err(e) => {
+            cc_warn!(
+                "[CursorChanger] Failed to read policy file {}: {}",
+                path.display(),
+                e
+            );
+            return AdminPolicy::default();
+        }
+    };
+
+    match serde_json::from_str::<AdminPolicy>(&contents) {
+        Ok(policy) => {
+            cc_info!(
+                "[CursorChanger] Loaded admin policy from {}: {:?}",
+                path.display(),
+                policy
+            );
+            policy
+        }
+        Err(e) => {
+            cc_error!(
+                "[CursorChanger] Ignoring malformed policy file {}: {}",
+                path.display(),
+                e
+            );
+            AdminPolicy::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_policy_file_locks_nothing() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let policy = load_admin_policy_from(&temp.path().join(POLICY_FILE_NAME));
+        assert!(policy.is_empty());
+        assert_eq!(policy.locks(), PolicyLocks::default());
+    }
+
+    #[test]
+    fn partial_policy_file_fills_defaults() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let path = temp.path().join(POLICY_FILE_NAME);
+        fs::write(
+            &path,
+            r#"{ "disable_hide": true, "force_default_cursor_style": "windows" }"#,
+        )
+        .expect("write policy");
+
+        let policy = load_admin_policy_from(&path);
+        assert!(policy.disable_hide);
+        assert_eq!(
+            policy.force_default_cursor_style,
+            Some(DefaultCursorStyle::Windows)
+        );
+        assert!(!policy.disable_marketplace);
+
+        let locks = policy.locks();
+        assert!(locks.hide_disabled);
+        assert!(locks.default_cursor_style_locked);
+        assert!(!locks.marketplace_disabled);
+        assert!(policy.check_marketplace().is_ok());
+    }
+
+    #[test]
+    fn disabled_marketplace_blocks_downloads() {
+        let policy = AdminPolicy {
+            disable_marketplace: true,
+            ..AdminPolicy::default()
+        };
+        assert!(policy.locks().marketplace_disabled);
+        assert!(policy.check_marketplace().is_err());
+    }
+
+    #[test]
+    fn malformed_policy_file_is_ignored() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let path = temp.path().join(POLICY_FILE_NAME);
+        fs::write(&path, "{ not json").expect("write policy");
+        assert!(load_admin_policy_from(&path).is_empty());
+    }
+}
@@ -0,0 +1,215 @@
+//! Feature settings kept in their own JSON file next to `config.json`.
+//!
+//! Every file goes through the same load path as `config.json`: a file that cannot be read,
+//! parsed or validated is copied to `<name>.invalid-<timestamp>.json`, reset to its defaults and
+//! listed in the config validation notice. Paths are stored relative to the portable data folder,
+//! and `--validate-config` checks every file in [`registered_files`].
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::fs;
+use std::path::Path;
+use tauri::{AppHandle, Runtime};
+
+use super::config::{config_path, report_settings_issue, ConfigFieldIssue};
+
+/// A settings type stored as `FILE_NAME` in the config folder.
+pub trait SettingsFile: Serialize + DeserializeOwned + Default + Clone {
+    const FILE_NAME: &'static str;
+    /// What the file holds, for messages, e.g. "health endpoint settings"
+    const DESCRIPTION: &'static str;
+
+    /// Reject values the app cannot use. Invalid files are reset on load and never saved.
+    fn validate(&self) -> Result<(), String> {
+        Ok(())
+    }
+
+    /// Rewrite every stored path with `map`; called with [`crate::portable::to_stored`] before
+    /// saving and [`crate::portable::resolve_stored`] after loading.
+    fn map_paths(&mut self, _map: fn(&str) -> String) {}
+}
+
+/// Read `T` from `dir` without side effects. A missing file gives the defaults.
+pub fn read<T: SettingsFile>(dir: &Path) -> Result<T, String> {
+    let path = dir.join(T::FILE_NAME);
+    if !path.exists() {
+        return Ok(T::default());
+    }
+    let contents = fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read {}: {}", T::DESCRIPTION, e))?;
+    let mut settings: T = serde_json::from_str(&contents)
+        .map_err(|e| format!("parse error at line {}, column {}", e.line(), e.column()))?;
+    settings.validate()?;
+    if crate::portable::is_portable() {
+        settings.map_paths(crate::portable::resolve_stored);
+    }
+    Ok(settings)
+}
+
+/// Load `T` from `dir`, resetting and reporting a file that fails [`read`].
+pub fn load_from<T: SettingsFile>(dir: &Path) -> T {
+    match read(dir) {
+        Ok(settings) => settings,
+        Err(e) => {
+            let path = dir.join(T::FILE_NAME);
+            cc_warn!("[cursor-changer] Invalid {}: {}", T::FILE_NAME, e);
+            backup_invalid_file(&path);
+            report_settings_issue(ConfigFieldIssue {
+                field: T::FILE_NAME.to_string(),
+                expected: format!("valid {}", T::DESCRIPTION),
+                actual: e,
+            });
+            T::default()
+        }
+    }
+}
+
+pub fn load<T: SettingsFile, R: Runtime>(app: &AppHandle<R>) -> T {
+    match config_path(app) {
+        Ok(dir) => load_from(&dir),
+        Err(_) => T::default(),
+    }
+}
+
+/// Validate `settings` and write them to `dir`.
+pub fn save_to<T: SettingsFile>(dir: &Path, settings: &T) -> Result<(), String> {
+    settings.validate()?;
+    fs::create_dir_all(dir).map_err(|e| format!("Failed to create config directory: {}", e))?;
+    let mut stored = settings.clone();
+    if crate::portable::is_portable() {
+        stored.map_paths(crate::portable::to_stored);
+    }
+    let json = serde_json::to_string_pretty(&stored)
+        .map_err(|e| format!("Failed to serialize {}: {}", T::DESCRIPTION, e))?;
+    fs::write(dir.join(T::FILE_NAME), json)
+        .map_err(|e| format!("Failed to write {}: {}", T::DESCRIPTION, e))
+}
+
+pub fn save<T: SettingsFile, R: Runtime>(app: &AppHandle<R>, settings: &T) -> Result<(), String> {
+    save_to(&config_path(app)?, settings)
+}
+
+/// Copy an invalid settings file to `<stem>.invalid-<timestamp>.json` next to it.
+fn backup_invalid_file(path: &Path) {
+    let Some(stem) = path.file_stem().map(|s| s.to_string_lossy()) else {
+        return;
+    };
+    let stamp = chrono::Local::now().format("%Y%m%d-%H%M%S");
+    let backup = path.with_file_name(format!("{}.invalid-{}.json", stem, stamp));
+    match fs::copy(path, &backup) {
+        Ok(_) => cc_info!(
+            "[cursor-changer] Kept invalid settings as {}",
+            backup.display()
+        ),
+        Err(e) => cc_warn!(
+            "[cursor-changer] Failed to back up invalid {}: {}",
+            path.display(),
+            e
+        ),
+    }
+}
+
+/// Checks [`read`] for one settings type, for `--validate-config`.
+pub type SettingsCheck = fn(&Path) -> Result<(), String>;
+
+fn check<T: SettingsFile>(dir: &Path) -> Result<(), String> {
+    read::<T>(dir).map(|_| ())
+}
+
+/// Every settings file with the check `--validate-config` runs on it.
+pub fn registered_files() -> Vec<(&'static str, SettingsCheck)> {
+    fn entry<T: SettingsFile>() -> (&'static str, SettingsCheck) {
+        (T::FILE_NAME, check::<T>)
+    }
+    vec![
+        entry::<crate::cache_gc::CacheGcSettings>(),
+        entry::<crate::commands::customization::library::CursorVariantSettings>(),
+        entry::<crate::commands::customization::pack_schedule::ScheduledApplies>(),
+        entry::<crate::conversion_output::ConversionOutputSettings>(),
+        entry::<crate::crash_reports::CrashReportSettings>(),
+        entry::<crate::cursor_brightness::AdaptiveBrightnessSettings>(),
+        entry::<crate::cursor_refresh::CursorRefreshSettings>(),
+        entry::<crate::decode_worker::DecodeSandboxSettings>(),
+        entry::<crate::effects_quality::EffectQualitySettings>(),
+        entry::<crate::health_endpoint::HealthEndpointSettings>(),
+        entry::<crate::hide_roles::HideRolesSettings>(),
+        entry::<crate::hot_corners::HotCornerSettings>(),
+        entry::<crate::hotkey_chords::HotkeyChordSettings>(),
+        entry::<crate::hotkey_press::HotkeyPressSettings>(),
+        entry::<crate::memory_policy::MemoryPolicySettings>(),
+        entry::<crate::monitor_schemes::MonitorSchemeSettings>(),
+        entry::<crate::pointer_control::PointerControlSettings>(),
+        entry::<crate::reduced_motion::ReducedMotionSettings>(),
+        entry::<crate::size_gesture::SizeGestureSettings>(),
+        entry::<crate::status_file::StatusFileSettings>(),
+        entry::<crate::storage_location::StorageLocationSettings>(),
+        entry::<crate::svg_resources::SvgResourceSettings>(),
+        entry::<crate::usage_stats::UsageStatsSettings>(),
+        entry::<crate::usage_stats::UsageCounts>(),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+    struct Example {
+        #[serde(default)]
+        limit: u32,
+    }
+
+    impl SettingsFile for Example {
+        const FILE_NAME: &'static str = "example.json";
+        const DESCRIPTION: &'static str = "example settings";
+
+        fn validate(&self) -> Result<(), String> {
+            if self.limit > 10 {
+                return Err("limit must be at most 10".to_string());
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn saved_settings_load_back_and_missing_files_default() {
+        let dir = tempfile::tempdir().unwrap();
+        assert_eq!(load_from::<Example>(dir.path()), Example::default());
+
+        save_to(dir.path(), &Example { limit: 4 }).unwrap();
+        assert_eq!(load_from::<Example>(dir.path()), Example { limit: 4 });
+        assert!(save_to(dir.path(), &Example { limit: 11 }).is_err());
+    }
+
+    #[test]
+    fn invalid_files_are_reset_and_backed_up() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("example.json"), r#"{"limit": 99}"#).unwrap();
+        assert!(read::<Example>(dir.path())
+            .unwrap_err()
+            .contains("at most 10"));
+
+        assert_eq!(load_from::<Example>(dir.path()), Example::default());
+        let backups: Vec<String> = fs::read_dir(dir.path())
+            .unwrap()
+            .flatten()
+            .map(|e| e.file_name().to_string_lossy().to_string())
+            .filter(|name| name.starts_with("example.invalid-"))
+            .collect();
+        assert_eq!(backups.len(), 1);
+
+        fs::write(dir.path().join("example.json"), "{ nope").unwrap();
+        assert!(read::<Example>(dir.path())
+            .unwrap_err()
+            .starts_with("parse error"));
+    }
+
+    #[test]
+    fn registered_file_names_are_unique() {
+        let files = registered_files();
+        let names: std::collections::HashSet<_> = files.iter().map(|(name, _)| *name).collect();
+        assert_eq!(names.len(), files.len());
+        assert!(!names.contains("config.json"));
+    }
+}
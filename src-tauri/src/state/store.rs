@@ -0,0 +1,419 @@
+//! Snapshot store backing [`AppState`](super::AppState).
+//!
+//! Every layer of application state lives in one immutable [`Layers`] value. Readers clone an
+//! `Arc` to the latest published snapshot and never wait. Writes go through the state actor, a
+//! thread that owns the authoritative layers and talks to writers only by message: a writer
+//! claims the layers it wants to change, stages private copies, and sends its changes back
+//! when its guard drops; the actor applies them and publishes the next snapshot.
+//!
+//! A write claims every layer it needs in one call. The actor grants disjoint layers to
+//! different threads at once and only queues a claim that overlaps layers another thread
+//! holds. A thread that already holds layers cannot claim more ([`StateError::NestedWrite`]),
+//! so no thread ever waits while holding a layer and claims cannot deadlock on their order.
+//! Reads always see the last published snapshot; a writer reads its own uncommitted changes
+//! through its guard.
+
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::ops::{Deref, DerefMut};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle, ThreadId};
+
+use super::app_state::{
+    CursorRuntimeState, ModeCustomizationState, PreferencesState, RestorationState,
+};
+use super::policy::AdminPolicy;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StateError {
+    /// A thread panicked while holding the store's internal locks, or the actor is gone
+    Poisoned,
+    /// The thread already holds layers of the store; a write claims all it needs at once
+    NestedWrite,
+}
+
+impl fmt::Display for StateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StateError::Poisoned => f.write_str("Application state poisoned"),
+            StateError::NestedWrite => {
+                f.write_str("Application state is already being written on this thread")
+            }
+        }
+    }
+}
+
+impl std::error::Error for StateError {}
+
+impl From<StateError> for String {
+    fn from(err: StateError) -> Self {
+        err.to_string()
+    }
+}
+
+/// One consistent snapshot of every state layer.
+#[derive(Debug, Clone, Default)]
+pub struct Layers {
+    pub prefs: Arc<PreferencesState>,
+    pub policy: Arc<AdminPolicy>,
+    pub modes: Arc<ModeCustomizationState>,
+    pub cursor: Arc<CursorRuntimeState>,
+    pub restoration: Arc<RestorationState>,
+}
+
+/// A set of layers claimed by a writer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct LayerSet(u8);
+
+impl LayerSet {
+    pub const PREFS: Self = Self(1);
+    pub const POLICY: Self = Self(1 << 1);
+    pub const MODES: Self = Self(1 << 2);
+    pub const CURSOR: Self = Self(1 << 3);
+    pub const RESTORATION: Self = Self(1 << 4);
+    pub const ALL: Self = Self(0b1_1111);
+
+    fn overlaps(self, other: Self) -> bool {
+        self.0 & other.0 != 0
+    }
+
+    fn with(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+/// Staged changes a writer hands back to the actor.
+type Changes = Box<dyn FnOnce(&mut Layers) + Send>;
+
+/// Answers a claim once its layers are granted.
+type Grant = Sender<Result<(), StateError>>;
+
+enum Message {
+    /// Grant `layers` to `thread`, answering once they are free
+    Claim {
+        thread: ThreadId,
+        layers: LayerSet,
+        granted: Grant,
+    },
+    /// Apply `changes`, if any, publish them and give back the layers `thread` holds
+    Release {
+        thread: ThreadId,
+        changes: Option<Changes>,
+        done: Sender<()>,
+    },
+    /// Stop the actor; sent when the store is dropped
+    Stop,
+}
+
+/// The state actor: the only owner of the authoritative layers.
+struct Actor {
+    layers: Layers,
+    published: Arc<Mutex<Arc<Layers>>>,
+    held: HashMap<ThreadId, LayerSet>,
+    waiting: VecDeque<(ThreadId, LayerSet, Grant)>,
+}
+
+impl Actor {
+    fn run(mut self, inbox: Receiver<Message>) {
+        for message in inbox {
+            match message {
+                Message::Claim {
+                    thread,
+                    layers,
+                    granted,
+                } => self.claim(thread, layers, granted),
+                Message::Release {
+                    thread,
+                    changes,
+                    done,
+                } => {
+                    self.release(thread, changes);
+                    let _ = done.send(());
+                }
+                Message::Stop => break,
+            }
+        }
+    }
+
+    /// Layers held by every thread other than `thread`.
+    fn held_by_others(&self, thread: ThreadId) -> LayerSet {
+        self.held
+            .iter()
+            .filter(|(owner, _)| **owner != thread)
+            .fold(LayerSet::default(), |all, (_, layers)| all.with(*layers))
+    }
+
+    fn claim(&mut self, thread: ThreadId, layers: LayerSet, granted: Grant) {
+        // Waiting for more layers while holding some is how lock-order deadlocks start
+        if self.held.contains_key(&thread) {
+            let _ = granted.send(Err(StateError::NestedWrite));
+        } else if self.held_by_others(thread).overlaps(layers) {
+            self.waiting.push_back((thread, layers, granted));
+        } else {
+            self.grant(thread, layers, &granted);
+        }
+    }
+
+    fn grant(&mut self, thread: ThreadId, layers: LayerSet, granted: &Grant) {
+        // A claimant that gave up waiting is skipped
+        if granted.send(Ok(())).is_ok() {
+            self.held.insert(thread, layers);
+        }
+    }
+
+    fn release(&mut self, thread: ThreadId, changes: Option<Changes>) {
+        if let Some(changes) = changes {
+            changes(&mut self.layers);
+            let mut published = self.published.lock().unwrap_or_else(|e| e.into_inner());
+            *published = Arc::new(self.layers.clone());
+        }
+        self.held.remove(&thread);
+        // Oldest first, grant every waiter whose layers are now free
+        for (thread, layers, granted) in std::mem::take(&mut self.waiting) {
+            if self.held_by_others(thread).overlaps(layers) {
+                self.waiting.push_back((thread, layers, granted));
+            } else {
+                self.grant(thread, layers, &granted);
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Store {
+    published: Arc<Mutex<Arc<Layers>>>,
+    actor: Sender<Message>,
+    worker: Option<JoinHandle<()>>,
+}
+
+impl Default for Store {
+    fn default() -> Self {
+        let published = Arc::new(Mutex::new(Arc::new(Layers::default())));
+        let (actor, inbox) = mpsc::channel();
+        let state = Actor {
+            layers: Layers::default(),
+            published: Arc::clone(&published),
+            held: HashMap::new(),
+            waiting: VecDeque::new(),
+        };
+        let worker = thread::Builder::new()
+            .name("app-state".to_string())
+            .spawn(move || state.run(inbox))
+            .expect("failed to start the application state actor");
+        Self {
+            published,
+            actor,
+            worker: Some(worker),
+        }
+    }
+}
+
+impl Drop for Store {
+    fn drop(&mut self) {
+        // No write token outlives the store, so nothing is left waiting on the actor.
+        let _ = self.actor.send(Message::Stop);
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join();
+        }
+    }
+}
+
+impl Store {
+    /// The latest published snapshot.
+    pub fn snapshot(&self) -> Result<Arc<Layers>, StateError> {
+        self.published
+            .lock()
+            .map(|current| Arc::clone(&current))
+            .map_err(|_| StateError::Poisoned)
+    }
+
+    /// Claims all of `layers` for the current thread at once, waiting while another thread
+    /// writes any of them. Fails with [`StateError::NestedWrite`] if this thread already holds
+    /// layers.
+    pub fn begin_write(&self, layers: LayerSet) -> Result<WriteToken<'_>, StateError> {
+        let thread = thread::current().id();
+        let (granted, reply) = mpsc::channel();
+        self.actor
+            .send(Message::Claim {
+                thread,
+                layers,
+                granted,
+            })
+            .map_err(|_| StateError::Poisoned)?;
+        reply.recv().map_err(|_| StateError::Poisoned)??;
+        Ok(WriteToken {
+            store: self,
+            thread,
+            released: false,
+        })
+    }
+}
+
+/// Proof that the current thread holds some layers; gives them back on drop.
+pub struct WriteToken<'a> {
+    store: &'a Store,
+    thread: ThreadId,
+    released: bool,
+}
+
+impl WriteToken<'_> {
+    /// Hands `changes` to the actor and returns once they are published.
+    pub(super) fn release(&mut self, changes: Option<Changes>) {
+        if self.released {
+            return;
+        }
+        self.released = true;
+        let (done, published) = mpsc::channel();
+        let sent = self.store.actor.send(Message::Release {
+            thread: self.thread,
+            changes,
+            done,
+        });
+        if sent.is_ok() {
+            let _ = published.recv();
+        }
+    }
+}
+
+impl Drop for WriteToken<'_> {
+    fn drop(&mut self) {
+        self.release(None);
+    }
+}
+
+/// A single layer of the store, read as a snapshot and written copy-on-write.
+pub struct StateCell<T> {
+    store: Arc<Store>,
+    layer: LayerSet,
+    select: fn(&Layers) -> &Arc<T>,
+    select_mut: fn(&mut Layers) -> &mut Arc<T>,
+}
+
+impl<T: Clone + Send + Sync + 'static> StateCell<T> {
+    pub(super) fn new(
+        store: Arc<Store>,
+        layer: LayerSet,
+        select: fn(&Layers) -> &Arc<T>,
+        select_mut: fn(&mut Layers) -> &mut Arc<T>,
+    ) -> Self {
+        Self {
+            store,
+            layer,
+            select,
+            select_mut,
+        }
+    }
+
+    /// The latest committed value. Never waits for writers; a writer sees its own staged
+    /// changes through its guard, not here.
+    pub fn read(&self) -> Result<Arc<T>, StateError> {
+        let layers = self.store.snapshot()?;
+        Ok(Arc::clone((self.select)(&layers)))
+    }
+
+    /// Stages a copy of the latest value; changes become visible to other threads when the
+    /// guard drops.
+    pub fn write(&self) -> Result<CellWriteGuard<'_, T>, StateError> {
+        let token = self.store.begin_write(self.layer)?;
+        let layers = self.store.snapshot()?;
+        Ok(CellWriteGuard {
+            cell: self,
+            value: Some(T::clone((self.select)(&layers))),
+            token,
+        })
+    }
+}
+
+impl<T: fmt::Debug + Clone + Send + Sync + 'static> fmt::Debug for StateCell<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.read() {
+            Ok(value) => f.debug_tuple("StateCell").field(&value).finish(),
+            Err(err) => f.debug_tuple("StateCell").field(&err).finish(),
+        }
+    }
+}
+
+pub struct CellWriteGuard<'a, T: Clone + Send + Sync + 'static> {
+    cell: &'a StateCell<T>,
+    value: Option<T>,
+    token: WriteToken<'a>,
+}
+
+impl<T: Clone + Send + Sync + 'static> Deref for CellWriteGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.value
+            .as_ref()
+            .expect("staged value present until drop")
+    }
+}
+
+impl<T: Clone + Send + Sync + 'static> DerefMut for CellWriteGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        self.value
+            .as_mut()
+            .expect("staged value present until drop")
+    }
+}
+
+impl<T: Clone + Send + Sync + 'static> Drop for CellWriteGuard<'_, T> {
+    fn drop(&mut self) {
+        // A panicking writer leaves the last committed snapshot untouched.
+        if thread::panicking() {
+            return;
+        }
+        if let Some(value) = self.value.take() {
+            let select_mut = self.cell.select_mut;
+            self.token
+                .release(Some(Box::new(move |layers: &mut Layers| {
+                    *select_mut(layers) = Arc::new(value);
+                })));
+        }
+    }
+}
+
+/// A privately staged copy of one layer inside a multi-layer write.
+#[derive(Debug)]
+pub struct Staged<T>(T);
+
+impl<T> Deref for Staged<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Staged<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<T: Clone> Staged<T> {
+    pub(super) fn of(value: &Arc<T>) -> Self {
+        Self(T::clone(value))
+    }
+}
+
+impl<T: Default> Staged<T> {
+    pub(super) fn publish(&mut self) -> Arc<T> {
+        Arc::new(std::mem::take(&mut self.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dropping_the_store_stops_the_actor() {
+        let store = Store::default();
+        let actor_copy = Arc::downgrade(&store.published);
+        drop(store);
+        // The actor held the other reference and has exited.
+        assert!(actor_copy.upgrade().is_none());
+    }
+}
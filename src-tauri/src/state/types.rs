@@ -1,5 +1,5 @@
 use crate::state::AppState;
-use crate::state::{DefaultCursorStyle, ThemeMode};
+use crate::state::{CursorVisibility, DefaultCursorStyle, EffectsConfig, PolicyLocks, ThemeMode};
 use serde::Serialize;
 use std::collections::HashMap;
 
@@ -7,6 +7,8 @@ use std::collections::HashMap;
 #[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
 pub struct CursorStatePayload {
     pub hidden: bool,
+    // Where the cursor is in the hide/restore lifecycle; `hidden` is its short form
+    pub visibility: CursorVisibility,
     pub shortcut: Option<String>,
     pub shortcut_enabled: bool,
     pub app_shortcut: Option<String>,
@@ -25,6 +27,10 @@ pub struct CursorStatePayload {
     pub theme_mode: ThemeMode,
     // Default cursor style: "windows"
     pub default_cursor_style: DefaultCursorStyle,
+    // Settings locked by the admin policy file (UI greys these out)
+    pub policy_locks: PolicyLocks,
+    // Effects enabled for the active customization profile
+    pub effects: EffectsConfig,
 }
 
 impl TryFrom<&AppState> for CursorStatePayload {
@@ -34,7 +40,8 @@ impl TryFrom<&AppState> for CursorStatePayload {
         let guard = state.read_all()?;
 
         Ok(Self {
-            hidden: guard.cursor.hidden,
+            hidden: guard.cursor.visibility.is_hidden(),
+            visibility: guard.cursor.visibility.clone(),
             shortcut: guard.prefs.shortcut.clone(),
             shortcut_enabled: guard.prefs.shortcut_enabled,
             app_shortcut: guard.prefs.app_shortcut.clone(),
@@ -48,6 +55,8 @@ impl TryFrom<&AppState> for CursorStatePayload {
             accent_color: guard.prefs.accent_color.clone(),
             theme_mode: guard.prefs.theme_mode,
             default_cursor_style: guard.prefs.default_cursor_style,
+            policy_locks: guard.policy.locks(),
+            effects: crate::reduced_motion::effective_effects(&guard.modes.effects),
         })
     }
 }
@@ -66,14 +75,17 @@ pub struct CursorClickPointInfo {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::state::AppState;
+    use crate::state::{AppState, HideSnapshot, VisibilityEvent};
 
     #[test]
     fn cursor_state_payload_from_app_state() {
         let mut state = AppState::default();
         {
             let mut cursor = state.cursor.write().unwrap();
-            cursor.hidden = true;
+            cursor
+                .visibility
+                .apply(VisibilityEvent::Hid(HideSnapshot::default()))
+                .unwrap();
         }
         {
             let mut prefs = state.prefs.write().unwrap();
@@ -86,12 +98,21 @@ mod tests {
             prefs.theme_mode = ThemeMode::System;
             prefs.default_cursor_style = DefaultCursorStyle::Windows;
         }
+        state.policy.write().unwrap().disable_hide = true;
+        state.modes.write().unwrap().effects.enabled = vec!["trail".to_string()];
 
         let payload = CursorStatePayload::try_from(&state).expect("Application state poisoned");
         assert_eq!(payload.hidden, true);
+        assert!(matches!(
+            payload.visibility,
+            CursorVisibility::Hidden { .. }
+        ));
         assert_eq!(payload.shortcut, Some("Ctrl+Shift+X".to_string()));
         assert_eq!(payload.minimize_to_tray, false);
         assert_eq!(payload.cursor_size, 64);
         assert_eq!(payload.default_cursor_style, DefaultCursorStyle::Windows);
+        assert!(payload.policy_locks.hide_disabled);
+        assert!(!payload.policy_locks.default_cursor_style_locked);
+        assert_eq!(payload.effects.enabled, vec!["trail".to_string()]);
     }
 }
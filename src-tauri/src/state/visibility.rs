@@ -0,0 +1,231 @@
+//! Hide/restore lifecycle of the system cursor.
+//!
+//! Hotkeys, commands, monitor schemes and the shutdown hooks all hide or restore the cursor.
+//! Every change goes through [`CursorVisibility::apply`], which rejects transitions that make
+//! no sense from the current state: restoring a cursor that is not hidden, hiding one that
+//! already is, or starting either while the other is still running.
+
+use serde::Serialize;
+
+/// What the cursor looked like when it was hidden.
+#[derive(ts_rs::TS, Serialize, Clone, Debug, Default, PartialEq, Eq)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct HideSnapshot {
+    /// Cursor roles that were replaced with the blank cursor
+    pub blanked_roles: Vec<String>,
+}
+
+#[derive(ts_rs::TS, Serialize, Clone, Debug, Default, PartialEq, Eq)]
+#[serde(tag = "state", rename_all = "snake_case")]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub enum CursorVisibility {
+    #[default]
+    Visible,
+    /// A hide is running; the cursor still counts as visible until it finishes
+    Hiding,
+    Hidden {
+        snapshot: HideSnapshot,
+    },
+    /// A restore is running; the cursor stays blank until it finishes
+    Restoring,
+    /// The last hide or restore failed. `cursor_hidden` tells which way the cursor was left.
+    Error {
+        message: String,
+        cursor_hidden: bool,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum VisibilityEvent {
+    /// A hide is about to start
+    BeginHide,
+    /// The blank cursors were applied
+    Hid(HideSnapshot),
+    /// A restore is about to start
+    BeginRestore,
+    /// The restore started by `BeginRestore` finished
+    Restored,
+    /// A hide or restore failed
+    Failed(String),
+}
+
+impl CursorVisibility {
+    /// Whether the system cursor is currently blanked (or still being restored).
+    pub fn is_hidden(&self) -> bool {
+        match self {
+            Self::Visible | Self::Hiding => false,
+            Self::Hidden { .. } | Self::Restoring => true,
+            Self::Error { cursor_hidden, .. } => *cursor_hidden,
+        }
+    }
+
+    pub fn is_restoring(&self) -> bool {
+        matches!(self, Self::Restoring)
+    }
+
+    /// The state `event` would lead to, or why it is not allowed from here.
+    pub fn next(&self, event: VisibilityEvent) -> Result<Self, String> {
+        match (self, event) {
+            (Self::Restoring, VisibilityEvent::BeginHide | VisibilityEvent::Hid(_)) => {
+                Err("Cannot hide the cursor while it is being restored".to_string())
+            }
+            (Self::Hiding, VisibilityEvent::BeginHide) => {
+                Err("The cursor is already being hidden".to_string())
+            }
+            (Self::Hidden { .. }, VisibilityEvent::BeginHide | VisibilityEvent::Hid(_)) => {
+                Err("The cursor is already hidden".to_string())
+            }
+            (_, VisibilityEvent::BeginHide) => Ok(Self::Hiding),
+            (_, VisibilityEvent::Hid(snapshot)) => Ok(Self::Hidden { snapshot }),
+            (Self::Restoring, VisibilityEvent::BeginRestore) => {
+                Err("The cursor is already being restored".to_string())
+            }
+            (
+                Self::Hidden { .. }
+                | Self::Error {
+                    cursor_hidden: true,
+                    ..
+                },
+                VisibilityEvent::BeginRestore,
+            ) => Ok(Self::Restoring),
+            (Self::Hiding, VisibilityEvent::BeginRestore) => {
+                Err("Cannot restore the cursor while it is being hidden".to_string())
+            }
+            (_, VisibilityEvent::BeginRestore) => Err("The cursor is not hidden".to_string()),
+            (Self::Restoring, VisibilityEvent::Restored) => Ok(Self::Visible),
+            (_, VisibilityEvent::Restored) => Err("No cursor restore is in progress".to_string()),
+            (current, VisibilityEvent::Failed(message)) => Ok(Self::Error {
+                message,
+                cursor_hidden: current.is_hidden(),
+            }),
+        }
+    }
+
+    /// Move to the state `event` leads to. The state is left unchanged when it is rejected.
+    pub fn apply(&mut self, event: VisibilityEvent) -> Result<(), String> {
+        *self = self.next(event)?;
+        Ok(())
+    }
+
+    /// Mark the cursor visible after it was restored outside the hide/show commands, e.g. by
+    /// the shutdown hooks. Valid from every state.
+    pub fn reset(&mut self) {
+        *self = Self::Visible;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hidden() -> CursorVisibility {
+        CursorVisibility::Hidden {
+            snapshot: HideSnapshot {
+                blanked_roles: vec!["Normal".to_string()],
+            },
+        }
+    }
+
+    #[test]
+    fn hide_then_restore_round_trips() {
+        let mut visibility = CursorVisibility::default();
+        assert!(!visibility.is_hidden());
+
+        visibility
+            .apply(VisibilityEvent::Hid(HideSnapshot::default()))
+            .unwrap();
+        assert!(visibility.is_hidden());
+
+        visibility.apply(VisibilityEvent::BeginRestore).unwrap();
+        assert!(visibility.is_restoring());
+        assert!(visibility.is_hidden());
+
+        visibility.apply(VisibilityEvent::Restored).unwrap();
+        assert_eq!(visibility, CursorVisibility::Visible);
+    }
+
+    #[test]
+    fn a_second_restore_is_rejected_while_one_runs() {
+        let mut visibility = hidden();
+        visibility.apply(VisibilityEvent::BeginRestore).unwrap();
+
+        assert!(visibility.apply(VisibilityEvent::BeginRestore).is_err());
+        assert!(visibility
+            .apply(VisibilityEvent::Hid(HideSnapshot::default()))
+            .is_err());
+        assert!(visibility.is_restoring());
+
+        assert!(CursorVisibility::Visible
+            .next(VisibilityEvent::Restored)
+            .is_err());
+
+        // Restoring a cursor that is not hidden is a double restore
+        assert!(CursorVisibility::Visible
+            .next(VisibilityEvent::BeginRestore)
+            .is_err());
+        let failed_hide = CursorVisibility::Error {
+            message: "hide".to_string(),
+            cursor_hidden: false,
+        };
+        assert!(failed_hide.next(VisibilityEvent::BeginRestore).is_err());
+
+        // A second hide would replace the snapshot of the first
+        assert!(hidden().next(VisibilityEvent::BeginHide).is_err());
+        assert!(hidden()
+            .next(VisibilityEvent::Hid(HideSnapshot::default()))
+            .is_err());
+    }
+
+    #[test]
+    fn a_hide_blocks_another_hide_or_restore_while_it_runs() {
+        let mut visibility = CursorVisibility::Visible;
+        visibility.apply(VisibilityEvent::BeginHide).unwrap();
+        assert!(!visibility.is_hidden());
+        assert!(visibility.apply(VisibilityEvent::BeginHide).is_err());
+        assert!(visibility.apply(VisibilityEvent::BeginRestore).is_err());
+
+        visibility
+            .apply(VisibilityEvent::Hid(HideSnapshot::default()))
+            .unwrap();
+        assert!(visibility.is_hidden());
+
+        let failed = CursorVisibility::Hiding
+            .next(VisibilityEvent::Failed("hide".to_string()))
+            .unwrap();
+        assert!(!failed.is_hidden());
+    }
+
+    #[test]
+    fn failures_remember_which_way_the_cursor_was_left() {
+        let failed_hide = CursorVisibility::Visible
+            .next(VisibilityEvent::Failed("hide".to_string()))
+            .unwrap();
+        assert!(!failed_hide.is_hidden());
+
+        let failed_restore = CursorVisibility::Restoring
+            .next(VisibilityEvent::Failed("restore".to_string()))
+            .unwrap();
+        assert!(failed_restore.is_hidden());
+
+        // Retrying from an error is allowed in both directions
+        assert!(failed_restore.next(VisibilityEvent::BeginRestore).is_ok());
+        assert!(failed_hide
+            .next(VisibilityEvent::Hid(HideSnapshot::default()))
+            .is_ok());
+
+        let mut visibility = failed_restore;
+        visibility.reset();
+        assert_eq!(visibility, CursorVisibility::Visible);
+    }
+
+    #[test]
+    fn serializes_with_a_state_tag() {
+        let json = serde_json::to_value(hidden()).unwrap();
+        assert_eq!(json["state"], "hidden");
+        assert_eq!(json["snapshot"]["blanked_roles"][0], "Normal");
+        assert_eq!(
+            serde_json::to_string(&CursorVisibility::Restoring).unwrap(),
+            r#"{"state":"restoring"}"#
+        );
+    }
+}
@@ -0,0 +1,203 @@
+//! Status file for desktop widgets (Rainmeter skins, Windows widgets, scripts).
+//!
+//! When enabled, the current cursor state (hidden, active pack, size) is written to a
+//! user-chosen path after every `cursor-state` event, debounced so a burst of changes
+//! produces one write. A path ending in `.ini` gets an INI file (Rainmeter's WebParser and
+//! `[Variables]` sections read it directly); anything else gets JSON.
+
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+use tauri::{AppHandle, Listener, Manager, Runtime};
+
+use crate::commands::customization::library::load_library;
+use crate::state::settings_file::{self, SettingsFile};
+use crate::state::AppState;
+use crate::timers::{self, TimerPriority};
+
+pub const STATUS_FILE_SETTINGS_FILE_NAME: &str = "status-file.json";
+const DEBOUNCE: Duration = Duration::from_millis(250);
+
+static GENERATION: AtomicU64 = AtomicU64::new(0);
+
+#[derive(ts_rs::TS, Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct StatusFileSettings {
+    #[serde(default)]
+    pub enabled: bool,
+    /// Where the status is written; `.ini` selects INI output, anything else JSON
+    #[serde(default)]
+    pub path: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct StatusSnapshot {
+    pub hidden: bool,
+    pub active_pack: Option<String>,
+    pub cursor_size: i32,
+}
+
+impl SettingsFile for StatusFileSettings {
+    const FILE_NAME: &'static str = STATUS_FILE_SETTINGS_FILE_NAME;
+    const DESCRIPTION: &'static str = "status file settings";
+}
+
+pub fn load_settings<R: Runtime>(app: &AppHandle<R>) -> StatusFileSettings {
+    settings_file::load(app)
+}
+
+pub fn save_settings<R: Runtime>(
+    app: &AppHandle<R>,
+    settings: &StatusFileSettings,
+) -> Result<(), String> {
+    settings_file::save(app, settings)
+}
+
+/// Write the status file after every state change. Called once at startup.
+pub fn install<R: Runtime>(app: &AppHandle<R>) {
+    let app_handle = app.clone();
+    app.listen(crate::events::CURSOR_STATE, move |_event| {
+        schedule_write(&app_handle);
+    });
+    schedule_write(app);
+}
+
+fn schedule_write<R: Runtime>(app: &AppHandle<R>) {
+    let generation = GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+    let app = app.clone();
+    timers::after(DEBOUNCE, TimerPriority::Essential, move || {
+        if GENERATION.load(Ordering::SeqCst) == generation {
+            if let Err(e) = write_now(&app) {
+                cc_warn!("[CursorChanger] Failed to update status file: {}", e);
+            }
+        }
+    });
+}
+
+/// Write the status file immediately if it is enabled.
+pub fn write_now<R: Runtime>(app: &AppHandle<R>) -> Result<(), String> {
+    let settings = load_settings(app);
+    let Some(path) = settings.path.filter(|_| settings.enabled) else {
+        return Ok(());
+    };
+    let snapshot = current_snapshot(app)?;
+    write_status(Path::new(&path), &snapshot)
+}
+
+fn current_snapshot<R: Runtime>(app: &AppHandle<R>) -> Result<StatusSnapshot, String> {
+    let state = app.state::<AppState>();
+    let (hidden, cursor_size, cursor_paths) = {
+        let guard = state.read_all()?;
+        (
+            guard.cursor.visibility.is_hidden(),
+            guard.prefs.cursor_size,
+            guard.cursor.cursor_paths.clone(),
+        )
+    };
+
+    let active_pack = crate::paths::pack_mounts_dir()
+        .ok()
+        .and_then(|mounts| {
+            cursor_paths
+                .values()
+                .find_map(|path| mounted_pack_id(&mounts, Path::new(path)))
+        })
+        .and_then(|pack_id| {
+            load_library(app)
+                .ok()?
+                .cursors
+                .into_iter()
+                .find(|c| c.is_pack && c.id == pack_id)
+                .map(|c| c.name)
+        });
+
+    Ok(StatusSnapshot {
+        hidden,
+        active_pack,
+        cursor_size,
+    })
+}
+
+/// Pack id for a path under `pack-mounts/<pack id>/`.
+pub(crate) fn mounted_pack_id(mounts: &Path, path: &Path) -> Option<String> {
+    let relative = path.strip_prefix(mounts).ok()?;
+    let first = relative.components().next()?;
+    Some(first.as_os_str().to_string_lossy().into_owned())
+}
+
+fn render_ini(snapshot: &StatusSnapshot) -> String {
+    format!(
+        "[CursorChanger]\r\nHidden={}\r\nActivePack={}\r\nCursorSize={}\r\n",
+        u8::from(snapshot.hidden),
+        snapshot.active_pack.as_deref().unwrap_or(""),
+        snapshot.cursor_size
+    )
+}
+
+fn write_status(path: &Path, snapshot: &StatusSnapshot) -> Result<(), String> {
+    let is_ini = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|e| e.eq_ignore_ascii_case("ini"));
+    let contents = if is_ini {
+        render_ini(snapshot)
+    } else {
+        serde_json::to_string_pretty(snapshot)
+            .map_err(|e| format!("Failed to serialize status: {}", e))?
+    };
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create status file directory: {}", e))?;
+    }
+    // Widgets poll the file, so replace it in one step rather than truncating in place
+    let tmp_path = path.with_extension("tmp");
+    fs::write(&tmp_path, contents).map_err(|e| format!("Failed to write status file: {}", e))?;
+    fs::rename(&tmp_path, path).map_err(|e| format!("Failed to write status file: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot() -> StatusSnapshot {
+        StatusSnapshot {
+            hidden: true,
+            active_pack: Some("Neon".to_string()),
+            cursor_size: 48,
+        }
+    }
+
+    #[test]
+    fn writes_ini_or_json_by_extension() {
+        let temp = tempfile::tempdir().expect("tempdir");
+
+        let ini = temp.path().join("status.ini");
+        write_status(&ini, &snapshot()).expect("write ini");
+        assert_eq!(
+            fs::read_to_string(&ini).unwrap(),
+            "[CursorChanger]\r\nHidden=1\r\nActivePack=Neon\r\nCursorSize=48\r\n"
+        );
+
+        let json = temp.path().join("nested").join("status.json");
+        write_status(&json, &snapshot()).expect("write json");
+        let value: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&json).unwrap()).unwrap();
+        assert_eq!(value["hidden"], true);
+        assert_eq!(value["active_pack"], "Neon");
+        assert_eq!(value["cursor_size"], 48);
+        assert!(!json.with_extension("tmp").exists());
+    }
+
+    #[test]
+    fn pack_id_comes_from_mount_folder() {
+        let mounts = Path::new("C:\\lib\\pack-mounts");
+        assert_eq!(
+            mounted_pack_id(mounts, &mounts.join("pack-1").join("abc.cur")),
+            Some("pack-1".to_string())
+        );
+        assert_eq!(mounted_pack_id(mounts, Path::new("C:\\other\\a.cur")), None);
+    }
+}
@@ -0,0 +1,392 @@
+//! Where the cursor library keeps its files.
+//!
+//! Cursors, packs, pack mounts and preview caches live under
+//! [`library_root_dir`](crate::paths::library_root_dir), by default a folder inside the app
+//! folder on the system drive. [`relocate`] moves them to a folder the user picks: it copies
+//! every file over, points `library.json`, the Windows cursor registry values and the cursor
+//! state at the copies, saves the new location and only then deletes the old folder. A failure
+//! while copying removes the partial copies and leaves everything as it was.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+use tauri::{AppHandle, Manager, Runtime};
+
+use crate::operations::{Operation, OperationPhase};
+use crate::state::settings_file::{self, SettingsFile};
+use crate::state::AppState;
+
+pub const STORAGE_LOCATION_FILE_NAME: &str = "storage-location.json";
+
+/// Written and deleted again to check the chosen folder is writable
+const WRITE_PROBE_FILE_NAME: &str = ".cursor-changer-write-test";
+
+/// The chosen library folder, `None` for the default one
+static LIBRARY_DIR: RwLock<Option<PathBuf>> = RwLock::new(None);
+
+#[derive(ts_rs::TS, Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct StorageLocationSettings {
+    /// Folder holding the library files; the default folder inside the app folder when unset
+    #[serde(default)]
+    pub library_dir: Option<String>,
+}
+
+#[derive(ts_rs::TS, Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct StorageRelocationReport {
+    /// Folder the library files live in now
+    pub library_dir: String,
+    pub moved_files: u32,
+    /// Paths in `library.json` that were pointed at the new folder
+    pub library_paths_updated: u32,
+    /// Windows cursor registry values that were pointed at the new folder
+    pub registry_values_updated: u32,
+    /// Old folder that could not be deleted completely and can be removed by hand
+    pub leftover_dir: Option<String>,
+}
+
+impl SettingsFile for StorageLocationSettings {
+    const FILE_NAME: &'static str = STORAGE_LOCATION_FILE_NAME;
+    const DESCRIPTION: &'static str = "storage location";
+
+    fn map_paths(&mut self, map: fn(&str) -> String) {
+        self.library_dir = self.library_dir.as_deref().map(map);
+    }
+}
+
+pub fn load_settings<R: Runtime>(app: &AppHandle<R>) -> StorageLocationSettings {
+    settings_file::load(app)
+}
+
+fn save_settings<R: Runtime>(
+    app: &AppHandle<R>,
+    settings: &StorageLocationSettings,
+) -> Result<(), String> {
+    settings_file::save(app, settings)?;
+    set_library_dir(settings.library_dir.as_ref().map(PathBuf::from));
+    Ok(())
+}
+
+fn set_library_dir(dir: Option<PathBuf>) {
+    if let Ok(mut current) = LIBRARY_DIR.write() {
+        *current = dir;
+    }
+}
+
+/// Load the saved location. Called once at startup, before anything touches the library.
+pub fn install<R: Runtime>(app: &AppHandle<R>) {
+    set_library_dir(load_settings(app).library_dir.map(PathBuf::from));
+}
+
+/// The folder the user moved the library to, if they did.
+pub fn library_dir() -> Option<PathBuf> {
+    LIBRARY_DIR.read().ok()?.clone()
+}
+
+/// `path` at the same place under `to` that it had under `from`, or `None` when it lies
+/// outside `from`.
+fn rebased(path: &str, from: &Path, to: &Path) -> Option<String> {
+    if path.is_empty() || !crate::paths::is_within(Path::new(path), from) {
+        return None;
+    }
+    let relative = Path::new(path)
+        .strip_prefix(from)
+        .map(Path::to_path_buf)
+        .ok()
+        .or_else(|| {
+            // Same folder spelled differently (case, short names); both still exist here
+            let path = fs::canonicalize(path).ok()?;
+            let from = fs::canonicalize(from).ok()?;
+            path.strip_prefix(from).map(Path::to_path_buf).ok()
+        })?;
+    Some(to.join(relative).to_string_lossy().into_owned())
+}
+
+/// Point the values of `paths` that lie under `from` at `to`, returning how many changed.
+fn rebase_values<K>(paths: &mut HashMap<K, String>, from: &Path, to: &Path) -> usize {
+    let mut changed = 0;
+    for path in paths.values_mut() {
+        if let Some(moved) = rebased(path, from, to) {
+            *path = moved;
+            changed += 1;
+        }
+    }
+    changed
+}
+
+/// Create `dir` if needed and check it is empty and writable.
+fn prepare_target(dir: &Path) -> Result<(), String> {
+    fs::create_dir_all(dir).map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+    let mut entries =
+        fs::read_dir(dir).map_err(|e| format!("Failed to read {}: {}", dir.display(), e))?;
+    if entries.next().is_some() {
+        return Err(format!(
+            "Choose an empty folder; {} is not empty",
+            dir.display()
+        ));
+    }
+    let probe = dir.join(WRITE_PROBE_FILE_NAME);
+    fs::write(&probe, b"")
+        .and_then(|()| fs::remove_file(&probe))
+        .map_err(|e| format!("{} is not writable: {}", dir.display(), e))
+}
+
+/// Every file under `dir` with its path relative to `dir` and its size.
+fn collect_files(dir: &Path) -> Result<Vec<(PathBuf, u64)>, String> {
+    fn walk(root: &Path, dir: &Path, files: &mut Vec<(PathBuf, u64)>) -> std::io::Result<()> {
+        for entry in fs::read_dir(dir)? {
+            let entry = entry?;
+            let path = entry.path();
+            if entry.file_type()?.is_dir() {
+                walk(root, &path, files)?;
+            } else if let Ok(relative) = path.strip_prefix(root) {
+                files.push((relative.to_path_buf(), entry.metadata()?.len()));
+            }
+        }
+        Ok(())
+    }
+
+    let mut files = Vec::new();
+    walk(dir, dir, &mut files)
+        .map_err(|e| format!("Failed to list library files in {}: {}", dir.display(), e))?;
+    files.sort();
+    Ok(files)
+}
+
+/// Empty `dir` again after a relocation into it failed part way.
+fn clear_dir(dir: &Path) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let removed = if path.is_dir() {
+            fs::remove_dir_all(&path)
+        } else {
+            fs::remove_file(&path)
+        };
+        if let Err(e) = removed {
+            cc_warn!(
+                "[CursorChanger] Failed to remove {} after a failed library move: {}",
+                path.display(),
+                e
+            );
+        }
+    }
+}
+
+fn copy_files<R: Runtime>(
+    operation: &Operation<R>,
+    files: &[(PathBuf, u64)],
+    from: &Path,
+    to: &Path,
+) -> Result<(), String> {
+    let mut progress = operation.phase(OperationPhase::Moving, files.len());
+    for (relative, _) in files {
+        operation.checkpoint()?;
+        let target = to.join(relative);
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+        }
+        fs::copy(from.join(relative), &target)
+            .map_err(|e| format!("Failed to copy {}: {}", relative.display(), e))?;
+        progress.advance(Some(&relative.to_string_lossy()));
+    }
+    progress.finish();
+    Ok(())
+}
+
+/// Point the cursor paths in the app state, and the registry snapshot taken at startup, at
+/// the moved files.
+fn rebase_state<R: Runtime>(app: &AppHandle<R>, from: &Path, to: &Path) -> Result<(), String> {
+    let state = app.state::<AppState>();
+    crate::commands::command_helpers::update_state_and_emit(app, &state, false, |guard| {
+        rebase_values(&mut guard.cursor.cursor_paths, from, to);
+        rebase_values(&mut guard.modes.simple_mode_cursor_paths, from, to);
+        rebase_values(&mut guard.modes.advanced_mode_cursor_paths, from, to);
+        if let Some(snapshot) = guard.restoration.cursor_registry_snapshot.as_mut() {
+            for value in snapshot.values_mut().flatten() {
+                if let Some(moved) = rebased(value, from, to) {
+                    *value = moved;
+                }
+            }
+        }
+        Ok(())
+    })
+    .map(|_| ())
+}
+
+/// Point the Windows cursor registry values at the moved files and have Windows reload them.
+fn rebase_registry<R: Runtime>(app: &AppHandle<R>, from: &Path, to: &Path) -> usize {
+    let mut entries = cursor_changer::snapshot_cursor_registry_entries();
+    let mut changed = 0;
+    for value in entries.values_mut().flatten() {
+        if let Some(moved) = rebased(value, from, to) {
+            *value = moved;
+            changed += 1;
+        }
+    }
+    if changed == 0 {
+        return 0;
+    }
+    if !cursor_changer::restore_cursor_registry_entries(&entries) {
+        cc_warn!("[CursorChanger] Some cursor registry values still point at the old library");
+    }
+    // A hidden cursor picks the new paths up when it is restored
+    let hidden = app
+        .state::<AppState>()
+        .cursor
+        .read()
+        .map(|cursor| cursor.visibility.is_hidden())
+        .unwrap_or(false);
+    if !hidden && !crate::system::restore_system_cursors() {
+        cc_warn!("[CursorChanger] Failed to reload cursors after moving the library");
+    }
+    changed
+}
+
+/// Move the library files to `target`, which must be an empty (or new) writable folder
+/// outside the current library folder.
+pub fn relocate<R: Runtime>(
+    app: &AppHandle<R>,
+    operation: &Operation<R>,
+    target: &Path,
+) -> Result<StorageRelocationReport, String> {
+    if !target.is_absolute() {
+        return Err("Choose a full folder path for the library".to_string());
+    }
+    let current = crate::paths::library_root_dir()?;
+    if crate::paths::canonical_eq(target, &current) {
+        return Err(format!(
+            "The library is already stored in {}",
+            target.display()
+        ));
+    }
+    if crate::paths::is_within(target, &current) || crate::paths::is_within(&current, target) {
+        return Err(
+            "The new folder cannot be inside the current library folder or contain it".to_string(),
+        );
+    }
+
+    let files = collect_files(&current)?;
+    prepare_target(target)?;
+    let total_bytes = files.iter().map(|(_, size)| size).sum();
+    if let Err(e) = crate::disk_space::ensure_space(target, total_bytes)
+        .and_then(|()| copy_files(operation, &files, &current, target))
+    {
+        clear_dir(target);
+        return Err(e);
+    }
+
+    // From here on the copies are complete, so paths can be switched over one store at a time
+    let rebase = |path: &str| rebased(path, &current, target).unwrap_or_else(|| path.to_string());
+    let library_paths_updated =
+        crate::commands::customization::library::rewrite_library_paths(app, rebase)?;
+    let registry_values_updated = rebase_registry(app, &current, target);
+    rebase_state(app, &current, target)?;
+
+    let is_default = crate::paths::canonical_eq(target, &crate::paths::default_library_root_dir()?);
+    save_settings(
+        app,
+        &StorageLocationSettings {
+            library_dir: (!is_default).then(|| target.to_string_lossy().into_owned()),
+        },
+    )?;
+
+    let leftover_dir = match fs::remove_dir_all(&current) {
+        Ok(()) => None,
+        Err(e) => {
+            cc_warn!(
+                "[CursorChanger] Failed to delete the old library folder {}: {}",
+                current.display(),
+                e
+            );
+            Some(current.to_string_lossy().into_owned())
+        }
+    };
+    cc_info!(
+        "[CursorChanger] Moved {} library files from {} to {}",
+        files.len(),
+        current.display(),
+        target.display()
+    );
+
+    Ok(StorageRelocationReport {
+        library_dir: target.to_string_lossy().into_owned(),
+        moved_files: u32::try_from(files.len()).unwrap_or(u32::MAX),
+        library_paths_updated: u32::try_from(library_paths_updated).unwrap_or(u32::MAX),
+        registry_values_updated: u32::try_from(registry_values_updated).unwrap_or(u32::MAX),
+        leftover_dir,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rebases_only_paths_inside_the_old_folder() {
+        let temp = tempfile::tempdir().unwrap();
+        let old = temp.path().join("library");
+        let new = temp.path().join("elsewhere");
+        let inside = old.join("cursors").join("arrow.cur");
+        let outside = temp.path().join("library-other").join("arrow.cur");
+
+        assert_eq!(
+            rebased(&inside.to_string_lossy(), &old, &new),
+            Some(
+                new.join("cursors")
+                    .join("arrow.cur")
+                    .to_string_lossy()
+                    .into_owned()
+            )
+        );
+        assert_eq!(rebased(&outside.to_string_lossy(), &old, &new), None);
+        assert_eq!(rebased("", &old, &new), None);
+
+        let mut paths = HashMap::from([
+            ("Normal", inside.to_string_lossy().into_owned()),
+            ("Hand", outside.to_string_lossy().into_owned()),
+        ]);
+        assert_eq!(rebase_values(&mut paths, &old, &new), 1);
+        assert!(paths["Normal"].starts_with(&*new.to_string_lossy()));
+        assert_eq!(paths["Hand"], outside.to_string_lossy());
+    }
+
+    #[test]
+    fn target_must_be_empty() {
+        let temp = tempfile::tempdir().unwrap();
+        let target = temp.path().join("new").join("library");
+        prepare_target(&target).unwrap();
+        assert!(target.is_dir());
+        assert_eq!(fs::read_dir(&target).unwrap().count(), 0);
+
+        fs::write(target.join("keep.txt"), b"x").unwrap();
+        assert!(prepare_target(&target).is_err());
+    }
+
+    #[test]
+    fn lists_nested_files_relative_to_the_folder() {
+        let temp = tempfile::tempdir().unwrap();
+        fs::create_dir_all(temp.path().join("pack-mounts").join("neon")).unwrap();
+        fs::write(
+            temp.path().join("pack-mounts").join("neon").join("a.cur"),
+            b"ab",
+        )
+        .unwrap();
+        fs::write(temp.path().join("b.cur"), b"b").unwrap();
+
+        let files = collect_files(temp.path()).unwrap();
+        assert_eq!(
+            files,
+            vec![
+                (PathBuf::from("b.cur"), 1),
+                (Path::new("pack-mounts").join("neon").join("a.cur"), 2),
+            ]
+        );
+    }
+}
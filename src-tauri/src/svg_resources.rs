@@ -0,0 +1,58 @@
+//! SVG resource policy for conversions and previews.
+//!
+//! SVG text always renders with the fonts bundled in cursor-convert; `load_system_fonts` in
+//! `svg-resources.json` also lets installed fonts match. External images are never loaded, and
+//! each file that referenced some is logged with the stripped `href`s.
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Runtime};
+
+use crate::cursor_converter::svg_handler;
+use crate::cursor_converter::SvgResourcePolicy;
+use crate::state::settings_file::{self, SettingsFile};
+
+pub const SVG_RESOURCES_FILE_NAME: &str = "svg-resources.json";
+
+#[derive(ts_rs::TS, Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct SvgResourceSettings {
+    /// Let SVG text use fonts installed on this PC; output may then differ between machines
+    #[serde(default)]
+    pub load_system_fonts: bool,
+}
+
+impl SettingsFile for SvgResourceSettings {
+    const FILE_NAME: &'static str = SVG_RESOURCES_FILE_NAME;
+    const DESCRIPTION: &'static str = "SVG resource settings";
+}
+
+pub fn load_settings<R: Runtime>(app: &AppHandle<R>) -> SvgResourceSettings {
+    settings_file::load(app)
+}
+
+pub fn save_settings<R: Runtime>(
+    app: &AppHandle<R>,
+    settings: &SvgResourceSettings,
+) -> Result<(), String> {
+    settings_file::save(app, settings)
+}
+
+fn warn_stripped(resources: &[String]) {
+    cc_warn!(
+        "[CursorChanger] SVG references external resources that were not loaded: {}",
+        resources.join(", ")
+    );
+}
+
+/// Install the policy for `settings` in cursor-convert.
+pub fn apply(settings: &SvgResourceSettings) {
+    svg_handler::set_resource_policy(SvgResourcePolicy {
+        load_system_fonts: settings.load_system_fonts,
+        on_stripped: Some(warn_stripped),
+    });
+}
+
+/// Load the saved settings and install them. Called once at startup.
+pub fn init<R: Runtime>(app: &AppHandle<R>) {
+    apply(&load_settings(app));
+}
@@ -74,7 +74,7 @@ fn apply_single_mock(
     guard.as_mut().map(|f| f(path, id, size))
 }
 
-pub fn apply_blank_system_cursors() -> bool {
+pub fn apply_blank_system_cursors(roles: cursor_changer::BlankRoleMask) -> bool {
     #[cfg(test)]
     {
         if let Some(result) = apply_mock(&APPLY_CURSOR_MOCK) {
@@ -82,18 +82,60 @@ pub fn apply_blank_system_cursors() -> bool {
         }
     }
 
-    unsafe { cursor_changer::apply_blank_system_cursors() }
+    unsafe { cursor_changer::apply_blank_system_cursors(roles) }
 }
 
 pub fn restore_system_cursors() -> bool {
+    reload_system_cursors().is_ok()
+}
+
+/// Reload the cursor scheme from the registry without letting a hung `SPI_SETCURSORS` freeze
+/// the calling thread. When the normal reload times out, the quiet one, which does not wait
+/// on other windows, is tried instead.
+pub fn reload_system_cursors() -> Result<(), cursor_changer::RefreshError> {
+    use cursor_changer::{RefreshStrategy, REFRESH_TIMEOUT};
+
     #[cfg(test)]
     {
         if let Some(result) = apply_mock(&RESTORE_CURSOR_MOCK) {
-            return result;
+            let label = RefreshStrategy::Broadcast.label();
+            return if result {
+                Ok(())
+            } else {
+                Err(cursor_changer::RefreshError::Failed { label })
+            };
+        }
+    }
+
+    let reload =
+        |strategy| unsafe { cursor_changer::reload_system_cursors(strategy, REFRESH_TIMEOUT) };
+    match reload(RefreshStrategy::Broadcast) {
+        Err(e) if e.is_timeout() => {
+            cc_warn!(
+                "[CursorChanger] {}; reloading without waiting on other windows",
+                e
+            );
+            reload(RefreshStrategy::Quiet)
         }
+        result => result,
     }
+}
+
+pub fn broadcast_cursor_setting_change() -> bool {
+    unsafe { cursor_changer::broadcast_cursor_setting_change() }
+}
+
+pub fn live_cursor_hash(cursor_id: u32) -> Option<u64> {
+    unsafe { cursor_changer::live_cursor_hash(cursor_id) }
+}
+
+pub fn cursor_file_hash(path: &str, size: i32) -> Option<u64> {
+    unsafe { cursor_changer::cursor_file_hash(path, size) }
+}
 
-    unsafe { cursor_changer::restore_system_cursors() }
+/// True when Windows is set to reduce motion ("Animation effects" turned off).
+pub fn system_reduce_motion() -> bool {
+    unsafe { cursor_changer::client_area_animation_enabled() }.is_some_and(|enabled| !enabled)
 }
 
 pub fn apply_cursor_file_with_size(path: &str, size: i32) -> bool {
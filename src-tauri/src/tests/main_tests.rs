@@ -25,6 +25,8 @@ mod tests {
             theme_mode: None,
             default_cursor_style: None,
             customization_mode: None,
+            window_geometry: None,
+            last_page: None,
         };
 
         let normalized = normalize_persisted_config(cfg);
@@ -50,6 +52,8 @@ mod tests {
             theme_mode: None,
             default_cursor_style: None,
             customization_mode: None,
+            window_geometry: None,
+            last_page: None,
         };
 
         let s = serde_json::to_string(&cfg).expect("serialize");
@@ -60,11 +64,14 @@ mod tests {
 
     #[test]
     fn cursor_state_payload_from_app_state() {
-        use crate::state::CursorStatePayload;
+        use crate::state::{CursorStatePayload, HideSnapshot, VisibilityEvent};
         let state = AppState::default();
         {
             let mut cursor = state.cursor.write().unwrap();
-            cursor.hidden = true;
+            cursor
+                .visibility
+                .apply(VisibilityEvent::Hid(HideSnapshot::default()))
+                .unwrap();
         }
         {
             let mut prefs = state.prefs.write().unwrap();
@@ -106,6 +113,8 @@ mod tests {
             theme_mode: None,
             default_cursor_style: None,
             customization_mode: None,
+            window_geometry: None,
+            last_page: None,
         };
 
         let result = write_config(&dir, &cfg);
@@ -124,7 +133,7 @@ mod tests {
     #[test]
     fn test_app_state_default() {
         let state = AppState::default();
-        assert_eq!(state.cursor.read().unwrap().hidden, false);
+        assert_eq!(state.cursor.read().unwrap().visibility.is_hidden(), false);
         assert_eq!(
             state.prefs.read().unwrap().shortcut,
             Some(DEFAULT_SHORTCUT.to_string())
@@ -160,6 +169,8 @@ mod tests {
             theme_mode: None,
             default_cursor_style: None,
             customization_mode: None,
+            window_geometry: None,
+            last_page: None,
         };
 
         write_config(&dir, &config1).expect("first write");
@@ -177,6 +188,8 @@ mod tests {
             theme_mode: None,
             default_cursor_style: None,
             customization_mode: None,
+            window_geometry: None,
+            last_page: None,
         };
 
         write_config(&dir, &config2).expect("second write");
@@ -250,6 +263,8 @@ mod tests {
             theme_mode: None,
             default_cursor_style: None,
             customization_mode: None,
+            window_geometry: None,
+            last_page: None,
         };
 
         let normalized = normalize_persisted_config(old_config);
@@ -312,6 +327,8 @@ mod tests {
                 theme_mode: None,
                 default_cursor_style: None,
                 customization_mode: None,
+                window_geometry: None,
+                last_page: None,
             };
 
             write_config(&dir, &config).expect("write");
@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod tests {
     use crate::commands::shutdown::restore_state;
-    use crate::state::AppState;
+    use crate::state::{AppState, HideSnapshot, VisibilityEvent};
     use crate::system::{set_apply_cursor_from_file_with_size_mock_guard, set_restore_mock_guard};
 
     #[test]
@@ -14,7 +14,10 @@ mod tests {
             let state = AppState::default();
             {
                 let mut cursor = state.cursor.write().unwrap();
-                cursor.hidden = true;
+                cursor
+                    .visibility
+                    .apply(VisibilityEvent::Hid(HideSnapshot::default()))
+                    .unwrap();
                 cursor
                     .cursor_paths
                     .insert("Normal".into(), "some_path.cur".into());
@@ -24,7 +27,7 @@ mod tests {
             let restored = restore_state(&state);
             assert!(restored);
             let cursor = state.cursor.read().unwrap();
-            assert!(!cursor.hidden);
+            assert!(!cursor.visibility.is_hidden());
             assert!(cursor.cursor_paths.is_empty());
             assert!(cursor.last_loaded_cursor_path.is_none());
         }
@@ -35,7 +38,7 @@ mod tests {
             let state = AppState::default();
             {
                 let mut cursor = state.cursor.write().unwrap();
-                cursor.hidden = false;
+                cursor.visibility.reset();
             }
 
             let restored = restore_state(&state);
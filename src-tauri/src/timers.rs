@@ -0,0 +1,296 @@
+//! Shared timer service for debounces, timeouts and periodic background jobs.
+//!
+//! Rather than each parking its own thread in `sleep`, timers queue here: one thread sleeps
+//! until the earliest deadline, and deferrable work due soon after rides along with that
+//! wakeup instead of waking the CPU again. While Windows battery saver is on, deferrable work
+//! (housekeeping such as cache collection) is held back and runs once it is turned off.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc, Condvar, Mutex, Once};
+use std::time::{Duration, Instant};
+
+/// Deferrable timers due within this long of a wakeup run with it.
+const COALESCE_WINDOW: Duration = Duration::from_secs(30);
+/// How long deferrable work waits before the power status is checked again.
+const POWER_SAVER_RECHECK: Duration = Duration::from_secs(5 * 60);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimerPriority {
+    /// Runs on time whatever the power state: debounces and user-visible timeouts
+    Essential,
+    /// May run early to share a wakeup, and waits while battery saver is on
+    Deferrable,
+}
+
+struct Timer {
+    due: Instant,
+    priority: TimerPriority,
+    /// Repeat interval, `None` for one-shot timers
+    interval: Option<Duration>,
+    /// Returns whether a repeating timer should run again
+    task: Box<dyn FnMut() -> bool + Send>,
+}
+
+static QUEUE: Mutex<Vec<Timer>> = Mutex::new(Vec::new());
+static WAKE: Condvar = Condvar::new();
+static START: Once = Once::new();
+
+fn schedule(timer: Timer) {
+    START.call_once(|| {
+        if let Err(e) = std::thread::Builder::new()
+            .name("timers".to_string())
+            .spawn(run_loop)
+        {
+            cc_error!("[Timers] Failed to start timer thread: {}", e);
+        }
+    });
+    match QUEUE.lock() {
+        Ok(mut queue) => {
+            queue.push(timer);
+            WAKE.notify_one();
+        }
+        Err(_) => cc_error!("[Timers] Timer queue poisoned"),
+    }
+}
+
+/// Run `task` once after `delay`.
+pub fn after(delay: Duration, priority: TimerPriority, task: impl FnOnce() + Send + 'static) {
+    let mut task = Some(task);
+    schedule(Timer {
+        due: Instant::now() + delay,
+        priority,
+        interval: None,
+        task: Box::new(move || {
+            if let Some(task) = task.take() {
+                task();
+            }
+            false
+        }),
+    });
+}
+
+/// Run `task` after `first`, then every `interval` after each run.
+pub fn every(
+    first: Duration,
+    interval: Duration,
+    priority: TimerPriority,
+    mut task: impl FnMut() + Send + 'static,
+) {
+    every_while(first, interval, priority, move || {
+        task();
+        true
+    });
+}
+
+/// Like [`every`], but stops repeating once `task` returns `false`.
+pub fn every_while(
+    first: Duration,
+    interval: Duration,
+    priority: TimerPriority,
+    task: impl FnMut() -> bool + Send + 'static,
+) {
+    schedule(Timer {
+        due: Instant::now() + first,
+        priority,
+        interval: Some(interval),
+        task: Box::new(task),
+    });
+}
+
+fn battery_saver_on() -> bool {
+    cursor_changer::power_status().is_some_and(|status| status.battery_saver)
+}
+
+/// Remove and return the timers to run at `now`, earliest first. Nothing is taken unless a
+/// timer is actually due; deferrable timers due within `COALESCE_WINDOW` then join in. While
+/// `power_saving`, due deferrable timers are pushed back instead.
+fn take_due(queue: &mut Vec<Timer>, now: Instant, power_saving: bool) -> Vec<Timer> {
+    let deferrable = |t: &Timer| t.priority == TimerPriority::Deferrable;
+    if power_saving {
+        for timer in queue.iter_mut().filter(|t| deferrable(t) && t.due <= now) {
+            timer.due = now + POWER_SAVER_RECHECK;
+        }
+    }
+    if !queue.iter().any(|t| t.due <= now) {
+        return Vec::new();
+    }
+
+    let horizon = now + COALESCE_WINDOW;
+    let mut batch = Vec::new();
+    let mut i = 0;
+    while i < queue.len() {
+        let timer = &queue[i];
+        if timer.due <= now || (!power_saving && deferrable(timer) && timer.due <= horizon) {
+            batch.push(queue.swap_remove(i));
+        } else {
+            i += 1;
+        }
+    }
+    batch.sort_by_key(|t| t.due);
+    batch
+}
+
+/// Runs due batches on one long-lived thread, so frequent timers do not start a thread per
+/// wakeup.
+struct Runner {
+    busy: Arc<AtomicBool>,
+    batches: Option<mpsc::Sender<(Vec<Timer>, Instant)>>,
+}
+
+impl Runner {
+    fn start() -> Self {
+        let busy = Arc::new(AtomicBool::new(false));
+        let (batches, received) = mpsc::channel::<(Vec<Timer>, Instant)>();
+        let worker_busy = Arc::clone(&busy);
+        let spawned = std::thread::Builder::new()
+            .name("timer-tasks".to_string())
+            .spawn(move || {
+                for (batch, started) in received {
+                    run_batch(batch, started);
+                    worker_busy.store(false, Ordering::SeqCst);
+                }
+            });
+        if let Err(e) = &spawned {
+            cc_error!("[Timers] Failed to start timer task thread: {}", e);
+        }
+        Self {
+            busy,
+            batches: spawned.ok().map(|_| batches),
+        }
+    }
+
+    fn run(&self, batch: Vec<Timer>, started: Instant) {
+        let batch = match &self.batches {
+            Some(batches) if !self.busy.swap(true, Ordering::SeqCst) => {
+                match batches.send((batch, started)) {
+                    Ok(()) => return,
+                    Err(mpsc::SendError((batch, _))) => batch,
+                }
+            }
+            _ => batch,
+        };
+        // A slow task (re-applying a cursor pack) must not hold up the schedule, so work due
+        // while the runner is busy gets a thread of its own
+        std::thread::spawn(move || run_batch(batch, started));
+    }
+}
+
+fn run_loop() {
+    let runner = Runner::start();
+    let Ok(mut queue) = QUEUE.lock() else {
+        return;
+    };
+    loop {
+        let now = Instant::now();
+        queue = match queue.iter().map(|t| t.due).min() {
+            None => match WAKE.wait(queue) {
+                Ok(queue) => queue,
+                Err(_) => return,
+            },
+            Some(due) if due > now => match WAKE.wait_timeout(queue, due - now) {
+                Ok((queue, _)) => queue,
+                Err(_) => return,
+            },
+            Some(_) => {
+                let horizon = now + COALESCE_WINDOW;
+                let power_saving = queue
+                    .iter()
+                    .any(|t| t.priority == TimerPriority::Deferrable && t.due <= horizon)
+                    && battery_saver_on();
+                let batch = take_due(&mut queue, now, power_saving);
+                if !batch.is_empty() {
+                    runner.run(batch, now);
+                }
+                queue
+            }
+        };
+    }
+}
+
+fn run_batch(batch: Vec<Timer>, started: Instant) {
+    for mut timer in batch {
+        let again = (timer.task)();
+        if let Some(interval) = timer.interval.filter(|_| again) {
+            timer.due = started + interval;
+            schedule(timer);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timer(due: Instant, priority: TimerPriority) -> Timer {
+        Timer {
+            due,
+            priority,
+            interval: None,
+            task: Box::new(|| false),
+        }
+    }
+
+    #[test]
+    fn deferrable_timers_share_a_due_wakeup() {
+        let now = Instant::now();
+        let mut queue = vec![
+            timer(now + Duration::from_secs(10), TimerPriority::Deferrable),
+            timer(now + Duration::from_secs(10), TimerPriority::Essential),
+            timer(now, TimerPriority::Essential),
+            timer(now + COALESCE_WINDOW * 2, TimerPriority::Deferrable),
+        ];
+
+        let batch = take_due(&mut queue, now, false);
+
+        assert_eq!(batch.len(), 2);
+        assert_eq!(batch[0].priority, TimerPriority::Essential);
+        assert_eq!(batch[1].priority, TimerPriority::Deferrable);
+        assert_eq!(queue.len(), 2);
+    }
+
+    #[test]
+    fn nothing_runs_early_without_a_due_timer() {
+        let now = Instant::now();
+        let mut queue = vec![timer(
+            now + Duration::from_secs(5),
+            TimerPriority::Deferrable,
+        )];
+
+        assert!(take_due(&mut queue, now, false).is_empty());
+        assert_eq!(queue.len(), 1);
+    }
+
+    #[test]
+    fn repeating_timer_stops_once_its_task_declines() {
+        let (sender, runs) = mpsc::channel();
+        let mut count = 0;
+        every_while(
+            Duration::ZERO,
+            Duration::from_millis(1),
+            TimerPriority::Essential,
+            move || {
+                count += 1;
+                sender.send(count).is_ok() && count < 3
+            },
+        );
+
+        // The sender is dropped with the timer, which ends the iteration
+        assert_eq!(runs.iter().collect::<Vec<_>>(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn battery_saver_postpones_deferrable_timers() {
+        let now = Instant::now();
+        let mut queue = vec![
+            timer(now, TimerPriority::Deferrable),
+            timer(now, TimerPriority::Essential),
+        ];
+
+        let batch = take_due(&mut queue, now, true);
+
+        assert_eq!(batch.len(), 1);
+        assert_eq!(batch[0].priority, TimerPriority::Essential);
+        assert_eq!(queue.len(), 1);
+        assert_eq!(queue[0].due, now + POWER_SAVER_RECHECK);
+    }
+}
@@ -0,0 +1,380 @@
+//! Built-in "Touch & Pen" cursor set.
+//!
+//! Cursors for tablets and pen displays, where the pointer is read at a glance rather than
+//! aimed with: extra large, white with a thick black outline, chunky shapes without
+//! precision details, and a tall IBeam. They are drawn here from simple shapes rather than
+//! bundled, and written once to the variants directory as `.cur` files.
+
+use std::fs;
+use std::path::PathBuf;
+
+use image::{Rgba, RgbaImage};
+
+const TOUCH_CURSORS_DIR: &str = "touch";
+/// Bump when the shapes change so cached files are redrawn.
+const GLYPH_VERSION: u32 = 1;
+pub const TOUCH_CURSOR_SIZE: u32 = 128;
+/// Outline width as a fraction of the cursor size.
+const OUTLINE: f32 = 0.035;
+/// Samples per pixel along each axis, for anti-aliased edges.
+const SUPERSAMPLE: u32 = 4;
+
+/// Classic arrow with its tip at the top left, drawn broad so it reads at a glance.
+const ARROW: [(f32, f32); 7] = [
+    (0.06, 0.04),
+    (0.06, 0.72),
+    (0.22, 0.58),
+    (0.33, 0.84),
+    (0.45, 0.79),
+    (0.34, 0.54),
+    (0.54, 0.54),
+];
+/// Double-headed arrow along the x axis, centred on the origin.
+const DOUBLE_ARROW: [(f32, f32); 10] = [
+    (-0.42, 0.0),
+    (-0.25, -0.17),
+    (-0.25, -0.06),
+    (0.25, -0.06),
+    (0.25, -0.17),
+    (0.42, 0.0),
+    (0.25, 0.17),
+    (0.25, 0.06),
+    (-0.25, 0.06),
+    (-0.25, 0.17),
+];
+/// Where status badges (busy, help, link) sit next to the arrow.
+const BADGE_CENTRE: (f32, f32) = (0.76, 0.74);
+
+/// One filled shape in unit coordinates (0..1, y down).
+enum Part {
+    Polygon(Vec<(f32, f32)>),
+    Disc {
+        centre: (f32, f32),
+        radius: f32,
+    },
+    Ring {
+        centre: (f32, f32),
+        radius: f32,
+        width: f32,
+    },
+    /// Line with rounded ends
+    Bar {
+        from: (f32, f32),
+        to: (f32, f32),
+        width: f32,
+    },
+}
+
+fn distance_to_segment(p: (f32, f32), a: (f32, f32), b: (f32, f32)) -> f32 {
+    let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+    let length_sq = dx * dx + dy * dy;
+    let t = if length_sq > 0.0 {
+        (((p.0 - a.0) * dx + (p.1 - a.1) * dy) / length_sq).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    (p.0 - a.0 - t * dx).hypot(p.1 - a.1 - t * dy)
+}
+
+impl Part {
+    /// Distance from `p` to the edge, negative inside.
+    fn signed_distance(&self, p: (f32, f32)) -> f32 {
+        match self {
+            Part::Polygon(points) => {
+                let mut inside = false;
+                let mut distance = f32::MAX;
+                for (i, &a) in points.iter().enumerate() {
+                    let b = points[(i + 1) % points.len()];
+                    distance = distance.min(distance_to_segment(p, a, b));
+                    if (a.1 > p.1) != (b.1 > p.1)
+                        && p.0 < a.0 + (p.1 - a.1) * (b.0 - a.0) / (b.1 - a.1)
+                    {
+                        inside = !inside;
+                    }
+                }
+                if inside {
+                    -distance
+                } else {
+                    distance
+                }
+            }
+            Part::Disc { centre, radius } => (p.0 - centre.0).hypot(p.1 - centre.1) - radius,
+            Part::Ring {
+                centre,
+                radius,
+                width,
+            } => ((p.0 - centre.0).hypot(p.1 - centre.1) - radius).abs() - width / 2.0,
+            Part::Bar { from, to, width } => distance_to_segment(p, *from, *to) - width / 2.0,
+        }
+    }
+}
+
+struct Glyph {
+    parts: Vec<Part>,
+    hotspot: (f32, f32),
+}
+
+impl Glyph {
+    fn signed_distance(&self, p: (f32, f32)) -> f32 {
+        self.parts
+            .iter()
+            .map(|part| part.signed_distance(p))
+            .fold(f32::MAX, f32::min)
+    }
+}
+
+/// `DOUBLE_ARROW` rotated by `degrees` (clockwise, y down) about the centre of the canvas.
+fn double_arrow(degrees: f32) -> Part {
+    let (sin, cos) = degrees.to_radians().sin_cos();
+    Part::Polygon(
+        DOUBLE_ARROW
+            .iter()
+            .map(|&(x, y)| (0.5 + x * cos - y * sin, 0.5 + x * sin + y * cos))
+            .collect(),
+    )
+}
+
+fn arrow_with_badge(badge: Vec<Part>) -> Glyph {
+    let mut parts = vec![Part::Polygon(ARROW.to_vec())];
+    parts.extend(badge);
+    Glyph {
+        parts,
+        hotspot: ARROW[0],
+    }
+}
+
+fn centred(parts: Vec<Part>) -> Glyph {
+    Glyph {
+        parts,
+        hotspot: (0.5, 0.5),
+    }
+}
+
+/// The shape for `cursor_name`. Roles without their own shape use the arrow.
+fn glyph(cursor_name: &str) -> Glyph {
+    match cursor_name {
+        "IBeam" => centred(vec![
+            Part::Bar {
+                from: (0.5, 0.14),
+                to: (0.5, 0.86),
+                width: 0.09,
+            },
+            Part::Bar {
+                from: (0.34, 0.12),
+                to: (0.66, 0.12),
+                width: 0.08,
+            },
+            Part::Bar {
+                from: (0.34, 0.88),
+                to: (0.66, 0.88),
+                width: 0.08,
+            },
+        ]),
+        "Cross" => centred(vec![
+            Part::Bar {
+                from: (0.5, 0.14),
+                to: (0.5, 0.86),
+                width: 0.09,
+            },
+            Part::Bar {
+                from: (0.14, 0.5),
+                to: (0.86, 0.5),
+                width: 0.09,
+            },
+        ]),
+        // The dot marks the hotspot in the middle of the ring
+        "Wait" => centred(vec![
+            Part::Ring {
+                centre: (0.5, 0.5),
+                radius: 0.3,
+                width: 0.11,
+            },
+            Part::Disc {
+                centre: (0.5, 0.5),
+                radius: 0.05,
+            },
+        ]),
+        "No" => centred(vec![
+            Part::Ring {
+                centre: (0.5, 0.5),
+                radius: 0.32,
+                width: 0.1,
+            },
+            Part::Bar {
+                from: (0.29, 0.29),
+                to: (0.71, 0.71),
+                width: 0.1,
+            },
+        ]),
+        "SizeWE" => centred(vec![double_arrow(0.0)]),
+        "SizeNS" => centred(vec![double_arrow(90.0)]),
+        "SizeNWSE" => centred(vec![double_arrow(45.0)]),
+        "SizeNESW" => centred(vec![double_arrow(-45.0)]),
+        "SizeAll" => centred(vec![double_arrow(0.0), double_arrow(90.0)]),
+        "Up" => Glyph {
+            parts: vec![Part::Polygon(vec![
+                (0.5, 0.08),
+                (0.8, 0.42),
+                (0.6, 0.42),
+                (0.6, 0.9),
+                (0.4, 0.9),
+                (0.4, 0.42),
+                (0.2, 0.42),
+            ])],
+            hotspot: (0.5, 0.08),
+        },
+        "Pen" => Glyph {
+            parts: vec![
+                Part::Bar {
+                    from: (0.26, 0.74),
+                    to: (0.8, 0.2),
+                    width: 0.16,
+                },
+                Part::Polygon(vec![(0.06, 0.94), (0.14, 0.7), (0.3, 0.86)]),
+            ],
+            hotspot: (0.06, 0.94),
+        },
+        "AppStarting" => arrow_with_badge(vec![Part::Ring {
+            centre: BADGE_CENTRE,
+            radius: 0.14,
+            width: 0.07,
+        }]),
+        "Help" => arrow_with_badge(vec![
+            Part::Ring {
+                centre: BADGE_CENTRE,
+                radius: 0.14,
+                width: 0.06,
+            },
+            Part::Disc {
+                centre: BADGE_CENTRE,
+                radius: 0.04,
+            },
+        ]),
+        "Hand" => arrow_with_badge(vec![Part::Disc {
+            centre: BADGE_CENTRE,
+            radius: 0.15,
+        }]),
+        _ => arrow_with_badge(Vec::new()),
+    }
+}
+
+/// White shape with a black outline, transparent elsewhere.
+fn render(glyph: &Glyph, size: u32) -> RgbaImage {
+    let samples = SUPERSAMPLE * SUPERSAMPLE;
+    let step = 1.0 / (size * SUPERSAMPLE) as f32;
+    RgbaImage::from_fn(size, size, |x, y| {
+        let (mut fill, mut outline) = (0u32, 0u32);
+        for sy in 0..SUPERSAMPLE {
+            for sx in 0..SUPERSAMPLE {
+                let p = (
+                    ((x * SUPERSAMPLE + sx) as f32 + 0.5) * step,
+                    ((y * SUPERSAMPLE + sy) as f32 + 0.5) * step,
+                );
+                let distance = glyph.signed_distance(p);
+                if distance <= 0.0 {
+                    fill += 1;
+                } else if distance <= OUTLINE {
+                    outline += 1;
+                }
+            }
+        }
+        let covered = fill + outline;
+        if covered == 0 {
+            return Rgba([0, 0, 0, 0]);
+        }
+        let value = (255 * fill / covered) as u8;
+        Rgba([value, value, value, (255 * covered / samples) as u8])
+    })
+}
+
+fn hotspot_pixel(hotspot: (f32, f32), size: u32) -> (u16, u16) {
+    let pixel = |v: f32| ((v * size as f32) as u32).min(size - 1) as u16;
+    (pixel(hotspot.0), pixel(hotspot.1))
+}
+
+/// Touch cursor for `cursor_name`, drawn and cached on first use. `None` for unknown roles
+/// or when the file can't be written.
+pub fn touch_cursor_file(cursor_name: &str) -> Option<PathBuf> {
+    if !cursor_changer::CURSOR_TYPES
+        .iter()
+        .any(|cursor_type| cursor_type.name == cursor_name)
+    {
+        return None;
+    }
+    let out_path = crate::paths::cursor_variants_dir()
+        .ok()?
+        .join(TOUCH_CURSORS_DIR)
+        .join(format!("{}_v{}.cur", cursor_name, GLYPH_VERSION));
+    if out_path.exists() {
+        return Some(out_path);
+    }
+
+    let glyph = glyph(cursor_name);
+    let image = render(&glyph, TOUCH_CURSOR_SIZE);
+    let (hotspot_x, hotspot_y) = hotspot_pixel(glyph.hotspot, TOUCH_CURSOR_SIZE);
+    let data = crate::cursor_converter::generate_cur_data(&image, hotspot_x, hotspot_y)
+        .map_err(|e| {
+            cc_warn!(
+                "[CursorChanger] Failed to build touch {} cursor: {}",
+                cursor_name,
+                e
+            )
+        })
+        .ok()?;
+    if let Some(parent) = out_path.parent() {
+        fs::create_dir_all(parent).ok()?;
+    }
+    fs::write(&out_path, data).ok()?;
+    Some(out_path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn alpha(image: &RgbaImage, x: u32, y: u32) -> u8 {
+        image.get_pixel(x, y).0[3]
+    }
+
+    #[test]
+    fn arrow_is_white_inside_a_black_outline() {
+        let image = render(&glyph("Normal"), TOUCH_CURSOR_SIZE);
+        // Well inside the arrow body
+        assert_eq!(image.get_pixel(20, 40).0, [255, 255, 255, 255]);
+        // Just left of the left edge, inside the outline
+        let edge = image.get_pixel(5, 40).0;
+        assert!(edge[0] < 64 && edge[3] > 192, "edge pixel was {:?}", edge);
+        assert_eq!(alpha(&image, 120, 10), 0);
+    }
+
+    #[test]
+    fn ibeam_spans_most_of_the_height() {
+        let image = render(&glyph("IBeam"), TOUCH_CURSOR_SIZE);
+        let column: Vec<u32> = (0..TOUCH_CURSOR_SIZE)
+            .filter(|&y| alpha(&image, 64, y) > 0)
+            .collect();
+        let height = column.last().unwrap() - column.first().unwrap();
+        assert!(
+            height > TOUCH_CURSOR_SIZE * 3 / 4,
+            "IBeam was {} px tall",
+            height
+        );
+    }
+
+    #[test]
+    fn every_role_has_a_visible_hotspot() {
+        for cursor_type in cursor_changer::CURSOR_TYPES.iter() {
+            let glyph = glyph(cursor_type.name);
+            let image = render(&glyph, TOUCH_CURSOR_SIZE);
+            let (x, y) = hotspot_pixel(glyph.hotspot, TOUCH_CURSOR_SIZE);
+            // The hotspot lies on the shape or its outline, never in empty space
+            assert!(
+                alpha(&image, u32::from(x), u32::from(y)) > 0,
+                "{} hotspot ({}, {}) is transparent",
+                cursor_type.name,
+                x,
+                y
+            );
+        }
+    }
+}
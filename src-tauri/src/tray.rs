@@ -1,8 +1,9 @@
 use crate::commands::window_commands::show_main_window;
 use tauri::image::Image;
-use tauri::menu::{Menu, MenuItem};
+use tauri::menu::{Menu, MenuItem, PredefinedMenuItem};
 use tauri::tray::{MouseButton, MouseButtonState, TrayIconBuilder, TrayIconEvent};
 use tauri::AppHandle;
+use tauri::Manager;
 use tauri::Result;
 
 /// Build and initialize the system tray icon with menu items.
@@ -38,6 +39,7 @@ pub fn build_tray(app: &AppHandle) -> Result<()> {
                 });
             }
             "show" => show_main_window(app),
+            "toggle-cursor" => crate::hotkey_press::toggle_cursor(app),
             _ => {}
         })
         .on_tray_icon_event(|tray, event| {
@@ -54,3 +56,20 @@ pub fn build_tray(app: &AppHandle) -> Result<()> {
 
     Ok(())
 }
+
+/// Pop up the quick menu at the pointer: hide/show the cursor, show the window, or quit.
+///
+/// Its items share ids with the tray menu, whose handler receives every menu event.
+pub fn show_quick_menu(app: &AppHandle) -> Result<()> {
+    let toggle_item =
+        MenuItem::with_id(app, "toggle-cursor", "Hide/Show cursor", true, None::<&str>)?;
+    let show_item = MenuItem::with_id(app, "show", "Show", true, None::<&str>)?;
+    let separator = PredefinedMenuItem::separator(app)?;
+    let quit_item = MenuItem::with_id(app, "quit", "Quit", true, None::<&str>)?;
+    let menu = Menu::with_items(app, &[&toggle_item, &show_item, &separator, &quit_item])?;
+
+    match app.get_webview_window("main") {
+        Some(window) => window.popup_menu(&menu),
+        None => Ok(()),
+    }
+}
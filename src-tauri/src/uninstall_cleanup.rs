@@ -0,0 +1,365 @@
+//! Finding and removing everything the app has put on the machine, for a clean uninstall.
+//!
+//! [`plan`] lists what a cleanup would remove without touching anything: cursor registry
+//! values that point into the app's folders, the autostart entry, the logon service, copies of
+//! library cursors saved into the Windows cursors folder and the app's data folders. [`run`]
+//! removes the listed items, or the ones the user picked, and reports each item it could not
+//! remove. The logon service needs administrator rights, so it is listed but left to
+//! `cursor_changer_service uninstall`. The app registers no scheduled tasks.
+
+use serde::{Deserialize, Serialize};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashSet;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Manager, Runtime};
+
+const AUTOSTART_ENTRY_NAME: &str = "CursorChanger";
+const RUN_KEY: &str = "Software\\Microsoft\\Windows\\CurrentVersion\\Run";
+const CURSORS_KEY: &str = "Control Panel\\Cursors";
+const LOGON_SERVICE_KEY: &str = "SYSTEM\\CurrentControlSet\\Services\\CursorChangerLogon";
+const CURSOR_FILE_EXTENSIONS: [&str; 2] = ["cur", "ani"];
+/// Library folders are shallow; this only guards against link loops
+const MAX_LIBRARY_DEPTH: usize = 6;
+
+#[derive(ts_rs::TS, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub enum CleanupItemKind {
+    CursorRegistryValue,
+    Autostart,
+    LogonService,
+    WindowsCursorCopy,
+    AppData,
+}
+
+#[derive(ts_rs::TS, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct CleanupItem {
+    pub kind: CleanupItemKind,
+    /// Registry value, service or path; selects the item in `run_uninstall_cleanup`
+    pub location: String,
+    /// Whether the app can remove the item itself
+    pub removable: bool,
+    /// How to remove the item by hand when the app cannot
+    pub note: Option<String>,
+}
+
+impl CleanupItem {
+    fn removable(kind: CleanupItemKind, location: String) -> Self {
+        Self {
+            kind,
+            location,
+            removable: true,
+            note: None,
+        }
+    }
+}
+
+#[derive(ts_rs::TS, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct CleanupFailure {
+    pub item: CleanupItem,
+    pub error: String,
+}
+
+#[derive(ts_rs::TS, Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct UninstallCleanupReport {
+    pub removed: Vec<CleanupItem>,
+    pub failed: Vec<CleanupFailure>,
+    /// Items left in place: not selected, or not removable by the app
+    pub skipped: Vec<CleanupItem>,
+}
+
+/// Everything a cleanup would remove, in the order it would be removed.
+pub fn plan<R: Runtime>(app: &AppHandle<R>) -> Vec<CleanupItem> {
+    let data_dirs = app_data_dirs(app);
+    let mut items = cursor_registry_items(&data_dirs);
+    items.extend(autostart_item());
+    items.extend(logon_service_item());
+    let library_dir = crate::storage_location::library_dir()
+        .map(Ok)
+        .unwrap_or_else(crate::paths::default_library_root_dir);
+    if let (Ok(library_dir), Some(windows_dir)) =
+        (library_dir, cursor_changer::get_windows_cursors_folder())
+    {
+        items.extend(
+            windows_cursor_copies(&library_dir, Path::new(&windows_dir))
+                .into_iter()
+                .map(|path| {
+                    CleanupItem::removable(
+                        CleanupItemKind::WindowsCursorCopy,
+                        path.to_string_lossy().into_owned(),
+                    )
+                }),
+        );
+    }
+    items.extend(data_dirs.iter().filter(|dir| dir.exists()).map(|dir| {
+        CleanupItem::removable(CleanupItemKind::AppData, dir.to_string_lossy().into_owned())
+    }));
+    items
+}
+
+/// Remove the planned items whose location is in `selected`, or every removable item when
+/// `selected` is `None`. The plan is rebuilt first, so only items the app found itself are
+/// ever removed.
+pub fn run<R: Runtime>(app: &AppHandle<R>, selected: Option<&[String]>) -> UninstallCleanupReport {
+    let mut report = UninstallCleanupReport::default();
+    let mut cursors_changed = false;
+    for item in plan(app) {
+        let wanted = selected.is_none_or(|selected| selected.contains(&item.location));
+        if !wanted || !item.removable {
+            report.skipped.push(item);
+            continue;
+        }
+        match remove(&item) {
+            Ok(()) => {
+                cursors_changed |= item.kind == CleanupItemKind::CursorRegistryValue;
+                cc_info!(
+                    "[CursorChanger] Uninstall cleanup removed {}",
+                    item.location
+                );
+                report.removed.push(item);
+            }
+            Err(error) => report.failed.push(CleanupFailure { item, error }),
+        }
+    }
+    if cursors_changed && !crate::system::restore_system_cursors() {
+        cc_warn!("[CursorChanger] Failed to reload system cursors after uninstall cleanup");
+    }
+    report
+}
+
+fn remove(item: &CleanupItem) -> Result<(), String> {
+    use winreg::enums::{HKEY_CURRENT_USER, KEY_WRITE};
+    use winreg::RegKey;
+
+    match item.kind {
+        CleanupItemKind::CursorRegistryValue => {
+            let name = item.location.rsplit('\\').next().unwrap_or_default();
+            // An empty value is how Windows spells "use the built-in cursor"
+            RegKey::predef(HKEY_CURRENT_USER)
+                .open_subkey_with_flags(CURSORS_KEY, KEY_WRITE)
+                .and_then(|key| key.set_value(name, &""))
+                .map_err(|e| format!("Failed to reset cursor registry value: {}", e))
+        }
+        CleanupItemKind::Autostart => {
+            crate::startup::set_autostart(false, AUTOSTART_ENTRY_NAME, None)
+        }
+        CleanupItemKind::LogonService => {
+            Err("The logon service can only be removed by an administrator".to_string())
+        }
+        CleanupItemKind::WindowsCursorCopy => fs::remove_file(&item.location)
+            .map_err(|e| format!("Failed to delete cursor file: {}", e)),
+        CleanupItemKind::AppData => fs::remove_dir_all(&item.location)
+            .map_err(|e| format!("Failed to delete folder: {}", e)),
+    }
+}
+
+/// Folders holding the app's own files, without folders nested in another one.
+fn app_data_dirs<R: Runtime>(app: &AppHandle<R>) -> Vec<PathBuf> {
+    let candidates = [
+        crate::portable::app_dir().ok(),
+        crate::state::config::config_path(app).ok(),
+        app.path().app_data_dir().ok(),
+        app.path().app_local_data_dir().ok(),
+        // A library moved elsewhere was created empty by the app
+        crate::storage_location::library_dir(),
+    ];
+    outermost_dirs(candidates.into_iter().flatten().collect())
+}
+
+fn outermost_dirs(dirs: Vec<PathBuf>) -> Vec<PathBuf> {
+    let mut kept: Vec<PathBuf> = Vec::new();
+    for dir in dirs {
+        if kept
+            .iter()
+            .any(|other| crate::paths::is_within(&dir, other))
+        {
+            continue;
+        }
+        kept.retain(|other| !crate::paths::is_within(other, &dir));
+        kept.push(dir);
+    }
+    kept
+}
+
+fn cursor_registry_items(data_dirs: &[PathBuf]) -> Vec<CleanupItem> {
+    cursor_changer::all_cursor_types()
+        .filter(|ct| {
+            cursor_changer::read_cursor_image_from_registry(ct).is_some_and(|value| {
+                !value.is_empty()
+                    && data_dirs
+                        .iter()
+                        .any(|dir| crate::paths::is_within(Path::new(&value), dir))
+            })
+        })
+        .map(|ct| {
+            CleanupItem::removable(
+                CleanupItemKind::CursorRegistryValue,
+                format!("HKCU\\{}\\{}", CURSORS_KEY, ct.registry_key),
+            )
+        })
+        .collect()
+}
+
+fn autostart_item() -> Option<CleanupItem> {
+    crate::startup::get_autostart_entry(AUTOSTART_ENTRY_NAME)
+        .ok()
+        .flatten()
+        .map(|_| {
+            CleanupItem::removable(
+                CleanupItemKind::Autostart,
+                format!("HKCU\\{}\\{}", RUN_KEY, AUTOSTART_ENTRY_NAME),
+            )
+        })
+}
+
+fn logon_service_item() -> Option<CleanupItem> {
+    use winreg::enums::HKEY_LOCAL_MACHINE;
+    use winreg::RegKey;
+
+    RegKey::predef(HKEY_LOCAL_MACHINE)
+        .open_subkey(LOGON_SERVICE_KEY)
+        .ok()
+        .map(|_| CleanupItem {
+            kind: CleanupItemKind::LogonService,
+            location: format!("HKLM\\{}", LOGON_SERVICE_KEY),
+            removable: false,
+            note: Some(
+                "Run `cursor_changer_service uninstall` as administrator to remove it".to_string(),
+            ),
+        })
+}
+
+fn is_cursor_file(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| {
+            CURSOR_FILE_EXTENSIONS
+                .iter()
+                .any(|known| ext.eq_ignore_ascii_case(known))
+        })
+}
+
+/// Size and content hash, enough to tell copies of a file from unrelated files.
+fn fingerprint(path: &Path) -> Option<(usize, u64)> {
+    let data = fs::read(path).ok()?;
+    let mut hasher = DefaultHasher::new();
+    data.hash(&mut hasher);
+    Some((data.len(), hasher.finish()))
+}
+
+fn collect_cursor_files(dir: &Path, depth: usize, files: &mut Vec<PathBuf>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for path in entries.flatten().map(|entry| entry.path()) {
+        if path.is_dir() {
+            if depth > 0 {
+                collect_cursor_files(&path, depth - 1, files);
+            }
+        } else if is_cursor_file(&path) {
+            files.push(path);
+        }
+    }
+}
+
+/// File names Windows ships in its cursors folder. These are never reported as copies, even
+/// when the library holds an identical file imported from there.
+fn windows_cursor_file_names() -> HashSet<String> {
+    cursor_changer::AERO_CURSOR_FILES
+        .iter()
+        .chain(cursor_changer::BLACK_CURSOR_FILES.iter())
+        .chain(cursor_changer::INVERTED_CURSOR_FILES.iter())
+        .chain(cursor_changer::MAGNIFIED_CURSOR_FILES.iter())
+        .map(|(_, file)| file.to_ascii_lowercase())
+        .collect()
+}
+
+/// Cursor files directly in `windows_dir` with the same content as a file in `library_dir`.
+fn windows_cursor_copies(library_dir: &Path, windows_dir: &Path) -> Vec<PathBuf> {
+    let mut library_files = Vec::new();
+    collect_cursor_files(library_dir, MAX_LIBRARY_DEPTH, &mut library_files);
+    let library: HashSet<(usize, u64)> = library_files
+        .iter()
+        .filter_map(|path| fingerprint(path))
+        .collect();
+    if library.is_empty() {
+        return Vec::new();
+    }
+
+    let shipped = windows_cursor_file_names();
+    let mut candidates = Vec::new();
+    collect_cursor_files(windows_dir, 0, &mut candidates);
+    candidates.retain(|path| {
+        let name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_ascii_lowercase())
+            .unwrap_or_default();
+        !shipped.contains(&name) && fingerprint(path).is_some_and(|fp| library.contains(&fp))
+    });
+    candidates.sort();
+    candidates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_copies_of_library_cursors_are_reported() {
+        let temp = tempfile::tempdir().unwrap();
+        let library = temp.path().join("library");
+        let windows = temp.path().join("Cursors");
+        fs::create_dir_all(library.join("cursors").join("pack")).unwrap();
+        fs::create_dir_all(&windows).unwrap();
+
+        fs::write(
+            library.join("cursors").join("pack").join("neon.cur"),
+            b"neon",
+        )
+        .unwrap();
+        fs::write(library.join("cursors").join("arrow.cur"), b"arrow").unwrap();
+        fs::write(windows.join("Neon Copy.CUR"), b"neon").unwrap();
+        fs::write(windows.join("other.cur"), b"other").unwrap();
+        fs::write(windows.join("neon.txt"), b"neon").unwrap();
+        // Shipped with Windows, so never a copy even when identical
+        fs::write(windows.join("aero_arrow.cur"), b"arrow").unwrap();
+
+        assert_eq!(
+            windows_cursor_copies(&library, &windows),
+            vec![windows.join("Neon Copy.CUR")]
+        );
+        assert!(windows_cursor_copies(&temp.path().join("missing"), &windows).is_empty());
+    }
+
+    #[test]
+    fn nested_data_folders_are_listed_once() {
+        let temp = tempfile::tempdir().unwrap();
+        let root = temp.path().join("cursor-changer");
+        let config = temp.path().join("com.cursorchanger.application");
+        let dirs = outermost_dirs(vec![
+            root.join("config"),
+            root.clone(),
+            config.join("cursor-changer"),
+            config.clone(),
+            root.clone(),
+        ]);
+        assert_eq!(dirs, vec![root, config]);
+    }
+
+    #[test]
+    fn cleanup_items_serialize_for_the_ui() {
+        let item = CleanupItem::removable(
+            CleanupItemKind::WindowsCursorCopy,
+            "C:\\Windows\\Cursors\\neon.cur".to_string(),
+        );
+        let json = serde_json::to_value(&item).unwrap();
+        assert_eq!(json["kind"], "windows_cursor_copy");
+        assert_eq!(json["removable"], true);
+        assert!(json["note"].is_null());
+    }
+}
@@ -0,0 +1,255 @@
+//! Opt-in local usage statistics.
+//!
+//! When enabled, toggles, cursor applies and pack applies are counted in
+//! `usage-counts.json` next to the other settings, for the personal insights page. The counts
+//! never leave the machine; turning the flag off stops counting and `purge_usage_stats`
+//! deletes what was collected.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use tauri::{AppHandle, Runtime};
+
+use crate::state::settings_file::{self, SettingsFile};
+
+pub const USAGE_STATS_SETTINGS_FILE_NAME: &str = "usage-stats.json";
+pub const USAGE_COUNTS_FILE_NAME: &str = "usage-counts.json";
+/// Packs listed under "most used"
+const TOP_PACKS: usize = 10;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+/// Config folder holding the counts file, and its contents, loaded at startup
+static COUNTS: Mutex<Option<(PathBuf, UsageCounts)>> = Mutex::new(None);
+
+#[derive(ts_rs::TS, Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct UsageStatsSettings {
+    #[serde(default)]
+    pub enabled: bool,
+}
+
+/// On-disk counters.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UsageCounts {
+    /// When the first event was counted
+    #[serde(default)]
+    pub since: Option<String>,
+    #[serde(default)]
+    pub toggles: u64,
+    #[serde(default)]
+    pub applies: u64,
+    /// Applies per library pack ID
+    #[serde(default)]
+    pub packs: BTreeMap<String, u64>,
+}
+
+#[derive(ts_rs::TS, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct PackUsage {
+    pub pack_id: String,
+    /// Library name, or `None` if the pack has since been removed
+    pub name: Option<String>,
+    #[ts(type = "number")]
+    pub applies: u64,
+}
+
+#[derive(ts_rs::TS, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[ts(export, export_to = "../../frontend-vite/src/types/generated/")]
+pub struct UsageStats {
+    pub enabled: bool,
+    pub since: Option<String>,
+    #[ts(type = "number")]
+    pub toggles: u64,
+    /// Cursor and pack applies
+    #[ts(type = "number")]
+    pub applies: u64,
+    /// Most applied packs, most used first
+    pub top_packs: Vec<PackUsage>,
+}
+
+enum UsageEvent<'a> {
+    Toggle,
+    Apply,
+    PackApply(&'a str),
+}
+
+impl SettingsFile for UsageCounts {
+    const FILE_NAME: &'static str = USAGE_COUNTS_FILE_NAME;
+    const DESCRIPTION: &'static str = "usage stats";
+}
+
+impl UsageCounts {
+    fn count(&mut self, event: UsageEvent<'_>) {
+        if self.since.is_none() {
+            self.since = Some(crate::utils::library_meta::now_iso8601_utc());
+        }
+        match event {
+            UsageEvent::Toggle => self.toggles += 1,
+            UsageEvent::Apply => self.applies += 1,
+            UsageEvent::PackApply(pack_id) => {
+                self.applies += 1;
+                *self.packs.entry(pack_id.to_string()).or_default() += 1;
+            }
+        }
+    }
+
+    /// Stats for display. `pack_name` resolves pack IDs to library names.
+    pub fn to_stats(
+        &self,
+        enabled: bool,
+        pack_name: impl Fn(&str) -> Option<String>,
+    ) -> UsageStats {
+        let mut packs: Vec<(&String, &u64)> = self.packs.iter().collect();
+        // Most applies first; ties keep ID order so the list is stable
+        packs.sort_by(|a, b| b.1.cmp(a.1));
+        UsageStats {
+            enabled,
+            since: self.since.clone(),
+            toggles: self.toggles,
+            applies: self.applies,
+            top_packs: packs
+                .into_iter()
+                .take(TOP_PACKS)
+                .map(|(pack_id, &applies)| PackUsage {
+                    pack_id: pack_id.clone(),
+                    name: pack_name(pack_id),
+                    applies,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl SettingsFile for UsageStatsSettings {
+    const FILE_NAME: &'static str = USAGE_STATS_SETTINGS_FILE_NAME;
+    const DESCRIPTION: &'static str = "usage stats settings";
+}
+
+pub fn load_settings<R: Runtime>(app: &AppHandle<R>) -> UsageStatsSettings {
+    settings_file::load(app)
+}
+
+pub fn save_settings<R: Runtime>(
+    app: &AppHandle<R>,
+    settings: &UsageStatsSettings,
+) -> Result<(), String> {
+    settings_file::save(app, settings)
+}
+
+/// Load the setting and the saved counts. Called once at startup.
+pub fn init<R: Runtime>(app: &AppHandle<R>) {
+    ENABLED.store(load_settings(app).enabled, Ordering::SeqCst);
+    match crate::state::config::config_path(app) {
+        Ok(dir) => {
+            let counts = settings_file::load_from(&dir);
+            *COUNTS.lock().unwrap_or_else(|e| e.into_inner()) = Some((dir, counts));
+        }
+        Err(e) => cc_warn!("[CursorChanger] Usage stats unavailable: {}", e),
+    }
+}
+
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::SeqCst);
+}
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::SeqCst)
+}
+
+fn record(event: UsageEvent<'_>) {
+    if !is_enabled() {
+        return;
+    }
+    let mut guard = COUNTS.lock().unwrap_or_else(|e| e.into_inner());
+    let Some((dir, counts)) = guard.as_mut() else {
+        return;
+    };
+    counts.count(event);
+    if let Err(e) = settings_file::save_to(dir, counts) {
+        cc_debug!("[CursorChanger] {}", e);
+    }
+}
+
+pub fn record_toggle() {
+    record(UsageEvent::Toggle);
+}
+
+pub fn record_apply() {
+    record(UsageEvent::Apply);
+}
+
+pub fn record_pack_apply(pack_id: &str) {
+    record(UsageEvent::PackApply(pack_id));
+}
+
+/// Current counts, empty before [`init`].
+pub fn counts() -> UsageCounts {
+    COUNTS
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .as_ref()
+        .map(|(_, counts)| counts.clone())
+        .unwrap_or_default()
+}
+
+/// Forget every count and delete the counts file.
+pub fn purge<R: Runtime>(app: &AppHandle<R>) -> Result<(), String> {
+    let dir = crate::state::config::config_path(app)?;
+    let path = dir.join(USAGE_COUNTS_FILE_NAME);
+    let mut guard = COUNTS.lock().unwrap_or_else(|e| e.into_inner());
+    if path.exists() {
+        fs::remove_file(&path).map_err(|e| format!("Failed to delete usage stats: {}", e))?;
+    }
+    *guard = Some((dir, UsageCounts::default()));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pack_applies_count_as_applies_and_rank_packs() {
+        let mut counts = UsageCounts::default();
+        counts.count(UsageEvent::Toggle);
+        counts.count(UsageEvent::Apply);
+        for pack in ["neon", "aero", "neon", "retro", "neon", "aero"] {
+            counts.count(UsageEvent::PackApply(pack));
+        }
+        assert!(counts.since.is_some());
+
+        let stats = counts.to_stats(true, |id| (id != "retro").then(|| id.to_uppercase()));
+        assert_eq!(stats.toggles, 1);
+        assert_eq!(stats.applies, 7);
+        let ranked: Vec<(&str, u64)> = stats
+            .top_packs
+            .iter()
+            .map(|p| (p.pack_id.as_str(), p.applies))
+            .collect();
+        assert_eq!(ranked, [("neon", 3), ("aero", 2), ("retro", 1)]);
+        assert_eq!(stats.top_packs[0].name.as_deref(), Some("NEON"));
+        assert_eq!(stats.top_packs[2].name, None);
+    }
+
+    #[test]
+    fn counts_round_trip_and_tolerate_missing_files() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let loaded: UsageCounts = settings_file::load_from(dir.path());
+        assert_eq!(loaded, UsageCounts::default());
+
+        let mut counts = UsageCounts::default();
+        counts.count(UsageEvent::PackApply("neon"));
+        settings_file::save_to(dir.path(), &counts).expect("write");
+        let loaded: UsageCounts = settings_file::load_from(dir.path());
+        assert_eq!(loaded, counts);
+    }
+
+    #[test]
+    fn usage_stats_are_off_by_default() {
+        let settings: UsageStatsSettings = serde_json::from_str("{}").unwrap();
+        assert!(!settings.enabled);
+    }
+}
@@ -31,16 +31,11 @@ pub fn parse_cur_click_point(bytes: &[u8]) -> (u16, u16) {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use cursor_convert::fixtures;
 
     #[test]
     fn parse_cur_click_point_reads_valid_coordinates() {
-        let mut bytes = vec![0u8; 22];
-        bytes[2] = 2; // cursor type
-        bytes[4] = 1; // images
-        bytes[6] = 16; // width
-        bytes[7] = 16; // height
-        bytes[10] = 5; // click point x low byte
-        bytes[12] = 10; // click point y low byte
+        let bytes = fixtures::cur(16, (5, 10));
 
         let (x, y) = parse_cur_click_point(&bytes);
         assert_eq!(x, 5);
@@ -1,13 +1,15 @@
-use std::time::{SystemTime, UNIX_EPOCH};
 use time::format_description::well_known::Rfc3339;
 use time::OffsetDateTime;
 
+/// New library entry ID. UUIDv7 keeps IDs time-ordered while the random bits stop two
+/// machines syncing the same library folder from minting the same ID.
 pub fn new_library_cursor_id() -> String {
-    let timestamp = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .unwrap_or_default()
-        .as_nanos();
-    format!("lib_{}", timestamp)
+    format!("lib_{}", uuid::Uuid::now_v7().simple())
+}
+
+/// New library collection ID, minted the same way as cursor IDs.
+pub fn new_library_collection_id() -> String {
+    format!("col_{}", uuid::Uuid::now_v7().simple())
 }
 
 pub fn now_iso8601_utc() -> String {
@@ -1,4 +1,5 @@
-use crate::state::MinimizePreference;
+use crate::state::config::{persist_config, PersistedConfig};
+use crate::state::{AppState, MinimizePreference};
 use std::sync::atomic::Ordering;
 use tauri::{AppHandle, Manager};
 
@@ -68,10 +69,46 @@ pub fn handle_window_resized(window: &tauri::Window) {
     }
 }
 
+/// Fold the window's current position, size and maximized state into the saved geometry.
+fn record_window_geometry(window: &tauri::Window) {
+    let (Ok(position), Ok(size)) = (window.outer_position(), window.inner_size()) else {
+        return;
+    };
+    let maximized = window.is_maximized().unwrap_or(false);
+    let minimized = window.is_minimized().unwrap_or(false);
+    let Some(state) = window.app_handle().try_state::<AppState>() else {
+        return;
+    };
+    let Ok(mut prefs) = state.prefs.write() else {
+        return;
+    };
+    prefs.window_geometry = crate::window_state::update_geometry(
+        prefs.window_geometry,
+        position,
+        size,
+        maximized,
+        minimized,
+    );
+}
+
+/// Write the remembered window geometry to `config.json` along with the other preferences.
+pub fn persist_window_state(app: &AppHandle) {
+    let Some(state) = app.try_state::<AppState>() else {
+        return;
+    };
+    if let Err(e) = persist_config(app, &PersistedConfig::from(&*state)) {
+        cc_warn!("[CursorChanger] Failed to save window state: {}", e);
+    }
+}
+
 pub fn on_window_event(window: &tauri::Window, event: &tauri::WindowEvent) {
     match event {
         tauri::WindowEvent::Resized(_) => {
             handle_window_resized(window);
+            record_window_geometry(window);
+        }
+        tauri::WindowEvent::Moved(_) => {
+            record_window_geometry(window);
         }
         tauri::WindowEvent::Focused(focused) => {
             // Also check on focus, as this covers the "user clicks taskbar icon" case
@@ -82,6 +119,7 @@ pub fn on_window_event(window: &tauri::Window, event: &tauri::WindowEvent) {
         tauri::WindowEvent::CloseRequested { api, .. } => {
             api.prevent_close();
             let app_handle = window.app_handle().clone();
+            persist_window_state(&app_handle);
             if should_minimize_to_tray(&app_handle).unwrap_or(false) {
                 let _ = window.hide();
             } else {
@@ -35,10 +35,10 @@ fn try_open_devtools(_win: &WebviewWindow) {
 /// 1. Logs the window URL for diagnostics
 /// 2. Injects a development server redirect probe (debug builds only)
 /// 3. Injects SVG rendering debug scripts
-/// 4. Calculates and applies optimal window size (16:9 aspect ratio)
-/// 5. Centers the window on the primary monitor
-/// 6. Opens developer tools if requested (debug builds only)
-/// 7. Sets window focus
+/// 4. Restores the saved window geometry, or calculates and applies optimal window size
+///    (16:9 aspect ratio) centered on the primary monitor and maximizes
+/// 5. Opens developer tools if requested (debug builds only)
+/// 6. Sets window focus
 ///
 /// # Arguments
 /// * `app` - The Tauri application handle
@@ -77,13 +77,14 @@ pub fn initialize_main_window(app: &AppHandle) {
     // Inject SVG rendering debug scripts
     inject_svg_debug_script(&win);
 
-    // Calculate and apply optimal window size
-    apply_optimal_window_size(app, &win);
+    // Reopen where the user left off; otherwise size to the monitor and maximize
+    if !restore_saved_geometry(app, &win) {
+        apply_optimal_window_size(app, &win);
 
-    // Default to maximized window on startup
-    match win.maximize() {
-        Ok(_) => cc_debug!("[CursorChanger] Window maximized on startup"),
-        Err(e) => cc_error!("[CursorChanger] Failed to maximize window on startup: {}", e),
+        match win.maximize() {
+            Ok(_) => cc_debug!("[CursorChanger] Window maximized on startup"),
+            Err(e) => cc_error!("[CursorChanger] Failed to maximize window on startup: {}", e),
+        }
     }
 
     // Open developer tools if requested (debug builds only)
@@ -117,6 +118,48 @@ pub fn reset_main_window_size(app: &AppHandle) -> Result<(), String> {
     Ok(())
 }
 
+/// Apply the geometry saved in the preferences. Returns false when there is none or it no
+/// longer fits the connected monitors.
+fn restore_saved_geometry(app: &AppHandle, win: &WebviewWindow) -> bool {
+    let monitors: Vec<crate::window_state::MonitorRect> = app
+        .available_monitors()
+        .unwrap_or_default()
+        .iter()
+        .map(|m| (m.position().x, m.position().y, m.size().width, m.size().height))
+        .collect();
+    let saved = app.try_state::<crate::state::AppState>().and_then(|state| {
+        state
+            .prefs
+            .read()
+            .ok()
+            .and_then(|prefs| prefs.window_geometry)
+    });
+    let Some(geometry) = crate::window_state::restorable_geometry(saved, &monitors) else {
+        return false;
+    };
+
+    if let Err(e) = win.set_size(tauri::Size::Physical(tauri::PhysicalSize {
+        width: geometry.width,
+        height: geometry.height,
+    })) {
+        cc_error!("[CursorChanger] Failed to restore window size: {}", e);
+        return false;
+    }
+    if let Err(e) = win.set_position(tauri::Position::Physical(tauri::PhysicalPosition {
+        x: geometry.x,
+        y: geometry.y,
+    })) {
+        cc_error!("[CursorChanger] Failed to restore window position: {}", e);
+    }
+    if geometry.maximized {
+        if let Err(e) = win.maximize() {
+            cc_error!("[CursorChanger] Failed to restore maximized window: {}", e);
+        }
+    }
+    cc_debug!("[CursorChanger] Restored window geometry {:?}", geometry);
+    true
+}
+
 /// Inject a script to redirect to the Vite dev server if it's running (debug builds only).
 /// NOTE: This is disabled because Tauri already loads from devUrl in tauri.conf.json.
 /// The redirect was causing window visibility issues.
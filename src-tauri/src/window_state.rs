@@ -0,0 +1,159 @@
+//! Window geometry and last open page, remembered across launches.
+//!
+//! Both live in the preferences layer and are saved with the rest of `config.json`. Move and
+//! resize events only update the in-memory copy; it is written when the window is closed or
+//! hidden and on shutdown. Geometry is the normal (restored) rectangle in physical pixels, so
+//! unmaximizing after a restart returns to the last size.
+
+use tauri::{AppHandle, Manager, PhysicalPosition, PhysicalSize, Runtime};
+
+use crate::state::config::{persist_config, PersistedConfig};
+use crate::state::{AppState, WindowGeometry};
+
+/// Page IDs longer than this are not stored.
+const MAX_PAGE_ID_LEN: usize = 64;
+/// Smallest restored size; anything smaller is treated as the minimized-window bug.
+const MIN_RESTORED_SIZE: u32 = 200;
+
+/// A monitor's bounds in physical pixels: (x, y, width, height).
+pub type MonitorRect = (i32, i32, u32, u32);
+
+/// Fold a window event into the remembered geometry. Minimized windows are ignored, and a
+/// maximized window only sets the flag so the normal rectangle survives.
+pub fn update_geometry(
+    current: Option<WindowGeometry>,
+    position: PhysicalPosition<i32>,
+    size: PhysicalSize<u32>,
+    maximized: bool,
+    minimized: bool,
+) -> Option<WindowGeometry> {
+    if minimized {
+        return current;
+    }
+    if maximized {
+        return current.map(|geometry| WindowGeometry {
+            maximized: true,
+            ..geometry
+        });
+    }
+    if size.width < MIN_RESTORED_SIZE || size.height < MIN_RESTORED_SIZE {
+        return current;
+    }
+    Some(WindowGeometry {
+        x: position.x,
+        y: position.y,
+        width: size.width,
+        height: size.height,
+        maximized: false,
+    })
+}
+
+pub fn last_page<R: Runtime>(app: &AppHandle<R>) -> Option<String> {
+    let state = app.try_state::<AppState>()?;
+    let prefs = state.prefs.read().ok()?;
+    prefs.last_page.clone()
+}
+
+pub fn set_last_page<R: Runtime>(app: &AppHandle<R>, page: Option<String>) -> Result<(), String> {
+    let page = page
+        .map(|p| p.trim().to_string())
+        .filter(|p| !p.is_empty());
+    if page.as_ref().is_some_and(|p| p.len() > MAX_PAGE_ID_LEN) {
+        return Err(format!("Page ID is longer than {} characters", MAX_PAGE_ID_LEN));
+    }
+    let state = app
+        .try_state::<AppState>()
+        .ok_or_else(|| "Application state is not available".to_string())?;
+    state.prefs.write()?.last_page = page;
+    persist_config(app, &PersistedConfig::from(&*state))
+}
+
+/// Whether at least a 100px square of the window's title area is on one of `monitors`,
+/// so a window saved on a now-disconnected display is not restored off-screen.
+pub fn is_reachable(geometry: &WindowGeometry, monitors: &[MonitorRect]) -> bool {
+    const VISIBLE: i64 = 100;
+    let (left, top) = (i64::from(geometry.x), i64::from(geometry.y));
+    let right = left + i64::from(geometry.width);
+    monitors.iter().any(|&(mx, my, mw, mh)| {
+        let (m_left, m_top) = (i64::from(mx), i64::from(my));
+        let (m_right, m_bottom) = (m_left + i64::from(mw), m_top + i64::from(mh));
+        let overlap_x = right.min(m_right) - left.max(m_left);
+        let title_visible = top >= m_top && top + VISIBLE <= m_bottom;
+        overlap_x >= VISIBLE && title_visible
+    })
+}
+
+/// `geometry` if it is still usable on the connected monitors.
+pub fn restorable_geometry(
+    geometry: Option<WindowGeometry>,
+    monitors: &[MonitorRect],
+) -> Option<WindowGeometry> {
+    let geometry = geometry?;
+    if is_reachable(&geometry, monitors) {
+        Some(geometry)
+    } else {
+        cc_debug!(
+            "[CursorChanger] Saved window position {:?} is off-screen; using defaults",
+            geometry
+        );
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn geometry(x: i32, y: i32) -> WindowGeometry {
+        WindowGeometry {
+            x,
+            y,
+            width: 800,
+            height: 600,
+            maximized: false,
+        }
+    }
+
+    #[test]
+    fn maximizing_keeps_the_normal_rectangle() {
+        let normal = Some(geometry(10, 20));
+        let updated = update_geometry(
+            normal,
+            PhysicalPosition::new(-8, -8),
+            PhysicalSize::new(1936, 1056),
+            true,
+            false,
+        )
+        .unwrap();
+        assert_eq!((updated.x, updated.width), (10, 800));
+        assert!(updated.maximized);
+
+        let unmaximized = update_geometry(
+            Some(updated),
+            PhysicalPosition::new(10, 20),
+            PhysicalSize::new(800, 600),
+            false,
+            false,
+        );
+        assert_eq!(unmaximized, normal);
+    }
+
+    #[test]
+    fn minimized_and_tiny_windows_are_ignored() {
+        let normal = Some(geometry(10, 20));
+        let pos = PhysicalPosition::new(-32000, -32000);
+        assert_eq!(update_geometry(normal, pos, PhysicalSize::new(0, 0), false, true), normal);
+        assert_eq!(update_geometry(normal, pos, PhysicalSize::new(160, 28), false, false), normal);
+    }
+
+    #[test]
+    fn off_screen_geometry_is_not_restored() {
+        let monitors = [(0, 0, 1920, 1080), (1920, 0, 1280, 1024)];
+        assert!(is_reachable(&geometry(100, 100), &monitors));
+        assert!(is_reachable(&geometry(2000, 50), &monitors));
+        // Secondary monitor to the left was disconnected
+        assert!(!is_reachable(&geometry(-1500, 100), &monitors));
+        // Title bar above the top edge
+        assert!(!is_reachable(&geometry(100, -200), &monitors));
+    }
+}
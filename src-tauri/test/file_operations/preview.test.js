@@ -122,7 +122,17 @@ async function handleBrowseCursorFile(args) {
     return null;
   }
 
-  return selection.as_path();
+  return {
+    path: selection.as_path(),
+    format: 'svg',
+    width: 32,
+    height: 32,
+    hotspot_x: null,
+    hotspot_y: null,
+    frame_count: 1,
+    file_size: 1024,
+    error: null
+  };
 }
 
 // ============================================================================
@@ -174,10 +184,11 @@ describe('File Preview Operations - Unit Tests', () => {
   });
 
   describe('browse_cursor_file', () => {
-    it('should open file dialog and return selected path', async () => {
+    it('should open file dialog and describe the selected file', async () => {
       const result = await mockInvoke('browse_cursor_file');
 
-      expect(result).toBe('/mock/selected/cursor.svg');
+      expect(result.path).toBe('/mock/selected/cursor.svg');
+      expect(result).toMatchObject({ format: 'svg', width: 32, height: 32, file_size: 1024 });
       expect(mockDialog.file).toHaveBeenCalled();
     });
 
@@ -14,6 +14,36 @@ pub fn get_library_contracts() -> HashMap<String, CommandContract> {
         parameters: vec![],
     });
     
+    contracts.insert("get_library_page".to_string(), CommandContract {
+        name: "get_library_page".to_string(),
+        parameters: vec![
+            ParameterSchema {
+                name: "offset".to_string(),
+                param_type: "u32".to_string(),
+                required: true,
+                description: "Index of the first entry to return".to_string(),
+            },
+            ParameterSchema {
+                name: "limit".to_string(),
+                param_type: "u32".to_string(),
+                required: true,
+                description: "Maximum number of entries to return".to_string(),
+            },
+            ParameterSchema {
+                name: "sort".to_string(),
+                param_type: "Option<LibrarySort>".to_string(),
+                required: false,
+                description: "Sort order, defaults to the custom order".to_string(),
+            },
+            ParameterSchema {
+                name: "filter".to_string(),
+                param_type: "Option<LibraryFilter>".to_string(),
+                required: false,
+                description: "Text and kind filter applied before paging".to_string(),
+            },
+        ],
+    });
+    
     contracts.insert("add_cursor_to_library".to_string(), CommandContract {
         name: "add_cursor_to_library".to_string(),
         parameters: vec![
@@ -110,6 +140,24 @@ pub fn get_library_contracts() -> HashMap<String, CommandContract> {
         ],
     });
     
+    contracts.insert("update_cursor_metadata".to_string(), CommandContract {
+        name: "update_cursor_metadata".to_string(),
+        parameters: vec![
+            ParameterSchema {
+                name: "id".to_string(),
+                param_type: "String".to_string(),
+                required: true,
+                description: "ID of the cursor to update".to_string(),
+            },
+            ParameterSchema {
+                name: "fields".to_string(),
+                param_type: "CursorMetadataUpdate".to_string(),
+                required: true,
+                description: "Notes and attribution fields to change".to_string(),
+            },
+        ],
+    });
+    
     contracts.insert("reorder_library_cursors".to_string(), CommandContract {
         name: "reorder_library_cursors".to_string(),
         parameters: vec![
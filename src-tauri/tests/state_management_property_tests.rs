@@ -49,6 +49,7 @@ fn arb_app_state() -> impl Strategy<Value = AppState> {
         use cursor_changer_tauri::state::app_state::{
             CursorRuntimeState, ModeCustomizationState, PreferencesState, RestorationState,
         };
+        use cursor_changer_tauri::state::AdminPolicy;
 
         let (
             hidden,
@@ -92,10 +93,12 @@ fn arb_app_state() -> impl Strategy<Value = AppState> {
                 simple_mode_cursor_paths,
                 advanced_mode_cursor_paths,
                 customization_mode,
+                effects: Default::default(),
             }),
             restoration: RwLock::new(RestorationState {
                 cursor_registry_snapshot: None,
             }),
+            policy: RwLock::new(AdminPolicy::default()),
         }
     })
 }
@@ -0,0 +1,125 @@
+//! Result reporting shared by the command-line modes of `cursor_changer` and
+//! `cursor_changer_service`.
+//!
+//! Pass `--json` to get a single JSON object on stdout that scripts (e.g. PowerShell's
+//! `ConvertFrom-Json`) can parse; otherwise a short human-readable summary is printed.
+
+use serde::Serialize;
+use std::time::Instant;
+
+/// Reuse the console of the parent process so output is visible. Release builds use the GUI
+/// subsystem and start without a console of their own.
+pub fn attach_parent_console() {
+    unsafe {
+        winapi::um::wincon::AttachConsole(winapi::um::wincon::ATTACH_PARENT_PROCESS);
+    }
+}
+
+/// How a command reports its outcome.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    Text,
+    Json,
+}
+
+impl OutputFormat {
+    /// Remove every `--json` flag from `args` and return the selected format.
+    pub fn take_from_args(args: &mut Vec<String>) -> Self {
+        let before = args.len();
+        args.retain(|arg| arg != "--json");
+        if args.len() == before {
+            Self::Text
+        } else {
+            Self::Json
+        }
+    }
+}
+
+/// Outcome of one CLI command.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CliReport {
+    pub command: String,
+    pub ok: bool,
+    /// Cursor roles (e.g. `"Normal"`, `"Hand"`) that were applied
+    pub applied_roles: Vec<String>,
+    pub errors: Vec<String>,
+    pub elapsed_ms: u64,
+    #[serde(skip)]
+    started: Option<Instant>,
+}
+
+impl CliReport {
+    /// Start a report; the elapsed time is measured from here until `finish`.
+    #[must_use]
+    pub fn start(command: &str) -> Self {
+        Self {
+            command: command.to_string(),
+            ok: true,
+            applied_roles: Vec::new(),
+            errors: Vec::new(),
+            elapsed_ms: 0,
+            started: Some(Instant::now()),
+        }
+    }
+
+    pub fn applied(&mut self, role: &str) {
+        self.applied_roles.push(role.to_string());
+    }
+
+    pub fn error(&mut self, message: impl Into<String>) {
+        self.errors.push(message.into());
+    }
+
+    /// Stop the clock. The report is `ok` when no errors were recorded.
+    #[must_use]
+    pub fn finish(mut self) -> Self {
+        if let Some(started) = self.started.take() {
+            self.elapsed_ms = u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX);
+        }
+        self.ok = self.errors.is_empty();
+        self
+    }
+
+    #[must_use]
+    pub fn render(&self, format: OutputFormat) -> String {
+        match format {
+            OutputFormat::Json => serde_json::to_string(self).unwrap_or_else(|e| {
+                format!("{{\"command\":\"{}\",\"ok\":false,\"errors\":[\"{e}\"]}}", self.command)
+            }),
+            OutputFormat::Text => {
+                let mut text = if self.applied_roles.is_empty() {
+                    format!("{}: done in {} ms", self.command, self.elapsed_ms)
+                } else {
+                    format!(
+                        "{}: applied {} role(s) ({}) in {} ms",
+                        self.command,
+                        self.applied_roles.len(),
+                        self.applied_roles.join(", "),
+                        self.elapsed_ms
+                    )
+                };
+                for error in &self.errors {
+                    text.push_str("\nerror: ");
+                    text.push_str(error);
+                }
+                text
+            }
+        }
+    }
+
+    /// Process exit code: 0 on success, 1 if anything failed.
+    #[must_use]
+    pub const fn exit_code(&self) -> i32 {
+        if self.ok {
+            0
+        } else {
+            1
+        }
+    }
+}
+
+/// Print the report to stdout in the requested format and return the exit code.
+pub fn print_report(report: &CliReport, format: OutputFormat) -> i32 {
+    println!("{}", report.render(format));
+    report.exit_code()
+}
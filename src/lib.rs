@@ -5,20 +5,49 @@
 
 #![allow(non_upper_case_globals)]
 
+pub mod cli_output;
+pub mod win_accessibility;
 mod win_common;
+pub mod win_crash;
 pub mod win_cursor;
+pub mod win_disk;
+pub mod win_keyboard;
+pub mod win_memory;
+pub mod win_mouse;
+pub mod win_overlay;
+pub mod win_power;
 pub mod win_runtime;
+pub mod win_sandbox;
+pub mod win_screen;
+pub mod win_sound;
 
+pub use win_accessibility::client_area_animation_enabled;
 pub use win_common::{build_tip_buffer, copy_tip_to_buf, to_wide};
+pub use win_crash::{install_crash_handler, new_dump_path, write_minidump};
+pub use win_disk::available_disk_space;
+pub use win_memory::{set_thread_background_mode, trim_working_set};
+pub use win_power::{power_status, PowerStatus};
+pub use win_sandbox::{confine_process, lower_own_token, ProcessJob};
+pub use win_screen::{pick_screen_color, ScreenColor};
+pub use win_sound::play_wav_bytes;
 pub use win_cursor::{
-    apply_blank_system_cursors, apply_cursor_file_with_size, apply_cursor_from_file_with_size,
-    clear_cursor_registry_entries, find_cursor_file_in_dir, find_default_cursor_in_dir,
-    get_default_cursor_base_name, get_windows_cursors_folder,
+    all_cursor_types, apply_blank_system_cursors, apply_cursor_file_with_size, apply_cursor_from_file_with_size,
+    broadcast_cursor_setting_change, clear_cursor_registry_entries, cursor_file_hash,
+    diff_cursor_registry_entries, find_cursor_file_in_dir, find_cursor_type, find_default_cursor_in_dir,
+    find_system_cursor_file, get_default_cursor_base_name, get_windows_cursors_folder, live_cursor_hash,
     perform_toggle, read_cursor_image_from_registry, refresh_cursor_settings,
-    restore_cursor_registry_entries, restore_system_cursors, snapshot_cursor_registry_entries,
-    toggle_action, write_cursor_image_to_registry, CursorType, SystemApi, ToggleAction,
-    CURSOR_EXTENSIONS, CURSOR_TYPES, DEFAULT_CURSOR_BASE_NAMES,
+    refresh_cursor_settings_quietly, reload_system_cursors, restore_cursor_registry_entries,
+    restore_system_cursors, snapshot_cursor_registry_entries,
+    toggle_action, validate_cursor_registry_value_name, verify_cursor_registry_entries,
+    write_cursor_image_to_registry, BlankRoleMask, CursorType, RefreshError, RefreshStrategy,
+    RegistryWriteError, RetryPolicy, SystemApi, SystemCursorScheme, ToggleAction, Watchdog,
+    AERO_CURSOR_FILES, BLACK_CURSOR_FILES, REFRESH_TIMEOUT,
+    CURSOR_EXTENSIONS, CURSOR_TYPES, DEFAULT_CURSOR_BASE_NAMES, EXTENDED_CURSOR_BASE_NAMES,
+    EXTENDED_CURSOR_TYPES, INVERTED_CURSOR_FILES, MAGNIFIED_CURSOR_FILES,
 };
 
-pub use win_runtime::run_app;
+#[cfg(feature = "e2e")]
+pub use win_cursor::{TestCursorSession, TestSessionMode};
+
+pub use win_runtime::{run_app, run_app_with_observer, RuntimeEvent};
 
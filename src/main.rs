@@ -1,8 +1,74 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
+use cursor_changer::cli_output::{attach_parent_console, print_report, CliReport, OutputFormat};
+use cursor_changer::CURSOR_TYPES;
+
+const DEFAULT_CLI_CURSOR_SIZE: i32 = 32;
+
 fn main() {
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+    if !args.is_empty() {
+        std::process::exit(run_cli(&mut args));
+    }
+
     if let Err(e) = cursor_changer::run_app() {
         eprintln!("Application error: {e}");
         std::process::exit(1);
     }
 }
+
+/// Command-line mode:
+///   cursor_changer apply <file> [--size N] [--json]
+///   cursor_changer restore [--json]
+fn run_cli(args: &mut Vec<String>) -> i32 {
+    attach_parent_console();
+
+    let format = OutputFormat::take_from_args(args);
+    let command = args.remove(0);
+    let mut report = CliReport::start(&command);
+
+    match command.as_str() {
+        "apply" => apply(args, &mut report),
+        "restore" => {
+            if !unsafe { cursor_changer::restore_system_cursors() } {
+                report.error("Failed to restore system cursors");
+            }
+        }
+        other => report.error(format!(
+            "Unknown command '{other}'. Usage: cursor_changer apply <file> [--size N] [--json] | restore [--json]"
+        )),
+    }
+
+    print_report(&report.finish(), format)
+}
+
+fn apply(args: &[String], report: &mut CliReport) {
+    let mut size = DEFAULT_CLI_CURSOR_SIZE;
+    let mut file = None;
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        if arg == "--size" {
+            match iter.next().and_then(|v| v.parse().ok()) {
+                Some(value) => size = value,
+                None => return report.error("--size expects a number of pixels"),
+            }
+        } else {
+            file = Some(arg.as_str());
+        }
+    }
+
+    let Some(file) = file else {
+        return report.error("apply expects a .cur or .ani file");
+    };
+    if !std::path::Path::new(file).exists() {
+        return report.error(format!("File not found: {file}"));
+    }
+
+    for cursor_type in &CURSOR_TYPES {
+        if unsafe { cursor_changer::apply_cursor_from_file_with_size(file, cursor_type.id, size) } {
+            report.applied(cursor_type.name);
+        } else {
+            report.error(format!("Failed to apply {file} to {}", cursor_type.name));
+        }
+    }
+}
@@ -0,0 +1,24 @@
+//! Windows accessibility settings that affect how cursors and effects are presented.
+
+use windows::core::BOOL;
+use windows::Win32::UI::WindowsAndMessaging::{
+    SystemParametersInfoW, SPI_GETCLIENTAREAANIMATION, SYSTEM_PARAMETERS_INFO_UPDATE_FLAGS,
+};
+
+/// Whether the "Animation effects" setting (Settings > Accessibility > Visual effects) is on.
+/// Returns `None` if the setting could not be read.
+///
+/// # Safety
+/// Calls Windows API functions.
+#[must_use]
+pub unsafe fn client_area_animation_enabled() -> Option<bool> {
+    let mut enabled = BOOL(1);
+    SystemParametersInfoW(
+        SPI_GETCLIENTAREAANIMATION,
+        0,
+        Some((&raw mut enabled).cast()),
+        SYSTEM_PARAMETERS_INFO_UPDATE_FLAGS(0),
+    )
+    .ok()?;
+    Some(enabled.as_bool())
+}
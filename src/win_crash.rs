@@ -0,0 +1,112 @@
+//! Minidump capture for crash reports.
+//!
+//! [`install_crash_handler`] registers a vectored exception handler that reacts only to fatal
+//! exception codes, so first-chance exceptions that something else handles are not reported.
+//! On the first fatal exception it runs the caller's hook (e.g. restore the system cursors)
+//! and writes a minidump, then lets the exception continue to the default handler.
+
+use std::os::windows::io::AsRawHandle;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::OnceLock;
+
+use windows::Win32::Foundation::{
+    EXCEPTION_ACCESS_VIOLATION, EXCEPTION_ARRAY_BOUNDS_EXCEEDED, EXCEPTION_DATATYPE_MISALIGNMENT,
+    EXCEPTION_ILLEGAL_INSTRUCTION, EXCEPTION_INT_DIVIDE_BY_ZERO, EXCEPTION_IN_PAGE_ERROR,
+    EXCEPTION_PRIV_INSTRUCTION, EXCEPTION_STACK_OVERFLOW, HANDLE, NTSTATUS,
+};
+use windows::Win32::System::Diagnostics::Debug::{
+    AddVectoredExceptionHandler, MiniDumpWithIndirectlyReferencedMemory, MiniDumpWithThreadInfo,
+    MiniDumpWriteDump, EXCEPTION_POINTERS, MINIDUMP_EXCEPTION_INFORMATION,
+};
+use windows::Win32::System::Threading::{GetCurrentProcess, GetCurrentProcessId, GetCurrentThreadId};
+
+const EXCEPTION_CONTINUE_SEARCH: i32 = 0;
+
+const FATAL_EXCEPTIONS: [NTSTATUS; 8] = [
+    EXCEPTION_ACCESS_VIOLATION,
+    EXCEPTION_ARRAY_BOUNDS_EXCEEDED,
+    EXCEPTION_DATATYPE_MISALIGNMENT,
+    EXCEPTION_ILLEGAL_INSTRUCTION,
+    EXCEPTION_INT_DIVIDE_BY_ZERO,
+    EXCEPTION_IN_PAGE_ERROR,
+    EXCEPTION_PRIV_INSTRUCTION,
+    EXCEPTION_STACK_OVERFLOW,
+];
+
+/// Called on the crashing thread before the dump is written, with the dump path.
+/// Returning `false` skips the dump (e.g. crash reporting was turned off after install).
+pub type CrashHook = fn(&Path) -> bool;
+
+struct CrashHandlerConfig {
+    dump_dir: PathBuf,
+    hook: CrashHook,
+}
+
+static CONFIG: OnceLock<CrashHandlerConfig> = OnceLock::new();
+static CRASHED: AtomicBool = AtomicBool::new(false);
+
+/// Write a minidump of the current process to `path`.
+///
+/// # Safety
+/// Calls Windows API functions. `exception` must be null or point to the exception being
+/// handled on the current thread.
+pub unsafe fn write_minidump(path: &Path, exception: *mut EXCEPTION_POINTERS) -> Result<(), String> {
+    let file = std::fs::File::create(path).map_err(|e| format!("Failed to create dump: {e}"))?;
+    let exception_info = MINIDUMP_EXCEPTION_INFORMATION {
+        ThreadId: GetCurrentThreadId(),
+        ExceptionPointers: exception,
+        ClientPointers: false.into(),
+    };
+    MiniDumpWriteDump(
+        GetCurrentProcess(),
+        GetCurrentProcessId(),
+        HANDLE(file.as_raw_handle()),
+        MiniDumpWithThreadInfo | MiniDumpWithIndirectlyReferencedMemory,
+        (!exception.is_null()).then_some(&raw const exception_info),
+        None,
+        None,
+    )
+    .map_err(|e| format!("MiniDumpWriteDump failed: {e}"))
+}
+
+/// Path for a new dump in `dir`, named after the current time.
+#[must_use]
+pub fn new_dump_path(dir: &Path) -> PathBuf {
+    let stamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_millis())
+        .unwrap_or_default();
+    dir.join(format!("crash-{stamp}.dmp"))
+}
+
+unsafe extern "system" fn vectored_handler(info: *mut EXCEPTION_POINTERS) -> i32 {
+    let code = info
+        .as_ref()
+        .and_then(|pointers| pointers.ExceptionRecord.as_ref())
+        .map(|record| record.ExceptionCode);
+    let is_fatal = code.is_some_and(|code| FATAL_EXCEPTIONS.contains(&code));
+    if !is_fatal || CRASHED.swap(true, Ordering::SeqCst) {
+        return EXCEPTION_CONTINUE_SEARCH;
+    }
+
+    if let Some(config) = CONFIG.get() {
+        let path = new_dump_path(&config.dump_dir);
+        if (config.hook)(&path) {
+            let _ = std::fs::create_dir_all(&config.dump_dir);
+            let _ = write_minidump(&path, info);
+        }
+    }
+    EXCEPTION_CONTINUE_SEARCH
+}
+
+/// Install the crash handler once per process. Later calls are ignored.
+///
+/// # Safety
+/// Calls Windows API functions. `hook` runs inside an exception handler and must not panic.
+pub unsafe fn install_crash_handler(dump_dir: PathBuf, hook: CrashHook) -> bool {
+    if CONFIG.set(CrashHandlerConfig { dump_dir, hook }).is_err() {
+        return false;
+    }
+    !AddVectoredExceptionHandler(1, Some(vectored_handler)).is_null()
+}
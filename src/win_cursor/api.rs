@@ -1,13 +1,21 @@
 use std::ptr::null_mut;
-use windows::Win32::Foundation::HANDLE;
+use windows::Win32::Foundation::{HANDLE, LPARAM, WPARAM};
 use windows::Win32::UI::WindowsAndMessaging::{
-    CopyImage, CreateCursor, LoadImageW, SetSystemCursor, SystemParametersInfoW, HCURSOR,
-    IMAGE_CURSOR, LR_LOADFROMFILE, SPIF_SENDCHANGE, SPI_SETCURSORS, SYSTEM_CURSOR_ID,
+    CopyImage, CreateCursor, LoadImageW, SendMessageTimeoutW, SetSystemCursor,
+    SystemParametersInfoW, HCURSOR, HWND_BROADCAST, IMAGE_CURSOR, LR_LOADFROMFILE,
+    SMTO_ABORTIFHUNG, SPIF_SENDCHANGE, SPI_SETCURSORS, SYSTEM_CURSOR_ID, WM_SETTINGCHANGE,
 };
 
 use crate::win_common::to_wide;
 
-use super::constants::{CURSOR_DIMENSION, CURSOR_IDS, CURSOR_PLANE_BYTES};
+use super::blank_roles::BlankRoleMask;
+use super::constants::{CURSOR_DIMENSION, CURSOR_IDS, CURSOR_PLANE_BYTES, EXTENDED_CURSOR_IDS};
+use super::retry::RetryPolicy;
+use super::watchdog::{RefreshError, RefreshStrategy, Watchdog};
+
+/// One watchdog per strategy, so a hung broadcast reload does not block the quiet one
+static BROADCAST_WATCHDOG: Watchdog = Watchdog::new();
+static QUIET_WATCHDOG: Watchdog = Watchdog::new();
 
 unsafe fn create_blank_cursor() -> HCURSOR {
     let and_plane = [0xFFu8; CURSOR_PLANE_BYTES];
@@ -24,20 +32,32 @@ unsafe fn create_blank_cursor() -> HCURSOR {
     ).expect("Failed to create blank cursor")
 }
 
-/// Replace common system cursors with a transparent cursor. Returns true on success.
+/// Replace the system cursors of the roles in `roles` with a transparent cursor; the other
+/// roles keep whatever cursor they have. Returns true on success.
 ///
 /// # Safety
 /// This function is unsafe because it calls Windows API functions that manipulate system cursors.
 /// The caller must ensure this is called from a valid Windows context.
 #[must_use]
-pub unsafe fn apply_blank_system_cursors() -> bool {
+pub unsafe fn apply_blank_system_cursors(roles: BlankRoleMask) -> bool {
     let mut success = true;
-    for &cursor_id in &CURSOR_IDS {
+    for &cursor_id in CURSOR_IDS.iter().filter(|&&id| roles.contains_id(id)) {
         let cursor = create_blank_cursor();
-        if cursor.is_invalid() || SetSystemCursor(cursor, SYSTEM_CURSOR_ID(cursor_id)).is_err() {
+        let applied = !cursor.is_invalid()
+            && RetryPolicy::DEFAULT.run_bool("SetSystemCursor (blank)", || {
+                SetSystemCursor(cursor, SYSTEM_CURSOR_ID(cursor_id)).is_ok()
+            });
+        if !applied {
             success = false;
         }
     }
+    // Older systems reject the extended IDs, so those stay best-effort
+    for &cursor_id in EXTENDED_CURSOR_IDS.iter().filter(|&&id| roles.contains_id(id)) {
+        let cursor = create_blank_cursor();
+        if !cursor.is_invalid() {
+            let _ = SetSystemCursor(cursor, SYSTEM_CURSOR_ID(cursor_id));
+        }
+    }
     if !success {
         let _ = restore_system_cursors();
     }
@@ -46,35 +66,103 @@ pub unsafe fn apply_blank_system_cursors() -> bool {
 
 #[must_use]
 pub unsafe fn restore_system_cursors() -> bool {
-    // First attempt: Standard approach with SPIF_SENDCHANGE only
-    let result = SystemParametersInfoW(SPI_SETCURSORS, 0, Some(null_mut()), SPIF_SENDCHANGE);
-
-    if result.is_ok() {
-        return true;
+    // Restoring is the same SPI_SETCURSORS reload, so it shares the refresh mock
+    #[cfg(any(test, feature = "e2e"))]
+    if let Some(result) = super::testing::call_refresh_cursor_settings_mock() {
+        return result;
     }
 
-    // Second attempt: Try without any flags
-    let result = SystemParametersInfoW(SPI_SETCURSORS, 0, Some(null_mut()), Default::default());
+    RetryPolicy::DEFAULT.run_bool("SPI_SETCURSORS (restore)", || {
+        // First attempt: Standard approach with SPIF_SENDCHANGE only
+        let result = SystemParametersInfoW(SPI_SETCURSORS, 0, Some(null_mut()), SPIF_SENDCHANGE);
+
+        if result.is_ok() {
+            return true;
+        }
+
+        // Second attempt: Try without any flags
+        let result = SystemParametersInfoW(SPI_SETCURSORS, 0, Some(null_mut()), Default::default());
 
-    result.is_ok()
+        result.is_ok()
+    })
 }
 
 #[must_use]
 pub unsafe fn refresh_cursor_settings() -> bool {
-    #[cfg(test)]
-    {
-        if let Some(lock) = super::testing::REFRESH_CURSOR_SETTINGS_MOCK.get() {
-            let value = lock
-                .lock()
-                .expect("refresh cursor settings mock poisoned")
-                .as_mut()
-                .map(|mock| mock());
-            if let Some(result) = value {
-                return result;
-            }
+    #[cfg(any(test, feature = "e2e"))]
+    if let Some(result) = super::testing::call_refresh_cursor_settings_mock() {
+        return result;
+    }
+    RetryPolicy::DEFAULT.run_bool("SPI_SETCURSORS (refresh)", || {
+        SystemParametersInfoW(SPI_SETCURSORS, 0, Some(null_mut()), SPIF_SENDCHANGE).is_ok()
+    })
+}
+
+/// Reload the cursor scheme like [`restore_system_cursors`] without reloading the cursor
+/// settings synchronously in every window: the reload skips `SPIF_SENDCHANGE` and the windows
+/// are told afterwards by a broadcast that skips hung ones.
+///
+/// # Safety
+/// This function is unsafe because it calls Windows API functions.
+#[must_use]
+pub unsafe fn refresh_cursor_settings_quietly() -> bool {
+    #[cfg(any(test, feature = "e2e"))]
+    if let Some(result) = super::testing::call_refresh_cursor_settings_mock() {
+        return result;
+    }
+    let reloaded = RetryPolicy::DEFAULT.run_bool("SPI_SETCURSORS (quiet)", || {
+        SystemParametersInfoW(SPI_SETCURSORS, 0, Some(null_mut()), Default::default()).is_ok()
+    });
+    if reloaded && !broadcast_cursor_setting_change() {
+        eprintln!("Cursor setting change broadcast timed out");
+    }
+    reloaded
+}
+
+/// Reload the cursor scheme from the registry with `strategy` on a worker thread, giving up
+/// after `timeout` instead of blocking the caller when `SPI_SETCURSORS` hangs.
+///
+/// # Errors
+/// See [`RefreshError`]. After a timeout, further calls with the same strategy fail with
+/// [`RefreshError::StillStuck`] until the hung call returns; [`RefreshStrategy::Quiet`] is
+/// the alternative to try.
+///
+/// # Safety
+/// This function is unsafe because it calls Windows API functions that manipulate system cursors.
+pub unsafe fn reload_system_cursors(
+    strategy: RefreshStrategy,
+    timeout: std::time::Duration,
+) -> Result<(), RefreshError> {
+    match strategy {
+        RefreshStrategy::Broadcast => {
+            BROADCAST_WATCHDOG.run_bool(strategy.label(), timeout, || restore_system_cursors())
         }
+        RefreshStrategy::Quiet => QUIET_WATCHDOG.run_bool(strategy.label(), timeout, || {
+            refresh_cursor_settings_quietly()
+        }),
     }
-    SystemParametersInfoW(SPI_SETCURSORS, 0, Some(null_mut()), SPIF_SENDCHANGE).is_ok()
+}
+
+/// Tell every top-level window that the cursor settings changed.
+///
+/// Some shells and apps keep their own cached cursor handles and only drop them when they
+/// see `WM_SETTINGCHANGE` for the cursors key. Hung windows are skipped after a short timeout.
+///
+/// # Safety
+/// This function is unsafe because it calls Windows API functions.
+#[must_use]
+pub unsafe fn broadcast_cursor_setting_change() -> bool {
+    let section = to_wide("Control Panel\\Cursors");
+    let result = SendMessageTimeoutW(
+        HWND_BROADCAST,
+        WM_SETTINGCHANGE,
+        WPARAM(SPI_SETCURSORS.0 as usize),
+        LPARAM(section.as_ptr() as isize),
+        SMTO_ABORTIFHUNG,
+        1000,
+        None,
+    );
+    result.0 != 0
 }
 
 #[must_use]
@@ -130,10 +218,13 @@ pub unsafe fn apply_cursor_from_file_with_size(file_path: &str, cursor_id: u32,
         return false;
     }
 
-    // Apply the cursor to the system
-    let result = SetSystemCursor(cursor, SYSTEM_CURSOR_ID(cursor_id));
+    // Apply the cursor to the system. The handle is only consumed on success, so it can be
+    // passed again on a retry.
+    let applied = RetryPolicy::DEFAULT.run_bool("SetSystemCursor", || {
+        SetSystemCursor(cursor, SYSTEM_CURSOR_ID(cursor_id)).is_ok()
+    });
 
-    if result.is_err() {
+    if !applied {
         eprintln!("SetSystemCursor failed for cursor ID {cursor_id}");
         return false;
     }
@@ -164,6 +255,11 @@ pub unsafe fn apply_cursor_file_with_size(file_path: &str, size: i32) -> bool {
             eprintln!("Failed to apply cursor {cursor_id} from {file_path}");
         }
     }
+    for &cursor_id in &EXTENDED_CURSOR_IDS {
+        if !apply_cursor_from_file_with_size(file_path, cursor_id, size) {
+            eprintln!("Skipped optional cursor {cursor_id}: not supported on this system");
+        }
+    }
 
     success
 }
@@ -0,0 +1,81 @@
+use super::constants::OCR_IBEAM;
+use super::cursor_types::{all_cursor_types, CURSOR_TYPES, EXTENDED_CURSOR_TYPES};
+
+/// Set of cursor roles that hiding replaces with the blank cursor, one bit per entry of
+/// [`all_cursor_types`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BlankRoleMask(u32);
+
+/// Bit of the role with `id`, or 0 for an unknown ID.
+const fn role_bit(id: u32) -> u32 {
+    let mut i = 0;
+    while i < CURSOR_TYPES.len() {
+        if CURSOR_TYPES[i].id == id {
+            return 1 << i;
+        }
+        i += 1;
+    }
+    let mut j = 0;
+    while j < EXTENDED_CURSOR_TYPES.len() {
+        if EXTENDED_CURSOR_TYPES[j].id == id {
+            return 1 << (CURSOR_TYPES.len() + j);
+        }
+        j += 1;
+    }
+    0
+}
+
+impl BlankRoleMask {
+    /// Every role: the strict "hide everything" behavior.
+    pub const ALL: Self = Self((1 << (CURSOR_TYPES.len() + EXTENDED_CURSOR_TYPES.len())) - 1);
+    /// Every role except the text-select cursor, so the caret position in a text field stays
+    /// visible while the pointer is hidden.
+    pub const DEFAULT: Self = Self::ALL.without_id(OCR_IBEAM);
+    pub const NONE: Self = Self(0);
+
+    #[must_use]
+    pub const fn bits(self) -> u32 {
+        self.0
+    }
+
+    /// Mask from raw [`bits`](Self::bits); bits of unknown roles are dropped.
+    #[must_use]
+    pub const fn from_bits(bits: u32) -> Self {
+        Self(bits & Self::ALL.0)
+    }
+
+    #[must_use]
+    pub const fn contains_id(self, id: u32) -> bool {
+        let bit = role_bit(id);
+        bit != 0 && self.0 & bit != 0
+    }
+
+    #[must_use]
+    pub const fn without_id(self, id: u32) -> Self {
+        Self(self.0 & !role_bit(id))
+    }
+
+    /// Every role except the named ones (cursor names such as `IBeam`). Unknown names are
+    /// ignored.
+    #[must_use]
+    pub fn all_except<'a>(names: impl IntoIterator<Item = &'a str>) -> Self {
+        names.into_iter().fold(Self::ALL, |mask, name| {
+            all_cursor_types()
+                .find(|ct| ct.name == name)
+                .map_or(mask, |ct| mask.without_id(ct.id))
+        })
+    }
+
+    /// Names of the roles in the mask, in [`all_cursor_types`] order.
+    pub fn names(self) -> impl Iterator<Item = &'static str> {
+        all_cursor_types()
+            .filter(move |ct| self.contains_id(ct.id))
+            .map(|ct| ct.name)
+    }
+}
+
+impl Default for BlankRoleMask {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
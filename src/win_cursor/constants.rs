@@ -17,6 +17,9 @@ pub(crate) const OCR_HAND: u32 = 32649;
 pub(crate) const OCR_APPSTARTING: u32 = 32650;
 pub(crate) const OCR_HELP: u32 = 32651;
 pub(crate) const OCR_PEN: u32 = 32631;
+/// `IDC_PIN` and `IDC_PERSON`, added in Windows 10 1709
+pub(crate) const OCR_PIN: u32 = 32671;
+pub(crate) const OCR_PERSON: u32 = 32672;
 
 pub(crate) const CURSOR_IDS: [u32; 15] = [
     OCR_NORMAL,
@@ -35,3 +38,7 @@ pub(crate) const CURSOR_IDS: [u32; 15] = [
     OCR_HELP,
     OCR_PEN,
 ];
+
+/// Roles only newer Windows versions have. Failing to set one is not an error, since older
+/// systems don't know the ID.
+pub(crate) const EXTENDED_CURSOR_IDS: [u32; 2] = [OCR_PIN, OCR_PERSON];
@@ -1,6 +1,7 @@
 use super::constants::{
     OCR_APPSTARTING, OCR_CROSS, OCR_HAND, OCR_HELP, OCR_IBEAM, OCR_NO, OCR_NORMAL, OCR_PEN,
-    OCR_SIZEALL, OCR_SIZENESW, OCR_SIZENS, OCR_SIZENWSE, OCR_SIZEWE, OCR_UP, OCR_WAIT,
+    OCR_PERSON, OCR_PIN, OCR_SIZEALL, OCR_SIZENESW, OCR_SIZENS, OCR_SIZENWSE, OCR_SIZEWE, OCR_UP,
+    OCR_WAIT,
 };
 
 /// Metadata for a customizable cursor type
@@ -104,3 +105,39 @@ pub const CURSOR_TYPES: [CursorType; 15] = [
         display_name: "Pen",
     },
 ];
+
+/// Roles added in Windows 10 1709 ("Location select" and "Person select"). Schemes may leave
+/// them unset, and packs don't have to include them.
+pub const EXTENDED_CURSOR_TYPES: [CursorType; 2] = [
+    CursorType {
+        id: OCR_PIN,
+        name: "Pin",
+        registry_key: "Pin",
+        display_name: "Location select",
+    },
+    CursorType {
+        id: OCR_PERSON,
+        name: "Person",
+        registry_key: "Person",
+        display_name: "Person select",
+    },
+];
+
+impl CursorType {
+    /// Whether this is one of the optional [`EXTENDED_CURSOR_TYPES`].
+    #[must_use]
+    pub fn is_extended(&self) -> bool {
+        EXTENDED_CURSOR_TYPES.iter().any(|ct| ct.id == self.id)
+    }
+}
+
+/// The core [`CURSOR_TYPES`] followed by the [`EXTENDED_CURSOR_TYPES`].
+pub fn all_cursor_types() -> impl Iterator<Item = &'static CursorType> {
+    CURSOR_TYPES.iter().chain(EXTENDED_CURSOR_TYPES.iter())
+}
+
+/// Look up a core or extended cursor type by name.
+#[must_use]
+pub fn find_cursor_type(name: &str) -> Option<&'static CursorType> {
+    all_cursor_types().find(|ct| ct.name == name)
+}
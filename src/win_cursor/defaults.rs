@@ -21,6 +21,122 @@ pub const DEFAULT_CURSOR_BASE_NAMES: [(&str, &str); 15] = [
     ("Pen", "pen"),
 ];
 
+/// Default bundle base names for the optional extended roles (Windows 10 1709+).
+pub const EXTENDED_CURSOR_BASE_NAMES: [(&str, &str); 2] =
+    [("Pin", "location-select"), ("Person", "person-select")];
+
+/// Files the built-in "Windows Default" (aero) scheme uses in `%WINDIR%\Cursors`.
+/// Roles missing here (Text, Precision) are drawn by Windows itself.
+pub const AERO_CURSOR_FILES: [(&str, &str); 15] = [
+    ("Normal", "aero_arrow.cur"),
+    ("Hand", "aero_link.cur"),
+    ("Wait", "aero_busy.ani"),
+    ("SizeNS", "aero_ns.cur"),
+    ("SizeWE", "aero_ew.cur"),
+    ("SizeNWSE", "aero_nwse.cur"),
+    ("SizeNESW", "aero_nesw.cur"),
+    ("SizeAll", "aero_move.cur"),
+    ("Help", "aero_helpsel.cur"),
+    ("No", "aero_unavail.cur"),
+    ("AppStarting", "aero_working.ani"),
+    ("Up", "aero_up.cur"),
+    ("Pen", "aero_pen.cur"),
+    ("Pin", "aero_pin.cur"),
+    ("Person", "aero_person.cur"),
+];
+
+/// Files the "Windows Black" scheme uses in `%WINDIR%\Cursors`.
+pub const BLACK_CURSOR_FILES: [(&str, &str); 14] = [
+    ("Normal", "arrow_r.cur"),
+    ("IBeam", "beam_r.cur"),
+    ("Wait", "busy_r.cur"),
+    ("SizeNS", "size4_r.cur"),
+    ("SizeWE", "size3_r.cur"),
+    ("SizeNWSE", "size2_r.cur"),
+    ("SizeNESW", "size1_r.cur"),
+    ("SizeAll", "move_r.cur"),
+    ("Help", "help_r.cur"),
+    ("No", "no_r.cur"),
+    ("AppStarting", "wait_r.cur"),
+    ("Up", "up_r.cur"),
+    ("Cross", "cross_r.cur"),
+    ("Pen", "pen_r.cur"),
+];
+
+/// Files the "Windows Inverted" scheme uses in `%WINDIR%\Cursors`.
+pub const INVERTED_CURSOR_FILES: [(&str, &str); 14] = [
+    ("Normal", "arrow_i.cur"),
+    ("IBeam", "beam_i.cur"),
+    ("Wait", "busy_i.cur"),
+    ("SizeNS", "size4_i.cur"),
+    ("SizeWE", "size3_i.cur"),
+    ("SizeNWSE", "size2_i.cur"),
+    ("SizeNESW", "size1_i.cur"),
+    ("SizeAll", "move_i.cur"),
+    ("Help", "help_i.cur"),
+    ("No", "no_i.cur"),
+    ("AppStarting", "wait_i.cur"),
+    ("Up", "up_i.cur"),
+    ("Cross", "cross_i.cur"),
+    ("Pen", "pen_i.cur"),
+];
+
+/// Files the "Magnified" scheme uses in `%WINDIR%\Cursors`.
+/// It has no Link, Alternate or Handwriting cursors.
+pub const MAGNIFIED_CURSOR_FILES: [(&str, &str); 12] = [
+    ("Normal", "larrow.cur"),
+    ("IBeam", "lbeam.cur"),
+    ("Wait", "lbusy.cur"),
+    ("SizeNS", "lns.cur"),
+    ("SizeWE", "lwe.cur"),
+    ("SizeNWSE", "lnwse.cur"),
+    ("SizeNESW", "lnesw.cur"),
+    ("SizeAll", "lmove.cur"),
+    ("Help", "lhelp.cur"),
+    ("No", "lnodrop.cur"),
+    ("AppStarting", "lappstrt.cur"),
+    ("Cross", "lcross.cur"),
+];
+
+/// Cursor schemes that ship with Windows in `%WINDIR%\Cursors`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SystemCursorScheme {
+    /// "Windows Default"
+    Aero,
+    Black,
+    Inverted,
+    Magnified,
+}
+
+impl SystemCursorScheme {
+    pub const ALL: [SystemCursorScheme; 4] = [
+        SystemCursorScheme::Aero,
+        SystemCursorScheme::Black,
+        SystemCursorScheme::Inverted,
+        SystemCursorScheme::Magnified,
+    ];
+
+    /// `(cursor type name, file name)` pairs for the scheme.
+    #[must_use]
+    pub fn files(self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Self::Aero => &AERO_CURSOR_FILES,
+            Self::Black => &BLACK_CURSOR_FILES,
+            Self::Inverted => &INVERTED_CURSOR_FILES,
+            Self::Magnified => &MAGNIFIED_CURSOR_FILES,
+        }
+    }
+
+    /// File name the scheme uses for a cursor type, if it has one.
+    #[must_use]
+    pub fn file_name(self, cursor_name: &str) -> Option<&'static str> {
+        self.files()
+            .iter()
+            .find(|(name, _)| *name == cursor_name)
+            .map(|(_, file_name)| *file_name)
+    }
+}
+
 /// Supported cursor file extensions in order of preference
 pub const CURSOR_EXTENSIONS: [&str; 2] = ["ani", "cur"];
 
@@ -30,6 +146,7 @@ pub const CURSOR_EXTENSIONS: [&str; 2] = ["ani", "cur"];
 pub fn get_default_cursor_base_name(cursor_name: &str) -> Option<&'static str> {
     DEFAULT_CURSOR_BASE_NAMES
         .iter()
+        .chain(EXTENDED_CURSOR_BASE_NAMES.iter())
         .find(|(name, _)| *name == cursor_name)
         .map(|(_, base_name)| *base_name)
 }
@@ -57,6 +174,18 @@ pub fn find_default_cursor_in_dir(
         .and_then(|base_name| find_cursor_file_in_dir(dir, base_name))
 }
 
+/// Find the file one of Windows' own schemes uses for a cursor type.
+/// Returns None if the scheme has no file for it or the file is missing.
+#[must_use]
+pub fn find_system_cursor_file(
+    cursor_name: &str,
+    scheme: SystemCursorScheme,
+) -> Option<std::path::PathBuf> {
+    let file_name = scheme.file_name(cursor_name)?;
+    let path = std::path::Path::new(&get_windows_cursors_folder()?).join(file_name);
+    path.exists().then_some(path)
+}
+
 /// Get the Windows Cursors folder path dynamically (e.g., C:\Windows\Cursors or D:\Windows\Cursors).
 /// Works regardless of which drive Windows is installed on.
 /// Returns None if unable to determine the path.
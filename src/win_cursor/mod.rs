@@ -1,39 +1,62 @@
 mod api;
+mod blank_roles;
 mod constants;
 mod cursor_types;
 mod defaults;
 mod paths;
 mod registry;
+mod retry;
 mod toggle;
+mod verify;
+mod watchdog;
 
-#[cfg(test)]
+#[cfg(any(test, feature = "e2e"))]
 mod testing;
 
 pub use api::{
     apply_blank_system_cursors, apply_cursor_file_with_size, apply_cursor_from_file_with_size,
-    refresh_cursor_settings, restore_system_cursors,
+    broadcast_cursor_setting_change, refresh_cursor_settings, refresh_cursor_settings_quietly,
+    reload_system_cursors, restore_system_cursors,
 };
 
-pub use cursor_types::{CursorType, CURSOR_TYPES};
+pub use blank_roles::BlankRoleMask;
+
+pub use cursor_types::{
+    all_cursor_types, find_cursor_type, CursorType, CURSOR_TYPES, EXTENDED_CURSOR_TYPES,
+};
 
 pub use defaults::{
-    find_cursor_file_in_dir, find_default_cursor_in_dir, get_default_cursor_base_name,
-    get_windows_cursors_folder, CURSOR_EXTENSIONS, DEFAULT_CURSOR_BASE_NAMES,
+    find_cursor_file_in_dir, find_default_cursor_in_dir, find_system_cursor_file,
+    get_default_cursor_base_name, get_windows_cursors_folder, SystemCursorScheme,
+    AERO_CURSOR_FILES, BLACK_CURSOR_FILES, CURSOR_EXTENSIONS, DEFAULT_CURSOR_BASE_NAMES,
+    EXTENDED_CURSOR_BASE_NAMES, INVERTED_CURSOR_FILES, MAGNIFIED_CURSOR_FILES,
 };
 
+pub use retry::RetryPolicy;
+
 pub use toggle::{perform_toggle, toggle_action, SystemApi, ToggleAction};
 
+pub use watchdog::{RefreshError, RefreshStrategy, Watchdog, REFRESH_TIMEOUT};
+
+pub use verify::{
+    cursor_file_hash, diff_cursor_registry_entries, live_cursor_hash,
+    verify_cursor_registry_entries,
+};
+
 #[cfg(test)]
 pub(crate) use constants::{CURSOR_DIMENSION, CURSOR_IDS, CURSOR_PLANE_BYTES};
 
 pub use registry::{
     clear_cursor_registry_entries, read_cursor_image_from_registry,
     restore_cursor_registry_entries, snapshot_cursor_registry_entries,
-    write_cursor_image_to_registry,
+    validate_cursor_registry_value_name, write_cursor_image_to_registry, RegistryWriteError,
 };
 
-#[cfg(test)]
+#[cfg(any(test, feature = "e2e"))]
 pub use testing::{
     clear_refresh_cursor_settings_mock, set_refresh_cursor_settings_mock,
     set_test_cursor_registry_path,
 };
+
+#[cfg(feature = "e2e")]
+pub use testing::{TestCursorSession, TestSessionMode};
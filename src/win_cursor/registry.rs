@@ -1,9 +1,63 @@
 use winreg::RegKey;
 
-use super::cursor_types::{CursorType, CURSOR_TYPES};
+use super::constants::{CURSOR_IDS, EXTENDED_CURSOR_IDS};
+use super::cursor_types::{all_cursor_types, CursorType};
+use super::retry::RetryPolicy;
+
+/// Why a cursor registry write was refused or failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RegistryWriteError {
+    /// The value name is not one of the cursor roles in [`CURSOR_TYPES`] or
+    /// [`EXTENDED_CURSOR_TYPES`].
+    ///
+    /// [`CURSOR_TYPES`]: super::CURSOR_TYPES
+    /// [`EXTENDED_CURSOR_TYPES`]: super::EXTENDED_CURSOR_TYPES
+    UnexpectedValueName(String),
+    OpenKey(String),
+    WriteValue { value_name: String, message: String },
+}
+
+impl std::fmt::Display for RegistryWriteError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnexpectedValueName(name) => {
+                write!(f, "Refusing to write unexpected cursor registry value '{name}'")
+            }
+            Self::OpenKey(message) => write!(f, "Failed to open registry key for writing: {message}"),
+            Self::WriteValue {
+                value_name,
+                message,
+            } => write!(f, "Failed to write registry value for {value_name}: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for RegistryWriteError {}
+
+/// Check that `value_name` is the registry value of a known cursor role.
+///
+/// `CursorType` has public fields, so a caller (or pack metadata feeding one) could build a
+/// type with any value name; only the names from [`CURSOR_TYPES`] and
+/// [`EXTENDED_CURSOR_TYPES`] whose IDs are in `CURSOR_IDS` or `EXTENDED_CURSOR_IDS` may be
+/// written.
+///
+/// [`CURSOR_TYPES`]: super::CURSOR_TYPES
+/// [`EXTENDED_CURSOR_TYPES`]: super::EXTENDED_CURSOR_TYPES
+pub fn validate_cursor_registry_value_name(value_name: &str) -> Result<(), RegistryWriteError> {
+    let allowed = all_cursor_types().any(|ct| {
+        ct.registry_key == value_name
+            && (CURSOR_IDS.contains(&ct.id) || EXTENDED_CURSOR_IDS.contains(&ct.id))
+    });
+    if allowed {
+        Ok(())
+    } else {
+        eprintln!("Rejected write to unexpected cursor registry value {value_name:?}");
+        Err(RegistryWriteError::UnexpectedValueName(value_name.to_string()))
+    }
+}
 
 fn cursor_registry_subkey() -> String {
-    #[cfg(test)]
+    #[cfg(any(test, feature = "e2e"))]
     {
         if let Some(lock) = super::testing::TEST_CURSOR_REGISTRY_PATH.get() {
             let value = lock.lock().expect("test registry mutex poisoned").clone();
@@ -25,36 +79,53 @@ pub fn read_cursor_image_from_registry(cursor_type: &CursorType) -> Option<Strin
 }
 
 /// Write a cursor image path to the Windows Registry for a specific cursor type.
-/// Returns true on success. Automatically refreshes cursor settings
-/// to apply the change immediately.
-pub fn write_cursor_image_to_registry(cursor_type: &CursorType, image_path: &str) -> bool {
+/// Automatically refreshes cursor settings to apply the change immediately.
+///
+/// # Errors
+/// [`RegistryWriteError::UnexpectedValueName`] if `cursor_type` is not a known cursor role
+/// (see [`validate_cursor_registry_value_name`]), otherwise the registry failure.
+pub fn write_cursor_image_to_registry(
+    cursor_type: &CursorType,
+    image_path: &str,
+) -> Result<(), RegistryWriteError> {
     use winreg::enums::KEY_WRITE;
-    let hkcu = RegKey::predef(winreg::enums::HKEY_CURRENT_USER);
-    let cursors = match hkcu.open_subkey_with_flags(cursor_registry_subkey(), KEY_WRITE) {
-        Ok(key) => key,
-        Err(e) => {
-            eprintln!("Failed to open registry key for writing: {e:?}");
-            return false;
-        }
-    };
-
-    let write_success = cursors
-        .set_value(cursor_type.registry_key, &image_path)
-        .is_ok();
-
-    if write_success {
-        // Notify Windows to refresh cursor settings to apply the change immediately
-        unsafe {
-            let _ = super::api::refresh_cursor_settings();
-        }
-        true
-    } else {
-        eprintln!(
-            "Failed to write registry value for {}",
-            cursor_type.registry_key
+    validate_cursor_registry_value_name(cursor_type.registry_key)?;
+
+    RetryPolicy::DEFAULT.run(
+        &format!("Registry write of {}", cursor_type.registry_key),
+        || {
+            let hkcu = RegKey::predef(winreg::enums::HKEY_CURRENT_USER);
+            let cursors = hkcu
+                .open_subkey_with_flags(cursor_registry_subkey(), KEY_WRITE)
+                .map_err(|e| {
+                    eprintln!("Failed to open registry key for writing: {e:?}");
+                    RegistryWriteError::OpenKey(e.to_string())
+                })?;
+
+            cursors
+                .set_value(cursor_type.registry_key, &image_path)
+                .map_err(|e| {
+                    eprintln!(
+                        "Failed to write registry value for {}",
+                        cursor_type.registry_key
+                    );
+                    RegistryWriteError::WriteValue {
+                        value_name: cursor_type.registry_key.to_string(),
+                        message: e.to_string(),
+                    }
+                })
+        },
+    )?;
+
+    // Notify Windows to refresh cursor settings to apply the change immediately, without
+    // hanging on a window that never answers
+    unsafe {
+        let _ = super::api::reload_system_cursors(
+            super::watchdog::RefreshStrategy::Broadcast,
+            super::watchdog::REFRESH_TIMEOUT,
         );
-        false
     }
+    Ok(())
 }
 
 /// Clear all cursor registry entries to reset to Windows system defaults.
@@ -67,11 +138,17 @@ pub fn clear_cursor_registry_entries() -> bool {
     use winreg::enums::KEY_WRITE;
     let hkcu = RegKey::predef(winreg::enums::HKEY_CURRENT_USER);
 
-    match hkcu.open_subkey_with_flags(cursor_registry_subkey(), KEY_WRITE) {
+    match RetryPolicy::DEFAULT.run("Opening the cursors registry key", || {
+        hkcu.open_subkey_with_flags(cursor_registry_subkey(), KEY_WRITE)
+    }) {
         Ok(cursors_key) => {
-            for cursor_type in &CURSOR_TYPES {
+            for cursor_type in all_cursor_types() {
                 // Set to empty string to reset to system default
-                if let Err(e) = cursors_key.set_value(cursor_type.registry_key, &"") {
+                let cleared = RetryPolicy::DEFAULT.run(
+                    &format!("Registry clear of {}", cursor_type.registry_key),
+                    || cursors_key.set_value(cursor_type.registry_key, &""),
+                );
+                if let Err(e) = cleared {
                     eprintln!(
                         "Warning: Failed to clear registry value for {}: {:?}",
                         cursor_type.registry_key, e
@@ -100,7 +177,7 @@ pub fn snapshot_cursor_registry_entries() -> std::collections::HashMap<String, O
         }
     };
 
-    for cursor_type in &CURSOR_TYPES {
+    for cursor_type in all_cursor_types() {
         let value: Result<String, _> = cursors.get_value(cursor_type.registry_key);
         snapshot.insert(cursor_type.registry_key.to_string(), value.ok());
     }
@@ -115,7 +192,9 @@ pub fn restore_cursor_registry_entries(
     use winreg::enums::KEY_WRITE;
 
     let hkcu = RegKey::predef(winreg::enums::HKEY_CURRENT_USER);
-    let cursors_key = match hkcu.open_subkey_with_flags(cursor_registry_subkey(), KEY_WRITE) {
+    let cursors_key = match RetryPolicy::DEFAULT.run("Opening the cursors registry key", || {
+        hkcu.open_subkey_with_flags(cursor_registry_subkey(), KEY_WRITE)
+    }) {
         Ok(key) => key,
         Err(e) => {
             eprintln!("Failed to open registry key for writing: {e:?}");
@@ -124,10 +203,14 @@ pub fn restore_cursor_registry_entries(
     };
 
     let mut success = true;
-    for cursor_type in &CURSOR_TYPES {
+    for cursor_type in all_cursor_types() {
         match snapshot.get(cursor_type.registry_key) {
             Some(Some(value)) => {
-                if let Err(e) = cursors_key.set_value(cursor_type.registry_key, value) {
+                let restored = RetryPolicy::DEFAULT.run(
+                    &format!("Registry restore of {}", cursor_type.registry_key),
+                    || cursors_key.set_value(cursor_type.registry_key, value),
+                );
+                if let Err(e) = restored {
                     eprintln!(
                         "Warning: Failed to restore registry value for {}: {:?}",
                         cursor_type.registry_key, e
@@ -0,0 +1,103 @@
+//! Bounded retries for transient registry and `SystemParametersInfo` failures.
+//!
+//! Cursor writes occasionally fail for a moment, e.g. while another process holds the cursors
+//! key or during the burst of activity at logon. The apply and restore paths retry with
+//! exponential backoff plus jitter and log how many attempts were needed.
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Total attempts, including the first
+    pub max_attempts: u32,
+    /// Delay before the first retry; doubled for each further retry
+    pub initial_delay: Duration,
+    pub max_delay: Duration,
+}
+
+impl RetryPolicy {
+    /// Policy used by the apply and restore paths: four attempts, 25 ms doubling up to 200 ms.
+    pub const DEFAULT: Self = Self {
+        max_attempts: 4,
+        initial_delay: Duration::from_millis(25),
+        max_delay: Duration::from_millis(200),
+    };
+
+    /// A single attempt, for callers that handle failure themselves.
+    pub const NONE: Self = Self {
+        max_attempts: 1,
+        initial_delay: Duration::ZERO,
+        max_delay: Duration::ZERO,
+    };
+
+    /// Backoff before retry number `retry` (1-based), before jitter is applied.
+    #[must_use]
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let factor = 1u32
+            .checked_shl(retry.saturating_sub(1))
+            .unwrap_or(u32::MAX);
+        self.initial_delay
+            .saturating_mul(factor)
+            .min(self.max_delay)
+    }
+
+    /// Run `op` until it succeeds or the attempts run out, returning the last error.
+    /// `label` names the operation in the log.
+    ///
+    /// # Errors
+    /// The error of the final attempt.
+    pub fn run<T, E: std::fmt::Display>(
+        &self,
+        label: &str,
+        mut op: impl FnMut() -> Result<T, E>,
+    ) -> Result<T, E> {
+        let attempts = self.max_attempts.max(1);
+        let mut attempt = 1;
+        loop {
+            match op() {
+                Ok(value) => {
+                    if attempt > 1 {
+                        eprintln!("{label} succeeded after {attempt} attempts");
+                    }
+                    return Ok(value);
+                }
+                Err(e) if attempt < attempts => {
+                    let delay = jittered(self.backoff(attempt));
+                    eprintln!(
+                        "{label} failed (attempt {attempt}/{attempts}): {e}; retrying in {} ms",
+                        delay.as_millis()
+                    );
+                    std::thread::sleep(delay);
+                    attempt += 1;
+                }
+                Err(e) => {
+                    if attempts > 1 {
+                        eprintln!("{label} failed after {attempts} attempts: {e}");
+                    }
+                    return Err(e);
+                }
+            }
+        }
+    }
+
+    /// [`run`](Self::run) for Windows calls that only report success as a `bool`.
+    pub fn run_bool(&self, label: &str, mut op: impl FnMut() -> bool) -> bool {
+        self.run(label, || if op() { Ok(()) } else { Err("call failed") })
+            .is_ok()
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Somewhere between half and all of `delay`, so processes retrying at the same moment
+/// (several apps at logon) spread out instead of colliding again.
+fn jittered(delay: Duration) -> Duration {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.subsec_nanos());
+    delay.mul_f64(0.5 + f64::from(nanos % 1000) / 2000.0)
+}
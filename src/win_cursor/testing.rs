@@ -1,17 +1,18 @@
-#[cfg(test)]
+//! Test hooks for the registry and `SPI_SETCURSORS` layers.
+//!
+//! Unit tests use the overrides directly. With the `e2e` feature, [`TestCursorSession`]
+//! bundles them into a scratch registry key plus a recording SPI mock so the real
+//! apply → verify → restore path can run end to end without touching the user's cursors.
+
 use std::sync::{Mutex, OnceLock};
 
-#[cfg(test)]
 pub(crate) static TEST_CURSOR_REGISTRY_PATH: OnceLock<Mutex<Option<String>>> = OnceLock::new();
 
-#[cfg(test)]
 pub(crate) type RefreshCursorMock = Box<dyn FnMut() -> bool + Send + 'static>;
 
-#[cfg(test)]
 pub(crate) static REFRESH_CURSOR_SETTINGS_MOCK: OnceLock<Mutex<Option<RefreshCursorMock>>> =
     OnceLock::new();
 
-#[cfg(test)]
 /// # Panics
 /// May panic if the test registry mutex is poisoned.
 pub fn set_test_cursor_registry_path(path: Option<String>) {
@@ -19,7 +20,6 @@ pub fn set_test_cursor_registry_path(path: Option<String>) {
     *lock.lock().expect("test registry mutex poisoned") = path;
 }
 
-#[cfg(test)]
 /// # Panics
 /// May panic if the mock mutex is poisoned.
 pub fn set_refresh_cursor_settings_mock<F>(mock: F)
@@ -30,7 +30,6 @@ where
     *lock.lock().expect("refresh cursor settings mock poisoned") = Some(Box::new(mock));
 }
 
-#[cfg(test)]
 /// # Panics
 /// May panic if the mock mutex is poisoned.
 pub fn clear_refresh_cursor_settings_mock() {
@@ -38,3 +37,158 @@ pub fn clear_refresh_cursor_settings_mock() {
         *lock.lock().expect("refresh cursor settings mock poisoned") = None;
     }
 }
+
+/// Result of the installed refresh mock, or None when no mock is installed.
+pub(crate) fn call_refresh_cursor_settings_mock() -> Option<bool> {
+    let lock = REFRESH_CURSOR_SETTINGS_MOCK.get()?;
+    lock.lock()
+        .expect("refresh cursor settings mock poisoned")
+        .as_mut()
+        .map(|mock| mock())
+}
+
+#[cfg(feature = "e2e")]
+pub use session::{TestCursorSession, TestSessionMode};
+
+#[cfg(feature = "e2e")]
+mod session {
+    use std::collections::HashMap;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex, MutexGuard};
+
+    use winreg::enums::HKEY_CURRENT_USER;
+    use winreg::RegKey;
+
+    use super::{
+        clear_refresh_cursor_settings_mock, set_refresh_cursor_settings_mock,
+        set_test_cursor_registry_path,
+    };
+    use crate::win_cursor::registry::{
+        restore_cursor_registry_entries, snapshot_cursor_registry_entries,
+    };
+
+    /// Set to `1` inside a dedicated, throwaway Windows session to run against the real
+    /// `Control Panel\Cursors` key and `SystemParametersInfoW`.
+    pub const LIVE_SESSION_ENV: &str = "CURSOR_CHANGER_E2E_LIVE";
+
+    const SCRATCH_ROOT: &str = "Software\\cursor-changer-e2e";
+
+    /// The overrides are process-wide, so sessions must not overlap.
+    static SESSION_LOCK: Mutex<()> = Mutex::new(());
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum TestSessionMode {
+        /// Scratch registry key and a recording SPI mock. Safe on any desktop.
+        Isolated,
+        /// Real registry key and SPI calls; the cursor registry is snapshotted at start
+        /// and restored when the session ends.
+        Live,
+    }
+
+    /// One end-to-end test run. Dropping it restores the registry and removes the overrides.
+    pub struct TestCursorSession {
+        mode: TestSessionMode,
+        scratch_key: Option<String>,
+        live_snapshot: Option<HashMap<String, Option<String>>>,
+        spi_calls: Arc<AtomicUsize>,
+        _guard: MutexGuard<'static, ()>,
+    }
+
+    impl TestCursorSession {
+        /// Start a session in the mode selected by [`LIVE_SESSION_ENV`].
+        ///
+        /// # Panics
+        /// Panics if the scratch registry key cannot be created.
+        #[must_use]
+        pub fn start() -> Self {
+            let live = std::env::var(LIVE_SESSION_ENV).is_ok_and(|v| v == "1");
+            Self::start_with_mode(if live {
+                TestSessionMode::Live
+            } else {
+                TestSessionMode::Isolated
+            })
+        }
+
+        /// # Panics
+        /// Panics if the scratch registry key cannot be created.
+        #[must_use]
+        pub fn start_with_mode(mode: TestSessionMode) -> Self {
+            // A failed test poisons the lock; the next session still cleans up after itself
+            let guard = SESSION_LOCK
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner);
+            let spi_calls = Arc::new(AtomicUsize::new(0));
+
+            match mode {
+                TestSessionMode::Isolated => {
+                    let nanos = std::time::SystemTime::now()
+                        .duration_since(std::time::UNIX_EPOCH)
+                        .map(|d| d.as_nanos())
+                        .unwrap_or_default();
+                    let scratch_key =
+                        format!("{SCRATCH_ROOT}\\{}-{nanos}\\Cursors", std::process::id());
+                    RegKey::predef(HKEY_CURRENT_USER)
+                        .create_subkey(&scratch_key)
+                        .expect("create scratch cursor registry key");
+                    set_test_cursor_registry_path(Some(scratch_key.clone()));
+
+                    let counter = Arc::clone(&spi_calls);
+                    set_refresh_cursor_settings_mock(move || {
+                        counter.fetch_add(1, Ordering::SeqCst);
+                        true
+                    });
+
+                    Self {
+                        mode,
+                        scratch_key: Some(scratch_key),
+                        live_snapshot: None,
+                        spi_calls,
+                        _guard: guard,
+                    }
+                }
+                TestSessionMode::Live => Self {
+                    mode,
+                    scratch_key: None,
+                    live_snapshot: Some(snapshot_cursor_registry_entries()),
+                    spi_calls,
+                    _guard: guard,
+                },
+            }
+        }
+
+        #[must_use]
+        pub const fn mode(&self) -> TestSessionMode {
+            self.mode
+        }
+
+        /// `SPI_SETCURSORS` reloads seen by the mock. Always 0 in live mode.
+        #[must_use]
+        pub fn spi_calls(&self) -> usize {
+            self.spi_calls.load(Ordering::SeqCst)
+        }
+    }
+
+    impl Drop for TestCursorSession {
+        fn drop(&mut self) {
+            if let Some(snapshot) = self.live_snapshot.take() {
+                if !restore_cursor_registry_entries(&snapshot) {
+                    eprintln!("e2e: failed to restore the cursor registry snapshot");
+                }
+                unsafe {
+                    let _ = crate::win_cursor::api::restore_system_cursors();
+                }
+            }
+
+            clear_refresh_cursor_settings_mock();
+            set_test_cursor_registry_path(None);
+
+            if let Some(scratch_key) = self.scratch_key.take() {
+                // Remove the per-session parent of `...\Cursors`
+                let session_key = scratch_key.trim_end_matches("\\Cursors");
+                if let Err(e) = RegKey::predef(HKEY_CURRENT_USER).delete_subkey_all(session_key) {
+                    eprintln!("e2e: failed to delete scratch key {session_key}: {e:?}");
+                }
+            }
+        }
+    }
+}
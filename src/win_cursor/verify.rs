@@ -0,0 +1,122 @@
+//! Read-back checks used to confirm that a cursor change actually took effect.
+//!
+//! On locked-down systems (group policy, kiosk shells) `SetSystemCursor` and registry writes
+//! can report success while changing nothing. These helpers fingerprint cursor bitmaps and
+//! compare registry values so callers can detect such silent no-ops.
+
+use std::collections::HashMap;
+
+use windows::core::PCWSTR;
+use windows::Win32::Graphics::Gdi::{DeleteObject, GetBitmapBits, GetObjectW, BITMAP, HBITMAP, HGDIOBJ};
+use windows::Win32::UI::WindowsAndMessaging::{
+    DestroyCursor, GetIconInfo, LoadCursorW, LoadImageW, HCURSOR, HICON, ICONINFO, IMAGE_CURSOR,
+    LR_LOADFROMFILE,
+};
+
+use crate::win_common::to_wide;
+
+use super::registry::snapshot_cursor_registry_entries;
+
+fn fnv1a(hash: &mut u64, bytes: &[u8]) {
+    for byte in bytes {
+        *hash ^= u64::from(*byte);
+        *hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+}
+
+unsafe fn hash_bitmap(hash: &mut u64, bitmap: HBITMAP) {
+    if bitmap.is_invalid() {
+        return;
+    }
+    let mut info = BITMAP::default();
+    #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+    let got = GetObjectW(
+        HGDIOBJ(bitmap.0),
+        std::mem::size_of::<BITMAP>() as i32,
+        Some((&raw mut info).cast()),
+    );
+    if got != 0 {
+        #[allow(clippy::cast_sign_loss)]
+        let len = (info.bmWidthBytes * info.bmHeight).max(0) as usize;
+        let mut bits = vec![0u8; len];
+        #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+        let copied = GetBitmapBits(bitmap, len as i32, bits.as_mut_ptr().cast());
+        #[allow(clippy::cast_sign_loss)]
+        bits.truncate(copied.max(0) as usize);
+        fnv1a(hash, &bits);
+    }
+    let _ = DeleteObject(HGDIOBJ(bitmap.0));
+}
+
+/// Fingerprint of a cursor's hotspot, mask and color bitmaps.
+unsafe fn cursor_hash(cursor: HCURSOR) -> Option<u64> {
+    let mut info = ICONINFO::default();
+    GetIconInfo(HICON(cursor.0), &raw mut info).ok()?;
+
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    fnv1a(&mut hash, &info.xHotspot.to_le_bytes());
+    fnv1a(&mut hash, &info.yHotspot.to_le_bytes());
+    // GetIconInfo hands us copies of both bitmaps; hash_bitmap frees them
+    hash_bitmap(&mut hash, info.hbmMask);
+    hash_bitmap(&mut hash, info.hbmColor);
+    Some(hash)
+}
+
+/// Fingerprint of the cursor Windows is currently using for `cursor_id` (an `OCR_*` value).
+///
+/// # Safety
+/// Calls Windows API functions.
+#[must_use]
+pub unsafe fn live_cursor_hash(cursor_id: u32) -> Option<u64> {
+    // OCR_* ids double as the IDC_* resource ids of the shared system cursors
+    let cursor = LoadCursorW(None, PCWSTR(cursor_id as usize as *const u16)).ok()?;
+    cursor_hash(cursor)
+}
+
+/// Fingerprint of `file_path` loaded the same way an apply loads it.
+///
+/// # Safety
+/// Calls Windows API functions.
+#[must_use]
+pub unsafe fn cursor_file_hash(file_path: &str, size: i32) -> Option<u64> {
+    let wide_path = to_wide(file_path);
+    let handle = LoadImageW(
+        None,
+        PCWSTR::from_raw(wide_path.as_ptr()),
+        IMAGE_CURSOR,
+        size,
+        size,
+        LR_LOADFROMFILE,
+    )
+    .ok()?;
+    let cursor = HCURSOR(handle.0);
+    let hash = cursor_hash(cursor);
+    let _ = DestroyCursor(cursor);
+    hash
+}
+
+/// Registry keys whose value differs between `expected` and `actual`.
+/// Keys missing from `actual` count as `None`.
+#[must_use]
+pub fn diff_cursor_registry_entries(
+    expected: &HashMap<String, Option<String>>,
+    actual: &HashMap<String, Option<String>>,
+) -> Vec<String> {
+    let mut mismatched: Vec<String> = expected
+        .iter()
+        .filter(|(key, value)| {
+            let actual = actual.get(*key).cloned().flatten().unwrap_or_default();
+            let expected = value.clone().unwrap_or_default();
+            !actual.eq_ignore_ascii_case(&expected)
+        })
+        .map(|(key, _)| key.clone())
+        .collect();
+    mismatched.sort();
+    mismatched
+}
+
+/// Read the cursor registry values back and return the keys that don't hold `expected`.
+#[must_use]
+pub fn verify_cursor_registry_entries(expected: &HashMap<String, Option<String>>) -> Vec<String> {
+    diff_cursor_registry_entries(expected, &snapshot_cursor_registry_entries())
+}
@@ -0,0 +1,189 @@
+//! Timeouts for `SystemParametersInfo` cursor reloads.
+//!
+//! `SPI_SETCURSORS` with `SPIF_SENDCHANGE` waits for every top-level window to handle the
+//! setting change. On some systems a buggy shell extension in the hook chain never answers
+//! and the call never returns. A [`Watchdog`] makes the call on a worker thread and stops
+//! waiting after a timeout. A stuck call cannot be cancelled, so its thread is left behind;
+//! until it returns, the watchdog refuses new calls instead of piling up more stuck threads.
+//! [`RefreshStrategy::Quiet`] reloads without the blocking broadcast, for use when the normal
+//! reload hangs.
+
+use std::fmt;
+use std::sync::atomic::{AtomicU8, AtomicUsize, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+/// How long callers wait for a cursor reload by default
+pub const REFRESH_TIMEOUT: Duration = Duration::from_secs(5);
+
+const RUNNING: u8 = 0;
+const FINISHED: u8 = 1;
+const ABANDONED: u8 = 2;
+
+/// How the cursor scheme is reloaded from the registry
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RefreshStrategy {
+    /// `SPI_SETCURSORS` with `SPIF_SENDCHANGE`, which waits for every window to see the change
+    Broadcast,
+    /// `SPI_SETCURSORS` without `SPIF_SENDCHANGE`, then a setting change broadcast that skips
+    /// hung windows
+    Quiet,
+}
+
+impl RefreshStrategy {
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Broadcast => "SPI_SETCURSORS",
+            Self::Quiet => "SPI_SETCURSORS (quiet)",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RefreshError {
+    /// The call did not return within `timeout`. It keeps running in the background.
+    TimedOut {
+        label: &'static str,
+        timeout: Duration,
+    },
+    /// An earlier call under the same watchdog has still not returned, so no new one was made
+    StillStuck { label: &'static str },
+    /// The call returned and reported failure
+    Failed { label: &'static str },
+    /// The worker thread could not be started
+    Spawn {
+        label: &'static str,
+        message: String,
+    },
+}
+
+impl RefreshError {
+    /// Whether the call hung, rather than failed outright
+    #[must_use]
+    pub const fn is_timeout(&self) -> bool {
+        matches!(self, Self::TimedOut { .. } | Self::StillStuck { .. })
+    }
+}
+
+impl fmt::Display for RefreshError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TimedOut { label, timeout } => write!(
+                f,
+                "{label} did not return within {} ms; another program may be blocking cursor changes",
+                timeout.as_millis()
+            ),
+            Self::StillStuck { label } => {
+                write!(f, "An earlier {label} call has still not returned")
+            }
+            Self::Failed { label } => write!(f, "{label} failed"),
+            Self::Spawn { label, message } => {
+                write!(f, "Failed to start a thread for {label}: {message}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for RefreshError {}
+
+/// Runs calls that may hang on a worker thread with a timeout. Calls that time out count as
+/// stuck until they return.
+#[derive(Debug, Default)]
+pub struct Watchdog {
+    stuck: AtomicUsize,
+}
+
+impl Watchdog {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            stuck: AtomicUsize::new(0),
+        }
+    }
+
+    /// Calls that timed out and have not returned yet
+    #[must_use]
+    pub fn stuck_calls(&self) -> usize {
+        self.stuck.load(Ordering::SeqCst)
+    }
+
+    /// Run `call` on a new thread and wait up to `timeout` for its result. `label` names the
+    /// call in errors and the log.
+    ///
+    /// # Errors
+    /// [`RefreshError::TimedOut`] when `call` does not return in time,
+    /// [`RefreshError::StillStuck`] when an earlier call is still running, and
+    /// [`RefreshError::Spawn`] when no thread could be started. A panicking `call` is
+    /// [`RefreshError::Failed`].
+    pub fn run<T: Send + 'static>(
+        &'static self,
+        label: &'static str,
+        timeout: Duration,
+        call: impl FnOnce() -> T + Send + 'static,
+    ) -> Result<T, RefreshError> {
+        if self.stuck_calls() > 0 {
+            return Err(RefreshError::StillStuck { label });
+        }
+
+        let state = Arc::new(AtomicU8::new(RUNNING));
+        let worker_state = Arc::clone(&state);
+        let (sender, receiver) = mpsc::channel();
+        thread::Builder::new()
+            .name(format!("{label} watchdog"))
+            .spawn(move || {
+                let result = call();
+                let abandoned = worker_state
+                    .compare_exchange(RUNNING, FINISHED, Ordering::SeqCst, Ordering::SeqCst)
+                    .is_err();
+                if abandoned {
+                    self.stuck.fetch_sub(1, Ordering::SeqCst);
+                    eprintln!("{label} returned after its watchdog gave up");
+                }
+                let _ = sender.send(result);
+            })
+            .map_err(|e| RefreshError::Spawn {
+                label,
+                message: e.to_string(),
+            })?;
+
+        match receiver.recv_timeout(timeout) {
+            Ok(result) => Ok(result),
+            Err(RecvTimeoutError::Disconnected) => Err(RefreshError::Failed { label }),
+            Err(RecvTimeoutError::Timeout) => {
+                // Count the call before marking it abandoned, so a worker returning right now
+                // never decrements below zero
+                self.stuck.fetch_add(1, Ordering::SeqCst);
+                let abandoned = state
+                    .compare_exchange(RUNNING, ABANDONED, Ordering::SeqCst, Ordering::SeqCst)
+                    .is_ok();
+                if abandoned {
+                    eprintln!("{label} did not return within {} ms", timeout.as_millis());
+                    return Err(RefreshError::TimedOut { label, timeout });
+                }
+                // Finished just as the timeout fired; the result is on its way
+                self.stuck.fetch_sub(1, Ordering::SeqCst);
+                receiver.recv().map_err(|_| RefreshError::Failed { label })
+            }
+        }
+    }
+
+    /// [`run`](Self::run) for Windows calls that only report success as a `bool`.
+    ///
+    /// # Errors
+    /// As [`run`](Self::run), plus [`RefreshError::Failed`] when `call` returns false
+    pub fn run_bool(
+        &'static self,
+        label: &'static str,
+        timeout: Duration,
+        call: impl FnOnce() -> bool + Send + 'static,
+    ) -> Result<(), RefreshError> {
+        if self.run(label, timeout, call)? {
+            Ok(())
+        } else {
+            Err(RefreshError::Failed { label })
+        }
+    }
+}
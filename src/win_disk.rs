@@ -0,0 +1,29 @@
+//! Free-space queries for the volume holding a path.
+
+use std::path::Path;
+
+use windows::core::PCWSTR;
+use windows::Win32::Storage::FileSystem::GetDiskFreeSpaceExW;
+
+use crate::win_common::to_wide;
+
+/// Bytes available to the current user on the volume containing `path`.
+///
+/// `path` does not have to exist yet: the nearest existing ancestor is queried, so a
+/// destination folder can be checked before it is created.
+pub fn available_disk_space(path: &Path) -> Result<u64, String> {
+    let existing = path
+        .ancestors()
+        .find(|p| p.exists())
+        .ok_or_else(|| format!("No existing folder on the path {}", path.display()))?;
+    let wide = to_wide(&existing.to_string_lossy());
+
+    let mut available = 0u64;
+    // SAFETY: `wide` is NUL-terminated and outlives the call; only the first out-parameter is
+    // requested.
+    unsafe {
+        GetDiskFreeSpaceExW(PCWSTR(wide.as_ptr()), Some(&raw mut available), None, None)
+    }
+    .map_err(|e| format!("Failed to query free space for {}: {e}", existing.display()))?;
+    Ok(available)
+}
@@ -0,0 +1,126 @@
+//! Low-level keyboard capture.
+//!
+//! Used for multi-step hotkeys: after the prefix shortcut fires, the next key press is routed
+//! to a callback instead of the focused app. Keyboard pointer control keeps a capture running
+//! and also watches key releases. The hook lives on its own thread with a message
+//! loop (low-level hooks are called on the installing thread) and is removed on drop.
+
+use std::cell::RefCell;
+use std::ptr::null_mut;
+use std::sync::mpsc;
+use std::thread::JoinHandle;
+
+use winapi::shared::minwindef::{DWORD, LPARAM, LRESULT, WPARAM};
+use winapi::um::libloaderapi::GetModuleHandleW;
+use winapi::um::processthreadsapi::GetCurrentThreadId;
+use winapi::um::winuser::{
+    CallNextHookEx, GetMessageW, PostThreadMessageW, SetWindowsHookExW, UnhookWindowsHookEx,
+    HC_ACTION, KBDLLHOOKSTRUCT, MSG, WH_KEYBOARD_LL, WM_KEYDOWN, WM_KEYUP, WM_QUIT, WM_SYSKEYDOWN,
+    WM_SYSKEYUP,
+};
+
+/// Callback for each key event: receives the virtual-key code and whether the key went down
+/// (auto-repeat included) rather than up, and returns `true` to swallow it.
+type KeyCallback = Box<dyn FnMut(u32, bool) -> bool + Send>;
+
+thread_local! {
+    static ON_KEY: RefCell<Option<KeyCallback>> = RefCell::new(None);
+}
+
+unsafe extern "system" fn keyboard_proc(code: i32, wparam: WPARAM, lparam: LPARAM) -> LRESULT {
+    #[allow(clippy::cast_possible_truncation)]
+    let message = wparam as u32;
+    let is_key_down = message == WM_KEYDOWN || message == WM_SYSKEYDOWN;
+    let is_key_up = message == WM_KEYUP || message == WM_SYSKEYUP;
+    if code == HC_ACTION && (is_key_down || is_key_up) {
+        let info = &*(lparam as *const KBDLLHOOKSTRUCT);
+        let swallow = ON_KEY.with(|cb| {
+            cb.borrow_mut()
+                .as_mut()
+                .is_some_and(|f| f(info.vkCode, is_key_down))
+        });
+        if swallow {
+            return 1;
+        }
+    }
+    CallNextHookEx(null_mut(), code, wparam, lparam)
+}
+
+/// Active keyboard capture. Dropping it removes the hook and stops the thread.
+pub struct KeyCapture {
+    thread_id: DWORD,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl KeyCapture {
+    /// Install the hook. Keep the callback quick: Windows skips hooks that take too long.
+    ///
+    /// # Errors
+    /// Returns an error if the hook could not be installed.
+    pub fn start<F>(mut on_key: F) -> Result<Self, String>
+    where
+        F: FnMut(u32) -> bool + Send + 'static,
+    {
+        Self::start_with_releases(move |vk, down| down && on_key(vk))
+    }
+
+    /// Like [`KeyCapture::start`], but the callback also sees key releases: it receives the
+    /// virtual-key code and `true` for key-down (including auto-repeat), `false` for key-up.
+    ///
+    /// # Errors
+    /// Returns an error if the hook could not be installed.
+    pub fn start_with_releases<F>(on_key: F) -> Result<Self, String>
+    where
+        F: FnMut(u32, bool) -> bool + Send + 'static,
+    {
+        let (ready_tx, ready_rx) = mpsc::channel();
+        let on_key: KeyCallback = Box::new(on_key);
+
+        let thread = std::thread::spawn(move || unsafe {
+            ON_KEY.with(|cb| *cb.borrow_mut() = Some(on_key));
+            let hook = SetWindowsHookExW(
+                WH_KEYBOARD_LL,
+                Some(keyboard_proc),
+                GetModuleHandleW(null_mut()),
+                0,
+            );
+            if hook.is_null() {
+                let _ = ready_tx.send(Err("Failed to install keyboard hook".to_string()));
+                return;
+            }
+            let _ = ready_tx.send(Ok(GetCurrentThreadId()));
+
+            let mut msg: MSG = std::mem::zeroed();
+            while GetMessageW(&raw mut msg, null_mut(), 0, 0) > 0 {}
+
+            UnhookWindowsHookEx(hook);
+            ON_KEY.with(|cb| *cb.borrow_mut() = None);
+        });
+
+        match ready_rx.recv() {
+            Ok(Ok(thread_id)) => Ok(Self {
+                thread_id,
+                thread: Some(thread),
+            }),
+            Ok(Err(e)) => {
+                let _ = thread.join();
+                Err(e)
+            }
+            Err(_) => Err("Keyboard hook thread exited unexpectedly".to_string()),
+        }
+    }
+}
+
+impl Drop for KeyCapture {
+    fn drop(&mut self) {
+        unsafe {
+            PostThreadMessageW(self.thread_id, WM_QUIT, 0, 0);
+        }
+        if let Some(thread) = self.thread.take() {
+            // Dropped from inside the callback: the loop exits on its own once it returns
+            if thread.thread().id() != std::thread::current().id() {
+                let _ = thread.join();
+            }
+        }
+    }
+}
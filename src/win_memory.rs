@@ -0,0 +1,30 @@
+//! Working-set trimming and thread background mode, so the tray-resident app can give memory
+//! and CPU back once heavy work is done.
+
+use windows::Win32::System::Threading::{
+    GetCurrentProcess, GetCurrentThread, SetProcessWorkingSetSize, SetThreadPriority,
+    THREAD_MODE_BACKGROUND_BEGIN, THREAD_MODE_BACKGROUND_END,
+};
+
+/// Ask Windows to page out as much of this process's working set as it can. Pages come back
+/// on their next use. Returns true on success.
+#[must_use]
+pub fn trim_working_set() -> bool {
+    // SAFETY: the current-process pseudo handle is always valid; passing `usize::MAX` for both
+    // sizes is the documented way to request a trim.
+    unsafe { SetProcessWorkingSetSize(GetCurrentProcess(), usize::MAX, usize::MAX) }.is_ok()
+}
+
+/// Move the calling thread into or out of background mode, which lowers its CPU, I/O and
+/// memory priority. Returns false if the thread already was in that mode.
+#[must_use]
+pub fn set_thread_background_mode(background: bool) -> bool {
+    let mode = if background {
+        THREAD_MODE_BACKGROUND_BEGIN
+    } else {
+        THREAD_MODE_BACKGROUND_END
+    };
+    // SAFETY: background mode may only be changed for the calling thread, whose pseudo handle
+    // is always valid.
+    unsafe { SetThreadPriority(GetCurrentThread(), mode) }.is_ok()
+}
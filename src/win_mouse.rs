@@ -0,0 +1,250 @@
+//! Low-level mouse wheel capture and synthetic pointer input.
+//!
+//! Used for modifier + wheel gestures: every wheel notch anywhere on the desktop is routed to a
+//! callback, which decides whether to swallow it. Like `win_keyboard`, the hook lives on its own
+//! thread with a message loop and is removed on drop. Keyboard pointer control moves the
+//! pointer and clicks through [`move_pointer_by`] and [`click`]; hot corners poll
+//! [`pointer_position`] against [`monitor_rect_at`], and per-monitor schemes look up
+//! [`monitor_name_at`].
+
+use std::cell::RefCell;
+use std::ptr::null_mut;
+use std::sync::mpsc;
+use std::thread::JoinHandle;
+
+use winapi::shared::minwindef::{DWORD, LPARAM, LRESULT, WPARAM};
+use winapi::shared::windef::POINT;
+use winapi::um::libloaderapi::GetModuleHandleW;
+use winapi::um::processthreadsapi::GetCurrentThreadId;
+use winapi::um::winuser::{
+    CallNextHookEx, GetAsyncKeyState, GetCursorPos, GetMessageW, GetMonitorInfoW, MonitorFromPoint,
+    PostThreadMessageW, SendInput, SetCursorPos, SetWindowsHookExW, UnhookWindowsHookEx, HC_ACTION,
+    INPUT, INPUT_MOUSE, MONITORINFO, MONITORINFOEXW, MONITOR_DEFAULTTONULL, MOUSEEVENTF_LEFTDOWN,
+    MOUSEEVENTF_LEFTUP, MOUSEEVENTF_RIGHTDOWN, MOUSEEVENTF_RIGHTUP, MSG, MSLLHOOKSTRUCT,
+    WH_MOUSE_LL, WM_MOUSEWHEEL, WM_QUIT,
+};
+
+/// Callback for each vertical wheel event: receives the wheel delta (positive away from the
+/// user, 120 per notch) and returns `true` to swallow it.
+type WheelCallback = Box<dyn FnMut(i16) -> bool + Send>;
+
+thread_local! {
+    static ON_WHEEL: RefCell<Option<WheelCallback>> = RefCell::new(None);
+}
+
+/// The wheel delta is the high word of `mouseData`.
+#[allow(clippy::cast_possible_truncation)]
+const fn wheel_delta(mouse_data: DWORD) -> i16 {
+    (mouse_data >> 16) as u16 as i16
+}
+
+unsafe extern "system" fn mouse_proc(code: i32, wparam: WPARAM, lparam: LPARAM) -> LRESULT {
+    #[allow(clippy::cast_possible_truncation)]
+    let is_wheel = wparam as u32 == WM_MOUSEWHEEL;
+    if code == HC_ACTION && is_wheel {
+        let info = &*(lparam as *const MSLLHOOKSTRUCT);
+        let delta = wheel_delta(info.mouseData);
+        let swallow = ON_WHEEL.with(|cb| cb.borrow_mut().as_mut().is_some_and(|f| f(delta)));
+        if swallow {
+            return 1;
+        }
+    }
+    CallNextHookEx(null_mut(), code, wparam, lparam)
+}
+
+/// Whether the key with virtual-key code `vk` is currently held down.
+#[must_use]
+pub fn is_key_down(vk: u32) -> bool {
+    #[allow(clippy::cast_possible_wrap)]
+    let state = unsafe { GetAsyncKeyState(vk as i32) };
+    state < 0
+}
+
+/// Move the pointer by `dx`, `dy` screen pixels, bypassing pointer acceleration.
+///
+/// # Errors
+/// Returns an error if the pointer position could not be read or set.
+pub fn move_pointer_by(dx: i32, dy: i32) -> Result<(), String> {
+    let mut point = POINT { x: 0, y: 0 };
+    unsafe {
+        if GetCursorPos(&raw mut point) == 0 {
+            return Err("Failed to read the pointer position".to_string());
+        }
+        if SetCursorPos(point.x.saturating_add(dx), point.y.saturating_add(dy)) == 0 {
+            return Err("Failed to move the pointer".to_string());
+        }
+    }
+    Ok(())
+}
+
+/// Current pointer position in virtual-screen coordinates.
+#[must_use]
+pub fn pointer_position() -> Option<(i32, i32)> {
+    let mut point = POINT { x: 0, y: 0 };
+    if unsafe { GetCursorPos(&raw mut point) } == 0 {
+        return None;
+    }
+    Some((point.x, point.y))
+}
+
+/// Bounds of one monitor in virtual-screen coordinates; `right` and `bottom` are exclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MonitorRect {
+    pub left: i32,
+    pub top: i32,
+    pub right: i32,
+    pub bottom: i32,
+}
+
+/// Bounds of the monitor showing the point `x`, `y`, if any monitor does.
+#[must_use]
+pub fn monitor_rect_at(x: i32, y: i32) -> Option<MonitorRect> {
+    unsafe {
+        let monitor = MonitorFromPoint(POINT { x, y }, MONITOR_DEFAULTTONULL);
+        if monitor.is_null() {
+            return None;
+        }
+        let mut info: MONITORINFO = std::mem::zeroed();
+        #[allow(clippy::cast_possible_truncation)]
+        {
+            info.cbSize = std::mem::size_of::<MONITORINFO>() as DWORD;
+        }
+        if GetMonitorInfoW(monitor, &raw mut info) == 0 {
+            return None;
+        }
+        let rect = info.rcMonitor;
+        Some(MonitorRect {
+            left: rect.left,
+            top: rect.top,
+            right: rect.right,
+            bottom: rect.bottom,
+        })
+    }
+}
+
+/// Device name (such as `\\.\DISPLAY2`) of the monitor showing the point `x`, `y`, if any
+/// monitor does. This is the name Tauri reports for the monitor.
+#[must_use]
+pub fn monitor_name_at(x: i32, y: i32) -> Option<String> {
+    unsafe {
+        let monitor = MonitorFromPoint(POINT { x, y }, MONITOR_DEFAULTTONULL);
+        if monitor.is_null() {
+            return None;
+        }
+        let mut info: MONITORINFOEXW = std::mem::zeroed();
+        #[allow(clippy::cast_possible_truncation)]
+        {
+            info.cbSize = std::mem::size_of::<MONITORINFOEXW>() as DWORD;
+        }
+        if GetMonitorInfoW(monitor, (&raw mut info).cast::<MONITORINFO>()) == 0 {
+            return None;
+        }
+        let len = info
+            .szDevice
+            .iter()
+            .position(|&c| c == 0)
+            .unwrap_or(info.szDevice.len());
+        Some(String::from_utf16_lossy(&info.szDevice[..len]))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MouseButton {
+    Left,
+    Right,
+}
+
+/// Click `button` at the current pointer position.
+///
+/// # Errors
+/// Returns an error if the input was blocked, e.g. by a higher-integrity foreground window.
+pub fn click(button: MouseButton) -> Result<(), String> {
+    let (down, up) = match button {
+        MouseButton::Left => (MOUSEEVENTF_LEFTDOWN, MOUSEEVENTF_LEFTUP),
+        MouseButton::Right => (MOUSEEVENTF_RIGHTDOWN, MOUSEEVENTF_RIGHTUP),
+    };
+    let mut inputs: [INPUT; 2] = unsafe { std::mem::zeroed() };
+    for (input, flags) in inputs.iter_mut().zip([down, up]) {
+        input.type_ = INPUT_MOUSE;
+        unsafe { input.u.mi_mut().dwFlags = flags };
+    }
+    #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+    let sent = unsafe {
+        SendInput(
+            inputs.len() as u32,
+            inputs.as_mut_ptr(),
+            std::mem::size_of::<INPUT>() as i32,
+        )
+    };
+    if sent as usize == inputs.len() {
+        Ok(())
+    } else {
+        Err("Failed to send the click".to_string())
+    }
+}
+
+/// Active wheel capture. Dropping it removes the hook and stops the thread.
+pub struct WheelCapture {
+    thread_id: DWORD,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl WheelCapture {
+    /// Install the hook. Keep the callback quick: Windows skips hooks that take too long.
+    ///
+    /// # Errors
+    /// Returns an error if the hook could not be installed.
+    pub fn start<F>(on_wheel: F) -> Result<Self, String>
+    where
+        F: FnMut(i16) -> bool + Send + 'static,
+    {
+        let (ready_tx, ready_rx) = mpsc::channel();
+        let on_wheel: WheelCallback = Box::new(on_wheel);
+
+        let thread = std::thread::spawn(move || unsafe {
+            ON_WHEEL.with(|cb| *cb.borrow_mut() = Some(on_wheel));
+            let hook = SetWindowsHookExW(
+                WH_MOUSE_LL,
+                Some(mouse_proc),
+                GetModuleHandleW(null_mut()),
+                0,
+            );
+            if hook.is_null() {
+                let _ = ready_tx.send(Err("Failed to install mouse hook".to_string()));
+                return;
+            }
+            let _ = ready_tx.send(Ok(GetCurrentThreadId()));
+
+            let mut msg: MSG = std::mem::zeroed();
+            while GetMessageW(&raw mut msg, null_mut(), 0, 0) > 0 {}
+
+            UnhookWindowsHookEx(hook);
+            ON_WHEEL.with(|cb| *cb.borrow_mut() = None);
+        });
+
+        match ready_rx.recv() {
+            Ok(Ok(thread_id)) => Ok(Self {
+                thread_id,
+                thread: Some(thread),
+            }),
+            Ok(Err(e)) => {
+                let _ = thread.join();
+                Err(e)
+            }
+            Err(_) => Err("Mouse hook thread exited unexpectedly".to_string()),
+        }
+    }
+}
+
+impl Drop for WheelCapture {
+    fn drop(&mut self) {
+        unsafe {
+            PostThreadMessageW(self.thread_id, WM_QUIT, 0, 0);
+        }
+        if let Some(thread) = self.thread.take() {
+            if thread.thread().id() != std::thread::current().id() {
+                let _ = thread.join();
+            }
+        }
+    }
+}
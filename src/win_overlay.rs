@@ -0,0 +1,424 @@
+//! Click-through overlay that draws a cursor image under the pointer.
+//!
+//! Windows applies one cursor scheme to every monitor. To show a different cursor on some
+//! monitors, the caller hides the real cursor there and the overlay draws a replica in a small
+//! layered, topmost window that follows the pointer. Like the hooks in `win_mouse`, the window
+//! lives on its own thread with a message loop and goes away on drop: a low-level mouse hook
+//! moves it on every pointer move, and a timer catches cursor shape changes that happen
+//! without movement, such as a page finishing loading under a still pointer.
+
+use std::cell::RefCell;
+use std::sync::mpsc;
+use std::sync::{Arc, OnceLock};
+use std::thread::JoinHandle;
+
+use windows::core::{w, PCWSTR};
+use windows::Win32::Foundation::{COLORREF, HWND, LPARAM, LRESULT, POINT, SIZE, WPARAM};
+use windows::Win32::Graphics::Gdi::{
+    CreateCompatibleDC, CreateDIBSection, DeleteDC, DeleteObject, GetDC, ReleaseDC, SelectObject,
+    AC_SRC_ALPHA, AC_SRC_OVER, BITMAPINFO, BITMAPINFOHEADER, BI_RGB, BLENDFUNCTION, DIB_RGB_COLORS,
+};
+use windows::Win32::System::LibraryLoader::GetModuleHandleW;
+use windows::Win32::System::Threading::GetCurrentThreadId;
+use windows::Win32::UI::WindowsAndMessaging::{
+    CallNextHookEx, CreateWindowExW, DefWindowProcW, DestroyWindow, DispatchMessageW,
+    GetCursorInfo, GetCursorPos, GetMessageW, LoadCursorW, PostThreadMessageW, RegisterClassExW,
+    SetTimer, SetWindowPos, SetWindowsHookExW, ShowWindow, UnhookWindowsHookEx,
+    UpdateLayeredWindow, CURSORINFO, CURSOR_SHOWING, HC_ACTION, HWND_TOPMOST, MA_NOACTIVATE, MSG,
+    MSLLHOOKSTRUCT, SWP_NOACTIVATE, SWP_NOSIZE, SW_HIDE, SW_SHOWNOACTIVATE, ULW_ALPHA, WH_MOUSE_LL,
+    WM_MOUSEACTIVATE, WM_MOUSEMOVE, WM_QUIT, WM_TIMER, WNDCLASSEXW, WS_EX_LAYERED,
+    WS_EX_NOACTIVATE, WS_EX_TOOLWINDOW, WS_EX_TOPMOST, WS_EX_TRANSPARENT, WS_POPUP,
+};
+
+use crate::win_cursor::{CURSOR_TYPES, EXTENDED_CURSOR_TYPES};
+
+/// How often the cursor shape is checked while the pointer is still.
+const SHAPE_POLL_MS: u32 = 50;
+const CLASS_NAME: PCWSTR = w!("CursorChangerOverlay");
+
+/// A cursor image ready for the overlay: premultiplied BGRA rows, top to bottom.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OverlayImage {
+    width: u32,
+    height: u32,
+    hotspot_x: i32,
+    hotspot_y: i32,
+    bgra: Vec<u8>,
+}
+
+impl OverlayImage {
+    /// Convert straight-alpha RGBA pixels, as the `image` crate produces them.
+    ///
+    /// # Errors
+    /// Returns an error if `rgba` does not hold `width` x `height` pixels or the image is empty.
+    pub fn from_rgba(
+        width: u32,
+        height: u32,
+        rgba: &[u8],
+        hotspot: (u32, u32),
+    ) -> Result<Self, String> {
+        let expected = width as usize * height as usize * 4;
+        if expected == 0 || rgba.len() != expected {
+            return Err(format!(
+                "Overlay image needs {width}x{height} RGBA pixels, got {} bytes",
+                rgba.len()
+            ));
+        }
+        #[allow(clippy::cast_possible_truncation)]
+        let premultiply = |c: u8, a: u8| ((u16::from(c) * u16::from(a) + 127) / 255) as u8;
+        let bgra = rgba
+            .chunks_exact(4)
+            .flat_map(|p| {
+                [
+                    premultiply(p[2], p[3]),
+                    premultiply(p[1], p[3]),
+                    premultiply(p[0], p[3]),
+                    p[3],
+                ]
+            })
+            .collect();
+        #[allow(clippy::cast_possible_wrap)]
+        let (hotspot_x, hotspot_y) = (
+            hotspot.0.min(width - 1) as i32,
+            hotspot.1.min(height - 1) as i32,
+        );
+        Ok(Self {
+            width,
+            height,
+            hotspot_x,
+            hotspot_y,
+            bgra,
+        })
+    }
+
+    #[must_use]
+    pub const fn size(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+
+    /// Hotspot, clamped into the image
+    #[must_use]
+    pub const fn hotspot(&self) -> (i32, i32) {
+        (self.hotspot_x, self.hotspot_y)
+    }
+
+    /// Premultiplied BGRA pixels
+    #[must_use]
+    pub fn bgra(&self) -> &[u8] {
+        &self.bgra
+    }
+}
+
+/// Decides what the overlay shows with the pointer at `x`, `y`: an image, or `None` to hide.
+type FrameCallback = Box<dyn FnMut(i32, i32) -> Option<Arc<OverlayImage>> + Send>;
+
+struct OverlayWindow {
+    hwnd: HWND,
+    frame: FrameCallback,
+    /// Image currently drawn into the window, compared by pointer to skip redraws
+    drawn: Option<Arc<OverlayImage>>,
+    visible: bool,
+}
+
+thread_local! {
+    static OVERLAY: RefCell<Option<OverlayWindow>> = const { RefCell::new(None) };
+}
+
+/// Copy `image` into the layered window and place its hotspot at `x`, `y`.
+unsafe fn draw(hwnd: HWND, image: &OverlayImage, x: i32, y: i32) -> windows::core::Result<()> {
+    #[allow(clippy::cast_possible_wrap)]
+    let (width, height) = (image.width as i32, image.height as i32);
+    let info = BITMAPINFO {
+        bmiHeader: BITMAPINFOHEADER {
+            #[allow(clippy::cast_possible_truncation)]
+            biSize: std::mem::size_of::<BITMAPINFOHEADER>() as u32,
+            biWidth: width,
+            // Negative height: rows run top to bottom like `bgra`
+            biHeight: -height,
+            biPlanes: 1,
+            biBitCount: 32,
+            biCompression: BI_RGB.0,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let screen = GetDC(None);
+    let memory = CreateCompatibleDC(Some(screen));
+    let mut bits = std::ptr::null_mut();
+    let result = CreateDIBSection(
+        Some(memory),
+        &raw const info,
+        DIB_RGB_COLORS,
+        &raw mut bits,
+        None,
+        0,
+    )
+    .and_then(|bitmap| {
+        std::ptr::copy_nonoverlapping(image.bgra.as_ptr(), bits.cast::<u8>(), image.bgra.len());
+        let previous = SelectObject(memory, bitmap.into());
+        let destination = POINT {
+            x: x - image.hotspot_x,
+            y: y - image.hotspot_y,
+        };
+        let size = SIZE {
+            cx: width,
+            cy: height,
+        };
+        let source = POINT::default();
+        #[allow(clippy::cast_possible_truncation)]
+        let blend = BLENDFUNCTION {
+            BlendOp: AC_SRC_OVER as u8,
+            BlendFlags: 0,
+            SourceConstantAlpha: 255,
+            AlphaFormat: AC_SRC_ALPHA as u8,
+        };
+        let updated = UpdateLayeredWindow(
+            hwnd,
+            Some(screen),
+            Some(&raw const destination),
+            Some(&raw const size),
+            Some(memory),
+            Some(&raw const source),
+            COLORREF(0),
+            Some(&raw const blend),
+            ULW_ALPHA,
+        );
+        SelectObject(memory, previous);
+        let _ = DeleteObject(bitmap.into());
+        updated
+    });
+    let _ = DeleteDC(memory);
+    ReleaseDC(None, screen);
+    result
+}
+
+/// Ask the callback what to show at `x`, `y` and update the window to match.
+fn refresh(x: i32, y: i32) {
+    OVERLAY.with(|overlay| {
+        // A hook call that arrives while the window is being updated has nothing to add
+        let Ok(mut overlay) = overlay.try_borrow_mut() else {
+            return;
+        };
+        let Some(window) = overlay.as_mut() else {
+            return;
+        };
+        let Some(image) = (window.frame)(x, y) else {
+            if window.visible {
+                unsafe {
+                    let _ = ShowWindow(window.hwnd, SW_HIDE);
+                }
+                window.visible = false;
+            }
+            return;
+        };
+
+        let unchanged = window
+            .drawn
+            .as_ref()
+            .is_some_and(|drawn| Arc::ptr_eq(drawn, &image));
+        unsafe {
+            if unchanged {
+                // Re-asserting HWND_TOPMOST keeps the replica above topmost windows raised later
+                let _ = SetWindowPos(
+                    window.hwnd,
+                    Some(HWND_TOPMOST),
+                    x - image.hotspot_x,
+                    y - image.hotspot_y,
+                    0,
+                    0,
+                    SWP_NOSIZE | SWP_NOACTIVATE,
+                );
+            } else if draw(window.hwnd, &image, x, y).is_ok() {
+                window.drawn = Some(image);
+            }
+            if !window.visible {
+                let _ = ShowWindow(window.hwnd, SW_SHOWNOACTIVATE);
+                window.visible = true;
+            }
+        }
+    });
+}
+
+unsafe extern "system" fn mouse_proc(code: i32, wparam: WPARAM, lparam: LPARAM) -> LRESULT {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+    let is_move = code == HC_ACTION as i32 && wparam.0 as u32 == WM_MOUSEMOVE;
+    if is_move {
+        let info = &*(lparam.0 as *const MSLLHOOKSTRUCT);
+        refresh(info.pt.x, info.pt.y);
+    }
+    CallNextHookEx(None, code, wparam, lparam)
+}
+
+unsafe extern "system" fn window_proc(
+    hwnd: HWND,
+    msg: u32,
+    wparam: WPARAM,
+    lparam: LPARAM,
+) -> LRESULT {
+    if msg == WM_MOUSEACTIVATE {
+        return LRESULT(MA_NOACTIVATE as isize);
+    }
+    DefWindowProcW(hwnd, msg, wparam, lparam)
+}
+
+unsafe fn create_window() -> windows::core::Result<HWND> {
+    let instance = GetModuleHandleW(PCWSTR::null())?.into();
+    let class = WNDCLASSEXW {
+        #[allow(clippy::cast_possible_truncation)]
+        cbSize: std::mem::size_of::<WNDCLASSEXW>() as u32,
+        lpfnWndProc: Some(window_proc),
+        hInstance: instance,
+        lpszClassName: CLASS_NAME,
+        ..Default::default()
+    };
+    // Fails harmlessly when an earlier overlay already registered the class
+    RegisterClassExW(&raw const class);
+    CreateWindowExW(
+        WS_EX_LAYERED | WS_EX_TRANSPARENT | WS_EX_TOPMOST | WS_EX_TOOLWINDOW | WS_EX_NOACTIVATE,
+        CLASS_NAME,
+        w!("Cursor overlay"),
+        WS_POPUP,
+        0,
+        0,
+        0,
+        0,
+        None,
+        None,
+        Some(instance),
+        None,
+    )
+}
+
+/// Running overlay. Dropping it removes the window and the hook and stops the thread.
+pub struct CursorOverlay {
+    thread_id: u32,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl CursorOverlay {
+    /// Create the overlay window, hidden until `frame` first returns an image. `frame` runs
+    /// inside a low-level mouse hook, so keep it quick: Windows skips hooks that take too long.
+    ///
+    /// # Errors
+    /// Returns an error if the window or the hook could not be created.
+    pub fn start<F>(frame: F) -> Result<Self, String>
+    where
+        F: FnMut(i32, i32) -> Option<Arc<OverlayImage>> + Send + 'static,
+    {
+        let (ready_tx, ready_rx) = mpsc::channel();
+        let frame: FrameCallback = Box::new(frame);
+
+        let thread = std::thread::spawn(move || unsafe {
+            let hwnd = match create_window() {
+                Ok(hwnd) => hwnd,
+                Err(e) => {
+                    let _ = ready_tx.send(Err(format!("Failed to create overlay window: {e}")));
+                    return;
+                }
+            };
+            OVERLAY.with(|overlay| {
+                *overlay.borrow_mut() = Some(OverlayWindow {
+                    hwnd,
+                    frame,
+                    drawn: None,
+                    visible: false,
+                });
+            });
+            let hook = match SetWindowsHookExW(
+                WH_MOUSE_LL,
+                Some(mouse_proc),
+                GetModuleHandleW(PCWSTR::null()).ok().map(Into::into),
+                0,
+            ) {
+                Ok(hook) => hook,
+                Err(e) => {
+                    OVERLAY.with(|overlay| *overlay.borrow_mut() = None);
+                    let _ = DestroyWindow(hwnd);
+                    let _ = ready_tx.send(Err(format!("Failed to install mouse hook: {e}")));
+                    return;
+                }
+            };
+            SetTimer(None, 0, SHAPE_POLL_MS, None);
+            let _ = ready_tx.send(Ok(GetCurrentThreadId()));
+
+            let mut msg = MSG::default();
+            while GetMessageW(&raw mut msg, None, 0, 0).as_bool() {
+                if msg.message == WM_TIMER && msg.hwnd.is_invalid() {
+                    let mut point = POINT::default();
+                    if GetCursorPos(&raw mut point).is_ok() {
+                        refresh(point.x, point.y);
+                    }
+                    continue;
+                }
+                DispatchMessageW(&raw const msg);
+            }
+
+            let _ = UnhookWindowsHookEx(hook);
+            OVERLAY.with(|overlay| *overlay.borrow_mut() = None);
+            let _ = DestroyWindow(hwnd);
+        });
+
+        match ready_rx.recv() {
+            Ok(Ok(thread_id)) => Ok(Self {
+                thread_id,
+                thread: Some(thread),
+            }),
+            Ok(Err(e)) => {
+                let _ = thread.join();
+                Err(e)
+            }
+            Err(_) => Err("Overlay thread exited unexpectedly".to_string()),
+        }
+    }
+}
+
+impl Drop for CursorOverlay {
+    fn drop(&mut self) {
+        unsafe {
+            let _ = PostThreadMessageW(self.thread_id, WM_QUIT, WPARAM(0), LPARAM(0));
+        }
+        if let Some(thread) = self.thread.take() {
+            if thread.thread().id() != std::thread::current().id() {
+                let _ = thread.join();
+            }
+        }
+    }
+}
+
+/// Shared handle of every system cursor with its `OCR_*` id. Replacing a system cursor's image
+/// keeps its handle, so these still identify the role after a scheme is applied or blanked.
+fn system_cursor_handles() -> &'static [(isize, u32)] {
+    static HANDLES: OnceLock<Vec<(isize, u32)>> = OnceLock::new();
+    HANDLES.get_or_init(|| {
+        CURSOR_TYPES
+            .iter()
+            .chain(EXTENDED_CURSOR_TYPES.iter())
+            .filter_map(|cursor_type| {
+                let id = PCWSTR(cursor_type.id as usize as *const u16);
+                let handle = unsafe { LoadCursorW(None, id) }.ok()?;
+                Some((handle.0 as isize, cursor_type.id))
+            })
+            .collect()
+    })
+}
+
+/// `OCR_*` id of the system cursor shown right now, or `None` when the cursor is hidden (as
+/// in most games) or the app under the pointer draws its own.
+#[must_use]
+pub fn current_cursor_id() -> Option<u32> {
+    let mut info = CURSORINFO {
+        #[allow(clippy::cast_possible_truncation)]
+        cbSize: std::mem::size_of::<CURSORINFO>() as u32,
+        ..Default::default()
+    };
+    unsafe { GetCursorInfo(&raw mut info) }.ok()?;
+    if info.flags.0 & CURSOR_SHOWING.0 == 0 {
+        return None;
+    }
+    let handle = info.hCursor.0 as isize;
+    system_cursor_handles()
+        .iter()
+        .find(|(h, _)| *h == handle)
+        .map(|(_, id)| *id)
+}
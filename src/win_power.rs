@@ -0,0 +1,32 @@
+//! Power status, so background work can back off while a laptop is saving energy.
+
+use windows::Win32::System::Power::{GetSystemPowerStatus, SYSTEM_POWER_STATUS};
+
+/// `ACLineStatus` value for "running on battery".
+const AC_LINE_OFFLINE: u8 = 0;
+/// `SystemStatusFlag` bit set while battery saver is on.
+const BATTERY_SAVER_ON: u8 = 1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PowerStatus {
+    pub on_battery: bool,
+    pub battery_saver: bool,
+}
+
+impl PowerStatus {
+    fn from_raw(status: &SYSTEM_POWER_STATUS) -> Self {
+        Self {
+            on_battery: status.ACLineStatus == AC_LINE_OFFLINE,
+            battery_saver: status.SystemStatusFlag & BATTERY_SAVER_ON != 0,
+        }
+    }
+}
+
+/// Current AC and battery saver state, or `None` if Windows could not report it.
+#[must_use]
+pub fn power_status() -> Option<PowerStatus> {
+    let mut status = SYSTEM_POWER_STATUS::default();
+    // SAFETY: `status` is a valid out-parameter for the duration of the call.
+    unsafe { GetSystemPowerStatus(&raw mut status) }.ok()?;
+    Some(PowerStatus::from_raw(&status))
+}
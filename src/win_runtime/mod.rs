@@ -1,5 +1,8 @@
+mod observer;
+
 use std::ptr::null_mut;
 use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::Sender;
 use std::sync::Arc;
 
 use winapi::shared::minwindef::{
@@ -22,9 +25,11 @@ use winapi::um::winuser::{
 
 use crate::win_common::{build_tip_buffer, to_wide};
 use crate::win_cursor::{
-    apply_blank_system_cursors, perform_toggle, restore_system_cursors, SystemApi,
+    apply_blank_system_cursors, perform_toggle, restore_system_cursors, BlankRoleMask, SystemApi,
 };
 
+pub use observer::RuntimeEvent;
+
 const WM_TRAY_ICON: UINT = WM_APP + 1;
 const HOTKEY_ID: i32 = 1;
 
@@ -35,6 +40,7 @@ fn restore_cursor_if_hidden(hidden: &Arc<AtomicBool>, context: &str) {
         unsafe {
             if restore_system_cursors() {
                 hidden.store(false, Ordering::SeqCst);
+                observer::notify(RuntimeEvent::Restored);
             } else {
                 eprintln!("Failed to restore system cursors ({context})");
             }
@@ -62,7 +68,7 @@ struct RealSystemApi;
 
 impl SystemApi for RealSystemApi {
     fn apply_blank_system_cursors(&mut self) -> bool {
-        unsafe { apply_blank_system_cursors() }
+        unsafe { apply_blank_system_cursors(BlankRoleMask::DEFAULT) }
     }
 
     fn restore_system_cursors(&mut self) -> bool {
@@ -76,6 +82,9 @@ fn toggle_cursor(hidden: &Arc<AtomicBool>) {
     let (ok, new_hidden) = perform_toggle(&mut api, currently_hidden);
     if ok {
         hidden.store(new_hidden, Ordering::SeqCst);
+        if new_hidden != currently_hidden {
+            observer::notify(RuntimeEvent::from_hidden(new_hidden));
+        }
     } else if currently_hidden && !ok {
         eprintln!("Failed to perform toggle operation");
     }
@@ -210,6 +219,7 @@ pub fn run_app() -> Result<(), String> {
         ) == 0
         {
             eprintln!("Failed to register hotkey");
+            observer::notify(RuntimeEvent::HotkeyFailed);
         }
 
         let mut nid: NOTIFYICONDATAW = std::mem::zeroed();
@@ -248,3 +258,13 @@ pub fn run_app() -> Result<(), String> {
 
     Ok(())
 }
+
+/// [`run_app`], sending a [`RuntimeEvent`] to `tx` whenever the cursor is hidden or restored
+/// or the hotkey fails to register. Events are sent from the message loop thread.
+///
+/// # Errors
+/// As [`run_app`].
+pub fn run_app_with_observer(tx: Sender<RuntimeEvent>) -> Result<(), String> {
+    observer::set_observer(tx);
+    run_app()
+}
@@ -0,0 +1,45 @@
+//! State-change notifications for programs embedding [`run_app`](super::run_app).
+
+use std::sync::mpsc::Sender;
+use std::sync::Mutex;
+
+/// Something the runtime did that an embedder may want to react to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RuntimeEvent {
+    /// The system cursors were replaced with blank ones
+    Hidden,
+    /// The system cursors were restored
+    Restored,
+    /// The Ctrl+Shift+C toggle hotkey could not be registered (usually already taken)
+    HotkeyFailed,
+}
+
+impl RuntimeEvent {
+    /// Event for a successful toggle that left the cursor `hidden` or not.
+    #[must_use]
+    pub fn from_hidden(hidden: bool) -> Self {
+        if hidden {
+            Self::Hidden
+        } else {
+            Self::Restored
+        }
+    }
+}
+
+static OBSERVER: Mutex<Option<Sender<RuntimeEvent>>> = Mutex::new(None);
+
+pub(super) fn set_observer(tx: Sender<RuntimeEvent>) {
+    if let Ok(mut observer) = OBSERVER.lock() {
+        *observer = Some(tx);
+    }
+}
+
+/// Send `event` to the observer, if any. A dropped receiver just stops delivery.
+pub(super) fn notify(event: RuntimeEvent) {
+    let Ok(mut observer) = OBSERVER.lock() else {
+        return;
+    };
+    if observer.as_ref().is_some_and(|tx| tx.send(event).is_err()) {
+        *observer = None;
+    }
+}
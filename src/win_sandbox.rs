@@ -0,0 +1,153 @@
+//! Confinement for helper processes that handle untrusted input: a job object that caps their
+//! memory and takes them down with its owner, and a token lowered to low integrity with every
+//! privilege removed.
+
+use std::os::windows::io::{AsRawHandle, FromRawHandle, OwnedHandle, RawHandle};
+
+use windows::core::PCWSTR;
+use windows::Win32::Foundation::HANDLE;
+use windows::Win32::Security::{
+    AdjustTokenPrivileges, CreateWellKnownSid, GetLengthSid, GetTokenInformation,
+    SetTokenInformation, TokenIntegrityLevel, TokenPrivileges, WinLowLabelSid, LUID_AND_ATTRIBUTES,
+    PSID, SECURITY_MAX_SID_SIZE, SE_PRIVILEGE_REMOVED, SID_AND_ATTRIBUTES, TOKEN_ADJUST_DEFAULT,
+    TOKEN_ADJUST_PRIVILEGES, TOKEN_MANDATORY_LABEL, TOKEN_PRIVILEGES, TOKEN_QUERY,
+};
+use windows::Win32::System::JobObjects::{
+    AssignProcessToJobObject, CreateJobObjectW, JobObjectExtendedLimitInformation,
+    SetInformationJobObject, JOBOBJECT_EXTENDED_LIMIT_INFORMATION, JOB_OBJECT_LIMIT_ACTIVE_PROCESS,
+    JOB_OBJECT_LIMIT_DIE_ON_UNHANDLED_EXCEPTION, JOB_OBJECT_LIMIT_KILL_ON_JOB_CLOSE,
+    JOB_OBJECT_LIMIT_PROCESS_MEMORY,
+};
+use windows::Win32::System::Threading::{GetCurrentProcess, OpenProcessToken};
+
+/// `SE_GROUP_INTEGRITY`; the SystemServices bindings are not worth enabling for one constant.
+const SE_GROUP_INTEGRITY: u32 = 0x20;
+
+/// Job object holding a confined process. Dropping it kills the process.
+pub struct ProcessJob(OwnedHandle);
+
+impl ProcessJob {
+    fn handle(&self) -> HANDLE {
+        HANDLE(self.0.as_raw_handle())
+    }
+}
+
+/// Put `process` in a new job that kills it when the returned [`ProcessJob`] is dropped, caps
+/// its committed memory at `memory_limit` bytes and keeps it from starting processes of its
+/// own. An unhandled exception ends it without the Windows error dialog.
+pub fn confine_process(process: RawHandle, memory_limit: usize) -> Result<ProcessJob, String> {
+    // SAFETY: no security attributes or name are passed; the call returns a new handle or fails.
+    let job = unsafe { CreateJobObjectW(None, PCWSTR::null()) }
+        .map_err(|e| format!("Failed to create job object: {e}"))?;
+    // SAFETY: `job` was just created and nothing else owns it.
+    let job = ProcessJob(unsafe { OwnedHandle::from_raw_handle(job.0) });
+
+    let mut limits = JOBOBJECT_EXTENDED_LIMIT_INFORMATION::default();
+    limits.BasicLimitInformation.LimitFlags = JOB_OBJECT_LIMIT_KILL_ON_JOB_CLOSE
+        | JOB_OBJECT_LIMIT_PROCESS_MEMORY
+        | JOB_OBJECT_LIMIT_ACTIVE_PROCESS
+        | JOB_OBJECT_LIMIT_DIE_ON_UNHANDLED_EXCEPTION;
+    limits.BasicLimitInformation.ActiveProcessLimit = 1;
+    limits.ProcessMemoryLimit = memory_limit;
+    // SAFETY: `limits` is the structure the information class expects and outlives the call.
+    unsafe {
+        SetInformationJobObject(
+            job.handle(),
+            JobObjectExtendedLimitInformation,
+            (&raw const limits).cast(),
+            std::mem::size_of::<JOBOBJECT_EXTENDED_LIMIT_INFORMATION>() as u32,
+        )
+    }
+    .map_err(|e| format!("Failed to set job limits: {e}"))?;
+
+    // SAFETY: both handles are valid; the caller keeps `process` open for the call.
+    unsafe { AssignProcessToJobObject(job.handle(), HANDLE(process)) }
+        .map_err(|e| format!("Failed to assign process to job: {e}"))?;
+    Ok(job)
+}
+
+/// Lower the current process to low integrity and remove every privilege from its token, for
+/// a helper about to read untrusted input. Neither change can be undone by the process, so
+/// code it runs afterwards can no longer write to the user's files or settings.
+pub fn lower_own_token() -> Result<(), String> {
+    let mut token = HANDLE::default();
+    // SAFETY: the current-process pseudo handle is always valid; `token` receives a new handle.
+    unsafe {
+        OpenProcessToken(
+            GetCurrentProcess(),
+            TOKEN_QUERY | TOKEN_ADJUST_DEFAULT | TOKEN_ADJUST_PRIVILEGES,
+            &raw mut token,
+        )
+    }
+    .map_err(|e| format!("Failed to open process token: {e}"))?;
+    // SAFETY: `token` was just opened and nothing else owns it.
+    let _owned = unsafe { OwnedHandle::from_raw_handle(token.0) };
+
+    remove_privileges(token)?;
+    set_low_integrity(token)
+}
+
+fn remove_privileges(token: HANDLE) -> Result<(), String> {
+    let mut len = 0u32;
+    // SAFETY: a size query; it fails with the required length in `len`.
+    let _ = unsafe { GetTokenInformation(token, TokenPrivileges, None, 0, &raw mut len) };
+    // u64 elements keep the buffer aligned for TOKEN_PRIVILEGES
+    let mut buffer = vec![0u64; (len as usize).div_ceil(8).max(1)];
+    // SAFETY: `buffer` holds at least `len` bytes.
+    unsafe {
+        GetTokenInformation(
+            token,
+            TokenPrivileges,
+            Some(buffer.as_mut_ptr().cast()),
+            len,
+            &raw mut len,
+        )
+    }
+    .map_err(|e| format!("Failed to read token privileges: {e}"))?;
+
+    let privileges = buffer.as_mut_ptr().cast::<TOKEN_PRIVILEGES>();
+    // SAFETY: Windows filled `buffer` with a TOKEN_PRIVILEGES followed by `PrivilegeCount`
+    // entries, and `buffer` outlives every use of `privileges`.
+    unsafe {
+        let count = (*privileges).PrivilegeCount as usize;
+        let entries = std::slice::from_raw_parts_mut(
+            (&raw mut (*privileges).Privileges).cast::<LUID_AND_ATTRIBUTES>(),
+            count,
+        );
+        for entry in entries {
+            entry.Attributes = SE_PRIVILEGE_REMOVED;
+        }
+        AdjustTokenPrivileges(token, false, Some(privileges), 0, None, None)
+    }
+    .map_err(|e| format!("Failed to remove token privileges: {e}"))
+}
+
+fn set_low_integrity(token: HANDLE) -> Result<(), String> {
+    // u32 elements keep the buffer aligned for a SID
+    let mut sid = [0u32; SECURITY_MAX_SID_SIZE as usize / 4];
+    let mut sid_len = SECURITY_MAX_SID_SIZE;
+    let psid = PSID(sid.as_mut_ptr().cast());
+    // SAFETY: `sid` holds SECURITY_MAX_SID_SIZE bytes, the size passed in `sid_len`.
+    unsafe { CreateWellKnownSid(WinLowLabelSid, None, Some(psid), &raw mut sid_len) }
+        .map_err(|e| format!("Failed to create low integrity SID: {e}"))?;
+
+    let label = TOKEN_MANDATORY_LABEL {
+        Label: SID_AND_ATTRIBUTES {
+            Sid: psid,
+            Attributes: SE_GROUP_INTEGRITY,
+        },
+    };
+    // SAFETY: `psid` points at the SID written above.
+    let label_len =
+        std::mem::size_of::<TOKEN_MANDATORY_LABEL>() as u32 + unsafe { GetLengthSid(psid) };
+    // SAFETY: `label` and the SID it points at outlive the call.
+    unsafe {
+        SetTokenInformation(
+            token,
+            TokenIntegrityLevel,
+            (&raw const label).cast(),
+            label_len,
+        )
+    }
+    .map_err(|e| format!("Failed to lower integrity level: {e}"))
+}
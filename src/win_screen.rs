@@ -0,0 +1,202 @@
+//! Reading the colour of the screen pixel under the mouse cursor, for eyedropper pickers.
+//!
+//! GDI's `GetPixel` on the screen DC is tried first. It fails for some surfaces (exclusive
+//! full-screen apps, protected or hardware-composited windows), in which case one frame is
+//! taken with DXGI desktop duplication and the pixel is read from that instead.
+
+use windows::core::Interface;
+use windows::Win32::Foundation::{HMODULE, POINT};
+use windows::Win32::Graphics::Direct3D::D3D_DRIVER_TYPE_HARDWARE;
+use windows::Win32::Graphics::Direct3D11::{
+    D3D11CreateDevice, ID3D11Device, ID3D11DeviceContext, ID3D11Texture2D, D3D11_BOX,
+    D3D11_CPU_ACCESS_READ, D3D11_CREATE_DEVICE_FLAG, D3D11_MAPPED_SUBRESOURCE, D3D11_MAP_READ,
+    D3D11_SDK_VERSION, D3D11_TEXTURE2D_DESC, D3D11_USAGE_STAGING,
+};
+use windows::Win32::Graphics::Dxgi::Common::{DXGI_FORMAT_B8G8R8A8_UNORM, DXGI_SAMPLE_DESC};
+use windows::Win32::Graphics::Dxgi::{
+    IDXGIDevice, IDXGIOutput1, IDXGIResource, DXGI_OUTDUPL_FRAME_INFO,
+};
+use windows::Win32::Graphics::Gdi::{GetDC, GetPixel, ReleaseDC};
+use windows::Win32::UI::WindowsAndMessaging::GetCursorPos;
+
+/// `GetPixel` result for pixels that cannot be read.
+const CLR_INVALID: u32 = 0xFFFF_FFFF;
+/// How long to wait for a desktop frame in the duplication fallback.
+const FRAME_TIMEOUT_MS: u32 = 100;
+
+/// A sampled screen pixel and where it was taken, in virtual-screen coordinates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScreenColor {
+    pub x: i32,
+    pub y: i32,
+    pub red: u8,
+    pub green: u8,
+    pub blue: u8,
+}
+
+/// Colour of the pixel currently under the mouse cursor.
+///
+/// # Errors
+/// Returns an error if the cursor position or the pixel could not be read.
+pub fn pick_screen_color() -> Result<ScreenColor, String> {
+    let mut point = POINT::default();
+    // SAFETY: `point` is a valid out-parameter for the duration of the call.
+    unsafe { GetCursorPos(&raw mut point) }
+        .map_err(|e| format!("Failed to read the cursor position: {e}"))?;
+
+    let (red, green, blue) = match gdi_pixel(point.x, point.y) {
+        Some(rgb) => rgb,
+        None => duplication_pixel(point.x, point.y)?,
+    };
+    Ok(ScreenColor {
+        x: point.x,
+        y: point.y,
+        red,
+        green,
+        blue,
+    })
+}
+
+fn gdi_pixel(x: i32, y: i32) -> Option<(u8, u8, u8)> {
+    // SAFETY: the screen DC is released before returning.
+    let color = unsafe {
+        let dc = GetDC(None);
+        if dc.is_invalid() {
+            return None;
+        }
+        let color = GetPixel(dc, x, y);
+        ReleaseDC(None, dc);
+        color
+    };
+    if color.0 == CLR_INVALID {
+        return None;
+    }
+    // COLORREF is 0x00BBGGRR
+    let [red, green, blue, _] = color.0.to_le_bytes();
+    Some((red, green, blue))
+}
+
+fn dxgi_error(e: &windows::core::Error) -> String {
+    format!("Desktop duplication failed: {e}")
+}
+
+fn duplication_pixel(x: i32, y: i32) -> Result<(u8, u8, u8), String> {
+    // SAFETY: every out-parameter points at a local that outlives its call, and the acquired
+    // frame is released before returning.
+    unsafe {
+        let mut device: Option<ID3D11Device> = None;
+        let mut context: Option<ID3D11DeviceContext> = None;
+        D3D11CreateDevice(
+            None,
+            D3D_DRIVER_TYPE_HARDWARE,
+            HMODULE::default(),
+            D3D11_CREATE_DEVICE_FLAG::default(),
+            None,
+            D3D11_SDK_VERSION,
+            Some(&raw mut device),
+            None,
+            Some(&raw mut context),
+        )
+        .map_err(|e| dxgi_error(&e))?;
+        let device = device.ok_or_else(|| "No Direct3D device was created".to_string())?;
+        let context = context.ok_or_else(|| "No Direct3D context was created".to_string())?;
+
+        let adapter = device
+            .cast::<IDXGIDevice>()
+            .and_then(|d| d.GetAdapter())
+            .map_err(|e| dxgi_error(&e))?;
+        let mut index = 0;
+        let (output, bounds) = loop {
+            let output = adapter
+                .EnumOutputs(index)
+                .map_err(|_| "No display found under the cursor".to_string())?;
+            let bounds = output
+                .GetDesc()
+                .map_err(|e| dxgi_error(&e))?
+                .DesktopCoordinates;
+            if x >= bounds.left && x < bounds.right && y >= bounds.top && y < bounds.bottom {
+                break (output, bounds);
+            }
+            index += 1;
+        };
+
+        let duplication = output
+            .cast::<IDXGIOutput1>()
+            .and_then(|o| o.DuplicateOutput(&device))
+            .map_err(|e| dxgi_error(&e))?;
+        let mut frame_info = DXGI_OUTDUPL_FRAME_INFO::default();
+        let mut resource: Option<IDXGIResource> = None;
+        duplication
+            .AcquireNextFrame(FRAME_TIMEOUT_MS, &raw mut frame_info, &raw mut resource)
+            .map_err(|e| dxgi_error(&e))?;
+
+        let result = read_frame_pixel(&device, &context, resource, x - bounds.left, y - bounds.top);
+        let _ = duplication.ReleaseFrame();
+        result
+    }
+}
+
+/// Copy the pixel at (`x`, `y`) of a duplicated frame into a 1x1 CPU-readable texture.
+unsafe fn read_frame_pixel(
+    device: &ID3D11Device,
+    context: &ID3D11DeviceContext,
+    resource: Option<IDXGIResource>,
+    x: i32,
+    y: i32,
+) -> Result<(u8, u8, u8), String> {
+    let frame: ID3D11Texture2D = resource
+        .ok_or_else(|| "No desktop frame was captured".to_string())?
+        .cast()
+        .map_err(|e| dxgi_error(&e))?;
+    let mut frame_desc = D3D11_TEXTURE2D_DESC::default();
+    frame.GetDesc(&raw mut frame_desc);
+    if frame_desc.Format != DXGI_FORMAT_B8G8R8A8_UNORM {
+        return Err("The desktop uses a pixel format the eyedropper cannot read".to_string());
+    }
+
+    #[allow(clippy::cast_sign_loss)]
+    let cpu_read = D3D11_CPU_ACCESS_READ.0 as u32;
+    let staging_desc = D3D11_TEXTURE2D_DESC {
+        Width: 1,
+        Height: 1,
+        MipLevels: 1,
+        ArraySize: 1,
+        Format: frame_desc.Format,
+        SampleDesc: DXGI_SAMPLE_DESC {
+            Count: 1,
+            Quality: 0,
+        },
+        Usage: D3D11_USAGE_STAGING,
+        BindFlags: 0,
+        CPUAccessFlags: cpu_read,
+        MiscFlags: 0,
+    };
+    let mut staging: Option<ID3D11Texture2D> = None;
+    device
+        .CreateTexture2D(&raw const staging_desc, None, Some(&raw mut staging))
+        .map_err(|e| dxgi_error(&e))?;
+    let staging = staging.ok_or_else(|| "No staging texture was created".to_string())?;
+
+    let (Ok(left), Ok(top)) = (u32::try_from(x), u32::try_from(y)) else {
+        return Err("The cursor is outside the captured display".to_string());
+    };
+    let source_box = D3D11_BOX {
+        left,
+        top,
+        front: 0,
+        right: left + 1,
+        bottom: top + 1,
+        back: 1,
+    };
+    context.CopySubresourceRegion(&staging, 0, 0, 0, 0, &frame, 0, Some(&raw const source_box));
+
+    let mut mapped = D3D11_MAPPED_SUBRESOURCE::default();
+    context
+        .Map(&staging, 0, D3D11_MAP_READ, 0, Some(&raw mut mapped))
+        .map_err(|e| dxgi_error(&e))?;
+    // Desktop frames are BGRA
+    let pixel = std::slice::from_raw_parts(mapped.pData.cast::<u8>(), 4);
+    let rgb = (pixel[2], pixel[1], pixel[0]);
+    context.Unmap(&staging, 0);
+    Ok(rgb)
+}
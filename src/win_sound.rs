@@ -0,0 +1,20 @@
+//! Short UI sounds from WAV data already in memory, such as the cues a cursor pack bundles.
+
+use windows::core::PCWSTR;
+use windows::Win32::Media::Audio::{PlaySoundW, SND_MEMORY, SND_NODEFAULT, SND_SYNC};
+
+/// Play a complete WAV file held in `wav` and return once it has finished. Returns false if
+/// Windows could not play it; no fallback sound is played in that case.
+#[must_use]
+pub fn play_wav_bytes(wav: &[u8]) -> bool {
+    // SAFETY: with SND_MEMORY the first argument points at the WAV image rather than a name.
+    // SND_SYNC keeps `wav` borrowed until playback is over.
+    unsafe {
+        PlaySoundW(
+            PCWSTR(wav.as_ptr().cast()),
+            None,
+            SND_MEMORY | SND_NODEFAULT | SND_SYNC,
+        )
+    }
+    .as_bool()
+}
@@ -0,0 +1,2 @@
+#[path = "suites/blank_role_mask_tests.rs"]
+mod blank_role_mask_tests;
@@ -0,0 +1,2 @@
+#[path = "suites/cli_output_tests.rs"]
+mod cli_output_tests;
@@ -0,0 +1,5 @@
+// Run with `cargo test --features e2e --test e2e_tests`. Set CURSOR_CHANGER_E2E_LIVE=1 only
+// inside a dedicated Windows test session: live mode changes the real system cursors.
+#[cfg(all(windows, feature = "e2e"))]
+#[path = "suites/e2e_tests.rs"]
+mod e2e_tests;
@@ -0,0 +1,3 @@
+#[cfg(windows)]
+#[path = "suites/overlay_image_tests.rs"]
+mod overlay_image_tests;
@@ -0,0 +1,2 @@
+#[path = "suites/registry_verify_tests.rs"]
+mod registry_verify_tests;
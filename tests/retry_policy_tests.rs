@@ -0,0 +1,5 @@
+#[path = "common/mod.rs"]
+mod common;
+
+#[path = "suites/retry_policy_tests.rs"]
+mod retry_policy_tests;
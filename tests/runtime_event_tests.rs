@@ -0,0 +1,5 @@
+#[path = "common/mod.rs"]
+mod common;
+
+#[path = "suites/runtime_event_tests.rs"]
+mod runtime_event_tests;
@@ -0,0 +1,44 @@
+use cursor_changer::{all_cursor_types, find_cursor_type, BlankRoleMask};
+
+fn id(name: &str) -> u32 {
+    find_cursor_type(name).expect("known cursor").id
+}
+
+#[test]
+fn default_hides_everything_but_the_text_cursor() {
+    assert_eq!(BlankRoleMask::default(), BlankRoleMask::DEFAULT);
+    assert!(!BlankRoleMask::DEFAULT.contains_id(id("IBeam")));
+    for ct in all_cursor_types().filter(|ct| ct.name != "IBeam") {
+        assert!(BlankRoleMask::DEFAULT.contains_id(ct.id), "{}", ct.name);
+    }
+    assert_eq!(BlankRoleMask::all_except(["IBeam"]), BlankRoleMask::DEFAULT);
+}
+
+#[test]
+fn strict_mask_covers_core_and_extended_roles() {
+    assert_eq!(
+        BlankRoleMask::ALL.names().count(),
+        all_cursor_types().count()
+    );
+    assert!(BlankRoleMask::ALL.contains_id(id("Person")));
+    assert!(!BlankRoleMask::ALL.contains_id(12345));
+    assert_eq!(BlankRoleMask::NONE.names().count(), 0);
+}
+
+#[test]
+fn masks_round_trip_through_bits_and_names() {
+    let mask = BlankRoleMask::all_except(["IBeam", "Hand", "NotACursor"]);
+    assert!(!mask.contains_id(id("Hand")));
+    assert!(mask.contains_id(id("Normal")));
+    assert_eq!(BlankRoleMask::from_bits(mask.bits()), mask);
+    assert_eq!(BlankRoleMask::from_bits(u32::MAX), BlankRoleMask::ALL);
+
+    let names: Vec<&str> = mask.names().collect();
+    assert!(!names.contains(&"Hand") && names.contains(&"Normal"));
+    assert_eq!(
+        BlankRoleMask::ALL
+            .without_id(id("Hand"))
+            .without_id(id("IBeam")),
+        mask
+    );
+}
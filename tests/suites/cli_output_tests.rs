@@ -0,0 +1,44 @@
+use cursor_changer::cli_output::{CliReport, OutputFormat};
+
+#[test]
+fn json_flag_is_removed_from_args() {
+    let mut args = vec!["apply".to_string(), "--json".to_string(), "a.cur".to_string()];
+    assert_eq!(OutputFormat::take_from_args(&mut args), OutputFormat::Json);
+    assert_eq!(args, vec!["apply", "a.cur"]);
+
+    let mut args = vec!["restore".to_string()];
+    assert_eq!(OutputFormat::take_from_args(&mut args), OutputFormat::Text);
+}
+
+#[test]
+fn json_report_has_stable_fields() {
+    let mut report = CliReport::start("apply");
+    report.applied("Normal");
+    report.error("Failed to apply Hand");
+    let report = report.finish();
+
+    assert!(!report.ok);
+    assert_eq!(report.exit_code(), 1);
+
+    let value: serde_json::Value =
+        serde_json::from_str(&report.render(OutputFormat::Json)).expect("valid JSON");
+    assert_eq!(value["command"], "apply");
+    assert_eq!(value["ok"], false);
+    assert_eq!(value["applied_roles"], serde_json::json!(["Normal"]));
+    assert_eq!(value["errors"], serde_json::json!(["Failed to apply Hand"]));
+    assert!(value["elapsed_ms"].is_u64());
+    assert!(value.get("started").is_none());
+}
+
+#[test]
+fn text_report_lists_roles_and_errors() {
+    let mut report = CliReport::start("apply");
+    report.applied("Normal");
+    report.applied("Hand");
+    let report = report.finish();
+
+    assert_eq!(report.exit_code(), 0);
+    let text = report.render(OutputFormat::Text);
+    assert!(text.starts_with("apply: applied 2 role(s) (Normal, Hand) in "));
+    assert!(!text.contains("error:"));
+}
@@ -1,9 +1,9 @@
-use cursor_changer::CURSOR_TYPES;
+use cursor_changer::{all_cursor_types, find_cursor_type, CURSOR_TYPES, EXTENDED_CURSOR_TYPES};
 
 #[test]
 fn test_all_cursor_types_have_unique_ids() {
     let mut ids = std::collections::HashSet::new();
-    for cursor_type in &CURSOR_TYPES {
+    for cursor_type in all_cursor_types() {
         assert!(
             ids.insert(cursor_type.id),
             "Duplicate ID: {}",
@@ -15,7 +15,7 @@ fn test_all_cursor_types_have_unique_ids() {
 #[test]
 fn test_all_cursor_types_have_unique_names() {
     let mut names = std::collections::HashSet::new();
-    for cursor_type in &CURSOR_TYPES {
+    for cursor_type in all_cursor_types() {
         assert!(
             names.insert(cursor_type.name),
             "Duplicate name: {}",
@@ -27,7 +27,7 @@ fn test_all_cursor_types_have_unique_names() {
 #[test]
 fn test_all_cursor_types_have_unique_registry_keys() {
     let mut keys = std::collections::HashSet::new();
-    for cursor_type in &CURSOR_TYPES {
+    for cursor_type in all_cursor_types() {
         assert!(
             keys.insert(cursor_type.registry_key),
             "Duplicate registry key: {}",
@@ -90,3 +90,22 @@ fn test_cursor_types_no_duplicate_ids() {
 fn test_cursor_types_count() {
     assert_eq!(CURSOR_TYPES.len(), 15);
 }
+
+#[test]
+fn test_extended_cursor_types_are_optional_roles() {
+    assert_eq!(EXTENDED_CURSOR_TYPES.len(), 2);
+    for cursor_type in &EXTENDED_CURSOR_TYPES {
+        assert!(cursor_type.is_extended());
+        assert!(cursor_type.id >= 32512 && cursor_type.id <= 32700);
+    }
+    assert!(CURSOR_TYPES.iter().all(|ct| !ct.is_extended()));
+    assert_eq!(all_cursor_types().count(), 17);
+}
+
+#[test]
+fn test_find_cursor_type_covers_extended_roles() {
+    assert_eq!(find_cursor_type("Pin").map(|ct| ct.id), Some(32671));
+    assert_eq!(find_cursor_type("Person").map(|ct| ct.id), Some(32672));
+    assert_eq!(find_cursor_type("Normal").map(|ct| ct.id), Some(32512));
+    assert!(find_cursor_type("pin").is_none());
+}
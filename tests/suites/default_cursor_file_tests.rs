@@ -96,3 +96,35 @@ fn test_find_default_cursor_in_dir() {
 }
 
 // Tests covering mac directories were removed since mac defaults are no longer bundled.
+
+#[test]
+fn test_system_scheme_files_name_known_cursor_types() {
+    use cursor_changer::{find_cursor_type, SystemCursorScheme};
+
+    for (name, file_name) in SystemCursorScheme::ALL
+        .iter()
+        .flat_map(|s| s.files().iter())
+    {
+        assert!(
+            find_cursor_type(name).is_some(),
+            "Unknown cursor type {name} for {file_name}"
+        );
+        assert!(file_name.ends_with(".cur") || file_name.ends_with(".ani"));
+    }
+}
+
+#[test]
+fn test_system_scheme_file_lookup() {
+    use cursor_changer::SystemCursorScheme;
+
+    assert_eq!(
+        SystemCursorScheme::Inverted.file_name("Normal"),
+        Some("arrow_i.cur")
+    );
+    assert_eq!(
+        SystemCursorScheme::Black.file_name("Cross"),
+        Some("cross_r.cur")
+    );
+    assert_eq!(SystemCursorScheme::Magnified.file_name("Hand"), None);
+    assert_eq!(SystemCursorScheme::Aero.file_name("Unknown"), None);
+}
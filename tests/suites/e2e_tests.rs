@@ -0,0 +1,126 @@
+use std::collections::HashMap;
+
+use cursor_changer::{
+    clear_cursor_registry_entries, find_system_cursor_file, read_cursor_image_from_registry,
+    restore_cursor_registry_entries, restore_system_cursors, snapshot_cursor_registry_entries,
+    verify_cursor_registry_entries, write_cursor_image_to_registry, CursorType, RegistryWriteError,
+    SystemCursorScheme, TestCursorSession, TestSessionMode, CURSOR_TYPES,
+};
+
+/// A pack as the apply path sees it: registry value name → cursor file.
+fn pack(label: &str) -> HashMap<String, Option<String>> {
+    CURSOR_TYPES
+        .iter()
+        .map(|ct| {
+            let path = find_system_cursor_file(ct.name, SystemCursorScheme::Aero).map_or_else(
+                || format!("C:\\e2e-packs\\{label}\\{}.cur", ct.name),
+                |p| p.to_string_lossy().to_string(),
+            );
+            (ct.registry_key.to_string(), Some(path))
+        })
+        .collect()
+}
+
+fn apply_pack(pack: &HashMap<String, Option<String>>) {
+    for cursor_type in &CURSOR_TYPES {
+        if let Some(Some(path)) = pack.get(cursor_type.registry_key) {
+            write_cursor_image_to_registry(cursor_type, path).expect("write cursor registry value");
+        }
+    }
+}
+
+#[test]
+fn pack_apply_verify_restore_loop() {
+    let session = TestCursorSession::start();
+    let original = snapshot_cursor_registry_entries();
+
+    for round in 0..3 {
+        let pack = pack(&format!("round-{round}"));
+        apply_pack(&pack);
+        assert!(
+            verify_cursor_registry_entries(&pack).is_empty(),
+            "round {round}: registry does not hold the applied pack"
+        );
+
+        assert!(restore_cursor_registry_entries(&original));
+        assert!(unsafe { restore_system_cursors() });
+        assert!(
+            verify_cursor_registry_entries(&original).is_empty(),
+            "round {round}: registry was not restored"
+        );
+    }
+
+    if session.mode() == TestSessionMode::Isolated {
+        // One refresh per written role plus one restore per round
+        assert_eq!(session.spi_calls(), 3 * (CURSOR_TYPES.len() + 1));
+    }
+}
+
+#[test]
+fn clearing_resets_every_role_and_restore_brings_the_pack_back() {
+    let _session = TestCursorSession::start();
+    let pack = pack("clear");
+    apply_pack(&pack);
+
+    assert!(clear_cursor_registry_entries());
+    for cursor_type in &CURSOR_TYPES {
+        assert_eq!(
+            read_cursor_image_from_registry(cursor_type).as_deref(),
+            Some(""),
+            "{} was not cleared",
+            cursor_type.name
+        );
+    }
+
+    assert!(restore_cursor_registry_entries(&pack));
+    assert!(verify_cursor_registry_entries(&pack).is_empty());
+}
+
+#[test]
+fn unknown_roles_never_reach_the_registry() {
+    let _session = TestCursorSession::start();
+    let before = snapshot_cursor_registry_entries();
+    let rogue = CursorType {
+        id: CURSOR_TYPES[0].id,
+        name: "Rogue",
+        registry_key: "Rogue",
+        display_name: "Rogue",
+    };
+
+    assert_eq!(
+        write_cursor_image_to_registry(&rogue, "C:\\e2e-packs\\rogue.cur"),
+        Err(RegistryWriteError::UnexpectedValueName("Rogue".to_string()))
+    );
+    assert_eq!(snapshot_cursor_registry_entries(), before);
+}
+
+#[test]
+fn live_apply_changes_the_visible_cursor_and_restore_reverts_it() {
+    let session = TestCursorSession::start();
+    if session.mode() != TestSessionMode::Live {
+        eprintln!("skipped: needs a dedicated session with CURSOR_CHANGER_E2E_LIVE=1");
+        return;
+    }
+
+    let normal = CURSOR_TYPES
+        .iter()
+        .find(|ct| ct.name == "Normal")
+        .expect("Normal role");
+    let Some(file) = find_system_cursor_file("Help", SystemCursorScheme::Aero) else {
+        eprintln!("skipped: aero cursor files are not installed");
+        return;
+    };
+    let file = file.to_string_lossy().to_string();
+
+    unsafe {
+        let before = cursor_changer::live_cursor_hash(normal.id);
+        let expected = cursor_changer::cursor_file_hash(&file, 32);
+        assert!(cursor_changer::apply_cursor_from_file_with_size(
+            &file, normal.id, 32
+        ));
+        assert_eq!(cursor_changer::live_cursor_hash(normal.id), expected);
+
+        assert!(restore_system_cursors());
+        assert_eq!(cursor_changer::live_cursor_hash(normal.id), before);
+    }
+}
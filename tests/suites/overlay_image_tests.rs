@@ -0,0 +1,23 @@
+use cursor_changer::win_overlay::OverlayImage;
+
+#[test]
+fn rgba_is_premultiplied_into_bgra() {
+    let rgba = [200, 100, 50, 128, 10, 20, 30, 255];
+    let image = OverlayImage::from_rgba(2, 1, &rgba, (0, 0)).expect("valid image");
+
+    assert_eq!(image.size(), (2, 1));
+    assert_eq!(image.bgra(), &[25, 50, 100, 128, 30, 20, 10, 255]);
+}
+
+#[test]
+fn hotspot_is_clamped_into_the_image() {
+    let image = OverlayImage::from_rgba(2, 2, &[0; 16], (5, 1)).expect("valid image");
+
+    assert_eq!(image.hotspot(), (1, 1));
+}
+
+#[test]
+fn mismatched_pixel_data_is_rejected() {
+    assert!(OverlayImage::from_rgba(2, 2, &[0; 12], (0, 0)).is_err());
+    assert!(OverlayImage::from_rgba(0, 0, &[], (0, 0)).is_err());
+}
@@ -0,0 +1,48 @@
+use std::collections::HashMap;
+
+use cursor_changer::diff_cursor_registry_entries;
+
+fn entries(pairs: &[(&str, Option<&str>)]) -> HashMap<String, Option<String>> {
+    pairs
+        .iter()
+        .map(|(k, v)| ((*k).to_string(), v.map(str::to_string)))
+        .collect()
+}
+
+#[test]
+fn matching_entries_have_no_diff() {
+    let expected = entries(&[("Arrow", Some("C:\\a.cur")), ("Hand", None)]);
+    let actual = entries(&[("Arrow", Some("c:\\A.CUR")), ("Hand", Some(""))]);
+    assert!(diff_cursor_registry_entries(&expected, &actual).is_empty());
+}
+
+#[test]
+fn changed_and_missing_entries_are_reported_sorted() {
+    let expected = entries(&[
+        ("Wait", Some("C:\\busy.ani")),
+        ("Arrow", Some("C:\\a.cur")),
+        ("Hand", None),
+    ]);
+    let actual = entries(&[("Arrow", Some("C:\\other.cur")), ("Hand", None)]);
+    assert_eq!(
+        diff_cursor_registry_entries(&expected, &actual),
+        vec!["Arrow".to_string(), "Wait".to_string()]
+    );
+}
+
+#[test]
+fn only_known_cursor_value_names_may_be_written() {
+    use cursor_changer::{
+        all_cursor_types, validate_cursor_registry_value_name, RegistryWriteError,
+    };
+
+    for cursor_type in all_cursor_types() {
+        assert!(validate_cursor_registry_value_name(cursor_type.registry_key).is_ok());
+    }
+    assert_eq!(
+        validate_cursor_registry_value_name("Scheme Source"),
+        Err(RegistryWriteError::UnexpectedValueName("Scheme Source".to_string()))
+    );
+    assert!(validate_cursor_registry_value_name("arrow").is_err());
+    assert!(validate_cursor_registry_value_name("").is_err());
+}
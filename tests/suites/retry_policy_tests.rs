@@ -0,0 +1,56 @@
+use std::time::Duration;
+
+use cursor_changer::RetryPolicy;
+
+const INSTANT: RetryPolicy = RetryPolicy {
+    max_attempts: 3,
+    initial_delay: Duration::ZERO,
+    max_delay: Duration::ZERO,
+};
+
+#[test]
+fn test_retry_stops_at_first_success() {
+    let mut calls = 0;
+    let result: Result<u32, String> = INSTANT.run("test op", || {
+        calls += 1;
+        if calls < 2 {
+            Err("busy".to_string())
+        } else {
+            Ok(calls)
+        }
+    });
+    assert_eq!(result, Ok(2));
+    assert_eq!(calls, 2);
+}
+
+#[test]
+fn test_retry_gives_up_after_max_attempts() {
+    let mut calls = 0;
+    let result: Result<(), String> = INSTANT.run("test op", || {
+        calls += 1;
+        Err(format!("failure {calls}"))
+    });
+    assert_eq!(result, Err("failure 3".to_string()));
+    assert_eq!(calls, 3);
+}
+
+#[test]
+fn test_retry_none_policy_tries_once() {
+    let mut calls = 0;
+    assert!(!RetryPolicy::NONE.run_bool("test op", || {
+        calls += 1;
+        false
+    }));
+    assert_eq!(calls, 1);
+}
+
+#[test]
+fn test_retry_backoff_doubles_up_to_cap() {
+    let policy = RetryPolicy::DEFAULT;
+    assert_eq!(policy.backoff(1), Duration::from_millis(25));
+    assert_eq!(policy.backoff(2), Duration::from_millis(50));
+    assert_eq!(policy.backoff(3), Duration::from_millis(100));
+    assert_eq!(policy.backoff(4), Duration::from_millis(200));
+    assert_eq!(policy.backoff(10), Duration::from_millis(200));
+    assert_eq!(policy.backoff(64), Duration::from_millis(200));
+}
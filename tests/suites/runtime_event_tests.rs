@@ -0,0 +1,16 @@
+use cursor_changer::RuntimeEvent;
+
+#[test]
+fn test_toggle_events_follow_the_new_state() {
+    assert_eq!(RuntimeEvent::from_hidden(true), RuntimeEvent::Hidden);
+    assert_eq!(RuntimeEvent::from_hidden(false), RuntimeEvent::Restored);
+}
+
+#[test]
+fn test_events_can_be_sent_across_threads() {
+    let (tx, rx) = std::sync::mpsc::channel();
+    std::thread::spawn(move || tx.send(RuntimeEvent::HotkeyFailed).unwrap())
+        .join()
+        .unwrap();
+    assert_eq!(rx.recv().unwrap(), RuntimeEvent::HotkeyFailed);
+}
@@ -0,0 +1,58 @@
+use std::sync::mpsc;
+use std::time::Duration;
+
+use cursor_changer::{RefreshError, Watchdog};
+
+const SHORT: Duration = Duration::from_millis(50);
+const LONG: Duration = Duration::from_secs(5);
+
+#[test]
+fn test_watchdog_returns_the_result_of_a_quick_call() {
+    static WATCHDOG: Watchdog = Watchdog::new();
+    assert_eq!(WATCHDOG.run("quick", LONG, || 42), Ok(42));
+    assert_eq!(WATCHDOG.run_bool("quick", LONG, || true), Ok(()));
+    assert_eq!(
+        WATCHDOG.run_bool("quick", LONG, || false),
+        Err(RefreshError::Failed { label: "quick" })
+    );
+    assert_eq!(WATCHDOG.stuck_calls(), 0);
+}
+
+#[test]
+fn test_watchdog_gives_up_on_a_hung_call_and_refuses_more_until_it_returns() {
+    static WATCHDOG: Watchdog = Watchdog::new();
+    let (release, hung) = mpsc::channel::<()>();
+
+    let result = WATCHDOG.run_bool("hung", SHORT, move || hung.recv().is_ok());
+    assert_eq!(
+        result,
+        Err(RefreshError::TimedOut {
+            label: "hung",
+            timeout: SHORT
+        })
+    );
+    assert!(result.unwrap_err().is_timeout());
+    assert_eq!(WATCHDOG.stuck_calls(), 1);
+    assert_eq!(
+        WATCHDOG.run("next", LONG, || ()),
+        Err(RefreshError::StillStuck { label: "next" })
+    );
+
+    release.send(()).expect("release hung call");
+    for _ in 0..100 {
+        if WATCHDOG.stuck_calls() == 0 {
+            break;
+        }
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    assert_eq!(WATCHDOG.stuck_calls(), 0);
+    assert_eq!(WATCHDOG.run("next", LONG, || 7), Ok(7));
+}
+
+#[test]
+fn test_watchdog_reports_a_panicking_call_as_failed() {
+    static WATCHDOG: Watchdog = Watchdog::new();
+    let result = WATCHDOG.run("panics", LONG, || -> u32 { panic!("call panicked") });
+    assert_eq!(result, Err(RefreshError::Failed { label: "panics" }));
+    assert_eq!(WATCHDOG.stuck_calls(), 0);
+}
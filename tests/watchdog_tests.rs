@@ -0,0 +1,2 @@
+#[path = "suites/watchdog_tests.rs"]
+mod watchdog_tests;